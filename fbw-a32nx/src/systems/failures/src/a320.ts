@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: GPL-3.0
 
-import { FailureDefinition } from '@flybywiresim/fbw-sdk';
+import { FailureDefinition, FailureReliability } from '@flybywiresim/fbw-sdk';
 
 export const A320Failure = Object.freeze({
   Acsc1Lane1: 21000,
@@ -25,6 +25,9 @@ export const A320Failure = Object.freeze({
   OutflowValve: 21017,
   SafetyValve: 21018,
   RapidDecompression: 21019,
+  AvionicsBlowerFan: 21020,
+  AvionicsExtractFan: 21021,
+  AvionicsSmoke: 21022,
 
   Fac1Failure: 22000,
   Fac2Failure: 22001,
@@ -63,6 +66,115 @@ export const A320Failure = Object.freeze({
   Fcdc1Failure: 27005,
   Fcdc2Failure: 27006,
 
+  LeftAileronBlueServoJam: 27100,
+  LeftAileronGreenServoJam: 27101,
+  RightAileronBlueServoJam: 27102,
+  RightAileronGreenServoJam: 27103,
+  LeftElevatorBlueServoJam: 27110,
+  LeftElevatorGreenServoJam: 27111,
+  RightElevatorBlueServoJam: 27112,
+  RightElevatorYellowServoJam: 27113,
+  RudderGreenServoJam: 27120,
+  RudderBlueServoJam: 27121,
+  RudderYellowServoJam: 27122,
+  LeftSpoiler1ServoJam: 27131,
+  LeftSpoiler2ServoJam: 27132,
+  LeftSpoiler3ServoJam: 27133,
+  LeftSpoiler4ServoJam: 27134,
+  LeftSpoiler5ServoJam: 27135,
+  RightSpoiler1ServoJam: 27141,
+  RightSpoiler2ServoJam: 27142,
+  RightSpoiler3ServoJam: 27143,
+  RightSpoiler4ServoJam: 27144,
+  RightSpoiler5ServoJam: 27145,
+
+  LeftAileronBlueServoRunaway: 27200,
+  LeftAileronGreenServoRunaway: 27201,
+  RightAileronBlueServoRunaway: 27202,
+  RightAileronGreenServoRunaway: 27203,
+  LeftElevatorBlueServoRunaway: 27210,
+  LeftElevatorGreenServoRunaway: 27211,
+  RightElevatorBlueServoRunaway: 27212,
+  RightElevatorYellowServoRunaway: 27213,
+  RudderGreenServoRunaway: 27220,
+  RudderBlueServoRunaway: 27221,
+  RudderYellowServoRunaway: 27222,
+  LeftSpoiler1ServoRunaway: 27231,
+  LeftSpoiler2ServoRunaway: 27232,
+  LeftSpoiler3ServoRunaway: 27233,
+  LeftSpoiler4ServoRunaway: 27234,
+  LeftSpoiler5ServoRunaway: 27235,
+  RightSpoiler1ServoRunaway: 27241,
+  RightSpoiler2ServoRunaway: 27242,
+  RightSpoiler3ServoRunaway: 27243,
+  RightSpoiler4ServoRunaway: 27244,
+  RightSpoiler5ServoRunaway: 27245,
+
+  LeftAileronBlueServoFeedbackLoss: 27300,
+  LeftAileronGreenServoFeedbackLoss: 27301,
+  RightAileronBlueServoFeedbackLoss: 27302,
+  RightAileronGreenServoFeedbackLoss: 27303,
+  LeftElevatorBlueServoFeedbackLoss: 27310,
+  LeftElevatorGreenServoFeedbackLoss: 27311,
+  RightElevatorBlueServoFeedbackLoss: 27312,
+  RightElevatorYellowServoFeedbackLoss: 27313,
+  RudderGreenServoFeedbackLoss: 27320,
+  RudderBlueServoFeedbackLoss: 27321,
+  RudderYellowServoFeedbackLoss: 27322,
+  LeftSpoiler1ServoFeedbackLoss: 27331,
+  LeftSpoiler2ServoFeedbackLoss: 27332,
+  LeftSpoiler3ServoFeedbackLoss: 27333,
+  LeftSpoiler4ServoFeedbackLoss: 27334,
+  LeftSpoiler5ServoFeedbackLoss: 27335,
+  RightSpoiler1ServoFeedbackLoss: 27341,
+  RightSpoiler2ServoFeedbackLoss: 27342,
+  RightSpoiler3ServoFeedbackLoss: 27343,
+  RightSpoiler4ServoFeedbackLoss: 27344,
+  RightSpoiler5ServoFeedbackLoss: 27345,
+
+  LeftAileronBlueServoOscillation: 27400,
+  LeftAileronGreenServoOscillation: 27401,
+  RightAileronBlueServoOscillation: 27402,
+  RightAileronGreenServoOscillation: 27403,
+  LeftElevatorBlueServoOscillation: 27410,
+  LeftElevatorGreenServoOscillation: 27411,
+  RightElevatorBlueServoOscillation: 27412,
+  RightElevatorYellowServoOscillation: 27413,
+  RudderGreenServoOscillation: 27420,
+  RudderBlueServoOscillation: 27421,
+  RudderYellowServoOscillation: 27422,
+  LeftSpoiler1ServoOscillation: 27431,
+  LeftSpoiler2ServoOscillation: 27432,
+  LeftSpoiler3ServoOscillation: 27433,
+  LeftSpoiler4ServoOscillation: 27434,
+  LeftSpoiler5ServoOscillation: 27435,
+  RightSpoiler1ServoOscillation: 27441,
+  RightSpoiler2ServoOscillation: 27442,
+  RightSpoiler3ServoOscillation: 27443,
+  RightSpoiler4ServoOscillation: 27444,
+  RightSpoiler5ServoOscillation: 27445,
+  LeftAileronBlueServoInternalLeak: 27500,
+  LeftAileronGreenServoInternalLeak: 27501,
+  RightAileronBlueServoInternalLeak: 27502,
+  RightAileronGreenServoInternalLeak: 27503,
+  LeftElevatorBlueServoInternalLeak: 27510,
+  LeftElevatorGreenServoInternalLeak: 27511,
+  RightElevatorBlueServoInternalLeak: 27512,
+  RightElevatorYellowServoInternalLeak: 27513,
+  RudderGreenServoInternalLeak: 27520,
+  RudderBlueServoInternalLeak: 27521,
+  RudderYellowServoInternalLeak: 27522,
+  LeftSpoiler1ServoInternalLeak: 27531,
+  LeftSpoiler2ServoInternalLeak: 27532,
+  LeftSpoiler3ServoInternalLeak: 27533,
+  LeftSpoiler4ServoInternalLeak: 27534,
+  LeftSpoiler5ServoInternalLeak: 27535,
+  RightSpoiler1ServoInternalLeak: 27541,
+  RightSpoiler2ServoInternalLeak: 27542,
+  RightSpoiler3ServoInternalLeak: 27543,
+  RightSpoiler4ServoInternalLeak: 27544,
+  RightSpoiler5ServoInternalLeak: 27545,
+
   GreenReservoirLeak: 29000,
   BlueReservoirLeak: 29001,
   YellowReservoirLeak: 29002,
@@ -76,6 +188,20 @@ export const A320Failure = Object.freeze({
   BlueEpumpOverheat: 29010,
   YellowEdpOverheat: 29011,
   YellowEpumpOverheat: 29012,
+  GreenPumpSectionLeak: 29013,
+  GreenSystemUpstreamPriorityValveLeak: 29014,
+  GreenSystemDownstreamPriorityValveLeak: 29015,
+  BluePumpSectionLeak: 29016,
+  BlueSystemUpstreamPriorityValveLeak: 29017,
+  BlueSystemDownstreamPriorityValveLeak: 29018,
+  YellowPumpSectionLeak: 29019,
+  YellowSystemUpstreamPriorityValveLeak: 29020,
+  YellowSystemDownstreamPriorityValveLeak: 29021,
+  GreenEdpWear: 29022,
+  YellowEdpWear: 29023,
+  BlueEpumpWear: 29024,
+  YellowEpumpWear: 29025,
+  PtuShaftFailure: 29026,
 
   LeftPfdDisplay: 31000,
   RightPfdDisplay: 31001,
@@ -110,6 +236,12 @@ export const A320Failure = Object.freeze({
   GearActuatorJammedGearDoorNose: 32023,
   GearActuatorJammedGearDoorLeft: 32024,
   GearActuatorJammedGearDoorRight: 32025,
+  GearActuatorInternalLeakGearNose: 32030,
+  GearActuatorInternalLeakGearLeft: 32031,
+  GearActuatorInternalLeakGearRight: 32032,
+  GearActuatorInternalLeakGearDoorNose: 32033,
+  GearActuatorInternalLeakGearDoorLeft: 32034,
+  GearActuatorInternalLeakGearDoorRight: 32035,
 
   GreenBrakeHydraulicLeak: 32100,
   YellowBrakeHydraulicLeak: 32101,
@@ -122,6 +254,58 @@ export const A320Failure = Object.freeze({
   RadioAntennaDirectCoupling1: 34020,
   RadioAntennaDirectCoupling2: 34021,
   Egpwc: 34030,
+  Mmr1: 34040,
+  Mmr2: 34041,
+  IlsLocalizerSignalLoss1: 34050,
+  IlsLocalizerSignalLoss2: 34051,
+  IlsGlideslopeSignalLoss1: 34052,
+  IlsGlideslopeSignalLoss2: 34053,
+  IlsLocalizerBeamBend1: 34054,
+  IlsLocalizerBeamBend2: 34055,
+  IlsGlideslopeBeamBend1: 34056,
+  IlsGlideslopeBeamBend2: 34057,
+  Tcas: 34060,
+  IrHeadingDrift1: 34070,
+  IrHeadingDrift2: 34071,
+  IrHeadingDrift3: 34072,
+
+  WingBleedLeak1: 36000,
+  WingBleedLeak2: 36001,
+  PylonBleedLeak1: 36010,
+  PylonBleedLeak2: 36011,
+  ApuBleedLeak: 36020,
+  WingLeakDetectionLoop1: 36100,
+  WingLeakDetectionLoop2: 36101,
+  PylonLeakDetectionLoop1: 36110,
+  PylonLeakDetectionLoop2: 36111,
+  ApuLeakDetectionLoop: 36120,
+  PressureRegulatingValveStuckOpen1: 36200,
+  PressureRegulatingValveStuckOpen2: 36201,
+  PressureRegulatingValveStuckClosed1: 36210,
+  PressureRegulatingValveStuckClosed2: 36211,
+  HighPressureValveStuckOpen1: 36220,
+  HighPressureValveStuckOpen2: 36221,
+  HighPressureValveStuckClosed1: 36230,
+  HighPressureValveStuckClosed2: 36231,
+  OverpressureValveStuckOpen1: 36240,
+  OverpressureValveStuckOpen2: 36241,
+  OverpressureValveStuckClosed1: 36250,
+  OverpressureValveStuckClosed2: 36251,
+  PrecoolerClogged1: 36300,
+  PrecoolerClogged2: 36301,
+
+  ApuNoLightOff: 49000,
+  ApuHungStart: 49001,
+  ApuOverspeed: 49002,
+  ApuEgtOvertemperature: 49003,
+  ApuOilPump: 49004,
+  ApuOilCooler: 49005,
+  ApuFuelPressureLoss: 49006,
+
+  ReverserInadvertentDeployment1: 78000,
+  ReverserInadvertentDeployment2: 78001,
+  ReverserJammed1: 78010,
+  ReverserJammed2: 78011,
 });
 
 export const A320FailureDefinitions: FailureDefinition[] = [
@@ -145,6 +329,9 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [21, A320Failure.OutflowValve, 'Outflow Valve'],
   [21, A320Failure.SafetyValve, 'Safety Valve'],
   [21, A320Failure.RapidDecompression, 'Rapid Decompression'],
+  [21, A320Failure.AvionicsBlowerFan, 'Avionics Ventilation Blower Fan'],
+  [21, A320Failure.AvionicsExtractFan, 'Avionics Ventilation Extract Fan'],
+  [21, A320Failure.AvionicsSmoke, 'Avionics Smoke'],
 
   [22, A320Failure.Fac1Failure, 'FAC 1'],
   [22, A320Failure.Fac2Failure, 'FAC 2'],
@@ -182,6 +369,111 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [27, A320Failure.Sec3Failure, 'SEC 3'],
   [27, A320Failure.Fcdc1Failure, 'FCDC 1'],
   [27, A320Failure.Fcdc2Failure, 'FCDC 2'],
+  [27, A320Failure.LeftAileronBlueServoJam, 'L AIL BLUE SERVO JAM'],
+  [27, A320Failure.LeftAileronGreenServoJam, 'L AIL GREEN SERVO JAM'],
+  [27, A320Failure.RightAileronBlueServoJam, 'R AIL BLUE SERVO JAM'],
+  [27, A320Failure.RightAileronGreenServoJam, 'R AIL GREEN SERVO JAM'],
+  [27, A320Failure.LeftElevatorBlueServoJam, 'L ELEV BLUE SERVO JAM'],
+  [27, A320Failure.LeftElevatorGreenServoJam, 'L ELEV GREEN SERVO JAM'],
+  [27, A320Failure.RightElevatorBlueServoJam, 'R ELEV BLUE SERVO JAM'],
+  [27, A320Failure.RightElevatorYellowServoJam, 'R ELEV YELLOW SERVO JAM'],
+  [27, A320Failure.RudderGreenServoJam, 'RUD GREEN SERVO JAM'],
+  [27, A320Failure.RudderBlueServoJam, 'RUD BLUE SERVO JAM'],
+  [27, A320Failure.RudderYellowServoJam, 'RUD YELLOW SERVO JAM'],
+  [27, A320Failure.LeftSpoiler1ServoJam, 'L SPLR 1 SERVO JAM'],
+  [27, A320Failure.LeftSpoiler2ServoJam, 'L SPLR 2 SERVO JAM'],
+  [27, A320Failure.LeftSpoiler3ServoJam, 'L SPLR 3 SERVO JAM'],
+  [27, A320Failure.LeftSpoiler4ServoJam, 'L SPLR 4 SERVO JAM'],
+  [27, A320Failure.LeftSpoiler5ServoJam, 'L SPLR 5 SERVO JAM'],
+  [27, A320Failure.RightSpoiler1ServoJam, 'R SPLR 1 SERVO JAM'],
+  [27, A320Failure.RightSpoiler2ServoJam, 'R SPLR 2 SERVO JAM'],
+  [27, A320Failure.RightSpoiler3ServoJam, 'R SPLR 3 SERVO JAM'],
+  [27, A320Failure.RightSpoiler4ServoJam, 'R SPLR 4 SERVO JAM'],
+  [27, A320Failure.RightSpoiler5ServoJam, 'R SPLR 5 SERVO JAM'],
+  [27, A320Failure.LeftAileronBlueServoRunaway, 'L AIL BLUE SERVO RUNAWAY'],
+  [27, A320Failure.LeftAileronGreenServoRunaway, 'L AIL GREEN SERVO RUNAWAY'],
+  [27, A320Failure.RightAileronBlueServoRunaway, 'R AIL BLUE SERVO RUNAWAY'],
+  [27, A320Failure.RightAileronGreenServoRunaway, 'R AIL GREEN SERVO RUNAWAY'],
+  [27, A320Failure.LeftElevatorBlueServoRunaway, 'L ELEV BLUE SERVO RUNAWAY'],
+  [27, A320Failure.LeftElevatorGreenServoRunaway, 'L ELEV GREEN SERVO RUNAWAY'],
+  [27, A320Failure.RightElevatorBlueServoRunaway, 'R ELEV BLUE SERVO RUNAWAY'],
+  [27, A320Failure.RightElevatorYellowServoRunaway, 'R ELEV YELLOW SERVO RUNAWAY'],
+  [27, A320Failure.RudderGreenServoRunaway, 'RUD GREEN SERVO RUNAWAY'],
+  [27, A320Failure.RudderBlueServoRunaway, 'RUD BLUE SERVO RUNAWAY'],
+  [27, A320Failure.RudderYellowServoRunaway, 'RUD YELLOW SERVO RUNAWAY'],
+  [27, A320Failure.LeftSpoiler1ServoRunaway, 'L SPLR 1 SERVO RUNAWAY'],
+  [27, A320Failure.LeftSpoiler2ServoRunaway, 'L SPLR 2 SERVO RUNAWAY'],
+  [27, A320Failure.LeftSpoiler3ServoRunaway, 'L SPLR 3 SERVO RUNAWAY'],
+  [27, A320Failure.LeftSpoiler4ServoRunaway, 'L SPLR 4 SERVO RUNAWAY'],
+  [27, A320Failure.LeftSpoiler5ServoRunaway, 'L SPLR 5 SERVO RUNAWAY'],
+  [27, A320Failure.RightSpoiler1ServoRunaway, 'R SPLR 1 SERVO RUNAWAY'],
+  [27, A320Failure.RightSpoiler2ServoRunaway, 'R SPLR 2 SERVO RUNAWAY'],
+  [27, A320Failure.RightSpoiler3ServoRunaway, 'R SPLR 3 SERVO RUNAWAY'],
+  [27, A320Failure.RightSpoiler4ServoRunaway, 'R SPLR 4 SERVO RUNAWAY'],
+  [27, A320Failure.RightSpoiler5ServoRunaway, 'R SPLR 5 SERVO RUNAWAY'],
+  [27, A320Failure.LeftAileronBlueServoFeedbackLoss, 'L AIL BLUE SERVO FEEDBACK LOSS'],
+  [27, A320Failure.LeftAileronGreenServoFeedbackLoss, 'L AIL GREEN SERVO FEEDBACK LOSS'],
+  [27, A320Failure.RightAileronBlueServoFeedbackLoss, 'R AIL BLUE SERVO FEEDBACK LOSS'],
+  [27, A320Failure.RightAileronGreenServoFeedbackLoss, 'R AIL GREEN SERVO FEEDBACK LOSS'],
+  [27, A320Failure.LeftElevatorBlueServoFeedbackLoss, 'L ELEV BLUE SERVO FEEDBACK LOSS'],
+  [27, A320Failure.LeftElevatorGreenServoFeedbackLoss, 'L ELEV GREEN SERVO FEEDBACK LOSS'],
+  [27, A320Failure.RightElevatorBlueServoFeedbackLoss, 'R ELEV BLUE SERVO FEEDBACK LOSS'],
+  [27, A320Failure.RightElevatorYellowServoFeedbackLoss, 'R ELEV YELLOW SERVO FEEDBACK LOSS'],
+  [27, A320Failure.RudderGreenServoFeedbackLoss, 'RUD GREEN SERVO FEEDBACK LOSS'],
+  [27, A320Failure.RudderBlueServoFeedbackLoss, 'RUD BLUE SERVO FEEDBACK LOSS'],
+  [27, A320Failure.RudderYellowServoFeedbackLoss, 'RUD YELLOW SERVO FEEDBACK LOSS'],
+  [27, A320Failure.LeftSpoiler1ServoFeedbackLoss, 'L SPLR 1 SERVO FEEDBACK LOSS'],
+  [27, A320Failure.LeftSpoiler2ServoFeedbackLoss, 'L SPLR 2 SERVO FEEDBACK LOSS'],
+  [27, A320Failure.LeftSpoiler3ServoFeedbackLoss, 'L SPLR 3 SERVO FEEDBACK LOSS'],
+  [27, A320Failure.LeftSpoiler4ServoFeedbackLoss, 'L SPLR 4 SERVO FEEDBACK LOSS'],
+  [27, A320Failure.LeftSpoiler5ServoFeedbackLoss, 'L SPLR 5 SERVO FEEDBACK LOSS'],
+  [27, A320Failure.RightSpoiler1ServoFeedbackLoss, 'R SPLR 1 SERVO FEEDBACK LOSS'],
+  [27, A320Failure.RightSpoiler2ServoFeedbackLoss, 'R SPLR 2 SERVO FEEDBACK LOSS'],
+  [27, A320Failure.RightSpoiler3ServoFeedbackLoss, 'R SPLR 3 SERVO FEEDBACK LOSS'],
+  [27, A320Failure.RightSpoiler4ServoFeedbackLoss, 'R SPLR 4 SERVO FEEDBACK LOSS'],
+  [27, A320Failure.RightSpoiler5ServoFeedbackLoss, 'R SPLR 5 SERVO FEEDBACK LOSS'],
+  [27, A320Failure.LeftAileronBlueServoOscillation, 'L AIL BLUE SERVO OSCILLATION'],
+  [27, A320Failure.LeftAileronGreenServoOscillation, 'L AIL GREEN SERVO OSCILLATION'],
+  [27, A320Failure.RightAileronBlueServoOscillation, 'R AIL BLUE SERVO OSCILLATION'],
+  [27, A320Failure.RightAileronGreenServoOscillation, 'R AIL GREEN SERVO OSCILLATION'],
+  [27, A320Failure.LeftElevatorBlueServoOscillation, 'L ELEV BLUE SERVO OSCILLATION'],
+  [27, A320Failure.LeftElevatorGreenServoOscillation, 'L ELEV GREEN SERVO OSCILLATION'],
+  [27, A320Failure.RightElevatorBlueServoOscillation, 'R ELEV BLUE SERVO OSCILLATION'],
+  [27, A320Failure.RightElevatorYellowServoOscillation, 'R ELEV YELLOW SERVO OSCILLATION'],
+  [27, A320Failure.RudderGreenServoOscillation, 'RUD GREEN SERVO OSCILLATION'],
+  [27, A320Failure.RudderBlueServoOscillation, 'RUD BLUE SERVO OSCILLATION'],
+  [27, A320Failure.RudderYellowServoOscillation, 'RUD YELLOW SERVO OSCILLATION'],
+  [27, A320Failure.LeftSpoiler1ServoOscillation, 'L SPLR 1 SERVO OSCILLATION'],
+  [27, A320Failure.LeftSpoiler2ServoOscillation, 'L SPLR 2 SERVO OSCILLATION'],
+  [27, A320Failure.LeftSpoiler3ServoOscillation, 'L SPLR 3 SERVO OSCILLATION'],
+  [27, A320Failure.LeftSpoiler4ServoOscillation, 'L SPLR 4 SERVO OSCILLATION'],
+  [27, A320Failure.LeftSpoiler5ServoOscillation, 'L SPLR 5 SERVO OSCILLATION'],
+  [27, A320Failure.RightSpoiler1ServoOscillation, 'R SPLR 1 SERVO OSCILLATION'],
+  [27, A320Failure.RightSpoiler2ServoOscillation, 'R SPLR 2 SERVO OSCILLATION'],
+  [27, A320Failure.RightSpoiler3ServoOscillation, 'R SPLR 3 SERVO OSCILLATION'],
+  [27, A320Failure.RightSpoiler4ServoOscillation, 'R SPLR 4 SERVO OSCILLATION'],
+  [27, A320Failure.RightSpoiler5ServoOscillation, 'R SPLR 5 SERVO OSCILLATION'],
+  [27, A320Failure.LeftAileronBlueServoInternalLeak, 'L AIL BLUE SERVO INTERNAL LEAK'],
+  [27, A320Failure.LeftAileronGreenServoInternalLeak, 'L AIL GREEN SERVO INTERNAL LEAK'],
+  [27, A320Failure.RightAileronBlueServoInternalLeak, 'R AIL BLUE SERVO INTERNAL LEAK'],
+  [27, A320Failure.RightAileronGreenServoInternalLeak, 'R AIL GREEN SERVO INTERNAL LEAK'],
+  [27, A320Failure.LeftElevatorBlueServoInternalLeak, 'L ELEV BLUE SERVO INTERNAL LEAK'],
+  [27, A320Failure.LeftElevatorGreenServoInternalLeak, 'L ELEV GREEN SERVO INTERNAL LEAK'],
+  [27, A320Failure.RightElevatorBlueServoInternalLeak, 'R ELEV BLUE SERVO INTERNAL LEAK'],
+  [27, A320Failure.RightElevatorYellowServoInternalLeak, 'R ELEV YELLOW SERVO INTERNAL LEAK'],
+  [27, A320Failure.RudderGreenServoInternalLeak, 'RUD GREEN SERVO INTERNAL LEAK'],
+  [27, A320Failure.RudderBlueServoInternalLeak, 'RUD BLUE SERVO INTERNAL LEAK'],
+  [27, A320Failure.RudderYellowServoInternalLeak, 'RUD YELLOW SERVO INTERNAL LEAK'],
+  [27, A320Failure.LeftSpoiler1ServoInternalLeak, 'L SPLR 1 SERVO INTERNAL LEAK'],
+  [27, A320Failure.LeftSpoiler2ServoInternalLeak, 'L SPLR 2 SERVO INTERNAL LEAK'],
+  [27, A320Failure.LeftSpoiler3ServoInternalLeak, 'L SPLR 3 SERVO INTERNAL LEAK'],
+  [27, A320Failure.LeftSpoiler4ServoInternalLeak, 'L SPLR 4 SERVO INTERNAL LEAK'],
+  [27, A320Failure.LeftSpoiler5ServoInternalLeak, 'L SPLR 5 SERVO INTERNAL LEAK'],
+  [27, A320Failure.RightSpoiler1ServoInternalLeak, 'R SPLR 1 SERVO INTERNAL LEAK'],
+  [27, A320Failure.RightSpoiler2ServoInternalLeak, 'R SPLR 2 SERVO INTERNAL LEAK'],
+  [27, A320Failure.RightSpoiler3ServoInternalLeak, 'R SPLR 3 SERVO INTERNAL LEAK'],
+  [27, A320Failure.RightSpoiler4ServoInternalLeak, 'R SPLR 4 SERVO INTERNAL LEAK'],
+  [27, A320Failure.RightSpoiler5ServoInternalLeak, 'R SPLR 5 SERVO INTERNAL LEAK'],
 
   [29, A320Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A320Failure.BlueReservoirLeak, 'Blue reservoir leak'],
@@ -196,6 +488,20 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [29, A320Failure.BlueEpumpOverheat, 'Blue electric pump overheat'],
   [29, A320Failure.YellowEdpOverheat, 'Yellow engine pump overheat'],
   [29, A320Failure.YellowEpumpOverheat, 'Yellow electric pump overheat'],
+  [29, A320Failure.GreenPumpSectionLeak, 'Green pump section leak'],
+  [29, A320Failure.GreenSystemUpstreamPriorityValveLeak, 'Green system leak upstream priority valve'],
+  [29, A320Failure.GreenSystemDownstreamPriorityValveLeak, 'Green system leak downstream priority valve'],
+  [29, A320Failure.BluePumpSectionLeak, 'Blue pump section leak'],
+  [29, A320Failure.BlueSystemUpstreamPriorityValveLeak, 'Blue system leak upstream priority valve'],
+  [29, A320Failure.BlueSystemDownstreamPriorityValveLeak, 'Blue system leak downstream priority valve'],
+  [29, A320Failure.YellowPumpSectionLeak, 'Yellow pump section leak'],
+  [29, A320Failure.YellowSystemUpstreamPriorityValveLeak, 'Yellow system leak upstream priority valve'],
+  [29, A320Failure.YellowSystemDownstreamPriorityValveLeak, 'Yellow system leak downstream priority valve'],
+  [29, A320Failure.GreenEdpWear, 'Green engine pump wear'],
+  [29, A320Failure.YellowEdpWear, 'Yellow engine pump wear'],
+  [29, A320Failure.BlueEpumpWear, 'Blue electric pump wear'],
+  [29, A320Failure.YellowEpumpWear, 'Yellow electric pump wear'],
+  [29, A320Failure.PtuShaftFailure, 'PTU shaft failure'],
 
   [31, A320Failure.LeftPfdDisplay, 'Captain PFD display'],
   [31, A320Failure.RightPfdDisplay, 'F/O PFD display'],
@@ -225,6 +531,12 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [32, A320Failure.GearActuatorJammedGearDoorNose, 'Nose gear door jammed actuator'],
   [32, A320Failure.GearActuatorJammedGearDoorLeft, 'Main left gear door jammed actuator'],
   [32, A320Failure.GearActuatorJammedGearDoorRight, 'Main right gear door jammed actuator'],
+  [32, A320Failure.GearActuatorInternalLeakGearNose, 'Nose gear actuator internal leak'],
+  [32, A320Failure.GearActuatorInternalLeakGearLeft, 'Main left gear actuator internal leak'],
+  [32, A320Failure.GearActuatorInternalLeakGearRight, 'Main right gear actuator internal leak'],
+  [32, A320Failure.GearActuatorInternalLeakGearDoorNose, 'Nose gear door actuator internal leak'],
+  [32, A320Failure.GearActuatorInternalLeakGearDoorLeft, 'Main left gear door actuator internal leak'],
+  [32, A320Failure.GearActuatorInternalLeakGearDoorRight, 'Main right gear door actuator internal leak'],
 
   [32, A320Failure.GreenBrakeHydraulicLeak, 'Green brakes circuit leak'],
   [32, A320Failure.YellowBrakeHydraulicLeak, 'Yellow brakes circuit leak'],
@@ -238,4 +550,109 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [34, A320Failure.RadioAntennaDirectCoupling2, 'RA 2 Direct Coupling'],
 
   [34, A320Failure.Egpwc, 'EGPWC'],
+
+  [34, A320Failure.Mmr1, 'MMR 1'],
+  [34, A320Failure.Mmr2, 'MMR 2'],
+  [34, A320Failure.IlsLocalizerSignalLoss1, 'MMR 1 ILS localizer signal loss'],
+  [34, A320Failure.IlsLocalizerSignalLoss2, 'MMR 2 ILS localizer signal loss'],
+  [34, A320Failure.IlsGlideslopeSignalLoss1, 'MMR 1 ILS glideslope signal loss'],
+  [34, A320Failure.IlsGlideslopeSignalLoss2, 'MMR 2 ILS glideslope signal loss'],
+  [34, A320Failure.IlsLocalizerBeamBend1, 'MMR 1 ILS localizer beam bend'],
+  [34, A320Failure.IlsLocalizerBeamBend2, 'MMR 2 ILS localizer beam bend'],
+  [34, A320Failure.IlsGlideslopeBeamBend1, 'MMR 1 ILS glideslope beam bend'],
+  [34, A320Failure.IlsGlideslopeBeamBend2, 'MMR 2 ILS glideslope beam bend'],
+  [34, A320Failure.Tcas, 'TCAS'],
+  [34, A320Failure.IrHeadingDrift1, 'IR 1 heading drift'],
+  [34, A320Failure.IrHeadingDrift2, 'IR 2 heading drift'],
+  [34, A320Failure.IrHeadingDrift3, 'IR 3 heading drift'],
+
+  [36, A320Failure.WingBleedLeak1, 'Wing bleed duct leak 1'],
+  [36, A320Failure.WingBleedLeak2, 'Wing bleed duct leak 2'],
+  [36, A320Failure.PylonBleedLeak1, 'Pylon bleed duct leak 1'],
+  [36, A320Failure.PylonBleedLeak2, 'Pylon bleed duct leak 2'],
+  [36, A320Failure.ApuBleedLeak, 'APU bleed duct leak'],
+  [36, A320Failure.WingLeakDetectionLoop1, 'Wing leak detection loop 1'],
+  [36, A320Failure.WingLeakDetectionLoop2, 'Wing leak detection loop 2'],
+  [36, A320Failure.PylonLeakDetectionLoop1, 'Pylon leak detection loop 1'],
+  [36, A320Failure.PylonLeakDetectionLoop2, 'Pylon leak detection loop 2'],
+  [36, A320Failure.ApuLeakDetectionLoop, 'APU leak detection loop'],
+  [36, A320Failure.PressureRegulatingValveStuckOpen1, 'Engine 1 PRV stuck open'],
+  [36, A320Failure.PressureRegulatingValveStuckOpen2, 'Engine 2 PRV stuck open'],
+  [36, A320Failure.PressureRegulatingValveStuckClosed1, 'Engine 1 PRV stuck closed'],
+  [36, A320Failure.PressureRegulatingValveStuckClosed2, 'Engine 2 PRV stuck closed'],
+  [36, A320Failure.HighPressureValveStuckOpen1, 'Engine 1 HP valve stuck open'],
+  [36, A320Failure.HighPressureValveStuckOpen2, 'Engine 2 HP valve stuck open'],
+  [36, A320Failure.HighPressureValveStuckClosed1, 'Engine 1 HP valve stuck closed'],
+  [36, A320Failure.HighPressureValveStuckClosed2, 'Engine 2 HP valve stuck closed'],
+  [36, A320Failure.OverpressureValveStuckOpen1, 'Engine 1 OPV stuck open'],
+  [36, A320Failure.OverpressureValveStuckOpen2, 'Engine 2 OPV stuck open'],
+  [36, A320Failure.OverpressureValveStuckClosed1, 'Engine 1 OPV stuck closed'],
+  [36, A320Failure.OverpressureValveStuckClosed2, 'Engine 2 OPV stuck closed'],
+  [36, A320Failure.PrecoolerClogged1, 'Engine 1 precooler clogged'],
+  [36, A320Failure.PrecoolerClogged2, 'Engine 2 precooler clogged'],
+
+  [49, A320Failure.ApuNoLightOff, 'APU No Light-Off'],
+  [49, A320Failure.ApuHungStart, 'APU Hung Start'],
+  [49, A320Failure.ApuOverspeed, 'APU Overspeed'],
+  [49, A320Failure.ApuEgtOvertemperature, 'APU EGT Overtemperature'],
+  [49, A320Failure.ApuOilPump, 'APU Oil Pump'],
+  [49, A320Failure.ApuOilCooler, 'APU Oil Cooler'],
+  [49, A320Failure.ApuFuelPressureLoss, 'APU Fuel Pressure Loss'],
+
+  [78, A320Failure.ReverserInadvertentDeployment1, 'Reverser 1 inadvertent deployment'],
+  [78, A320Failure.ReverserInadvertentDeployment2, 'Reverser 2 inadvertent deployment'],
+  [78, A320Failure.ReverserJammed1, 'Reverser 1 jammed'],
+  [78, A320Failure.ReverserJammed2, 'Reverser 2 jammed'],
+];
+
+/**
+ * Mean time between failures of the components failed by the random failure generator of the systems, in flight hours.
+ * Failures with an exposure stay dormant until the system is used.
+ */
+export const A320FailureReliabilities: FailureReliability[] = [
+  { identifier: A320Failure.Acsc1Lane1, mtbfHours: 40000 },
+  { identifier: A320Failure.Acsc2Lane1, mtbfHours: 40000 },
+  { identifier: A320Failure.CabinFan1Failure, mtbfHours: 20000 },
+  { identifier: A320Failure.CabinFan2Failure, mtbfHours: 20000 },
+  { identifier: A320Failure.Cpc1, mtbfHours: 30000 },
+  { identifier: A320Failure.Cpc2, mtbfHours: 30000 },
+  { identifier: A320Failure.AvionicsBlowerFan, mtbfHours: 25000 },
+  { identifier: A320Failure.AvionicsExtractFan, mtbfHours: 25000 },
+
+  { identifier: A320Failure.TransformerRectifier1, mtbfHours: 60000 },
+  { identifier: A320Failure.TransformerRectifier2, mtbfHours: 60000 },
+  { identifier: A320Failure.StaticInverter, mtbfHours: 50000 },
+  { identifier: A320Failure.Generator1, mtbfHours: 15000 },
+  { identifier: A320Failure.Generator2, mtbfHours: 15000 },
+  { identifier: A320Failure.ApuGenerator1, mtbfHours: 20000 },
+
+  { identifier: A320Failure.GreenEdpOverheat, mtbfHours: 30000 },
+  { identifier: A320Failure.YellowEdpOverheat, mtbfHours: 30000 },
+  { identifier: A320Failure.BlueEpumpOverheat, mtbfHours: 25000 },
+  { identifier: A320Failure.YellowEpumpOverheat, mtbfHours: 25000 },
+  { identifier: A320Failure.GreenReservoirLeak, mtbfHours: 50000 },
+  { identifier: A320Failure.BlueReservoirLeak, mtbfHours: 50000 },
+  { identifier: A320Failure.YellowReservoirLeak, mtbfHours: 50000 },
+  { identifier: A320Failure.PtuShaftFailure, mtbfHours: 60000, exposure: { flightPhase: 'ground' } },
+
+  { identifier: A320Failure.LgciuInternalError1, mtbfHours: 40000 },
+  { identifier: A320Failure.LgciuInternalError2, mtbfHours: 40000 },
+  { identifier: A320Failure.YellowBrakeAccumulatorGasLeak, mtbfHours: 40000, exposure: { flightPhase: 'ground' } },
+
+  { identifier: A320Failure.RadioAltimeter1, mtbfHours: 20000 },
+  { identifier: A320Failure.RadioAltimeter2, mtbfHours: 20000 },
+  { identifier: A320Failure.Mmr1, mtbfHours: 25000 },
+  { identifier: A320Failure.Mmr2, mtbfHours: 25000 },
+  { identifier: A320Failure.Tcas, mtbfHours: 20000 },
+
+  { identifier: A320Failure.PressureRegulatingValveStuckClosed1, mtbfHours: 30000 },
+  { identifier: A320Failure.PressureRegulatingValveStuckClosed2, mtbfHours: 30000 },
+  { identifier: A320Failure.PrecoolerClogged1, mtbfHours: 40000 },
+  { identifier: A320Failure.PrecoolerClogged2, mtbfHours: 40000 },
+
+  { identifier: A320Failure.ApuNoLightOff, mtbfHours: 15000, exposure: { flightPhase: 'ground' } },
+  { identifier: A320Failure.ApuOilPump, mtbfHours: 30000 },
+
+  { identifier: A320Failure.ReverserJammed1, mtbfHours: 50000, exposure: { flightPhase: 'ground' } },
+  { identifier: A320Failure.ReverserJammed2, mtbfHours: 50000, exposure: { flightPhase: 'ground' } },
 ];
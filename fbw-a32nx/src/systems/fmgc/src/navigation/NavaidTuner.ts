@@ -12,6 +12,7 @@ import {
   Arinc429Word,
   NotificationManager,
   IlsNavaid,
+  MagVar,
   NdbNavaid,
   VhfNavaid,
   VhfNavaidType,
@@ -246,6 +247,8 @@ export class NavaidTuner {
 
   private lastMmrCourses = [null, null];
 
+  private lastMmrNavaidIds: (string | null)[] = [null, null];
+
   private lastAdfFrequencies = [null, null];
 
   /** Increments each time the tuned navaids change */
@@ -457,6 +460,7 @@ export class NavaidTuner {
 
       this.tuneMmrIlsFrequency((i + 1) as 1 | 2, mmr.frequency);
       this.tuneMmrCourse((i + 1) as 1 | 2, mmr.course, mmr.backcourse);
+      this.publishMmrNavaid((i + 1) as 1 | 2, mmr.facility);
     }
 
     for (const [i, adf] of this.adfTuningStatus.entries()) {
@@ -553,6 +557,56 @@ export class NavaidTuner {
     return false;
   }
 
+  /**
+   * Publish the database definition of the ILS an MMR is tuned to, from which the MMR computes the ILS signals.
+   * Nothing is published when tuned by frequency only, as the MMR then has no definition of the ground station.
+   * @param index MMR index, 1 or 2
+   * @param facility the tuned ILS, or undefined if none
+   */
+  private publishMmrNavaid(index: 1 | 2, facility?: IlsNavaid): void {
+    const databaseId = facility?.databaseId ?? null;
+    if (this.lastMmrNavaidIds[index - 1] === databaseId) {
+      return;
+    }
+    this.lastMmrNavaidIds[index - 1] = databaseId;
+
+    const prefix = `L:A32NX_MMR_${index}_ILS_`;
+    if (!facility) {
+      SimVar.SetSimVarValue(`${prefix}FREQUENCY`, SimVarValueType.Number, 0);
+      return;
+    }
+
+    // up to four ISO 5 characters, seven bits each
+    const packedIdent = facility.ident
+      .substring(0, 4)
+      .split('')
+      .reduce((packed, c, i) => packed + (c.charCodeAt(0) & 0x7f) * 2 ** (7 * i), 0);
+    const magneticCourse = facility.trueReferenced
+      ? MagVar.trueToMagnetic(facility.locBearing, facility.stationDeclination)
+      : facility.locBearing;
+    const trueCourse = facility.trueReferenced
+      ? facility.locBearing
+      : MagVar.magneticToTrue(facility.locBearing, facility.stationDeclination);
+    const hasGlideslope = facility.gsLocation !== undefined && facility.gsSlope !== undefined;
+    const gsElevation = facility.gsLocation?.alt ?? facility.dmeLocation?.alt ?? 0;
+
+    SimVar.SetSimVarValue(`${prefix}IDENT`, SimVarValueType.Number, packedIdent);
+    SimVar.SetSimVarValue(`${prefix}MAGNETIC_COURSE`, SimVarValueType.Number, magneticCourse);
+    SimVar.SetSimVarValue(`${prefix}TRUE_COURSE`, SimVarValueType.Number, trueCourse);
+    SimVar.SetSimVarValue(`${prefix}LOC_LATITUDE`, SimVarValueType.Number, facility.locLocation.lat);
+    SimVar.SetSimVarValue(`${prefix}LOC_LONGITUDE`, SimVarValueType.Number, facility.locLocation.long);
+    SimVar.SetSimVarValue(`${prefix}GS_ANGLE`, SimVarValueType.Number, hasGlideslope ? Math.abs(facility.gsSlope) : 0);
+    SimVar.SetSimVarValue(`${prefix}GS_LATITUDE`, SimVarValueType.Number, facility.gsLocation?.lat ?? 0);
+    SimVar.SetSimVarValue(`${prefix}GS_LONGITUDE`, SimVarValueType.Number, facility.gsLocation?.long ?? 0);
+    SimVar.SetSimVarValue(`${prefix}GS_ELEVATION`, SimVarValueType.Number, gsElevation);
+    SimVar.SetSimVarValue(`${prefix}HAS_DME`, SimVarValueType.Bool, facility.dmeLocation !== undefined);
+    SimVar.SetSimVarValue(`${prefix}DME_LATITUDE`, SimVarValueType.Number, facility.dmeLocation?.lat ?? 0);
+    SimVar.SetSimVarValue(`${prefix}DME_LONGITUDE`, SimVarValueType.Number, facility.dmeLocation?.long ?? 0);
+    SimVar.SetSimVarValue(`${prefix}DME_ELEVATION`, SimVarValueType.Number, facility.dmeLocation?.alt ?? 0);
+    // written last, as it marks the definition as valid
+    SimVar.SetSimVarValue(`${prefix}FREQUENCY`, SimVarValueType.Number, facility.frequency * 1_000_000);
+  }
+
   /**
    * Tune the ADF to a frequency
    * @param index ADF index, 1 or 2
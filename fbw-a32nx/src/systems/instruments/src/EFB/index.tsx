@@ -5,7 +5,7 @@ import React from 'react';
 
 import { render } from '@instruments/common/index';
 import { AircraftContext, EfbWrapper, syncSettingsFromPersistentStorage } from '@flybywiresim/flypad';
-import { A320FailureDefinitions } from '@failures';
+import { A320FailureDefinitions, A320FailureReliabilities } from '@failures';
 import { A320251NLandingCalculator } from '@shared/performance/a32nx_landing';
 import { A320251NTakeoffPerformanceCalculator } from '@shared/performance/a32nx_takeoff';
 import { AutomaticCallOutsPage } from './Pages/AutomaticCallOutsPage';
@@ -77,7 +77,12 @@ render(
       hashSeed: 320,
     }}
   >
-    <EfbWrapper failures={A320FailureDefinitions} aircraftSetup={aircraftEfbSetup} eventBus={new EventBus()} />
+    <EfbWrapper
+      failures={A320FailureDefinitions}
+      failureReliabilities={A320FailureReliabilities}
+      aircraftSetup={aircraftEfbSetup}
+      eventBus={new EventBus()}
+    />
   </AircraftContext.Provider>,
   true,
   true,
@@ -7,9 +7,7 @@
 export class A32NX_FADEC {
   private fadecTimer = -1;
   private dcEssPoweredInPreviousUpdate = false;
-  private lastActiveIgniterAutostart = 0; // 0 = A, 1 = B
   private lastEngineState;
-  private igniting;
   private lastIgnitionState;
 
   constructor(private readonly engine: number) {}
@@ -22,7 +20,6 @@ export class A32NX_FADEC {
     const dcEssIsPowered = this.isDcEssPowered();
     const ignitionState = SimVar.GetSimVarValue('L:XMLVAR_ENG_MODE_SEL', 'Enum') === 2;
     const engineState = SimVar.GetSimVarValue(`L:A32NX_ENGINE_STATE:${this.engine}`, 'Number');
-    const n2Percent = SimVar.GetSimVarValue(`L:A32NX_ENGINE_N2:${this.engine}`, 'Number');
 
     if (
       (this.dcEssPoweredInPreviousUpdate !== dcEssIsPowered && dcEssIsPowered === 1) ||
@@ -30,12 +27,6 @@ export class A32NX_FADEC {
     ) {
       this.fadecTimer = 5 * 60;
     }
-    if ((this.lastEngineState === 2 || this.lastEngineState === 3) && engineState !== 2 && engineState !== 3) {
-      this.lastActiveIgniterAutostart ^= 1; // toggles Igniter
-    }
-
-    this.igniting = ignitionState && (engineState === 2 || engineState === 3) && n2Percent > 25 && n2Percent < 55;
-
     if (this.lastIgnitionState !== ignitionState && !ignitionState) {
       this.fadecTimer = Math.max(30, this.fadecTimer);
     }
@@ -48,16 +39,6 @@ export class A32NX_FADEC {
     this.lastIgnitionState = SimVar.GetSimVarValue('L:XMLVAR_ENG_MODE_SEL', 'Enum') === 2;
     this.lastEngineState = SimVar.GetSimVarValue(`L:A32NX_ENGINE_STATE:${this.engine}`, 'Number');
     SimVar.SetSimVarValue(`L:A32NX_FADEC_POWERED_ENG${this.engine}`, 'Bool', this.isPowered() ? 1 : 0);
-    SimVar.SetSimVarValue(
-      `L:A32NX_FADEC_IGNITER_A_ACTIVE_ENG${this.engine}`,
-      'Bool',
-      this.igniting && this.lastActiveIgniterAutostart === 0 ? 1 : 0,
-    );
-    SimVar.SetSimVarValue(
-      `L:A32NX_FADEC_IGNITER_B_ACTIVE_ENG${this.engine}`,
-      'Bool',
-      this.igniting && this.lastActiveIgniterAutostart === 1 ? 1 : 0,
-    );
   }
 
   isPowered() {
@@ -98,9 +98,9 @@ export class A32NX_LocalVarUpdater {
       !SimVar.GetSimVarValue('SIM ON GROUND', 'bool') ||
       SimVar.GetSimVarValue('ON ANY RUNWAY', 'bool') ||
       (SimVar.GetSimVarValue('LIGHT BEACON ON', 'bool') &&
-        SimVar.GetSimVarValue('INTERACTIVE POINT OPEN:0', 'percent') < 5 && // Pilot side front door for ramp/stairs
-        SimVar.GetSimVarValue('INTERACTIVE POINT OPEN:3', 'percent') < 5 && // Rear door, FO side for catering
-        SimVar.GetSimVarValue('L:A32NX_FWD_DOOR_CARGO_LOCKED', 'bool')) // Cargo door FO side
+        SimVar.GetSimVarValue('L:A32NX_DOOR_FWD_LEFT_IS_LOCKED', 'bool') && // Pilot side front door for ramp/stairs
+        SimVar.GetSimVarValue('L:A32NX_DOOR_AFT_RIGHT_IS_LOCKED', 'bool') && // Rear door, FO side for catering
+        SimVar.GetSimVarValue('L:A32NX_DOOR_FWD_CARGO_IS_LOCKED', 'bool')) // Cargo door FO side
     );
   }
 
@@ -8,11 +8,26 @@ import { useSimVar } from '@flybywiresim/fbw-sdk';
 import './Door.scss';
 
 export const DoorPage = () => {
-  const [cabin] = useSimVar('INTERACTIVE POINT OPEN:0', 'percent', 1000);
-  const [catering] = useSimVar('INTERACTIVE POINT OPEN:3', 'percent', 1000);
-  const [cargoLocked] = useSimVar('L:A32NX_FWD_DOOR_CARGO_LOCKED', 'bool', 1000);
+  const [fwdLeftLocked] = useSimVar('L:A32NX_DOOR_FWD_LEFT_IS_LOCKED', 'bool', 1000);
+  const [fwdRightLocked] = useSimVar('L:A32NX_DOOR_FWD_RIGHT_IS_LOCKED', 'bool', 1000);
+  const [aftLeftLocked] = useSimVar('L:A32NX_DOOR_AFT_LEFT_IS_LOCKED', 'bool', 1000);
+  const [aftRightLocked] = useSimVar('L:A32NX_DOOR_AFT_RIGHT_IS_LOCKED', 'bool', 1000);
+  const [cargoLocked] = useSimVar('L:A32NX_DOOR_FWD_CARGO_IS_LOCKED', 'bool', 1000);
+  const [aftCargoLocked] = useSimVar('L:A32NX_DOOR_AFT_CARGO_IS_LOCKED', 'bool', 1000);
+  const [emerLeft1Locked] = useSimVar('L:A32NX_DOOR_EMER_LEFT_1_IS_LOCKED', 'bool', 1000);
+  const [emerRight1Locked] = useSimVar('L:A32NX_DOOR_EMER_RIGHT_1_IS_LOCKED', 'bool', 1000);
+  const [emerLeft2Locked] = useSimVar('L:A32NX_DOOR_EMER_LEFT_2_IS_LOCKED', 'bool', 1000);
+  const [emerRight2Locked] = useSimVar('L:A32NX_DOOR_EMER_RIGHT_2_IS_LOCKED', 'bool', 1000);
   const [oxygen] = useSimVar('L:PUSH_OVHD_OXYGEN_CREW', 'bool', 1000);
-  const [slides] = useSimVar('L:A32NX_SLIDES_ARMED', 'bool', 1000);
+  const [fwdLeftSlide] = useSimVar('L:A32NX_DOOR_FWD_LEFT_SLIDE_ARMED', 'bool', 1000);
+  const [fwdRightSlide] = useSimVar('L:A32NX_DOOR_FWD_RIGHT_SLIDE_ARMED', 'bool', 1000);
+  const [aftLeftSlide] = useSimVar('L:A32NX_DOOR_AFT_LEFT_SLIDE_ARMED', 'bool', 1000);
+  const [aftRightSlide] = useSimVar('L:A32NX_DOOR_AFT_RIGHT_SLIDE_ARMED', 'bool', 1000);
+  const [emerLeftSlide] = useSimVar('L:A32NX_DOOR_EMER_LEFT_1_SLIDE_ARMED', 'bool', 1000);
+  const [emerRightSlide] = useSimVar('L:A32NX_DOOR_EMER_RIGHT_1_SLIDE_ARMED', 'bool', 1000);
+
+  const emerLeftLocked = emerLeft1Locked && emerLeft2Locked;
+  const emerRightLocked = emerRight1Locked && emerRight2Locked;
 
   return (
     <>
@@ -36,26 +51,38 @@ export const DoorPage = () => {
             className={cargoLocked ? 'DoorShape' : 'WarningShape'}
             d="M336 221 l0 -20 l-18 0 l0 20Z"
           />
-          <path className="DoorShape" d="M336 384 l0 -20 l-18 0 l0 20Z" />
+          <path
+            id="DoorAftCargo"
+            className={aftCargoLocked ? 'DoorShape' : 'WarningShape'}
+            d="M336 384 l0 -20 l-18 0 l0 20Z"
+          />
           <path className="DoorShape" d="M328 414 l0 -22 l-8 0 l0 22Z" />
         </g>
 
         <g id="slides">
           <path
             id="DoorFrontLeft"
-            className={cabin > 20 ? 'WarningShape' : 'DoorShape'}
+            className={fwdLeftLocked ? 'DoorShape' : 'WarningShape'}
             d="M264 145 l0 -20 l12 0 l0 20Z"
           />
-          <path className="DoorShape" d="M336 145 l0 -20 l-12 0 l0 20Z" />
-          <path className="DoorShape" d="M264 310 l0 -20 l12 0 l0 20Z" />
-          <path className="DoorShape" d="M336 310 l0 -20 l-12 0 l0 20Z" />
-          <path className="DoorShape" d="M264 344 l0 -20 l12 0 l0 20Z" />
-          <path className="DoorShape" d="M336 344 l0 -20 l-12 0 l0 20Z" />
+          <path
+            id="DoorFrontRight"
+            className={fwdRightLocked ? 'DoorShape' : 'WarningShape'}
+            d="M336 145 l0 -20 l-12 0 l0 20Z"
+          />
+          <path id="DoorEmerLeft1" className={emerLeft1Locked ? 'DoorShape' : 'WarningShape'} d="M264 310 l0 -20 l12 0 l0 20Z" />
+          <path id="DoorEmerRight1" className={emerRight1Locked ? 'DoorShape' : 'WarningShape'} d="M336 310 l0 -20 l-12 0 l0 20Z" />
+          <path id="DoorEmerLeft2" className={emerLeft2Locked ? 'DoorShape' : 'WarningShape'} d="M264 344 l0 -20 l12 0 l0 20Z" />
+          <path id="DoorEmerRight2" className={emerRight2Locked ? 'DoorShape' : 'WarningShape'} d="M336 344 l0 -20 l-12 0 l0 20Z" />
 
-          <path className="DoorShape" d="M264 445 l0 -20 l12 0 l0 20Z" />
+          <path
+            id="DoorBackLeft"
+            className={aftLeftLocked ? 'DoorShape' : 'WarningShape'}
+            d="M264 445 l0 -20 l12 0 l0 20Z"
+          />
           <path
             id="DoorBackRight"
-            className={catering > 20 ? 'WarningShape' : 'DoorShape'}
+            className={aftRightLocked ? 'DoorShape' : 'WarningShape'}
             d="M336 445 l0 -20 l-12 0 l0 20Z"
           />
         </g>
@@ -63,22 +90,52 @@ export const DoorPage = () => {
         <g id="dashes">
           <path
             id="cabin1dash"
-            className={cabin > 20 ? 'WarningShape' : 'Hide'}
+            className={fwdLeftLocked ? 'Hide' : 'WarningShape'}
             strokeDasharray="7,4"
             d="M138, 136 l121 0"
           />
+          <path
+            id="cabin2dash"
+            className={fwdRightLocked ? 'Hide' : 'WarningShape'}
+            strokeDasharray="7,4"
+            d="M346, 136 l77 0"
+          />
+          <path
+            id="cabin3dash"
+            className={aftLeftLocked ? 'Hide' : 'WarningShape'}
+            strokeDasharray="7,4"
+            d="M138, 438 l121 0"
+          />
           <path
             id="cabin4dash"
-            className={catering > 20 ? 'WarningShape' : 'Hide'}
+            className={aftRightLocked ? 'Hide' : 'WarningShape'}
             strokeDasharray="7,4"
             d="M346, 438 l77 0"
           />
+          <path
+            id="emer1dash"
+            className={emerLeftLocked ? 'Hide' : 'WarningShape'}
+            strokeDasharray="7,4"
+            d="M150, 317 l109 0"
+          />
+          <path
+            id="emer2dash"
+            className={emerRightLocked ? 'Hide' : 'WarningShape'}
+            strokeDasharray="7,4"
+            d="M341, 317 l109 0"
+          />
           <path
             id="cargo1dash"
             className={cargoLocked ? 'Hide' : 'WarningShape'}
             strokeDasharray="7,4"
             d="M346, 210 l77 0"
           />
+          <path
+            id="cargo2dash"
+            className={aftCargoLocked ? 'Hide' : 'WarningShape'}
+            strokeDasharray="7,4"
+            d="M346, 374 l77 0"
+          />
         </g>
 
         {/* Texts */}
@@ -96,7 +153,7 @@ export const DoorPage = () => {
           </text>
           <text
             id="slide1"
-            className={slides ? 'Slide' : 'Hide'}
+            className={fwdLeftSlide ? 'Slide' : 'Hide'}
             x="232"
             y="136"
             textAnchor="middle"
@@ -106,7 +163,7 @@ export const DoorPage = () => {
           </text>
           <text
             id="slide2"
-            className={slides ? 'Slide' : 'Hide'}
+            className={fwdRightSlide ? 'Slide' : 'Hide'}
             x="368"
             y="136"
             textAnchor="middle"
@@ -114,15 +171,29 @@ export const DoorPage = () => {
           >
             SLIDE
           </text>
-          <text id="slide3" className="Slide" x="232" y="320" textAnchor="middle" alignmentBaseline="central">
+          <text
+            id="slide3"
+            className={emerLeftSlide ? 'Slide' : 'Hide'}
+            x="232"
+            y="320"
+            textAnchor="middle"
+            alignmentBaseline="central"
+          >
             SLIDE
           </text>
-          <text id="slide4" className="Slide" x="368" y="320" textAnchor="middle" alignmentBaseline="central">
+          <text
+            id="slide4"
+            className={emerRightSlide ? 'Slide' : 'Hide'}
+            x="368"
+            y="320"
+            textAnchor="middle"
+            alignmentBaseline="central"
+          >
             SLIDE
           </text>
           <text
             id="slide5"
-            className={slides ? 'Slide' : 'Hide'}
+            className={aftLeftSlide ? 'Slide' : 'Hide'}
             x="232"
             y="438"
             textAnchor="middle"
@@ -132,7 +203,7 @@ export const DoorPage = () => {
           </text>
           <text
             id="slide6"
-            className={slides ? 'Slide' : 'Hide'}
+            className={aftRightSlide ? 'Slide' : 'Hide'}
             x="368"
             y="438"
             textAnchor="middle"
@@ -143,7 +214,7 @@ export const DoorPage = () => {
 
           <text
             id="cabin1"
-            className={cabin > 20 ? 'Warning' : 'Hide'}
+            className={fwdLeftLocked ? 'Hide' : 'Warning'}
             x="103"
             y="136"
             textAnchor="middle"
@@ -151,9 +222,29 @@ export const DoorPage = () => {
           >
             CABIN
           </text>
+          <text
+            id="cabin2"
+            className={fwdRightLocked ? 'Hide' : 'Warning'}
+            x="455"
+            y="136"
+            textAnchor="middle"
+            alignmentBaseline="central"
+          >
+            CABIN
+          </text>
+          <text
+            id="cabin3"
+            className={aftLeftLocked ? 'Hide' : 'Warning'}
+            x="103"
+            y="438"
+            textAnchor="middle"
+            alignmentBaseline="central"
+          >
+            CABIN
+          </text>
           <text
             id="cabin4"
-            className={catering > 20 ? 'Warning' : 'Hide'}
+            className={aftRightLocked ? 'Hide' : 'Warning'}
             x="455"
             y="438"
             textAnchor="middle"
@@ -161,6 +252,26 @@ export const DoorPage = () => {
           >
             CABIN
           </text>
+          <text
+            id="emer1"
+            className={emerLeftLocked ? 'Hide' : 'Warning'}
+            x="103"
+            y="317"
+            textAnchor="middle"
+            alignmentBaseline="central"
+          >
+            EMER EXIT
+          </text>
+          <text
+            id="emer2"
+            className={emerRightLocked ? 'Hide' : 'Warning'}
+            x="497"
+            y="317"
+            textAnchor="middle"
+            alignmentBaseline="central"
+          >
+            EMER EXIT
+          </text>
           <text
             id="cargo1"
             className={cargoLocked ? 'Hide' : 'Warning'}
@@ -171,6 +282,16 @@ export const DoorPage = () => {
           >
             CARGO
           </text>
+          <text
+            id="cargo2"
+            className={aftCargoLocked ? 'Hide' : 'Warning'}
+            x="455"
+            y="374"
+            textAnchor="middle"
+            alignmentBaseline="central"
+          >
+            CARGO
+          </text>
 
           <text
             id="oxy"
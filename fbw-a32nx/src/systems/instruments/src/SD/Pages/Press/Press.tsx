@@ -615,7 +615,7 @@ type OverboardInletComponentType = {
 };
 
 const OverboardInletComponent: FC<OverboardInletComponentType> = ({ validSDAC, flightPhase }) => {
-  const [realInletValvePosition] = useSimVar('L:VENT_INLET_VALVE', 'percent', 500);
+  const [realInletValvePosition] = useSimVar('L:A32NX_VENT_INLET_VALVE', 'percent', 500);
   let indicator = true;
   let classNameValue = 'GreenLine';
   let classNameText = 'White';
@@ -676,7 +676,7 @@ type OverboardOutletComponentType = {
 };
 
 const OverboardOutletComponent: FC<OverboardOutletComponentType> = ({ validSDAC, flightPhase }) => {
-  const [realOutletValvePosition] = useSimVar('L:VENT_OUTLET_VALVE', 'percent', 500);
+  const [realOutletValvePosition] = useSimVar('L:A32NX_VENT_OUTLET_VALVE', 'percent', 500);
   let indicator = true;
   let classNameValue = 'GreenLine';
   let classNameText = 'White';
@@ -45,6 +45,7 @@ const EwdGroups: Record<string, string> = {
   'FUEL$3': '\x1b<4m\x1b4mFUEL\x1bm',
   'AIR$4': '\x1b<4m\x1b4mAIR\x1bm',
   'COND$2': '\x1b<4m\x1b4mCOND\x1bm',
+  'VENT$2': '\x1b<4m\x1b4mVENT\x1bm',
   'CAB PR$3': '\x1b<4m\x1b4mCAB PR\x1bm',
   'SEVERE ICE$1': '\x1b<4m\x1b4mSEVERE ICE\x1bm',
   'ANTI ICE$2': '\x1b<4m\x1b4mANTI ICE\x1bm',
@@ -174,6 +175,11 @@ const EwdMessages = new Map<string, { group?: string; text: string }>([
   ['260015007', { text: '\x1b<7m BEFORE OPEN CRG DOORS:' }],
   ['260015008', { text: '\x1b<7m .BEFORE OPEN CRG DOORS:' }],
   ['260015009', { text: '\x1b<5m -PAX..........DISEMBARK' }],
+  ['260016001', { group: 'SMOKE$2', text: ' AVIONICS SMOKE' }],
+  ['260016002', { text: '\x1b<5m -BLOWER............OVRD' }],
+  ['260016003', { text: '\x1b<5m -EXTRACT...........OVRD' }],
+  ['260016004', { text: '\x1b<5m -CAB FANS...........OFF' }],
+  ['260016005', { text: '\x1b<5m -GEN 1 LINE.........OFF' }],
   ['213123501', { group: 'CAB PR$2', text: ' EXCES RESIDUAL PR' }],
   ['213123502', { text: '\x1b<5m -PACK 1.............OFF' }],
   ['213123503', { text: '\x1b<5m -PACK 2.............OFF' }],
@@ -353,6 +359,22 @@ const EwdMessages = new Map<string, { group?: string; text: string }>([
   ['216120606', { text: '\x1b<7m   AND FL BELOW 100:' }],
   ['216120607', { text: '\x1b<5m -RAM AIR.............ON' }],
   ['216120608', { text: '\x1b<5m MAX FL.....100/MEA-MORA' }],
+  ['361101001', { group: 'AIR$4', text: ' ENG 1 BLEED LEAK' }],
+  ['361101002', { text: '\x1b<5m -ENG 1 BLEED........OFF' }],
+  ['361101003', { text: '\x1b<5m -X BLEED...........SHUT' }],
+  ['361101004', { text: '\x1b<5m -APU BLEED..........OFF' }],
+  ['361102001', { group: 'AIR$4', text: ' ENG 2 BLEED LEAK' }],
+  ['361102002', { text: '\x1b<5m -ENG 2 BLEED........OFF' }],
+  ['361102003', { text: '\x1b<5m -X BLEED...........SHUT' }],
+  ['361102004', { text: '\x1b<5m -APU BLEED..........OFF' }],
+  ['361103001', { group: 'AIR$4', text: ' APU BLEED LEAK' }],
+  ['361103002', { text: '\x1b<5m -APU BLEED..........OFF' }],
+  ['361104001', { group: 'AIR$4', text: ' ENG 1 BLEED NOT CLSD' }],
+  ['361104002', { text: '\x1b<5m -ENG 1 BLEED........OFF' }],
+  ['361104003', { text: '\x1b<5m -X BLEED...........SHUT' }],
+  ['361105001', { group: 'AIR$4', text: ' ENG 2 BLEED NOT CLSD' }],
+  ['361105002', { text: '\x1b<5m -ENG 2 BLEED........OFF' }],
+  ['361105003', { text: '\x1b<5m -X BLEED...........SHUT' }],
   ['216120201', { group: 'AIR$4', text: ' PACK 1 FAULT' }],
   ['216120202', { text: '\x1b<5m -PACK 1.............OFF' }],
   ['216120301', { group: 'AIR$4', text: ' PACK 2 FAULT' }],
@@ -378,6 +400,10 @@ const EwdMessages = new Map<string, { group?: string; text: string }>([
   ['216329005', { text: '\x1b<5m -PACK 2.............OFF' }],
   ['216321801', { group: 'COND$2', text: ' L+R CAB FAN FAULT' }],
   ['216321802', { text: '\x1b<5m -PACK FLOW...........HI' }],
+  ['212601001', { group: 'VENT$2', text: ' BLOWER FAULT' }],
+  ['212601002', { text: '\x1b<5m -BLOWER............OVRD' }],
+  ['212602001', { group: 'VENT$2', text: ' EXTRACT FAULT' }],
+  ['212602002', { text: '\x1b<5m -EXTRACT...........OVRD' }],
   ['213122401', { group: 'CAB PR$3', text: ' SYS 1+2 FAULT' }],
   ['213122402', { text: '\x1b<5m -MODE SEL...........MAN' }],
   ['213122403', { text: '\x1b<5m -MAN V/S CTL....AS RQRD' }],
@@ -325,6 +325,24 @@ export class PseudoFWC {
 
   private readonly pack1And2Fault = Subject.create(false);
 
+  private readonly eng1BleedLeak = Subject.create(false);
+
+  private readonly eng2BleedLeak = Subject.create(false);
+
+  private readonly apuBleedLeak = Subject.create(false);
+
+  private readonly eng1BleedNotClosed = Subject.create(false);
+
+  private readonly eng2BleedNotClosed = Subject.create(false);
+
+  private readonly eng1BleedPbAuto = Subject.create(false);
+
+  private readonly eng2BleedPbAuto = Subject.create(false);
+
+  private readonly apuBleedPbOn = Subject.create(false);
+
+  private readonly crossbleedClosed = Subject.create(false);
+
   private readonly ramAirOn = Subject.create(false);
 
   private readonly hotAirDisagrees = Subject.create(false);
@@ -353,6 +371,12 @@ export class PseudoFWC {
 
   private readonly lavGalleyFanFault = Subject.create(false);
 
+  private readonly ventBlowerFault = Subject.create(false);
+
+  private readonly ventExtractFault = Subject.create(false);
+
+  private readonly avionicsSmoke = Subject.create(false);
+
   private readonly pack1On = Subject.create(false);
 
   private readonly pack2On = Subject.create(false);
@@ -2681,11 +2705,28 @@ export class PseudoFWC {
       this.acsc1DiscreteWord1.bitValueOr(24, false) || this.acsc2DiscreteWord1.bitValueOr(24, false),
     );
 
+    this.avionicsSmoke.set(SimVar.GetSimVarValue('L:A32NX_VENT_AVIONICS_SMOKE_DETECTED', 'bool'));
+    this.ventBlowerFault.set(
+      SimVar.GetSimVarValue('L:A32NX_VENTILATION_BLOWER_FAULT', 'bool') && !this.avionicsSmoke.get(),
+    );
+    this.ventExtractFault.set(
+      SimVar.GetSimVarValue('L:A32NX_VENTILATION_EXTRACT_FAULT', 'bool') && !this.avionicsSmoke.get(),
+    );
+
     const crossbleedFullyClosed = SimVar.GetSimVarValue('L:A32NX_PNEU_XBLEED_VALVE_FULLY_CLOSED', 'bool');
     const eng1Bleed = SimVar.GetSimVarValue('L:A32NX_OVHD_PNEU_ENG_1_BLEED_PB_IS_AUTO', 'bool');
     const eng1BleedPbFault = SimVar.GetSimVarValue('L:A32NX_OVHD_PNEU_ENG_1_BLEED_PB_HAS_FAULT', 'bool');
     const eng2Bleed = SimVar.GetSimVarValue('L:A32NX_OVHD_PNEU_ENG_1_BLEED_PB_IS_AUTO', 'bool');
     const eng2BleedPbFault = SimVar.GetSimVarValue('L:A32NX_OVHD_PNEU_ENG_2_BLEED_PB_HAS_FAULT', 'bool');
+    this.eng1BleedPbAuto.set(eng1Bleed);
+    this.eng2BleedPbAuto.set(SimVar.GetSimVarValue('L:A32NX_OVHD_PNEU_ENG_2_BLEED_PB_IS_AUTO', 'bool'));
+    this.apuBleedPbOn.set(SimVar.GetSimVarValue('L:A32NX_OVHD_APU_BLEED_PB_IS_ON', 'bool'));
+    this.crossbleedClosed.set(crossbleedFullyClosed);
+    this.eng1BleedLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_1_BLEED_LEAK', 'bool'));
+    this.eng2BleedLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_2_BLEED_LEAK', 'bool'));
+    this.apuBleedLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_APU_BLEED_LEAK', 'bool'));
+    this.eng1BleedNotClosed.set(SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_1_BLEED_NOT_CLOSED', 'bool'));
+    this.eng2BleedNotClosed.set(SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_2_BLEED_NOT_CLOSED', 'bool'));
     const pack1Fault = SimVar.GetSimVarValue('L:A32NX_OVHD_COND_PACK_1_PB_HAS_FAULT', 'bool');
     const pack2Fault = SimVar.GetSimVarValue('L:A32NX_OVHD_COND_PACK_2_PB_HAS_FAULT', 'bool');
     this.pack1On.set(SimVar.GetSimVarValue('L:A32NX_OVHD_COND_PACK_1_PB_IS_ON', 'bool'));
@@ -3793,13 +3834,14 @@ export class PseudoFWC {
       this.eng1pumpPBisAuto.get() &&
       this.eng2pumpPBisAuto.get();
 
-    const cabin = SimVar.GetSimVarValue('INTERACTIVE POINT OPEN:0', 'percent');
-    const catering = SimVar.GetSimVarValue('INTERACTIVE POINT OPEN:3', 'percent');
-    const cargofwdLocked = SimVar.GetSimVarValue('L:A32NX_FWD_DOOR_CARGO_LOCKED', 'bool');
-    const cargoaftLocked = SimVar.GetSimVarValue('L:A32NX_AFT_DOOR_CARGO_LOCKED', 'bool');
+    const cabinDoorsLocked = ['FWD_LEFT', 'FWD_RIGHT', 'AFT_LEFT', 'AFT_RIGHT'].every((door) =>
+      SimVar.GetSimVarValue(`L:A32NX_DOOR_${door}_IS_LOCKED`, 'bool'),
+    );
+    const cargofwdLocked = SimVar.GetSimVarValue('L:A32NX_DOOR_FWD_CARGO_IS_LOCKED', 'bool');
+    const cargoaftLocked = SimVar.GetSimVarValue('L:A32NX_DOOR_AFT_CARGO_IS_LOCKED', 'bool');
 
     const speeds = !toSpeedsTooLow && !toV2VRV2Disagree && !fmToSpeedsNotInserted;
-    const doors = !!(cabin === 0 && catering === 0 && cargoaftLocked && cargofwdLocked);
+    const doors = !!(cabinDoorsLocked && cargoaftLocked && cargofwdLocked);
     const surfacesNotTo =
       flapsNotInToPos ||
       slatsNotInToPos ||
@@ -5426,6 +5468,71 @@ export class PseudoFWC {
       sysPage: EcamSysPage.BLEED,
       side: 'LEFT',
     },
+    3611010: {
+      // ENG 1 BLEED LEAK
+      flightPhaseInhib: [4, 5, 7, 8],
+      simVarIsActive: this.eng1BleedLeak,
+      whichCodeToReturn: () => [
+        0,
+        this.eng1BleedPbAuto.get() ? 1 : null,
+        !this.crossbleedClosed.get() ? 2 : null,
+        this.apuBleedPbOn.get() ? 3 : null,
+      ],
+      codesToReturn: ['361101001', '361101002', '361101003', '361101004'],
+      memoInhibit: () => false,
+      failure: 2,
+      sysPage: EcamSysPage.BLEED,
+      side: 'LEFT',
+    },
+    3611020: {
+      // ENG 2 BLEED LEAK
+      flightPhaseInhib: [4, 5, 7, 8],
+      simVarIsActive: this.eng2BleedLeak,
+      whichCodeToReturn: () => [
+        0,
+        this.eng2BleedPbAuto.get() ? 1 : null,
+        !this.crossbleedClosed.get() ? 2 : null,
+        this.apuBleedPbOn.get() ? 3 : null,
+      ],
+      codesToReturn: ['361102001', '361102002', '361102003', '361102004'],
+      memoInhibit: () => false,
+      failure: 2,
+      sysPage: EcamSysPage.BLEED,
+      side: 'LEFT',
+    },
+    3611030: {
+      // APU BLEED LEAK
+      flightPhaseInhib: [4, 5, 7, 8],
+      simVarIsActive: this.apuBleedLeak,
+      whichCodeToReturn: () => [0, this.apuBleedPbOn.get() ? 1 : null],
+      codesToReturn: ['361103001', '361103002'],
+      memoInhibit: () => false,
+      failure: 2,
+      sysPage: EcamSysPage.BLEED,
+      side: 'LEFT',
+    },
+    3611040: {
+      // ENG 1 BLEED NOT CLSD
+      flightPhaseInhib: [4, 5, 7, 8],
+      simVarIsActive: this.eng1BleedNotClosed,
+      whichCodeToReturn: () => [0, this.eng1BleedPbAuto.get() ? 1 : null, !this.crossbleedClosed.get() ? 2 : null],
+      codesToReturn: ['361104001', '361104002', '361104003'],
+      memoInhibit: () => false,
+      failure: 2,
+      sysPage: EcamSysPage.BLEED,
+      side: 'LEFT',
+    },
+    3611050: {
+      // ENG 2 BLEED NOT CLSD
+      flightPhaseInhib: [4, 5, 7, 8],
+      simVarIsActive: this.eng2BleedNotClosed,
+      whichCodeToReturn: () => [0, this.eng2BleedPbAuto.get() ? 1 : null, !this.crossbleedClosed.get() ? 2 : null],
+      codesToReturn: ['361105001', '361105002', '361105003'],
+      memoInhibit: () => false,
+      failure: 2,
+      sysPage: EcamSysPage.BLEED,
+      side: 'LEFT',
+    },
     2161202: {
       // PACK 1 FAULT
       flightPhaseInhib: [3, 4, 5, 7, 8],
@@ -5606,6 +5713,34 @@ export class PseudoFWC {
       sysPage: EcamSysPage.COND,
       side: 'LEFT',
     },
+    2126010: {
+      // VENT BLOWER FAULT
+      flightPhaseInhib: [3, 4, 5, 7, 8],
+      simVarIsActive: this.ventBlowerFault,
+      whichCodeToReturn: () => [
+        0,
+        SimVar.GetSimVarValue('L:A32NX_VENTILATION_BLOWER_TOGGLE', 'bool') === 1 ? 1 : null,
+      ],
+      codesToReturn: ['212601001', '212601002'],
+      memoInhibit: () => false,
+      failure: 2,
+      sysPage: EcamSysPage.NONE,
+      side: 'LEFT',
+    },
+    2126020: {
+      // VENT EXTRACT FAULT
+      flightPhaseInhib: [3, 4, 5, 7, 8],
+      simVarIsActive: this.ventExtractFault,
+      whichCodeToReturn: () => [
+        0,
+        SimVar.GetSimVarValue('L:A32NX_VENTILATION_EXTRACT_TOGGLE', 'bool') === 1 ? 1 : null,
+      ],
+      codesToReturn: ['212602001', '212602002'],
+      memoInhibit: () => false,
+      failure: 2,
+      sysPage: EcamSysPage.NONE,
+      side: 'LEFT',
+    },
     2163305: {
       // TRIM AIR SYS FAULT
       flightPhaseInhib: [3, 4, 5, 7, 8],
@@ -5654,6 +5789,23 @@ export class PseudoFWC {
       sysPage: EcamSysPage.NONE,
       side: 'LEFT',
     },
+    2600160: {
+      // SMOKE AVIONICS SMOKE
+      flightPhaseInhib: [4, 5, 7, 8],
+      simVarIsActive: this.avionicsSmoke,
+      whichCodeToReturn: () => [
+        0,
+        SimVar.GetSimVarValue('L:A32NX_VENTILATION_BLOWER_TOGGLE', 'bool') === 1 ? 1 : null,
+        SimVar.GetSimVarValue('L:A32NX_VENTILATION_EXTRACT_TOGGLE', 'bool') === 1 ? 2 : null,
+        SimVar.GetSimVarValue('L:A32NX_OVHD_VENT_CAB_FANS_PB_IS_ON', 'bool') === 1 ? 3 : null,
+        SimVar.GetSimVarValue('L:A32NX_OVHD_EMER_ELEC_GEN_1_LINE_PB_IS_ON', 'bool') === 1 ? 4 : null,
+      ],
+      codesToReturn: ['260016001', '260016002', '260016003', '260016004', '260016005'],
+      memoInhibit: () => false,
+      failure: 3,
+      sysPage: EcamSysPage.NONE,
+      side: 'LEFT',
+    },
     7700647: {
       // THR LEVERS NOT SET  (on ground)
       flightPhaseInhib: [1, 4, 5, 6, 7, 8, 10],
@@ -81,14 +81,23 @@ void EngineControl_A32NX::update() {
     const bool engineMasterTurnedOn  = (prevEngineMasterPos[engineIdx] < 1 && engineFuelValveFullyOpen);
     const bool engineMasterTurnedOff = (prevEngineMasterPos[engineIdx] > 0 && engineFuelValveFullyClosed);
 
-    // starts engines if Engine Master is turned on and Starter is pressurized
+    // the start control of the systems keeps dry cranking the engine after a start fault and only aborts the start once
+    // the dry crank has completed
+    const bool starterValveCommanded = simData.fadecStarterValveCommanded[engineIdx]->getAsBool();
+    const bool startAborted          = simData.fadecStartPhase[engineIdx]->getAsInt64() == START_PHASE_ABORTED;
+
+    // starts engines if Engine Master is turned on or the start control cranks the engine and Starter is pressurized
     // or the engine is still spinning fast enough
-    if (!engineStarter && engineFuelValveFullyOpen && (engineStarterPressurized || simN2 >= 20)) {
+    if (!engineStarter && !startAborted && (engineFuelValveFullyOpen || starterValveCommanded) &&
+        (engineStarterPressurized || simN2 >= 20)) {
       simData.setStarterHeldEvent[engineIdx]->trigger(1);
       engineStarter = true;
     }
-    // shuts off engines if Engine Master is turned off or starter is depressurized while N2 is below 20%
-    else if (engineStarter && (engineFuelValveFullyClosed || (engineFuelValveFullyOpen && !engineStarterPressurized && simN2 < 20))) {
+    // shuts off engines if the start is aborted or, while the start control does not crank the engine,
+    // if Engine Master is turned off or starter is depressurized while N2 is below 20%
+    else if (engineStarter &&
+             (startAborted || (!starterValveCommanded && (engineFuelValveFullyClosed ||
+                                                          (engineFuelValveFullyOpen && !engineStarterPressurized && simN2 < 20))))) {
       simData.setStarterHeldEvent[engineIdx]->trigger(0);
       simData.setStarterEvent[engineIdx]->trigger(0);
       engineStarter = false;
@@ -103,6 +112,7 @@ void EngineControl_A32NX::update() {
                                                  engineStarterTurnedOff,  //
                                                  engineMasterTurnedOn,    //
                                                  engineMasterTurnedOff,   //
+                                                 startAborted,            //
                                                  simN2,                   //
                                                  idleN2,                  //
                                                  ambientTemperature);     //
@@ -382,6 +392,7 @@ EngineControl_A32NX::EngineState EngineControl_A32NX::engineStateMachine(int
                                                                          bool   engineStarterTurnedOff,  //
                                                                          bool   engineMasterTurnedOn,    //
                                                                          bool   engineMasterTurnedOff,   //
+                                                                         bool   startAborted,            //
                                                                          double simN2,                   //
                                                                          double idleN2,                  //
                                                                          double ambientTemperature) {    //
@@ -395,6 +406,9 @@ EngineControl_A32NX::EngineState EngineControl_A32NX::engineStateMachine(int
 
   EngineState engineState = static_cast<EngineState>(simData.engineState[engineIdx]->get());
 
+  // a dry cranked engine does not run up to idle as the start control does not command the fuel on
+  const bool fuelOnCommanded = simData.fadecFuelOnCommanded[engineIdx]->getAsBool();
+
   // Current State: OFF
   if (engineState == OFF) {
     if (engineIgniter == 1 && engineStarter && simN2 > 20) {
@@ -415,10 +429,10 @@ EngineControl_A32NX::EngineState EngineControl_A32NX::engineStateMachine(int
   }
   // Current State: Starting.
   else if (engineState == STARTING) {
-    if (engineStarter && simN2 >= (idleN2 - 0.1)) {
+    if (engineStarter && fuelOnCommanded && simN2 >= (idleN2 - 0.1)) {
       engineState = ON;
       resetTimer  = true;
-    } else if (engineStarterTurnedOff || engineMasterTurnedOff) {
+    } else if (engineStarterTurnedOff || engineMasterTurnedOff || startAborted) {
       engineState = SHUTTING;
       resetTimer  = true;
     } else {
@@ -427,10 +441,10 @@ EngineControl_A32NX::EngineState EngineControl_A32NX::engineStateMachine(int
   }
   // Current State: Re-Starting.
   else if (engineState == RESTARTING) {
-    if (engineStarter && simN2 >= (idleN2 - 0.1)) {
+    if (engineStarter && fuelOnCommanded && simN2 >= (idleN2 - 0.1)) {
       engineState = ON;
       resetTimer  = true;
-    } else if (engineStarterTurnedOff || engineMasterTurnedOff) {
+    } else if (engineStarterTurnedOff || engineMasterTurnedOff || startAborted) {
       engineState = SHUTTING;
       resetTimer  = true;
     } else {
@@ -445,7 +459,7 @@ EngineControl_A32NX::EngineState EngineControl_A32NX::engineStateMachine(int
     } else if (!engineStarter && simN2 < 0.05 && simData.engineEgt[engineIdx]->get() <= ambientTemperature) {
       engineState = OFF;
       resetTimer  = true;
-    } else if (engineStarter && simN2 > 50) {
+    } else if (engineStarter && !startAborted && simN2 > 50) {
       engineState = RESTARTING;
       resetTimer  = true;
     } else {
@@ -528,9 +542,13 @@ void EngineControl_A32NX::engineStartProcedure(int                     engine,
 
   simData.engineN2[engineIdx]->set(newN2Fbw);
   simData.engineN1[engineIdx]->set(startN1Fbw);
-  simData.engineFF[engineIdx]->set(startFfFbw);
 
-  if (engineState == RESTARTING) {
+  // the engine is only cranked by the starter until the start control commands the fuel on
+  if (!simData.fadecFuelOnCommanded[engineIdx]->getAsBool()) {
+    simData.engineFF[engineIdx]->set(0);
+    simData.engineEgt[engineIdx]->set(shutdownEgtFbw);
+  } else if (engineState == RESTARTING) {
+    simData.engineFF[engineIdx]->set(startFfFbw);
     if ((std::abs)(startEgtFbw - preEgtFbw) <= 1.5) {
       simData.engineEgt[engineIdx]->set(startEgtFbw);
       simData.engineState[engineIdx]->set(STARTING);
@@ -540,6 +558,7 @@ void EngineControl_A32NX::engineStartProcedure(int                     engine,
       simData.engineEgt[engineIdx]->set(shutdownEgtFbw);
     }
   } else {
+    simData.engineFF[engineIdx]->set(startFfFbw);
     simData.engineEgt[engineIdx]->set(startEgtFbw);
   }
 
@@ -71,6 +71,7 @@ class EngineControl_A32NX {
   static constexpr int    MAX_OIL             = 200;
   static constexpr int    MIN_OIL             = 140;
   static constexpr double FUEL_RATE_THRESHOLD = 661;  // lbs/sec for determining fuel ui tampering
+  static constexpr int    START_PHASE_ABORTED = 6;    // start control phase in which the systems have aborted the start
 
   /**
    * @enum EngineState
@@ -213,6 +214,7 @@ class EngineControl_A32NX {
    * @param engineStarterTurnedOff The status of the engine starter being turned off.
    * @param engineMasterTurnedOn The status of the engine master switch being turned on.
    * @param engineMasterTurnedOff The status of the engine master switch being turned off.
+   * @param startAborted Whether the start control of the systems aborted the start and cut the fuel.
    * @param simN2 The current N2 value from the simulator.
    * @param idleN2 The idle N2 value.
    * @param ambientTemperature The current ambient temperature.
@@ -225,6 +227,7 @@ class EngineControl_A32NX {
                                                       bool   engineStarterTurnedOff,
                                                       bool   engineMasterTurnedOn,
                                                       bool   engineMasterTurnedOff,
+                                                      bool   startAborted,
                                                       double simN2,
                                                       double idleN2,
                                                       double ambientTemperature);
@@ -203,6 +203,9 @@ class FadecSimData_A32NX {
   NamedVariablePtr engineStarterPressurized[2];
   NamedVariablePtr engineState[2];
   NamedVariablePtr engineTimer[2];
+  NamedVariablePtr fadecFuelOnCommanded[2];        // Bool, fuel on as commanded by the start control of the systems
+  NamedVariablePtr fadecStarterValveCommanded[2];  // Bool, starter valve open as commanded by the start control of the systems
+  NamedVariablePtr fadecStartPhase[2];             // Number, phase of the start control of the systems
   NamedVariablePtr fuelAuxLeftPre;   // Pounds
   NamedVariablePtr fuelAuxRightPre;  // Pounds
   NamedVariablePtr fuelCenterPre;    // Pounds
@@ -332,6 +335,15 @@ class FadecSimData_A32NX {
     engineStarterPressurized[L] = dm->make_named_var("A32NX_PNEU_ENG_1_STARTER_PRESSURIZED", UNITS.Number, AUTO_READ);
     engineStarterPressurized[R] = dm->make_named_var("A32NX_PNEU_ENG_2_STARTER_PRESSURIZED", UNITS.Number, AUTO_READ);
 
+    fadecFuelOnCommanded[L] = dm->make_named_var("A32NX_FADEC_FUEL_ON_COMMANDED_ENG1", UNITS.Number, AUTO_READ);
+    fadecFuelOnCommanded[R] = dm->make_named_var("A32NX_FADEC_FUEL_ON_COMMANDED_ENG2", UNITS.Number, AUTO_READ);
+
+    fadecStarterValveCommanded[L] = dm->make_named_var("A32NX_FADEC_STARTER_VALVE_COMMANDED_ENG1", UNITS.Number, AUTO_READ);
+    fadecStarterValveCommanded[R] = dm->make_named_var("A32NX_FADEC_STARTER_VALVE_COMMANDED_ENG2", UNITS.Number, AUTO_READ);
+
+    fadecStartPhase[L] = dm->make_named_var("A32NX_FADEC_START_PHASE_ENG1", UNITS.Number, AUTO_READ);
+    fadecStartPhase[R] = dm->make_named_var("A32NX_FADEC_START_PHASE_ENG2", UNITS.Number, AUTO_READ);
+
     fuelAuxLeftPre   = dm->make_named_var("A32NX_FUEL_AUX_LEFT_PRE", UNITS.Number, AUTO_READ_WRITE);
     fuelAuxRightPre  = dm->make_named_var("A32NX_FUEL_AUX_RIGHT_PRE", UNITS.Number, AUTO_READ_WRITE);
     fuelCenterPre    = dm->make_named_var("A32NX_FUEL_CENTER_PRE", UNITS.Number, AUTO_READ_WRITE);
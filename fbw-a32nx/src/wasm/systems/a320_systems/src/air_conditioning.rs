@@ -4,12 +4,15 @@ use systems::{
         acs_controller::{AcscId, AirConditioningSystemController, Pack},
         cabin_air::CabinAirSimulation,
         cabin_pressure_controller::{CabinPressureController, CpcId},
-        pressure_valve::{OutflowValve, SafetyValve, SafetyValveSignal},
+        pressure_valve::{
+            OutflowValve, PressureValve, PressureValveSignal, SafetyValve, SafetyValveSignal,
+        },
         AdirsToAirCondInterface, Air, AirConditioningOverheadShared, AirConditioningPack, CabinFan,
-        Channel, DuctTemperature, MixerUnit, OutflowValveSignal, OutletAir, OverheadFlowSelector,
-        PackFlowControllers, PressurizationConstants, PressurizationOverheadShared, TrimAirSystem,
-        VcmShared, ZoneType,
+        CabinFansSignal, CabinPressure, Channel, DuctTemperature, MixerUnit, OutflowValveSignal,
+        OutletAir, OverheadFlowSelector, PackFlowControllers, PressurizationConstants,
+        PressurizationOverheadShared, TrimAirSystem, VcmShared, ZoneType,
     },
+    failures::{Failure, FailureType},
     overhead::{
         AutoManFaultPushButton, NormalOnPushButton, OnOffFaultPushButton, OnOffPushButton,
         SpringLoadedSwitch, ValueKnob,
@@ -21,8 +24,8 @@ use systems::{
         random_number,
         update_iterator::MaxStepLoop,
         AverageExt, CabinAltitude, CabinSimulation, ControllerSignal, ElectricalBusType,
-        EngineCorrectedN1, EngineFirePushButtons, EngineStartState, LgciuWeightOnWheels,
-        PackFlowValveState, PneumaticBleed,
+        ElectricalBuses, EngineCorrectedN1, EngineFirePushButtons, EngineStartState,
+        LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -33,9 +36,11 @@ use systems::{
 use std::time::Duration;
 use uom::si::{
     f64::*,
+    mass_rate::kilogram_per_second,
+    power::watt,
     pressure::{hectopascal, psi},
     ratio::percent,
-    thermodynamic_temperature::degree_celsius,
+    thermodynamic_temperature::{degree_celsius, kelvin},
     velocity::knot,
     volume::cubic_meter,
     volume_rate::liter_per_second,
@@ -47,6 +52,7 @@ pub(super) struct A320AirConditioning {
     a320_cabin: A320Cabin,
     a320_air_conditioning_system: A320AirConditioningSystem,
     a320_pressurization_system: A320PressurizationSystem,
+    a320_avionics_ventilation: A320AvionicsVentilation,
 
     pressurization_updater: MaxStepLoop,
 }
@@ -62,6 +68,7 @@ impl A320AirConditioning {
             a320_cabin: A320Cabin::new(context),
             a320_air_conditioning_system: A320AirConditioningSystem::new(context, &cabin_zones),
             a320_pressurization_system: A320PressurizationSystem::new(context),
+            a320_avionics_ventilation: A320AvionicsVentilation::new(context),
 
             pressurization_updater: MaxStepLoop::new(Self::PRESSURIZATION_SIM_MAX_TIME_STEP),
         }
@@ -76,6 +83,7 @@ impl A320AirConditioning {
         number_of_passengers: &impl NumberOfPassengers,
         pneumatic: &(impl EngineStartState + PackFlowValveState + PneumaticBleed),
         lgciu: [&impl LgciuWeightOnWheels; 2],
+        avionics_electrical_load: Power,
     ) {
         self.pressurization_updater.update(context);
 
@@ -111,6 +119,19 @@ impl A320AirConditioning {
                 &self.a320_cabin,
             );
         }
+
+        self.a320_avionics_ventilation.update(
+            context,
+            &self.a320_cabin,
+            avionics_electrical_load,
+            lgciu
+                .iter()
+                .all(|&a| a.left_and_right_gear_compressed(true)),
+        );
+    }
+
+    pub fn avionics_smoke_detected(&self) -> bool {
+        self.a320_avionics_ventilation.avionics_smoke_detected()
     }
 
     pub fn mix_packs_air_update(&mut self, pack_container: &mut [impl PneumaticContainer; 2]) {
@@ -128,6 +149,16 @@ impl A320AirConditioning {
     }
 }
 
+impl CabinPressure for A320AirConditioning {
+    fn exterior_pressure(&self) -> Pressure {
+        self.a320_cabin.exterior_pressure()
+    }
+
+    fn cabin_pressure(&self) -> Pressure {
+        self.a320_cabin.cabin_pressure()
+    }
+}
+
 impl PackFlowControllers for A320AirConditioning {
     type PackFlowControllerSignal =
         <A320AirConditioningSystem as PackFlowControllers>::PackFlowControllerSignal;
@@ -143,6 +174,7 @@ impl SimulationElement for A320AirConditioning {
         self.a320_cabin.accept(visitor);
         self.a320_air_conditioning_system.accept(visitor);
         self.a320_pressurization_system.accept(visitor);
+        self.a320_avionics_ventilation.accept(visitor);
 
         visitor.visit(self);
     }
@@ -1090,6 +1122,614 @@ impl ControllerSignal<OutflowValveSignal> for ResidualPressureController {
     }
 }
 
+/// The avionics equipment ventilation cools the avionics bay with a blower and an extract fan.
+/// The AEVC selects the configuration from the skin temperature and the flight phase: on
+/// ground the bay is ventilated with outside air through the skin air inlet and outlet valves,
+/// while in flight the air is recirculated and cooled through the skin heat exchanger.
+struct A320AvionicsVentilation {
+    avionics_bay_temperature_id: VariableIdentifier,
+    avionics_overheat_id: VariableIdentifier,
+    avionics_smoke_detected_id: VariableIdentifier,
+    skin_air_inlet_valve_id: VariableIdentifier,
+    skin_air_outlet_valve_id: VariableIdentifier,
+
+    aevc: AvionicsEquipmentVentilationComputer,
+    blower_fan: CabinFan,
+    extract_fan: CabinFan,
+    skin_air_inlet_valve: SkinValve,
+    skin_air_outlet_valve: SkinValve,
+    avionics_bay: AvionicsBay,
+    smoke_detector: AvionicsSmokeDetector,
+    overhead: A320AvionicsVentilationOverhead,
+}
+
+impl A320AvionicsVentilation {
+    const BLOWER_FAN_DESIGN_FLOW_RATE_L_S: f64 = 650.; // litres/sec
+    const EXTRACT_FAN_DESIGN_FLOW_RATE_L_S: f64 = 650.; // litres/sec
+
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            avionics_bay_temperature_id: context
+                .get_identifier("VENT_AVIONICS_BAY_TEMP".to_owned()),
+            avionics_overheat_id: context.get_identifier("VENT_AVIONICS_OVERHEAT".to_owned()),
+            avionics_smoke_detected_id: context
+                .get_identifier("VENT_AVIONICS_SMOKE_DETECTED".to_owned()),
+            skin_air_inlet_valve_id: context.get_identifier("VENT_INLET_VALVE".to_owned()),
+            skin_air_outlet_valve_id: context.get_identifier("VENT_OUTLET_VALVE".to_owned()),
+
+            aevc: AvionicsEquipmentVentilationComputer::new(ElectricalBusType::DirectCurrent(1)),
+            blower_fan: CabinFan::new_with_failure(
+                FailureType::AvionicsBlowerFan,
+                VolumeRate::new::<liter_per_second>(Self::BLOWER_FAN_DESIGN_FLOW_RATE_L_S),
+                ElectricalBusType::AlternatingCurrent(1),
+            ),
+            extract_fan: CabinFan::new_with_failure(
+                FailureType::AvionicsExtractFan,
+                VolumeRate::new::<liter_per_second>(Self::EXTRACT_FAN_DESIGN_FLOW_RATE_L_S),
+                ElectricalBusType::AlternatingCurrent(2),
+            ),
+            skin_air_inlet_valve: SkinValve::new(ElectricalBusType::DirectCurrent(1)),
+            skin_air_outlet_valve: SkinValve::new(ElectricalBusType::DirectCurrent(1)),
+            avionics_bay: AvionicsBay::new(),
+            smoke_detector: AvionicsSmokeDetector::new(ElectricalBusType::DirectCurrentEssential),
+            overhead: A320AvionicsVentilationOverhead::new(context),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        cabin_simulation: &impl CabinSimulation,
+        avionics_electrical_load: Power,
+        lgciu_gears_compressed: bool,
+    ) {
+        let skin_temperature = Self::skin_temperature(context);
+
+        self.smoke_detector.update();
+        self.aevc
+            .update(&self.overhead, lgciu_gears_compressed, skin_temperature);
+
+        self.blower_fan.update(
+            cabin_simulation,
+            &self.aevc.blower_fan_control(&self.overhead),
+        );
+        self.extract_fan.update(
+            cabin_simulation,
+            &self.aevc.extract_fan_control(&self.overhead),
+        );
+
+        self.skin_air_inlet_valve
+            .update(context, self.aevc.skin_air_inlet_valve_target());
+        self.skin_air_outlet_valve
+            .update(context, self.aevc.skin_air_outlet_valve_target());
+
+        self.avionics_bay.update(
+            context,
+            avionics_electrical_load,
+            self.cooling_air_flow(),
+            self.cooling_air_temperature(cabin_simulation, skin_temperature),
+            skin_temperature,
+        );
+
+        self.update_fault_lights();
+    }
+
+    /// The skin temperature is the total air temperature, as the boundary layer air is slowed down
+    /// against the fuselage.
+    fn skin_temperature(context: &UpdateContext) -> ThermodynamicTemperature {
+        const RECOVERY_FACTOR: f64 = 0.9;
+        let mach_number = f64::from(context.mach_number());
+
+        ThermodynamicTemperature::new::<kelvin>(
+            context.ambient_temperature().get::<kelvin>()
+                * (1. + 0.2 * RECOVERY_FACTOR * mach_number.powi(2)),
+        )
+    }
+
+    fn cooling_air_flow(&self) -> MassRate {
+        if self.blower_fan.is_on() {
+            self.blower_fan.outlet_air().flow_rate()
+        } else {
+            // Without the blower fan, the extract fan draws air through the avionics bay
+            self.extract_fan.outlet_air().flow_rate()
+        }
+    }
+
+    fn cooling_air_temperature(
+        &self,
+        cabin_simulation: &impl CabinSimulation,
+        skin_temperature: ThermodynamicTemperature,
+    ) -> ThermodynamicTemperature {
+        match self.aevc.configuration() {
+            AvionicsVentilationConfiguration::Open => skin_temperature,
+            AvionicsVentilationConfiguration::Closed
+            | AvionicsVentilationConfiguration::Intermediate => self
+                .avionics_bay
+                .skin_heat_exchanger_outlet_temperature(skin_temperature),
+            AvionicsVentilationConfiguration::Abnormal
+            | AvionicsVentilationConfiguration::Smoke => {
+                cabin_simulation.cabin_temperature().iter().average()
+            }
+        }
+    }
+
+    fn update_fault_lights(&mut self) {
+        let smoke_detected = self.smoke_detector.smoke_detected();
+        let aevc_failed = !self.aevc.is_powered();
+
+        self.overhead.set_blower_fault(
+            aevc_failed
+                || smoke_detected
+                || self.avionics_bay.is_overheating()
+                || (self.aevc.blower_fan_is_commanded_on(&self.overhead)
+                    && !self.blower_fan.is_on()),
+        );
+        self.overhead.set_extract_fault(
+            aevc_failed
+                || smoke_detected
+                || (self.aevc.extract_fan_is_commanded_on(&self.overhead)
+                    && !self.extract_fan.is_on()),
+        );
+    }
+
+    fn avionics_smoke_detected(&self) -> bool {
+        self.smoke_detector.smoke_detected()
+    }
+}
+
+impl SimulationElement for A320AvionicsVentilation {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.avionics_bay_temperature_id,
+            self.avionics_bay.temperature(),
+        );
+        writer.write(
+            &self.avionics_overheat_id,
+            self.avionics_bay.is_overheating(),
+        );
+        writer.write(
+            &self.avionics_smoke_detected_id,
+            self.smoke_detector.smoke_detected(),
+        );
+        writer.write(
+            &self.skin_air_inlet_valve_id,
+            self.skin_air_inlet_valve.open_amount(),
+        );
+        writer.write(
+            &self.skin_air_outlet_valve_id,
+            self.skin_air_outlet_valve.open_amount(),
+        );
+    }
+
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.aevc.accept(visitor);
+        self.blower_fan.accept(visitor);
+        self.extract_fan.accept(visitor);
+        self.skin_air_inlet_valve.accept(visitor);
+        self.skin_air_outlet_valve.accept(visitor);
+        self.smoke_detector.accept(visitor);
+        self.overhead.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum AvionicsVentilationConfiguration {
+    /// Outside air is blown through the avionics bay and exhausted overboard
+    Open,
+    /// Air is recirculated through the skin heat exchanger
+    Closed,
+    /// As closed, with the skin air outlet valve partially open to exhaust hot air
+    Intermediate,
+    /// Air from the air conditioning is added when the blower or extract fan is overridden
+    Abnormal,
+    /// Smoke is exhausted overboard by the extract fan
+    Smoke,
+}
+
+struct AvionicsEquipmentVentilationComputer {
+    configuration: AvionicsVentilationConfiguration,
+
+    is_powered: bool,
+    powered_by: ElectricalBusType,
+}
+
+impl AvionicsEquipmentVentilationComputer {
+    // Skin temperature thresholds, lower values are the thresholds on decreasing temperature
+    const GROUND_OPEN_THRESHOLD_DEGREES_C: f64 = 12.;
+    const GROUND_CLOSED_THRESHOLD_DEGREES_C: f64 = 9.;
+    const FLIGHT_INTERMEDIATE_THRESHOLD_DEGREES_C: f64 = 35.;
+    const FLIGHT_CLOSED_THRESHOLD_DEGREES_C: f64 = 32.;
+
+    const SKIN_AIR_OUTLET_VALVE_PARTIALLY_OPEN_PERCENT: f64 = 25.;
+
+    fn new(powered_by: ElectricalBusType) -> Self {
+        Self {
+            configuration: AvionicsVentilationConfiguration::Open,
+
+            is_powered: false,
+            powered_by,
+        }
+    }
+
+    fn update(
+        &mut self,
+        overhead: &A320AvionicsVentilationOverhead,
+        lgciu_gears_compressed: bool,
+        skin_temperature: ThermodynamicTemperature,
+    ) {
+        self.configuration = if !overhead.blower_is_auto() && !overhead.extract_is_auto() {
+            AvionicsVentilationConfiguration::Smoke
+        } else if !overhead.blower_is_auto() || !overhead.extract_is_auto() {
+            AvionicsVentilationConfiguration::Abnormal
+        } else {
+            self.normal_configuration(lgciu_gears_compressed, skin_temperature)
+        };
+    }
+
+    fn normal_configuration(
+        &self,
+        lgciu_gears_compressed: bool,
+        skin_temperature: ThermodynamicTemperature,
+    ) -> AvionicsVentilationConfiguration {
+        let skin_temperature = skin_temperature.get::<degree_celsius>();
+
+        if lgciu_gears_compressed {
+            if skin_temperature > Self::GROUND_OPEN_THRESHOLD_DEGREES_C
+                || (skin_temperature > Self::GROUND_CLOSED_THRESHOLD_DEGREES_C
+                    && self.configuration == AvionicsVentilationConfiguration::Open)
+            {
+                AvionicsVentilationConfiguration::Open
+            } else {
+                AvionicsVentilationConfiguration::Closed
+            }
+        } else if skin_temperature > Self::FLIGHT_INTERMEDIATE_THRESHOLD_DEGREES_C
+            || (skin_temperature > Self::FLIGHT_CLOSED_THRESHOLD_DEGREES_C
+                && self.configuration == AvionicsVentilationConfiguration::Intermediate)
+        {
+            AvionicsVentilationConfiguration::Intermediate
+        } else {
+            AvionicsVentilationConfiguration::Closed
+        }
+    }
+
+    fn configuration(&self) -> AvionicsVentilationConfiguration {
+        self.configuration
+    }
+
+    fn is_powered(&self) -> bool {
+        self.is_powered
+    }
+
+    /// In OVRD the blower fan is switched off, independently of the AEVC
+    fn blower_fan_is_commanded_on(&self, overhead: &A320AvionicsVentilationOverhead) -> bool {
+        overhead.blower_is_auto() && self.is_powered
+    }
+
+    /// In OVRD the extract fan is hardwired to run, independently of the AEVC
+    fn extract_fan_is_commanded_on(&self, overhead: &A320AvionicsVentilationOverhead) -> bool {
+        !overhead.extract_is_auto() || self.is_powered
+    }
+
+    fn blower_fan_control(&self, overhead: &A320AvionicsVentilationOverhead) -> AvionicsFanControl {
+        AvionicsFanControl {
+            is_on: self.blower_fan_is_commanded_on(overhead),
+        }
+    }
+
+    fn extract_fan_control(
+        &self,
+        overhead: &A320AvionicsVentilationOverhead,
+    ) -> AvionicsFanControl {
+        AvionicsFanControl {
+            is_on: self.extract_fan_is_commanded_on(overhead),
+        }
+    }
+
+    fn skin_air_inlet_valve_target(&self) -> Option<Ratio> {
+        if !self.is_powered {
+            None
+        } else if self.configuration == AvionicsVentilationConfiguration::Open {
+            Some(Ratio::new::<percent>(100.))
+        } else {
+            Some(Ratio::default())
+        }
+    }
+
+    fn skin_air_outlet_valve_target(&self) -> Option<Ratio> {
+        if !self.is_powered {
+            None
+        } else {
+            Some(match self.configuration {
+                AvionicsVentilationConfiguration::Open => Ratio::new::<percent>(100.),
+                AvionicsVentilationConfiguration::Closed => Ratio::default(),
+                AvionicsVentilationConfiguration::Intermediate
+                | AvionicsVentilationConfiguration::Abnormal
+                | AvionicsVentilationConfiguration::Smoke => {
+                    Ratio::new::<percent>(Self::SKIN_AIR_OUTLET_VALVE_PARTIALLY_OPEN_PERCENT)
+                }
+            })
+        }
+    }
+}
+
+impl SimulationElement for AvionicsEquipmentVentilationComputer {
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+struct AvionicsFanControl {
+    is_on: bool,
+}
+
+impl ControllerSignal<CabinFansSignal> for AvionicsFanControl {
+    fn signal(&self) -> Option<CabinFansSignal> {
+        if self.is_on {
+            Some(CabinFansSignal::On(None))
+        } else {
+            Some(CabinFansSignal::Off)
+        }
+    }
+}
+
+struct SkinValve {
+    motor: SkinValveMotor,
+    valve: PressureValve,
+}
+
+impl SkinValve {
+    fn new(powered_by: ElectricalBusType) -> Self {
+        Self {
+            motor: SkinValveMotor::new(powered_by),
+            valve: PressureValve::new_open(),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, target_open: Option<Ratio>) {
+        self.motor.update(target_open, self.valve.open_amount());
+        self.valve.update(context, &self.motor);
+    }
+
+    fn open_amount(&self) -> Ratio {
+        self.valve.open_amount()
+    }
+}
+
+impl SimulationElement for SkinValve {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.motor.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+struct SkinValveMotor {
+    open_amount: Ratio,
+    target_open: Ratio,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+}
+
+impl SkinValveMotor {
+    const TRAVEL_TIME: Duration = Duration::from_secs(10);
+
+    fn new(powered_by: ElectricalBusType) -> Self {
+        Self {
+            open_amount: Ratio::new::<percent>(100.),
+            target_open: Ratio::new::<percent>(100.),
+
+            powered_by,
+            is_powered: false,
+        }
+    }
+
+    fn update(&mut self, target_open: Option<Ratio>, open_amount: Ratio) {
+        self.open_amount = open_amount;
+        self.target_open = target_open.unwrap_or(open_amount);
+    }
+}
+
+impl ControllerSignal<PressureValveSignal> for SkinValveMotor {
+    fn signal(&self) -> Option<PressureValveSignal> {
+        if !self.is_powered {
+            None
+        } else if self.target_open > self.open_amount {
+            Some(PressureValveSignal::Open(
+                self.target_open,
+                Self::TRAVEL_TIME,
+            ))
+        } else if self.target_open < self.open_amount {
+            Some(PressureValveSignal::Close(
+                self.target_open,
+                Self::TRAVEL_TIME,
+            ))
+        } else {
+            Some(PressureValveSignal::Neutral)
+        }
+    }
+}
+
+impl SimulationElement for SkinValveMotor {
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+/// Lumped thermal model of the avionics bay, heated by the avionics and cooled by the
+/// ventilation air and through the fuselage skin.
+struct AvionicsBay {
+    temperature: ThermodynamicTemperature,
+    is_overheating: bool,
+}
+
+impl AvionicsBay {
+    const HEAT_CAPACITY_J_PER_K: f64 = 400000.;
+    const SKIN_HEAT_TRANSFER_COEFFICIENT_W_PER_K: f64 = 100.;
+    const SKIN_HEAT_EXCHANGER_EFFECTIVENESS: f64 = 0.5;
+    // Part of the avionics electrical load dissipated in the avionics bay
+    const DISSIPATED_HEAT_RATIO: f64 = 0.6;
+    const AIR_SPECIFIC_HEAT_J_PER_KG_K: f64 = 1005.;
+
+    const OVERHEAT_THRESHOLD_DEGREES_C: f64 = 60.;
+    const OVERHEAT_RESET_DEGREES_C: f64 = 55.;
+
+    fn new() -> Self {
+        Self {
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(24.),
+            is_overheating: false,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        avionics_electrical_load: Power,
+        cooling_air_flow: MassRate,
+        cooling_air_temperature: ThermodynamicTemperature,
+        skin_temperature: ThermodynamicTemperature,
+    ) {
+        let bay_temperature = self.temperature.get::<degree_celsius>();
+
+        let heat_input = avionics_electrical_load.get::<watt>() * Self::DISSIPATED_HEAT_RATIO;
+        let ventilation_heat_output = cooling_air_flow.get::<kilogram_per_second>()
+            * Self::AIR_SPECIFIC_HEAT_J_PER_KG_K
+            * (bay_temperature - cooling_air_temperature.get::<degree_celsius>());
+        let skin_heat_output = Self::SKIN_HEAT_TRANSFER_COEFFICIENT_W_PER_K
+            * (bay_temperature - skin_temperature.get::<degree_celsius>());
+
+        self.temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            bay_temperature
+                + (heat_input - ventilation_heat_output - skin_heat_output)
+                    * context.delta_as_secs_f64()
+                    / Self::HEAT_CAPACITY_J_PER_K,
+        );
+
+        let temperature = self.temperature.get::<degree_celsius>();
+        self.is_overheating = temperature > Self::OVERHEAT_THRESHOLD_DEGREES_C
+            || (self.is_overheating && temperature > Self::OVERHEAT_RESET_DEGREES_C);
+    }
+
+    fn skin_heat_exchanger_outlet_temperature(
+        &self,
+        skin_temperature: ThermodynamicTemperature,
+    ) -> ThermodynamicTemperature {
+        let bay_temperature = self.temperature.get::<degree_celsius>();
+        ThermodynamicTemperature::new::<degree_celsius>(
+            bay_temperature
+                - Self::SKIN_HEAT_EXCHANGER_EFFECTIVENESS
+                    * (bay_temperature - skin_temperature.get::<degree_celsius>()),
+        )
+    }
+
+    fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    fn is_overheating(&self) -> bool {
+        self.is_overheating
+    }
+}
+
+/// Smoke detector in the extraction duct of the avionics ventilation
+struct AvionicsSmokeDetector {
+    smoke_detected: bool,
+    smoke: Failure,
+
+    is_powered: bool,
+    powered_by: ElectricalBusType,
+}
+
+impl AvionicsSmokeDetector {
+    fn new(powered_by: ElectricalBusType) -> Self {
+        Self {
+            smoke_detected: false,
+            smoke: Failure::new(FailureType::AvionicsSmoke),
+
+            is_powered: false,
+            powered_by,
+        }
+    }
+
+    fn update(&mut self) {
+        self.smoke_detected = self.is_powered && self.smoke.is_active();
+    }
+
+    fn smoke_detected(&self) -> bool {
+        self.smoke_detected
+    }
+}
+
+impl SimulationElement for AvionicsSmokeDetector {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.smoke.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+struct A320AvionicsVentilationOverhead {
+    blower_is_auto_id: VariableIdentifier,
+    blower_has_fault_id: VariableIdentifier,
+    extract_is_auto_id: VariableIdentifier,
+    extract_has_fault_id: VariableIdentifier,
+
+    blower_is_auto: bool,
+    blower_has_fault: bool,
+    extract_is_auto: bool,
+    extract_has_fault: bool,
+}
+
+impl A320AvionicsVentilationOverhead {
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            blower_is_auto_id: context.get_identifier("VENTILATION_BLOWER_TOGGLE".to_owned()),
+            blower_has_fault_id: context.get_identifier("VENTILATION_BLOWER_FAULT".to_owned()),
+            extract_is_auto_id: context.get_identifier("VENTILATION_EXTRACT_TOGGLE".to_owned()),
+            extract_has_fault_id: context.get_identifier("VENTILATION_EXTRACT_FAULT".to_owned()),
+
+            blower_is_auto: true,
+            blower_has_fault: false,
+            extract_is_auto: true,
+            extract_has_fault: false,
+        }
+    }
+
+    fn blower_is_auto(&self) -> bool {
+        self.blower_is_auto
+    }
+
+    fn extract_is_auto(&self) -> bool {
+        self.extract_is_auto
+    }
+
+    fn set_blower_fault(&mut self, has_fault: bool) {
+        self.blower_has_fault = has_fault;
+    }
+
+    fn set_extract_fault(&mut self, has_fault: bool) {
+        self.extract_has_fault = has_fault;
+    }
+}
+
+impl SimulationElement for A320AvionicsVentilationOverhead {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.blower_is_auto = reader.read(&self.blower_is_auto_id);
+        self.extract_is_auto = reader.read(&self.extract_is_auto_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.blower_has_fault_id, self.blower_has_fault);
+        writer.write(&self.extract_has_fault_id, self.extract_has_fault);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1567,6 +2207,7 @@ mod tests {
         ac_2_bus: ElectricalBus,
         dc_ess_bus: ElectricalBus,
         dc_bat_bus: ElectricalBus,
+        avionics_electrical_load: Power,
     }
 
     impl TestAircraft {
@@ -1610,6 +2251,7 @@ mod tests {
                 ac_2_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(2)),
                 dc_ess_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
                 dc_bat_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentBattery),
+                avionics_electrical_load: Power::new::<watt>(13000.),
             };
             test_aircraft
                 .a320_cabin_air
@@ -1685,6 +2327,7 @@ mod tests {
                 &self.payload,
                 &self.pneumatic,
                 [&self.lgciu1, &self.lgciu2],
+                self.avionics_electrical_load,
             );
         }
     }
@@ -1721,6 +2364,8 @@ mod tests {
             );
             test_bed.command_pack_flow_selector_position(1);
             test_bed.command_engine_n1(Ratio::new::<percent>(30.));
+            test_bed.write_by_name("VENTILATION_BLOWER_TOGGLE", true);
+            test_bed.write_by_name("VENTILATION_EXTRACT_TOGGLE", true);
 
             test_bed
         }
@@ -1849,6 +2494,16 @@ mod tests {
             self
         }
 
+        fn blower_pb_auto(mut self, value: bool) -> Self {
+            self.write_by_name("VENTILATION_BLOWER_TOGGLE", value);
+            self
+        }
+
+        fn extract_pb_auto(mut self, value: bool) -> Self {
+            self.write_by_name("VENTILATION_EXTRACT_TOGGLE", value);
+            self
+        }
+
         fn set_vertical_speed(&mut self, vertical_speed: Velocity) {
             self.vertical_speed = vertical_speed;
         }
@@ -2039,6 +2694,58 @@ mod tests {
         fn reference_pressure(&self) -> Pressure {
             self.query(|a| a.a320_cabin_air.a320_pressurization_system.cpc[0].reference_pressure())
         }
+
+        fn avionics_ventilation_configuration(&self) -> AvionicsVentilationConfiguration {
+            self.query(|a| {
+                a.a320_cabin_air
+                    .a320_avionics_ventilation
+                    .aevc
+                    .configuration()
+            })
+        }
+
+        fn skin_air_inlet_valve_open_amount(&self) -> Ratio {
+            self.query(|a| {
+                a.a320_cabin_air
+                    .a320_avionics_ventilation
+                    .skin_air_inlet_valve
+                    .open_amount()
+            })
+        }
+
+        fn skin_air_outlet_valve_open_amount(&self) -> Ratio {
+            self.query(|a| {
+                a.a320_cabin_air
+                    .a320_avionics_ventilation
+                    .skin_air_outlet_valve
+                    .open_amount()
+            })
+        }
+
+        fn avionics_bay_temperature(&self) -> ThermodynamicTemperature {
+            self.query(|a| {
+                a.a320_cabin_air
+                    .a320_avionics_ventilation
+                    .avionics_bay
+                    .temperature()
+            })
+        }
+
+        fn blower_has_fault(&mut self) -> bool {
+            self.read_by_name("VENTILATION_BLOWER_FAULT")
+        }
+
+        fn extract_has_fault(&mut self) -> bool {
+            self.read_by_name("VENTILATION_EXTRACT_FAULT")
+        }
+
+        fn avionics_overheat(&mut self) -> bool {
+            self.read_by_name("VENT_AVIONICS_OVERHEAT")
+        }
+
+        fn avionics_smoke_detected(&mut self) -> bool {
+            self.read_by_name("VENT_AVIONICS_SMOKE_DETECTED")
+        }
     }
     impl TestBed for CabinAirTestBed {
         type Aircraft = TestAircraft;
@@ -3016,6 +3723,200 @@ mod tests {
         }
     }
 
+    mod a320_avionics_ventilation_tests {
+        use super::*;
+        use systems::failures::FailureType;
+
+        #[test]
+        fn avionics_ventilation_is_open_on_ground_with_warm_skin() {
+            let test_bed = test_bed().on_ground().iterate(20);
+
+            assert_eq!(
+                test_bed.avionics_ventilation_configuration(),
+                AvionicsVentilationConfiguration::Open
+            );
+            assert_about_eq!(
+                test_bed.skin_air_inlet_valve_open_amount().get::<percent>(),
+                100.
+            );
+            assert_about_eq!(
+                test_bed
+                    .skin_air_outlet_valve_open_amount()
+                    .get::<percent>(),
+                100.
+            );
+        }
+
+        #[test]
+        fn avionics_ventilation_is_closed_on_ground_with_cold_skin() {
+            let test_bed = test_bed()
+                .on_ground()
+                .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(0.))
+                .iterate(20);
+
+            assert_eq!(
+                test_bed.avionics_ventilation_configuration(),
+                AvionicsVentilationConfiguration::Closed
+            );
+            assert_about_eq!(
+                test_bed.skin_air_inlet_valve_open_amount().get::<percent>(),
+                0.
+            );
+            assert_about_eq!(
+                test_bed
+                    .skin_air_outlet_valve_open_amount()
+                    .get::<percent>(),
+                0.
+            );
+        }
+
+        #[test]
+        fn avionics_ventilation_closes_in_flight() {
+            let mut test_bed = test_bed().on_ground().iterate(20);
+            test_bed.command_on_ground(false);
+            test_bed = test_bed.iterate(20);
+
+            assert_eq!(
+                test_bed.avionics_ventilation_configuration(),
+                AvionicsVentilationConfiguration::Closed
+            );
+            assert_about_eq!(
+                test_bed.skin_air_inlet_valve_open_amount().get::<percent>(),
+                0.
+            );
+        }
+
+        #[test]
+        fn avionics_ventilation_is_intermediate_in_flight_with_hot_skin() {
+            let test_bed = test_bed()
+                .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(40.))
+                .iterate(20);
+
+            assert_eq!(
+                test_bed.avionics_ventilation_configuration(),
+                AvionicsVentilationConfiguration::Intermediate
+            );
+            assert_about_eq!(
+                test_bed.skin_air_inlet_valve_open_amount().get::<percent>(),
+                0.
+            );
+            assert_about_eq!(
+                test_bed
+                    .skin_air_outlet_valve_open_amount()
+                    .get::<percent>(),
+                AvionicsEquipmentVentilationComputer::SKIN_AIR_OUTLET_VALVE_PARTIALLY_OPEN_PERCENT
+            );
+        }
+
+        #[test]
+        fn blower_and_extract_in_ovrd_select_smoke_configuration() {
+            let test_bed = test_bed()
+                .on_ground()
+                .blower_pb_auto(false)
+                .extract_pb_auto(false)
+                .iterate(20);
+
+            assert_eq!(
+                test_bed.avionics_ventilation_configuration(),
+                AvionicsVentilationConfiguration::Smoke
+            );
+            assert_about_eq!(
+                test_bed.skin_air_inlet_valve_open_amount().get::<percent>(),
+                0.
+            );
+            assert_about_eq!(
+                test_bed
+                    .skin_air_outlet_valve_open_amount()
+                    .get::<percent>(),
+                AvionicsEquipmentVentilationComputer::SKIN_AIR_OUTLET_VALVE_PARTIALLY_OPEN_PERCENT
+            );
+        }
+
+        #[test]
+        fn no_fault_lights_in_normal_operation() {
+            let mut test_bed = test_bed().on_ground().iterate(20);
+
+            assert!(!test_bed.blower_has_fault());
+            assert!(!test_bed.extract_has_fault());
+        }
+
+        #[test]
+        fn blower_fault_light_when_blower_fan_fails() {
+            let mut test_bed = test_bed().on_ground();
+            test_bed.fail(FailureType::AvionicsBlowerFan);
+            test_bed = test_bed.iterate(2);
+
+            assert!(test_bed.blower_has_fault());
+            assert!(!test_bed.extract_has_fault());
+        }
+
+        #[test]
+        fn blower_fault_light_goes_off_when_blower_in_ovrd() {
+            let mut test_bed = test_bed().on_ground();
+            test_bed.fail(FailureType::AvionicsBlowerFan);
+            test_bed = test_bed.iterate(2).blower_pb_auto(false).iterate(2);
+
+            assert!(!test_bed.blower_has_fault());
+            assert_eq!(
+                test_bed.avionics_ventilation_configuration(),
+                AvionicsVentilationConfiguration::Abnormal
+            );
+        }
+
+        #[test]
+        fn extract_fault_light_when_extract_fan_fails() {
+            let mut test_bed = test_bed().on_ground();
+            test_bed.fail(FailureType::AvionicsExtractFan);
+            test_bed = test_bed.iterate(2);
+
+            assert!(!test_bed.blower_has_fault());
+            assert!(test_bed.extract_has_fault());
+        }
+
+        #[test]
+        fn avionics_smoke_lights_blower_and_extract_fault() {
+            let mut test_bed = test_bed().on_ground();
+            test_bed.fail(FailureType::AvionicsSmoke);
+            test_bed = test_bed.iterate(2);
+
+            assert!(test_bed.avionics_smoke_detected());
+            assert!(test_bed.blower_has_fault());
+            assert!(test_bed.extract_has_fault());
+        }
+
+        #[test]
+        fn avionics_bay_temperature_is_stable_with_ventilation() {
+            let mut test_bed = test_bed()
+                .on_ground()
+                .iterate_with_delta(120, Duration::from_secs(30));
+
+            assert!(
+                test_bed.avionics_bay_temperature()
+                    > ThermodynamicTemperature::new::<degree_celsius>(24.)
+            );
+            assert!(
+                test_bed.avionics_bay_temperature()
+                    < ThermodynamicTemperature::new::<degree_celsius>(40.)
+            );
+            assert!(!test_bed.avionics_overheat());
+        }
+
+        #[test]
+        fn avionics_bay_overheats_without_ventilation() {
+            let mut test_bed = test_bed().on_ground();
+            test_bed.fail(FailureType::AvionicsBlowerFan);
+            test_bed.fail(FailureType::AvionicsExtractFan);
+            test_bed = test_bed.iterate_with_delta(120, Duration::from_secs(30));
+
+            assert!(
+                test_bed.avionics_bay_temperature()
+                    > ThermodynamicTemperature::new::<degree_celsius>(60.)
+            );
+            assert!(test_bed.avionics_overheat());
+            assert!(test_bed.blower_has_fault());
+        }
+    }
+
     #[test]
     fn packs_off_and_cab_fans_off_results_in_no_air_in() {
         let test_bed = test_bed()
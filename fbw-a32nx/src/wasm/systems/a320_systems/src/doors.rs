@@ -0,0 +1,256 @@
+use systems::{
+    accept_iterable,
+    air_conditioning::CabinPressure,
+    doors::{Door, DoorType, EscapeSlide},
+    shared::{CargoDoorLocked, CargoDoorOpenAmount},
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+use uom::si::f64::*;
+
+pub struct A320Doors {
+    passenger_doors: [Door; 4],
+    emergency_exits: [Door; 4],
+    forward_cargo_door: Door,
+    aft_cargo_door: Door,
+}
+impl A320Doors {
+    // Door id and the interactive point opening the door in the simulator.
+    pub const PASSENGER_DOORS: [(&'static str, usize); 4] = [
+        ("FWD_LEFT", 0),
+        ("FWD_RIGHT", 1),
+        ("AFT_LEFT", 2),
+        ("AFT_RIGHT", 3),
+    ];
+    const EMERGENCY_EXITS: [&'static str; 4] =
+        ["EMER_LEFT_1", "EMER_RIGHT_1", "EMER_LEFT_2", "EMER_RIGHT_2"];
+
+    pub fn new(context: &mut InitContext) -> Self {
+        let slides_armed_id = context.get_identifier("SLIDES_ARMED".to_owned());
+
+        Self {
+            passenger_doors: Self::PASSENGER_DOORS.map(|(id, interactive_point)| {
+                let open_request_id =
+                    context.get_identifier(format!("INTERACTIVE POINT OPEN:{}", interactive_point));
+                Door::new(
+                    context,
+                    id,
+                    DoorType::Passenger,
+                    Some(open_request_id),
+                    Some(EscapeSlide::new(Some(slides_armed_id))),
+                )
+            }),
+            // The overwing exits are only opened in an evacuation, their slides are always armed.
+            emergency_exits: Self::EMERGENCY_EXITS.map(|id| {
+                Door::new(
+                    context,
+                    id,
+                    DoorType::Emergency,
+                    None,
+                    Some(EscapeSlide::new(None)),
+                )
+            }),
+            forward_cargo_door: Door::new(context, "FWD_CARGO", DoorType::Cargo, None, None),
+            aft_cargo_door: Door::new(context, "AFT_CARGO", DoorType::Cargo, None, None),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        cabin_pressure: &impl CabinPressure,
+        cargo_doors: &(impl CargoDoorLocked + CargoDoorOpenAmount),
+    ) {
+        self.passenger_doors
+            .iter_mut()
+            .chain(self.emergency_exits.iter_mut())
+            .for_each(|door| door.update(context, cabin_pressure));
+
+        self.forward_cargo_door.update_actuated(
+            cargo_doors.fwd_cargo_door_open_amount(),
+            cargo_doors.fwd_cargo_door_locked(),
+        );
+        self.aft_cargo_door.update_actuated(
+            cargo_doors.aft_cargo_door_open_amount(),
+            cargo_doors.aft_cargo_door_locked(),
+        );
+    }
+
+    /// Open amount of the doors served by the boarding agents: the two forward doors and the aft left door.
+    pub fn boarding_door_open_amounts(&self) -> [Ratio; 3] {
+        [
+            self.passenger_doors[0].open_amount(),
+            self.passenger_doors[1].open_amount(),
+            self.passenger_doors[2].open_amount(),
+        ]
+    }
+}
+impl SimulationElement for A320Doors {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.passenger_doors, visitor);
+        accept_iterable!(self.emergency_exits, visitor);
+        self.forward_cargo_door.accept(visitor);
+        self.aft_cargo_door.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use systems::{
+        electrical::Electricity,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use uom::si::{pressure::hectopascal, ratio::percent};
+
+    struct TestCabinPressure {
+        differential_pressure: Pressure,
+    }
+    impl CabinPressure for TestCabinPressure {
+        fn exterior_pressure(&self) -> Pressure {
+            Pressure::new::<hectopascal>(1013.25)
+        }
+
+        fn cabin_pressure(&self) -> Pressure {
+            Pressure::new::<hectopascal>(1013.25) + self.differential_pressure
+        }
+    }
+
+    struct TestCargoDoors {
+        locked: bool,
+    }
+    impl CargoDoorLocked for TestCargoDoors {
+        fn fwd_cargo_door_locked(&self) -> bool {
+            self.locked
+        }
+
+        fn aft_cargo_door_locked(&self) -> bool {
+            self.locked
+        }
+    }
+    impl CargoDoorOpenAmount for TestCargoDoors {
+        fn fwd_cargo_door_open_amount(&self) -> Ratio {
+            self.open_amount()
+        }
+
+        fn aft_cargo_door_open_amount(&self) -> Ratio {
+            self.open_amount()
+        }
+    }
+    impl TestCargoDoors {
+        fn open_amount(&self) -> Ratio {
+            if self.locked {
+                Ratio::default()
+            } else {
+                Ratio::new::<percent>(100.)
+            }
+        }
+    }
+
+    struct TestAircraft {
+        doors: A320Doors,
+        cabin_pressure: TestCabinPressure,
+        cargo_doors: TestCargoDoors,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                doors: A320Doors::new(context),
+                cabin_pressure: TestCabinPressure {
+                    differential_pressure: Pressure::default(),
+                },
+                cargo_doors: TestCargoDoors { locked: true },
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            context: &UpdateContext,
+            _: &mut Electricity,
+        ) {
+            self.doors
+                .update(context, &self.cabin_pressure, &self.cargo_doors);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.doors.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    #[test]
+    fn all_doors_are_locked_at_init() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        for id in [
+            "FWD_LEFT",
+            "FWD_RIGHT",
+            "AFT_LEFT",
+            "AFT_RIGHT",
+            "EMER_LEFT_1",
+            "EMER_RIGHT_1",
+            "EMER_LEFT_2",
+            "EMER_RIGHT_2",
+            "FWD_CARGO",
+            "AFT_CARGO",
+        ] {
+            let is_locked: bool = test_bed.read_by_name(&format!("DOOR_{}_IS_LOCKED", id));
+            assert!(is_locked);
+        }
+    }
+
+    #[test]
+    fn boarding_doors_follow_interactive_points() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("INTERACTIVE POINT OPEN:1", Ratio::new::<percent>(100.));
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.doors.boarding_door_open_amounts()),
+            [
+                Ratio::default(),
+                Ratio::new::<percent>(100.),
+                Ratio::default()
+            ]
+        );
+    }
+
+    #[test]
+    fn opening_boarding_door_in_pressurised_cabin_raises_residual_pressure_warning() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.cabin_pressure.differential_pressure = Pressure::new::<hectopascal>(20.)
+        });
+        test_bed.write_by_name("INTERACTIVE POINT OPEN:0", Ratio::new::<percent>(100.));
+        test_bed.run();
+
+        let residual_pressure: bool = test_bed.read_by_name("DOOR_FWD_LEFT_RESIDUAL_PRESSURE");
+        assert!(residual_pressure);
+        let is_closed: bool = test_bed.read_by_name("DOOR_FWD_LEFT_IS_CLOSED");
+        assert!(!is_closed);
+    }
+
+    #[test]
+    fn cargo_doors_report_mechanism_lock() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.cargo_doors.locked = false);
+        test_bed.run();
+
+        let fwd_cargo_door_locked: bool = test_bed.read_by_name("DOOR_FWD_CARGO_IS_LOCKED");
+        assert!(!fwd_cargo_door_locked);
+        let aft_cargo_door_locked: bool = test_bed.read_by_name("DOOR_AFT_CARGO_IS_LOCKED");
+        assert!(!aft_cargo_door_locked);
+    }
+}
@@ -391,6 +391,11 @@ impl A320EmergencyElectricalOverheadPanel {
         );
     }
 
+    /// The GEN 1 LINE SMOKE light is lit when smoke is detected in the avionics ventilation.
+    pub fn set_gen_1_line_smoke(&mut self, avionics_smoke_detected: bool) {
+        self.gen_1_line.set_fault(avionics_smoke_detected);
+    }
+
     fn generator_1_line_is_on(&self) -> bool {
         self.gen_1_line.is_on()
     }
@@ -26,7 +26,9 @@ use systems::{
         brake::{BrakeAssembly, BrakeFanPanel, BrakeProperties},
         brake_circuit::{
             AutobrakeDecelerationGovernor, AutobrakeMode, AutobrakePanel,
-            BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController,
+            AutobrakeRunwayOverrunProtection, BrakeAccumulatorCharacteristics, BrakeCircuit,
+            BrakeCircuitController, BrakingDistanceCalculator, BrakingPerformanceCharacteristics,
+            BtvDecelScheduler,
         },
         bypass_pin::BypassPin,
         cargo_doors::{CargoDoor, HydraulicDoorController},
@@ -36,9 +38,10 @@ use systems::{
         },
         landing_gear::{GearGravityExtension, GearSystemController, HydraulicGearSystem},
         linear_actuator::{
-            Actuator, BoundedLinearLength, ElectroHydrostaticPowered, HydraulicAssemblyController,
-            HydraulicLinearActuatorAssembly, HydraulicLocking, LinearActuatedRigidBodyOnHingeAxis,
-            LinearActuator, LinearActuatorCharacteristics, LinearActuatorMode,
+            Actuator, BoundedLinearLength, ElectroHydrostaticPowered, FlightControlServoFailures,
+            HydraulicAssemblyController, HydraulicLinearActuatorAssembly, HydraulicLocking,
+            LinearActuatedRigidBodyOnHingeAxis, LinearActuator, LinearActuatorCharacteristics,
+            LinearActuatorMode,
         },
         nose_steering::{
             SteeringActuator, SteeringAngleLimiter, SteeringController, SteeringRatioToAngle,
@@ -49,6 +52,7 @@ use systems::{
         rudder_control::{
             AngularPositioningController, RudderMechanicalControl, YawDamperActuatorController,
         },
+        runway_friction::TyreRunwayFriction,
         trimmable_horizontal_stabilizer::{
             ManualPitchTrimController, PitchTrimActuatorController,
             TrimmableHorizontalStabilizerAssembly,
@@ -65,12 +69,13 @@ use systems::{
     shared::{
         arinc429::SignStatus, interpolation, random_from_normal_distribution, random_from_range,
         update_iterator::MaxStepLoop, AdirsDiscreteOutputs, AdirsMeasurementOutputs,
-        AirbusElectricPumpId, AirbusEngineDrivenPumpId, ControllerSignal, DelayedFalseLogicGate,
-        DelayedPulseTrueLogicGate, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
-        EmergencyElectricalRatPushButton, EmergencyElectricalState, EmergencyGeneratorControlUnit,
-        EmergencyGeneratorPower, EngineFirePushButtons, GearWheel, HydraulicColor,
-        LandingGearHandle, LgciuInterface, LgciuWeightOnWheels, RamAirTurbineController,
-        ReservoirAirPressure, ReverserPosition, SectionPressure, TrimmableHorizontalStabilizer,
+        AirbusElectricPumpId, AirbusEngineDrivenPumpId, CargoDoorLocked, CargoDoorOpenAmount,
+        ControllerSignal, DelayedFalseLogicGate, DelayedPulseTrueLogicGate, DelayedTrueLogicGate,
+        ElectricalBusType, ElectricalBuses, EmergencyElectricalRatPushButton,
+        EmergencyElectricalState, EmergencyGeneratorControlUnit, EmergencyGeneratorPower,
+        EngineFirePushButtons, FlightControlSurface, GearWheel, HydraulicColor, LandingGearHandle,
+        LgciuInterface, LgciuWeightOnWheels, RamAirTurbineController, ReservoirAirPressure,
+        ReverserPosition, SectionPressure, TrimmableHorizontalStabilizer,
     },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -553,18 +558,11 @@ impl A320SpoilerFactory {
         let spoiler_4 = Self::new_a320_spoiler_element(context, id, 4);
         let spoiler_5 = Self::new_a320_spoiler_element(context, id, 5);
 
-        match id {
-            ActuatorSide::Left => SpoilerGroup::new(
-                context,
-                "LEFT",
-                [spoiler_1, spoiler_2, spoiler_3, spoiler_4, spoiler_5],
-            ),
-            ActuatorSide::Right => SpoilerGroup::new(
-                context,
-                "RIGHT",
-                [spoiler_1, spoiler_2, spoiler_3, spoiler_4, spoiler_5],
-            ),
-        }
+        SpoilerGroup::new(
+            context,
+            id,
+            [spoiler_1, spoiler_2, spoiler_3, spoiler_4, spoiler_5],
+        )
     }
 
     fn new_a320_spoiler_element(
@@ -1328,7 +1326,7 @@ impl A320GearSystemFactory {
         )
     }
 }
-struct A320PowerTransferUnitCharacteristics {
+pub struct A320PowerTransferUnitCharacteristics {
     efficiency: Ratio,
 
     deactivation_delta_pressure: Pressure,
@@ -1366,7 +1364,7 @@ impl A320PowerTransferUnitCharacteristics {
 
     const SHOT_TO_SHOT_VARIABILITY_PERCENT_RATIO: f64 = 0.05;
 
-    fn new_randomized() -> Self {
+    pub fn new_randomized() -> Self {
         let randomized_is_ptu_worn_out = Self::randomized_is_ptu_worn_out();
 
         Self {
@@ -1520,6 +1518,7 @@ pub(super) struct A320Hydraulic {
     braking_circuit_norm: BrakeCircuit,
     braking_circuit_altn: BrakeCircuit,
     braking_force: A320BrakingForce,
+    runway_friction: TyreRunwayFriction,
 
     flap_system: FlapSlatAssembly,
     slat_system: FlapSlatAssembly,
@@ -1566,6 +1565,8 @@ pub(super) struct A320Hydraulic {
     reversers_assembly: A320Reversers,
 }
 impl A320Hydraulic {
+    const MAIN_GEAR_TYRE_PRESSURE_PSI: f64 = 200.;
+
     const HIGH_PITCH_PTU_SOUND_DELTA_PRESS_THRESHOLD_PSI: f64 = 2400.;
     const HIGH_PITCH_PTU_SOUND_DURATION: Duration = Duration::from_millis(3000);
 
@@ -1760,6 +1761,10 @@ impl A320Hydraulic {
             ),
 
             braking_force: A320BrakingForce::new(context),
+            runway_friction: TyreRunwayFriction::new(
+                context,
+                Pressure::new::<psi>(Self::MAIN_GEAR_TYRE_PRESSURE_PSI),
+            ),
 
             flap_system: FlapSlatAssembly::new(
                 context,
@@ -2084,6 +2089,12 @@ impl A320Hydraulic {
             ],
         );
 
+        self.aileron_system_controller.update_servo_failures(
+            context,
+            self.left_aileron.position(),
+            self.right_aileron.position(),
+        );
+
         self.left_aileron.update(
             context,
             self.aileron_system_controller.left_controllers(),
@@ -2098,6 +2109,12 @@ impl A320Hydraulic {
             self.green_circuit.system_section(),
         );
 
+        self.elevator_system_controller.update_servo_failures(
+            context,
+            self.left_elevator.position(),
+            self.right_elevator.position(),
+        );
+
         self.left_elevator.update(
             context,
             self.elevator_system_controller.left_controllers(),
@@ -2114,6 +2131,9 @@ impl A320Hydraulic {
             &self.trim_assembly,
         );
 
+        self.rudder_mechanical_assembly
+            .update_servo_failures(context, self.rudder.position());
+
         self.rudder.update(
             context,
             self.rudder_mechanical_assembly.rudder_controllers(),
@@ -2158,6 +2178,8 @@ impl A320Hydraulic {
         engine2: &impl Engine,
         adirs: &impl AdirsMeasurementOutputs,
     ) {
+        self.runway_friction.update(context);
+
         self.nose_steering.update(
             context,
             self.yellow_circuit.system_section(),
@@ -2177,6 +2199,7 @@ impl A320Hydraulic {
             brake_fan_panel,
             engine1,
             engine2,
+            &self.runway_friction,
             self.left_brake_assembly.brake_temperature_sensors(),
             self.right_brake_assembly.brake_temperature_sensors(),
         );
@@ -2228,6 +2251,8 @@ impl A320Hydraulic {
             &self.braking_circuit_altn,
             engine1,
             engine2,
+            &self.runway_friction,
+            self.brake_steer_computer.anti_skid_is_active(),
         );
 
         self.slats_flaps_complex.update(
@@ -2664,6 +2689,23 @@ impl A320Hydraulic {
         self.brake_steer_computer.brakes_hot()
     }
 }
+impl CargoDoorLocked for A320Hydraulic {
+    fn fwd_cargo_door_locked(&self) -> bool {
+        self.forward_cargo_door.is_locked()
+    }
+    fn aft_cargo_door_locked(&self) -> bool {
+        self.aft_cargo_door.is_locked()
+    }
+}
+impl CargoDoorOpenAmount for A320Hydraulic {
+    fn fwd_cargo_door_open_amount(&self) -> Ratio {
+        self.forward_cargo_door.position()
+    }
+    fn aft_cargo_door_open_amount(&self) -> Ratio {
+        self.aft_cargo_door.position()
+    }
+}
+
 impl SimulationElement for A320Hydraulic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.engine_driven_pump_1.accept(visitor);
@@ -2702,6 +2744,7 @@ impl SimulationElement for A320Hydraulic {
         self.braking_circuit_norm.accept(visitor);
         self.braking_circuit_altn.accept(visitor);
         self.braking_force.accept(visitor);
+        self.runway_friction.accept(visitor);
 
         self.emergency_gen.accept(visitor);
         self.nose_steering.accept(visitor);
@@ -3812,6 +3855,10 @@ impl A320HydraulicBrakeSteerComputerUnit {
         self.anti_skid_activated && self.normal_brakes_available
     }
 
+    fn anti_skid_is_active(&self) -> bool {
+        self.anti_skid_activated
+    }
+
     fn update_normal_braking_availability(&mut self, normal_braking_circuit_pressure: Pressure) {
         if normal_braking_circuit_pressure.get::<psi>() > Self::MIN_PRESSURE_BRAKE_ALTN_HYST_HI
             && (self.left_brake_pilot_input.get::<ratio>() < Self::PILOT_INPUT_DETECTION_TRESHOLD
@@ -3867,6 +3914,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
         brake_fan_panel: &BrakeFanPanel,
         engine1: &impl Engine,
         engine2: &impl Engine,
+        runway_friction: &TyreRunwayFriction,
         left_brake_temperature_sensors: &[TemperatureSensor; 2],
         right_brake_temperature_sensors: &[TemperatureSensor; 2],
     ) {
@@ -3893,6 +3941,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
             self.right_brake_pilot_input,
             lgciu1,
             lgciu2,
+            runway_friction.max_braking_deceleration(),
         );
 
         let is_in_flight_gear_lever_up = !(lgciu1.left_and_right_gear_compressed(true)
@@ -4133,6 +4182,8 @@ impl A320BrakingForce {
         altn_brakes: &BrakeCircuit,
         engine1: &impl Engine,
         engine2: &impl Engine,
+        runway_friction: &TyreRunwayFriction,
+        anti_skid_is_active: bool,
     ) {
         // Base formula for output force is output_force[0:1] = 50 * sqrt(current_pressure) / Max_brake_pressure
         // This formula gives a bit more punch for lower brake pressures (like 1000 psi alternate braking), as linear formula
@@ -4152,6 +4203,12 @@ impl A320BrakingForce {
         self.right_braking_force = right_force_norm + right_force_altn;
         self.right_braking_force = self.right_braking_force.clamp(0., 1.);
 
+        // Tyres can't transmit more braking force than the runway friction allows
+        self.left_braking_force =
+            runway_friction.braking_force(self.left_braking_force, anti_skid_is_active);
+        self.right_braking_force =
+            runway_friction.braking_force(self.right_braking_force, anti_skid_is_active);
+
         self.correct_with_flaps_state(context);
 
         self.update_chocks_braking(context, engine1, engine2);
@@ -4215,7 +4272,9 @@ impl SimulationElement for A320BrakingForce {
 }
 
 /// Autobrake controller computes the state machine of the autobrake logic, and the deceleration target
-/// that we expect for the plane
+/// that we expect for the plane.
+/// With the optional brake to vacate (BTV) and runway overrun protection (ROW/ROP) installed, pressing LO
+/// while a BTV exit is selected arms BTV, which then schedules the deceleration down to the selected exit.
 pub struct A320AutobrakeController {
     armed_mode_id: VariableIdentifier,
     armed_mode_id_set: VariableIdentifier,
@@ -4225,6 +4284,8 @@ pub struct A320AutobrakeController {
     ground_spoilers_out_sec2_id: VariableIdentifier,
     ground_spoilers_out_sec3_id: VariableIdentifier,
     external_disarm_event_id: VariableIdentifier,
+    btv_rop_installed_id: VariableIdentifier,
+    btv_appr_different_runway_id: VariableIdentifier,
 
     deceleration_governor: AutobrakeDecelerationGovernor,
 
@@ -4242,6 +4303,14 @@ pub struct A320AutobrakeController {
     should_reject_max_mode_after_time_in_flight: DelayedTrueLogicGate,
 
     external_disarm_event: bool,
+
+    btv_rop_installed: bool,
+    btv_appr_different_runway: bool,
+    btv_is_selected: bool,
+
+    btv_scheduler: BtvDecelScheduler,
+    braking_distance_calculator: BrakingDistanceCalculator,
+    autobrake_runway_overrun_protection: AutobrakeRunwayOverrunProtection,
 }
 impl A320AutobrakeController {
     const DURATION_OF_FLIGHT_TO_DISARM_AUTOBRAKE_SECS: f64 = 10.;
@@ -4259,7 +4328,22 @@ impl A320AutobrakeController {
     const MARGIN_PERCENT_TO_TARGET_TO_SHOW_DECEL_IN_LO_MED: f64 = 80.;
     const TARGET_TO_SHOW_DECEL_IN_MAX_MS2: f64 = -2.7;
 
+    // Offset for the BTV stop bar so it shows at front of the plane instead of its reference position
+    const OFFSET_PLANE_REF_POINT_TO_FRONT_METERS: f64 = 18.;
+    const ESTIMATED_MAX_DECEL_DRY_MS2: f64 = -3.;
+    const ESTIMATED_MAX_DECEL_WET_MS2: f64 = -2.;
+    const BTV_MAX_DECEL_DRY_MS2: f64 = -3.;
+    const BTV_MAX_DECEL_WET_MS2: f64 = -2.;
+
     fn new(context: &mut InitContext) -> A320AutobrakeController {
+        let braking_performance = BrakingPerformanceCharacteristics::new(
+            Length::new::<meter>(Self::OFFSET_PLANE_REF_POINT_TO_FRONT_METERS),
+            Acceleration::new::<meter_per_second_squared>(Self::ESTIMATED_MAX_DECEL_DRY_MS2),
+            Acceleration::new::<meter_per_second_squared>(Self::ESTIMATED_MAX_DECEL_WET_MS2),
+            Acceleration::new::<meter_per_second_squared>(Self::BTV_MAX_DECEL_DRY_MS2),
+            Acceleration::new::<meter_per_second_squared>(Self::BTV_MAX_DECEL_WET_MS2),
+        );
+
         A320AutobrakeController {
             armed_mode_id: context.get_identifier("AUTOBRAKES_ARMED_MODE".to_owned()),
             armed_mode_id_set: context.get_identifier("AUTOBRAKES_ARMED_MODE_SET".to_owned()),
@@ -4272,6 +4356,9 @@ impl A320AutobrakeController {
             ground_spoilers_out_sec3_id: context
                 .get_identifier("SEC_3_GROUND_SPOILER_OUT".to_owned()),
             external_disarm_event_id: context.get_identifier("AUTOBRAKE_DISARM".to_owned()),
+            btv_rop_installed_id: context.get_identifier("CONFIG_BTV_ROP_INSTALLED".to_owned()),
+            btv_appr_different_runway_id: context
+                .get_identifier("BTV_APPR_DIFFERENT_RUNWAY".to_owned()),
 
             deceleration_governor: AutobrakeDecelerationGovernor::new(),
             target: Acceleration::new::<meter_per_second_squared>(0.),
@@ -4290,6 +4377,17 @@ impl A320AutobrakeController {
             )
             .starting_as(context.is_in_flight()),
             external_disarm_event: false,
+
+            btv_rop_installed: false,
+            btv_appr_different_runway: false,
+            btv_is_selected: false,
+
+            btv_scheduler: BtvDecelScheduler::new(context, braking_performance),
+            braking_distance_calculator: BrakingDistanceCalculator::new(
+                context,
+                braking_performance,
+            ),
+            autobrake_runway_overrun_protection: AutobrakeRunwayOverrunProtection::new(context, 2),
         }
     }
 
@@ -4298,7 +4396,19 @@ impl A320AutobrakeController {
     }
 
     fn brake_output(&self) -> Ratio {
-        Ratio::new::<ratio>(self.deceleration_governor.output())
+        if self.btv_rop_installed
+            && self
+                .autobrake_runway_overrun_protection
+                .rop_max_braking_requested()
+        {
+            Ratio::new::<ratio>(1.)
+        } else {
+            Ratio::new::<ratio>(self.deceleration_governor.output())
+        }
+    }
+
+    fn brake_to_vacate_is_selected(&self) -> bool {
+        self.btv_is_selected && self.mode == AutobrakeMode::LOW
     }
 
     fn determine_mode(
@@ -4385,11 +4495,37 @@ impl A320AutobrakeController {
             || self.external_disarm_event
             || (self.mode == AutobrakeMode::MAX
                 && self.should_reject_max_mode_after_time_in_flight.output())
+            || (self.brake_to_vacate_is_selected() && !self.btv_scheduler.is_armed())
+    }
+
+    fn btv_should_revert_to_low(&self) -> bool {
+        self.brake_to_vacate_is_selected()
+            && (self.btv_scheduler.is_lost() || self.btv_appr_different_runway)
+    }
+
+    fn update_brake_to_vacate_selection(&mut self, lo_mode_was_armed: bool) {
+        if self.mode != AutobrakeMode::LOW {
+            self.btv_is_selected = false;
+        } else if !lo_mode_was_armed && self.btv_rop_installed {
+            self.btv_scheduler.enable();
+            self.btv_is_selected = self.btv_scheduler.is_armed();
+        }
+
+        if self.btv_should_revert_to_low() {
+            self.btv_is_selected = false;
+        }
+
+        if !self.btv_is_selected {
+            self.btv_scheduler.disarm();
+        }
     }
 
     fn calculate_target(&mut self) -> Acceleration {
         Acceleration::new::<meter_per_second_squared>(match self.mode {
             AutobrakeMode::NONE => Self::OFF_MODE_DECEL_TARGET_MS2,
+            AutobrakeMode::LOW if self.brake_to_vacate_is_selected() => {
+                self.btv_scheduler.decel().get::<meter_per_second_squared>()
+            }
             AutobrakeMode::LOW => interpolation(
                 &Self::LOW_MODE_DECEL_PROFILE_TIME_S,
                 &Self::LOW_MODE_DECEL_PROFILE_ACCEL_MS2,
@@ -4437,6 +4573,7 @@ impl A320AutobrakeController {
         pedal_input_right: Ratio,
         lgciu1: &impl LgciuInterface,
         lgciu2: &impl LgciuInterface,
+        max_braking_deceleration: Option<Acceleration>,
     ) {
         self.update_input_conditions(
             context,
@@ -4446,16 +4583,62 @@ impl A320AutobrakeController {
             lgciu1,
             lgciu2,
         );
+
+        if self.btv_rop_installed {
+            self.braking_distance_calculator.update_braking_estimations(
+                context,
+                if self.brake_to_vacate_is_selected() {
+                    self.btv_scheduler.predicted_decel()
+                } else if self.is_armed() {
+                    context.long_accel()
+                } else {
+                    Acceleration::default()
+                },
+                max_braking_deceleration,
+            );
+        }
+
+        let lo_mode_was_armed = self.mode == AutobrakeMode::LOW;
         self.mode = self.determine_mode(context, autobrake_panel);
+        self.update_brake_to_vacate_selection(lo_mode_was_armed);
 
         self.deceleration_governor
             .engage_when(self.should_engage_deceleration_governor(context));
 
-        self.target = self.calculate_target();
+        // Deceleration target is limited to what a contaminated runway allows, so the brakes don't
+        // keep pushing for an unreachable target
+        self.target = max_braking_deceleration.map_or(self.calculate_target(), |limit| {
+            self.calculate_target().max(limit)
+        });
         self.deceleration_governor.update(context, self.target);
+
+        if self.btv_rop_installed {
+            self.btv_scheduler.update(
+                context,
+                self.ground_spoilers_are_deployed,
+                &self.braking_distance_calculator,
+                &self.autobrake_runway_overrun_protection,
+            );
+
+            self.autobrake_runway_overrun_protection.update(
+                context,
+                self.deceleration_governor.is_engaged(),
+                &self.braking_distance_calculator,
+                lgciu1,
+                lgciu2,
+            );
+        }
     }
 }
 impl SimulationElement for A320AutobrakeController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.braking_distance_calculator.accept(visitor);
+        self.btv_scheduler.accept(visitor);
+        self.autobrake_runway_overrun_protection.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.armed_mode_id, self.mode as u8 as f64);
         writer.write(&self.armed_mode_id_set, -1.);
@@ -4472,6 +4655,8 @@ impl SimulationElement for A320AutobrakeController {
             && (sec_3_gnd_splrs_out || sec_2_gnd_splrs_out))
             || (sec_2_gnd_splrs_out && sec_3_gnd_splrs_out);
         self.external_disarm_event = reader.read(&self.external_disarm_event_id);
+        self.btv_rop_installed = reader.read(&self.btv_rop_installed_id);
+        self.btv_appr_different_runway = reader.read(&self.btv_appr_different_runway_id);
 
         // Reading current mode in sim to initialize correct mode if sim changes it (from .FLT files for example)
         let readed_mode = reader.read_f64(&self.armed_mode_id_set);
@@ -4615,13 +4800,17 @@ impl SimulationElement for A320HydraulicOverheadPanel {
 struct AileronController {
     mode: LinearActuatorMode,
     requested_position: Ratio,
+
+    servo_failures: FlightControlServoFailures,
 }
 impl AileronController {
-    fn new() -> Self {
+    fn new(surface: FlightControlSurface, circuit: HydraulicColor) -> Self {
         Self {
             mode: LinearActuatorMode::ClosedCircuitDamping,
 
             requested_position: Ratio::new::<ratio>(0.),
+
+            servo_failures: FlightControlServoFailures::new(surface, circuit),
         }
     }
 
@@ -4635,22 +4824,46 @@ impl AileronController {
             .min(Ratio::new::<ratio>(1.))
             .max(Ratio::new::<ratio>(0.));
     }
+
+    fn update_servo_failures(&mut self, context: &UpdateContext, surface_position: Ratio) {
+        self.servo_failures.update(
+            context,
+            self.mode,
+            self.requested_position,
+            surface_position,
+        );
+    }
+
+    fn is_servo_failure_detected(&self) -> bool {
+        self.servo_failures.is_failure_detected()
+    }
 }
 impl HydraulicAssemblyController for AileronController {
     fn requested_mode(&self) -> LinearActuatorMode {
-        self.mode
+        self.servo_failures.mode(self.mode)
     }
 
     fn requested_position(&self) -> Ratio {
-        self.requested_position
+        self.servo_failures.position(self.requested_position)
     }
 
     fn should_lock(&self) -> bool {
-        false
+        self.servo_failures.is_jammed()
     }
 
     fn requested_lock_position(&self) -> Ratio {
-        Ratio::default()
+        self.servo_failures.jam_position()
+    }
+
+    fn has_internal_leakage(&self) -> bool {
+        self.servo_failures.has_internal_leakage()
+    }
+}
+impl SimulationElement for AileronController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.servo_failures.accept(visitor);
+
+        visitor.visit(self);
     }
 }
 impl HydraulicLocking for AileronController {}
@@ -4667,6 +4880,10 @@ struct AileronSystemHydraulicController {
     left_aileron_green_actuator_position_demand_id: VariableIdentifier,
     right_aileron_green_actuator_position_demand_id: VariableIdentifier,
 
+    // Servo failure ids are in servo number order as monitored by ELAC1 and ELAC2
+    left_aileron_servo_failed_ids: [VariableIdentifier; 2],
+    right_aileron_servo_failed_ids: [VariableIdentifier; 2],
+
     left_aileron_controllers: [AileronController; 2],
     right_aileron_controllers: [AileronController; 2],
 }
@@ -4691,9 +4908,39 @@ impl AileronSystemHydraulicController {
             right_aileron_green_actuator_position_demand_id: context
                 .get_identifier("RIGHT_AIL_GREEN_COMMANDED_POSITION".to_owned()),
 
+            left_aileron_servo_failed_ids: [
+                context.get_identifier("LEFT_AIL_SERVO_1_FAILED".to_owned()),
+                context.get_identifier("LEFT_AIL_SERVO_2_FAILED".to_owned()),
+            ],
+            right_aileron_servo_failed_ids: [
+                context.get_identifier("RIGHT_AIL_SERVO_1_FAILED".to_owned()),
+                context.get_identifier("RIGHT_AIL_SERVO_2_FAILED".to_owned()),
+            ],
+
             // Controllers are in outward->inward order, so for aileron [Blue circuit, Green circuit]
-            left_aileron_controllers: [AileronController::new(), AileronController::new()],
-            right_aileron_controllers: [AileronController::new(), AileronController::new()],
+            left_aileron_controllers: [
+                AileronController::new(FlightControlSurface::LeftAileron, HydraulicColor::Blue),
+                AileronController::new(FlightControlSurface::LeftAileron, HydraulicColor::Green),
+            ],
+            right_aileron_controllers: [
+                AileronController::new(FlightControlSurface::RightAileron, HydraulicColor::Blue),
+                AileronController::new(FlightControlSurface::RightAileron, HydraulicColor::Green),
+            ],
+        }
+    }
+
+    fn update_servo_failures(
+        &mut self,
+        context: &UpdateContext,
+        left_aileron_position: Ratio,
+        right_aileron_position: Ratio,
+    ) {
+        for controller in &mut self.left_aileron_controllers {
+            controller.update_servo_failures(context, left_aileron_position);
+        }
+
+        for controller in &mut self.right_aileron_controllers {
+            controller.update_servo_failures(context, right_aileron_position);
         }
     }
 
@@ -4789,6 +5036,37 @@ impl AileronSystemHydraulicController {
     }
 }
 impl SimulationElement for AileronSystemHydraulicController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.left_aileron_controllers, visitor);
+        accept_iterable!(self.right_aileron_controllers, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        // ELAC1 monitors left blue and right green servos, ELAC2 left green and right blue ones
+        writer.write(
+            &self.left_aileron_servo_failed_ids[0],
+            self.left_aileron_controllers[AileronActuatorPosition::Blue as usize]
+                .is_servo_failure_detected(),
+        );
+        writer.write(
+            &self.left_aileron_servo_failed_ids[1],
+            self.left_aileron_controllers[AileronActuatorPosition::Green as usize]
+                .is_servo_failure_detected(),
+        );
+        writer.write(
+            &self.right_aileron_servo_failed_ids[0],
+            self.right_aileron_controllers[AileronActuatorPosition::Green as usize]
+                .is_servo_failure_detected(),
+        );
+        writer.write(
+            &self.right_aileron_servo_failed_ids[1],
+            self.right_aileron_controllers[AileronActuatorPosition::Blue as usize]
+                .is_servo_failure_detected(),
+        );
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         // Note that we reverse left, as positions are just passed through msfs for now
         self.update_aileron_controllers_positions(
@@ -4834,6 +5112,10 @@ struct ElevatorSystemHydraulicController {
     left_elevator_green_actuator_position_demand_id: VariableIdentifier,
     right_elevator_yellow_actuator_position_demand_id: VariableIdentifier,
 
+    // Servo failure ids are in servo number order as monitored by ELAC1 and ELAC2
+    left_elevator_servo_failed_ids: [VariableIdentifier; 2],
+    right_elevator_servo_failed_ids: [VariableIdentifier; 2],
+
     left_controllers: [AileronController; 2],
     right_controllers: [AileronController; 2],
 }
@@ -4858,9 +5140,39 @@ impl ElevatorSystemHydraulicController {
             right_elevator_yellow_actuator_position_demand_id: context
                 .get_identifier("RIGHT_ELEV_YELLOW_COMMANDED_POSITION".to_owned()),
 
+            left_elevator_servo_failed_ids: [
+                context.get_identifier("LEFT_ELEV_SERVO_1_FAILED".to_owned()),
+                context.get_identifier("LEFT_ELEV_SERVO_2_FAILED".to_owned()),
+            ],
+            right_elevator_servo_failed_ids: [
+                context.get_identifier("RIGHT_ELEV_SERVO_1_FAILED".to_owned()),
+                context.get_identifier("RIGHT_ELEV_SERVO_2_FAILED".to_owned()),
+            ],
+
             // Controllers are in outboard->inboard order
-            left_controllers: [AileronController::new(), AileronController::new()],
-            right_controllers: [AileronController::new(), AileronController::new()],
+            left_controllers: [
+                AileronController::new(FlightControlSurface::LeftElevator, HydraulicColor::Blue),
+                AileronController::new(FlightControlSurface::LeftElevator, HydraulicColor::Green),
+            ],
+            right_controllers: [
+                AileronController::new(FlightControlSurface::RightElevator, HydraulicColor::Blue),
+                AileronController::new(FlightControlSurface::RightElevator, HydraulicColor::Yellow),
+            ],
+        }
+    }
+
+    fn update_servo_failures(
+        &mut self,
+        context: &UpdateContext,
+        left_elevator_position: Ratio,
+        right_elevator_position: Ratio,
+    ) {
+        for controller in &mut self.left_controllers {
+            controller.update_servo_failures(context, left_elevator_position);
+        }
+
+        for controller in &mut self.right_controllers {
+            controller.update_servo_failures(context, right_elevator_position);
         }
     }
 
@@ -4951,6 +5263,37 @@ impl ElevatorSystemHydraulicController {
     }
 }
 impl SimulationElement for ElevatorSystemHydraulicController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.left_controllers, visitor);
+        accept_iterable!(self.right_controllers, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        // ELAC1 monitors blue servos, ELAC2 green and yellow ones
+        writer.write(
+            &self.left_elevator_servo_failed_ids[0],
+            self.left_controllers[LeftElevatorActuatorCircuit::Blue as usize]
+                .is_servo_failure_detected(),
+        );
+        writer.write(
+            &self.left_elevator_servo_failed_ids[1],
+            self.left_controllers[LeftElevatorActuatorCircuit::Green as usize]
+                .is_servo_failure_detected(),
+        );
+        writer.write(
+            &self.right_elevator_servo_failed_ids[0],
+            self.right_controllers[RightElevatorActuatorCircuit::Blue as usize]
+                .is_servo_failure_detected(),
+        );
+        writer.write(
+            &self.right_elevator_servo_failed_ids[1],
+            self.right_controllers[RightElevatorActuatorCircuit::Yellow as usize]
+                .is_servo_failure_detected(),
+        );
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.update_elevator_controllers_positions(
             [
@@ -5174,9 +5517,9 @@ impl RudderSystemHydraulicController {
 
             // Controllers are in [ Green circuit, Blue circuit, Yellow circuit] order
             rudder_controllers: [
-                AileronController::new(),
-                AileronController::new(),
-                AileronController::new(),
+                AileronController::new(FlightControlSurface::Rudder, HydraulicColor::Green),
+                AileronController::new(FlightControlSurface::Rudder, HydraulicColor::Blue),
+                AileronController::new(FlightControlSurface::Rudder, HydraulicColor::Yellow),
             ],
         }
     }
@@ -5313,6 +5656,12 @@ impl RudderSystemHydraulicController {
         self.update_rudder_control_state();
     }
 
+    fn update_servo_failures(&mut self, context: &UpdateContext, rudder_position: Ratio) {
+        for controller in &mut self.rudder_controllers {
+            controller.update_servo_failures(context, rudder_position);
+        }
+    }
+
     fn rudder_controllers(
         &self,
     ) -> &[impl HydraulicAssemblyController + HydraulicLocking + ElectroHydrostaticPowered] {
@@ -5330,6 +5679,7 @@ impl RudderSystemHydraulicController {
 impl SimulationElement for RudderSystemHydraulicController {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.yaw_damper_control, visitor);
+        accept_iterable!(self.rudder_controllers, visitor);
         self.trim_control.accept(visitor);
         self.travel_limiter_control.accept(visitor);
         self.rudder_mechanical_assembly.accept(visitor);
@@ -5427,6 +5777,10 @@ impl AileronAssembly {
     ) {
         self.aerodynamic_model
             .update_body(context, self.hydraulic_assembly.body());
+        self.hydraulic_assembly.update_fluid_viscosity([
+            current_pressure_outward.fluid_viscosity(),
+            current_pressure_inward.fluid_viscosity(),
+        ]);
         self.hydraulic_assembly.update(
             context,
             aileron_controllers,
@@ -5438,6 +5792,10 @@ impl AileronAssembly {
 
         self.position = self.hydraulic_assembly.position_normalized();
     }
+
+    fn position(&self) -> Ratio {
+        self.position
+    }
 }
 impl SimulationElement for AileronAssembly {
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -5492,6 +5850,10 @@ impl ElevatorAssembly {
 
         self.aerodynamic_model
             .update_body(context, self.hydraulic_assembly.body());
+        self.hydraulic_assembly.update_fluid_viscosity([
+            current_pressure_outward.fluid_viscosity(),
+            current_pressure_inward.fluid_viscosity(),
+        ]);
         self.hydraulic_assembly.update(
             context,
             elevator_controllers,
@@ -5503,6 +5865,10 @@ impl ElevatorAssembly {
 
         self.position = self.hydraulic_assembly.position_normalized();
     }
+
+    fn position(&self) -> Ratio {
+        self.position
+    }
 }
 impl SimulationElement for ElevatorAssembly {
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -5552,6 +5918,11 @@ impl RudderAssembly {
         self.aerodynamic_model
             .update_body(context, self.hydraulic_assembly.body());
 
+        self.hydraulic_assembly.update_fluid_viscosity([
+            current_pressure_green.fluid_viscosity(),
+            current_pressure_blue.fluid_viscosity(),
+            current_pressure_yellow.fluid_viscosity(),
+        ]);
         self.hydraulic_assembly.update(
             context,
             rudder_controllers,
@@ -5564,6 +5935,10 @@ impl RudderAssembly {
 
         self.position = self.hydraulic_assembly.position_normalized();
     }
+
+    fn position(&self) -> Ratio {
+        self.position
+    }
 }
 impl SimulationElement for RudderAssembly {
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -5613,18 +5988,24 @@ impl SpoilerElement {
         spoiler_controller: &(impl HydraulicAssemblyController
               + HydraulicLocking
               + ElectroHydrostaticPowered),
-        current_pressure: Pressure,
+        current_pressure: &impl SectionPressure,
     ) {
         self.aerodynamic_model
             .update_body(context, self.hydraulic_assembly.body());
+        self.hydraulic_assembly
+            .update_fluid_viscosity([current_pressure.fluid_viscosity()]);
         self.hydraulic_assembly.update(
             context,
             std::slice::from_ref(spoiler_controller),
-            [current_pressure],
+            [current_pressure.pressure_downstream_leak_valve()],
         );
 
         self.position = self.hydraulic_assembly.position_normalized();
     }
+
+    fn position(&self) -> Ratio {
+        self.position
+    }
 }
 impl SimulationElement for SpoilerElement {
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -5637,15 +6018,15 @@ struct SpoilerGroup {
     hydraulic_controllers: [SpoilerController; 5],
 }
 impl SpoilerGroup {
-    fn new(context: &mut InitContext, spoiler_side: &str, spoilers: [SpoilerElement; 5]) -> Self {
+    fn new(context: &mut InitContext, id: ActuatorSide, spoilers: [SpoilerElement; 5]) -> Self {
         Self {
             spoilers,
             hydraulic_controllers: [
-                SpoilerController::new(context, spoiler_side, 1),
-                SpoilerController::new(context, spoiler_side, 2),
-                SpoilerController::new(context, spoiler_side, 3),
-                SpoilerController::new(context, spoiler_side, 4),
-                SpoilerController::new(context, spoiler_side, 5),
+                SpoilerController::new(context, id, 1, HydraulicColor::Green),
+                SpoilerController::new(context, id, 2, HydraulicColor::Yellow),
+                SpoilerController::new(context, id, 3, HydraulicColor::Blue),
+                SpoilerController::new(context, id, 4, HydraulicColor::Yellow),
+                SpoilerController::new(context, id, 5, HydraulicColor::Green),
             ],
         }
     }
@@ -5657,31 +6038,15 @@ impl SpoilerGroup {
         blue_section: &impl SectionPressure,
         yellow_section: &impl SectionPressure,
     ) {
-        self.spoilers[0].update(
-            context,
-            &self.hydraulic_controllers[0],
-            green_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[1].update(
-            context,
-            &self.hydraulic_controllers[1],
-            yellow_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[2].update(
-            context,
-            &self.hydraulic_controllers[2],
-            blue_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[3].update(
-            context,
-            &self.hydraulic_controllers[3],
-            yellow_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[4].update(
-            context,
-            &self.hydraulic_controllers[4],
-            green_section.pressure_downstream_leak_valve(),
-        );
+        for (controller, spoiler) in self.hydraulic_controllers.iter_mut().zip(&self.spoilers) {
+            controller.update_servo_failures(context, spoiler.position());
+        }
+
+        self.spoilers[0].update(context, &self.hydraulic_controllers[0], green_section);
+        self.spoilers[1].update(context, &self.hydraulic_controllers[1], yellow_section);
+        self.spoilers[2].update(context, &self.hydraulic_controllers[2], blue_section);
+        self.spoilers[3].update(context, &self.hydraulic_controllers[3], yellow_section);
+        self.spoilers[4].update(context, &self.hydraulic_controllers[4], green_section);
     }
 
     fn actuator(&mut self, spoiler_id: usize) -> &mut impl Actuator {
@@ -5704,42 +6069,91 @@ impl SimulationElement for SpoilerGroup {
 
 struct SpoilerController {
     position_demand_id: VariableIdentifier,
+    servo_failed_id: VariableIdentifier,
     requested_position: Ratio,
+
+    servo_failures: FlightControlServoFailures,
 }
 impl SpoilerController {
-    fn new(context: &mut InitContext, spoiler_side: &str, spoiler_id_number: usize) -> Self {
+    fn new(
+        context: &mut InitContext,
+        id: ActuatorSide,
+        spoiler_id_number: usize,
+        circuit: HydraulicColor,
+    ) -> Self {
+        let (spoiler_side, surface) = match id {
+            ActuatorSide::Left => ("LEFT", FlightControlSurface::LeftSpoiler(spoiler_id_number)),
+            ActuatorSide::Right => (
+                "RIGHT",
+                FlightControlSurface::RightSpoiler(spoiler_id_number),
+            ),
+        };
+
         Self {
             position_demand_id: context.get_identifier(format!(
                 "{}_SPOILER_{}_COMMANDED_POSITION",
                 spoiler_side, spoiler_id_number
             )),
+            servo_failed_id: context.get_identifier(format!(
+                "{}_SPLR_{}_SERVO_FAILED",
+                spoiler_side, spoiler_id_number
+            )),
 
             requested_position: Ratio::new::<ratio>(0.),
+
+            servo_failures: FlightControlServoFailures::new(surface, circuit),
         }
     }
 
+    fn update_servo_failures(&mut self, context: &UpdateContext, surface_position: Ratio) {
+        self.servo_failures.update(
+            context,
+            LinearActuatorMode::PositionControl,
+            self.requested_position,
+            surface_position,
+        );
+    }
+
     fn spoiler_actuator_position_from_surface_angle(surface_angle: Angle) -> Ratio {
         Ratio::new::<ratio>((surface_angle.get::<degree>() / 50.).clamp(0., 1.))
     }
 }
 impl HydraulicAssemblyController for SpoilerController {
     fn requested_mode(&self) -> LinearActuatorMode {
-        LinearActuatorMode::PositionControl
+        self.servo_failures
+            .mode(LinearActuatorMode::PositionControl)
     }
 
     fn requested_position(&self) -> Ratio {
-        self.requested_position
+        self.servo_failures.position(self.requested_position)
     }
 
     fn should_lock(&self) -> bool {
-        false
+        self.servo_failures.is_jammed()
     }
 
     fn requested_lock_position(&self) -> Ratio {
-        Ratio::default()
+        self.servo_failures.jam_position()
+    }
+
+    fn has_internal_leakage(&self) -> bool {
+        self.servo_failures.has_internal_leakage()
     }
 }
 impl SimulationElement for SpoilerController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.servo_failures.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.servo_failed_id,
+            self.servo_failures.is_failure_detected(),
+        );
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.requested_position =
             Self::spoiler_actuator_position_from_surface_angle(Angle::new::<degree>(
@@ -6047,6 +6461,7 @@ impl A320Reversers {
 
             reversers: [
                 ReverserAssembly::new(
+                    1,
                     Pressure::new::<psi>(
                         A320HydraulicCircuitFactory::HYDRAULIC_TARGET_PRESSURE_PSI,
                     ),
@@ -6061,6 +6476,7 @@ impl A320Reversers {
                     Self::REVERSER_1_SECONDARY_VALVES_SUPPLY_POWER_BUS,
                 ),
                 ReverserAssembly::new(
+                    2,
                     Pressure::new::<psi>(
                         A320HydraulicCircuitFactory::HYDRAULIC_TARGET_PRESSURE_PSI,
                     ),
@@ -6175,11 +6591,12 @@ mod tests {
             hydraulic::{
                 cargo_doors::{DoorControlState, HydraulicDoorController},
                 electrical_generator::TestGenerator,
+                runway_friction::RunwayContaminant,
             },
             landing_gear::{GearSystemState, LandingGear, LandingGearControlInterfaceUnitSet},
             shared::{
                 arinc429::Arinc429Word, EmergencyElectricalState, EmergencyGeneratorControlUnit,
-                LgciuId, PotentialOrigin,
+                HydraulicLeakLocation, LgciuId, PotentialOrigin,
             },
             simulation::{
                 test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -6192,7 +6609,7 @@ mod tests {
                 angle::degree,
                 angular_velocity::degree_per_second,
                 electric_potential::volt,
-                length::foot,
+                length::{foot, meter},
                 mass_density::kilogram_per_cubic_meter,
                 ratio::{percent, ratio},
                 volume::liter,
@@ -6484,6 +6901,13 @@ mod tests {
                 self.hydraulics.gcu.is_at_nominal_speed()
             }
 
+            fn autobrake_target(&self) -> Acceleration {
+                self.hydraulics
+                    .brake_steer_computer
+                    .autobrake_controller
+                    .target
+            }
+
             fn is_green_edp_commanded_on(&self) -> bool {
                 self.hydraulics
                     .engine_driven_pump_1_controller
@@ -6545,6 +6969,10 @@ mod tests {
                 self.hydraulics.power_transfer_unit.is_enabled()
             }
 
+            fn yellow_epump_wear(&self) -> Ratio {
+                self.hydraulics.yellow_electric_pump.wear()
+            }
+
             fn is_blue_pressure_switch_pressurised(&self) -> bool {
                 self.hydraulics.is_blue_pressure_switch_pressurised()
             }
@@ -6736,6 +7164,10 @@ mod tests {
                 self
             }
 
+            fn autobrake_target(&self) -> Acceleration {
+                self.query(|a| a.autobrake_target())
+            }
+
             fn is_green_edp_commanded_on(&self) -> bool {
                 self.query(|a| a.is_green_edp_commanded_on())
             }
@@ -6748,6 +7180,10 @@ mod tests {
                 self.query(|a| a.is_ptu_enabled())
             }
 
+            fn yellow_epump_wear(&self) -> Ratio {
+                self.query(|a| a.yellow_epump_wear())
+            }
+
             fn is_blue_pressure_switch_pressurised(&self) -> bool {
                 self.query(|a| a.is_blue_pressure_switch_pressurised())
             }
@@ -6938,6 +7374,17 @@ mod tests {
                 Ratio::new::<ratio>(self.read_by_name("HYD_RUD_DEFLECTION"))
             }
 
+            fn get_left_spoiler_position(&mut self, spoiler_id_number: usize) -> Ratio {
+                Ratio::new::<ratio>(self.read_by_name(&format!(
+                    "HYD_SPOILER_{}_LEFT_DEFLECTION",
+                    spoiler_id_number
+                )))
+            }
+
+            fn is_servo_failure_reported(&mut self, servo_failed_name: &str) -> bool {
+                self.read_by_name(servo_failed_name)
+            }
+
             fn get_nose_steering_ratio(&mut self) -> Ratio {
                 Ratio::new::<ratio>(self.read_by_name("NOSE_WHEEL_POSITION_RATIO"))
             }
@@ -7079,6 +7526,11 @@ mod tests {
                     .external_power(false)
             }
 
+            fn cold_soaked_at(mut self, ambient_temperature: ThermodynamicTemperature) -> Self {
+                self.set_ambient_temperature(ambient_temperature);
+                self
+            }
+
             fn sim_not_ready(mut self) -> Self {
                 self.set_sim_is_ready(false);
                 self
@@ -7488,6 +7940,53 @@ mod tests {
                 self
             }
 
+            fn with_btv_rop_installed(mut self) -> Self {
+                self.write_by_name("CONFIG_BTV_ROP_INSTALLED", true);
+                self
+            }
+
+            fn set_btv_exit_selected(mut self, runway_length: Length, exit: Length) -> Self {
+                self.write_arinc429_by_name(
+                    "OANS_RWY_LENGTH",
+                    runway_length.get::<meter>(),
+                    SignStatus::NormalOperation,
+                );
+                self.write_arinc429_by_name(
+                    "OANS_BTV_REQ_STOPPING_DISTANCE",
+                    exit.get::<meter>(),
+                    SignStatus::NormalOperation,
+                );
+                self
+            }
+
+            fn set_distance_to_runway_end(mut self, distance: Length) -> Self {
+                self.write_arinc429_by_name(
+                    "OANS_BTV_REMAINING_DIST_TO_RWY_END",
+                    distance.get::<meter>(),
+                    SignStatus::NormalOperation,
+                );
+                self
+            }
+
+            fn rolling_at(mut self, ground_speed: Velocity, acceleration: Acceleration) -> Self {
+                self.write_by_name("GPS GROUND SPEED", ground_speed.get::<knot>());
+                self.test_bed.set_long_acceleration(acceleration);
+                self
+            }
+
+            fn btv_state(&mut self) -> f64 {
+                self.read_by_name("BTV_STATE")
+            }
+
+            fn set_runway_contaminant(mut self, contaminant: RunwayContaminant) -> Self {
+                self.write_by_name("RUNWAY_CONTAMINANT_SELECTED", contaminant as u8 as f64);
+                self
+            }
+
+            fn brake_left_force_factor(&mut self) -> f64 {
+                self.read_by_name("BRAKE LEFT FORCE FACTOR")
+            }
+
             fn set_retract_ground_spoilers(mut self) -> Self {
                 self.write_by_name("SEC_1_GROUND_SPOILER_OUT", false);
                 self.write_by_name("SEC_2_GROUND_SPOILER_OUT", false);
@@ -9742,24 +10241,110 @@ mod tests {
         }
 
         #[test]
-        // Should disable with one pedal > 61° over max range of 79.4° thus 77%
-        fn autobrakes_max_disengage_at_77_on_one_pedal_input() {
+        fn autobrakes_low_arms_btv_when_installed_and_exit_selected() {
             let mut test_bed = test_bed_on_ground_with()
                 .set_cold_dark_inputs()
                 .on_the_ground()
-                .set_park_brake(false)
-                .start_eng1(Ratio::new::<percent>(100.))
-                .start_eng2(Ratio::new::<percent>(100.))
+                .with_btv_rop_installed()
+                .set_btv_exit_selected(Length::new::<meter>(3000.), Length::new::<meter>(1800.))
+                .start_eng1(Ratio::new::<percent>(60.))
+                .start_eng2(Ratio::new::<percent>(60.))
                 .run_waiting_for(Duration::from_secs(3));
 
             test_bed = test_bed
-                .set_autobrake_max()
-                .set_deploy_ground_spoilers()
-                .run_waiting_for(Duration::from_secs(6));
-
-            assert!(test_bed.autobrake_mode() == AutobrakeMode::MAX);
-            assert!(test_bed.get_brake_left_green_pressure() > Pressure::new::<psi>(1000.));
-            assert!(test_bed.get_brake_right_green_pressure() > Pressure::new::<psi>(1000.));
+                .set_autobrake_low()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::LOW);
+            assert!(test_bed.btv_state() == 1.);
+        }
+
+        #[test]
+        fn autobrakes_low_does_not_arm_btv_when_not_installed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_btv_exit_selected(Length::new::<meter>(3000.), Length::new::<meter>(1800.))
+                .start_eng1(Ratio::new::<percent>(60.))
+                .start_eng2(Ratio::new::<percent>(60.))
+                .run_waiting_for(Duration::from_secs(3));
+
+            test_bed = test_bed
+                .set_autobrake_low()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::LOW);
+            assert!(test_bed.btv_state() == 0.);
+        }
+
+        #[test]
+        fn autobrakes_rop_brakes_at_max_on_overrun_risk_when_installed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .with_btv_rop_installed()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(3));
+
+            test_bed = test_bed
+                .set_autobrake_low()
+                .set_distance_to_runway_end(Length::new::<meter>(400.))
+                .rolling_at(
+                    Velocity::new::<knot>(120.),
+                    Acceleration::new::<meter_per_second_squared>(-3.),
+                )
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs(6));
+
+            assert!(test_bed.get_brake_left_green_pressure() > Pressure::new::<psi>(2000.));
+            assert!(test_bed.get_brake_right_green_pressure() > Pressure::new::<psi>(2000.));
+        }
+
+        #[test]
+        fn autobrakes_no_rop_max_braking_when_not_installed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(3));
+
+            test_bed = test_bed
+                .set_autobrake_low()
+                .set_distance_to_runway_end(Length::new::<meter>(400.))
+                .rolling_at(
+                    Velocity::new::<knot>(120.),
+                    Acceleration::new::<meter_per_second_squared>(-3.),
+                )
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs(6));
+
+            assert!(test_bed.get_brake_left_green_pressure() < Pressure::new::<psi>(2000.));
+            assert!(test_bed.get_brake_right_green_pressure() < Pressure::new::<psi>(2000.));
+        }
+
+        #[test]
+        // Should disable with one pedal > 61° over max range of 79.4° thus 77%
+        fn autobrakes_max_disengage_at_77_on_one_pedal_input() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(3));
+
+            test_bed = test_bed
+                .set_autobrake_max()
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs(6));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::MAX);
+            assert!(test_bed.get_brake_left_green_pressure() > Pressure::new::<psi>(1000.));
+            assert!(test_bed.get_brake_right_green_pressure() > Pressure::new::<psi>(1000.));
 
             test_bed = test_bed
                 .set_left_brake(Ratio::new::<percent>(70.))
@@ -11696,6 +12281,24 @@ mod tests {
             assert!(test_bed.gear_system_state() == GearSystemState::AllUpLocked);
         }
 
+        #[test]
+        fn gear_retraction_is_slower_with_cold_fluid() {
+            let mut test_bed = test_bed_on_ground_with()
+                .cold_soaked_at(ThermodynamicTemperature::new::<degree_celsius>(-40.))
+                .set_cold_dark_inputs()
+                .in_flight();
+
+            assert!(test_bed.gear_system_state() == GearSystemState::AllDownLocked);
+
+            test_bed = test_bed
+                .set_gear_lever_up()
+                .run_waiting_for(Duration::from_secs_f64(25.));
+            assert!(test_bed.gear_system_state() != GearSystemState::AllUpLocked);
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(60.));
+            assert!(test_bed.gear_system_state() == GearSystemState::AllUpLocked);
+        }
+
         #[test]
         fn gear_retracts_using_yellow_epump_plus_ptu() {
             let mut test_bed = test_bed_on_ground_with()
@@ -11868,6 +12471,136 @@ mod tests {
             assert!(test_bed.get_mean_right_spoilers_position().get::<ratio>() < 0.01);
         }
 
+        #[test]
+        fn jammed_aileron_servo_holds_aileron_after_control_is_handed_to_adjacent_servo() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .load_brake_accumulator()
+                .set_blue_e_pump_ovrd_pressed(true)
+                .set_yellow_e_pump(false)
+                .set_ailerons_neutral()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            let jammed_position = test_bed.get_left_aileron_position();
+            test_bed.fail(FailureType::FlightControlServoJam(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Blue,
+            ));
+            test_bed = test_bed
+                .set_ailerons_left_turn()
+                .run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(test_bed.is_servo_failure_reported("LEFT_AIL_SERVO_1_FAILED"));
+            assert!(!test_bed.is_servo_failure_reported("LEFT_AIL_SERVO_2_FAILED"));
+            assert!(
+                (test_bed.get_left_aileron_position() - jammed_position)
+                    .abs()
+                    .get::<ratio>()
+                    < 0.01
+            );
+            assert!(test_bed.get_right_aileron_position().get::<ratio>() < 0.1);
+
+            test_bed.write_by_name("LEFT_AIL_BLUE_SERVO_SOLENOID_ENERGIZED", 0.);
+            test_bed.write_by_name("LEFT_AIL_GREEN_SERVO_SOLENOID_ENERGIZED", 1.);
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(
+                (test_bed.get_left_aileron_position() - jammed_position)
+                    .abs()
+                    .get::<ratio>()
+                    < 0.01
+            );
+
+            test_bed.unfail(FailureType::FlightControlServoJam(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Blue,
+            ));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(!test_bed.is_servo_failure_reported("LEFT_AIL_SERVO_1_FAILED"));
+            assert!(test_bed.get_left_aileron_position().get::<ratio>() > 0.9);
+        }
+
+        #[test]
+        fn runaway_aileron_servo_is_stopped_when_adjacent_servo_takes_over() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .load_brake_accumulator()
+                .set_blue_e_pump_ovrd_pressed(true)
+                .set_yellow_e_pump(false)
+                .set_ailerons_neutral()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed.fail(FailureType::FlightControlServoRunaway(
+                FlightControlSurface::RightAileron,
+                HydraulicColor::Green,
+            ));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(2.));
+
+            let runaway_position = test_bed.get_right_aileron_position().get::<ratio>();
+            assert!(!(0.1..=0.9).contains(&runaway_position));
+            assert!(test_bed.is_servo_failure_reported("RIGHT_AIL_SERVO_1_FAILED"));
+
+            test_bed.write_by_name("RIGHT_AIL_GREEN_SERVO_SOLENOID_ENERGIZED", 0.);
+            test_bed.write_by_name("RIGHT_AIL_BLUE_SERVO_SOLENOID_ENERGIZED", 1.);
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!((test_bed.get_right_aileron_position().get::<ratio>() - 0.5).abs() < 0.05);
+            assert!(test_bed.is_servo_failure_reported("RIGHT_AIL_SERVO_1_FAILED"));
+        }
+
+        #[test]
+        fn elevator_servo_feedback_loss_is_detected_and_adjacent_servo_keeps_control() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .load_brake_accumulator()
+                .set_blue_e_pump_ovrd_pressed(true)
+                .set_yellow_e_pump(false)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed.fail(FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::LeftElevator,
+                HydraulicColor::Blue,
+            ));
+            test_bed = test_bed.run_one_tick();
+
+            assert!(test_bed.is_servo_failure_reported("LEFT_ELEV_SERVO_1_FAILED"));
+            assert!(!test_bed.is_servo_failure_reported("RIGHT_ELEV_SERVO_1_FAILED"));
+
+            // Elevator solenoids have reverted logic: energized servo is in damping
+            test_bed.write_by_name("LEFT_ELEV_BLUE_SERVO_SOLENOID_ENERGIZED", 1.);
+            test_bed.write_by_name("LEFT_ELEV_GREEN_SERVO_SOLENOID_ENERGIZED", 0.);
+            test_bed = test_bed
+                .set_elevator_full_up()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() > 0.9);
+            assert!(test_bed.get_right_elevator_position().get::<ratio>() > 0.9);
+        }
+
+        #[test]
+        fn jammed_spoiler_servo_stays_retracted_and_is_reported_failed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .load_brake_accumulator()
+                .set_blue_e_pump_ovrd_pressed(true)
+                .set_yellow_e_pump(false)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed.fail(FailureType::FlightControlServoJam(
+                FlightControlSurface::LeftSpoiler(3),
+                HydraulicColor::Blue,
+            ));
+            test_bed = test_bed
+                .set_left_spoilers_out()
+                .run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(test_bed.is_servo_failure_reported("LEFT_SPLR_3_SERVO_FAILED"));
+            assert!(!test_bed.is_servo_failure_reported("LEFT_SPLR_2_SERVO_FAILED"));
+            assert!(test_bed.get_left_spoiler_position(3).get::<ratio>() < 0.01);
+            assert!(test_bed.get_left_spoiler_position(2).get::<ratio>() > 0.9);
+        }
+
         #[test]
         fn gear_init_up_if_spawning_in_air() {
             let test_bed = test_bed_in_flight_with()
@@ -12062,6 +12795,104 @@ mod tests {
             assert!(test_bed.get_brake_right_green_pressure() > Pressure::new::<psi>(500.));
         }
 
+        #[test]
+        fn full_braking_force_on_dry_runway() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(60.))
+                .start_eng2(Ratio::new::<percent>(60.))
+                .run_waiting_for(Duration::from_secs(3));
+
+            test_bed = test_bed
+                .set_runway_contaminant(RunwayContaminant::Dry)
+                .rolling_at(
+                    Velocity::new::<knot>(100.),
+                    Acceleration::new::<meter_per_second_squared>(0.),
+                )
+                .set_left_brake(Ratio::new::<ratio>(1.))
+                .set_right_brake(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs(2));
+
+            assert!(test_bed.brake_left_force_factor() > 0.9);
+        }
+
+        #[test]
+        fn braking_force_limited_on_icy_runway() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(60.))
+                .start_eng2(Ratio::new::<percent>(60.))
+                .run_waiting_for(Duration::from_secs(3));
+
+            test_bed = test_bed
+                .set_runway_contaminant(RunwayContaminant::Ice)
+                .rolling_at(
+                    Velocity::new::<knot>(100.),
+                    Acceleration::new::<meter_per_second_squared>(0.),
+                )
+                .set_left_brake(Ratio::new::<ratio>(1.))
+                .set_right_brake(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs(2));
+
+            assert!(test_bed.brake_left_force_factor() < 0.3);
+        }
+
+        #[test]
+        fn autobrake_max_target_not_limited_on_dry_runway() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(3));
+
+            test_bed = test_bed
+                .set_runway_contaminant(RunwayContaminant::Dry)
+                .set_autobrake_max()
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::MAX);
+            assert!(
+                (test_bed
+                    .autobrake_target()
+                    .get::<meter_per_second_squared>()
+                    + 6.)
+                    .abs()
+                    < 0.01
+            );
+        }
+
+        #[test]
+        fn autobrake_max_target_limited_on_icy_runway() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(3));
+
+            test_bed = test_bed
+                .set_runway_contaminant(RunwayContaminant::Ice)
+                .set_autobrake_max()
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::MAX);
+            assert!(
+                test_bed
+                    .autobrake_target()
+                    .get::<meter_per_second_squared>()
+                    > -2.
+            );
+        }
+
         #[test]
         fn gears_do_not_deploy_with_all_lgciu_failed() {
             let mut test_bed = test_bed_in_flight_with()
@@ -12190,6 +13021,96 @@ mod tests {
             assert!(!test_bed.yellow_reservoir_has_overheat_fault());
         }
 
+        #[test]
+        fn green_system_external_leak_drains_green_reservoir() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            let initial_volume = test_bed.get_green_reservoir_volume();
+
+            test_bed.fail(FailureType::HydraulicExternalLeak(
+                HydraulicColor::Green,
+                HydraulicLeakLocation::SystemSectionUpstreamPriorityValve,
+            ));
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(30.));
+            assert!(
+                test_bed.get_green_reservoir_volume() < initial_volume - Volume::new::<gallon>(1.)
+            );
+        }
+
+        #[test]
+        fn yellow_pump_section_external_leak_do_not_leak_if_unpressurised() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_yellow_ed_pump(false)
+                .set_ptu_state(false)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            let initial_volume = test_bed.get_yellow_reservoir_volume();
+
+            test_bed.fail(FailureType::HydraulicExternalLeak(
+                HydraulicColor::Yellow,
+                HydraulicLeakLocation::PumpSection(1),
+            ));
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(30.));
+            assert!(
+                (test_bed.get_yellow_reservoir_volume() - initial_volume).abs()
+                    < Volume::new::<gallon>(0.1)
+            );
+        }
+
+        #[test]
+        fn ptu_shaft_failure_prevents_green_pressurisation_from_yellow_edp() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .start_eng2(Ratio::new::<percent>(60.))
+                .set_park_brake(false)
+                .set_ptu_state(false)
+                .run_waiting_for(Duration::from_secs(25));
+
+            test_bed.fail(FailureType::PowerTransferUnitShaft);
+
+            test_bed = test_bed
+                .set_ptu_state(true)
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.is_ptu_enabled());
+            assert!(!test_bed.is_green_pressure_switch_pressurised());
+            assert!(test_bed.green_pressure() < Pressure::new::<psi>(500.));
+
+            assert!(test_bed.is_yellow_pressure_switch_pressurised());
+        }
+
+        #[test]
+        fn yellow_epump_wear_failure_wears_pump_only_while_running() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_ptu_state(false)
+                .run_one_tick();
+
+            test_bed.fail(FailureType::ElecPumpWear(AirbusElectricPumpId::Yellow));
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(10.));
+            assert!(test_bed.yellow_epump_wear() == Ratio::default());
+
+            test_bed = test_bed
+                .set_yellow_e_pump(false)
+                .run_waiting_for(Duration::from_secs_f64(60.));
+            assert!(test_bed.yellow_epump_wear() > Ratio::new::<percent>(10.));
+
+            test_bed.unfail(FailureType::ElecPumpWear(AirbusElectricPumpId::Yellow));
+            test_bed = test_bed.run_one_tick();
+            assert!(test_bed.yellow_epump_wear() == Ratio::default());
+        }
+
         #[test]
         fn gear_stays_uplocked_when_door_sensors_fails() {
             let mut test_bed = test_bed_in_flight_with()
@@ -12320,6 +13241,39 @@ mod tests {
             assert!(test_bed.get_reverser_2_position().get::<ratio>() < 0.01);
         }
 
+        #[test]
+        fn reverser_deploys_in_flight_on_inadvertent_deployment_failure() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            test_bed.fail(FailureType::ReverserInadvertentDeployment(1));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.get_reverser_1_position().get::<ratio>() > 0.99);
+            assert!(test_bed.get_reverser_2_position().get::<ratio>() < 0.01);
+        }
+
+        #[test]
+        fn jammed_reverser_gives_asymmetric_deployment_on_ground() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .start_eng1(Ratio::new::<percent>(60.))
+                .start_eng2(Ratio::new::<percent>(60.))
+                .run_waiting_for(Duration::from_secs_f64(10.));
+
+            test_bed.fail(FailureType::ReverserJammed(2));
+            test_bed = test_bed
+                .eng1_throttle_reverse_full()
+                .eng2_throttle_reverse_full()
+                .run_waiting_for(Duration::from_secs_f64(3.));
+
+            assert!(test_bed.get_reverser_1_position().get::<ratio>() > 0.99);
+            assert!(test_bed.get_reverser_2_position().get::<ratio>() < 0.01);
+        }
+
         #[test]
         fn reversers_deploy_and_retract_on_ground_with_eng_on() {
             let mut test_bed = test_bed_in_flight_with()
@@ -2,6 +2,7 @@ extern crate systems;
 
 mod air_conditioning;
 mod airframe;
+pub mod doors;
 mod electrical;
 mod fuel;
 pub mod hydraulic;
@@ -13,6 +14,7 @@ mod surveillance;
 
 use self::{
     air_conditioning::A320AirConditioning,
+    doors::A320Doors,
     fuel::A320Fuel,
     payload::A320Payload,
     pneumatic::{A320Pneumatic, A320PneumaticOverheadPanel},
@@ -24,15 +26,20 @@ use electrical::{
     APU_START_MOTOR_BUS_TYPE,
 };
 use hydraulic::{A320Hydraulic, A320HydraulicOverheadPanel};
-use navigation::{A320AirDataInertialReferenceSystemBuilder, A320RadioAltimeters};
+use navigation::{
+    A320AirDataInertialReferenceSystemBuilder, A320MultiModeReceivers, A320RadioAltimeters,
+};
 use power_consumption::A320PowerConsumption;
-use systems::navigation::ils::MultiModeReceiverShim;
 use systems::{
     enhanced_gpwc::EnhancedGroundProximityWarningComputer,
     surveillance::egpws::EnhancedGroundProximityWarningComputer as EnhancedGroundProximityWarningComputer2,
 };
 use systems::{hydraulic::brake::BrakeFanPanel, simulation::InitContext};
-use uom::si::{f64::Length, length::nautical_mile};
+use uom::si::{
+    f64::{Force, Length},
+    force::kilonewton,
+    length::nautical_mile,
+};
 
 use systems::{
     air_starter_unit::AirStarterUnit,
@@ -50,6 +57,7 @@ use systems::{
     },
     shared::ElectricalBusType,
     simulation::{Aircraft, SimulationElement, SimulationElementVisitor, UpdateContext},
+    surveillance::tcas::SimulatorTrafficCollisionAvoidanceSystem,
 };
 
 pub struct A320 {
@@ -63,6 +71,7 @@ pub struct A320 {
     pneumatic_overhead: A320PneumaticOverheadPanel,
     electrical_overhead: A320ElectricalOverheadPanel,
     emergency_electrical_overhead: A320EmergencyElectricalOverheadPanel,
+    doors: A320Doors,
     payload: A320Payload,
     airframe: A320Airframe,
     fuel: A320Fuel,
@@ -83,10 +92,13 @@ pub struct A320 {
     egpwc: EnhancedGroundProximityWarningComputer,
     egpwc_2: EnhancedGroundProximityWarningComputer2,
     egpws_electrical_harness: A320EgpwsElectricalHarness,
-    mmr: MultiModeReceiverShim,
+    mmrs: A320MultiModeReceivers,
+    tcas: SimulatorTrafficCollisionAvoidanceSystem,
     reverse_thrust: ReverserForce,
 }
 impl A320 {
+    const MAX_REVERSE_THRUST_KILONEWTON: f64 = 30.;
+
     pub fn new(context: &mut InitContext) -> A320 {
         A320 {
             adirs: A320AirDataInertialReferenceSystemBuilder::build(context),
@@ -105,6 +117,7 @@ impl A320 {
             pneumatic_overhead: A320PneumaticOverheadPanel::new(context),
             electrical_overhead: A320ElectricalOverheadPanel::new(context),
             emergency_electrical_overhead: A320EmergencyElectricalOverheadPanel::new(context),
+            doors: A320Doors::new(context),
             payload: A320Payload::new(context),
             airframe: A320Airframe::new(context),
             fuel: A320Fuel::new(context),
@@ -144,8 +157,15 @@ impl A320 {
                 ElectricalBusType::AlternatingCurrent(1),
             ),
             egpws_electrical_harness: A320EgpwsElectricalHarness::new(context),
-            mmr: MultiModeReceiverShim::new(context),
-            reverse_thrust: ReverserForce::new(context),
+            mmrs: A320MultiModeReceivers::new(context),
+            tcas: SimulatorTrafficCollisionAvoidanceSystem::new(
+                context,
+                ElectricalBusType::DirectCurrent(1),
+            ),
+            reverse_thrust: ReverserForce::new(
+                context,
+                Force::new::<kilonewton>(Self::MAX_REVERSE_THRUST_KILONEWTON),
+            ),
         }
     }
 }
@@ -160,7 +180,8 @@ impl Aircraft for A320 {
             &self.apu_overhead,
             false, // Todo: fire detection system
             &self.apu_fire_overhead,
-            self.pneumatic_overhead.apu_bleed_is_on(),
+            self.pneumatic_overhead.apu_bleed_is_on()
+                && !self.pneumatic.apu_bleed_leak_isolation_commanded(),
             // This will be replaced when integrating the whole electrical system.
             // For now we use the same logic as found in the JavaScript code; ignoring whether or not
             // the engine generators are supplying electricity.
@@ -190,7 +211,9 @@ impl Aircraft for A320 {
             .update_after_electrical(&self.electrical, electricity);
         self.emergency_electrical_overhead
             .update_after_electrical(context, &self.electrical);
-        self.payload.update(context);
+        self.doors
+            .update(context, &self.air_conditioning, &self.hydraulic);
+        self.payload.update(context, &self.doors);
         self.airframe
             .update(&self.fuel, &self.payload, &self.payload);
     }
@@ -268,7 +291,12 @@ impl Aircraft for A320 {
             &self.payload,
             &self.pneumatic,
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+            self.power_consumption.avionics_load(),
         );
+        self.emergency_electrical_overhead
+            .set_gen_1_line_smoke(self.air_conditioning.avionics_smoke_detected());
+
+        self.mmrs.update(context);
 
         self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
         self.egpws_electrical_harness.update(self.lgcius.lgciu1());
@@ -279,7 +307,12 @@ impl Aircraft for A320 {
             self.radio_altimeters.radio_altimeter_2(),
             self.adirs.adr_bus(1),
             self.adirs.ir_bus(1),
-            &self.mmr,
+            self.mmrs.mmr_1(),
+        );
+        self.tcas.update(
+            context,
+            self.adirs.adr_bus(1),
+            self.radio_altimeters.radio_altimeter_1(),
         );
     }
 }
@@ -292,6 +325,7 @@ impl SimulationElement for A320 {
         self.asu.accept(visitor);
         self.apu_fire_overhead.accept(visitor);
         self.apu_overhead.accept(visitor);
+        self.doors.accept(visitor);
         self.payload.accept(visitor);
         self.airframe.accept(visitor);
         self.electrical_overhead.accept(visitor);
@@ -315,7 +349,8 @@ impl SimulationElement for A320 {
         self.egpwc.accept(visitor);
         self.egpws_electrical_harness.accept(visitor);
         self.egpwc_2.accept(visitor);
-        self.mmr.accept(visitor);
+        self.mmrs.accept(visitor);
+        self.tcas.accept(visitor);
         self.reverse_thrust.accept(visitor);
 
         visitor.visit(self);
@@ -5,6 +5,7 @@ use systems::navigation::adirs::{
 use systems::navigation::ala52b::{
     Ala52BAircraftInstallationDelay, Ala52BRadioAltimeter, Ala52BTransceiverPair,
 };
+use systems::navigation::ils::{InstrumentLandingSystemBus, MultiModeReceiver, TunedIlsNavaid};
 use systems::navigation::radio_altimeter::{AntennaInstallation, RadioAltimeter};
 use systems::shared::{ElectricalBusType, MachNumber};
 use systems::simulation::{
@@ -110,6 +111,54 @@ impl SimulationElement for A320RadioAltimeters {
     }
 }
 
+pub struct A320MultiModeReceivers {
+    mmr_1: MultiModeReceiver,
+    mmr_1_navaid: TunedIlsNavaid,
+    mmr_2: MultiModeReceiver,
+    mmr_2_navaid: TunedIlsNavaid,
+}
+impl A320MultiModeReceivers {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            mmr_1: MultiModeReceiver::new(
+                context,
+                1,
+                ElectricalBusType::AlternatingCurrentEssential,
+            ),
+            mmr_1_navaid: TunedIlsNavaid::new(context, 1),
+            mmr_2: MultiModeReceiver::new(context, 2, ElectricalBusType::AlternatingCurrent(2)),
+            mmr_2_navaid: TunedIlsNavaid::new(context, 2),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.mmr_1.update(
+            context,
+            self.mmr_1_navaid.tuned_frequency(),
+            &self.mmr_1_navaid,
+        );
+        self.mmr_2.update(
+            context,
+            self.mmr_2_navaid.tuned_frequency(),
+            &self.mmr_2_navaid,
+        );
+    }
+
+    pub fn mmr_1(&self) -> &impl InstrumentLandingSystemBus {
+        &self.mmr_1
+    }
+}
+impl SimulationElement for A320MultiModeReceivers {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.mmr_1.accept(visitor);
+        self.mmr_1_navaid.accept(visitor);
+        self.mmr_2.accept(visitor);
+        self.mmr_2_navaid.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 pub struct A320RadioAltimeter {
     radio_altimeter: Ala52BRadioAltimeter,
     transceivers: Ala52BTransceiverPair,
@@ -12,6 +12,8 @@ use systems::{
     simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 
+use crate::doors::A320Doors;
+
 #[cfg(test)]
 mod test;
 
@@ -154,19 +156,11 @@ impl A320Payload {
         });
         let default_boarding_agent = BoardingAgent::new(None, [0, 1, 2, 3]);
 
+        // The boarding doors are provided by the door system, see `A320Doors::boarding_door_open_amounts`.
         let boarding_agents = [
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:0".to_owned())),
-                [0, 1, 2, 3],
-            ),
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:1".to_owned())),
-                [0, 1, 2, 3],
-            ),
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:2".to_owned())),
-                [3, 2, 1, 0],
-            ),
+            BoardingAgent::new(None, [0, 1, 2, 3]),
+            BoardingAgent::new(None, [0, 1, 2, 3]),
+            BoardingAgent::new(None, [3, 2, 1, 0]),
         ];
 
         let passenger_deck = PassengerDeck::new(pax, default_boarding_agent, boarding_agents);
@@ -186,7 +180,9 @@ impl A320Payload {
         }
     }
 
-    pub(crate) fn update(&mut self, context: &UpdateContext) {
+    pub(crate) fn update(&mut self, context: &UpdateContext, doors: &A320Doors) {
+        self.payload_manager
+            .update_boarding_doors(doors.boarding_door_open_amounts());
         self.payload_manager.update(context.delta());
     }
 
@@ -5,29 +5,76 @@ use std::time::Duration;
 
 use rand::seq::IteratorRandom;
 use rand::SeedableRng;
+use systems::air_conditioning::CabinPressure;
 use systems::electrical::Electricity;
 use systems::payload::{BoardingRate, GsxState};
-use uom::si::f64::Ratio;
+use systems::shared::{CargoDoorLocked, CargoDoorOpenAmount};
+use uom::si::f64::{Pressure, Ratio};
 use uom::si::mass::pound;
+use uom::si::pressure::hectopascal;
 use uom::si::ratio::percent;
 
 use super::*;
+use crate::doors::A320Doors;
 use crate::payload::A320Payload;
 use crate::systems::simulation::{
     test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
     Aircraft, SimulationElement, SimulationElementVisitor,
 };
+struct TestCabinPressure {
+    differential_pressure: Pressure,
+}
+impl CabinPressure for TestCabinPressure {
+    fn exterior_pressure(&self) -> Pressure {
+        Pressure::new::<hectopascal>(1013.25)
+    }
+
+    fn cabin_pressure(&self) -> Pressure {
+        Pressure::new::<hectopascal>(1013.25) + self.differential_pressure
+    }
+}
+
+struct TestCargoDoors;
+impl CargoDoorLocked for TestCargoDoors {
+    fn fwd_cargo_door_locked(&self) -> bool {
+        true
+    }
+
+    fn aft_cargo_door_locked(&self) -> bool {
+        true
+    }
+}
+impl CargoDoorOpenAmount for TestCargoDoors {
+    fn fwd_cargo_door_open_amount(&self) -> Ratio {
+        Ratio::default()
+    }
+
+    fn aft_cargo_door_open_amount(&self) -> Ratio {
+        Ratio::default()
+    }
+}
+
 struct BoardingTestAircraft {
+    doors: A320Doors,
+    cabin_pressure: TestCabinPressure,
     payload: A320Payload,
 }
 
 impl BoardingTestAircraft {
     fn new(context: &mut InitContext) -> Self {
         Self {
+            doors: A320Doors::new(context),
+            cabin_pressure: TestCabinPressure {
+                differential_pressure: Pressure::default(),
+            },
             payload: A320Payload::new(context),
         }
     }
 
+    fn set_differential_pressure(&mut self, differential_pressure: Pressure) {
+        self.cabin_pressure.differential_pressure = differential_pressure;
+    }
+
     fn pax_num(&self, ps: usize) -> i8 {
         self.payload.pax_num(ps)
     }
@@ -107,11 +154,14 @@ impl Aircraft for BoardingTestAircraft {
         context: &UpdateContext,
         _electricity: &mut Electricity,
     ) {
-        self.payload.update(context);
+        self.doors
+            .update(context, &self.cabin_pressure, &TestCargoDoors);
+        self.payload.update(context, &self.doors);
     }
 }
 impl SimulationElement for BoardingTestAircraft {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.doors.accept(visitor);
         self.payload.accept(visitor);
 
         visitor.visit(self);
@@ -179,6 +229,29 @@ impl BoardingTestBed {
         self
     }
 
+    fn with_residual_cabin_pressure(mut self) -> Self {
+        self.command(|a| a.set_differential_pressure(Pressure::new::<hectopascal>(10.)));
+        self
+    }
+
+    fn residual_pressure_warning(&mut self, door_id: &str) -> bool {
+        self.read_by_name(&format!("DOOR_{}_RESIDUAL_PRESSURE", door_id))
+    }
+
+    /// Closes the opened boarding doors again, as the aircraft does in the simulator when the
+    /// residual pressure warning is raised.
+    fn close_doors_locked_by_residual_pressure(mut self) -> Self {
+        for (door_id, interactive_point) in [("FWD_LEFT", 0), ("FWD_RIGHT", 1)] {
+            if self.residual_pressure_warning(door_id) {
+                self.write_by_name(
+                    &format!("INTERACTIVE POINT OPEN:{}", interactive_point),
+                    Ratio::default(),
+                );
+            }
+        }
+        self
+    }
+
     fn gsx_requested_board_state(mut self) -> Self {
         self.write_by_name("FSDT_GSX_BOARDING_STATE", GsxState::Requested);
         self
@@ -1071,6 +1144,52 @@ fn start_no_pax_half_cargo_target_full_cargo_real_board_double_gate() {
     test_bed.sound_boarding_complete_reset();
 }
 
+#[test]
+fn doors_opened_with_residual_cabin_pressure_raise_warning_and_do_not_board_pax() {
+    let four_minutes_in_seconds = 4 * MINUTES_TO_SECONDS;
+
+    let mut test_bed = test_bed_with()
+        .init_vars()
+        .double_gate_boarding()
+        .with_no_pax()
+        .target_half_pax()
+        .real_board_rate()
+        .start_boarding()
+        .and_run();
+    test_bed
+        .test_bed
+        .run_multiple_frames(Duration::from_secs(four_minutes_in_seconds));
+    let pax_boarded_through_open_doors = test_bed.total_pax_num();
+
+    let mut test_bed = test_bed_with()
+        .init_vars()
+        .with_residual_cabin_pressure()
+        .double_gate_boarding()
+        .with_no_pax()
+        .target_half_pax()
+        .real_board_rate()
+        .start_boarding()
+        .and_run();
+
+    assert!(test_bed.residual_pressure_warning("FWD_LEFT"));
+    assert!(test_bed.residual_pressure_warning("FWD_RIGHT"));
+    assert!(!test_bed.residual_pressure_warning("AFT_LEFT"));
+
+    test_bed = test_bed.close_doors_locked_by_residual_pressure().and_run();
+
+    assert!(test_bed.query(|a| a
+        .doors
+        .boarding_door_open_amounts()
+        .iter()
+        .all(|open_amount| *open_amount == Ratio::default())));
+
+    test_bed
+        .test_bed
+        .run_multiple_frames(Duration::from_secs(four_minutes_in_seconds));
+
+    assert!(test_bed.total_pax_num() < pax_boarded_through_open_doors);
+}
+
 #[test]
 fn start_half_cargo_target_full_cargo_real_board_usr_interferes_with_payload_manually() {
     let mut test_bed = test_bed_with()
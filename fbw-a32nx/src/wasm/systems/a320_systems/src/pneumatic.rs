@@ -4,7 +4,7 @@ use uom::si::{
     f64::*,
     length::foot,
     pressure::psi,
-    ratio::{percent, ratio},
+    ratio::ratio,
     thermodynamic_temperature::degree_celsius,
     velocity::foot_per_minute,
     volume::{cubic_meter, gallon},
@@ -13,22 +13,26 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    engine::{leap_engine::LeapEngine, start_control::EngineStartControl},
+    failures::FailureType,
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
-        valve::*, BleedMonitoringComputerChannelOperationMode,
-        BleedMonitoringComputerIsAliveSignal, BleedTemperatureSensor, CompressionChamber,
-        ControllablePneumaticValve, CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode,
-        DifferentialPressureTransducer, EngineCompressionChamberController, EngineModeSelector,
-        EngineState, PneumaticContainer, PneumaticPipe, PneumaticValveSignal, Precooler,
-        PressureTransducer, PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
-        SolenoidSignal, TargetPressureTemperatureSignal, VariableVolumeContainer,
-        WingAntiIcePushButton, WingAntiIceSelected,
+        leak_detection::{BleedAirDuctLeak, BleedAirLeakDetection, LeakDetectionLoop},
+        valve::*,
+        BleedMonitoringComputerChannelOperationMode, BleedMonitoringComputerIsAliveSignal,
+        BleedTemperatureSensor, CompressionChamber, ControllablePneumaticValve,
+        CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode, DifferentialPressureTransducer,
+        EngineCompressionChamberController, EngineModeSelector, EngineState, PneumaticContainer,
+        PneumaticPipe, PneumaticValveSignal, Precooler, PressureTransducer,
+        PressurisedReservoirWithExhaustValve, PressurizeableReservoir, SolenoidSignal,
+        TargetPressureTemperatureSignal, VariableVolumeContainer, WingAntiIcePushButton,
+        WingAntiIceSelected,
     },
     shared::{
-        pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, DelayedTrueLogicGate,
-        ElectricalBusType, ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1,
-        EngineCorrectedN2, EngineFirePushButtons, EngineStartState, HydraulicColor,
-        LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
+        pid::PidController, update_iterator::MaxStepLoop, BleedAirLeakZone, ControllerSignal,
+        DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, EngineBleedPushbutton,
+        EngineCorrectedN1, EngineCorrectedN2, EngineFirePushButtons, EngineStartState,
+        HydraulicColor, LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
         ReservoirAirPressure,
     },
     simulation::{
@@ -95,6 +99,7 @@ pub struct A320Pneumatic {
     cross_bleed_valve_fully_closed_id: VariableIdentifier,
     apu_bleed_air_valve_open_id: VariableIdentifier,
     apu_bleed_air_pressure_id: VariableIdentifier,
+    apu_bleed_leak_id: VariableIdentifier,
     bleed_monitoring_computers: [BleedMonitoringComputer; 2],
     engine_systems: [EngineBleedAirSystem; 2],
 
@@ -105,6 +110,9 @@ pub struct A320Pneumatic {
 
     apu_compression_chamber: CompressionChamber,
     apu_bleed_air_valve: DefaultValve,
+    apu_duct_leak: BleedAirDuctLeak,
+
+    leak_detection_loops: [LeakDetectionLoop; 5],
 
     air_starter_unit_compression_chamber: CompressionChamber,
     air_starter_unit_bleed_air_valve: PurelyPneumaticValve,
@@ -126,6 +134,10 @@ pub struct A320Pneumatic {
 impl A320Pneumatic {
     const PNEUMATIC_SIM_MAX_TIME_STEP: Duration = Duration::from_millis(10);
 
+    const WING_LEAK_DETECTION_TEMPERATURE_C: f64 = 124.;
+    const PYLON_LEAK_DETECTION_TEMPERATURE_C: f64 = 204.;
+    const APU_LEAK_DETECTION_TEMPERATURE_C: f64 = 124.;
+
     pub fn new(context: &mut InitContext) -> Self {
         Self {
             physics_updater: MaxStepLoop::new(Self::PNEUMATIC_SIM_MAX_TIME_STEP),
@@ -137,6 +149,7 @@ impl A320Pneumatic {
                 .get_identifier("APU_BLEED_AIR_VALVE_OPEN".to_owned()),
             apu_bleed_air_pressure_id: context
                 .get_identifier("PNEU_APU_BLEED_CONTAINER_PRESSURE".to_owned()),
+            apu_bleed_leak_id: context.get_identifier("PNEU_APU_BLEED_LEAK".to_owned()),
             bleed_monitoring_computers: [
                 BleedMonitoringComputer::new(
                     context,
@@ -162,6 +175,49 @@ impl A320Pneumatic {
             ],
             apu_compression_chamber: CompressionChamber::new(Volume::new::<cubic_meter>(5.)),
             apu_bleed_air_valve: DefaultValve::new_closed(),
+            apu_duct_leak: BleedAirDuctLeak::new(BleedAirLeakZone::Apu, 0.05),
+            leak_detection_loops: [
+                LeakDetectionLoop::new(
+                    context,
+                    BleedAirLeakZone::Wing(1),
+                    ThermodynamicTemperature::new::<degree_celsius>(
+                        Self::WING_LEAK_DETECTION_TEMPERATURE_C,
+                    ),
+                    ElectricalBusType::DirectCurrentEssentialShed,
+                ),
+                LeakDetectionLoop::new(
+                    context,
+                    BleedAirLeakZone::Wing(2),
+                    ThermodynamicTemperature::new::<degree_celsius>(
+                        Self::WING_LEAK_DETECTION_TEMPERATURE_C,
+                    ),
+                    ElectricalBusType::DirectCurrent(2),
+                ),
+                LeakDetectionLoop::new(
+                    context,
+                    BleedAirLeakZone::Pylon(1),
+                    ThermodynamicTemperature::new::<degree_celsius>(
+                        Self::PYLON_LEAK_DETECTION_TEMPERATURE_C,
+                    ),
+                    ElectricalBusType::DirectCurrentEssentialShed,
+                ),
+                LeakDetectionLoop::new(
+                    context,
+                    BleedAirLeakZone::Pylon(2),
+                    ThermodynamicTemperature::new::<degree_celsius>(
+                        Self::PYLON_LEAK_DETECTION_TEMPERATURE_C,
+                    ),
+                    ElectricalBusType::DirectCurrent(2),
+                ),
+                LeakDetectionLoop::new(
+                    context,
+                    BleedAirLeakZone::Apu,
+                    ThermodynamicTemperature::new::<degree_celsius>(
+                        Self::APU_LEAK_DETECTION_TEMPERATURE_C,
+                    ),
+                    ElectricalBusType::DirectCurrentEssentialShed,
+                ),
+            ],
             air_starter_unit_compression_chamber: CompressionChamber::new(
                 Volume::new::<cubic_meter>(5.),
             ),
@@ -268,6 +324,7 @@ impl A320Pneumatic {
                 &self.cross_bleed_valve,
                 &self.fadec,
                 &self.wing_anti_ice,
+                &self.leak_detection_loops,
             );
 
             // I am not exactly sure if both BMCs should actually control this valve all the time.
@@ -280,6 +337,8 @@ impl A320Pneumatic {
         bmc_one.check_for_failure(bmc_two);
         bmc_two.check_for_failure(bmc_one);
 
+        self.fadec.update(context, &self.engine_systems);
+
         for controller in self.engine_starter_valve_controllers.iter_mut() {
             controller.update(&self.fadec);
         }
@@ -335,6 +394,13 @@ impl A320Pneumatic {
         self.cross_bleed_valve
             .update_move_fluid(context, left_system, right_system);
 
+        // The APU bleed duct is only pressurised while the APU bleed valve is open.
+        if self.apu_bleed_air_valve.is_open() {
+            self.apu_duct_leak.update_move_fluid(context, left_system);
+        } else {
+            self.apu_duct_leak.update_isolated();
+        }
+
         self.green_hydraulic_reservoir_with_valve
             .update_flow_through_valve(context, &mut self.hydraulic_reservoir_bleed_air_pipe);
         self.blue_hydraulic_reservoir_with_valve
@@ -348,6 +414,38 @@ impl A320Pneumatic {
             .for_each(|(pack, engine_system)| {
                 pack.update(context, engine_system, pack_flow_valve_signals)
             });
+
+        self.update_leak_detection(context);
+    }
+
+    fn update_leak_detection(&mut self, context: &UpdateContext) {
+        for detection_loop in self.leak_detection_loops.iter_mut() {
+            let leak = match detection_loop.zone() {
+                BleedAirLeakZone::Wing(number) => self.engine_systems[number - 1].wing_duct_leak(),
+                BleedAirLeakZone::Pylon(number) => {
+                    self.engine_systems[number - 1].pylon_duct_leak()
+                }
+                BleedAirLeakZone::Apu => &self.apu_duct_leak,
+            };
+
+            detection_loop.update(context, leak);
+        }
+    }
+
+    /// The APU bleed valve is closed through the ECB when a leak is detected in the APU bleed duct,
+    /// or in the left wing while no engine is being started.
+    pub fn apu_bleed_leak_isolation_commanded(&self) -> bool {
+        let engine_start_in_progress = (1..=2).any(|number| {
+            matches!(
+                self.fadec.engine_state(number),
+                EngineState::Starting | EngineState::Restarting
+            )
+        });
+
+        self.bleed_monitoring_computers.iter().any(|bmc| {
+            bmc.has_latched_leak(BleedAirLeakZone::Apu)
+                || (bmc.has_latched_leak(BleedAirLeakZone::Wing(1)) && !engine_start_in_progress)
+        })
     }
 
     // TODO: Returning a mutable reference here is not great. I was running into an issue with the update order:
@@ -410,7 +508,9 @@ impl SimulationElement for A320Pneumatic {
         self.cross_bleed_valve.accept(visitor);
         self.fadec.accept(visitor);
         self.wing_anti_ice.accept(visitor);
+        self.apu_duct_leak.accept(visitor);
 
+        accept_iterable!(self.leak_detection_loops, visitor);
         accept_iterable!(self.bleed_monitoring_computers, visitor);
         accept_iterable!(self.engine_systems, visitor);
         accept_iterable!(self.packs, visitor);
@@ -439,6 +539,11 @@ impl SimulationElement for A320Pneumatic {
             &self.apu_bleed_air_pressure_id,
             self.apu_compression_chamber.pressure(),
         );
+        writer.write(
+            &self.apu_bleed_leak_id,
+            self.leak_detection_loops
+                .leak_detected(BleedAirLeakZone::Apu),
+        );
     }
 }
 impl ReservoirAirPressure for A320Pneumatic {
@@ -457,17 +562,14 @@ impl ReservoirAirPressure for A320Pneumatic {
 
 struct EngineStarterValveController {
     number: usize,
-    engine_state: EngineState,
-    engine_n2_percent: f64,
+    is_commanded_open: bool,
 }
 impl ControllerSignal<EngineStarterValveSignal> for EngineStarterValveController {
     fn signal(&self) -> Option<EngineStarterValveSignal> {
-        match self.engine_state {
-            //FIXME should start at around 60% N2 and complete at 65% N2 because of traveltime of valve
-            EngineState::Starting | EngineState::Restarting if self.engine_n2_percent < 65. => {
-                Some(EngineStarterValveSignal::new_open())
-            }
-            _ => Some(EngineStarterValveSignal::new_closed()),
+        if self.is_commanded_open {
+            Some(EngineStarterValveSignal::new_open())
+        } else {
+            Some(EngineStarterValveSignal::new_closed())
         }
     }
 }
@@ -475,14 +577,12 @@ impl EngineStarterValveController {
     fn new(number: usize) -> Self {
         Self {
             number,
-            engine_state: EngineState::Off,
-            engine_n2_percent: 0.,
+            is_commanded_open: false,
         }
     }
 
     fn update(&mut self, fadec: &FullAuthorityDigitalEngineControl) {
-        self.engine_state = fadec.engine_state(self.number);
-        self.engine_n2_percent = fadec.engine_n2_percent(self.number);
+        self.is_commanded_open = fadec.starter_valve_is_commanded_open(self.number);
     }
 }
 
@@ -529,6 +629,7 @@ impl BleedMonitoringComputer {
         cross_bleed_valve: &impl PneumaticValve,
         fadec: &FullAuthorityDigitalEngineControl,
         wing_anti_ice: &impl WingAntiIceSelected,
+        leak_detection: &impl BleedAirLeakDetection,
     ) {
         self.main_channel.update(
             context,
@@ -539,6 +640,7 @@ impl BleedMonitoringComputer {
             overhead_panel,
             fadec,
             wing_anti_ice,
+            leak_detection,
         );
 
         self.backup_channel.update(
@@ -550,9 +652,18 @@ impl BleedMonitoringComputer {
             overhead_panel,
             fadec,
             wing_anti_ice,
+            leak_detection,
         );
     }
 
+    fn has_latched_leak(&self, zone: BleedAirLeakZone) -> bool {
+        self.main_channel.operation_mode() == BleedMonitoringComputerChannelOperationMode::Master
+            && self.main_channel.has_latched_leak(zone)
+            || self.backup_channel.operation_mode()
+                == BleedMonitoringComputerChannelOperationMode::Master
+                && self.backup_channel.has_latched_leak(zone)
+    }
+
     fn check_for_failure(&mut self, other: &mut BleedMonitoringComputer) {
         if other.signal().is_none() {
             self.change_backup_channel_operation_mode(
@@ -638,15 +749,19 @@ struct BleedMonitoringComputerChannel {
     should_use_ip_vs_hp_valve: bool,
     overheat_monitor: BleedOverheatMonitor,
     overpressure_monitor: BleedOverpressureMonitor,
+    leak_monitor: BleedLeakMonitor,
     has_low_bleed_temperature: bool,
     is_in_dual_bleed_config: bool,
     flight_phase_loop: FlightPhaseLoop,
     low_temperature_regulation_active: DelayedTrueLogicGate,
     should_command_onside_prv_closed: bool,
+    bleed_not_closed: DelayedTrueLogicGate,
 
     low_temperature_id: VariableIdentifier,
     overheat_id: VariableIdentifier,
     overpressure_id: VariableIdentifier,
+    leak_id: VariableIdentifier,
+    bleed_not_closed_id: VariableIdentifier,
 }
 impl BleedMonitoringComputerChannel {
     const PRESSURE_REGULATING_VALVE_SINGLE_BLEED_CONFIG_TARGET_PSI: f64 = 50.;
@@ -665,6 +780,8 @@ impl BleedMonitoringComputerChannel {
     const HIGH_TEMPERATURE_REGULATION_SETPOINT: f64 = 200.;
     const LOW_TEMPERATURE_REGULATION_THRESHOLD: f64 = 160.;
 
+    const BLEED_NOT_CLOSED_DELAY: Duration = Duration::from_secs(5);
+
     fn new(
         context: &mut InitContext,
         engine_number: usize,
@@ -691,16 +808,21 @@ impl BleedMonitoringComputerChannel {
             should_use_ip_vs_hp_valve: false,
             overheat_monitor: BleedOverheatMonitor::new(),
             overpressure_monitor: BleedOverpressureMonitor::new(),
+            leak_monitor: BleedLeakMonitor::new(),
             has_low_bleed_temperature: false,
             flight_phase_loop: FlightPhaseLoop::new(),
             low_temperature_regulation_active: DelayedTrueLogicGate::new(Duration::from_secs(20)),
             should_command_onside_prv_closed: false,
+            bleed_not_closed: DelayedTrueLogicGate::new(Self::BLEED_NOT_CLOSED_DELAY),
             is_in_dual_bleed_config: false,
             low_temperature_id: context
                 .get_identifier(format!("PNEU_ENG_{}_LOW_TEMPERATURE", engine_number)),
             overheat_id: context.get_identifier(format!("PNEU_ENG_{}_OVERHEAT", engine_number)),
             overpressure_id: context
                 .get_identifier(format!("PNEU_ENG_{}_OVERPRESSURE", engine_number)),
+            leak_id: context.get_identifier(format!("PNEU_ENG_{}_BLEED_LEAK", engine_number)),
+            bleed_not_closed_id: context
+                .get_identifier(format!("PNEU_ENG_{}_BLEED_NOT_CLOSED", engine_number)),
         }
     }
 
@@ -714,6 +836,7 @@ impl BleedMonitoringComputerChannel {
         overhead_panel: &A320PneumaticOverheadPanel,
         fadec: &FullAuthorityDigitalEngineControl,
         wing_anti_ice: &impl WingAntiIceSelected,
+        leak_detection: &impl BleedAirLeakDetection,
     ) {
         // READ IN SENSORS
 
@@ -729,6 +852,7 @@ impl BleedMonitoringComputerChannel {
 
         // UPDATE STATE
 
+        self.leak_monitor.update(leak_detection, overhead_panel);
         self.flight_phase_loop.update(context);
         self.update_dual_vs_single_bleed_operation(
             sensors,
@@ -739,6 +863,11 @@ impl BleedMonitoringComputerChannel {
         );
         self.update_low_temperature_regulation(context, wing_anti_ice);
 
+        self.bleed_not_closed.update(
+            context,
+            self.should_command_onside_prv_closed && !self.pressure_regulating_valve_is_closed,
+        );
+
         // UPDATE SETPOINTS
 
         self.fan_air_valve_pid
@@ -888,6 +1017,18 @@ impl BleedMonitoringComputerChannel {
         self.overheat_monitor.has_overheat()
     }
 
+    fn has_leak(&self) -> bool {
+        self.leak_monitor.has_onside_leak(self.engine_number)
+    }
+
+    fn has_latched_leak(&self, zone: BleedAirLeakZone) -> bool {
+        self.leak_monitor.has_latched_leak(zone)
+    }
+
+    fn has_bleed_not_closed(&self) -> bool {
+        self.bleed_not_closed.output()
+    }
+
     fn determine_temperature_setpoint(&self) -> f64 {
         if self.low_temperature_regulation_active.output() {
             Self::LOW_TEMPERATURE_REGULATION_THRESHOLD
@@ -911,7 +1052,8 @@ impl BleedMonitoringComputerChannel {
             cross_bleed_valve,
         ) || sensors.engine_starter_valve_is_open()
             || self.overpressure_monitor.has_overpressure()
-            || self.overheat_monitor.has_overheat();
+            || self.overheat_monitor.has_overheat()
+            || self.leak_monitor.has_onside_leak(self.engine_number);
 
         let should_command_offside_prv_closed = self.should_command_prv_closed(
             self.engine_number % 2 + 1,
@@ -993,7 +1135,7 @@ impl ControllerSignal<CrossBleedValveSignal> for BleedMonitoringComputerChannel
                 CrossBleedValveSignalType::Manual,
             )),
             CrossBleedValveSelectorMode::Auto => {
-                if self.is_apu_bleed_valve_open {
+                if self.is_apu_bleed_valve_open && !self.leak_monitor.has_any_leak() {
                     Some(CrossBleedValveSignal::new_open(
                         CrossBleedValveSignalType::Automatic,
                     ))
@@ -1016,6 +1158,8 @@ impl SimulationElement for BleedMonitoringComputerChannel {
             writer.write(&self.low_temperature_id, self.has_low_temperature());
             writer.write(&self.overheat_id, self.has_overheat());
             writer.write(&self.overpressure_id, self.has_overpressure());
+            writer.write(&self.leak_id, self.has_leak());
+            writer.write(&self.bleed_not_closed_id, self.has_bleed_not_closed());
         }
     }
 }
@@ -1061,6 +1205,8 @@ struct EngineBleedAirSystem {
     engine_starter_valve: DefaultValve,
     fan_air_valve: ElectroPneumaticValve,
     precooler: Precooler,
+    wing_duct_leak: BleedAirDuctLeak,
+    pylon_duct_leak: BleedAirDuctLeak,
 
     transfer_pressure_transducer: PressureTransducer,
     regulated_pressure_transducer: PressureTransducer,
@@ -1135,8 +1281,15 @@ impl EngineBleedAirSystem {
                     0.25, // -> 1 / 0.25 = 4 seconds to open
                 ),
                 powered_by,
+            )
+            .with_stuck_failures(
+                FailureType::HighPressureValveStuckOpen(number),
+                FailureType::HighPressureValveStuckClosed(number),
+            ),
+            pressure_regulating_valve: ElectroPneumaticValve::new(powered_by).with_stuck_failures(
+                FailureType::PressureRegulatingValveStuckOpen(number),
+                FailureType::PressureRegulatingValveStuckClosed(number),
             ),
-            pressure_regulating_valve: ElectroPneumaticValve::new(powered_by),
             overpressure_valve: OverpressureValve::new(
                 PneumaticValveCharacteristics::new(
                     Pressure::new::<psi>(0.), // psig
@@ -1145,6 +1298,10 @@ impl EngineBleedAirSystem {
                     0.5, // -> 1 / 0.5 = 2 seconds to open
                 ),
                 Pressure::new::<psi>(80.),
+            )
+            .with_stuck_failures(
+                FailureType::OverpressureValveStuckOpen(number),
+                FailureType::OverpressureValveStuckClosed(number),
             ),
             fan_air_valve: ElectroPneumaticValve::new(powered_by),
             transfer_pressure_pipe: PneumaticPipe::new(
@@ -1180,7 +1337,10 @@ impl EngineBleedAirSystem {
             engine_starter_pressurized: false,
             engine_starter_exhaust: PneumaticExhaust::new(10., 10., Pressure::new::<psi>(0.)),
             engine_starter_valve: DefaultValve::new_closed(),
-            precooler: Precooler::new(900. * 2.),
+            precooler: Precooler::new(900. * 2.)
+                .with_clogging_failure(FailureType::PrecoolerClogged(number)),
+            wing_duct_leak: BleedAirDuctLeak::new(BleedAirLeakZone::Wing(number), 0.05),
+            pylon_duct_leak: BleedAirDuctLeak::new(BleedAirLeakZone::Pylon(number), 0.05),
             transfer_pressure_transducer: PressureTransducer::new(powered_by),
             // Should be powered by 801PP for engine 1 and 202PP for engine 2
             regulated_pressure_transducer: PressureTransducer::new(powered_by),
@@ -1264,6 +1424,11 @@ impl EngineBleedAirSystem {
             .update_move_fluid(context, &mut self.engine_starter_container);
         self.update_engine_start_pressurization(context);
 
+        self.pylon_duct_leak
+            .update_move_fluid(context, &mut self.precooler_inlet_pipe);
+        self.wing_duct_leak
+            .update_move_fluid(context, &mut self.precooler_outlet_pipe);
+
         self.transfer_pressure_transducer
             .update(context, &self.transfer_pressure_pipe);
         self.regulated_pressure_transducer
@@ -1344,10 +1509,22 @@ impl EngineBleedAirSystem {
         self.engine_starter_valve.is_open()
     }
 
+    fn engine_starter_container_pressure(&self) -> Pressure {
+        self.engine_starter_container.pressure()
+    }
+
     fn pressure_regulating_valve_is_open(&self) -> bool {
         self.pressure_regulating_valve.is_open()
     }
 
+    fn wing_duct_leak(&self) -> &BleedAirDuctLeak {
+        &self.wing_duct_leak
+    }
+
+    fn pylon_duct_leak(&self) -> &BleedAirDuctLeak {
+        &self.pylon_duct_leak
+    }
+
     fn transfer_pressure_transducer_pressure(&self) -> Option<Pressure> {
         self.transfer_pressure_transducer.signal()
     }
@@ -1368,7 +1545,11 @@ impl SimulationElement for EngineBleedAirSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.high_pressure_valve.accept(visitor);
         self.pressure_regulating_valve.accept(visitor);
+        self.overpressure_valve.accept(visitor);
         self.fan_air_valve.accept(visitor);
+        self.precooler.accept(visitor);
+        self.wing_duct_leak.accept(visitor);
+        self.pylon_duct_leak.accept(visitor);
 
         self.transfer_pressure_transducer.accept(visitor);
         self.regulated_pressure_transducer.accept(visitor);
@@ -1532,6 +1713,61 @@ impl BleedOverpressureMonitor {
     }
 }
 
+/// Latches the leaks reported by the detection loops. A latched leak is only reset once the zone
+/// has cooled down and the affected bleed source has been switched off.
+struct BleedLeakMonitor {
+    latched_leaks: [bool; 5],
+}
+impl BleedLeakMonitor {
+    const ZONES: [BleedAirLeakZone; 5] = [
+        BleedAirLeakZone::Wing(1),
+        BleedAirLeakZone::Wing(2),
+        BleedAirLeakZone::Pylon(1),
+        BleedAirLeakZone::Pylon(2),
+        BleedAirLeakZone::Apu,
+    ];
+
+    fn new() -> Self {
+        Self {
+            latched_leaks: [false; 5],
+        }
+    }
+
+    fn update(
+        &mut self,
+        leak_detection: &impl BleedAirLeakDetection,
+        overhead_panel: &A320PneumaticOverheadPanel,
+    ) {
+        for (latched_leak, zone) in self.latched_leaks.iter_mut().zip(Self::ZONES) {
+            let is_detected = leak_detection.leak_detected(zone);
+            let source_is_off = match zone {
+                BleedAirLeakZone::Wing(number) | BleedAirLeakZone::Pylon(number) => {
+                    !overhead_panel.engine_bleed_pb_is_auto(number)
+                }
+                BleedAirLeakZone::Apu => !overhead_panel.apu_bleed_is_on(),
+            };
+
+            *latched_leak = is_detected || (*latched_leak && !source_is_off);
+        }
+    }
+
+    fn has_latched_leak(&self, zone: BleedAirLeakZone) -> bool {
+        Self::ZONES
+            .iter()
+            .zip(self.latched_leaks)
+            .any(|(&latched_zone, is_latched)| latched_zone == zone && is_latched)
+    }
+
+    fn has_onside_leak(&self, engine_number: usize) -> bool {
+        self.has_latched_leak(BleedAirLeakZone::Wing(engine_number))
+            || self.has_latched_leak(BleedAirLeakZone::Pylon(engine_number))
+    }
+
+    fn has_any_leak(&self) -> bool {
+        self.latched_leaks.iter().any(|&is_latched| is_latched)
+    }
+}
+
 pub struct A320PneumaticOverheadPanel {
     apu_bleed: OnOffFaultPushButton,
     cross_bleed: CrossBleedValveSelectorKnob,
@@ -1583,7 +1819,8 @@ impl SimulationElement for A320PneumaticOverheadPanel {
     }
 }
 
-/// We use this simply as an interface to engine parameter simvars. It should probably not be part of the pneumatic system.
+/// We use this as an interface to engine parameter simvars and to sequence the engine starts.
+/// It should probably not be part of the pneumatic system.
 pub struct FullAuthorityDigitalEngineControl {
     engine_1_state_id: VariableIdentifier,
     engine_2_state_id: VariableIdentifier,
@@ -1593,10 +1830,7 @@ pub struct FullAuthorityDigitalEngineControl {
     engine_mode_selector1_id: VariableIdentifier,
     engine_mode_selector1_position: EngineModeSelector,
 
-    engine_1_n2_percent_id: VariableIdentifier,
-    engine_2_n2_percent_id: VariableIdentifier,
-    engine_1_n2_percent: Ratio,
-    engine_2_n2_percent: Ratio,
+    start_controls: [EngineStartControl; 2],
 }
 impl FullAuthorityDigitalEngineControl {
     fn new(context: &mut InitContext) -> Self {
@@ -1608,25 +1842,37 @@ impl FullAuthorityDigitalEngineControl {
             engine_mode_selector1_id: context
                 .get_identifier("TURB ENG IGNITION SWITCH EX1:1".to_owned()),
             engine_mode_selector1_position: EngineModeSelector::Norm,
-            engine_1_n2_percent_id: context.get_identifier("ENGINE_N2:1".to_owned()),
-            engine_2_n2_percent_id: context.get_identifier("ENGINE_N2:2".to_owned()),
-            engine_1_n2_percent: Ratio::new::<percent>(0.),
-            engine_2_n2_percent: Ratio::new::<percent>(0.),
+
+            start_controls: [1, 2].map(|number| {
+                EngineStartControl::new(
+                    context,
+                    number,
+                    LeapEngine::start_schedule(),
+                    ElectricalBusType::AlternatingCurrentEssentialShed,
+                    ElectricalBusType::AlternatingCurrent(1),
+                )
+            }),
         }
     }
 
-    fn engine_state(&self, number: usize) -> EngineState {
-        match number {
-            1 => self.engine_1_state,
-            2 => self.engine_2_state,
-            _ => panic!("Invalid engine number"),
+    fn update(&mut self, context: &UpdateContext, engine_systems: &[EngineBleedAirSystem; 2]) {
+        for (start_control, engine_system) in self.start_controls.iter_mut().zip(engine_systems) {
+            start_control.update(
+                context,
+                self.engine_mode_selector1_position,
+                engine_system.engine_starter_container_pressure(),
+            );
         }
     }
 
-    fn engine_n2_percent(&self, number: usize) -> f64 {
+    fn starter_valve_is_commanded_open(&self, number: usize) -> bool {
+        self.start_controls[number - 1].starter_valve_is_commanded_open()
+    }
+
+    fn engine_state(&self, number: usize) -> EngineState {
         match number {
-            1 => self.engine_1_n2_percent.get::<percent>(),
-            2 => self.engine_2_n2_percent.get::<percent>(),
+            1 => self.engine_1_state,
+            2 => self.engine_2_state,
             _ => panic!("Invalid engine number"),
         }
     }
@@ -1640,12 +1886,16 @@ impl FullAuthorityDigitalEngineControl {
     }
 }
 impl SimulationElement for FullAuthorityDigitalEngineControl {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.start_controls, visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.engine_1_state = reader.read(&self.engine_1_state_id);
         self.engine_2_state = reader.read(&self.engine_2_state_id);
         self.engine_mode_selector1_position = reader.read(&self.engine_mode_selector1_id);
-        self.engine_1_n2_percent = Ratio::new::<percent>(reader.read(&self.engine_1_n2_percent_id));
-        self.engine_2_n2_percent = Ratio::new::<percent>(reader.read(&self.engine_2_n2_percent_id));
     }
 }
 
@@ -1909,16 +2159,16 @@ pub mod tests {
         failures::FailureType,
         pneumatic::{
             BleedMonitoringComputerChannelOperationMode, ControllablePneumaticValve,
-            CrossBleedValveSelectorMode, EngineState, PneumaticContainer, PneumaticValveSignal,
-            TargetPressureTemperatureSignal, WingAntiIcePushButtonMode,
+            CrossBleedValveSelectorMode, EngineModeSelector, EngineState, PneumaticContainer,
+            PneumaticValveSignal, TargetPressureTemperatureSignal, WingAntiIcePushButtonMode,
         },
         shared::{
             arinc429::{Arinc429Word, SignStatus},
-            interpolation, ApuBleedAirValveSignal, CabinAltitude, CabinSimulation,
-            ControllerSignal, ElectricalBusType, ElectricalBuses, EmergencyElectricalState,
-            EngineCorrectedN1, EngineFirePushButtons, EngineStartState, HydraulicColor,
-            InternationalStandardAtmosphere, LgciuWeightOnWheels, MachNumber, PackFlowValveState,
-            PneumaticBleed, PneumaticValve, PotentialOrigin,
+            interpolation, ApuBleedAirValveSignal, BleedAirLeakZone, CabinAltitude,
+            CabinSimulation, ControllerSignal, ElectricalBusType, ElectricalBuses,
+            EmergencyElectricalState, EngineCorrectedN1, EngineFirePushButtons, EngineStartState,
+            HydraulicColor, InternationalStandardAtmosphere, LgciuWeightOnWheels, MachNumber,
+            PackFlowValveState, PneumaticBleed, PneumaticValve, PotentialOrigin,
         },
         simulation::{
             test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -1934,6 +2184,7 @@ pub mod tests {
         mass_rate::kilogram_per_second,
         pressure::psi,
         ratio::ratio,
+        temperature_interval,
         thermodynamic_temperature::degree_celsius,
         velocity::{foot_per_minute, knot},
     };
@@ -2384,6 +2635,7 @@ pub mod tests {
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.7));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.2));
             self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N2:1", 70.);
 
             self
         }
@@ -2393,6 +2645,7 @@ pub mod tests {
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.7));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.2));
             self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N2:2", 70.);
 
             self
         }
@@ -2402,6 +2655,7 @@ pub mod tests {
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(1.05));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.87));
             self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N2:1", 105.);
 
             self
         }
@@ -2411,6 +2665,7 @@ pub mod tests {
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(1.05));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.87));
             self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N2:2", 105.);
 
             self
         }
@@ -2419,6 +2674,7 @@ pub mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(n1));
             self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N2:1", 70.);
 
             self
         }
@@ -2433,6 +2689,7 @@ pub mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(n1));
             self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N2:2", 70.);
 
             self
         }
@@ -2466,6 +2723,7 @@ pub mod tests {
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.92));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.5));
             self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N2:1", 92.);
 
             self
         }
@@ -2475,12 +2733,15 @@ pub mod tests {
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.92));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.5));
             self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N2:2", 92.);
 
             self
         }
 
         fn stop_eng1(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", false);
+            self.write_by_name("FUELSYSTEM VALVE SWITCH:1", false);
+            self.write_by_name("ENGINE_N2:1", 0.);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.));
             self.write_by_name("ENGINE_STATE:1", EngineState::Off);
@@ -2490,6 +2751,8 @@ pub mod tests {
 
         fn stop_eng2(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", false);
+            self.write_by_name("FUELSYSTEM VALVE SWITCH:2", false);
+            self.write_by_name("ENGINE_N2:2", 0.);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.));
             self.write_by_name("ENGINE_STATE:2", EngineState::Off);
@@ -2499,6 +2762,12 @@ pub mod tests {
 
         fn start_eng1(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
+            self.write_by_name(
+                "TURB ENG IGNITION SWITCH EX1:1",
+                EngineModeSelector::Ignition,
+            );
+            self.write_by_name("FUELSYSTEM VALVE SWITCH:1", true);
+            self.write_by_name("ENGINE_N2:1", 0.);
             self.write_by_name("ENGINE_STATE:1", EngineState::Starting);
 
             self
@@ -2506,6 +2775,12 @@ pub mod tests {
 
         fn start_eng2(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
+            self.write_by_name(
+                "TURB ENG IGNITION SWITCH EX1:1",
+                EngineModeSelector::Ignition,
+            );
+            self.write_by_name("FUELSYSTEM VALVE SWITCH:2", true);
+            self.write_by_name("ENGINE_N2:2", 0.);
             self.write_by_name("ENGINE_STATE:2", EngineState::Starting);
 
             self
@@ -2742,6 +3017,30 @@ pub mod tests {
             self.query(|a| a.pneumatic.cross_bleed_valve.is_open())
         }
 
+        fn leak_detected(&mut self, zone: BleedAirLeakZone) -> bool {
+            self.read_by_name(&format!("PNEU_{}_LEAK_DETECTED", zone))
+        }
+
+        fn leak_detection_loop_fault(&mut self, zone: BleedAirLeakZone) -> bool {
+            self.read_by_name(&format!("PNEU_{}_LEAK_DETECTION_LOOP_FAULT", zone))
+        }
+
+        fn bleed_leak(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_ENG_{}_BLEED_LEAK", number))
+        }
+
+        fn apu_bleed_leak(&mut self) -> bool {
+            self.read_by_name("PNEU_APU_BLEED_LEAK")
+        }
+
+        fn bleed_not_closed(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_ENG_{}_BLEED_NOT_CLOSED", number))
+        }
+
+        fn apu_bleed_leak_isolation_commanded(&self) -> bool {
+            self.query(|a| a.pneumatic.apu_bleed_leak_isolation_commanded())
+        }
+
         fn cross_bleed_valve_selector(&self) -> CrossBleedValveSelectorMode {
             self.query(|a| a.pneumatic_overhead_panel.cross_bleed_mode())
         }
@@ -3425,6 +3724,22 @@ pub mod tests {
         assert!(test_bed.es_valve_is_open(2));
     }
 
+    #[test]
+    fn starter_valve_closes_at_starter_cut_out() {
+        let mut test_bed = test_bed_with()
+            .start_eng1()
+            .stop_eng2()
+            .set_bleed_air_running()
+            .and_stabilize();
+
+        assert!(test_bed.es_valve_is_open(1));
+
+        test_bed.write_by_name("ENGINE_N2:1", 51.);
+        test_bed = test_bed.and_run().and_run();
+
+        assert!(!test_bed.es_valve_is_open(1));
+    }
+
     #[test]
     fn apu_bleed_engine_start() {
         let mut test_bed = test_bed_with()
@@ -3958,6 +4273,175 @@ pub mod tests {
         assert!(!test_bed.precooler_outlet_pressure(2).is_nan());
     }
 
+    #[test]
+    fn wing_leak_closes_onside_pressure_regulating_valve() {
+        let mut test_bed = test_bed_with().toga_eng1().toga_eng2().and_stabilize();
+
+        assert!(test_bed.pr_valve_is_open(1));
+
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(1)));
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        assert!(test_bed.bleed_leak(1));
+        assert!(!test_bed.bleed_leak(2));
+        assert!(!test_bed.pr_valve_is_open(1));
+        assert!(test_bed.pr_valve_is_open(2));
+    }
+
+    #[test]
+    fn pylon_leak_closes_onside_pressure_regulating_valve() {
+        let mut test_bed = test_bed_with()
+            .eng1_n1(0.8)
+            .and_eng1_n2_based_on_n1()
+            .eng2_n1(0.8)
+            .and_eng2_n2_based_on_n1()
+            .in_isa_atmosphere(Length::new::<foot>(30000.))
+            .and_stabilize();
+
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Pylon(2)));
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        assert!(test_bed.bleed_leak(2));
+        assert!(test_bed.pr_valve_is_open(1));
+        assert!(!test_bed.pr_valve_is_open(2));
+    }
+
+    #[test]
+    fn leak_closes_cross_bleed_valve_in_auto_mode() {
+        let mut test_bed = test_bed_with()
+            .idle_eng1()
+            .idle_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+            .set_bleed_air_running()
+            .and_stabilize();
+
+        assert!(test_bed.cross_bleed_valve_is_open());
+
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(2)));
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        assert!(test_bed.bleed_leak(2));
+        assert!(!test_bed.cross_bleed_valve_is_open());
+    }
+
+    #[test]
+    fn apu_leak_commands_apu_bleed_valve_closed() {
+        let mut test_bed = test_bed_with().set_bleed_air_running().and_stabilize();
+
+        assert!(!test_bed.apu_bleed_leak_isolation_commanded());
+
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Apu));
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        assert!(test_bed.apu_bleed_leak());
+        assert!(test_bed.apu_bleed_leak_isolation_commanded());
+    }
+
+    #[test]
+    fn left_wing_leak_does_not_command_apu_bleed_valve_closed_during_engine_start() {
+        let mut test_bed = test_bed_with()
+            .toga_eng1()
+            .toga_eng2()
+            .set_bleed_air_running()
+            .and_stabilize();
+
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(1)));
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        assert!(test_bed.apu_bleed_leak_isolation_commanded());
+
+        test_bed = test_bed
+            .set_engine_state(2, EngineState::Starting)
+            .and_run();
+
+        assert!(!test_bed.apu_bleed_leak_isolation_commanded());
+    }
+
+    #[test]
+    fn leak_isolation_is_only_reset_with_engine_bleed_push_button_off() {
+        let mut test_bed = test_bed_with().toga_eng1().toga_eng2().and_stabilize();
+
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(1)));
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+        test_bed.unfail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(1)));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert!(!test_bed.leak_detected(BleedAirLeakZone::Wing(1)));
+        assert!(!test_bed.pr_valve_is_open(1));
+
+        test_bed = test_bed
+            .set_engine_bleed_push_button_off(1)
+            .and_run()
+            .set_engine_bleed_push_button_auto(1)
+            .and_stabilize();
+
+        assert!(test_bed.pr_valve_is_open(1));
+    }
+
+    #[test]
+    fn failed_leak_detection_loop_does_not_isolate_leak() {
+        let mut test_bed = test_bed_with().toga_eng1().toga_eng2().and_stabilize();
+
+        test_bed.fail(FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Wing(
+            1,
+        )));
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(1)));
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        assert!(!test_bed.leak_detected(BleedAirLeakZone::Wing(1)));
+        assert!(test_bed.leak_detection_loop_fault(BleedAirLeakZone::Wing(1)));
+        assert!(test_bed.pr_valve_is_open(1));
+    }
+
+    #[test]
+    fn pressure_regulating_valve_stuck_open_triggers_bleed_not_closed() {
+        let mut test_bed = test_bed_with().idle_eng1().idle_eng2().and_stabilize();
+
+        test_bed.fail(FailureType::PressureRegulatingValveStuckOpen(1));
+        test_bed = test_bed.set_engine_bleed_push_button_off(1).and_run();
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(test_bed.pr_valve_is_open(1));
+        assert!(test_bed.bleed_not_closed(1));
+        assert!(!test_bed.bleed_not_closed(2));
+    }
+
+    #[test]
+    fn pressure_regulating_valve_stuck_closed_does_not_open() {
+        let mut test_bed = test_bed_with().stop_eng1().stop_eng2().and_run();
+
+        test_bed.fail(FailureType::PressureRegulatingValveStuckClosed(1));
+        test_bed = test_bed.idle_eng1().idle_eng2().and_stabilize();
+
+        assert!(!test_bed.pr_valve_is_open(1));
+        assert!(test_bed.pr_valve_is_open(2));
+    }
+
+    #[test]
+    fn high_pressure_valve_stuck_open_stays_open_at_high_power() {
+        let mut test_bed = test_bed_with().idle_eng1().idle_eng2().and_stabilize();
+
+        test_bed.fail(FailureType::HighPressureValveStuckOpen(1));
+        test_bed = test_bed.toga_eng1().toga_eng2().and_stabilize();
+
+        assert!(test_bed.hp_valve_is_open(1));
+        assert!(!test_bed.hp_valve_is_open(2));
+    }
+
+    #[test]
+    fn clogged_precooler_does_not_cool_bleed_air() {
+        let mut test_bed = test_bed_with().toga_eng1().toga_eng2().and_stabilize();
+
+        test_bed.fail(FailureType::PrecoolerClogged(1));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert!(
+            test_bed.precooler_outlet_temperature(1)
+                > test_bed.precooler_outlet_temperature(2)
+                    + TemperatureInterval::new::<temperature_interval::degree_celsius>(10.)
+        );
+    }
+
     #[test]
     fn bleed_monitoring_computers_powered_by_correct_buses() {
         let mut test_bed = test_bed()
@@ -11,7 +11,7 @@ mod rudder;
 mod spoilers;
 mod trimmable_horizontal_stabilizer;
 
-use a320_systems::A320;
+use a320_systems::{doors::A320Doors, A320};
 use ailerons::ailerons;
 use autobrakes::autobrakes;
 use brakes::brakes;
@@ -29,8 +29,9 @@ use systems::air_conditioning::{
 };
 use systems::failures::FailureType;
 use systems::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, GearActuatorId,
-    HydraulicColor, LgciuId, ProximityDetectorId,
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, BleedAirLeakZone, ElectricalBusType,
+    FlightControlSurface, GearActuatorId, HydraulicColor, HydraulicLeakLocation, LgciuId,
+    ProximityDetectorId,
 };
 use systems_wasm::aspects::ExecuteOn;
 use systems_wasm::{MsfsSimulationBuilder, Variable};
@@ -65,6 +66,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .with_auxiliary_power_unit(Variable::named("OVHD_APU_START_PB_IS_AVAILABLE"), 8, 7)?
     .with_engine_anti_ice(2)?
     .with_wing_anti_ice()?
+    .with_residual_pressure_door_lock(A320Doors::PASSENGER_DOORS)?
     .with_failures([
         (
             21_000,
@@ -98,6 +100,9 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (21_017, FailureType::OutflowValveFault),
         (21_018, FailureType::SafetyValveFault),
         (21_019, FailureType::RapidDecompression),
+        (21_020, FailureType::AvionicsBlowerFan),
+        (21_021, FailureType::AvionicsExtractFan),
+        (21_022, FailureType::AvionicsSmoke),
         (24_000, FailureType::TransformerRectifier(1)),
         (24_001, FailureType::TransformerRectifier(2)),
         (24_002, FailureType::TransformerRectifier(3)),
@@ -161,6 +166,735 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             24_113,
             FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
         ),
+        (
+            27_100,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_101,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_102,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::RightAileron,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_103,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::RightAileron,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_110,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::LeftElevator,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_111,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::LeftElevator,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_112,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::RightElevator,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_113,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::RightElevator,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_120,
+            FailureType::FlightControlServoJam(FlightControlSurface::Rudder, HydraulicColor::Green),
+        ),
+        (
+            27_121,
+            FailureType::FlightControlServoJam(FlightControlSurface::Rudder, HydraulicColor::Blue),
+        ),
+        (
+            27_122,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_131,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::LeftSpoiler(1),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_132,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::LeftSpoiler(2),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_133,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::LeftSpoiler(3),
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_134,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::LeftSpoiler(4),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_135,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::LeftSpoiler(5),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_141,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::RightSpoiler(1),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_142,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::RightSpoiler(2),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_143,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::RightSpoiler(3),
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_144,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::RightSpoiler(4),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_145,
+            FailureType::FlightControlServoJam(
+                FlightControlSurface::RightSpoiler(5),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_200,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_201,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_202,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::RightAileron,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_203,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::RightAileron,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_210,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::LeftElevator,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_211,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::LeftElevator,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_212,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::RightElevator,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_213,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::RightElevator,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_220,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_221,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_222,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_231,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::LeftSpoiler(1),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_232,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::LeftSpoiler(2),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_233,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::LeftSpoiler(3),
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_234,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::LeftSpoiler(4),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_235,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::LeftSpoiler(5),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_241,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::RightSpoiler(1),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_242,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::RightSpoiler(2),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_243,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::RightSpoiler(3),
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_244,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::RightSpoiler(4),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_245,
+            FailureType::FlightControlServoRunaway(
+                FlightControlSurface::RightSpoiler(5),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_300,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_301,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_302,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::RightAileron,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_303,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::RightAileron,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_310,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::LeftElevator,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_311,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::LeftElevator,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_312,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::RightElevator,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_313,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::RightElevator,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_320,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_321,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_322,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_331,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::LeftSpoiler(1),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_332,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::LeftSpoiler(2),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_333,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::LeftSpoiler(3),
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_334,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::LeftSpoiler(4),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_335,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::LeftSpoiler(5),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_341,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::RightSpoiler(1),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_342,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::RightSpoiler(2),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_343,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::RightSpoiler(3),
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_344,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::RightSpoiler(4),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_345,
+            FailureType::FlightControlServoFeedbackLoss(
+                FlightControlSurface::RightSpoiler(5),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_400,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_401,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_402,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::RightAileron,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_403,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::RightAileron,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_410,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::LeftElevator,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_411,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::LeftElevator,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_412,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::RightElevator,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_413,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::RightElevator,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_420,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_421,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_422,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_431,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::LeftSpoiler(1),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_432,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::LeftSpoiler(2),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_433,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::LeftSpoiler(3),
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_434,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::LeftSpoiler(4),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_435,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::LeftSpoiler(5),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_441,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::RightSpoiler(1),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_442,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::RightSpoiler(2),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_443,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::RightSpoiler(3),
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_444,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::RightSpoiler(4),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_445,
+            FailureType::FlightControlServoOscillation(
+                FlightControlSurface::RightSpoiler(5),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_500,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_501,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_502,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::RightAileron,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_503,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::RightAileron,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_510,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::LeftElevator,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_511,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::LeftElevator,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_512,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::RightElevator,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_513,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::RightElevator,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_520,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_521,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_522,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_531,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::LeftSpoiler(1),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_532,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::LeftSpoiler(2),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_533,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::LeftSpoiler(3),
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_534,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::LeftSpoiler(4),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_535,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::LeftSpoiler(5),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_541,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::RightSpoiler(1),
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_542,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::RightSpoiler(2),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_543,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::RightSpoiler(3),
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_544,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::RightSpoiler(4),
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_545,
+            FailureType::FlightControlServoInternalLeak(
+                FlightControlSurface::RightSpoiler(5),
+                HydraulicColor::Green,
+            ),
+        ),
         (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
         (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
         (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
@@ -198,6 +932,86 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             29_012,
             FailureType::ElecPumpOverheat(AirbusElectricPumpId::Yellow),
         ),
+        (
+            29_013,
+            FailureType::HydraulicExternalLeak(
+                HydraulicColor::Green,
+                HydraulicLeakLocation::PumpSection(1),
+            ),
+        ),
+        (
+            29_014,
+            FailureType::HydraulicExternalLeak(
+                HydraulicColor::Green,
+                HydraulicLeakLocation::SystemSectionUpstreamPriorityValve,
+            ),
+        ),
+        (
+            29_015,
+            FailureType::HydraulicExternalLeak(
+                HydraulicColor::Green,
+                HydraulicLeakLocation::SystemSectionDownstreamPriorityValve,
+            ),
+        ),
+        (
+            29_016,
+            FailureType::HydraulicExternalLeak(
+                HydraulicColor::Blue,
+                HydraulicLeakLocation::PumpSection(1),
+            ),
+        ),
+        (
+            29_017,
+            FailureType::HydraulicExternalLeak(
+                HydraulicColor::Blue,
+                HydraulicLeakLocation::SystemSectionUpstreamPriorityValve,
+            ),
+        ),
+        (
+            29_018,
+            FailureType::HydraulicExternalLeak(
+                HydraulicColor::Blue,
+                HydraulicLeakLocation::SystemSectionDownstreamPriorityValve,
+            ),
+        ),
+        (
+            29_019,
+            FailureType::HydraulicExternalLeak(
+                HydraulicColor::Yellow,
+                HydraulicLeakLocation::PumpSection(1),
+            ),
+        ),
+        (
+            29_020,
+            FailureType::HydraulicExternalLeak(
+                HydraulicColor::Yellow,
+                HydraulicLeakLocation::SystemSectionUpstreamPriorityValve,
+            ),
+        ),
+        (
+            29_021,
+            FailureType::HydraulicExternalLeak(
+                HydraulicColor::Yellow,
+                HydraulicLeakLocation::SystemSectionDownstreamPriorityValve,
+            ),
+        ),
+        (
+            29_022,
+            FailureType::EnginePumpWear(AirbusEngineDrivenPumpId::Green),
+        ),
+        (
+            29_023,
+            FailureType::EnginePumpWear(AirbusEngineDrivenPumpId::Yellow),
+        ),
+        (
+            29_024,
+            FailureType::ElecPumpWear(AirbusElectricPumpId::Blue),
+        ),
+        (
+            29_025,
+            FailureType::ElecPumpWear(AirbusElectricPumpId::Yellow),
+        ),
+        (29_026, FailureType::PowerTransferUnitShaft),
         (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
         (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
         (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
@@ -274,6 +1088,30 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             32_025,
             FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
         ),
+        (
+            32_030,
+            FailureType::GearActuatorInternalLeak(GearActuatorId::GearNose),
+        ),
+        (
+            32_031,
+            FailureType::GearActuatorInternalLeak(GearActuatorId::GearLeft),
+        ),
+        (
+            32_032,
+            FailureType::GearActuatorInternalLeak(GearActuatorId::GearRight),
+        ),
+        (
+            32_033,
+            FailureType::GearActuatorInternalLeak(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_034,
+            FailureType::GearActuatorInternalLeak(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_035,
+            FailureType::GearActuatorInternalLeak(GearActuatorId::GearDoorRight),
+        ),
         (
             32_100,
             FailureType::BrakeHydraulicLeak(HydraulicColor::Green),
@@ -293,6 +1131,82 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             34_030,
             FailureType::EnhancedGroundProximityWarningSystemComputer,
         ),
+        (34_040, FailureType::MultiModeReceiver(1)),
+        (34_041, FailureType::MultiModeReceiver(2)),
+        (34_050, FailureType::IlsLocalizerSignalLoss(1)),
+        (34_051, FailureType::IlsLocalizerSignalLoss(2)),
+        (34_052, FailureType::IlsGlideslopeSignalLoss(1)),
+        (34_053, FailureType::IlsGlideslopeSignalLoss(2)),
+        (34_054, FailureType::IlsLocalizerBeamBend(1)),
+        (34_055, FailureType::IlsLocalizerBeamBend(2)),
+        (34_056, FailureType::IlsGlideslopeBeamBend(1)),
+        (34_057, FailureType::IlsGlideslopeBeamBend(2)),
+        (34_060, FailureType::TrafficCollisionAvoidanceSystem),
+        (34_070, FailureType::InertialReferenceHeadingDrift(1)),
+        (34_071, FailureType::InertialReferenceHeadingDrift(2)),
+        (34_072, FailureType::InertialReferenceHeadingDrift(3)),
+        (
+            36_000,
+            FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(1)),
+        ),
+        (
+            36_001,
+            FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(2)),
+        ),
+        (
+            36_010,
+            FailureType::BleedAirDuctLeak(BleedAirLeakZone::Pylon(1)),
+        ),
+        (
+            36_011,
+            FailureType::BleedAirDuctLeak(BleedAirLeakZone::Pylon(2)),
+        ),
+        (36_020, FailureType::BleedAirDuctLeak(BleedAirLeakZone::Apu)),
+        (
+            36_100,
+            FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Wing(1)),
+        ),
+        (
+            36_101,
+            FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Wing(2)),
+        ),
+        (
+            36_110,
+            FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Pylon(1)),
+        ),
+        (
+            36_111,
+            FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Pylon(2)),
+        ),
+        (
+            36_120,
+            FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Apu),
+        ),
+        (36_200, FailureType::PressureRegulatingValveStuckOpen(1)),
+        (36_201, FailureType::PressureRegulatingValveStuckOpen(2)),
+        (36_210, FailureType::PressureRegulatingValveStuckClosed(1)),
+        (36_211, FailureType::PressureRegulatingValveStuckClosed(2)),
+        (36_220, FailureType::HighPressureValveStuckOpen(1)),
+        (36_221, FailureType::HighPressureValveStuckOpen(2)),
+        (36_230, FailureType::HighPressureValveStuckClosed(1)),
+        (36_231, FailureType::HighPressureValveStuckClosed(2)),
+        (36_240, FailureType::OverpressureValveStuckOpen(1)),
+        (36_241, FailureType::OverpressureValveStuckOpen(2)),
+        (36_250, FailureType::OverpressureValveStuckClosed(1)),
+        (36_251, FailureType::OverpressureValveStuckClosed(2)),
+        (36_300, FailureType::PrecoolerClogged(1)),
+        (36_301, FailureType::PrecoolerClogged(2)),
+        (49_000, FailureType::ApuNoLightOff),
+        (49_001, FailureType::ApuHungStart),
+        (49_002, FailureType::ApuOverspeed),
+        (49_003, FailureType::ApuEgtOvertemperature),
+        (49_004, FailureType::ApuOilPump),
+        (49_005, FailureType::ApuOilCooler),
+        (49_006, FailureType::ApuFuelPressureLoss),
+        (78_000, FailureType::ReverserInadvertentDeployment(1)),
+        (78_001, FailureType::ReverserInadvertentDeployment(2)),
+        (78_010, FailureType::ReverserJammed(1)),
+        (78_011, FailureType::ReverserJammed(2)),
     ])
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
@@ -321,6 +1235,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("FUEL TANK RIGHT AUX QUANTITY", "gallons", 0)?
     .provides_aircraft_variable("FUELSYSTEM LINE FUEL FLOW", "gallons per hour", 18)?
     .provides_aircraft_variable_range("FUELSYSTEM PUMP ACTIVE", "Bool", 1..=7)?
+    .provides_aircraft_variable_range("FUELSYSTEM VALVE SWITCH", "Bool", 1..=2)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 0)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 1)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 2)?
@@ -340,6 +1255,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("KOHLSMAN SETTING MB", "Millibars", 1)?
     .provides_aircraft_variable("LIGHT BEACON", "Bool", 0)?
     .provides_aircraft_variable("LIGHT BEACON ON", "Bool", 0)?
+    .provides_aircraft_variable("PITOT HEAT", "Bool", 0)?
     .provides_aircraft_variable("PLANE ALT ABOVE GROUND", "Feet", 0)?
     .provides_aircraft_variable("PLANE PITCH DEGREES", "Degrees", 0)?
     .provides_aircraft_variable("PLANE BANK DEGREES", "Degrees", 0)?
@@ -347,6 +1263,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("PLANE HEADING DEGREES TRUE", "Degrees", 0)?
     .provides_aircraft_variable("PLANE LATITUDE", "degree latitude", 0)?
     .provides_aircraft_variable("PLANE LONGITUDE", "degree longitude", 0)?
+    .provides_aircraft_variable("PLANE ALTITUDE", "Feet", 0)?
     .provides_aircraft_variable("PRESSURE ALTITUDE", "Feet", 0)?
     .provides_aircraft_variable("PUSHBACK STATE", "Enum", 0)?
     .provides_aircraft_variable("PUSHBACK ANGLE", "Radians", 0)?
@@ -384,13 +1301,8 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("PAYLOAD STATION WEIGHT", "Pounds", 7)?
     .provides_aircraft_variable("PAYLOAD STATION WEIGHT", "Pounds", 8)?
     .provides_aircraft_variable("IS SLEW ACTIVE", "Bool", 0)?
-    .provides_aircraft_variable("NAV HAS NAV", "Bool", 3)?
-    .provides_aircraft_variable("NAV HAS LOC", "Bool", 3)?
-    .provides_aircraft_variable("NAV HAS GLIDE SLOPE", "Bool", 3)?
-    .provides_aircraft_variable("NAV MAGVAR", "degree", 3)?
-    .provides_aircraft_variable("NAV RADIAL ERROR", "degree", 3)?
-    .provides_aircraft_variable("NAV GLIDE SLOPE ERROR", "degree", 3)?
     .provides_aircraft_variable("NAV FREQUENCY", "Hz", 3)?
+    .provides_aircraft_variable("NAV FREQUENCY", "Hz", 4)?
     .provides_named_variable("FSDT_GSX_BOARDING_STATE")?
     .provides_named_variable("FSDT_GSX_DEBOARDING_STATE")?
     .provides_named_variable("FSDT_GSX_NUMPASSENGERS_BOARDING_TOTAL")?
@@ -1,6 +1,6 @@
 // Copyright (c) 2023-2025 FlyByWire Simulations
 // SPDX-License-Identifier: GPL-3.0
-import { FailureDefinition } from '@flybywiresim/fbw-sdk';
+import { FailureDefinition, FailureReliability } from '@flybywiresim/fbw-sdk';
 
 // Keep in mind that the CPP code also keeps a list of failure codes: fbw-a380x\src\wasm\fbw_a380\src\failures\FailureList.h
 export const A380Failure = Object.freeze({
@@ -127,6 +127,8 @@ export const A380Failure = Object.freeze({
   Fcdc1: 27006,
   Fcdc2: 27007,
 
+  TrimTankTransferValveStuckClosed: 28000,
+
   GreenReservoirLeak: 29000,
   YellowReservoirLeak: 29001,
   GreenReservoirAirLeak: 29002,
@@ -145,6 +147,32 @@ export const A380Failure = Object.freeze({
   EnginePump3BOHeat: 29015,
   EnginePump4AOHeat: 29016,
   EnginePump4BOHeat: 29017,
+  GreenSystemUpstreamPriorityValveLeak: 29018,
+  GreenSystemDownstreamPriorityValveLeak: 29019,
+  GreenAuxiliarySectionLeak: 29020,
+  YellowSystemUpstreamPriorityValveLeak: 29021,
+  YellowSystemDownstreamPriorityValveLeak: 29022,
+  GreenElecPumpAWear: 29023,
+  GreenElecPumpBWear: 29024,
+  YellowElecPumpAWear: 29025,
+  YellowElecPumpBWear: 29026,
+  EnginePump1AWear: 29027,
+  EnginePump1BWear: 29028,
+  EnginePump2AWear: 29029,
+  EnginePump2BWear: 29030,
+  EnginePump3AWear: 29031,
+  EnginePump3BWear: 29032,
+  EnginePump4AWear: 29033,
+  EnginePump4BWear: 29034,
+
+  CaptPfdDisplayUnit: 31000,
+  CaptNdDisplayUnit: 31001,
+  CaptMfdDisplayUnit: 31002,
+  FoPfdDisplayUnit: 31003,
+  FoNdDisplayUnit: 31004,
+  FoMfdDisplayUnit: 31005,
+  EwdDisplayUnit: 31006,
+  SdDisplayUnit: 31007,
 
   Fws1: 31100,
   Fws2: 31101,
@@ -180,6 +208,21 @@ export const A380Failure = Object.freeze({
   Gpws1: 34007,
   Gpws2: 34008,
 
+  Mmr1: 34040,
+  Mmr2: 34041,
+  IlsLocalizerSignalLoss1: 34050,
+  IlsLocalizerSignalLoss2: 34051,
+  IlsGlideslopeSignalLoss1: 34052,
+  IlsGlideslopeSignalLoss2: 34053,
+  IlsLocalizerBeamBend1: 34054,
+  IlsLocalizerBeamBend2: 34055,
+  IlsGlideslopeBeamBend1: 34056,
+  IlsGlideslopeBeamBend2: 34057,
+  Tcas: 34060,
+  IrHeadingDrift1: 34070,
+  IrHeadingDrift2: 34071,
+  IrHeadingDrift3: 34072,
+
   NssAnsu1: 46001,
   NssAnsu2: 46002,
   FltOpsAnsu: 46003,
@@ -187,6 +230,53 @@ export const A380Failure = Object.freeze({
   FirstOfficerLaptop: 46005,
   CaptainOit: 46006,
   FirstOfficerOit: 46007,
+
+  WingBleedLeak1: 36000,
+  WingBleedLeak2: 36001,
+  WingBleedLeak3: 36002,
+  WingBleedLeak4: 36003,
+  PylonBleedLeak1: 36010,
+  PylonBleedLeak2: 36011,
+  PylonBleedLeak3: 36012,
+  PylonBleedLeak4: 36013,
+  ApuBleedLeak: 36020,
+  WingLeakDetectionLoop1: 36100,
+  WingLeakDetectionLoop2: 36101,
+  WingLeakDetectionLoop3: 36102,
+  WingLeakDetectionLoop4: 36103,
+  PylonLeakDetectionLoop1: 36110,
+  PylonLeakDetectionLoop2: 36111,
+  PylonLeakDetectionLoop3: 36112,
+  PylonLeakDetectionLoop4: 36113,
+  ApuLeakDetectionLoop: 36120,
+  PressureRegulatingValveStuckOpen1: 36200,
+  PressureRegulatingValveStuckOpen2: 36201,
+  PressureRegulatingValveStuckOpen3: 36202,
+  PressureRegulatingValveStuckOpen4: 36203,
+  PressureRegulatingValveStuckClosed1: 36210,
+  PressureRegulatingValveStuckClosed2: 36211,
+  PressureRegulatingValveStuckClosed3: 36212,
+  PressureRegulatingValveStuckClosed4: 36213,
+  HighPressureValveStuckOpen1: 36220,
+  HighPressureValveStuckOpen2: 36221,
+  HighPressureValveStuckOpen3: 36222,
+  HighPressureValveStuckOpen4: 36223,
+  HighPressureValveStuckClosed1: 36230,
+  HighPressureValveStuckClosed2: 36231,
+  HighPressureValveStuckClosed3: 36232,
+  HighPressureValveStuckClosed4: 36233,
+  PrecoolerClogged1: 36300,
+  PrecoolerClogged2: 36301,
+  PrecoolerClogged3: 36302,
+  PrecoolerClogged4: 36303,
+
+  ApuNoLightOff: 49000,
+  ApuHungStart: 49001,
+  ApuOverspeed: 49002,
+  ApuEgtOvertemperature: 49003,
+  ApuOilPump: 49004,
+  ApuOilCooler: 49005,
+  ApuFuelPressureLoss: 49006,
 });
 
 export const A380FailureDefinitions: FailureDefinition[] = [
@@ -313,6 +403,8 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [27, A380Failure.Fcdc1, 'FCDC 1'],
   [27, A380Failure.Fcdc2, 'FCDC 2'],
 
+  [28, A380Failure.TrimTankTransferValveStuckClosed, 'Trim tank transfer valve stuck closed'],
+
   [29, A380Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A380Failure.YellowReservoirLeak, 'Yellow reservoir leak'],
   [29, A380Failure.GreenReservoirAirLeak, 'Green reservoir air leak'],
@@ -331,7 +423,32 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [29, A380Failure.EnginePump3BOHeat, 'Engine 3 pump B overheat'],
   [29, A380Failure.EnginePump4AOHeat, 'Engine 4 pump A overheat'],
   [29, A380Failure.EnginePump4BOHeat, 'Engine 4 pump B overheat'],
+  [29, A380Failure.GreenSystemUpstreamPriorityValveLeak, 'Green system leak upstream priority valve'],
+  [29, A380Failure.GreenSystemDownstreamPriorityValveLeak, 'Green system leak downstream priority valve'],
+  [29, A380Failure.GreenAuxiliarySectionLeak, 'Green auxiliary section leak'],
+  [29, A380Failure.YellowSystemUpstreamPriorityValveLeak, 'Yellow system leak upstream priority valve'],
+  [29, A380Failure.YellowSystemDownstreamPriorityValveLeak, 'Yellow system leak downstream priority valve'],
+  [29, A380Failure.GreenElecPumpAWear, 'Green A elec pump wear'],
+  [29, A380Failure.GreenElecPumpBWear, 'Green B elec pump wear'],
+  [29, A380Failure.YellowElecPumpAWear, 'Yellow A elec pump wear'],
+  [29, A380Failure.YellowElecPumpBWear, 'Yellow B elec pump wear'],
+  [29, A380Failure.EnginePump1AWear, 'Engine 1 pump A wear'],
+  [29, A380Failure.EnginePump1BWear, 'Engine 1 pump B wear'],
+  [29, A380Failure.EnginePump2AWear, 'Engine 2 pump A wear'],
+  [29, A380Failure.EnginePump2BWear, 'Engine 2 pump B wear'],
+  [29, A380Failure.EnginePump3AWear, 'Engine 3 pump A wear'],
+  [29, A380Failure.EnginePump3BWear, 'Engine 3 pump B wear'],
+  [29, A380Failure.EnginePump4AWear, 'Engine 4 pump A wear'],
+  [29, A380Failure.EnginePump4BWear, 'Engine 4 pump B wear'],
 
+  [31, A380Failure.CaptPfdDisplayUnit, 'Captain PFD display unit'],
+  [31, A380Failure.CaptNdDisplayUnit, 'Captain ND display unit'],
+  [31, A380Failure.CaptMfdDisplayUnit, 'Captain MFD display unit'],
+  [31, A380Failure.FoPfdDisplayUnit, 'F/O PFD display unit'],
+  [31, A380Failure.FoNdDisplayUnit, 'F/O ND display unit'],
+  [31, A380Failure.FoMfdDisplayUnit, 'F/O MFD display unit'],
+  [31, A380Failure.EwdDisplayUnit, 'EWD display unit'],
+  [31, A380Failure.SdDisplayUnit, 'SD display unit'],
   [31, A380Failure.Fws1, 'FWS 1'],
   [31, A380Failure.Fws2, 'FWS 2'],
   [31, A380Failure.Fws1AudioFunction, 'FWS 1 Audio Function'],
@@ -360,6 +477,20 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [34, A380Failure.Gpws2, 'GPWS 2'],
   [34, A380Failure.Transponder1, 'XPDR 1'],
   [34, A380Failure.Transponder2, 'XPDR 2'],
+  [34, A380Failure.Mmr1, 'MMR 1'],
+  [34, A380Failure.Mmr2, 'MMR 2'],
+  [34, A380Failure.IlsLocalizerSignalLoss1, 'MMR 1 ILS localizer signal loss'],
+  [34, A380Failure.IlsLocalizerSignalLoss2, 'MMR 2 ILS localizer signal loss'],
+  [34, A380Failure.IlsGlideslopeSignalLoss1, 'MMR 1 ILS glideslope signal loss'],
+  [34, A380Failure.IlsGlideslopeSignalLoss2, 'MMR 2 ILS glideslope signal loss'],
+  [34, A380Failure.IlsLocalizerBeamBend1, 'MMR 1 ILS localizer beam bend'],
+  [34, A380Failure.IlsLocalizerBeamBend2, 'MMR 2 ILS localizer beam bend'],
+  [34, A380Failure.IlsGlideslopeBeamBend1, 'MMR 1 ILS glideslope beam bend'],
+  [34, A380Failure.IlsGlideslopeBeamBend2, 'MMR 2 ILS glideslope beam bend'],
+  [34, A380Failure.Tcas, 'TCAS'],
+  [34, A380Failure.IrHeadingDrift1, 'IR 1 heading drift'],
+  [34, A380Failure.IrHeadingDrift2, 'IR 2 heading drift'],
+  [34, A380Failure.IrHeadingDrift3, 'IR 3 heading drift'],
 
   [46, A380Failure.NssAnsu1, 'NSS AVNCS ANSU 1'],
   [46, A380Failure.NssAnsu2, 'NSS AVNCS ANSU 2'],
@@ -368,4 +499,118 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [46, A380Failure.FirstOfficerLaptop, 'F/O Laptop'],
   [46, A380Failure.CaptainOit, 'Captain OIT'],
   [46, A380Failure.FirstOfficerOit, 'F/O OIT'],
+
+  [36, A380Failure.WingBleedLeak1, 'Wing bleed duct leak 1'],
+  [36, A380Failure.WingBleedLeak2, 'Wing bleed duct leak 2'],
+  [36, A380Failure.WingBleedLeak3, 'Wing bleed duct leak 3'],
+  [36, A380Failure.WingBleedLeak4, 'Wing bleed duct leak 4'],
+  [36, A380Failure.PylonBleedLeak1, 'Pylon bleed duct leak 1'],
+  [36, A380Failure.PylonBleedLeak2, 'Pylon bleed duct leak 2'],
+  [36, A380Failure.PylonBleedLeak3, 'Pylon bleed duct leak 3'],
+  [36, A380Failure.PylonBleedLeak4, 'Pylon bleed duct leak 4'],
+  [36, A380Failure.ApuBleedLeak, 'APU bleed duct leak'],
+  [36, A380Failure.WingLeakDetectionLoop1, 'Wing leak detection loop 1'],
+  [36, A380Failure.WingLeakDetectionLoop2, 'Wing leak detection loop 2'],
+  [36, A380Failure.WingLeakDetectionLoop3, 'Wing leak detection loop 3'],
+  [36, A380Failure.WingLeakDetectionLoop4, 'Wing leak detection loop 4'],
+  [36, A380Failure.PylonLeakDetectionLoop1, 'Pylon leak detection loop 1'],
+  [36, A380Failure.PylonLeakDetectionLoop2, 'Pylon leak detection loop 2'],
+  [36, A380Failure.PylonLeakDetectionLoop3, 'Pylon leak detection loop 3'],
+  [36, A380Failure.PylonLeakDetectionLoop4, 'Pylon leak detection loop 4'],
+  [36, A380Failure.ApuLeakDetectionLoop, 'APU leak detection loop'],
+  [36, A380Failure.PressureRegulatingValveStuckOpen1, 'Engine 1 PRV stuck open'],
+  [36, A380Failure.PressureRegulatingValveStuckOpen2, 'Engine 2 PRV stuck open'],
+  [36, A380Failure.PressureRegulatingValveStuckOpen3, 'Engine 3 PRV stuck open'],
+  [36, A380Failure.PressureRegulatingValveStuckOpen4, 'Engine 4 PRV stuck open'],
+  [36, A380Failure.PressureRegulatingValveStuckClosed1, 'Engine 1 PRV stuck closed'],
+  [36, A380Failure.PressureRegulatingValveStuckClosed2, 'Engine 2 PRV stuck closed'],
+  [36, A380Failure.PressureRegulatingValveStuckClosed3, 'Engine 3 PRV stuck closed'],
+  [36, A380Failure.PressureRegulatingValveStuckClosed4, 'Engine 4 PRV stuck closed'],
+  [36, A380Failure.HighPressureValveStuckOpen1, 'Engine 1 HP valve stuck open'],
+  [36, A380Failure.HighPressureValveStuckOpen2, 'Engine 2 HP valve stuck open'],
+  [36, A380Failure.HighPressureValveStuckOpen3, 'Engine 3 HP valve stuck open'],
+  [36, A380Failure.HighPressureValveStuckOpen4, 'Engine 4 HP valve stuck open'],
+  [36, A380Failure.HighPressureValveStuckClosed1, 'Engine 1 HP valve stuck closed'],
+  [36, A380Failure.HighPressureValveStuckClosed2, 'Engine 2 HP valve stuck closed'],
+  [36, A380Failure.HighPressureValveStuckClosed3, 'Engine 3 HP valve stuck closed'],
+  [36, A380Failure.HighPressureValveStuckClosed4, 'Engine 4 HP valve stuck closed'],
+  [36, A380Failure.PrecoolerClogged1, 'Engine 1 precooler clogged'],
+  [36, A380Failure.PrecoolerClogged2, 'Engine 2 precooler clogged'],
+  [36, A380Failure.PrecoolerClogged3, 'Engine 3 precooler clogged'],
+  [36, A380Failure.PrecoolerClogged4, 'Engine 4 precooler clogged'],
+
+  [49, A380Failure.ApuNoLightOff, 'APU No Light-Off'],
+  [49, A380Failure.ApuHungStart, 'APU Hung Start'],
+  [49, A380Failure.ApuOverspeed, 'APU Overspeed'],
+  [49, A380Failure.ApuEgtOvertemperature, 'APU EGT Overtemperature'],
+  [49, A380Failure.ApuOilPump, 'APU Oil Pump'],
+  [49, A380Failure.ApuOilCooler, 'APU Oil Cooler'],
+  [49, A380Failure.ApuFuelPressureLoss, 'APU Fuel Pressure Loss'],
+];
+
+/**
+ * Mean time between failures of the components failed by the random failure generator of the systems, in flight hours.
+ * Failures with an exposure stay dormant until the system is used.
+ */
+export const A380FailureReliabilities: FailureReliability[] = [
+  { identifier: A380Failure.CabinFan1, mtbfHours: 20000 },
+  { identifier: A380Failure.CabinFan2, mtbfHours: 20000 },
+  { identifier: A380Failure.CabinFan3, mtbfHours: 20000 },
+  { identifier: A380Failure.CabinFan4, mtbfHours: 20000 },
+  { identifier: A380Failure.Fdac1Ch1, mtbfHours: 40000 },
+  { identifier: A380Failure.Fdac1Ch2, mtbfHours: 40000 },
+  { identifier: A380Failure.Fdac2Ch1, mtbfHours: 40000 },
+  { identifier: A380Failure.Fdac2Ch2, mtbfHours: 40000 },
+  { identifier: A380Failure.CpcsApp1, mtbfHours: 40000 },
+  { identifier: A380Failure.CpcsApp2, mtbfHours: 40000 },
+  { identifier: A380Failure.CpcsApp3, mtbfHours: 40000 },
+  { identifier: A380Failure.CpcsApp4, mtbfHours: 40000 },
+
+  { identifier: A380Failure.TransformerRectifier1, mtbfHours: 60000 },
+  { identifier: A380Failure.TransformerRectifier2, mtbfHours: 60000 },
+  { identifier: A380Failure.TransformerRectifier3, mtbfHours: 60000 },
+  { identifier: A380Failure.TransformerRectifier4, mtbfHours: 60000 },
+  { identifier: A380Failure.StaticInverter, mtbfHours: 50000 },
+  { identifier: A380Failure.Generator1, mtbfHours: 15000 },
+  { identifier: A380Failure.Generator2, mtbfHours: 15000 },
+  { identifier: A380Failure.Generator3, mtbfHours: 15000 },
+  { identifier: A380Failure.Generator4, mtbfHours: 15000 },
+  { identifier: A380Failure.ApuGenerator1, mtbfHours: 20000 },
+  { identifier: A380Failure.ApuGenerator2, mtbfHours: 20000 },
+
+  { identifier: A380Failure.TrimTankTransferValveStuckClosed, mtbfHours: 40000 },
+
+  { identifier: A380Failure.GreenReservoirLeak, mtbfHours: 50000 },
+  { identifier: A380Failure.YellowReservoirLeak, mtbfHours: 50000 },
+
+  { identifier: A380Failure.CaptPfdDisplayUnit, mtbfHours: 25000 },
+  { identifier: A380Failure.CaptNdDisplayUnit, mtbfHours: 25000 },
+  { identifier: A380Failure.CaptMfdDisplayUnit, mtbfHours: 25000 },
+  { identifier: A380Failure.FoPfdDisplayUnit, mtbfHours: 25000 },
+  { identifier: A380Failure.FoNdDisplayUnit, mtbfHours: 25000 },
+  { identifier: A380Failure.FoMfdDisplayUnit, mtbfHours: 25000 },
+  { identifier: A380Failure.EwdDisplayUnit, mtbfHours: 25000 },
+  { identifier: A380Failure.SdDisplayUnit, mtbfHours: 25000 },
+
+  { identifier: A380Failure.LgciuInternalError1, mtbfHours: 40000 },
+  { identifier: A380Failure.LgciuInternalError2, mtbfHours: 40000 },
+
+  { identifier: A380Failure.RadioAltimeter1, mtbfHours: 20000 },
+  { identifier: A380Failure.RadioAltimeter2, mtbfHours: 20000 },
+  { identifier: A380Failure.RadioAltimeter3, mtbfHours: 20000 },
+  { identifier: A380Failure.Mmr1, mtbfHours: 25000 },
+  { identifier: A380Failure.Mmr2, mtbfHours: 25000 },
+  { identifier: A380Failure.Tcas, mtbfHours: 20000 },
+
+  { identifier: A380Failure.PressureRegulatingValveStuckClosed1, mtbfHours: 30000 },
+  { identifier: A380Failure.PressureRegulatingValveStuckClosed2, mtbfHours: 30000 },
+  { identifier: A380Failure.PressureRegulatingValveStuckClosed3, mtbfHours: 30000 },
+  { identifier: A380Failure.PressureRegulatingValveStuckClosed4, mtbfHours: 30000 },
+  { identifier: A380Failure.PrecoolerClogged1, mtbfHours: 40000 },
+  { identifier: A380Failure.PrecoolerClogged2, mtbfHours: 40000 },
+  { identifier: A380Failure.PrecoolerClogged3, mtbfHours: 40000 },
+  { identifier: A380Failure.PrecoolerClogged4, mtbfHours: 40000 },
+
+  { identifier: A380Failure.ApuNoLightOff, mtbfHours: 15000, exposure: { flightPhase: 'ground' } },
+  { identifier: A380Failure.ApuOilPump, mtbfHours: 30000 },
 ];
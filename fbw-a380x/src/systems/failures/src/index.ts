@@ -1 +1 @@
-export { A380Failure, A380FailureDefinitions } from './a380';
+export { A380Failure, A380FailureDefinitions, A380FailureReliabilities } from './a380';
@@ -31,8 +31,6 @@ export type EGTProps = {
 
 export type CabinDoorProps = {
   doorNumber: number;
-  interactivePoint: number;
-  slideArmed: boolean;
   side: 'L' | 'R';
   engineRunning: boolean;
   mainOrUpper: 'MAIN' | 'UPPER';
@@ -4,7 +4,7 @@
 import React from 'react';
 import { render } from '@instruments/common/index';
 import { AircraftContext, EfbWrapper, syncSettingsFromPersistentStorage } from '@flybywiresim/flypad';
-import { A380FailureDefinitions } from '../../../failures';
+import { A380FailureDefinitions, A380FailureReliabilities } from '../../../failures';
 import { AutomaticCallOutsPage } from './Pages/AutomaticCallOutsPage';
 import { a380xSyncedSettings } from 'instruments/src/EFB/settingsSync';
 
@@ -78,6 +78,11 @@ render(
       hashSeed: 380,
     }}
   >
-    <EfbWrapper failures={A380FailureDefinitions} aircraftSetup={aircraftEfbSetup} eventBus={new EventBus()} />
+    <EfbWrapper
+      failures={A380FailureDefinitions}
+      failureReliabilities={A380FailureReliabilities}
+      aircraftSetup={aircraftEfbSetup}
+      eventBus={new EventBus()}
+    />
   </AircraftContext.Provider>,
 );
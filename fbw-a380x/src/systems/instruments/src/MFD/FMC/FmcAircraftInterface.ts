@@ -74,6 +74,7 @@ export class FmcAircraftInterface {
   public readonly arincTransitionLevel = new FmArinc429OutputWord('TRANS_LVL');
   public readonly arincZeroFuelWeight = new FmArinc429OutputWord('ZERO_FUEL_WEIGHT');
   public readonly arincZeroFuelWeightCg = new FmArinc429OutputWord('ZERO_FUEL_WEIGHT_CG');
+  public readonly arincJettisonTargetWeight = new FmArinc429OutputWord('JETTISON_TARGET_WEIGHT');
   public readonly arincRemainingFlightTime = new FmArinc429OutputWord('REMAINING_FLIGHT_TIME');
   /** contains fm messages (not yet implemented) and nodh bit */
   public readonly arincEisWord2 = new FmArinc429OutputWord('EIS_DISCRETE_WORD_2');
@@ -103,6 +104,7 @@ export class FmcAircraftInterface {
     this.arincTransitionLevel,
     this.arincZeroFuelWeight,
     this.arincZeroFuelWeightCg,
+    this.arincJettisonTargetWeight,
     this.arincRemainingFlightTime,
     this.arincEisWord2,
     this.arincFlightNumber1,
@@ -282,6 +284,18 @@ export class FmcAircraftInterface {
       }),
     );
 
+    this.subs.push(
+      this.fmgc.data.jettisonGrossWeight.sub((gw) =>
+        this.arincJettisonTargetWeight.setBnrValue(
+          gw ?? 0,
+          gw !== null ? Arinc429SignStatusMatrix.NormalOperation : Arinc429SignStatusMatrix.NoComputedData,
+          19,
+          524288,
+          0,
+        ),
+      ),
+    );
+
     this.subs.push(
       this.fmc.zeroFuelWeightCenterOfGravity.sub((zfwCg) =>
         this.arincZeroFuelWeightCg.setBnrValue(
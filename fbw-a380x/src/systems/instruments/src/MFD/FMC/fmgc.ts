@@ -155,6 +155,9 @@ export class FmgcData {
 
   public readonly fuelPenaltyActive = this.fuelPenaltyPercentage.map((v) => v !== null && v > 0);
 
+  /** Jettison target gross weight in kg, sent to the FQMS to stop the fuel jettison. Null if not set. */
+  public readonly jettisonGrossWeight = Subject.create<number | null>(null);
+
   private static readonly DEFAULT_SETTINGS = new FmgcData();
 
   /**
@@ -134,7 +134,6 @@ export class MfdFmsFuelLoad extends FmsPage<MfdFmsFuelLoadProps> {
 
   private readonly costIndexMode = Subject.create<CostIndexMode | null>(null);
 
-  private readonly jettisonGrossWeight = Subject.create(null);
   private readonly takeoffWeight = NumberUnitSubject.create(UnitType.KILOGRAM.createNumber(NaN));
   private readonly takeoffWeightText = this.createWeightSubscribable(this.takeoffWeight);
   private readonly landingWeight = NumberUnitSubject.create(UnitType.KILOGRAM.createNumber(NaN));
@@ -770,10 +769,10 @@ export class MfdFmsFuelLoad extends FmsPage<MfdFmsFuelLoadProps> {
                   JTSN GW
                 </div>
                 <div style={{ 'margin-bottom': '10px', visibility: this.jettisonGrossWeightVisibility }}>
-                  <InputField<number, number, false>
+                  <InputField<number>
                     dataEntryFormat={new WeightFormat(Subject.create(0), Subject.create(maxJtsnGw), this.weightUnit)}
-                    disabled={Subject.create(true)}
-                    readonlyValue={this.jettisonGrossWeight}
+                    value={this.props.fmcService.master.fmgc.data.jettisonGrossWeight}
+                    canBeCleared={Subject.create(true)}
                     alignText="flex-end"
                     containerStyle="width: 150px;"
                     errorHandler={(e) => this.props.fmcService.master.showFmsErrorMessage(e.type, e.details)}
@@ -17,4 +17,105 @@ import { AbnormalProcedure } from 'instruments/src/MsfsAvionicsCommon/EcamMessag
 //    9 for ABN non-sensed procedures
 
 /** All abnormal sensed procedures (alerts, via ECL) should be here. */
-export const EcamAbnormalSensedAta353642: { [n: number]: AbnormalProcedure } = {};
+export const EcamAbnormalSensedAta353642: { [n: number]: AbnormalProcedure } = {
+  // ATA 36: PNEUMATIC
+  361800001: {
+    title: '\x1b<4m\x1b4mAIR\x1bm ENG 1 BLEED LEAK',
+    sensed: true,
+    items: [
+      {
+        name: 'ENG 1 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+    ],
+  },
+  361800002: {
+    title: '\x1b<4m\x1b4mAIR\x1bm ENG 2 BLEED LEAK',
+    sensed: true,
+    items: [
+      {
+        name: 'ENG 2 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+    ],
+  },
+  361800003: {
+    title: '\x1b<4m\x1b4mAIR\x1bm ENG 3 BLEED LEAK',
+    sensed: true,
+    items: [
+      {
+        name: 'ENG 3 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+    ],
+  },
+  361800004: {
+    title: '\x1b<4m\x1b4mAIR\x1bm ENG 4 BLEED LEAK',
+    sensed: true,
+    items: [
+      {
+        name: 'ENG 4 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+    ],
+  },
+  361800005: {
+    title: '\x1b<4m\x1b4mAIR\x1bm APU BLEED LEAK',
+    sensed: true,
+    items: [
+      {
+        name: 'APU BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+    ],
+  },
+  361800006: {
+    title: '\x1b<4m\x1b4mAIR\x1bm ENG 1 BLEED NOT CLSD',
+    sensed: true,
+    items: [
+      {
+        name: 'ENG 1 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+    ],
+  },
+  361800007: {
+    title: '\x1b<4m\x1b4mAIR\x1bm ENG 2 BLEED NOT CLSD',
+    sensed: true,
+    items: [
+      {
+        name: 'ENG 2 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+    ],
+  },
+  361800008: {
+    title: '\x1b<4m\x1b4mAIR\x1bm ENG 3 BLEED NOT CLSD',
+    sensed: true,
+    items: [
+      {
+        name: 'ENG 3 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+    ],
+  },
+  361800009: {
+    title: '\x1b<4m\x1b4mAIR\x1bm ENG 4 BLEED NOT CLSD',
+    sensed: true,
+    items: [
+      {
+        name: 'ENG 4 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+    ],
+  },
+};
@@ -11,8 +11,7 @@ import CargoDoor from './elements/CargoDoor';
 export const DoorPage = () => {
   const [windowLeft] = useSimVar('L:CPT_SLIDING_WINDOW', 'number');
   const [windowRight] = useSimVar('L:FO_SLIDING_WINDOW', 'number');
-  // TODO replace once proper slide implementation & fadec
-  const [beaconOn] = useSimVar('LIGHT BEACON ON', 'bool', 1000);
+  // TODO replace once proper fadec
   const [engine1State] = useSimVar('L:A32NX_ENGINE_STATE:1', 'enum', 1000);
   const [engine2State] = useSimVar('L:A32NX_ENGINE_STATE:2', 'enum', 1000);
   const [engine3State] = useSimVar('L:A32NX_ENGINE_STATE:3', 'enum', 1000);
@@ -20,8 +19,8 @@ export const DoorPage = () => {
   const engineRunning = engine1State === 1 || engine2State === 1 || engine3State === 1 || engine4State === 1;
   const sdacActive = true;
   const onGround = true;
-  const [fwdCargoClosed] = useSimVar('L:A32NX_FWD_DOOR_CARGO_LOCKED', 'bool', 1000);
-  const [aftCargoClosed] = useSimVar('L:A32NX_AFT_DOOR_CARGO_LOCKED', 'bool', 1000);
+  const [fwdCargoClosed] = useSimVar('L:A32NX_DOOR_FWD_CARGO_IS_LOCKED', 'bool', 1000);
+  const [aftCargoClosed] = useSimVar('L:A32NX_DOOR_AFT_CARGO_IS_LOCKED', 'bool', 1000);
 
   return (
     <>
@@ -51,8 +50,6 @@ export const DoorPage = () => {
         mainOrUpper="MAIN"
         doorNumber={1}
         engineRunning={engineRunning}
-        interactivePoint={0}
-        slideArmed={beaconOn}
       />
       <CabinDoor
         x={183}
@@ -61,8 +58,6 @@ export const DoorPage = () => {
         mainOrUpper="MAIN"
         doorNumber={2}
         engineRunning={engineRunning}
-        interactivePoint={2}
-        slideArmed={beaconOn}
       />
       <CabinDoor
         x={183}
@@ -71,8 +66,6 @@ export const DoorPage = () => {
         mainOrUpper="MAIN"
         doorNumber={3}
         engineRunning={engineRunning}
-        interactivePoint={4}
-        slideArmed={beaconOn}
       />
       <CabinDoor
         x={183}
@@ -81,8 +74,6 @@ export const DoorPage = () => {
         mainOrUpper="MAIN"
         doorNumber={4}
         engineRunning={engineRunning}
-        interactivePoint={6}
-        slideArmed={beaconOn}
       />
       <CabinDoor
         x={183}
@@ -91,8 +82,6 @@ export const DoorPage = () => {
         mainOrUpper="MAIN"
         doorNumber={5}
         engineRunning={engineRunning}
-        interactivePoint={8}
-        slideArmed={beaconOn}
       />
 
       <CabinDoor
@@ -102,8 +91,6 @@ export const DoorPage = () => {
         mainOrUpper="MAIN"
         doorNumber={1}
         engineRunning={engineRunning}
-        interactivePoint={1}
-        slideArmed={beaconOn}
       />
       <CabinDoor
         x={368}
@@ -112,8 +99,6 @@ export const DoorPage = () => {
         mainOrUpper="MAIN"
         doorNumber={2}
         engineRunning={engineRunning}
-        interactivePoint={3}
-        slideArmed={beaconOn}
       />
       <CabinDoor
         x={368}
@@ -122,8 +107,6 @@ export const DoorPage = () => {
         mainOrUpper="MAIN"
         doorNumber={3}
         engineRunning={engineRunning}
-        interactivePoint={5}
-        slideArmed={beaconOn}
       />
       <CabinDoor
         x={368}
@@ -132,8 +115,6 @@ export const DoorPage = () => {
         mainOrUpper="MAIN"
         doorNumber={4}
         engineRunning={engineRunning}
-        interactivePoint={7}
-        slideArmed={beaconOn}
       />
       <CabinDoor
         x={368}
@@ -142,8 +123,6 @@ export const DoorPage = () => {
         mainOrUpper="MAIN"
         doorNumber={5}
         engineRunning={engineRunning}
-        interactivePoint={9}
-        slideArmed={beaconOn}
       />
 
       <CabinDoor
@@ -153,8 +132,6 @@ export const DoorPage = () => {
         mainOrUpper="UPPER"
         doorNumber={1}
         engineRunning={engineRunning}
-        interactivePoint={10}
-        slideArmed={beaconOn}
       />
       <CabinDoor
         x={239}
@@ -163,8 +140,6 @@ export const DoorPage = () => {
         mainOrUpper="UPPER"
         doorNumber={2}
         engineRunning={engineRunning}
-        interactivePoint={12}
-        slideArmed={beaconOn}
       />
       <CabinDoor
         x={239}
@@ -173,8 +148,6 @@ export const DoorPage = () => {
         mainOrUpper="UPPER"
         doorNumber={3}
         engineRunning={engineRunning}
-        interactivePoint={14}
-        slideArmed={beaconOn}
       />
 
       <CabinDoor
@@ -184,8 +157,6 @@ export const DoorPage = () => {
         mainOrUpper="UPPER"
         doorNumber={1}
         engineRunning={engineRunning}
-        interactivePoint={11}
-        slideArmed={beaconOn}
       />
       <CabinDoor
         x={310}
@@ -194,8 +165,6 @@ export const DoorPage = () => {
         mainOrUpper="UPPER"
         doorNumber={2}
         engineRunning={engineRunning}
-        interactivePoint={13}
-        slideArmed={beaconOn}
       />
       <CabinDoor
         x={310}
@@ -204,8 +173,6 @@ export const DoorPage = () => {
         mainOrUpper="UPPER"
         doorNumber={3}
         engineRunning={engineRunning}
-        interactivePoint={15}
-        slideArmed={beaconOn}
       />
 
       {/* Cargo Doors */}
@@ -6,14 +6,14 @@ const CabinDoor: React.FC<Position & CabinDoorProps> = ({
   x,
   y,
   doorNumber,
-  interactivePoint,
   side,
   mainOrUpper,
   engineRunning,
-  slideArmed,
 }) => {
-  const [openPercentage] = useSimVar(`INTERACTIVE POINT OPEN:${interactivePoint}`, 'percent', 1000);
-  const doorOpen = openPercentage > 20;
+  const doorId = `${mainOrUpper === 'MAIN' ? 'M' : 'U'}${doorNumber}${side}`;
+  const [doorLocked] = useSimVar(`L:A32NX_DOOR_${doorId}_IS_LOCKED`, 'bool', 1000);
+  const [slideArmed] = useSimVar(`L:A32NX_DOOR_${doorId}_SLIDE_ARMED`, 'bool', 1000);
+  const doorOpen = !doorLocked;
   const armed = !doorOpen && slideArmed;
   const validSDAC = true;
 
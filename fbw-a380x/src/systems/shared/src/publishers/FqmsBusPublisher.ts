@@ -1,7 +1,14 @@
 // Copyright (c) 2025 FlyByWire Simulations
 // SPDX-License-Identifier: GPL-3.0
 
-import { EventBus, PublishPacer, SimVarPublisher, SimVarPublisherEntry, SimVarValueType } from '@microsoft/msfs-sdk';
+import {
+  EventBus,
+  IndexedEventType,
+  PublishPacer,
+  SimVarPublisher,
+  SimVarPublisherEntry,
+  SimVarValueType,
+} from '@microsoft/msfs-sdk';
 
 interface FqmsBusBaseEvents {
   /**
@@ -59,11 +66,53 @@ interface FqmsBusBaseEvents {
    * | 20  | Right Trim Pump running          |
    */
   fqms_fuel_pump_running_right_word: number;
+  /**
+   * Discrete status word for the in-flight fuel transfers, raw ARINC word.
+   * | Bit |            Description            |
+   * |:---:|:---------------------------------:|
+   * | 11  | Feed tank top up in progress      |
+   * | 12  | Trim tank aft transfer            |
+   * | 13  | Trim tank forward transfer        |
+   * | 14  | Outer tank transfer               |
+   * | 15  | Emergency outer tank transfer     |
+   * | 16  | Trim tank fuel transferred        |
+   * | 17  | Outer tank fuel transferred       |
+   * | 18  | Trim tank transfer fault          |
+   * | 19  | Auto transfer fault               |
+   */
+  fqms_fuel_transfer_status_word: number;
 }
+
+/**
+ * FQMS data as received by the FWS over the AFDX virtual links. Indexed by the FWS number.
+ * The words are failure warning when the data is stale or not transmitted.
+ */
+interface FqmsFwsBusBaseEvents {
+  /**
+   * Discrete status word for the FQMS, raw ARINC word.
+   * See fqms_status_word for the bit layout.
+   */
+  fqms_fws_status_word: number;
+  /**
+   * FQMS fuel on board in kilograms.
+   * Raw ARINC word.
+   */
+  fqms_fws_total_fuel_on_board: number;
+  /**
+   * FQMS center of gravity in percent.
+   * Raw ARINC word.
+   */
+  fqms_fws_center_of_gravity_mac: number;
+}
+
+type FqmsFwsBusIndexedEvents = {
+  [P in keyof FqmsFwsBusBaseEvents as IndexedEventType<P>]: FqmsFwsBusBaseEvents[P];
+};
+
 /**
  * Events for FQMS bus local vars.
  */
-export interface FqmsBusEvents extends FqmsBusBaseEvents {}
+export interface FqmsBusEvents extends FqmsBusBaseEvents, FqmsFwsBusBaseEvents, FqmsFwsBusIndexedEvents {}
 
 /**
  * Publisher for FQMS bus local vars.
@@ -88,6 +137,22 @@ export class FqmsBusPublisher extends SimVarPublisher<FqmsBusEvents> {
         'fqms_fuel_pump_running_right_word',
         { name: 'L:A32NX_FQMS_RIGHT_FUEL_PUMP_RUNNING_WORD', type: SimVarValueType.Number },
       ],
+      [
+        'fqms_fuel_transfer_status_word',
+        { name: 'L:A32NX_FQMS_FUEL_TRANSFER_STATUS_WORD', type: SimVarValueType.Number },
+      ],
+      [
+        'fqms_fws_status_word',
+        { name: 'L:A32NX_FWS_#index#_FQMS_STATUS_WORD', type: SimVarValueType.Enum, indexed: true },
+      ],
+      [
+        'fqms_fws_total_fuel_on_board',
+        { name: 'L:A32NX_FWS_#index#_FQMS_TOTAL_FUEL_ON_BOARD', type: SimVarValueType.Enum, indexed: true },
+      ],
+      [
+        'fqms_fws_center_of_gravity_mac',
+        { name: 'L:A32NX_FWS_#index#_FQMS_CENTER_OF_GRAVITY_MAC', type: SimVarValueType.Enum, indexed: true },
+      ],
     ]);
 
     super(simvars, bus, pacer);
@@ -3182,6 +3182,28 @@ export class FwsAbnormalSensed {
       notActiveWhenItemActive: [],
       inopSysAllPhases: () => [],
     },
+    281800078: {
+      // NORM XFR FAULT
+      flightPhaseInhib: [1, 3, 4, 5, 6, 7, 9, 10, 12],
+      simVarIsActive: this.fws.autoTransferFault,
+      whichItemsToShow: () => [],
+      whichItemsChecked: () => [],
+      failure: 2,
+      sysPage: SdPages.Fuel,
+      notActiveWhenItemActive: [],
+      inopSysAllPhases: () => [],
+    },
+    281800094: {
+      // TRIM TK XFR FAULT
+      flightPhaseInhib: [1, 3, 4, 5, 6, 7, 9, 10, 12],
+      simVarIsActive: this.fws.trimTankTransferFault,
+      whichItemsToShow: () => [],
+      whichItemsChecked: () => [],
+      failure: 2,
+      sysPage: SdPages.Fuel,
+      notActiveWhenItemActive: [],
+      inopSysAllPhases: () => [],
+    },
     // ATA 29 - HYDRAULICS
     290800001: {
       // G ELEC PMP A FAULT
@@ -4341,6 +4363,97 @@ export class FwsAbnormalSensed {
       failure: 2,
       sysPage: SdPages.None,
     },
+    // ATA 36 - PNEUMATIC
+    361800001: {
+      // ENG 1 BLEED LEAK
+      flightPhaseInhib: [4, 5, 6, 9, 10],
+      simVarIsActive: this.fws.eng1BleedLeak,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true],
+      whichItemsChecked: () => [!this.fws.eng1BleedPbAuto.get()],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+    },
+    361800002: {
+      // ENG 2 BLEED LEAK
+      flightPhaseInhib: [4, 5, 6, 9, 10],
+      simVarIsActive: this.fws.eng2BleedLeak,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true],
+      whichItemsChecked: () => [!this.fws.eng2BleedPbAuto.get()],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+    },
+    361800003: {
+      // ENG 3 BLEED LEAK
+      flightPhaseInhib: [4, 5, 6, 9, 10],
+      simVarIsActive: this.fws.eng3BleedLeak,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true],
+      whichItemsChecked: () => [!this.fws.eng3BleedPbAuto.get()],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+    },
+    361800004: {
+      // ENG 4 BLEED LEAK
+      flightPhaseInhib: [4, 5, 6, 9, 10],
+      simVarIsActive: this.fws.eng4BleedLeak,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true],
+      whichItemsChecked: () => [!this.fws.eng4BleedPbAuto.get()],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+    },
+    361800005: {
+      // APU BLEED LEAK
+      flightPhaseInhib: [4, 5, 6, 9, 10],
+      simVarIsActive: this.fws.apuBleedLeak,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true],
+      whichItemsChecked: () => [!this.fws.apuBleedPbOn.get()],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+    },
+    361800006: {
+      // ENG 1 BLEED NOT CLSD
+      flightPhaseInhib: [4, 5, 6, 9, 10],
+      simVarIsActive: this.fws.eng1BleedNotClosed,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true],
+      whichItemsChecked: () => [!this.fws.eng1BleedPbAuto.get()],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+    },
+    361800007: {
+      // ENG 2 BLEED NOT CLSD
+      flightPhaseInhib: [4, 5, 6, 9, 10],
+      simVarIsActive: this.fws.eng2BleedNotClosed,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true],
+      whichItemsChecked: () => [!this.fws.eng2BleedPbAuto.get()],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+    },
+    361800008: {
+      // ENG 3 BLEED NOT CLSD
+      flightPhaseInhib: [4, 5, 6, 9, 10],
+      simVarIsActive: this.fws.eng3BleedNotClosed,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true],
+      whichItemsChecked: () => [!this.fws.eng3BleedPbAuto.get()],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+    },
+    361800009: {
+      // ENG 4 BLEED NOT CLSD
+      flightPhaseInhib: [4, 5, 6, 9, 10],
+      simVarIsActive: this.fws.eng4BleedNotClosed,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true],
+      whichItemsChecked: () => [!this.fws.eng4BleedPbAuto.get()],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+    },
     // ATA 52 - DOORS
     520800008: {
       // CKPT SLIDING WINDOW NOT CLOSED
@@ -525,6 +525,23 @@ export class FwsCore {
   public readonly eng3BleedAbnormalOff = Subject.create(false);
   public readonly eng4BleedAbnormalOff = Subject.create(false);
 
+  public readonly eng1BleedPbAuto = Subject.create(false);
+  public readonly eng2BleedPbAuto = Subject.create(false);
+  public readonly eng3BleedPbAuto = Subject.create(false);
+  public readonly eng4BleedPbAuto = Subject.create(false);
+
+  public readonly eng1BleedLeak = Subject.create(false);
+  public readonly eng2BleedLeak = Subject.create(false);
+  public readonly eng3BleedLeak = Subject.create(false);
+  public readonly eng4BleedLeak = Subject.create(false);
+
+  public readonly apuBleedLeak = Subject.create(false);
+
+  public readonly eng1BleedNotClosed = Subject.create(false);
+  public readonly eng2BleedNotClosed = Subject.create(false);
+  public readonly eng3BleedNotClosed = Subject.create(false);
+  public readonly eng4BleedNotClosed = Subject.create(false);
+
   public readonly enginesOffAndOnGroundSignal = new NXLogicConfirmNode(7);
 
   public readonly excessCabinAltitude = Subject.create(false);
@@ -541,25 +558,25 @@ export class FwsCore {
 
   public readonly cabinDoorOpen = Subject.create(false);
 
-  private readonly interactivePointZeroOpen = RegisteredSimVar.createBoolean('INTERACTIVE POINT OPEN:0');
+  private readonly doorM1lLocked = RegisteredSimVar.createBoolean('L:A32NX_DOOR_M1L_IS_LOCKED');
 
-  private readonly interactivePointOneOpen = RegisteredSimVar.createBoolean('INTERACTIVE POINT OPEN:1');
+  private readonly doorM1rLocked = RegisteredSimVar.createBoolean('L:A32NX_DOOR_M1R_IS_LOCKED');
 
-  private readonly interactivePointTwoOpen = RegisteredSimVar.createBoolean('INTERACTIVE POINT OPEN:2');
+  private readonly doorM2lLocked = RegisteredSimVar.createBoolean('L:A32NX_DOOR_M2L_IS_LOCKED');
 
-  private readonly interactivePointThreeOpen = RegisteredSimVar.createBoolean('INTERACTIVE POINT OPEN:3');
+  private readonly doorM2rLocked = RegisteredSimVar.createBoolean('L:A32NX_DOOR_M2R_IS_LOCKED');
 
-  private readonly interactivePointFourOpen = RegisteredSimVar.createBoolean('INTERACTIVE POINT OPEN:4');
+  private readonly doorM3lLocked = RegisteredSimVar.createBoolean('L:A32NX_DOOR_M3L_IS_LOCKED');
 
-  private readonly interactivePointFiveOpen = RegisteredSimVar.createBoolean('INTERACTIVE POINT OPEN:5');
+  private readonly doorM3rLocked = RegisteredSimVar.createBoolean('L:A32NX_DOOR_M3R_IS_LOCKED');
 
-  private readonly interactivePointSixOpen = RegisteredSimVar.createBoolean('INTERACTIVE POINT OPEN:6');
+  private readonly doorM4lLocked = RegisteredSimVar.createBoolean('L:A32NX_DOOR_M4L_IS_LOCKED');
 
-  private readonly interactivePointSevenOpen = RegisteredSimVar.createBoolean('INTERACTIVE POINT OPEN:7');
+  private readonly doorM4rLocked = RegisteredSimVar.createBoolean('L:A32NX_DOOR_M4R_IS_LOCKED');
 
-  private readonly interactivePointEightOpen = RegisteredSimVar.createBoolean('INTERACTIVE POINT OPEN:8');
+  private readonly doorM5lLocked = RegisteredSimVar.createBoolean('L:A32NX_DOOR_M5L_IS_LOCKED');
 
-  private readonly interactivePointNineOpen = RegisteredSimVar.createBoolean('INTERACTIVE POINT OPEN:9');
+  private readonly doorM5rLocked = RegisteredSimVar.createBoolean('L:A32NX_DOOR_M5R_IS_LOCKED');
 
   public readonly allOutflowValvesOpen = Subject.create(false);
 
@@ -1223,18 +1240,40 @@ export class FwsCore {
     this.crossFeed4ValveOpen,
   );
 
-  private readonly fuelOnBoard = Arinc429LocalVarConsumerSubject.create(this.sub.on('fqms_total_fuel_on_board'));
+  private readonly fuelOnBoard = Arinc429LocalVarConsumerSubject.create(
+    this.sub.on(`fqms_fws_total_fuel_on_board_${this.fwsNumber}`),
+  );
 
   private readonly fqmsGrossWeightCgPercent = Arinc429LocalVarConsumerSubject.create(
-    this.sub.on('fqms_center_of_gravity_mac'),
+    this.sub.on(`fqms_fws_center_of_gravity_mac_${this.fwsNumber}`),
   );
 
-  private readonly fqmsStatusWord = Arinc429LocalVarConsumerSubject.create(this.sub.on('fqms_status_word'));
+  private readonly fqmsStatusWord = Arinc429LocalVarConsumerSubject.create(
+    this.sub.on(`fqms_fws_status_word_${this.fwsNumber}`),
+  );
 
   public readonly fqmsZfwOrZfwCgNotSet = this.fqmsStatusWord.map((w) => w.bitValueOr(12, false));
 
   public readonly fqmsZfwOrZfwCgDisagree = this.fqmsStatusWord.map((w) => w.bitValueOr(13, false));
 
+  private readonly fqmsFuelTransferStatusWord = Arinc429LocalVarConsumerSubject.create(
+    this.sub.on('fqms_fuel_transfer_status_word'),
+  );
+
+  public readonly trimTankFwdTransferInProgress = this.fqmsFuelTransferStatusWord.map((w) => w.bitValueOr(14, false));
+
+  public readonly outerTankTransferInProgress = this.fqmsFuelTransferStatusWord.map(
+    (w) => w.bitValueOr(15, false) || w.bitValueOr(16, false),
+  );
+
+  public readonly trimTankFuelTransferred = this.fqmsFuelTransferStatusWord.map((w) => w.bitValueOr(17, false));
+
+  public readonly outerTankFuelTransferred = this.fqmsFuelTransferStatusWord.map((w) => w.bitValueOr(18, false));
+
+  public readonly trimTankTransferFault = this.fqmsFuelTransferStatusWord.map((w) => w.bitValueOr(19, false));
+
+  public readonly autoTransferFault = this.fqmsFuelTransferStatusWord.map((w) => w.bitValueOr(20, false));
+
   private readonly refuelPanel = ConsumerSubject.create(this.sub.on('msfs_interactive_point_open_18'), 0);
 
   private readonly fuelingInitiated = ConsumerSubject.create(this.sub.on('fuel_refuel_started_by_user'), false);
@@ -2347,6 +2386,13 @@ export class FwsCore {
       this.fqmsStatusWord,
       this.fqmsZfwOrZfwCgNotSet,
       this.fqmsZfwOrZfwCgDisagree,
+      this.fqmsFuelTransferStatusWord,
+      this.trimTankFwdTransferInProgress,
+      this.outerTankTransferInProgress,
+      this.trimTankFuelTransferred,
+      this.outerTankFuelTransferred,
+      this.trimTankTransferFault,
+      this.autoTransferFault,
       this.fuelingInitiated,
       this.fuelingTarget,
       this.allFeedTankPumpsOff,
@@ -2959,6 +3005,20 @@ export class FwsCore {
       (this.adrPressureAltitude.get() ?? 0) < 22_500 && (machBelow56 || this.allEnginesFailure.get());
     this.apuBleedPbOnOver22500ft.set(this.apuBleedPbOn.get() && !apuWithinEnvelope);
 
+    this.eng1BleedPbAuto.set(SimVar.GetSimVarValue('L:A32NX_OVHD_PNEU_ENG_1_BLEED_PB_IS_AUTO', SimVarValueType.Bool));
+    this.eng2BleedPbAuto.set(SimVar.GetSimVarValue('L:A32NX_OVHD_PNEU_ENG_2_BLEED_PB_IS_AUTO', SimVarValueType.Bool));
+    this.eng3BleedPbAuto.set(SimVar.GetSimVarValue('L:A32NX_OVHD_PNEU_ENG_3_BLEED_PB_IS_AUTO', SimVarValueType.Bool));
+    this.eng4BleedPbAuto.set(SimVar.GetSimVarValue('L:A32NX_OVHD_PNEU_ENG_4_BLEED_PB_IS_AUTO', SimVarValueType.Bool));
+    this.eng1BleedLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_1_BLEED_LEAK', SimVarValueType.Bool));
+    this.eng2BleedLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_2_BLEED_LEAK', SimVarValueType.Bool));
+    this.eng3BleedLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_3_BLEED_LEAK', SimVarValueType.Bool));
+    this.eng4BleedLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_4_BLEED_LEAK', SimVarValueType.Bool));
+    this.apuBleedLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_APU_BLEED_LEAK', SimVarValueType.Bool));
+    this.eng1BleedNotClosed.set(SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_1_BLEED_NOT_CLOSED', SimVarValueType.Bool));
+    this.eng2BleedNotClosed.set(SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_2_BLEED_NOT_CLOSED', SimVarValueType.Bool));
+    this.eng3BleedNotClosed.set(SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_3_BLEED_NOT_CLOSED', SimVarValueType.Bool));
+    this.eng4BleedNotClosed.set(SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_4_BLEED_NOT_CLOSED', SimVarValueType.Bool));
+
     this.eng1BleedAbnormalOff.set(
       this.engine1Running.get() &&
         !SimVar.GetSimVarValue('L:A32NX_OVHD_PNEU_ENG_1_BLEED_PB_IS_AUTO', SimVarValueType.Bool),
@@ -5078,16 +5138,16 @@ export class FwsCore {
         SimVar.GetSimVarValue('L:FO_SLIDING_WINDOW', 'number') === 1,
     );
 
-    this.main1LOpen.set(this.interactivePointZeroOpen.get());
-    this.main1ROpen.set(this.interactivePointOneOpen.get());
-    this.main2LOpen.set(this.interactivePointTwoOpen.get());
-    this.main2ROpen.set(this.interactivePointThreeOpen.get());
-    this.main3LOpen.set(this.interactivePointFourOpen.get());
-    this.main3ROpen.set(this.interactivePointFiveOpen.get());
-    this.main4LOpen.set(this.interactivePointSixOpen.get());
-    this.main4ROpen.set(this.interactivePointSevenOpen.get());
-    this.main5LOpen.set(this.interactivePointEightOpen.get());
-    this.main5ROpen.set(this.interactivePointNineOpen.get());
+    this.main1LOpen.set(!this.doorM1lLocked.get());
+    this.main1ROpen.set(!this.doorM1rLocked.get());
+    this.main2LOpen.set(!this.doorM2lLocked.get());
+    this.main2ROpen.set(!this.doorM2rLocked.get());
+    this.main3LOpen.set(!this.doorM3lLocked.get());
+    this.main3ROpen.set(!this.doorM3rLocked.get());
+    this.main4LOpen.set(!this.doorM4lLocked.get());
+    this.main4ROpen.set(!this.doorM4rLocked.get());
+    this.main5LOpen.set(!this.doorM5lLocked.get());
+    this.main5ROpen.set(!this.doorM5rLocked.get());
 
     /* CABIN READY */
 
@@ -5248,14 +5308,30 @@ export class FwsCore {
       // TODO Note that fuel tank low pressure and gravity feed warnings are not included
       const systemStatus = this.engine1Generator.get() && this.engine2Generator.get();
 
-      const cabin = SimVar.GetSimVarValue('INTERACTIVE POINT OPEN:0', 'percent');
-      const catering = SimVar.GetSimVarValue('INTERACTIVE POINT OPEN:3', 'percent');
-      const cargofwdLocked = SimVar.GetSimVarValue('L:A32NX_FWD_DOOR_CARGO_LOCKED', 'bool');
-      const cargoaftLocked = SimVar.GetSimVarValue('L:A32NX_AFT_DOOR_CARGO_LOCKED', 'bool');
+      const cabinDoorsLocked = [
+        'M1L',
+        'M1R',
+        'M2L',
+        'M2R',
+        'M3L',
+        'M3R',
+        'M4L',
+        'M4R',
+        'M5L',
+        'M5R',
+        'U1L',
+        'U1R',
+        'U2L',
+        'U2R',
+        'U3L',
+        'U3R',
+      ].every((door) => SimVar.GetSimVarValue(`L:A32NX_DOOR_${door}_IS_LOCKED`, 'bool'));
+      const cargofwdLocked = SimVar.GetSimVarValue('L:A32NX_DOOR_FWD_CARGO_IS_LOCKED', 'bool');
+      const cargoaftLocked = SimVar.GetSimVarValue('L:A32NX_DOOR_AFT_CARGO_IS_LOCKED', 'bool');
       const brakesHot = SimVar.GetSimVarValue('L:A32NX_BRAKES_HOT', 'bool');
 
       const speeds = !toSpeedsTooLow && !toV2VRV2Disagree && !fmToSpeedsNotInserted;
-      const doors = !!(cabin === 0 && catering === 0 && cargoaftLocked && cargofwdLocked);
+      const doors = !!(cabinDoorsLocked && cargoaftLocked && cargofwdLocked);
       const surfacesNotTo =
         flapsNotInToPos ||
         slatsNotInToPos ||
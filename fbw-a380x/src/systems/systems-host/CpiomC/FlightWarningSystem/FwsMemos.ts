@@ -63,6 +63,30 @@ export class FwsMemos {
       codesToReturn: ['280000003'],
       memoInhibit: () => false,
     },
+    280000004: {
+      // FWD XFR IN PROGRESS
+      flightPhaseInhib: [],
+      simVarIsActive: this.fws.trimTankFwdTransferInProgress,
+      whichCodeToReturn: () => [0],
+      codesToReturn: ['280000004'],
+      memoInhibit: () => false,
+    },
+    280000007: {
+      // OUTR TK XFR IN PROG
+      flightPhaseInhib: [],
+      simVarIsActive: this.fws.outerTankTransferInProgress,
+      whichCodeToReturn: () => [0],
+      codesToReturn: ['280000007'],
+      memoInhibit: () => false,
+    },
+    280000008: {
+      // OUTR TKS XFRD
+      flightPhaseInhib: [],
+      simVarIsActive: this.fws.outerTankFuelTransferred,
+      whichCodeToReturn: () => [0],
+      codesToReturn: ['280000008'],
+      memoInhibit: () => false,
+    },
     280000009: {
       // REFUEL IN PROGRESS
       flightPhaseInhib: [3, 4, 5, 6, 7, 8, 9, 10],
@@ -79,6 +103,14 @@ export class FwsMemos {
       memoInhibit: () => false,
       codesToReturn: ['280000010', '280000011'],
     },
+    280000012: {
+      // TRIM TK XFRD
+      flightPhaseInhib: [],
+      simVarIsActive: this.fws.trimTankFuelTransferred,
+      whichCodeToReturn: () => [0],
+      codesToReturn: ['280000012'],
+      memoInhibit: () => false,
+    },
     '280000013': {
       // CROSSFEED OPEN during TO or GA
       flightPhaseInhib: [1, 2, 8, 9, 10, 11, 12],
@@ -0,0 +1,56 @@
+// Copyright (c) 2026 FlyByWire Simulations
+// SPDX-License-Identifier: GPL-3.0
+
+import { UpdateThrottler } from '@flybywiresim/fbw-sdk';
+import { Instrument, SimVarValueType } from '@microsoft/msfs-sdk';
+
+interface DeckSlideArming {
+  /** The slide arming selected by the cabin crew of the deck, read by the door system. */
+  readonly armedVar: string;
+  readonly doors: readonly string[];
+}
+
+/**
+ * Emulates the cabin crew arming the escape slides of each deck once the doors of the deck are locked for departure,
+ * and disarming them again after arrival.
+ */
+export class CabinCrewSlideArming implements Instrument {
+  private static readonly DECKS: readonly DeckSlideArming[] = [
+    {
+      armedVar: 'L:A32NX_SLIDES_ARMED_MAIN_DECK',
+      doors: ['M1L', 'M1R', 'M2L', 'M2R', 'M3L', 'M3R', 'M4L', 'M4R', 'M5L', 'M5R'],
+    },
+    {
+      armedVar: 'L:A32NX_SLIDES_ARMED_UPPER_DECK',
+      doors: ['U1L', 'U1R', 'U2L', 'U2R', 'U3L', 'U3R'],
+    },
+  ];
+
+  private readonly updateThrottler = new UpdateThrottler(100);
+
+  constructor(private readonly instrument: BaseInstrument) {}
+
+  init(): void {
+    // noop
+  }
+
+  onUpdate(): void {
+    if (this.updateThrottler.canUpdate(this.instrument.deltaTime) === -1) {
+      return;
+    }
+
+    const inFlightOrOnRunway =
+      !SimVar.GetSimVarValue('SIM ON GROUND', SimVarValueType.Bool) ||
+      SimVar.GetSimVarValue('ON ANY RUNWAY', SimVarValueType.Bool);
+    const beaconOn = SimVar.GetSimVarValue('LIGHT BEACON ON', SimVarValueType.Bool);
+
+    for (const deck of CabinCrewSlideArming.DECKS) {
+      const armed =
+        inFlightOrOnRunway ||
+        (beaconOn &&
+          deck.doors.every((door) => SimVar.GetSimVarValue(`L:A32NX_DOOR_${door}_IS_LOCKED`, SimVarValueType.Bool)));
+
+      SimVar.SetSimVarValue(deck.armedVar, SimVarValueType.Bool, armed);
+    }
+  }
+}
@@ -557,12 +557,13 @@ export class LegacyGpws {
       mode.current = 0;
       return;
     }
-    if (!SimVar.GetSimVarValue('L:A32NX_RADIO_RECEIVER_GS_IS_VALID', 'number')) {
+    const glideslopeDeviation = Arinc429Word.fromSimVarValue('L:A32NX_MMR_1_GS_DEVIATION');
+    if (!glideslopeDeviation.isNormalOperation()) {
       mode.current = 0;
       return;
     }
-    const error = SimVar.GetSimVarValue('L:A32NX_RADIO_RECEIVER_GS_DEVIATION', 'number');
-    const dots = -error * 2.5; // According to the FCOM, one dot is approx. 0.4 degrees. 1/0.4 = 2.5
+    // The MMR provides the deviation in DDM, positive above the glide path. One dot is 0.0875 DDM.
+    const dots = -glideslopeDeviation.value / 0.0875;
 
     const minAltForWarning = dots < 2.9 ? -75 * dots + 247.5 : 30;
     const minAltForHardWarning = dots < 3.8 ? -66.66 * dots + 283.33 : 30;
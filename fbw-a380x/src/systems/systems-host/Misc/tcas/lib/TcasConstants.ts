@@ -7,21 +7,6 @@ const REFRESH_RATE: number = 1000; // Time between refreshes in ms
 const TRACKING_MAX: number = 40; // # max contacts tracked - AMM 34-43-00:6a
 const DISPLAY_MAX: number = 8; // # max contacts tracked - usually configurable by PIN program
 const MEMORY_MAX: number = 200; // Max history before culling
-const MIN_VS: number = -6000;
-const MAX_VS: number = 6000;
-const INHIBIT_CLB_RA: number = 39000; // for all climb RA's
-const INHIBIT_INC_DES_RA_AGL: number = 1450; // for increase descent RA's
-const INHIBIT_ALL_DES_RA_AGL: number = 1200; // 1200 takeoff, 1000 approach
-const INHIBIT_ALL_RA: number = 1000; // 1100 in climb, 900 in descent
-const REALLY_BIG_NUMBER: number = 1000000;
-const INITIAL_DELAY: number = 5; // in seconds
-const FOLLOWUP_DELAY: number = 2.5; // in deconds
-const INITIAL_ACCEL: number = 8.04; // 0.25G in f/s^2
-const FOLLOWUP_ACCEL: number = 10.62; // 0.33G in f/s^2
-const TA_EXPIRATION_DELAY: number = 4; // in seconds
-const MIN_RA_DURATION: number = 5; // in seconds
-const VOL_BOOST: number = 1.25; // multiplier
-const CLOSURE_RATE_THRESH: number = -40; // in knots
 
 export enum TcasThreat {
   THREAT = 0,
@@ -51,10 +36,6 @@ export enum XpdrMode {
   ON = 3,
   ALT = 4,
 }
-export enum TaRaIndex {
-  TA = 0,
-  RA = 1,
-}
 export enum TaRaIntrusion {
   TRAFFIC = 0,
   PROXIMITY = 1,
@@ -66,40 +47,23 @@ export enum Intrude {
   ALT = 1,
   SPEED = 2,
 }
-export enum RaSense {
-  UP = 0,
-  DOWN = 1,
-}
-export enum RaType {
-  CORRECT = 0,
-  PREVENT = 1,
-}
-export enum RaType2 {
-  NONE = 0,
-  CROSSING = 1,
-  REVERSAL = 2,
-  INCREASE = 3,
-  MAINTAIN = 4,
-}
-export enum UpDownAdvisoryStatus {
-  NO_ADVISORY = 0,
-  CLIMB_DESCEND = 1,
-  DONT_CLIMB_DONT_DESCEND = 2,
-  DONT_CLIMB_DONT_DESCEND_GREATER_500 = 3,
-  DONT_CLIMB_DONT_DESCEND_GREATER_1000 = 4,
-  DONT_CLIMB_DONT_DESCEND_GREATER_2000 = 5,
-}
 export enum Limits {
   MIN = 0,
   MAX = 1,
 }
-export enum Inhibit {
+export enum RaCalloutId {
   NONE = 0,
-  ALL_RA_AURAL_TA = 1,
-  ALL_RA = 2,
-  ALL_CLIMB_RA = 3,
-  ALL_DESC_RA = 4,
-  ALL_INCR_DESC_RA = 5,
+  CLIMB = 1,
+  CLIMB_CROSSING = 2,
+  INCREASE_CLIMB = 3,
+  CLIMB_NOW = 4,
+  DESCEND = 5,
+  DESCEND_CROSSING = 6,
+  INCREASE_DESCENT = 7,
+  DESCEND_NOW = 8,
+  MONITOR_VS = 9,
+  MAINTAIN_VS = 10,
+  MAINTAIN_VS_CROSSING = 11,
 }
 
 export interface JS_NPCPlane {
@@ -112,36 +76,6 @@ export interface JS_NPCPlane {
   heading: number;
 }
 
-interface AltitudeMatrix {
-  readonly 3: readonly [number, number];
-  readonly 4: readonly [number, number];
-  readonly 5: readonly [number, number];
-  readonly 6: readonly [number, number];
-  readonly 7: readonly [number, number];
-}
-
-interface SensitivityMatrix {
-  readonly 1: readonly [number, number];
-  readonly 2: readonly [number, number];
-  readonly 3: readonly [number, number];
-  readonly 4: readonly [number, number];
-  readonly 5: readonly [number, number];
-  readonly 6: readonly [number, number];
-  readonly 7: readonly [number, number];
-  readonly 8: readonly [number, number];
-}
-
-interface SensitivityList {
-  readonly 1: number;
-  readonly 2: number;
-  readonly 3: number;
-  readonly 4: number;
-  readonly 5: number;
-  readonly 6: number;
-  readonly 7: number;
-  readonly 8: number;
-}
-
 interface RangeLimit {
   readonly forward: readonly [number, number];
   readonly side: number;
@@ -149,22 +83,6 @@ interface RangeLimit {
   readonly alt: number;
 }
 
-export interface RaParams {
-  callout: RaCallout;
-  sense: RaSense;
-  type: RaType;
-  type2: RaType2;
-  vs: VerticalSpeedLimits;
-  rateToMaintain: number;
-  upAdvisory: UpDownAdvisoryStatus;
-  downAdvisory: UpDownAdvisoryStatus;
-}
-
-interface VerticalSpeedLimits {
-  readonly green: readonly [number, number];
-  readonly red: readonly [number, number];
-}
-
 interface RaCallout {
   readonly id: number;
   readonly repeat: boolean;
@@ -183,15 +101,6 @@ const THREAT: { [key in TcasThreat]: readonly [number, number] } = {
   [TcasThreat.BELOW]: [-9900, 2700],
 };
 
-// Altitude -> Sensitivity
-const SENSE: AltitudeMatrix = {
-  3: [1000, 2350],
-  4: [2350, 5000],
-  5: [5000, 10000],
-  6: [10000, 20000],
-  7: [20000, 47000],
-} as const;
-
 // TCAS Range Limit
 const RANGE: RangeLimit = {
   // 34-43-00 6:2339
@@ -201,79 +110,6 @@ const RANGE: RangeLimit = {
   alt: 9900,
 } as const;
 
-// Sensitivity to TAU limit
-const TAU: SensitivityMatrix = {
-  1: [-1, -1],
-  2: [20, -1],
-  3: [25, 15],
-  4: [30, 20],
-  5: [40, 25],
-  6: [45, 30],
-  7: [48, 35],
-  8: [48, 35],
-} as const;
-
-// Incremental Range Protection Volume
-const DMOD: SensitivityMatrix = {
-  1: [-1, -1],
-  2: [0.3, -1],
-  3: [0.33, 0.2],
-  4: [0.48, 0.35],
-  5: [0.75, 0.55],
-  6: [1, 0.8],
-  7: [1.3, 1.1],
-  8: [1.3, 1.1],
-} as const;
-
-// Detection Alt Threshold
-const ZTHR: SensitivityMatrix = {
-  1: [-1, -1],
-  2: [850, -1],
-  3: [850, 300],
-  4: [850, 300],
-  5: [850, 350],
-  6: [850, 400],
-  7: [850, 600],
-  8: [1200, 700],
-} as const;
-
-// Time Variable Alt Tau Threshold
-const TVTHR: SensitivityList = {
-  1: -1,
-  2: -1,
-  3: 15,
-  4: 18,
-  5: 20,
-  6: 22,
-  7: 25,
-  8: 25,
-} as const;
-
-// Positive Advisory Altitude Threshold
-const ALIM: SensitivityList = {
-  1: -1,
-  2: -1,
-  3: 300,
-  4: 300,
-  5: 350,
-  6: 400,
-  7: 600,
-  8: 700,
-} as const;
-
-// Limit closure acceleration (workaround/unrealistic)
-// TODO FIXME: Replace with HMD accel filter?
-const ACCEL: SensitivityMatrix = {
-  1: [900, 900],
-  2: [1000, 1000],
-  3: [1100, 1200],
-  4: [1200, 1300],
-  5: [1300, 1400],
-  6: [2000, 2200],
-  7: [2100, 2300],
-  8: [2200, 2500],
-} as const;
-
 // many lengths are approximate until we can get them accuratly (when boris re-makes them and we have the sources)
 const SOUNDS: { [key: string]: RaSound } = {
   pull_up: {
@@ -495,324 +331,19 @@ const CALLOUTS: { [key: string]: RaCallout } = {
   },
 } as const;
 
-const RA_VARIANTS: { [key: string]: RaParams } = {
-  // PREVENTIVE RA's
-  monitor_vs_climb_0: {
-    callout: CALLOUTS.monitor_vs,
-    sense: RaSense.UP,
-    type: RaType.PREVENT,
-    type2: RaType2.NONE,
-    vs: {
-      green: [0, MAX_VS],
-      red: [MIN_VS, 0],
-    },
-    rateToMaintain: 0,
-    upAdvisory: UpDownAdvisoryStatus.DONT_CLIMB_DONT_DESCEND,
-    downAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-  },
-  monitor_vs_climb_500: {
-    callout: CALLOUTS.monitor_vs,
-    sense: RaSense.UP,
-    type: RaType.PREVENT,
-    type2: RaType2.NONE,
-    vs: {
-      green: [-500, MAX_VS],
-      red: [MIN_VS, -500],
-    },
-    rateToMaintain: 0,
-    upAdvisory: UpDownAdvisoryStatus.DONT_CLIMB_DONT_DESCEND_GREATER_500,
-    downAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-  },
-  monitor_vs_climb_1000: {
-    callout: CALLOUTS.monitor_vs,
-    sense: RaSense.UP,
-    type: RaType.PREVENT,
-    type2: RaType2.NONE,
-    vs: {
-      green: [-1000, MAX_VS],
-      red: [MIN_VS, -1000],
-    },
-    rateToMaintain: 0,
-    upAdvisory: UpDownAdvisoryStatus.DONT_CLIMB_DONT_DESCEND_GREATER_1000,
-    downAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-  },
-  monitor_vs_climb_2000: {
-    callout: CALLOUTS.monitor_vs,
-    sense: RaSense.UP,
-    type: RaType.PREVENT,
-    type2: RaType2.NONE,
-    vs: {
-      green: [-2000, MAX_VS],
-      red: [MIN_VS, -2000],
-    },
-    rateToMaintain: 0,
-    upAdvisory: UpDownAdvisoryStatus.DONT_CLIMB_DONT_DESCEND_GREATER_2000,
-    downAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-  },
-
-  monitor_vs_descend_0: {
-    callout: CALLOUTS.monitor_vs,
-    sense: RaSense.DOWN,
-    type: RaType.PREVENT,
-    type2: RaType2.NONE,
-    vs: {
-      green: [MIN_VS, 0],
-      red: [0, MAX_VS],
-    },
-    rateToMaintain: 0,
-    upAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-    downAdvisory: UpDownAdvisoryStatus.DONT_CLIMB_DONT_DESCEND,
-  },
-  monitor_vs_descend_500: {
-    callout: CALLOUTS.monitor_vs,
-    sense: RaSense.DOWN,
-    type: RaType.PREVENT,
-    type2: RaType2.NONE,
-    vs: {
-      green: [MIN_VS, 500],
-      red: [500, MAX_VS],
-    },
-    rateToMaintain: 0,
-    upAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-    downAdvisory: UpDownAdvisoryStatus.DONT_CLIMB_DONT_DESCEND_GREATER_500,
-  },
-  monitor_vs_descend_1000: {
-    callout: CALLOUTS.monitor_vs,
-    sense: RaSense.DOWN,
-    type: RaType.PREVENT,
-    type2: RaType2.NONE,
-    vs: {
-      green: [MIN_VS, 1000],
-      red: [1000, MAX_VS],
-    },
-    rateToMaintain: 0,
-    upAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-    downAdvisory: UpDownAdvisoryStatus.DONT_CLIMB_DONT_DESCEND_GREATER_1000,
-  },
-  monitor_vs_descend_2000: {
-    callout: CALLOUTS.monitor_vs,
-    sense: RaSense.DOWN,
-    type: RaType.PREVENT,
-    type2: RaType2.NONE,
-    vs: {
-      green: [MIN_VS, 2000],
-      red: [2000, MAX_VS],
-    },
-    rateToMaintain: 0,
-    upAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-    downAdvisory: UpDownAdvisoryStatus.DONT_CLIMB_DONT_DESCEND_GREATER_2000,
-  },
-  // CORRECTIVE RA's
-  // CLIMB
-  climb: {
-    callout: CALLOUTS.climb,
-    sense: RaSense.UP,
-    type: RaType.CORRECT,
-    type2: RaType2.NONE,
-    vs: {
-      green: [1500, 2000],
-      red: [MIN_VS, 1500],
-    },
-    rateToMaintain: 1500,
-    upAdvisory: UpDownAdvisoryStatus.CLIMB_DESCEND,
-    downAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-  },
-  climb_cross: {
-    callout: CALLOUTS.climb_cross,
-    sense: RaSense.UP,
-    type: RaType.CORRECT,
-    type2: RaType2.CROSSING,
-    vs: {
-      green: [1500, 2000],
-      red: [MIN_VS, 1500],
-    },
-    rateToMaintain: 1500,
-    upAdvisory: UpDownAdvisoryStatus.CLIMB_DESCEND,
-    downAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-  },
-  climb_increase: {
-    callout: CALLOUTS.climb_increase,
-    sense: RaSense.UP,
-    type: RaType.CORRECT,
-    type2: RaType2.INCREASE,
-    vs: {
-      green: [2500, 4400],
-      red: [MIN_VS, 2500],
-    },
-    rateToMaintain: 2500,
-    upAdvisory: UpDownAdvisoryStatus.CLIMB_DESCEND,
-    downAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-  },
-  climb_now: {
-    callout: CALLOUTS.climb_now,
-    sense: RaSense.UP,
-    type: RaType.CORRECT,
-    type2: RaType2.REVERSAL,
-    vs: {
-      green: [1500, 2000],
-      red: [MIN_VS, 1500],
-    },
-    rateToMaintain: 1500,
-    upAdvisory: UpDownAdvisoryStatus.CLIMB_DESCEND,
-    downAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-  },
-  // CORRECTIVE RA's
-  // DESCEND
-  descend: {
-    callout: CALLOUTS.descend,
-    sense: RaSense.DOWN,
-    type: RaType.CORRECT,
-    type2: RaType2.NONE,
-    vs: {
-      green: [-2000, -1500],
-      red: [-1500, MAX_VS],
-    },
-    rateToMaintain: -1500,
-    upAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-    downAdvisory: UpDownAdvisoryStatus.CLIMB_DESCEND,
-  },
-  descend_cross: {
-    callout: CALLOUTS.descend_cross,
-    sense: RaSense.DOWN,
-    type: RaType.CORRECT,
-    type2: RaType2.CROSSING,
-    vs: {
-      green: [-2000, -1500],
-      red: [-1500, MAX_VS],
-    },
-    rateToMaintain: -1500,
-    upAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-    downAdvisory: UpDownAdvisoryStatus.CLIMB_DESCEND,
-  },
-  descend_increase: {
-    callout: CALLOUTS.descend_increase,
-    sense: RaSense.DOWN,
-    type: RaType.CORRECT,
-    type2: RaType2.INCREASE,
-    vs: {
-      green: [-4400, -2500],
-      red: [-2500, MAX_VS],
-    },
-    rateToMaintain: -2500,
-    upAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-    downAdvisory: UpDownAdvisoryStatus.CLIMB_DESCEND,
-  },
-  descend_now: {
-    callout: CALLOUTS.descend_now,
-    sense: RaSense.DOWN,
-    type: RaType.CORRECT,
-    type2: RaType2.REVERSAL,
-    vs: {
-      green: [-2000, -1500],
-      red: [-1500, MAX_VS],
-    },
-    rateToMaintain: -1500,
-    upAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-    downAdvisory: UpDownAdvisoryStatus.CLIMB_DESCEND,
-  },
-  // CORRECTIVE RA's
-  // LEVEL OFF
-  // level_off_250_both: {
-  //     // Currently not used
-  //     // Will be used when support for multi-threat RA's,
-  //     // from both above and below, will be added
-  //     callout: CALLOUTS.level_off,
-  //     sense: RaSense.UP,
-  //     type: RaType.CORRECT,
-  //     type2: RaType2.NONE,
-  //     vs: {
-  //         green: [-250, 250],
-  //         red: [
-  //             [MIN_VS, -250],
-  //             [250, MAX_VS]
-  //         ]
-  //     }
-  //     rateToMaintain: 0,
-  //     upAdvisory: UpDownAdvisoryStatus.DONT_CLIMB_DONT_DESCEND,
-  //     downAdvisory: UpDownAdvisoryStatus.DONT_CLIMB_DONT_DESCEND,
-  // },
-  level_off_300_below: {
-    callout: CALLOUTS.level_off,
-    sense: RaSense.DOWN,
-    type: RaType.CORRECT,
-    type2: RaType2.NONE,
-    vs: {
-      green: [-400, 0],
-      red: [0, MAX_VS],
-    },
-    rateToMaintain: 0,
-    upAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-    downAdvisory: UpDownAdvisoryStatus.DONT_CLIMB_DONT_DESCEND,
-  },
-  level_off_300_above: {
-    callout: CALLOUTS.level_off,
-    sense: RaSense.UP,
-    type: RaType.CORRECT,
-    type2: RaType2.NONE,
-    vs: {
-      green: [0, 400],
-      red: [MIN_VS, 0],
-    },
-    rateToMaintain: 0,
-    upAdvisory: UpDownAdvisoryStatus.DONT_CLIMB_DONT_DESCEND,
-    downAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-  },
-  // CORRECTIVE RA's
-  // MAINTAIN VS, CLIMB
-  climb_maintain_vs: {
-    callout: CALLOUTS.maintain_vs,
-    sense: RaSense.UP,
-    type: RaType.CORRECT,
-    type2: RaType2.MAINTAIN,
-    vs: {
-      green: [1500, 4400],
-      red: [MIN_VS, 1500],
-    },
-    rateToMaintain: 1500,
-    upAdvisory: UpDownAdvisoryStatus.CLIMB_DESCEND,
-    downAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-  },
-  climb_maintain_vs_crossing: {
-    callout: CALLOUTS.maintain_vs,
-    sense: RaSense.UP,
-    type: RaType.CORRECT,
-    type2: RaType2.MAINTAIN,
-    vs: {
-      green: [1500, 4400],
-      red: [MIN_VS, 1500],
-    },
-    rateToMaintain: 1500,
-    upAdvisory: UpDownAdvisoryStatus.CLIMB_DESCEND,
-    downAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-  },
-  // CORRECTIVE RA's
-  // MAINTAIN VS, DESCEND
-  descend_maintain_vs: {
-    callout: CALLOUTS.maintain_vs,
-    sense: RaSense.DOWN,
-    type: RaType.CORRECT,
-    type2: RaType2.MAINTAIN,
-    vs: {
-      green: [-4400, -1500],
-      red: [-1500, MAX_VS],
-    },
-    rateToMaintain: -1500,
-    upAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-    downAdvisory: UpDownAdvisoryStatus.CLIMB_DESCEND,
-  },
-  descend_maintain_vs_crossing: {
-    callout: CALLOUTS.maintain_vs,
-    sense: RaSense.DOWN,
-    type: RaType.CORRECT,
-    type2: RaType2.MAINTAIN,
-    vs: {
-      green: [-4400, -1500],
-      red: [-1500, MAX_VS],
-    },
-    rateToMaintain: -1500,
-    upAdvisory: UpDownAdvisoryStatus.NO_ADVISORY,
-    downAdvisory: UpDownAdvisoryStatus.CLIMB_DESCEND,
-  },
+// RA callout selected by the TCAS computer -> Aural callout
+const RA_CALLOUTS: { [key in RaCalloutId]?: RaCallout } = {
+  [RaCalloutId.CLIMB]: CALLOUTS.climb,
+  [RaCalloutId.CLIMB_CROSSING]: CALLOUTS.climb_cross,
+  [RaCalloutId.INCREASE_CLIMB]: CALLOUTS.climb_increase,
+  [RaCalloutId.CLIMB_NOW]: CALLOUTS.climb_now,
+  [RaCalloutId.DESCEND]: CALLOUTS.descend,
+  [RaCalloutId.DESCEND_CROSSING]: CALLOUTS.descend_cross,
+  [RaCalloutId.INCREASE_DESCENT]: CALLOUTS.descend_increase,
+  [RaCalloutId.DESCEND_NOW]: CALLOUTS.descend_now,
+  [RaCalloutId.MONITOR_VS]: CALLOUTS.monitor_vs,
+  [RaCalloutId.MAINTAIN_VS]: CALLOUTS.maintain_vs,
+  [RaCalloutId.MAINTAIN_VS_CROSSING]: CALLOUTS.maintain_vs_cross,
 } as const;
 
 export const TCAS_CONST: { [key: string]: any } = {
@@ -820,30 +351,9 @@ export const TCAS_CONST: { [key: string]: any } = {
   TRACKING_MAX,
   DISPLAY_MAX,
   MEMORY_MAX,
-  MIN_VS,
-  INHIBIT_CLB_RA, // for all climb RA's
-  INHIBIT_INC_DES_RA_AGL, // for increase descent RA's
-  INHIBIT_ALL_DES_RA_AGL, // 1200 takeoff, 1000 approach
-  INHIBIT_ALL_RA, // 1100 in climb, 900 in descent
-  REALLY_BIG_NUMBER,
-  INITIAL_DELAY, // in seconds
-  FOLLOWUP_DELAY, // in deconds
-  INITIAL_ACCEL, // 0.25G in f/s^2
-  FOLLOWUP_ACCEL, // 0.33G in f/s^2
-  TA_EXPIRATION_DELAY, // in seconds
-  MIN_RA_DURATION, // in seconds
-  VOL_BOOST, // multiplier
-  CLOSURE_RATE_THRESH, // in knots
   THREAT,
-  SENSE,
   RANGE,
-  TAU,
-  DMOD,
-  ZTHR,
-  TVTHR,
-  ALIM,
-  ACCEL,
   SOUNDS,
   CALLOUTS,
-  RA_VARIANTS,
+  RA_CALLOUTS,
 } as const;
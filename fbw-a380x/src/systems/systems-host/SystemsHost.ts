@@ -58,6 +58,7 @@ import { FGDataPublisher } from 'instruments/src/MsfsAvionicsCommon/providers/FG
 import { AesuBusPublisher } from 'instruments/src/MsfsAvionicsCommon/providers/AesuBusPublisher';
 import { A380Failure } from '@failures';
 import { AutoThsTrimmer } from 'systems-host/PseudoPRIM/AutoThsTrimmer';
+import { CabinCrewSlideArming } from 'systems-host/Misc/CabinCrewSlideArming';
 import { EfisTawsBridge } from 'systems-host/Misc/EfisTawsBridge';
 import { FmsSymbolsPublisher } from 'instruments/src/ND/FmsSymbolsPublisher';
 import { FmsMessagePublisher } from 'instruments/src/MsfsAvionicsCommon/providers/FmsMessagePublisher';
@@ -110,6 +111,8 @@ class SystemsHost extends BaseInstrument {
 
   private readonly btv = new BrakeToVacate(this.bus, this);
 
+  private readonly cabinCrewSlideArming = new CabinCrewSlideArming(this);
+
   private readonly rmpAmuBusPublisher = new RmpAmuBusPublisher(this.bus);
 
   private readonly pilotSeatPublisher = new PilotSeatPublisher(this.bus);
@@ -207,6 +210,7 @@ class SystemsHost extends BaseInstrument {
     this.backplane.addInstrument('LegacyFuel', this.legacyFuel);
     this.backplane.addInstrument('BtvDistanceUpdater', this.btv);
     this.backplane.addInstrument('EfisTawsBridge', this.efisTawsBridge);
+    this.backplane.addInstrument('CabinCrewSlideArming', this.cabinCrewSlideArming);
     this.backplane.addPublisher('RmpAmuBusPublisher', this.rmpAmuBusPublisher);
     this.backplane.addPublisher('PilotSeatPublisher', this.pilotSeatPublisher);
     this.backplane.addPublisher('PowerPublisher', this.powerPublisher);
@@ -59,14 +59,24 @@ void EngineControl_A380X::update() {
   for (int engine = 1; engine <= 4; engine++) {
     const int engineIdx = engine - 1;
 
-    const bool engineStarter = static_cast<bool>(simData.simVarsDataPtr->data().engineStarter[engineIdx]);
-    const int  engineIgniter = static_cast<int>(simData.simVarsDataPtr->data().engineIgniter[engineIdx]);
+    bool      engineStarter = static_cast<bool>(simData.simVarsDataPtr->data().engineStarter[engineIdx]);
+    const int engineIgniter = static_cast<int>(simData.simVarsDataPtr->data().engineIgniter[engineIdx]);
+
+    // the start control of the systems keeps dry cranking the engine after a start fault and only aborts the start once
+    // the dry crank has completed
+    const bool startAborted = simData.fadecStartPhase[engineIdx]->getAsInt64() == START_PHASE_ABORTED;
+    if (engineStarter && startAborted) {
+      simData.setStarterHeldEvent[engineIdx]->trigger(0);
+      simData.setStarterEvent[engineIdx]->trigger(0);
+      engineStarter = false;
+    }
 
     // determine the current engine state based on the previous state and the current ignition, starter and other parameters
     // also resets the engine timer if the engine is starting or restarting
     EngineState engineState = engineStateMachine(engine,                      //
                                                  engineIgniter,               //
                                                  engineStarter,               //
+                                                 startAborted,                //
                                                  prevSimEngineN3[engineIdx],  //
                                                  idleN3,                      //
                                                  ambientTemperature);         //
@@ -315,6 +325,7 @@ void EngineControl_A380X::generateIdleParameters(double pressAltitude, double ma
 EngineControl_A380X::EngineState EngineControl_A380X::engineStateMachine(int    engine,
                                                                          int    engineIgniter,
                                                                          bool   engineStarter,
+                                                                         bool   startAborted,
                                                                          double simN3,
                                                                          double idleN3,
                                                                          double ambientTemperature) {
@@ -328,11 +339,14 @@ EngineControl_A380X::EngineState EngineControl_A380X::engineStateMachine(int
 
   EngineState engineState = static_cast<EngineState>(simData.engineState[engineIdx]->get());
 
+  // a dry cranked engine does not run up to idle as the start control does not command the fuel on
+  const bool fuelOnCommanded = simData.fadecFuelOnCommanded[engineIdx]->getAsBool();
+
   // Current State: OFF
   if (engineState == OFF) {
     if (engineIgniter == 1 && engineStarter && simN3 > 20) {
       engineState = ON;
-    } else if (engineIgniter == 2 && engineStarter) {
+    } else if (engineIgniter == 2 && engineStarter && !startAborted) {
       engineState = STARTING;
     } else {
       engineState = OFF;
@@ -348,10 +362,10 @@ EngineControl_A380X::EngineState EngineControl_A380X::engineStateMachine(int
   }
   // Current State: Starting.
   else if (engineState == STARTING) {
-    if (engineStarter && simN3 >= (idleN3 - 0.1)) {
+    if (engineStarter && fuelOnCommanded && simN3 >= (idleN3 - 0.1)) {
       engineState = ON;
       resetTimer  = true;
-    } else if (!engineStarter) {
+    } else if (!engineStarter || startAborted) {
       engineState = SHUTTING;
       resetTimer  = true;
     } else {
@@ -360,10 +374,10 @@ EngineControl_A380X::EngineState EngineControl_A380X::engineStateMachine(int
   }
   // Current State: Re-Starting.
   else if (engineState == RESTARTING) {
-    if (engineStarter && simN3 >= (idleN3 - 0.1)) {
+    if (engineStarter && fuelOnCommanded && simN3 >= (idleN3 - 0.1)) {
       engineState = ON;
       resetTimer  = true;
-    } else if (!engineStarter) {
+    } else if (!engineStarter || startAborted) {
       engineState = SHUTTING;
       resetTimer  = true;
     } else {
@@ -372,13 +386,13 @@ EngineControl_A380X::EngineState EngineControl_A380X::engineStateMachine(int
   }
   // Current State: Shutting
   else if (engineState == SHUTTING) {
-    if (engineIgniter == 2 && engineStarter) {
+    if (engineIgniter == 2 && engineStarter && !startAborted) {
       engineState = RESTARTING;
       resetTimer  = true;
     } else if (!engineStarter && simN3 < 0.05 && simData.engineEgt[engineIdx]->get() <= ambientTemperature) {
       engineState = OFF;
       resetTimer  = true;
-    } else if (engineStarter == 1 && simN3 > 50) {
+    } else if (engineStarter == 1 && !startAborted && simN3 > 50) {
       engineState = RESTARTING;
       resetTimer  = true;
     } else {
@@ -453,9 +467,13 @@ void EngineControl_A380X::engineStartProcedure(int         engine,
     simData.engineN3[engineIdx]->set(newN3Fbw);
     simData.engineN2[engineIdx]->set(newN3Fbw == 0 ? 0 : newN3Fbw + 0.7);  // 0.7 seems to be an arbitrary offset to get N2 from N3
     simData.engineN1[engineIdx]->set(startN1Fbw);
-    simData.engineFF[engineIdx]->set(startFfFbw);
 
-    if (engineState == RESTARTING) {
+    // the engine is only cranked by the starter until the start control commands the fuel on
+    if (!simData.fadecFuelOnCommanded[engineIdx]->getAsBool()) {
+      simData.engineFF[engineIdx]->set(0);
+      simData.engineEgt[engineIdx]->set(shutdownEgtFbw);
+    } else if (engineState == RESTARTING) {
+      simData.engineFF[engineIdx]->set(startFfFbw);
       if (std::abs(startEgtFbw - preEgtFbw) <= 1.5) {
         simData.engineEgt[engineIdx]->set(startEgtFbw);
         simData.engineState[engineIdx]->set(STARTING);
@@ -466,6 +484,7 @@ void EngineControl_A380X::engineStartProcedure(int         engine,
         simData.engineEgt[engineIdx]->set(shutdownEgtFbw);
       }
     } else {
+      simData.engineFF[engineIdx]->set(startFfFbw);
       simData.engineEgt[engineIdx]->set(startEgtFbw);
     }
 
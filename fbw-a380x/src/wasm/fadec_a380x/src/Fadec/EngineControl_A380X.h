@@ -73,6 +73,7 @@ class EngineControl_A380X {
   static constexpr int    MAX_OIL_TEMP        = 85;
   static constexpr double FORCE_LB_TO_N       = 4.4482216153;
   static constexpr double FUEL_RATE_THRESHOLD = 661;  // lbs/sec for determining fuel ui tampering
+  static constexpr int    START_PHASE_ABORTED = 6;    // start control phase in which the systems have aborted the start
 
   /**
    * @enum EngineState
@@ -165,6 +166,7 @@ class EngineControl_A380X {
    * @param engine The engine number (1-4).
    * @param engineIgniter The status of the engine igniter (enum 0=Crank, 1=Norm, 2=Ign).
    * @param engineStarter The status of the engine starter as bool.
+   * @param startAborted Whether the start control of the systems aborted the start and cut the fuel.
    * @param simN3 The current N2 value from the simulator used as N3 for the A380X in percent.
    * @param idleN3 The idle N3 value in percent.
    * @param ambientTemperature The current ambient temperature in degrees Celsius.
@@ -174,6 +176,7 @@ class EngineControl_A380X {
   EngineControl_A380X::EngineState engineStateMachine(int    engine,
                                                       int    engineIgniter,
                                                       bool   engineStarter,
+                                                      bool   startAborted,
                                                       double simN3,
                                                       double idleN3,
                                                       double ambientTemperature);
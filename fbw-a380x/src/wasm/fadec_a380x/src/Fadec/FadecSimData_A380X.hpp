@@ -226,6 +226,8 @@ class FadecSimData_A380X {
   NamedVariablePtr enginePreFF[4];  // kg/hour
   NamedVariablePtr engineState[4];
   NamedVariablePtr engineTimer[4];
+  NamedVariablePtr fadecFuelOnCommanded[4];  // Bool, fuel on as commanded by the start control of the systems
+  NamedVariablePtr fadecStartPhase[4];       // Number, phase of the start control of the systems
   NamedVariablePtr fuelLeftOuterPre;   // Pounds
   NamedVariablePtr fuelFeedOnePre;     // Pounds
   NamedVariablePtr fuelLeftMidPre;     // Pounds
@@ -410,6 +412,16 @@ class FadecSimData_A380X {
     engineTimer[E3] = dm->make_named_var("A32NX_ENGINE_TIMER:3", UNITS.Number, AUTO_READ_WRITE);
     engineTimer[E4] = dm->make_named_var("A32NX_ENGINE_TIMER:4", UNITS.Number, AUTO_READ_WRITE);
 
+    fadecFuelOnCommanded[E1] = dm->make_named_var("A32NX_FADEC_FUEL_ON_COMMANDED_ENG1", UNITS.Number, AUTO_READ);
+    fadecFuelOnCommanded[E2] = dm->make_named_var("A32NX_FADEC_FUEL_ON_COMMANDED_ENG2", UNITS.Number, AUTO_READ);
+    fadecFuelOnCommanded[E3] = dm->make_named_var("A32NX_FADEC_FUEL_ON_COMMANDED_ENG3", UNITS.Number, AUTO_READ);
+    fadecFuelOnCommanded[E4] = dm->make_named_var("A32NX_FADEC_FUEL_ON_COMMANDED_ENG4", UNITS.Number, AUTO_READ);
+
+    fadecStartPhase[E1] = dm->make_named_var("A32NX_FADEC_START_PHASE_ENG1", UNITS.Number, AUTO_READ);
+    fadecStartPhase[E2] = dm->make_named_var("A32NX_FADEC_START_PHASE_ENG2", UNITS.Number, AUTO_READ);
+    fadecStartPhase[E3] = dm->make_named_var("A32NX_FADEC_START_PHASE_ENG3", UNITS.Number, AUTO_READ);
+    fadecStartPhase[E4] = dm->make_named_var("A32NX_FADEC_START_PHASE_ENG4", UNITS.Number, AUTO_READ);
+
     fuelLeftOuterPre  = dm->make_named_var("A32NX_FUEL_LEFTOUTER_PRE", UNITS.Number, AUTO_READ_WRITE);
     fuelFeedOnePre    = dm->make_named_var("A32NX_FUEL_FEED1_PRE", UNITS.Number, AUTO_READ_WRITE);
     fuelLeftMidPre    = dm->make_named_var("A32NX_FUEL_LEFTMID_PRE", UNITS.Number, AUTO_READ_WRITE);
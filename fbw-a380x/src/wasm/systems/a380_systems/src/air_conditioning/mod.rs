@@ -6,7 +6,7 @@ use systems::{
         cabin_air::CabinAirSimulation,
         pressure_valve::{NegativeRelieveValveSignal, SafetyValve},
         AdirsToAirCondInterface, Air, AirConditioningOverheadShared, AirConditioningPack,
-        AirHeater, CabinFan, DuctTemperature, FdacId, MixerUnit, OcsmId, OutletAir,
+        AirHeater, CabinFan, CabinPressure, DuctTemperature, FdacId, MixerUnit, OcsmId, OutletAir,
         OverheadFlowSelector, PackFlow, PackFlowControllers, PressurizationConstants,
         PressurizationOverheadShared, TrimAirSystem, VcmId, VcmShared, ZoneType,
     },
@@ -221,6 +221,16 @@ impl A380AirConditioning {
     }
 }
 
+impl CabinPressure for A380AirConditioning {
+    fn exterior_pressure(&self) -> Pressure {
+        self.a380_cabin.exterior_pressure()
+    }
+
+    fn cabin_pressure(&self) -> Pressure {
+        self.a380_cabin.cabin_pressure()
+    }
+}
+
 impl PackFlowControllers for A380AirConditioning {
     type PackFlowControllerSignal =
         <A380AirConditioningSystem as PackFlowControllers>::PackFlowControllerSignal;
@@ -1202,6 +1212,8 @@ mod tests {
             avionics_full_duplex_switch::AvionicsFullDuplexSwitch,
             core_processing_input_output_module::CoreProcessingInputOutputModule,
             input_output_module::InputOutputModule,
+            virtual_link::{VirtualLink, VirtualLinkConfiguration},
+            AvionicsDataCommunicationNetworkMessageIdentifier,
         },
         overhead::AutoOffFaultPushButton,
         pneumatic::{
@@ -1339,6 +1351,30 @@ mod tests {
         ) -> &InputOutputModule<A380AvionicsDataCommunicationNetworkMessageData> {
             unimplemented!()
         }
+
+        fn add_virtual_link(
+            &mut self,
+            _name: String,
+            _configuration: VirtualLinkConfiguration,
+            _network_a_path: &[u8],
+            _network_b_path: &[u8],
+        ) -> AvionicsDataCommunicationNetworkMessageIdentifier {
+            unimplemented!()
+        }
+
+        fn get_virtual_link(
+            &self,
+            _id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+        ) -> Option<&VirtualLink<A380AvionicsDataCommunicationNetworkMessageData>> {
+            unimplemented!()
+        }
+
+        fn get_virtual_link_mut(
+            &mut self,
+            _id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+        ) -> Option<&mut VirtualLink<A380AvionicsDataCommunicationNetworkMessageData>> {
+            unimplemented!()
+        }
     }
     impl SimulationElement for TestAdcn {
         fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -1,7 +1,8 @@
 use systems::{
     airframe::{CenterOfGravityData, WeightData},
     fuel::FuelPayload,
-    payload::{CargoPayload, LoadsheetInfo, PassengerPayload},
+    indicating_recording::load_report::StructuralLimits,
+    payload::{CargoPayload, Loadsheet, LoadsheetFigures, LoadsheetInfo, PassengerPayload},
     simulation::{InitContext, SimulationElement, SimulationElementVisitor},
 };
 use uom::si::{f64::Mass, mass::kilogram};
@@ -12,6 +13,7 @@ mod test;
 pub struct A380Airframe {
     center_of_gravity: CenterOfGravityData,
     weight: WeightData,
+    loadsheet: Loadsheet,
     // trim_horizontal_stabiliser: f64,
 }
 impl A380Airframe {
@@ -22,11 +24,29 @@ impl A380Airframe {
         mean_aerodynamic_chord_size: 40.35,
         leading_edge_mean_aerodynamic_chord: 21.09,
     };
+    const STRUCTURAL_LIMITS: StructuralLimits = StructuralLimits {
+        maximum_landing_weight_kg: 386000.,
+        hard_landing: (10., 2.6),
+        severe_hard_landing: (14., 2.86),
+        overweight_hard_landing: (6., 1.7),
+        // CONF 1+F, 2, 3 and FULL
+        flaps_extended_speeds_kt: &[(0.02, 222.), (0.45, 220.), (0.7, 196.), (0.95, 182.)],
+        gear_extended_speed_kt: 250.,
+        clean_load_factor: (-1., 2.5),
+        flaps_extended_load_factor: (0., 2.),
+    };
+    const TAKE_OFF_TRIM_CG_PERCENT_MAC: [f64; 3] = [29., 36., 43.];
+    const TAKE_OFF_TRIM_DEGREES: [f64; 3] = [3.2, 1.0, -1.3];
 
     pub fn new(context: &mut InitContext) -> Self {
         A380Airframe {
             center_of_gravity: CenterOfGravityData::new(context),
             weight: WeightData::new(context),
+            loadsheet: Loadsheet::new(
+                context,
+                &Self::TAKE_OFF_TRIM_CG_PERCENT_MAC,
+                &Self::TAKE_OFF_TRIM_DEGREES,
+            ),
             // trim_horizontal_stabiliser: 0.,
         }
     }
@@ -35,6 +55,14 @@ impl A380Airframe {
         &Self::LOADSHEET
     }
 
+    pub(crate) fn get_structural_limits() -> &'static StructuralLimits {
+        &Self::STRUCTURAL_LIMITS
+    }
+
+    pub(crate) fn weight(&self) -> &WeightData {
+        &self.weight
+    }
+
     #[cfg(test)]
     fn zero_fuel_weight_center_of_gravity(&self) -> f64 {
         self.center_of_gravity.zero_fuel_weight_center_of_gravity()
@@ -69,11 +97,16 @@ impl A380Airframe {
         self.center_of_gravity.target_take_off_center_of_gravity()
     }
 
-    fn convert_cg(cg: f64) -> f64 {
+    pub(crate) fn convert_cg(cg: f64) -> f64 {
         -100. * (cg - Self::LOADSHEET.leading_edge_mean_aerodynamic_chord)
             / Self::LOADSHEET.mean_aerodynamic_chord_size
     }
 
+    pub(crate) fn convert_cg_to_position(cg_percent_mac: f64) -> f64 {
+        Self::LOADSHEET.leading_edge_mean_aerodynamic_chord
+            - cg_percent_mac / 100. * Self::LOADSHEET.mean_aerodynamic_chord_size
+    }
+
     fn set_zero_fuel_weight_center_of_gravity(&mut self, zero_fuel_weight_cg: f64) {
         let zero_fuel_weight_center_of_gravity = Self::convert_cg(zero_fuel_weight_cg);
         self.center_of_gravity
@@ -213,12 +246,20 @@ impl A380Airframe {
 
         self.set_target_take_off_weight(target_tow);
         self.set_target_take_off_center_of_gravity(target_to_cg);
+
+        self.loadsheet.update(LoadsheetFigures {
+            zero_fuel_weight: target_zero_fuel_weight,
+            zero_fuel_weight_cg_percent_mac: Self::convert_cg(target_zero_fuel_weight_cg),
+            take_off_weight: target_tow,
+            take_off_cg_percent_mac: Self::convert_cg(target_to_cg),
+        });
     }
 }
 impl SimulationElement for A380Airframe {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.center_of_gravity.accept(visitor);
         self.weight.accept(visitor);
+        self.loadsheet.accept(visitor);
 
         visitor.visit(self);
     }
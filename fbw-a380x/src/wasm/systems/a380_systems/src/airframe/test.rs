@@ -2,7 +2,7 @@ use super::*;
 use crate::{
     payload::A380Payload,
     systems::simulation::{
-        test::{SimulationTestBed, TestBed},
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
         Aircraft, SimulationElement, SimulationElementVisitor,
     },
 };
@@ -435,6 +435,31 @@ impl AirframeTestBed {
     fn target_take_off_center_of_gravity(&self) -> f64 {
         self.query(|a| a.target_take_off_center_of_gravity())
     }
+
+    fn request_loadsheet(mut self) -> Self {
+        self.write_by_name("LOADSHEET_REQUESTED", true);
+        self
+    }
+
+    fn loadsheet_edition(&mut self) -> u32 {
+        self.read_by_name("LOADSHEET_EDITION")
+    }
+
+    fn loadsheet_zero_fuel_weight(&mut self) -> Mass {
+        Mass::new::<kilogram>(self.read_by_name("LOADSHEET_ZFW"))
+    }
+
+    fn loadsheet_zero_fuel_weight_cg(&mut self) -> f64 {
+        self.read_by_name("LOADSHEET_MACZFW")
+    }
+
+    fn loadsheet_take_off_cg(&mut self) -> f64 {
+        self.read_by_name("LOADSHEET_MACTOW")
+    }
+
+    fn loadsheet_take_off_trim(&mut self) -> f64 {
+        self.read_by_name("LOADSHEET_TAKEOFF_TRIM")
+    }
 }
 impl TestBed for AirframeTestBed {
     type Aircraft = AirframeTestAircraft;
@@ -573,3 +598,71 @@ fn half_pax_cargo_target_full() {
     assert!(target_gross_weight_center_of_gravity > 39.);
     assert!(target_gross_weight_center_of_gravity < 40.);
 }
+
+#[test]
+fn loadsheet_is_only_issued_on_request() {
+    let mut test_bed = test_bed_with()
+        .target_half_pax()
+        .target_half_cargo()
+        .load_low_fuel()
+        .and_run();
+
+    assert_eq!(test_bed.loadsheet_edition(), 0);
+    assert_eq!(test_bed.loadsheet_zero_fuel_weight(), Mass::default());
+
+    test_bed = test_bed.request_loadsheet().and_run();
+
+    assert_eq!(test_bed.loadsheet_edition(), 1);
+    let requested: bool = test_bed.read_by_name("LOADSHEET_REQUESTED");
+    assert!(!requested);
+    assert!(
+        (test_bed.loadsheet_zero_fuel_weight_cg()
+            - test_bed.target_zero_fuel_weight_center_of_gravity())
+        .abs()
+            <= 0.05
+    );
+    assert!(
+        (test_bed.loadsheet_take_off_cg() - test_bed.target_take_off_center_of_gravity()).abs()
+            <= 0.05
+    );
+}
+
+#[test]
+fn loadsheet_keeps_figures_until_next_edition() {
+    let mut test_bed = test_bed_with()
+        .target_half_pax()
+        .target_half_cargo()
+        .load_low_fuel()
+        .request_loadsheet()
+        .and_run();
+
+    let zero_fuel_weight = test_bed.loadsheet_zero_fuel_weight();
+
+    test_bed = test_bed.target_full_pax().target_full_cargo().and_run();
+    assert_eq!(test_bed.loadsheet_zero_fuel_weight(), zero_fuel_weight);
+
+    test_bed = test_bed.request_loadsheet().and_run();
+    assert_eq!(test_bed.loadsheet_edition(), 2);
+    assert!(test_bed.loadsheet_zero_fuel_weight() > zero_fuel_weight);
+}
+
+#[test]
+fn loadsheet_take_off_trim_decreases_with_aft_cg() {
+    let mut forward = test_bed_with()
+        .target_full_pax()
+        .load_low_fuel()
+        .target_cargo(Mass::new::<kilogram>(28577.), Vector3::new(67.4, 0., -1.9))
+        .request_loadsheet()
+        .and_run();
+    let mut aft = test_bed_with()
+        .target_full_pax()
+        .load_low_fuel()
+        .target_cargo(Mass::new::<kilogram>(20310.), Vector3::new(-18.5, 0., -1.9))
+        .request_loadsheet()
+        .and_run();
+
+    assert!(forward.loadsheet_take_off_cg() < aft.loadsheet_take_off_cg());
+    assert!(forward.loadsheet_take_off_trim() > aft.loadsheet_take_off_trim());
+    assert!(forward.loadsheet_take_off_trim() <= 3.2);
+    assert!(aft.loadsheet_take_off_trim() >= -1.3);
+}
@@ -1,13 +1,16 @@
 use crate::systems::{
+    accept_iterable,
     integrated_modular_avionics::{
         avionics_full_duplex_switch::AvionicsFullDuplexSwitch,
         core_processing_input_output_module::CoreProcessingInputOutputModule,
-        input_output_module::InputOutputModule, AvionicsDataCommunicationNetwork,
-        AvionicsDataCommunicationNetworkMessageIdentifier,
+        input_output_module::InputOutputModule,
+        topology::AvionicsDataCommunicationNetworkTopology,
+        virtual_link::{AfdxNetwork, VirtualLink, VirtualLinkConfiguration},
+        AvionicsDataCommunicationNetwork, AvionicsDataCommunicationNetworkMessageIdentifier,
     },
-    shared::ElectricalBusType,
+    shared::arinc429::SignStatus,
     simulation::{
-        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter,
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
         VariableIdentifier, Write,
     },
 };
@@ -23,20 +26,35 @@ use systems::integrated_modular_avionics::{
     AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus,
 };
 
+const TOPOLOGY_TOML: &str = include_str!("./avionics_data_communication_network.toml");
+// A topology placed in the work folder replaces the built-in one on the next flight load, which
+// allows changing the network without rebuilding the systems.
+const TOPOLOGY_OVERRIDE_PATH: &str = "\\work\\avionics_data_communication_network.toml";
+
 struct RoutingTableEntry {
     routing_id_1: VariableIdentifier,
     routing_id_2: VariableIdentifier,
+    from: usize,
+    to: usize,
     reachable: bool,
 }
 
 // The routing table entry describes if two AFDX switches are reachable
 impl RoutingTableEntry {
-    pub fn new(context: &mut InitContext, lower_id: u8, upper_id: u8) -> Self {
+    pub fn new(
+        context: &mut InitContext,
+        lower_id: u8,
+        upper_id: u8,
+        lower_index: usize,
+        upper_index: usize,
+    ) -> Self {
         Self {
             routing_id_1: context
                 .get_identifier(format!("AFDX_{}_{}_REACHABLE", lower_id, upper_id)),
             routing_id_2: context
                 .get_identifier(format!("AFDX_{}_{}_REACHABLE", upper_id, lower_id)),
+            from: lower_index,
+            to: upper_index,
             reachable: false,
         }
     }
@@ -53,6 +71,8 @@ impl RoutingTableEntry {
 
 // The ADCN contains information about the CDIOMs, IOMs and the AFDX networs.
 // The ADCN networks are defined by two redundant networks with same wirings.
+// The topology of the networks, the power supplies of the switches, the attached
+// modules and the virtual links are loaded from avionics_data_communication_network.toml.
 // The routing tables per network are defined as upper triangular matrices
 // that define if two AFDX switches are reachable.
 //
@@ -67,307 +87,285 @@ impl RoutingTableEntry {
 //
 // The routing tables define the upper triangular matrix for the two networks.
 // A breadth-first-search is used to update the routing table per AFDX switch.
+//
+// Messages can additionally be transported over virtual links with a statically defined path
+// through the switches of each network. Virtual links model the transmission timing, frame loss
+// and redundancy management, which degrades the freshness of the received data on partial
+// network failures.
 pub struct A380AvionicsDataCommunicationNetwork {
-    afdx_switches: [Rc<
-        RefCell<AvionicsFullDuplexSwitch<A380AvionicsDataCommunicationNetworkMessageData>>,
-    >; 16],
-    afdx_networks: [FxHashMap<u8, Vec<u8>>; 2],
+    afdx_switches:
+        Vec<Rc<RefCell<AvionicsFullDuplexSwitch<A380AvionicsDataCommunicationNetworkMessageData>>>>,
+    switch_indices: FxHashMap<u8, usize>,
+    switch_networks: Vec<AfdxNetwork>,
+    afdx_networks: [FxHashMap<usize, Vec<usize>>; 2],
     cpio_modules: FxHashMap<
-        &'static str,
+        String,
         CoreProcessingInputOutputModule<A380AvionicsDataCommunicationNetworkMessageData>,
     >,
     io_modules:
-        FxHashMap<&'static str, InputOutputModule<A380AvionicsDataCommunicationNetworkMessageData>>,
-    routing_tables: [[Vec<RoutingTableEntry>; 8]; 2],
+        FxHashMap<String, InputOutputModule<A380AvionicsDataCommunicationNetworkMessageData>>,
+    routing_tables: [Vec<Vec<RoutingTableEntry>>; 2],
     publish_routing_table: bool,
     next_message_identifier: AvionicsDataCommunicationNetworkMessageIdentifier,
     message_identifiers: FxHashMap<String, AvionicsDataCommunicationNetworkMessageIdentifier>,
+    virtual_links: FxHashMap<
+        AvionicsDataCommunicationNetworkMessageIdentifier,
+        VirtualLink<A380AvionicsDataCommunicationNetworkMessageData>,
+    >,
 }
 
 impl A380AvionicsDataCommunicationNetwork {
     pub fn new(context: &mut InitContext) -> Self {
-        let first_network = FxHashMap::from_iter([
-            (0, vec![1, 2, 7]),
-            (1, vec![0, 3, 7]),
-            (2, vec![0, 3, 4, 6, 7]),
-            (3, vec![1, 2, 5, 6, 7]),
-            (4, vec![2, 5, 6]),
-            (5, vec![3, 4, 6]),
-            (6, vec![2, 3, 4, 5]),
-            (7, vec![0, 1, 2, 3]),
-        ]);
-        let second_network = FxHashMap::from_iter([
-            (8, vec![9, 10, 15]),
-            (9, vec![8, 11, 15]),
-            (10, vec![8, 11, 12, 14, 15]),
-            (11, vec![9, 10, 13, 14, 15]),
-            (12, vec![10, 13, 14]),
-            (13, vec![11, 12, 14]),
-            (14, vec![10, 11, 12, 13]),
-            (15, vec![8, 9, 10, 11]),
-        ]);
-
-        let afdx_switches = [
-            (1, (ElectricalBusType::DirectCurrentEssential, None)), // powered by 425PP
-            (2, (ElectricalBusType::DirectCurrent(2), None)),
-            (3, (ElectricalBusType::DirectCurrentEssential, None)), // powered by 433PP
-            (4, (ElectricalBusType::DirectCurrent(2), None)),
-            (5, (ElectricalBusType::DirectCurrentNamed("108PH"), None)), // powered by 415PP
-            (6, (ElectricalBusType::DirectCurrent(2), None)),
-            (7, (ElectricalBusType::DirectCurrent(2), None)),
-            (9, (ElectricalBusType::DirectCurrentEssential, None)), // powered by 433PP
-            (
-                11,
-                (
-                    ElectricalBusType::DirectCurrent(1),
-                    Some(ElectricalBusType::DirectCurrentNamed("108PH")), // powered by 417PP
-                ),
-            ),
-            (12, (ElectricalBusType::DirectCurrent(1), None)),
-            (
-                13,
-                (
-                    ElectricalBusType::DirectCurrent(1),
-                    Some(ElectricalBusType::DirectCurrentNamed("108PH")), // powered by 417PP
+        Self::new_with_topology(context, &Self::load_topology())
+    }
+
+    fn load_topology() -> AvionicsDataCommunicationNetworkTopology {
+        let built_in = AvionicsDataCommunicationNetworkTopology::from_toml(TOPOLOGY_TOML)
+            .expect("Failed to parse ADCN topology TOML file");
+
+        if let Ok(source) = std::fs::read_to_string(TOPOLOGY_OVERRIDE_PATH) {
+            match AvionicsDataCommunicationNetworkTopology::from_toml(&source)
+                .map_err(|error| error.to_string())
+                .and_then(|topology| {
+                    Self::check_required_elements(&topology, &built_in).map(|_| topology)
+                }) {
+                Ok(topology) => return topology,
+                Err(error) => eprintln!(
+                    "SYSTEMS: Ignoring ADCN topology {}: {}",
+                    TOPOLOGY_OVERRIDE_PATH, error
                 ),
-            ),
-            (14, (ElectricalBusType::DirectCurrent(1), None)),
-            (
-                15,
+            }
+        }
+
+        built_in
+    }
+
+    // The aircraft systems look up switches by id, modules by name and virtual links by name, so an
+    // override may rewire the network, but it has to keep all switches, modules and virtual links of
+    // the built-in topology, and route the virtual links on every network the built-in one does.
+    fn check_required_elements(
+        topology: &AvionicsDataCommunicationNetworkTopology,
+        built_in: &AvionicsDataCommunicationNetworkTopology,
+    ) -> Result<(), String> {
+        if let Some(switch) = built_in
+            .switches()
+            .iter()
+            .find(|switch| topology.switch(switch.id()).is_none())
+        {
+            return Err(format!("Switch {} is missing", switch.id()));
+        }
+
+        for (kind, required, present) in [
+            ("CPIOM", built_in.cpioms(), topology.cpioms()),
+            ("IOM", built_in.ioms(), topology.ioms()),
+        ] {
+            if let Some(module) = required
+                .iter()
+                .find(|module| !present.iter().any(|other| other.name() == module.name()))
+            {
+                return Err(format!("{} {} is missing", kind, module.name()));
+            }
+        }
+
+        for required in built_in.virtual_links() {
+            let virtual_link = topology
+                .virtual_links()
+                .iter()
+                .find(|virtual_link| virtual_link.name() == required.name())
+                .ok_or_else(|| format!("Virtual link {} is missing", required.name()))?;
+
+            for (network, required_path, path) in [
                 (
-                    ElectricalBusType::DirectCurrent(1),
-                    Some(ElectricalBusType::DirectCurrentNamed("108PH")), // powered by 417PP
+                    AfdxNetwork::A,
+                    required.network_a_path(),
+                    virtual_link.network_a_path(),
                 ),
-            ),
-            (16, (ElectricalBusType::DirectCurrent(1), None)),
-            (17, (ElectricalBusType::DirectCurrent(1), None)),
-            (
-                19,
                 (
-                    ElectricalBusType::DirectCurrent(1),
-                    Some(ElectricalBusType::DirectCurrentNamed("108PH")), // powered by 417PP
+                    AfdxNetwork::B,
+                    required.network_b_path(),
+                    virtual_link.network_b_path(),
                 ),
-            ),
-        ]
-        .map(|(id, (primary_power_supply, secondary_power_supply))| {
-            Rc::new(
-                if let Some(secondary_power_supply) = secondary_power_supply {
-                    RefCell::new(AvionicsFullDuplexSwitch::new_dual_power_supply(
-                        context,
-                        id,
-                        primary_power_supply,
-                        secondary_power_supply,
-                    ))
-                } else {
-                    RefCell::new(AvionicsFullDuplexSwitch::new_single_power_supply(
-                        context,
-                        id,
-                        primary_power_supply,
-                    ))
-                },
-            )
+            ] {
+                if !required_path.is_empty() && path.is_empty() {
+                    return Err(format!(
+                        "Virtual link {} isn't routed on network {:?}",
+                        virtual_link.name(),
+                        network
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn new_with_topology(
+        context: &mut InitContext,
+        topology: &AvionicsDataCommunicationNetworkTopology,
+    ) -> Self {
+        let afdx_switches: Vec<_> = topology
+            .switches()
+            .iter()
+            .map(|switch| {
+                Rc::new(RefCell::new(
+                    if let Some(secondary_power_supply) = switch.secondary_power_supply() {
+                        AvionicsFullDuplexSwitch::new_dual_power_supply(
+                            context,
+                            switch.id(),
+                            switch.power_supply(),
+                            secondary_power_supply,
+                        )
+                    } else {
+                        AvionicsFullDuplexSwitch::new_single_power_supply(
+                            context,
+                            switch.id(),
+                            switch.power_supply(),
+                        )
+                    },
+                ))
+            })
+            .collect();
+
+        let switch_indices: FxHashMap<u8, usize> = topology
+            .switches()
+            .iter()
+            .enumerate()
+            .map(|(index, switch)| (switch.id(), index))
+            .collect();
+
+        let afdx_networks = [AfdxNetwork::A, AfdxNetwork::B].map(|network| {
+            topology
+                .switches()
+                .iter()
+                .enumerate()
+                .filter(|(_, switch)| switch.network() == network)
+                .map(|(index, switch)| {
+                    (
+                        index,
+                        switch
+                            .linked_to()
+                            .iter()
+                            .map(|id| switch_indices[id])
+                            .collect(),
+                    )
+                })
+                .collect()
         });
 
-        let io_modules = FxHashMap::from_iter(
-            [
-                ("A1", 1, ElectricalBusType::DirectCurrentEssential), // powered by 425PP
-                ("A2", 2, ElectricalBusType::DirectCurrent(2)),
-                ("A3", 1, ElectricalBusType::DirectCurrentEssential), // powered by 425PP
-                ("A4", 2, ElectricalBusType::DirectCurrent(2)),
-                ("A5", 3, ElectricalBusType::DirectCurrentEssential), // powered by 433PP
-                ("A6", 4, ElectricalBusType::DirectCurrent(2)),
-                ("A7", 3, ElectricalBusType::DirectCurrentEssential), // powered by 433PP
-                ("A8", 4, ElectricalBusType::DirectCurrent(2)),
-            ]
-            .map(|(name, connected_switch, power_supply)| {
+        let routing_tables = [AfdxNetwork::A, AfdxNetwork::B].map(|network| {
+            let mut ids: Vec<u8> = topology
+                .switches()
+                .iter()
+                .filter(|switch| switch.network() == network)
+                .map(|switch| switch.id())
+                .collect();
+            ids.sort_unstable();
+
+            ids.iter()
+                .enumerate()
+                .map(|(y, &lower_id)| {
+                    ids[y..]
+                        .iter()
+                        .map(|&upper_id| {
+                            RoutingTableEntry::new(
+                                context,
+                                lower_id,
+                                upper_id,
+                                switch_indices[&lower_id],
+                                switch_indices[&upper_id],
+                            )
+                        })
+                        .collect()
+                })
+                .collect()
+        });
+
+        let connected_switches = |switches: &[u8]| {
+            switches
+                .iter()
+                .map(|id| afdx_switches[switch_indices[id]].clone())
+                .collect::<Vec<_>>()
+        };
+
+        let io_modules = topology
+            .ioms()
+            .iter()
+            .map(|module| {
                 (
-                    name,
+                    module.name().to_owned(),
                     InputOutputModule::new(
                         context,
-                        name,
-                        power_supply,
-                        [connected_switch, connected_switch + 10]
-                            .map(|id| afdx_switches[Self::map_switch_id(id)].clone())
-                            .to_vec(),
+                        module.name(),
+                        module.power_supply(),
+                        connected_switches(module.switches()),
                     ),
                 )
-            }),
-        );
+            })
+            .collect();
 
-        let cpio_modules = FxHashMap::from_iter(
-            [
-                ("A1", 7, ElectricalBusType::DirectCurrent(1)),
-                ("A2", 5, ElectricalBusType::DirectCurrentNamed("108PH")), // powered by 419PP
-                ("A3", 5, ElectricalBusType::DirectCurrentNamed("108PH")), // powered by 415PP
-                ("A4", 6, ElectricalBusType::DirectCurrent(2)),
-                ("B1", 7, ElectricalBusType::DirectCurrent(1)),
-                ("B2", 5, ElectricalBusType::DirectCurrentNamed("108PH")), // powered by 419PP
-                ("B3", 5, ElectricalBusType::DirectCurrentNamed("108PH")), // powered by 417PP
-                ("B4", 6, ElectricalBusType::DirectCurrent(2)),
-                ("C1", 3, ElectricalBusType::DirectCurrentEssential), // powered by 433PP
-                ("C2", 4, ElectricalBusType::DirectCurrent(2)),
-                ("D1", 3, ElectricalBusType::DirectCurrent(1)),
-                ("D3", 3, ElectricalBusType::DirectCurrent(1)),
-                ("E1", 5, ElectricalBusType::DirectCurrent(1)),
-                ("E2", 6, ElectricalBusType::DirectCurrent(2)),
-                ("F1", 5, ElectricalBusType::DirectCurrentNamed("108PH")), // powered by 419PP
-                ("F2", 6, ElectricalBusType::DirectCurrentNamed("502PP")),
-                ("F3", 5, ElectricalBusType::DirectCurrentNamed("108PH")), // powered by 419PP
-                ("F4", 6, ElectricalBusType::DirectCurrentNamed("502PP")),
-                ("G1", 7, ElectricalBusType::DirectCurrent(1)),
-                ("G2", 6, ElectricalBusType::DirectCurrent(2)),
-                ("G3", 7, ElectricalBusType::DirectCurrent(2)),
-                ("G4", 6, ElectricalBusType::DirectCurrent(2)),
-            ]
-            .map(|(name, connected_switch, bus)| {
+        let cpio_modules = topology
+            .cpioms()
+            .iter()
+            .map(|module| {
                 (
-                    name,
+                    module.name().to_owned(),
                     CoreProcessingInputOutputModule::new(
                         context,
-                        name,
-                        bus,
-                        [connected_switch, connected_switch + 10]
-                            .map(|id| afdx_switches[Self::map_switch_id(id)].clone())
-                            .to_vec(),
+                        module.name(),
+                        module.power_supply(),
+                        connected_switches(module.switches()),
                     ),
                 )
-            }),
-        );
-
-        Self {
+            })
+            .collect();
+
+        let mut adcn = Self {
+            switch_networks: topology
+                .switches()
+                .iter()
+                .map(|switch| switch.network())
+                .collect(),
             afdx_switches,
-            afdx_networks: [first_network, second_network],
+            switch_indices,
+            afdx_networks,
             io_modules,
             cpio_modules,
-            routing_tables: [
-                [
-                    vec![
-                        RoutingTableEntry::new(context, 1, 1),
-                        RoutingTableEntry::new(context, 1, 2),
-                        RoutingTableEntry::new(context, 1, 3),
-                        RoutingTableEntry::new(context, 1, 4),
-                        RoutingTableEntry::new(context, 1, 5),
-                        RoutingTableEntry::new(context, 1, 6),
-                        RoutingTableEntry::new(context, 1, 7),
-                        RoutingTableEntry::new(context, 1, 9),
-                    ],
-                    vec![
-                        RoutingTableEntry::new(context, 2, 2),
-                        RoutingTableEntry::new(context, 2, 3),
-                        RoutingTableEntry::new(context, 2, 4),
-                        RoutingTableEntry::new(context, 2, 5),
-                        RoutingTableEntry::new(context, 2, 6),
-                        RoutingTableEntry::new(context, 2, 7),
-                        RoutingTableEntry::new(context, 2, 9),
-                    ],
-                    vec![
-                        RoutingTableEntry::new(context, 3, 3),
-                        RoutingTableEntry::new(context, 3, 4),
-                        RoutingTableEntry::new(context, 3, 5),
-                        RoutingTableEntry::new(context, 3, 6),
-                        RoutingTableEntry::new(context, 3, 7),
-                        RoutingTableEntry::new(context, 3, 9),
-                    ],
-                    vec![
-                        RoutingTableEntry::new(context, 4, 4),
-                        RoutingTableEntry::new(context, 4, 5),
-                        RoutingTableEntry::new(context, 4, 6),
-                        RoutingTableEntry::new(context, 4, 7),
-                        RoutingTableEntry::new(context, 4, 9),
-                    ],
-                    vec![
-                        RoutingTableEntry::new(context, 5, 5),
-                        RoutingTableEntry::new(context, 5, 6),
-                        RoutingTableEntry::new(context, 5, 7),
-                        RoutingTableEntry::new(context, 5, 9),
-                    ],
-                    vec![
-                        RoutingTableEntry::new(context, 6, 6),
-                        RoutingTableEntry::new(context, 6, 7),
-                        RoutingTableEntry::new(context, 6, 9),
-                    ],
-                    vec![
-                        RoutingTableEntry::new(context, 7, 7),
-                        RoutingTableEntry::new(context, 7, 9),
-                    ],
-                    vec![RoutingTableEntry::new(context, 9, 9)],
-                ],
-                [
-                    vec![
-                        RoutingTableEntry::new(context, 11, 11),
-                        RoutingTableEntry::new(context, 11, 12),
-                        RoutingTableEntry::new(context, 11, 13),
-                        RoutingTableEntry::new(context, 11, 14),
-                        RoutingTableEntry::new(context, 11, 15),
-                        RoutingTableEntry::new(context, 11, 16),
-                        RoutingTableEntry::new(context, 11, 17),
-                        RoutingTableEntry::new(context, 11, 19),
-                    ],
-                    vec![
-                        RoutingTableEntry::new(context, 12, 12),
-                        RoutingTableEntry::new(context, 12, 13),
-                        RoutingTableEntry::new(context, 12, 14),
-                        RoutingTableEntry::new(context, 12, 15),
-                        RoutingTableEntry::new(context, 12, 16),
-                        RoutingTableEntry::new(context, 12, 17),
-                        RoutingTableEntry::new(context, 12, 19),
-                    ],
-                    vec![
-                        RoutingTableEntry::new(context, 13, 13),
-                        RoutingTableEntry::new(context, 13, 14),
-                        RoutingTableEntry::new(context, 13, 15),
-                        RoutingTableEntry::new(context, 13, 16),
-                        RoutingTableEntry::new(context, 13, 17),
-                        RoutingTableEntry::new(context, 13, 19),
-                    ],
-                    vec![
-                        RoutingTableEntry::new(context, 14, 14),
-                        RoutingTableEntry::new(context, 14, 15),
-                        RoutingTableEntry::new(context, 14, 16),
-                        RoutingTableEntry::new(context, 14, 17),
-                        RoutingTableEntry::new(context, 14, 19),
-                    ],
-                    vec![
-                        RoutingTableEntry::new(context, 15, 15),
-                        RoutingTableEntry::new(context, 15, 16),
-                        RoutingTableEntry::new(context, 15, 17),
-                        RoutingTableEntry::new(context, 15, 19),
-                    ],
-                    vec![
-                        RoutingTableEntry::new(context, 16, 16),
-                        RoutingTableEntry::new(context, 16, 17),
-                        RoutingTableEntry::new(context, 16, 19),
-                    ],
-                    vec![
-                        RoutingTableEntry::new(context, 17, 17),
-                        RoutingTableEntry::new(context, 17, 19),
-                    ],
-                    vec![RoutingTableEntry::new(context, 19, 19)],
-                ],
-            ],
+            routing_tables,
             publish_routing_table: true,
             next_message_identifier: AvionicsDataCommunicationNetworkMessageIdentifier::default(),
             message_identifiers: FxHashMap::default(),
+            virtual_links: FxHashMap::default(),
+        };
+
+        for virtual_link in topology.virtual_links() {
+            adcn.add_virtual_link(
+                virtual_link.name().to_owned(),
+                virtual_link.configuration(),
+                virtual_link.network_a_path(),
+                virtual_link.network_b_path(),
+            );
         }
+
+        adcn
     }
 
     fn switches_reachable(
-        afdx_switches: &[Rc<RefCell<AvionicsFullDuplexSwitch<A380AvionicsDataCommunicationNetworkMessageData>>>;
-             16],
-        network: &FxHashMap<u8, Vec<u8>>,
-        from: u8,
-        to: u8,
+        afdx_switches: &[Rc<
+            RefCell<AvionicsFullDuplexSwitch<A380AvionicsDataCommunicationNetworkMessageData>>,
+        >],
+        network: &FxHashMap<usize, Vec<usize>>,
+        from: usize,
+        to: usize,
     ) -> bool {
-        let mut frontier: VecDeque<u8> = VecDeque::new();
-        let mut visited: Vec<u8> = Vec::new();
+        let mut frontier: VecDeque<usize> = VecDeque::new();
+        let mut visited = vec![false; afdx_switches.len()];
 
-        if !afdx_switches[from as usize].borrow().is_available() {
+        if !afdx_switches[from].borrow().is_available() {
             return false;
         }
 
-        visited.resize(network.len() * 2, 0xff);
         frontier.push_front(from);
-        visited[from as usize] = from;
+        visited[from] = true;
 
         while !frontier.is_empty() {
             let node = frontier.pop_front().unwrap();
@@ -378,10 +376,8 @@ impl A380AvionicsDataCommunicationNetwork {
 
             let neighbors = &network[&node];
             for &neighbor in neighbors {
-                if afdx_switches[neighbor as usize].borrow().is_available()
-                    && visited[neighbor as usize] == 0xff
-                {
-                    visited[neighbor as usize] = node;
+                if afdx_switches[neighbor].borrow().is_available() && !visited[neighbor] {
+                    visited[neighbor] = true;
                     frontier.push_back(neighbor);
                 }
             }
@@ -390,26 +386,24 @@ impl A380AvionicsDataCommunicationNetwork {
         false
     }
 
-    fn update_routing_table(&mut self, network: usize, offset: usize) {
-        for (y, row) in self.routing_tables[network].iter_mut().enumerate() {
-            for (x, entry) in row.iter_mut().enumerate() {
+    fn update_routing_table(&mut self, network: usize) {
+        for row in self.routing_tables[network].iter_mut() {
+            for entry in row.iter_mut() {
                 entry.set_reachable(Self::switches_reachable(
                     &self.afdx_switches,
                     &self.afdx_networks[network],
-                    (y + offset) as u8,
-                    (x + offset) as u8,
+                    entry.from,
+                    entry.to,
                 ));
             }
         }
     }
 
     fn update_switch_messages(&mut self, network: usize) {
-        let mut initialised = [false; 16];
+        let mut initialised = vec![false; self.afdx_switches.len()];
         for (&switch_id, neighbours) in &self.afdx_networks[network] {
-            let switch_id = switch_id as usize;
             if !initialised[switch_id] {
                 let messages = if let Some(neighboor) = neighbours.iter().find_map(|&id| {
-                    let id = id as usize;
                     (initialised[id] && self.afdx_switches[id].borrow().is_available())
                         .then_some(id)
                 }) {
@@ -425,7 +419,6 @@ impl A380AvionicsDataCommunicationNetwork {
                 let current_messages = current_switch.borrow().get_adcn_messages();
 
                 for &neighbour in neighbours {
-                    let neighbour = neighbour as usize;
                     if !initialised[neighbour] {
                         let neighbouring_switch = &mut self.afdx_switches[neighbour];
                         let messages = if neighbouring_switch.borrow().is_available() {
@@ -443,44 +436,60 @@ impl A380AvionicsDataCommunicationNetwork {
         }
     }
 
-    pub fn update(&mut self) {
-        let mut update_network_a = false;
-        let mut update_network_b = false;
+    pub fn update(&mut self, context: &UpdateContext) {
+        let mut update_networks = [false; 2];
 
-        for (i, afdx) in self.afdx_switches.iter().enumerate() {
+        for (afdx, &network) in self.afdx_switches.iter().zip(&self.switch_networks) {
             let mut afdx = RefCell::borrow_mut(afdx);
             afdx.update();
             if afdx.routing_update_required() {
-                if i >= 8 {
-                    update_network_b = true;
-                } else {
-                    update_network_a = true;
-                }
+                update_networks[network as usize] = true;
             }
         }
 
-        if update_network_a {
-            self.update_routing_table(0, 0);
-            self.update_switch_messages(0);
+        for (network, &update_network) in update_networks.iter().enumerate() {
+            if update_network {
+                self.update_routing_table(network);
+                self.update_switch_messages(network);
+            }
         }
 
-        if update_network_b {
-            self.update_routing_table(1, 8);
-            self.update_switch_messages(1);
+        self.publish_routing_table = update_networks.iter().any(|&update| update);
+
+        for virtual_link in self.virtual_links.values_mut() {
+            virtual_link.update(context);
         }
+    }
+
+    fn virtual_link_path(
+        &self,
+        network: usize,
+        path: &[u8],
+    ) -> Vec<Rc<RefCell<AvionicsFullDuplexSwitch<A380AvionicsDataCommunicationNetworkMessageData>>>>
+    {
+        let indices: Vec<usize> = path.iter().map(|&id| self.switch_index(id)).collect();
+
+        assert!(
+            indices
+                .iter()
+                .all(|index| self.afdx_networks[network].contains_key(index)),
+            "Virtual link path contains switches of another network."
+        );
+        assert!(
+            indices
+                .windows(2)
+                .all(|hop| self.afdx_networks[network][&hop[0]].contains(&hop[1])),
+            "Virtual link path contains switches which aren't connected to each other."
+        );
 
-        self.publish_routing_table = update_network_a | update_network_b;
+        indices
+            .iter()
+            .map(|&index| self.afdx_switches[index].clone())
+            .collect()
     }
 
-    const fn map_switch_id(id: u8) -> usize {
-        let id = id as usize;
-        match id {
-            1..=7 => id - 1,
-            9 => 7,
-            11..=17 => id - 3,
-            19 => 15,
-            _ => panic!("Unknown switch id"),
-        }
+    fn switch_index(&self, id: u8) -> usize {
+        *self.switch_indices.get(&id).expect("Unknown switch id")
     }
 }
 impl<'a> AvionicsDataCommunicationNetwork<'a, A380AvionicsDataCommunicationNetworkMessageData>
@@ -502,7 +511,7 @@ impl<'a> AvionicsDataCommunicationNetwork<'a, A380AvionicsDataCommunicationNetwo
     }
 
     fn get_endpoint(&'a self, id: u8) -> Self::NetworkEndpointRef {
-        self.afdx_switches[Self::map_switch_id(id)].borrow()
+        self.afdx_switches[self.switch_index(id)].borrow()
     }
 
     fn get_cpiom(
@@ -518,6 +527,38 @@ impl<'a> AvionicsDataCommunicationNetwork<'a, A380AvionicsDataCommunicationNetwo
     ) -> &InputOutputModule<A380AvionicsDataCommunicationNetworkMessageData> {
         &self.io_modules[name]
     }
+
+    fn add_virtual_link(
+        &mut self,
+        name: String,
+        configuration: VirtualLinkConfiguration,
+        network_a_path: &[u8],
+        network_b_path: &[u8],
+    ) -> AvionicsDataCommunicationNetworkMessageIdentifier {
+        let id = self.get_message_identifier(name);
+        let virtual_link = VirtualLink::new(
+            configuration,
+            self.virtual_link_path(0, network_a_path),
+            self.virtual_link_path(1, network_b_path),
+        );
+        self.virtual_links.insert(id, virtual_link);
+
+        id
+    }
+
+    fn get_virtual_link(
+        &self,
+        id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+    ) -> Option<&VirtualLink<A380AvionicsDataCommunicationNetworkMessageData>> {
+        self.virtual_links.get(id)
+    }
+
+    fn get_virtual_link_mut(
+        &mut self,
+        id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+    ) -> Option<&mut VirtualLink<A380AvionicsDataCommunicationNetworkMessageData>> {
+        self.virtual_links.get_mut(id)
+    }
 }
 
 impl SimulationElement for A380AvionicsDataCommunicationNetwork {
@@ -546,34 +587,150 @@ impl SimulationElement for A380AvionicsDataCommunicationNetwork {
     }
 }
 
+/// Receives the fuel data the FQMS of one side sends to the FWS of the same side over its
+/// virtual link and provides it to the FWS application. Data which is older than the freshness
+/// limit of the virtual link, or which the FQMS doesn't flag as normal operation, isn't used.
+struct FqmsToFwsReceiver {
+    virtual_link_id: AvionicsDataCommunicationNetworkMessageIdentifier,
+    total_fuel_on_board_id: VariableIdentifier,
+    center_of_gravity_id: VariableIdentifier,
+    status_word_id: VariableIdentifier,
+
+    received: Option<(
+        AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus,
+        FqmsFuelData,
+    )>,
+}
+impl FqmsToFwsReceiver {
+    fn new<'a>(
+        context: &mut InitContext,
+        adcn: &mut impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+        side: usize,
+    ) -> Self {
+        Self {
+            virtual_link_id: adcn.get_message_identifier(format!("FQMS_{side}_TO_FWS_{side}")),
+            total_fuel_on_board_id: context
+                .get_identifier(format!("FWS_{side}_FQMS_TOTAL_FUEL_ON_BOARD")),
+            center_of_gravity_id: context
+                .get_identifier(format!("FWS_{side}_FQMS_CENTER_OF_GRAVITY_MAC")),
+            status_word_id: context.get_identifier(format!("FWS_{side}_FQMS_STATUS_WORD")),
+            received: None,
+        }
+    }
+
+    fn update<'a>(
+        &mut self,
+        adcn: &impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+    ) {
+        self.received = adcn
+            .get_virtual_link(&self.virtual_link_id)
+            .filter(|virtual_link| virtual_link.is_fresh())
+            .and_then(|virtual_link| virtual_link.recv_value())
+            .and_then(|message| {
+                message
+                    .data()
+                    .fqms_fuel_data()
+                    .map(|data| (message.status(), *data))
+            });
+    }
+
+    fn ssm(&self, is_value_computed: bool) -> SignStatus {
+        match self.received.map(|(status, _)| status) {
+            Some(
+                AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::NormalOperation,
+            ) if is_value_computed => SignStatus::NormalOperation,
+            Some(
+                AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::NormalOperation,
+            )
+            | Some(
+                AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::NoComputedData,
+            ) => SignStatus::NoComputedData,
+            Some(
+                AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::FunctionalTest,
+            ) => SignStatus::FunctionalTest,
+            Some(AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::NoData) | None => {
+                SignStatus::FailureWarning
+            }
+        }
+    }
+}
+impl SimulationElement for FqmsToFwsReceiver {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        let data = self.received.map(|(_, data)| data).unwrap_or_default();
+
+        writer.write_arinc429(
+            &self.total_fuel_on_board_id,
+            data.total_fuel_on_board.map_or(0., |fob| fob as f64),
+            self.ssm(data.total_fuel_on_board.is_some()),
+        );
+        writer.write_arinc429(
+            &self.center_of_gravity_id,
+            data.center_of_gravity_mac.map_or(0., |cg| cg as f64 / 10.),
+            self.ssm(data.center_of_gravity_mac.is_some()),
+        );
+        writer.write_arinc429(&self.status_word_id, data.status_word, self.ssm(true));
+    }
+}
+
 // This struct is intended to translate simvar values to ADCN messages
 // and ADCN messages to simvars.
-pub struct A380AvionicsDataCommunicationNetworkSimvarTranslator {}
+pub struct A380AvionicsDataCommunicationNetworkSimvarTranslator {
+    fqms_to_fws_receivers: [FqmsToFwsReceiver; 2],
+}
 impl A380AvionicsDataCommunicationNetworkSimvarTranslator {
     pub fn new<'a>(
-        _context: &mut InitContext,
-        _adcn: &mut impl AvionicsDataCommunicationNetwork<
+        context: &mut InitContext,
+        adcn: &mut impl AvionicsDataCommunicationNetwork<
             'a,
             A380AvionicsDataCommunicationNetworkMessageData,
         >,
     ) -> Self {
-        Self {}
+        Self {
+            fqms_to_fws_receivers: [1, 2].map(|side| FqmsToFwsReceiver::new(context, adcn, side)),
+        }
     }
 
     pub fn update<'a>(
         &mut self,
-        _adcn: &impl AvionicsDataCommunicationNetwork<
+        adcn: &impl AvionicsDataCommunicationNetwork<
             'a,
             A380AvionicsDataCommunicationNetworkMessageData,
         >,
     ) {
+        for receiver in &mut self.fqms_to_fws_receivers {
+            receiver.update(adcn);
+        }
+    }
+}
+impl SimulationElement for A380AvionicsDataCommunicationNetworkSimvarTranslator {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.fqms_to_fws_receivers, visitor);
+
+        visitor.visit(self);
     }
 }
-impl SimulationElement for A380AvionicsDataCommunicationNetworkSimvarTranslator {}
+
+/// Fuel data the FQMS sends to the FWS of its side
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FqmsFuelData {
+    /// Total fuel on board in kilograms
+    pub total_fuel_on_board: Option<u32>,
+    /// Center of gravity of the aircraft in tenths of percent MAC
+    pub center_of_gravity_mac: Option<u32>,
+    /// The FQMS status word, with the bits at their ARINC 429 positions
+    pub status_word: u32,
+}
 
 /// This type represents all the messages which can be send over AFDX
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum A380AvionicsDataCommunicationNetworkMessageData {
+    FqmsFuelData(FqmsFuelData),
     #[cfg(test)]
     TestValue(&'static str),
 }
@@ -584,6 +741,14 @@ impl A380AvionicsDataCommunicationNetworkMessageData {
     ) -> AvionicsDataCommunicationNetworkMessage<Self> {
         AvionicsDataCommunicationNetworkMessage::new(status, self)
     }
+
+    fn fqms_fuel_data(&self) -> Option<&FqmsFuelData> {
+        match self {
+            Self::FqmsFuelData(data) => Some(data),
+            #[cfg(test)]
+            _ => None,
+        }
+    }
 }
 impl From<A380AvionicsDataCommunicationNetworkMessageData>
     for AvionicsDataCommunicationNetworkMessage<A380AvionicsDataCommunicationNetworkMessageData>
@@ -601,17 +766,25 @@ mod tests {
     use crate::systems::{
         electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
         integrated_modular_avionics::AvionicsDataCommunicationNetworkEndpoint,
-        shared::PotentialOrigin,
+        shared::{ElectricalBusType, PotentialOrigin},
         simulation::{
             test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
             Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
         },
     };
-    use systems::integrated_modular_avionics::AvionicsDataCommunicationNetworkMessage;
+    use ntest::assert_about_eq;
+    use std::time::Duration;
+    use systems::{
+        integrated_modular_avionics::{
+            virtual_link::AfdxNetwork, AvionicsDataCommunicationNetworkMessage,
+        },
+        shared::arinc429::Arinc429Word,
+    };
     use uom::si::{electric_potential::volt, f64::*};
 
     struct AdcnTestAircraft {
         adcn: A380AvionicsDataCommunicationNetwork,
+        simvar_translator: A380AvionicsDataCommunicationNetworkSimvarTranslator,
         powered_source_dc: TestElectricitySource,
         dc_1_bus: ElectricalBus,
         dc_2_bus: ElectricalBus,
@@ -621,8 +794,12 @@ mod tests {
     }
     impl AdcnTestAircraft {
         fn new(context: &mut InitContext) -> Self {
+            let mut adcn = A380AvionicsDataCommunicationNetwork::new(context);
+            let simvar_translator =
+                A380AvionicsDataCommunicationNetworkSimvarTranslator::new(context, &mut adcn);
             Self {
-                adcn: A380AvionicsDataCommunicationNetwork::new(context),
+                adcn,
+                simvar_translator,
                 powered_source_dc: TestElectricitySource::powered(
                     context,
                     PotentialOrigin::Battery(2),
@@ -638,8 +815,9 @@ mod tests {
             }
         }
 
-        fn update(&mut self) {
-            self.adcn.update();
+        fn update(&mut self, context: &UpdateContext) {
+            self.adcn.update(context);
+            self.simvar_translator.update(&self.adcn);
         }
 
         fn set_elec_powered(&mut self, is_powered: bool) {
@@ -675,6 +853,39 @@ mod tests {
         > {
             self.adcn.get_endpoint(switch_id).recv_value(id)
         }
+        fn add_virtual_link(
+            &mut self,
+            configuration: VirtualLinkConfiguration,
+            network_a_path: &[u8],
+            network_b_path: &[u8],
+        ) -> AvionicsDataCommunicationNetworkMessageIdentifier {
+            self.adcn.add_virtual_link(
+                "test_virtual_link".to_owned(),
+                configuration,
+                network_a_path,
+                network_b_path,
+            )
+        }
+
+        fn send_virtual_link_message(
+            &mut self,
+            id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+            message: AvionicsDataCommunicationNetworkMessage<
+                A380AvionicsDataCommunicationNetworkMessageData,
+            >,
+        ) {
+            self.adcn
+                .get_virtual_link_mut(id)
+                .unwrap()
+                .send_value(message);
+        }
+
+        fn virtual_link(
+            &self,
+            id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+        ) -> &VirtualLink<A380AvionicsDataCommunicationNetworkMessageData> {
+            self.adcn.get_virtual_link(id).unwrap()
+        }
     }
     impl Aircraft for AdcnTestAircraft {
         fn update_before_power_distribution(
@@ -694,24 +905,27 @@ mod tests {
             }
         }
 
-        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
-            self.update();
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.update(context);
         }
     }
     impl SimulationElement for AdcnTestAircraft {
         fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
             self.adcn.accept(visitor);
+            self.simvar_translator.accept(visitor);
             visitor.visit(self);
         }
     }
 
     #[test]
     fn switch_mapping() {
+        let test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
         for (i, id) in [1, 2, 3, 4, 5, 6, 7, 9, 11, 12, 13, 14, 15, 16, 17, 19]
             .iter()
             .enumerate()
         {
-            assert_eq!(A380AvionicsDataCommunicationNetwork::map_switch_id(*id), i);
+            assert_eq!(test_bed.query(|a| a.adcn.switch_index(*id)), i);
         }
     }
 
@@ -1002,4 +1216,339 @@ mod tests {
             assert_eq!(message, Some(message2.clone()));
         }
     }
+
+    fn virtual_link_test_bed() -> (
+        SimulationTestBed<AdcnTestAircraft>,
+        AvionicsDataCommunicationNetworkMessageIdentifier,
+    ) {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        let mut vl_id = Default::default();
+        test_bed.command(|a| {
+            vl_id = a.add_virtual_link(
+                VirtualLinkConfiguration::new(Duration::from_millis(32), 1518),
+                &[1, 3, 4],
+                &[11, 13, 14],
+            )
+        });
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.run();
+
+        (test_bed, vl_id)
+    }
+
+    #[test]
+    fn virtual_link_delivers_messages() {
+        let (mut test_bed, vl_id) = virtual_link_test_bed();
+
+        let message_sent: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("testval").into();
+        test_bed.command(|a| a.send_virtual_link_message(&vl_id, message_sent.clone()));
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        let message = test_bed.query(|a| a.virtual_link(&vl_id).recv_value());
+        assert_eq!(message, Some(message_sent));
+        assert!(test_bed.query(|a| a.virtual_link(&vl_id).is_fresh()));
+    }
+
+    #[test]
+    fn virtual_link_latency_adds_up_per_hop() {
+        let (test_bed, vl_id) = virtual_link_test_bed();
+
+        // 1538 bytes at 100 Mbit/s take 123.04 µs per link, each switch adds 100 µs.
+        let latency = test_bed
+            .query(|a| a.virtual_link(&vl_id).latency(AfdxNetwork::A))
+            .unwrap();
+        assert_about_eq!(latency.as_secs_f64(), 4. * 123.04e-6 + 3. * 100e-6, 1e-9);
+    }
+
+    #[test]
+    fn virtual_link_is_redundant_over_both_networks() {
+        let (mut test_bed, vl_id) = virtual_link_test_bed();
+
+        test_bed.write_by_name("AFDX_SWITCH_3_FAILURE", true);
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        let message_sent: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("testval").into();
+        test_bed.command(|a| a.send_virtual_link_message(&vl_id, message_sent.clone()));
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        let message = test_bed.query(|a| a.virtual_link(&vl_id).recv_value());
+        assert_eq!(message, Some(message_sent));
+        assert_eq!(
+            test_bed.query(|a| a.virtual_link(&vl_id).frames_lost(AfdxNetwork::A)),
+            1
+        );
+        assert_eq!(
+            test_bed.query(|a| a.virtual_link(&vl_id).frames_lost(AfdxNetwork::B)),
+            0
+        );
+    }
+
+    #[test]
+    fn virtual_link_data_becomes_stale_when_both_networks_fail() {
+        let (mut test_bed, vl_id) = virtual_link_test_bed();
+
+        let message_sent: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("testval").into();
+        test_bed.command(|a| a.send_virtual_link_message(&vl_id, message_sent.clone()));
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        test_bed.write_by_name("AFDX_SWITCH_4_FAILURE", true);
+        test_bed.write_by_name("AFDX_SWITCH_14_FAILURE", true);
+        for _ in 0..20 {
+            test_bed.command(|a| a.send_virtual_link_message(&vl_id, message_sent.clone()));
+            test_bed.run_with_delta(Duration::from_millis(50));
+        }
+
+        assert!(!test_bed.query(|a| a.virtual_link(&vl_id).is_fresh()));
+        let message = test_bed
+            .query(|a| a.virtual_link(&vl_id).recv_value())
+            .unwrap();
+        assert!(message.is_no_data());
+    }
+
+    #[test]
+    fn virtual_link_transmits_at_most_once_per_bag() {
+        let (mut test_bed, vl_id) = virtual_link_test_bed();
+
+        let message1: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("message1").into();
+        let message2: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("message2").into();
+        test_bed.command(|a| a.send_virtual_link_message(&vl_id, message1.clone()));
+        test_bed.run_with_delta(Duration::from_millis(10));
+        test_bed.command(|a| a.send_virtual_link_message(&vl_id, message2.clone()));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let message = test_bed.query(|a| a.virtual_link(&vl_id).recv_value());
+        assert_eq!(message, Some(message1));
+
+        test_bed.run_with_delta(Duration::from_millis(20));
+
+        let message = test_bed.query(|a| a.virtual_link(&vl_id).recv_value());
+        assert_eq!(message, Some(message2));
+    }
+
+    #[test]
+    fn virtual_link_frame_is_lost_when_switch_fails_mid_transfer() {
+        let (mut test_bed, vl_id) = virtual_link_test_bed();
+
+        test_bed.write_by_name("AFDX_SWITCH_14_FAILURE", true);
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        let message_sent: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("testval").into();
+        test_bed.command(|a| a.send_virtual_link_message(&vl_id, message_sent.clone()));
+        test_bed.run_with_delta(Duration::from_micros(100));
+        test_bed.write_by_name("AFDX_SWITCH_4_FAILURE", true);
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        let message = test_bed.query(|a| a.virtual_link(&vl_id).recv_value());
+        assert_eq!(message, None);
+        assert_eq!(
+            test_bed.query(|a| a.virtual_link(&vl_id).frames_lost(AfdxNetwork::A)),
+            1
+        );
+    }
+
+    #[test]
+    fn virtual_link_rejects_out_of_sequence_frames_after_loss() {
+        let (mut test_bed, vl_id) = virtual_link_test_bed();
+
+        let message_sent: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("testval").into();
+        test_bed.command(|a| a.send_virtual_link_message(&vl_id, message_sent.clone()));
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        test_bed.write_by_name("AFDX_SWITCH_4_FAILURE", true);
+        for _ in 0..3 {
+            test_bed.command(|a| a.send_virtual_link_message(&vl_id, message_sent.clone()));
+            test_bed.run_with_delta(Duration::from_millis(50));
+        }
+
+        test_bed.write_by_name("AFDX_SWITCH_4_FAILURE", false);
+        test_bed.command(|a| a.send_virtual_link_message(&vl_id, message_sent.clone()));
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        assert_eq!(
+            test_bed.query(|a| a.virtual_link(&vl_id).frames_rejected(AfdxNetwork::A)),
+            1
+        );
+        assert_eq!(
+            test_bed.query(|a| a.virtual_link(&vl_id).frames_rejected(AfdxNetwork::B)),
+            0
+        );
+        assert!(test_bed.query(|a| a.virtual_link(&vl_id).is_fresh()));
+    }
+
+    #[test]
+    fn built_in_topology_contains_required_elements() {
+        let built_in = AvionicsDataCommunicationNetworkTopology::from_toml(TOPOLOGY_TOML).unwrap();
+
+        assert_eq!(
+            A380AvionicsDataCommunicationNetwork::check_required_elements(&built_in, &built_in),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn topology_without_required_cpiom_is_rejected() {
+        let built_in = AvionicsDataCommunicationNetworkTopology::from_toml(TOPOLOGY_TOML).unwrap();
+        let topology = AvionicsDataCommunicationNetworkTopology::from_toml(
+            &TOPOLOGY_TOML.replace("[[cpiom]]\nname = \"F1\"", "[[iom]]\nname = \"F1\""),
+        )
+        .unwrap();
+
+        assert_eq!(
+            A380AvionicsDataCommunicationNetwork::check_required_elements(&topology, &built_in),
+            Err("CPIOM F1 is missing".to_owned())
+        );
+    }
+
+    #[test]
+    fn topology_without_required_virtual_link_is_rejected() {
+        let built_in = AvionicsDataCommunicationNetworkTopology::from_toml(TOPOLOGY_TOML).unwrap();
+        let topology = AvionicsDataCommunicationNetworkTopology::from_toml(
+            &TOPOLOGY_TOML.replace("name = \"FQMS_1_TO_FWS_1\"", "name = \"FQMS_1_TO_FWS_3\""),
+        )
+        .unwrap();
+
+        assert_eq!(
+            A380AvionicsDataCommunicationNetwork::check_required_elements(&topology, &built_in),
+            Err("Virtual link FQMS_1_TO_FWS_1 is missing".to_owned())
+        );
+    }
+
+    #[test]
+    fn topology_without_required_virtual_link_path_is_rejected() {
+        let built_in = AvionicsDataCommunicationNetworkTopology::from_toml(TOPOLOGY_TOML).unwrap();
+        let topology = AvionicsDataCommunicationNetworkTopology::from_toml(
+            &TOPOLOGY_TOML.replace("network_b_path = [16, 14]\n", ""),
+        )
+        .unwrap();
+
+        assert_eq!(
+            A380AvionicsDataCommunicationNetwork::check_required_elements(&topology, &built_in),
+            Err("Virtual link FQMS_2_TO_FWS_2 isn't routed on network B".to_owned())
+        );
+    }
+
+    #[test]
+    fn topology_virtual_links_deliver_messages() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.run();
+
+        for name in ["FQMS_1_TO_FWS_1", "FQMS_2_TO_FWS_2"] {
+            let mut vl_id = Default::default();
+            test_bed.command(|a| vl_id = a.get_message_identifier(name.to_owned()));
+
+            let message_sent: AvionicsDataCommunicationNetworkMessage<_> =
+                A380AvionicsDataCommunicationNetworkMessageData::TestValue("testval").into();
+            test_bed.command(|a| a.send_virtual_link_message(&vl_id, message_sent.clone()));
+            test_bed.run_with_delta(Duration::from_millis(100));
+
+            let message = test_bed.query(|a| a.virtual_link(&vl_id).recv_value());
+            assert_eq!(message, Some(message_sent));
+        }
+    }
+
+    fn send_fqms_fuel_data(
+        test_bed: &mut SimulationTestBed<AdcnTestAircraft>,
+        vl_id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+    ) {
+        let data = FqmsFuelData {
+            total_fuel_on_board: Some(120000),
+            center_of_gravity_mac: Some(385),
+            status_word: 1 << 11,
+        };
+        test_bed.command(|a| {
+            a.send_virtual_link_message(
+                vl_id,
+                A380AvionicsDataCommunicationNetworkMessageData::FqmsFuelData(data).into(),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_millis(100));
+    }
+
+    #[test]
+    fn fws_receives_fqms_fuel_data_over_virtual_link() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.run();
+
+        let mut vl_id = Default::default();
+        test_bed.command(|a| vl_id = a.get_message_identifier("FQMS_1_TO_FWS_1".to_owned()));
+        send_fqms_fuel_data(&mut test_bed, &vl_id);
+
+        let fob: Arinc429Word<f64> =
+            test_bed.read_arinc429_by_name("FWS_1_FQMS_TOTAL_FUEL_ON_BOARD");
+        assert!(fob.is_normal_operation());
+        assert_about_eq!(fob.value(), 120000.);
+        let cg: Arinc429Word<f64> =
+            test_bed.read_arinc429_by_name("FWS_1_FQMS_CENTER_OF_GRAVITY_MAC");
+        assert_about_eq!(cg.value(), 38.5, 1e-4);
+        let status_word: Arinc429Word<u32> =
+            test_bed.read_arinc429_by_name("FWS_1_FQMS_STATUS_WORD");
+        assert!(status_word.get_bit(12));
+
+        let other_side_fob: Arinc429Word<f64> =
+            test_bed.read_arinc429_by_name("FWS_2_FQMS_TOTAL_FUEL_ON_BOARD");
+        assert_eq!(other_side_fob.ssm(), SignStatus::FailureWarning);
+    }
+
+    #[test]
+    fn fws_fqms_fuel_data_remains_valid_when_one_network_fails() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.run();
+
+        let mut vl_id = Default::default();
+        test_bed.command(|a| vl_id = a.get_message_identifier("FQMS_1_TO_FWS_1".to_owned()));
+        test_bed.write_by_name("AFDX_SWITCH_3_FAILURE", true);
+        for _ in 0..10 {
+            send_fqms_fuel_data(&mut test_bed, &vl_id);
+        }
+
+        let fob: Arinc429Word<f64> =
+            test_bed.read_arinc429_by_name("FWS_1_FQMS_TOTAL_FUEL_ON_BOARD");
+        assert!(fob.is_normal_operation());
+    }
+
+    #[test]
+    fn fws_fqms_fuel_data_becomes_invalid_when_it_is_no_longer_fresh() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.run();
+
+        let mut vl_id = Default::default();
+        test_bed.command(|a| vl_id = a.get_message_identifier("FQMS_1_TO_FWS_1".to_owned()));
+        send_fqms_fuel_data(&mut test_bed, &vl_id);
+
+        test_bed.write_by_name("AFDX_SWITCH_3_FAILURE", true);
+        test_bed.write_by_name("AFDX_SWITCH_13_FAILURE", true);
+        for _ in 0..10 {
+            send_fqms_fuel_data(&mut test_bed, &vl_id);
+        }
+
+        let fob: Arinc429Word<f64> =
+            test_bed.read_arinc429_by_name("FWS_1_FQMS_TOTAL_FUEL_ON_BOARD");
+        assert_eq!(fob.ssm(), SignStatus::FailureWarning);
+    }
+
+    #[test]
+    #[should_panic]
+    fn virtual_link_path_must_consist_of_connected_switches() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| {
+            a.add_virtual_link(
+                VirtualLinkConfiguration::new(Duration::from_millis(32), 1518),
+                &[1, 4],
+                &[],
+            );
+        });
+    }
 }
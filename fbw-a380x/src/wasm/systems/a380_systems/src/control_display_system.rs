@@ -1,80 +1,84 @@
 use crate::systems::{
     accept_iterable,
-    indicating_recording::controls::keyboard_cursor_control_unit::KeyboardCursorControlUnit,
+    indicating_recording::{
+        controls::keyboard_cursor_control_unit::KeyboardCursorControlUnit,
+        display_unit::{DisplayUnit, DisplayUnitFormat},
+    },
     shared::{can_bus::CanBus, ElectricalBusType},
-    simulation::{InitContext, SimulationElement, SimulationElementVisitor},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        VariableIdentifier,
+    },
 };
 
-// they are not the same values as the real IDs
-// PFD and ND are missing
+// The function IDs identify the stations on the CDS CAN buses within the simulation only. The
+// manufacturer's identifiers aren't modelled, as their assignment isn't publicly documented.
 enum CanBusFunctionIds {
     KccuKeyboard = 42,
     KccuCursorControl = 43,
-    // PrimaryFlightDisplay = 44,
-    // NavigationDisplay = 45,
+    PrimaryFlightDisplay = 44,
+    NavigationDisplay = 45,
     MultiFunctionDisplay = 46,
     EngineWarningDisplay = 47,
     SystemDisplay = 48,
 }
 
+const CAN_BUS_FUNCTION_IDS: [u8; 7] = [
+    CanBusFunctionIds::KccuKeyboard as u8,
+    CanBusFunctionIds::KccuCursorControl as u8,
+    CanBusFunctionIds::PrimaryFlightDisplay as u8,
+    CanBusFunctionIds::NavigationDisplay as u8,
+    CanBusFunctionIds::MultiFunctionDisplay as u8,
+    CanBusFunctionIds::EngineWarningDisplay as u8,
+    CanBusFunctionIds::SystemDisplay as u8,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum EcamNdTransferPosition {
+    Captain,
+    Norm,
+    FirstOfficer,
+}
+impl From<f64> for EcamNdTransferPosition {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => EcamNdTransferPosition::Captain,
+            2 => EcamNdTransferPosition::FirstOfficer,
+            _ => EcamNdTransferPosition::Norm,
+        }
+    }
+}
+
 // implements the system topology of the Control and Display System (CDS)
 pub struct A380ControlDisplaySystem {
-    can_bus_1: [CanBus<5>; 2],
-    can_bus_2: [CanBus<5>; 2],
+    can_bus_1: [CanBus<7>; 2],
+    can_bus_2: [CanBus<7>; 2],
     kccu_capt: KeyboardCursorControlUnit,
     kccu_fo: KeyboardCursorControlUnit,
+    capt_pfd: DisplayUnit,
+    capt_nd: DisplayUnit,
+    capt_mfd: DisplayUnit,
+    fo_pfd: DisplayUnit,
+    fo_nd: DisplayUnit,
+    fo_mfd: DisplayUnit,
+    ewd: DisplayUnit,
+    sd: DisplayUnit,
+    pfd_nd_xfr_ids: [VariableIdentifier; 2],
+    pfd_nd_xfr: [bool; 2],
+    ecam_nd_xfr_id: VariableIdentifier,
+    ecam_nd_xfr: EcamNdTransferPosition,
 }
 
 impl A380ControlDisplaySystem {
     pub fn new(context: &mut InitContext) -> Self {
         Self {
             can_bus_1: [
-                CanBus::new(
-                    context,
-                    "CDS_CAN_BUS_1_1",
-                    [
-                        CanBusFunctionIds::KccuKeyboard as u8,
-                        CanBusFunctionIds::KccuCursorControl as u8,
-                        CanBusFunctionIds::MultiFunctionDisplay as u8,
-                        CanBusFunctionIds::EngineWarningDisplay as u8,
-                        CanBusFunctionIds::SystemDisplay as u8,
-                    ],
-                ),
-                CanBus::new(
-                    context,
-                    "CDS_CAN_BUS_1_2",
-                    [
-                        CanBusFunctionIds::KccuKeyboard as u8,
-                        CanBusFunctionIds::KccuCursorControl as u8,
-                        CanBusFunctionIds::MultiFunctionDisplay as u8,
-                        CanBusFunctionIds::EngineWarningDisplay as u8,
-                        CanBusFunctionIds::SystemDisplay as u8,
-                    ],
-                ),
+                CanBus::new(context, "CDS_CAN_BUS_1_1", CAN_BUS_FUNCTION_IDS),
+                CanBus::new(context, "CDS_CAN_BUS_1_2", CAN_BUS_FUNCTION_IDS),
             ],
             can_bus_2: [
-                CanBus::new(
-                    context,
-                    "CDS_CAN_BUS_2_1",
-                    [
-                        CanBusFunctionIds::KccuKeyboard as u8,
-                        CanBusFunctionIds::KccuCursorControl as u8,
-                        CanBusFunctionIds::MultiFunctionDisplay as u8,
-                        CanBusFunctionIds::EngineWarningDisplay as u8,
-                        CanBusFunctionIds::SystemDisplay as u8,
-                    ],
-                ),
-                CanBus::new(
-                    context,
-                    "CDS_CAN_BUS_2_2",
-                    [
-                        CanBusFunctionIds::KccuKeyboard as u8,
-                        CanBusFunctionIds::KccuCursorControl as u8,
-                        CanBusFunctionIds::MultiFunctionDisplay as u8,
-                        CanBusFunctionIds::EngineWarningDisplay as u8,
-                        CanBusFunctionIds::SystemDisplay as u8,
-                    ],
-                ),
+                CanBus::new(context, "CDS_CAN_BUS_2_1", CAN_BUS_FUNCTION_IDS),
+                CanBus::new(context, "CDS_CAN_BUS_2_2", CAN_BUS_FUNCTION_IDS),
             ],
             kccu_capt: KeyboardCursorControlUnit::new(
                 context,
@@ -94,6 +98,85 @@ impl A380ControlDisplaySystem {
                 ElectricalBusType::DirectCurrent(1),
                 ElectricalBusType::DirectCurrent(2),
             ),
+            // 108PH, 400PP as fallback
+            capt_pfd: DisplayUnit::new(
+                context,
+                "CAPT_PFD",
+                1,
+                CanBusFunctionIds::PrimaryFlightDisplay as u8,
+                ElectricalBusType::DirectCurrentNamed("108PH"),
+                ElectricalBusType::DirectCurrentEssential,
+            ),
+            // 108PH, 100PP as fallback
+            capt_nd: DisplayUnit::new(
+                context,
+                "CAPT_ND",
+                2,
+                CanBusFunctionIds::NavigationDisplay as u8,
+                ElectricalBusType::DirectCurrentNamed("108PH"),
+                ElectricalBusType::DirectCurrent(1),
+            ),
+            // 400PP, 100PP as fallback
+            capt_mfd: DisplayUnit::new(
+                context,
+                "CAPT_MFD",
+                3,
+                CanBusFunctionIds::MultiFunctionDisplay as u8,
+                ElectricalBusType::DirectCurrentEssential,
+                ElectricalBusType::DirectCurrent(1),
+            ),
+            // 200PP, 100PP as fallback
+            fo_pfd: DisplayUnit::new(
+                context,
+                "FO_PFD",
+                4,
+                CanBusFunctionIds::PrimaryFlightDisplay as u8,
+                ElectricalBusType::DirectCurrent(2),
+                ElectricalBusType::DirectCurrent(1),
+            ),
+            // 100PP, 200PP as fallback
+            fo_nd: DisplayUnit::new(
+                context,
+                "FO_ND",
+                5,
+                CanBusFunctionIds::NavigationDisplay as u8,
+                ElectricalBusType::DirectCurrent(1),
+                ElectricalBusType::DirectCurrent(2),
+            ),
+            // 100PP, 200PP as fallback
+            fo_mfd: DisplayUnit::new(
+                context,
+                "FO_MFD",
+                6,
+                CanBusFunctionIds::MultiFunctionDisplay as u8,
+                ElectricalBusType::DirectCurrent(1),
+                ElectricalBusType::DirectCurrent(2),
+            ),
+            // 400PP, 100PP as fallback
+            ewd: DisplayUnit::new(
+                context,
+                "EWD",
+                7,
+                CanBusFunctionIds::EngineWarningDisplay as u8,
+                ElectricalBusType::DirectCurrentEssential,
+                ElectricalBusType::DirectCurrent(1),
+            ),
+            // 200PP, 100PP as fallback
+            sd: DisplayUnit::new(
+                context,
+                "SD",
+                8,
+                CanBusFunctionIds::SystemDisplay as u8,
+                ElectricalBusType::DirectCurrent(2),
+                ElectricalBusType::DirectCurrent(1),
+            ),
+            pfd_nd_xfr_ids: [
+                context.get_identifier("CDS_PFD_ND_XFR_L".to_owned()),
+                context.get_identifier("CDS_PFD_ND_XFR_R".to_owned()),
+            ],
+            pfd_nd_xfr: [false; 2],
+            ecam_nd_xfr_id: context.get_identifier("ECAM_ND_XFR_SWITCHING_KNOB".to_owned()),
+            ecam_nd_xfr: EcamNdTransferPosition::Norm,
         }
     }
 
@@ -101,6 +184,9 @@ impl A380ControlDisplaySystem {
         self.kccu_capt.update(&mut self.can_bus_1);
         self.kccu_fo.update(&mut self.can_bus_2);
 
+        self.update_display_units();
+        self.reconfigure();
+
         self.can_bus_1.iter_mut().for_each(|bus| {
             bus.update();
         });
@@ -108,6 +194,85 @@ impl A380ControlDisplaySystem {
             bus.update();
         });
     }
+
+    fn update_display_units(&mut self) {
+        self.capt_pfd.update();
+        self.capt_nd.update();
+        self.capt_mfd.update();
+        self.fo_pfd.update();
+        self.fo_nd.update();
+        self.fo_mfd.update();
+        self.ewd.update();
+        self.sd.update();
+
+        for bus in self.can_bus_1.iter_mut() {
+            self.capt_pfd.consume_messages(bus);
+            self.capt_nd.consume_messages(bus);
+            self.capt_mfd.consume_messages(bus);
+        }
+        for bus in self.can_bus_2.iter_mut() {
+            self.fo_pfd.consume_messages(bus);
+            self.fo_nd.consume_messages(bus);
+            self.fo_mfd.consume_messages(bus);
+        }
+        for bus in self.can_bus_1.iter_mut().chain(self.can_bus_2.iter_mut()) {
+            self.ewd.consume_messages(bus);
+            self.sd.consume_messages(bus);
+        }
+    }
+
+    fn reconfigure(&mut self) {
+        // the EWD has priority and is transferred automatically to the SD display unit
+        self.ewd.show(DisplayUnitFormat::EngineWarningDisplay);
+        self.sd.show(if self.ewd.is_operative() {
+            DisplayUnitFormat::SystemDisplay
+        } else {
+            DisplayUnitFormat::EngineWarningDisplay
+        });
+
+        // the ECAM/ND XFR knob transfers the SD onto the ND of the selected side
+        Self::reconfigure_side(
+            &mut self.capt_pfd,
+            &mut self.capt_nd,
+            self.pfd_nd_xfr[0],
+            self.ecam_nd_xfr == EcamNdTransferPosition::Captain,
+        );
+        Self::reconfigure_side(
+            &mut self.fo_pfd,
+            &mut self.fo_nd,
+            self.pfd_nd_xfr[1],
+            self.ecam_nd_xfr == EcamNdTransferPosition::FirstOfficer,
+        );
+
+        self.capt_mfd.show(DisplayUnitFormat::MultiFunctionDisplay);
+        self.fo_mfd.show(DisplayUnitFormat::MultiFunctionDisplay);
+    }
+
+    fn reconfigure_side(
+        pfd_du: &mut DisplayUnit,
+        nd_du: &mut DisplayUnit,
+        pfd_nd_transferred: bool,
+        system_display_on_nd: bool,
+    ) {
+        let (pfd_target, nd_target) = if pfd_nd_transferred {
+            (nd_du, pfd_du)
+        } else {
+            (pfd_du, nd_du)
+        };
+
+        if !pfd_target.is_operative() && nd_target.is_operative() {
+            // automatic PFD transfer, the PFD has priority over the ND and the SD
+            pfd_target.show(DisplayUnitFormat::None);
+            nd_target.show(DisplayUnitFormat::PrimaryFlightDisplay);
+        } else {
+            pfd_target.show(DisplayUnitFormat::PrimaryFlightDisplay);
+            nd_target.show(if system_display_on_nd {
+                DisplayUnitFormat::SystemDisplay
+            } else {
+                DisplayUnitFormat::NavigationDisplay
+            });
+        }
+    }
 }
 
 impl SimulationElement for A380ControlDisplaySystem {
@@ -116,6 +281,317 @@ impl SimulationElement for A380ControlDisplaySystem {
         accept_iterable!(self.can_bus_2, visitor);
         self.kccu_capt.accept(visitor);
         self.kccu_fo.accept(visitor);
+        self.capt_pfd.accept(visitor);
+        self.capt_nd.accept(visitor);
+        self.capt_mfd.accept(visitor);
+        self.fo_pfd.accept(visitor);
+        self.fo_nd.accept(visitor);
+        self.fo_mfd.accept(visitor);
+        self.ewd.accept(visitor);
+        self.sd.accept(visitor);
         visitor.visit(self);
     }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.pfd_nd_xfr[0] = reader.read(&self.pfd_nd_xfr_ids[0]);
+        self.pfd_nd_xfr[1] = reader.read(&self.pfd_nd_xfr_ids[1]);
+
+        let knob: f64 = reader.read(&self.ecam_nd_xfr_id);
+        self.ecam_nd_xfr = knob.into();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use systems::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        failures::FailureType,
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft, UpdateContext,
+        },
+    };
+    use uom::si::{electric_potential::volt, f64::*};
+
+    struct TestAircraft {
+        cds: A380ControlDisplaySystem,
+        powered_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        dc_2_bus: ElectricalBus,
+        dc_ess_bus: ElectricalBus,
+        dc_ess_in_flight_bus: ElectricalBus,
+        unpowered_bus: Option<ElectricalBusType>,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                cds: A380ControlDisplaySystem::new(context),
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                dc_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+                dc_ess_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
+                dc_ess_in_flight_bus: ElectricalBus::new(
+                    context,
+                    ElectricalBusType::DirectCurrentNamed("108PH"),
+                ),
+                unpowered_bus: None,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.powered_source
+                .power_with_potential(ElectricPotential::new::<volt>(28.));
+            electricity.supplied_by(&self.powered_source);
+            for (bus, bus_type) in [
+                (&self.dc_1_bus, ElectricalBusType::DirectCurrent(1)),
+                (&self.dc_2_bus, ElectricalBusType::DirectCurrent(2)),
+                (&self.dc_ess_bus, ElectricalBusType::DirectCurrentEssential),
+                (
+                    &self.dc_ess_in_flight_bus,
+                    ElectricalBusType::DirectCurrentNamed("108PH"),
+                ),
+            ] {
+                if self.unpowered_bus != Some(bus_type) {
+                    electricity.flow(&self.powered_source, bus);
+                }
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
+            self.cds.update();
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.cds.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    struct CdsTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl CdsTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.write_by_name("ECAM_ND_XFR_SWITCHING_KNOB", 1);
+            test_bed
+        }
+
+        fn and_run(mut self) -> Self {
+            self.run();
+            self
+        }
+
+        fn pfd_nd_xfr(mut self, side: &str) -> Self {
+            self.write_by_name(&format!("CDS_PFD_ND_XFR_{}", side), true);
+            self
+        }
+
+        fn ecam_nd_xfr(mut self, position: u8) -> Self {
+            self.write_by_name("ECAM_ND_XFR_SWITCHING_KNOB", position);
+            self
+        }
+
+        fn unpowered_bus(mut self, bus: ElectricalBusType) -> Self {
+            self.command(|a| a.unpowered_bus = Some(bus));
+            self
+        }
+
+        fn failed_display_unit(mut self, number: usize) -> Self {
+            self.fail(FailureType::DisplayUnit(number));
+            self
+        }
+
+        fn format(&mut self, name: &str) -> DisplayUnitFormat {
+            self.read_by_name(&format!("CDS_DU_{}_FORMAT", name))
+        }
+
+        fn is_available(&mut self, name: &str) -> bool {
+            self.read_by_name(&format!("CDS_DU_{}_AVAIL", name))
+        }
+    }
+    impl TestBed for CdsTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> CdsTestBed {
+        CdsTestBed::new()
+    }
+
+    #[test]
+    fn normal_configuration() {
+        let mut test_bed = test_bed().and_run();
+
+        for name in [
+            "CAPT_PFD", "CAPT_ND", "CAPT_MFD", "FO_PFD", "FO_ND", "FO_MFD", "EWD", "SD",
+        ] {
+            assert!(test_bed.is_available(name));
+        }
+
+        assert_eq!(
+            test_bed.format("CAPT_PFD"),
+            DisplayUnitFormat::PrimaryFlightDisplay
+        );
+        assert_eq!(
+            test_bed.format("CAPT_ND"),
+            DisplayUnitFormat::NavigationDisplay
+        );
+        assert_eq!(
+            test_bed.format("CAPT_MFD"),
+            DisplayUnitFormat::MultiFunctionDisplay
+        );
+        assert_eq!(
+            test_bed.format("FO_PFD"),
+            DisplayUnitFormat::PrimaryFlightDisplay
+        );
+        assert_eq!(
+            test_bed.format("FO_ND"),
+            DisplayUnitFormat::NavigationDisplay
+        );
+        assert_eq!(
+            test_bed.format("FO_MFD"),
+            DisplayUnitFormat::MultiFunctionDisplay
+        );
+        assert_eq!(
+            test_bed.format("EWD"),
+            DisplayUnitFormat::EngineWarningDisplay
+        );
+        assert_eq!(test_bed.format("SD"), DisplayUnitFormat::SystemDisplay);
+    }
+
+    #[test]
+    fn display_units_stay_available_after_loss_of_a_single_bus() {
+        for bus in [
+            ElectricalBusType::DirectCurrent(1),
+            ElectricalBusType::DirectCurrent(2),
+            ElectricalBusType::DirectCurrentEssential,
+            ElectricalBusType::DirectCurrentNamed("108PH"),
+        ] {
+            let mut test_bed = test_bed().unpowered_bus(bus).and_run().and_run();
+
+            for name in [
+                "CAPT_PFD", "CAPT_ND", "CAPT_MFD", "FO_PFD", "FO_ND", "FO_MFD", "EWD", "SD",
+            ] {
+                assert!(test_bed.is_available(name));
+            }
+        }
+    }
+
+    #[test]
+    fn pfd_nd_xfr_swaps_pfd_and_nd() {
+        let mut test_bed = test_bed().pfd_nd_xfr("L").and_run();
+
+        assert_eq!(
+            test_bed.format("CAPT_PFD"),
+            DisplayUnitFormat::NavigationDisplay
+        );
+        assert_eq!(
+            test_bed.format("CAPT_ND"),
+            DisplayUnitFormat::PrimaryFlightDisplay
+        );
+        assert_eq!(
+            test_bed.format("FO_PFD"),
+            DisplayUnitFormat::PrimaryFlightDisplay
+        );
+    }
+
+    #[test]
+    fn pfd_is_transferred_automatically_on_pfd_display_unit_failure() {
+        let mut test_bed = test_bed().failed_display_unit(4).and_run();
+
+        assert!(!test_bed.is_available("FO_PFD"));
+        assert_eq!(test_bed.format("FO_PFD"), DisplayUnitFormat::None);
+        assert_eq!(
+            test_bed.format("FO_ND"),
+            DisplayUnitFormat::PrimaryFlightDisplay
+        );
+    }
+
+    #[test]
+    fn pfd_stays_on_nd_display_unit_when_pfd_nd_xfr_is_pressed_after_failure() {
+        let mut test_bed = test_bed()
+            .failed_display_unit(1)
+            .and_run()
+            .pfd_nd_xfr("L")
+            .and_run();
+
+        assert_eq!(test_bed.format("CAPT_PFD"), DisplayUnitFormat::None);
+        assert_eq!(
+            test_bed.format("CAPT_ND"),
+            DisplayUnitFormat::PrimaryFlightDisplay
+        );
+    }
+
+    #[test]
+    fn nd_failure_does_not_affect_pfd() {
+        let mut test_bed = test_bed().failed_display_unit(2).and_run();
+
+        assert_eq!(
+            test_bed.format("CAPT_PFD"),
+            DisplayUnitFormat::PrimaryFlightDisplay
+        );
+        assert_eq!(test_bed.format("CAPT_ND"), DisplayUnitFormat::None);
+    }
+
+    #[test]
+    fn ewd_is_transferred_to_sd_display_unit_on_failure() {
+        let mut test_bed = test_bed().failed_display_unit(7).and_run();
+
+        assert_eq!(test_bed.format("EWD"), DisplayUnitFormat::None);
+        assert_eq!(
+            test_bed.format("SD"),
+            DisplayUnitFormat::EngineWarningDisplay
+        );
+    }
+
+    #[test]
+    fn ecam_nd_xfr_shows_sd_on_selected_nd() {
+        let mut test_bed = test_bed().ecam_nd_xfr(0).and_run();
+
+        assert_eq!(test_bed.format("CAPT_ND"), DisplayUnitFormat::SystemDisplay);
+        assert_eq!(
+            test_bed.format("FO_ND"),
+            DisplayUnitFormat::NavigationDisplay
+        );
+
+        test_bed = test_bed.ecam_nd_xfr(2).and_run();
+
+        assert_eq!(
+            test_bed.format("CAPT_ND"),
+            DisplayUnitFormat::NavigationDisplay
+        );
+        assert_eq!(test_bed.format("FO_ND"), DisplayUnitFormat::SystemDisplay);
+    }
+
+    #[test]
+    fn sd_recovered_on_nd_after_ecam_display_unit_failure() {
+        let mut test_bed = test_bed().failed_display_unit(7).ecam_nd_xfr(2).and_run();
+
+        assert_eq!(
+            test_bed.format("SD"),
+            DisplayUnitFormat::EngineWarningDisplay
+        );
+        assert_eq!(test_bed.format("FO_ND"), DisplayUnitFormat::SystemDisplay);
+    }
 }
@@ -0,0 +1,277 @@
+use systems::{
+    accept_iterable,
+    air_conditioning::CabinPressure,
+    doors::{Door, DoorType, EscapeSlide},
+    shared::{CargoDoorLocked, CargoDoorOpenAmount},
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+use uom::si::f64::*;
+
+pub struct A380Doors {
+    passenger_doors: [Door; 16],
+    forward_cargo_door: Door,
+    aft_cargo_door: Door,
+}
+impl A380Doors {
+    // Door id and the interactive point opening the door in the simulator.
+    pub const PASSENGER_DOORS: [(&'static str, usize); 16] = [
+        ("M1L", 0),
+        ("M1R", 1),
+        ("M2L", 2),
+        ("M2R", 3),
+        ("M3L", 4),
+        ("M3R", 5),
+        ("M4L", 6),
+        ("M4R", 7),
+        ("M5L", 8),
+        ("M5R", 9),
+        ("U1L", 10),
+        ("U1R", 11),
+        ("U2L", 12),
+        ("U2R", 13),
+        ("U3L", 14),
+        ("U3R", 15),
+    ];
+
+    // Doors served by the boarding agents: M1L, M2L, M4L, M5L and U1L.
+    const BOARDING_DOORS: [usize; 5] = [0, 2, 6, 8, 10];
+
+    pub fn new(context: &mut InitContext) -> Self {
+        // The cabin crew arms and disarms the slides of each deck.
+        let main_deck_slides_armed_id = context.get_identifier("SLIDES_ARMED_MAIN_DECK".to_owned());
+        let upper_deck_slides_armed_id =
+            context.get_identifier("SLIDES_ARMED_UPPER_DECK".to_owned());
+
+        Self {
+            passenger_doors: Self::PASSENGER_DOORS.map(|(id, interactive_point)| {
+                let open_request_id =
+                    context.get_identifier(format!("INTERACTIVE POINT OPEN:{}", interactive_point));
+                let slides_armed_id = if id.starts_with('U') {
+                    upper_deck_slides_armed_id
+                } else {
+                    main_deck_slides_armed_id
+                };
+                Door::new(
+                    context,
+                    id,
+                    DoorType::Passenger,
+                    Some(open_request_id),
+                    Some(EscapeSlide::new(Some(slides_armed_id))),
+                )
+            }),
+            forward_cargo_door: Door::new(context, "FWD_CARGO", DoorType::Cargo, None, None),
+            aft_cargo_door: Door::new(context, "AFT_CARGO", DoorType::Cargo, None, None),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        cabin_pressure: &impl CabinPressure,
+        cargo_doors: &(impl CargoDoorLocked + CargoDoorOpenAmount),
+    ) {
+        self.passenger_doors
+            .iter_mut()
+            .for_each(|door| door.update(context, cabin_pressure));
+
+        self.forward_cargo_door.update_actuated(
+            cargo_doors.fwd_cargo_door_open_amount(),
+            cargo_doors.fwd_cargo_door_locked(),
+        );
+        self.aft_cargo_door.update_actuated(
+            cargo_doors.aft_cargo_door_open_amount(),
+            cargo_doors.aft_cargo_door_locked(),
+        );
+    }
+
+    pub fn boarding_door_open_amounts(&self) -> [Ratio; 5] {
+        Self::BOARDING_DOORS.map(|door| self.passenger_doors[door].open_amount())
+    }
+}
+impl SimulationElement for A380Doors {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.passenger_doors, visitor);
+        self.forward_cargo_door.accept(visitor);
+        self.aft_cargo_door.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use systems::{
+        electrical::Electricity,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use uom::si::{pressure::hectopascal, ratio::percent};
+
+    struct TestCabinPressure {
+        differential_pressure: Pressure,
+    }
+    impl CabinPressure for TestCabinPressure {
+        fn exterior_pressure(&self) -> Pressure {
+            Pressure::new::<hectopascal>(1013.25)
+        }
+
+        fn cabin_pressure(&self) -> Pressure {
+            Pressure::new::<hectopascal>(1013.25) + self.differential_pressure
+        }
+    }
+
+    struct TestCargoDoors {
+        locked: bool,
+    }
+    impl CargoDoorLocked for TestCargoDoors {
+        fn fwd_cargo_door_locked(&self) -> bool {
+            self.locked
+        }
+
+        fn aft_cargo_door_locked(&self) -> bool {
+            self.locked
+        }
+    }
+    impl CargoDoorOpenAmount for TestCargoDoors {
+        fn fwd_cargo_door_open_amount(&self) -> Ratio {
+            self.open_amount()
+        }
+
+        fn aft_cargo_door_open_amount(&self) -> Ratio {
+            self.open_amount()
+        }
+    }
+    impl TestCargoDoors {
+        fn open_amount(&self) -> Ratio {
+            if self.locked {
+                Ratio::default()
+            } else {
+                Ratio::new::<percent>(100.)
+            }
+        }
+    }
+
+    struct TestAircraft {
+        doors: A380Doors,
+        cabin_pressure: TestCabinPressure,
+        cargo_doors: TestCargoDoors,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                doors: A380Doors::new(context),
+                cabin_pressure: TestCabinPressure {
+                    differential_pressure: Pressure::default(),
+                },
+                cargo_doors: TestCargoDoors { locked: true },
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            context: &UpdateContext,
+            _: &mut Electricity,
+        ) {
+            self.doors
+                .update(context, &self.cabin_pressure, &self.cargo_doors);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.doors.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    #[test]
+    fn all_doors_are_locked_at_init() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        for (id, _) in A380Doors::PASSENGER_DOORS {
+            let is_locked: bool = test_bed.read_by_name(&format!("DOOR_{}_IS_LOCKED", id));
+            assert!(is_locked);
+        }
+        for id in ["FWD_CARGO", "AFT_CARGO"] {
+            let is_locked: bool = test_bed.read_by_name(&format!("DOOR_{}_IS_LOCKED", id));
+            assert!(is_locked);
+        }
+    }
+
+    #[test]
+    fn doors_follow_interactive_points() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("INTERACTIVE POINT OPEN:13", Ratio::new::<percent>(100.));
+        test_bed.run();
+
+        let is_closed: bool = test_bed.read_by_name("DOOR_U2R_IS_CLOSED");
+        assert!(!is_closed);
+        let is_closed: bool = test_bed.read_by_name("DOOR_U2L_IS_CLOSED");
+        assert!(is_closed);
+    }
+
+    #[test]
+    fn boarding_doors_follow_interactive_points() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("INTERACTIVE POINT OPEN:10", Ratio::new::<percent>(100.));
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.doors.boarding_door_open_amounts()),
+            [
+                Ratio::default(),
+                Ratio::default(),
+                Ratio::default(),
+                Ratio::default(),
+                Ratio::new::<percent>(100.)
+            ]
+        );
+    }
+
+    #[test]
+    fn opening_door_in_pressurised_cabin_raises_residual_pressure_warning() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.cabin_pressure.differential_pressure = Pressure::new::<hectopascal>(20.)
+        });
+        test_bed.write_by_name("INTERACTIVE POINT OPEN:0", Ratio::new::<percent>(100.));
+        test_bed.run();
+
+        let residual_pressure: bool = test_bed.read_by_name("DOOR_M1L_RESIDUAL_PRESSURE");
+        assert!(residual_pressure);
+    }
+
+    #[test]
+    fn slides_are_armed_per_deck() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("SLIDES_ARMED_MAIN_DECK", true);
+        test_bed.write_by_name("SLIDES_ARMED_UPPER_DECK", false);
+        test_bed.run();
+
+        for (id, _) in A380Doors::PASSENGER_DOORS {
+            let slide_armed: bool = test_bed.read_by_name(&format!("DOOR_{}_SLIDE_ARMED", id));
+            assert_eq!(slide_armed, id.starts_with('M'));
+        }
+    }
+
+    #[test]
+    fn cargo_doors_report_mechanism_lock() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.cargo_doors.locked = false);
+        test_bed.run();
+
+        let fwd_cargo_door_locked: bool = test_bed.read_by_name("DOOR_FWD_CARGO_IS_LOCKED");
+        assert!(!fwd_cargo_door_locked);
+        let aft_cargo_door_closed: bool = test_bed.read_by_name("DOOR_AFT_CARGO_IS_CLOSED");
+        assert!(!aft_cargo_door_closed);
+    }
+}
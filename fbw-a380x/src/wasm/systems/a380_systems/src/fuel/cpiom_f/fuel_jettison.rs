@@ -0,0 +1,360 @@
+use crate::fuel::{
+    cpiom_f::FEED_TANKS, A380FuelPump, A380FuelTankType, FuelPumpStatus, SetFuelLevel,
+};
+use bitflags::bitflags;
+use std::time::Duration;
+use systems::{
+    accept_iterable,
+    fuel::FuelPayload,
+    overhead::{IndicationLight, OnOffPushButton},
+    shared::{DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+use uom::si::{
+    f64::{Mass, Ratio},
+    mass::kilogram,
+    ratio::{percent, ratio},
+};
+
+bitflags! {
+    /// Jettison states as used by the FWS for the FUEL memos and cautions.
+    #[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+    pub(crate) struct FuelJettisonFlags: u32 {
+        /// Jettison pb ARM is on
+        const ARMED = 1 << 0;
+        /// JETTISON IN PROGRESS
+        const IN_PROGRESS = 1 << 1;
+        /// JETTISON COMPLETED: the jettison target weight has been reached
+        const TARGET_REACHED = 1 << 2;
+        /// JETTISON COMPLETED: only the minimum feed tank quantity is left
+        const FEED_TANK_MINIMUM_REACHED = 1 << 3;
+        /// FUEL JETTISON FAULT
+        const FAULT = 1 << 4;
+        /// FUEL JETTISON VLV NOT CLOSED
+        const VALVE_NOT_CLOSED = 1 << 5;
+    }
+}
+
+/// Motor driven jettison valve upstream of the nozzle of one wing.
+struct JettisonValve {
+    open_percentage_id: VariableIdentifier,
+    open_amount: Ratio,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+}
+impl JettisonValve {
+    const TRAVEL_TIME: Duration = Duration::from_secs(4);
+
+    fn new(context: &mut InitContext, id: usize, powered_by: ElectricalBusType) -> Self {
+        Self {
+            open_percentage_id: context
+                .get_identifier(format!("FUEL_JETTISON_VALVE_{id}_OPEN_PERCENTAGE")),
+            open_amount: Ratio::default(),
+            powered_by,
+            is_powered: false,
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, open_commanded: bool) {
+        // The valve stays in its last position when unpowered
+        if !self.is_powered {
+            return;
+        }
+
+        let travel = context.delta_as_secs_f64() / Self::TRAVEL_TIME.as_secs_f64();
+        let open_amount = if open_commanded {
+            self.open_amount.get::<ratio>() + travel
+        } else {
+            self.open_amount.get::<ratio>() - travel
+        };
+        self.open_amount = Ratio::new::<ratio>(open_amount.clamp(0., 1.));
+    }
+
+    fn open_amount(&self) -> Ratio {
+        self.open_amount
+    }
+
+    fn is_fully_open(&self) -> bool {
+        self.open_amount.get::<ratio>() >= 1.
+    }
+
+    fn is_closed(&self) -> bool {
+        self.open_amount.get::<ratio>() <= 0.
+    }
+}
+impl SimulationElement for JettisonValve {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.open_percentage_id, self.open_amount.get::<percent>());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+/// A tank which can be emptied through the jettison galleries, with the pumps feeding them.
+struct JettisonSource {
+    tank: A380FuelTankType,
+    pumps: &'static [A380FuelPump],
+    rate_kg_per_sec: f64,
+    /// The wing whose jettison valve the fuel flows through, `None` for both wings.
+    wing: Option<usize>,
+}
+
+// Outer tank fuel is never jettisoned.
+const JETTISON_SOURCES: [JettisonSource; 9] = [
+    JettisonSource {
+        tank: A380FuelTankType::LeftInner,
+        pumps: &[A380FuelPump::LeftInnerFwd, A380FuelPump::LeftInnerAft],
+        rate_kg_per_sec: 7.,
+        wing: Some(0),
+    },
+    JettisonSource {
+        tank: A380FuelTankType::LeftMid,
+        pumps: &[A380FuelPump::LeftMidFwd, A380FuelPump::LeftMidAft],
+        rate_kg_per_sec: 6.,
+        wing: Some(0),
+    },
+    JettisonSource {
+        tank: A380FuelTankType::FeedOne,
+        pumps: &[A380FuelPump::Feed1Main, A380FuelPump::Feed1Stby],
+        rate_kg_per_sec: 5.,
+        wing: Some(0),
+    },
+    JettisonSource {
+        tank: A380FuelTankType::FeedTwo,
+        pumps: &[A380FuelPump::Feed2Main, A380FuelPump::Feed2Stby],
+        rate_kg_per_sec: 5.,
+        wing: Some(0),
+    },
+    JettisonSource {
+        tank: A380FuelTankType::RightInner,
+        pumps: &[A380FuelPump::RightInnerFwd, A380FuelPump::RightInnerAft],
+        rate_kg_per_sec: 7.,
+        wing: Some(1),
+    },
+    JettisonSource {
+        tank: A380FuelTankType::RightMid,
+        pumps: &[A380FuelPump::RightMidFwd, A380FuelPump::RightMidAft],
+        rate_kg_per_sec: 6.,
+        wing: Some(1),
+    },
+    JettisonSource {
+        tank: A380FuelTankType::FeedThree,
+        pumps: &[A380FuelPump::Feed3Main, A380FuelPump::Feed3Stby],
+        rate_kg_per_sec: 5.,
+        wing: Some(1),
+    },
+    JettisonSource {
+        tank: A380FuelTankType::FeedFour,
+        pumps: &[A380FuelPump::Feed4Main, A380FuelPump::Feed4Stby],
+        rate_kg_per_sec: 5.,
+        wing: Some(1),
+    },
+    JettisonSource {
+        tank: A380FuelTankType::Trim,
+        pumps: &[A380FuelPump::TrimLeft, A380FuelPump::TrimRight],
+        rate_kg_per_sec: 6.,
+        wing: None,
+    },
+];
+
+/// # Fuel jettison application of the FQMS
+///
+/// With the ARM and ACTIVE pbs on, the jettison valves open and the inner, mid, trim and feed
+/// tanks are emptied through the wing nozzles. The jettison stops automatically at the FMS
+/// jettison target weight, or once only the minimum feed tank quantity is left.
+pub(super) struct FuelJettisonApplication {
+    arm_pb: OnOffPushButton,
+    active_pb: OnOffPushButton,
+    open_light: IndicationLight,
+    valves: [JettisonValve; 2],
+
+    target_reached: bool,
+    feed_tank_minimum_reached: bool,
+    in_progress: bool,
+    valve_not_open: DelayedTrueLogicGate,
+    valve_not_closed: DelayedTrueLogicGate,
+
+    status: FuelJettisonFlags,
+}
+impl FuelJettisonApplication {
+    const DEFAULT_TARGET_WEIGHT_KG: f64 = 386000.; // MLW
+    const FEED_TANK_MINIMUM_KG: f64 = 4000.;
+    const EMPTY_TANK_KG: f64 = 1.;
+
+    // Maximum flow through one nozzle with the valve fully open
+    const NOZZLE_FLOW_KG_PER_SEC: f64 = 25.;
+
+    const VALVE_DISAGREE_DELAY: Duration = Duration::from_secs(10);
+
+    pub(super) fn new(context: &mut InitContext) -> Self {
+        Self {
+            arm_pb: OnOffPushButton::new_off(context, "FUEL_JETTISON_ARM"),
+            active_pb: OnOffPushButton::new_off(context, "FUEL_JETTISON_ACTIVE"),
+            open_light: IndicationLight::new(context, "FUEL_JETTISON_OPEN"),
+            valves: [
+                (1, ElectricalBusType::DirectCurrent(1)),
+                (2, ElectricalBusType::DirectCurrent(2)),
+            ]
+            .map(|(id, powered_by)| JettisonValve::new(context, id, powered_by)),
+
+            target_reached: false,
+            feed_tank_minimum_reached: false,
+            in_progress: false,
+            valve_not_open: DelayedTrueLogicGate::new(Self::VALVE_DISAGREE_DELAY),
+            valve_not_closed: DelayedTrueLogicGate::new(Self::VALVE_DISAGREE_DELAY),
+
+            status: FuelJettisonFlags::empty(),
+        }
+    }
+
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut (impl SetFuelLevel + FuelPayload + FuelPumpStatus),
+        gross_weight: Option<Mass>,
+        target_weight: Option<Mass>,
+        jettison_available: bool,
+    ) {
+        let armed = self.arm_pb.is_on();
+        let selected = armed && self.active_pb.is_on();
+        if selected {
+            let target_weight = target_weight
+                .unwrap_or_else(|| Mass::new::<kilogram>(Self::DEFAULT_TARGET_WEIGHT_KG));
+            if gross_weight.is_some_and(|gross_weight| gross_weight <= target_weight) {
+                self.target_reached = true;
+            }
+            if JETTISON_SOURCES.iter().all(|source| {
+                Self::jettisonable_quantity(fuel_system, source.tank) <= Mass::default()
+            }) {
+                self.feed_tank_minimum_reached = true;
+            }
+        } else {
+            self.target_reached = false;
+            self.feed_tank_minimum_reached = false;
+        }
+
+        self.in_progress = jettison_available
+            && selected
+            && !context.is_on_ground()
+            && !self.target_reached
+            && !self.feed_tank_minimum_reached;
+
+        for valve in &mut self.valves {
+            valve.update(context, self.in_progress);
+        }
+        self.valve_not_open.update(
+            context,
+            self.in_progress && self.valves.iter().any(|valve| !valve.is_fully_open()),
+        );
+        self.valve_not_closed.update(
+            context,
+            !self.in_progress && self.valves.iter().any(|valve| !valve.is_closed()),
+        );
+
+        self.jettison(context, fuel_system);
+
+        self.open_light
+            .set_illuminated(self.valves.iter().any(|valve| !valve.is_closed()));
+
+        self.status = FuelJettisonFlags::empty();
+        self.status.set(FuelJettisonFlags::ARMED, armed);
+        self.status
+            .set(FuelJettisonFlags::IN_PROGRESS, self.in_progress);
+        self.status
+            .set(FuelJettisonFlags::TARGET_REACHED, self.target_reached);
+        self.status.set(
+            FuelJettisonFlags::FEED_TANK_MINIMUM_REACHED,
+            self.feed_tank_minimum_reached,
+        );
+        self.status.set(
+            FuelJettisonFlags::FAULT,
+            self.valve_not_open.output() || (selected && !jettison_available),
+        );
+        self.status.set(
+            FuelJettisonFlags::VALVE_NOT_CLOSED,
+            self.valve_not_closed.output(),
+        );
+    }
+
+    /// Drains the tanks through the nozzles, each wing limited by its valve opening.
+    fn jettison(
+        &self,
+        context: &UpdateContext,
+        fuel_system: &mut (impl SetFuelLevel + FuelPayload + FuelPumpStatus),
+    ) {
+        if !self.in_progress {
+            return;
+        }
+
+        let delta = context.delta_as_secs_f64();
+        let flows = JETTISON_SOURCES.map(|source| {
+            let running_pumps = source
+                .pumps
+                .iter()
+                .filter(|pump| fuel_system.is_fuel_pump_running(**pump))
+                .count();
+            source.rate_kg_per_sec * running_pumps as f64 / source.pumps.len() as f64
+        });
+
+        for (wing, valve) in self.valves.iter().enumerate() {
+            let wing_share = |source: &JettisonSource| match source.wing {
+                Some(source_wing) if source_wing == wing => 1.,
+                Some(_) => 0.,
+                None => 0.5,
+            };
+            let demand: f64 = JETTISON_SOURCES
+                .iter()
+                .zip(flows)
+                .map(|(source, flow)| flow * wing_share(source))
+                .sum();
+            if demand <= 0. {
+                continue;
+            }
+
+            let nozzle_flow = Self::NOZZLE_FLOW_KG_PER_SEC * valve.open_amount().get::<ratio>();
+            let flow_factor = (nozzle_flow / demand).min(1.);
+
+            for (source, flow) in JETTISON_SOURCES.iter().zip(flows) {
+                let amount = Mass::new::<kilogram>(flow * wing_share(source) * flow_factor * delta)
+                    .min(Self::jettisonable_quantity(fuel_system, source.tank));
+                if amount > Mass::default() {
+                    let quantity = fuel_system.tank_mass(source.tank as usize);
+                    fuel_system.set_tank_quantity(source.tank, quantity - amount);
+                }
+            }
+        }
+    }
+
+    /// The fuel of the tank which may still be jettisoned.
+    fn jettisonable_quantity(fuel_system: &impl FuelPayload, tank: A380FuelTankType) -> Mass {
+        let remaining = if FEED_TANKS.contains(&tank) {
+            Self::FEED_TANK_MINIMUM_KG
+        } else {
+            Self::EMPTY_TANK_KG
+        };
+        (fuel_system.tank_mass(tank as usize) - Mass::new::<kilogram>(remaining))
+            .max(Mass::default())
+    }
+
+    pub(super) fn is_in_progress(&self) -> bool {
+        self.in_progress
+    }
+
+    pub(super) fn status(&self) -> FuelJettisonFlags {
+        self.status
+    }
+}
+impl SimulationElement for FuelJettisonApplication {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.arm_pb.accept(visitor);
+        self.active_pb.accept(visitor);
+        self.open_light.accept(visitor);
+        accept_iterable!(self.valves, visitor);
+        visitor.visit(self);
+    }
+}
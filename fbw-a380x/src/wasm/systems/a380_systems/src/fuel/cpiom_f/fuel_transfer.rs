@@ -0,0 +1,537 @@
+use crate::fuel::{
+    cpiom_f::FEED_TANKS, A380FuelPump, A380FuelTankType, FuelPumpStatus, SetFuelLevel,
+};
+use bitflags::bitflags;
+use systems::{
+    failures::{Failure, FailureType},
+    fuel::FuelPayload,
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        UpdateContext, VariableIdentifier,
+    },
+};
+use uom::si::{f64::Mass, mass::kilogram};
+
+bitflags! {
+    /// Transfer states as used by the FWS for the FUEL memos and cautions.
+    #[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+    pub(crate) struct FuelTransferFlags: u32 {
+        /// Inner or mid tank fuel is transferred to the feed tanks
+        const FEED_TANK_TOP_UP = 1 << 0;
+        /// CG control: wing fuel is transferred into the trim tank
+        const TRIM_TANK_AFT_TRANSFER = 1 << 1;
+        /// TRIM TK XFR
+        const TRIM_TANK_FWD_TRANSFER = 1 << 2;
+        /// OUTR TK XFR
+        const OUTER_TANK_TRANSFER = 1 << 3;
+        /// EMER OUTR TK XFR
+        const EMERGENCY_OUTER_TANK_TRANSFER = 1 << 4;
+        /// TRIM TK FUEL XFRD
+        const TRIM_TANK_FUEL_TRANSFERRED = 1 << 5;
+        /// OUTR TK FUEL XFRD
+        const OUTER_TANK_FUEL_TRANSFERRED = 1 << 6;
+        /// FUEL TRIM TK XFR FAULT
+        const TRIM_TANK_TRANSFER_FAULT = 1 << 7;
+        /// FUEL AUTO XFR FAULT
+        const AUTO_TRANSFER_FAULT = 1 << 8;
+    }
+}
+
+struct FuelTransferPanelInput {
+    trim_tank_transfer_is_auto_id: VariableIdentifier,
+    trim_tank_transfer_is_auto: bool,
+
+    outer_tank_transfer_is_auto_id: VariableIdentifier,
+    outer_tank_transfer_is_auto: bool,
+
+    inner_tank_transfer_is_auto_id: VariableIdentifier,
+    inner_tank_transfer_is_auto: bool,
+
+    mid_tank_transfer_is_auto_id: VariableIdentifier,
+    mid_tank_transfer_is_auto: bool,
+
+    emergency_outer_tank_transfer_is_on_id: VariableIdentifier,
+    emergency_outer_tank_transfer_is_on: bool,
+}
+impl FuelTransferPanelInput {
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            trim_tank_transfer_is_auto_id: context
+                .get_identifier("OVHD_FUEL_TRIMTK_XFR_PB_IS_AUTO".to_owned()),
+            trim_tank_transfer_is_auto: true,
+
+            outer_tank_transfer_is_auto_id: context
+                .get_identifier("OVHD_FUEL_OUTRTK_XFR_PB_IS_AUTO".to_owned()),
+            outer_tank_transfer_is_auto: true,
+
+            inner_tank_transfer_is_auto_id: context
+                .get_identifier("OVHD_FUEL_INRTK_XFR_PB_IS_AUTO".to_owned()),
+            inner_tank_transfer_is_auto: true,
+
+            mid_tank_transfer_is_auto_id: context
+                .get_identifier("OVHD_FUEL_MIDTK_XFR_PB_IS_AUTO".to_owned()),
+            mid_tank_transfer_is_auto: true,
+
+            emergency_outer_tank_transfer_is_on_id: context
+                .get_identifier("OVHD_FUEL_EMER_OUTR_XFR_PB_IS_ON".to_owned()),
+            emergency_outer_tank_transfer_is_on: false,
+        }
+    }
+}
+impl SimulationElement for FuelTransferPanelInput {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.trim_tank_transfer_is_auto = reader.read(&self.trim_tank_transfer_is_auto_id);
+        self.outer_tank_transfer_is_auto = reader.read(&self.outer_tank_transfer_is_auto_id);
+        self.inner_tank_transfer_is_auto = reader.read(&self.inner_tank_transfer_is_auto_id);
+        self.mid_tank_transfer_is_auto = reader.read(&self.mid_tank_transfer_is_auto_id);
+        self.emergency_outer_tank_transfer_is_on =
+            reader.read(&self.emergency_outer_tank_transfer_is_on_id);
+    }
+}
+
+/// The tanks and transfer pumps of one wing, with the feed tanks they replenish.
+struct WingTransferGroup {
+    feed_tanks: [A380FuelTankType; 2],
+    emergency_feed_tank: A380FuelTankType,
+    inner_tank: A380FuelTankType,
+    inner_pumps: [A380FuelPump; 2],
+    mid_tank: A380FuelTankType,
+    mid_pumps: [A380FuelPump; 2],
+    outer_tank: A380FuelTankType,
+    outer_pump: A380FuelPump,
+}
+
+const WINGS: [WingTransferGroup; 2] = [
+    WingTransferGroup {
+        feed_tanks: [A380FuelTankType::FeedOne, A380FuelTankType::FeedTwo],
+        emergency_feed_tank: A380FuelTankType::FeedOne,
+        inner_tank: A380FuelTankType::LeftInner,
+        inner_pumps: [A380FuelPump::LeftInnerFwd, A380FuelPump::LeftInnerAft],
+        mid_tank: A380FuelTankType::LeftMid,
+        mid_pumps: [A380FuelPump::LeftMidFwd, A380FuelPump::LeftMidAft],
+        outer_tank: A380FuelTankType::LeftOuter,
+        outer_pump: A380FuelPump::LeftOuter,
+    },
+    WingTransferGroup {
+        feed_tanks: [A380FuelTankType::FeedThree, A380FuelTankType::FeedFour],
+        emergency_feed_tank: A380FuelTankType::FeedFour,
+        inner_tank: A380FuelTankType::RightInner,
+        inner_pumps: [A380FuelPump::RightInnerFwd, A380FuelPump::RightInnerAft],
+        mid_tank: A380FuelTankType::RightMid,
+        mid_pumps: [A380FuelPump::RightMidFwd, A380FuelPump::RightMidAft],
+        outer_tank: A380FuelTankType::RightOuter,
+        outer_pump: A380FuelPump::RightOuter,
+    },
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TrimTankTransfer {
+    None,
+    Aft,
+    Forward,
+}
+
+/// # In-flight fuel transfer application of the FQMS
+///
+/// Keeps the feed tanks replenished from the inner and mid tanks, controls the CG by moving
+/// fuel in and out of the trim tank towards the quantity scheduled by the trim tank target
+/// tables for the FMS ZFW and ZFWCG, and empties the trim and outer tanks towards the end of
+/// the flight. The emergency outer tank transfer is hardwired and works without the FQMS.
+pub(super) struct FuelTransferApplication {
+    panel_input: FuelTransferPanelInput,
+    trim_tank_transfer_valve_failure: Failure,
+
+    feed_tank_top_up: [bool; 4],
+    trim_tank_transfer: TrimTankTransfer,
+    trim_tank_end_of_flight_transfer: bool,
+    outer_tank_transfer: [bool; 2],
+    trim_tank_fuel_transferred: bool,
+    outer_tank_fuel_transferred: bool,
+
+    status: FuelTransferFlags,
+}
+impl FuelTransferApplication {
+    const FEED_TANK_TOP_UP_START_KG: f64 = 19000.;
+    const FEED_TANK_TOP_UP_TARGET_KG: f64 = 20000.;
+    const TRIM_TANK_END_OF_FLIGHT_FEED_LEVEL_KG: f64 = 6000.;
+    const OUTER_TANK_TRANSFER_FEED_LEVEL_KG: f64 = 4000.;
+    const EMPTY_TANK_KG: f64 = 1.;
+
+    const FEED_TANK_TOP_UP_RATE_KG_PER_SEC: f64 = 6.;
+    const TRIM_TANK_TRANSFER_RATE_KG_PER_SEC: f64 = 10.;
+    const OUTER_TANK_TRANSFER_RATE_KG_PER_SEC: f64 = 4.;
+    const EMERGENCY_OUTER_TANK_TRANSFER_RATE_KG_PER_SEC: f64 = 3.;
+
+    // Half of the resolution of the trim tank target tables
+    const TRIM_TANK_TARGET_DEADBAND_KG: f64 = 250.;
+
+    pub(super) fn new(context: &mut InitContext) -> Self {
+        Self {
+            panel_input: FuelTransferPanelInput::new(context),
+            trim_tank_transfer_valve_failure: Failure::new(
+                FailureType::TrimTankTransferValveStuckClosed,
+            ),
+
+            feed_tank_top_up: [false; 4],
+            trim_tank_transfer: TrimTankTransfer::None,
+            trim_tank_end_of_flight_transfer: false,
+            outer_tank_transfer: [false; 2],
+            trim_tank_fuel_transferred: false,
+            outer_tank_fuel_transferred: false,
+
+            status: FuelTransferFlags::empty(),
+        }
+    }
+
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut (impl SetFuelLevel + FuelPayload + FuelPumpStatus),
+        trim_tank_target: Option<Mass>,
+        jettison_in_progress: bool,
+        auto_transfer_available: bool,
+    ) {
+        self.status = FuelTransferFlags::empty();
+        if self.trim_tank_transfer_valve_failure.is_active() {
+            self.status |= FuelTransferFlags::TRIM_TANK_TRANSFER_FAULT;
+        }
+
+        if context.is_on_ground() {
+            self.reset();
+            return;
+        }
+
+        let delta = context.delta_as_secs_f64();
+
+        if self.panel_input.emergency_outer_tank_transfer_is_on {
+            self.status |= FuelTransferFlags::EMERGENCY_OUTER_TANK_TRANSFER;
+            for wing in &WINGS {
+                Self::transfer(
+                    fuel_system,
+                    wing.outer_tank,
+                    wing.emergency_feed_tank,
+                    Mass::new::<kilogram>(
+                        Self::EMERGENCY_OUTER_TANK_TRANSFER_RATE_KG_PER_SEC * delta,
+                    ),
+                );
+            }
+        }
+
+        if !auto_transfer_available {
+            self.reset_automatic_transfers();
+            return;
+        }
+
+        self.update_trim_tank_transfer(fuel_system, trim_tank_target, jettison_in_progress, delta);
+        self.update_feed_tank_top_up(fuel_system, delta);
+        self.update_outer_tank_transfer(fuel_system, delta);
+        self.update_transferred_memos(fuel_system);
+    }
+
+    fn update_trim_tank_transfer(
+        &mut self,
+        fuel_system: &mut (impl SetFuelLevel + FuelPayload + FuelPumpStatus),
+        trim_tank_target: Option<Mass>,
+        jettison_in_progress: bool,
+        delta: f64,
+    ) {
+        let trim_tank_quantity = fuel_system.tank_mass(A380FuelTankType::Trim as usize);
+        let trim_tank_is_empty = Self::is_empty(trim_tank_quantity);
+        let trim_tank_is_full = trim_tank_quantity >= A380FuelTankType::Trim.capacity();
+        let trim_pumps_running = fuel_system.is_fuel_pump_running(A380FuelPump::TrimLeft)
+            || fuel_system.is_fuel_pump_running(A380FuelPump::TrimRight);
+        let valve_failed = self.trim_tank_transfer_valve_failure.is_active();
+
+        if !self.trim_tank_end_of_flight_transfer
+            && !trim_tank_is_empty
+            && self.panel_input.trim_tank_transfer_is_auto
+            && Self::wing_transfer_tanks_are_empty(fuel_system)
+            && FEED_TANKS.iter().any(|tank| {
+                fuel_system.tank_mass(*tank as usize)
+                    < Mass::new::<kilogram>(Self::TRIM_TANK_END_OF_FLIGHT_FEED_LEVEL_KG)
+            })
+        {
+            self.trim_tank_end_of_flight_transfer = true;
+        }
+
+        let deadband = Mass::new::<kilogram>(Self::TRIM_TANK_TARGET_DEADBAND_KG);
+        // No fuel is moved into the trim tank while it is being jettisoned
+        let aft_source_available = !jettison_in_progress
+            && WINGS
+                .iter()
+                .any(|wing| Self::wing_source(fuel_system, wing, &self.panel_input).is_some());
+
+        self.trim_tank_transfer = if valve_failed || !self.panel_input.trim_tank_transfer_is_auto {
+            TrimTankTransfer::None
+        } else if self.trim_tank_end_of_flight_transfer {
+            TrimTankTransfer::Forward
+        } else if let Some(target) = trim_tank_target {
+            match self.trim_tank_transfer {
+                TrimTankTransfer::None
+                    if trim_tank_quantity < target - deadband
+                        && !trim_tank_is_full
+                        && aft_source_available =>
+                {
+                    TrimTankTransfer::Aft
+                }
+                TrimTankTransfer::None
+                    if trim_tank_quantity > target + deadband && !trim_tank_is_empty =>
+                {
+                    TrimTankTransfer::Forward
+                }
+                TrimTankTransfer::Aft
+                    if trim_tank_quantity >= target
+                        || trim_tank_is_full
+                        || !aft_source_available =>
+                {
+                    TrimTankTransfer::None
+                }
+                TrimTankTransfer::Forward if trim_tank_quantity <= target || trim_tank_is_empty => {
+                    TrimTankTransfer::None
+                }
+                state => state,
+            }
+        } else {
+            TrimTankTransfer::None
+        };
+
+        let max_delta = Mass::new::<kilogram>(Self::TRIM_TANK_TRANSFER_RATE_KG_PER_SEC * delta);
+        match self.trim_tank_transfer {
+            TrimTankTransfer::Aft => {
+                let mut transferred = Mass::default();
+                for wing in &WINGS {
+                    if let Some(source) = Self::wing_source(fuel_system, wing, &self.panel_input) {
+                        transferred += Self::transfer(
+                            fuel_system,
+                            source,
+                            A380FuelTankType::Trim,
+                            max_delta / 2.,
+                        );
+                    }
+                }
+                if transferred > Mass::default() {
+                    self.status |= FuelTransferFlags::TRIM_TANK_AFT_TRANSFER;
+                }
+            }
+            TrimTankTransfer::Forward if trim_pumps_running => {
+                let mut transferred = Mass::default();
+                for feed_tank in FEED_TANKS {
+                    transferred += Self::transfer(
+                        fuel_system,
+                        A380FuelTankType::Trim,
+                        feed_tank,
+                        max_delta / FEED_TANKS.len() as f64,
+                    );
+                }
+                if transferred > Mass::default() {
+                    self.status |= FuelTransferFlags::TRIM_TANK_FWD_TRANSFER;
+                }
+            }
+            TrimTankTransfer::Forward => {
+                if !trim_tank_is_empty {
+                    self.status |= FuelTransferFlags::AUTO_TRANSFER_FAULT;
+                }
+            }
+            TrimTankTransfer::None => {}
+        }
+    }
+
+    fn update_feed_tank_top_up(
+        &mut self,
+        fuel_system: &mut (impl SetFuelLevel + FuelPayload + FuelPumpStatus),
+        delta: f64,
+    ) {
+        let max_delta = Mass::new::<kilogram>(Self::FEED_TANK_TOP_UP_RATE_KG_PER_SEC * delta);
+        let mut feed_tank_index = 0;
+        for wing in &WINGS {
+            for feed_tank in wing.feed_tanks {
+                let quantity = fuel_system.tank_mass(feed_tank as usize);
+                let top_up = &mut self.feed_tank_top_up[feed_tank_index];
+                feed_tank_index += 1;
+
+                if quantity < Mass::new::<kilogram>(Self::FEED_TANK_TOP_UP_START_KG) {
+                    *top_up = true;
+                } else if quantity >= Mass::new::<kilogram>(Self::FEED_TANK_TOP_UP_TARGET_KG) {
+                    *top_up = false;
+                }
+
+                if !*top_up {
+                    continue;
+                }
+
+                let room = Mass::new::<kilogram>(Self::FEED_TANK_TOP_UP_TARGET_KG) - quantity;
+                match Self::wing_source(fuel_system, wing, &self.panel_input) {
+                    Some(source) => {
+                        if Self::transfer(fuel_system, source, feed_tank, max_delta.min(room))
+                            > Mass::default()
+                        {
+                            self.status |= FuelTransferFlags::FEED_TANK_TOP_UP;
+                        }
+                    }
+                    None => {
+                        if Self::wing_source_has_unpumped_fuel(fuel_system, wing, &self.panel_input)
+                        {
+                            self.status |= FuelTransferFlags::AUTO_TRANSFER_FAULT;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn update_outer_tank_transfer(
+        &mut self,
+        fuel_system: &mut (impl SetFuelLevel + FuelPayload + FuelPumpStatus),
+        delta: f64,
+    ) {
+        let feed_tank_low = FEED_TANKS.iter().any(|tank| {
+            fuel_system.tank_mass(*tank as usize)
+                < Mass::new::<kilogram>(Self::OUTER_TANK_TRANSFER_FEED_LEVEL_KG)
+        });
+        let max_delta = Mass::new::<kilogram>(Self::OUTER_TANK_TRANSFER_RATE_KG_PER_SEC * delta);
+
+        for (wing, transfer) in WINGS.iter().zip(&mut self.outer_tank_transfer) {
+            let outer_tank_is_empty =
+                Self::is_empty(fuel_system.tank_mass(wing.outer_tank as usize));
+            if outer_tank_is_empty || !self.panel_input.outer_tank_transfer_is_auto {
+                *transfer = false;
+            } else if feed_tank_low {
+                *transfer = true;
+            }
+
+            if !*transfer {
+                continue;
+            }
+
+            if fuel_system.is_fuel_pump_running(wing.outer_pump) {
+                if Self::transfer(fuel_system, wing.outer_tank, wing.inner_tank, max_delta)
+                    > Mass::default()
+                {
+                    self.status |= FuelTransferFlags::OUTER_TANK_TRANSFER;
+                }
+            } else {
+                self.status |= FuelTransferFlags::AUTO_TRANSFER_FAULT;
+            }
+        }
+    }
+
+    fn update_transferred_memos(&mut self, fuel_system: &impl FuelPayload) {
+        if self.trim_tank_end_of_flight_transfer
+            && Self::is_empty(fuel_system.tank_mass(A380FuelTankType::Trim as usize))
+        {
+            self.trim_tank_fuel_transferred = true;
+        }
+        if self.outer_tank_transfer.iter().any(|transfer| *transfer)
+            && WINGS
+                .iter()
+                .all(|wing| Self::is_empty(fuel_system.tank_mass(wing.outer_tank as usize)))
+        {
+            self.outer_tank_fuel_transferred = true;
+        }
+
+        if self.trim_tank_fuel_transferred {
+            self.status |= FuelTransferFlags::TRIM_TANK_FUEL_TRANSFERRED;
+        }
+        if self.outer_tank_fuel_transferred {
+            self.status |= FuelTransferFlags::OUTER_TANK_FUEL_TRANSFERRED;
+        }
+    }
+
+    /// The first tank of the wing which can currently supply fuel: inner tank before mid tank.
+    fn wing_source(
+        fuel_system: &(impl FuelPayload + FuelPumpStatus),
+        wing: &WingTransferGroup,
+        panel_input: &FuelTransferPanelInput,
+    ) -> Option<A380FuelTankType> {
+        [
+            (
+                wing.inner_tank,
+                wing.inner_pumps,
+                panel_input.inner_tank_transfer_is_auto,
+            ),
+            (
+                wing.mid_tank,
+                wing.mid_pumps,
+                panel_input.mid_tank_transfer_is_auto,
+            ),
+        ]
+        .into_iter()
+        .find(|(tank, pumps, is_auto)| {
+            *is_auto
+                && !Self::is_empty(fuel_system.tank_mass(*tank as usize))
+                && pumps
+                    .iter()
+                    .any(|pump| fuel_system.is_fuel_pump_running(*pump))
+        })
+        .map(|(tank, _, _)| tank)
+    }
+
+    fn wing_source_has_unpumped_fuel(
+        fuel_system: &impl FuelPayload,
+        wing: &WingTransferGroup,
+        panel_input: &FuelTransferPanelInput,
+    ) -> bool {
+        (panel_input.inner_tank_transfer_is_auto
+            && !Self::is_empty(fuel_system.tank_mass(wing.inner_tank as usize)))
+            || (panel_input.mid_tank_transfer_is_auto
+                && !Self::is_empty(fuel_system.tank_mass(wing.mid_tank as usize)))
+    }
+
+    fn wing_transfer_tanks_are_empty(fuel_system: &impl FuelPayload) -> bool {
+        WINGS.iter().all(|wing| {
+            Self::is_empty(fuel_system.tank_mass(wing.inner_tank as usize))
+                && Self::is_empty(fuel_system.tank_mass(wing.mid_tank as usize))
+        })
+    }
+
+    fn is_empty(quantity: Mass) -> bool {
+        quantity < Mass::new::<kilogram>(Self::EMPTY_TANK_KG)
+    }
+
+    /// Moves up to `max_delta` of fuel, limited by the fuel available and the space left in the
+    /// destination tank. Returns the transferred mass.
+    fn transfer(
+        fuel_system: &mut (impl SetFuelLevel + FuelPayload),
+        from: A380FuelTankType,
+        to: A380FuelTankType,
+        max_delta: Mass,
+    ) -> Mass {
+        let source_quantity = fuel_system.tank_mass(from as usize);
+        let destination_quantity = fuel_system.tank_mass(to as usize);
+        let amount = max_delta
+            .min(source_quantity)
+            .min(to.capacity() - destination_quantity);
+
+        if amount <= Mass::default() {
+            return Mass::default();
+        }
+
+        fuel_system.set_tank_quantity(from, source_quantity - amount);
+        fuel_system.set_tank_quantity(to, destination_quantity + amount);
+        amount
+    }
+
+    fn reset_automatic_transfers(&mut self) {
+        self.feed_tank_top_up = [false; 4];
+        self.trim_tank_transfer = TrimTankTransfer::None;
+        self.outer_tank_transfer = [false; 2];
+    }
+
+    fn reset(&mut self) {
+        self.reset_automatic_transfers();
+        self.trim_tank_end_of_flight_transfer = false;
+        self.trim_tank_fuel_transferred = false;
+        self.outer_tank_fuel_transferred = false;
+    }
+
+    pub(super) fn status(&self) -> FuelTransferFlags {
+        self.status
+    }
+}
+impl SimulationElement for FuelTransferApplication {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.panel_input.accept(visitor);
+        self.trim_tank_transfer_valve_failure.accept(visitor);
+        visitor.visit(self);
+    }
+}
@@ -1,18 +1,33 @@
+mod fuel_jettison;
 mod fuel_measuring;
+mod fuel_transfer;
 
-use super::{A380FuelTankType, SetFuelLevel};
+use super::{A380FuelTankType, FuelPumpStatus, SetFuelLevel};
 use crate::{
+    avionics_data_communication_network::{
+        A380AvionicsDataCommunicationNetwork, A380AvionicsDataCommunicationNetworkMessageData,
+        FqmsFuelData,
+    },
     fuel::{ArincFuelPumpStatusProvider, FuelQuantityDataConcentrator},
     systems::simulation::SimulationElement,
 };
 use bitflags::{bitflags, Flags};
 use enum_map::Enum;
+use fuel_jettison::FuelJettisonApplication;
+#[cfg(test)]
+pub(super) use fuel_jettison::FuelJettisonFlags;
 use fuel_measuring::FuelMeasuringApplication;
+use fuel_transfer::FuelTransferApplication;
+#[cfg(test)]
+pub(super) use fuel_transfer::FuelTransferFlags;
 use serde::Deserialize;
 use serde_with::{serde_as, DisplayFromStr};
 use std::{collections::HashMap, time::Duration};
 use systems::{
     fuel::{self, FuelPayload, RefuelRate},
+    integrated_modular_avionics::{
+        AvionicsDataCommunicationNetwork, AvionicsDataCommunicationNetworkMessageIdentifier,
+    },
     payload::LoadsheetInfo,
     pneumatic::EngineState,
     shared::{
@@ -325,9 +340,11 @@ impl RefuelApplication {
         target_fuel_range: &[u32],
         zero_fuel_weight_cg_rounded: u32,
     ) -> Mass {
+        let min_zfw_cg = *target_zfw_cg_keys.iter().min().unwrap();
+        let max_zfw_cg = *target_zfw_cg_keys.iter().max().unwrap();
         let cg_index = target_zfw_cg_keys
             .iter()
-            .position(|&x| x == zero_fuel_weight_cg_rounded)
+            .position(|&x| x == zero_fuel_weight_cg_rounded.clamp(min_zfw_cg, max_zfw_cg))
             .unwrap_or_default();
         Mass::new::<kilogram>(target_fuel_range[cg_index] as f64)
     }
@@ -345,14 +362,30 @@ impl RefuelApplication {
     }
 
     fn calculate_trim_fuel(
-        &mut self,
+        &self,
         total_desired_fuel: Mass,
         zero_fuel_weight: Mass,
         zero_fuel_weight_cg_percent_mac: f64,
     ) -> Mass {
+        self.trim_tank_target(
+            total_desired_fuel,
+            zero_fuel_weight,
+            zero_fuel_weight_cg_percent_mac,
+        )
+        .unwrap_or_default()
+    }
+
+    /// The trim tank quantity scheduled for the given fuel on board, ZFW and ZFWCG, or `None`
+    /// when the ZFW is outside of the trim tank tables.
+    pub(super) fn trim_tank_target(
+        &self,
+        total_fuel: Mass,
+        zero_fuel_weight: Mass,
+        zero_fuel_weight_cg_percent_mac: f64,
+    ) -> Option<Mass> {
         // Init Inputs
         let total_desired_fuel_rounded =
-            ((total_desired_fuel.get::<kilogram>() / 1000.0).floor() * 1000.0) as u32;
+            ((total_fuel.get::<kilogram>() / 1000.0).floor() * 1000.0) as u32;
         let zero_fuel_weight_cg_rounded = (zero_fuel_weight_cg_percent_mac).floor() as u32;
         let target_zfw = &self.trim_tank_map.params.target_zfw;
         let trim_tank_tables = &self.trim_tank_map.trim_tank_targets.tables;
@@ -367,14 +400,14 @@ impl RefuelApplication {
                     Self::get_target_fuel_range(zfw_category, total_desired_fuel_rounded);
 
                 // Then, given the current target fuel (row). Shift this value depending on ZFWCG% (column) to find trim fuel value.
-                return Self::lookup_trim_fuel_from_target_fuel_range(
+                return Some(Self::lookup_trim_fuel_from_target_fuel_range(
                     target_zfw_cg_keys,
                     target_fuel_range,
                     zero_fuel_weight_cg_rounded,
-                );
+                ));
             }
         }
-        Mass::default()
+        None
     }
 
     pub fn calculate_auto_refuel(
@@ -631,7 +664,8 @@ bitflags! {
 
 /// # A380 Fuel Quantity Management System (FQMS)
 ///
-/// Handles fuel quantity measurement, refueling, and pump state reporting.
+/// Handles fuel quantity measurement, refueling, in-flight fuel transfers, fuel jettison and pump
+/// state reporting.
 /// We simulate both FQMS sides together here for simplicity and performance reasons.
 // TODO: This is a preliminary implementation and needs to be expanded to cover all FQMS functionalities
 // TODO: implement AFDX communication (FMS data, pump states, valve states, etc.)
@@ -641,10 +675,14 @@ pub(super) struct A380FuelQuantityManagementSystem {
     fuel_measuring_application: FuelMeasuringApplication,
     refuel_application: RefuelApplication,
     integrated_refuel_panel: IntegratedRefuelPanel,
+    fuel_transfer_application: FuelTransferApplication,
+    fuel_jettison_application: FuelJettisonApplication,
 
     fuel_pump_running_word_id: [VariableIdentifier; 2],
     fuel_pump_running_words: [Arinc429Word<u32>; 2],
 
+    fws_virtual_link_ids: [AvionicsDataCommunicationNetworkMessageIdentifier; 2],
+
     // TODO: should be transmitted over AFDX
     // fms_weights_ids: [AvionicsDataCommunicationNetworkMessageIdentifier; 2],
     // fms_remaining_time_ids: [AvionicsDataCommunicationNetworkMessageIdentifier; 2],
@@ -652,6 +690,8 @@ pub(super) struct A380FuelQuantityManagementSystem {
     fms_zero_fuel_weights: [Option<Mass>; 2],
     fms_zero_fuel_weight_cg_ids: [VariableIdentifier; 2],
     fms_zero_fuel_weight_cgs: [Option<Ratio>; 2],
+    fms_jettison_target_weight_ids: [VariableIdentifier; 2],
+    fms_jettison_target_weights: [Option<Mass>; 2],
 
     // Output ARINC 429 backup signals (only outputed by CPIOM-F3 and CPIOM-F4)
     // TODO: currently we output the values from all FQMS' as it would be one
@@ -662,12 +702,18 @@ pub(super) struct A380FuelQuantityManagementSystem {
 
     fqms_status_word_id: VariableIdentifier,
     fqms_status_word: FQMSDiscreteFlags,
+
+    fuel_transfer_status_word_id: VariableIdentifier,
+    fuel_jettison_status_word_id: VariableIdentifier,
 }
 impl A380FuelQuantityManagementSystem {
     // Self test time (reference: FFS)
     const SELF_TEST_DURATION: Duration = Duration::from_secs(30);
 
-    pub(super) fn new(context: &mut InitContext) -> Self {
+    pub(super) fn new(
+        context: &mut InitContext,
+        adcn: &mut A380AvionicsDataCommunicationNetwork,
+    ) -> Self {
         // TODO: This needs to be refactored when CPIOM implementation is done
         // CPIOM_COM_F1, CPIOM_MON_F3 [FQDC_1] -> 501PP
         // CPIOM_COM_F2, CPIOM_MON_F4 [FQDC_2] -> 109PP 101PP 107PP
@@ -680,11 +726,16 @@ impl A380FuelQuantityManagementSystem {
                 context,
                 ElectricalBusType::DirectCurrentNamed("502PP"),
             ),
+            fuel_transfer_application: FuelTransferApplication::new(context),
+            fuel_jettison_application: FuelJettisonApplication::new(context),
 
             fuel_pump_running_word_id: ["LEFT", "RIGHT"]
                 .map(|side| context.get_identifier(format!("FQMS_{side}_FUEL_PUMP_RUNNING_WORD"))),
             fuel_pump_running_words: Default::default(),
 
+            fws_virtual_link_ids: [1, 2]
+                .map(|side| adcn.get_message_identifier(format!("FQMS_{side}_TO_FWS_{side}"))),
+
             // Input from FMS
             // TODO: should be transmitted over AFDX
             fms_zero_fuel_weight_ids: [1, 2]
@@ -693,6 +744,9 @@ impl A380FuelQuantityManagementSystem {
             fms_zero_fuel_weight_cg_ids: [1, 2]
                 .map(|id| context.get_identifier(format!("FM{id}_ZERO_FUEL_WEIGHT_CG"))),
             fms_zero_fuel_weight_cgs: Default::default(),
+            fms_jettison_target_weight_ids: [1, 2]
+                .map(|id| context.get_identifier(format!("FM{id}_JETTISON_TARGET_WEIGHT"))),
+            fms_jettison_target_weights: Default::default(),
 
             fuel_tank_quantities_id: A380FuelTankType::iterator()
                 .map(|tank| context.get_identifier(format!("FQMS_{tank}_QUANTITY")))
@@ -705,13 +759,18 @@ impl A380FuelQuantityManagementSystem {
 
             fqms_status_word_id: context.get_identifier("FQMS_STATUS_WORD".to_owned()),
             fqms_status_word: Default::default(),
+
+            fuel_transfer_status_word_id: context
+                .get_identifier("FQMS_FUEL_TRANSFER_STATUS_WORD".to_owned()),
+            fuel_jettison_status_word_id: context
+                .get_identifier("FQMS_FUEL_JETTISON_STATUS_WORD".to_owned()),
         }
     }
 
     pub(super) fn update(
         &mut self,
         context: &UpdateContext,
-        fuel_system: &mut (impl SetFuelLevel + FuelPayload),
+        fuel_system: &mut (impl SetFuelLevel + FuelPayload + FuelPumpStatus),
         loadsheet: &LoadsheetInfo,
         fqdcs: &[FuelQuantityDataConcentrator; 2],
         cpioms_available: [bool; 4],
@@ -728,6 +787,34 @@ impl A380FuelQuantityManagementSystem {
         self.self_test_finished
             .update(context, cpioms_available.into_iter().any(|a| a));
 
+        let (fms_zfw, flags1) = Self::get_fms_data_and_status(self.fms_zero_fuel_weights);
+        let (fms_zfwcg, flags2) = Self::get_fms_data_and_status(self.fms_zero_fuel_weight_cgs);
+        let trim_tank_target = fms_zfw.zip(fms_zfwcg).and_then(|(zfw, zfwcg)| {
+            self.refuel_application.trim_tank_target(
+                fuel_system.total_load(),
+                zfw,
+                zfwcg.get::<percent>(),
+            )
+        });
+
+        self.fuel_transfer_application.update(
+            context,
+            fuel_system,
+            trim_tank_target,
+            self.fuel_jettison_application.is_in_progress(),
+            self.self_test_finished.output(),
+        );
+
+        let (fms_jettison_target_weight, _) =
+            Self::get_fms_data_and_status(self.fms_jettison_target_weights);
+        self.fuel_jettison_application.update(
+            context,
+            fuel_system,
+            self.fuel_measuring_application.total_aircraft_weight(),
+            fms_jettison_target_weight,
+            self.self_test_finished.output(),
+        );
+
         if !self.self_test_finished.output() {
             self.reset();
             return;
@@ -745,19 +832,46 @@ impl A380FuelQuantityManagementSystem {
             selected_fqdc.get_right_fuel_pump_running_word(),
         ];
 
-        let (fms_zfw, flags1) = Self::get_fms_data_and_status(self.fms_zero_fuel_weights);
-        let (fms_zfwcg, flags2) = Self::get_fms_data_and_status(self.fms_zero_fuel_weight_cgs);
         self.fqms_status_word = flags1 | flags2;
 
         self.fuel_measuring_application
             .update(loadsheet, selected_fqdc, fms_zfw, fms_zfwcg);
     }
 
+    /// Sends the fuel data used by the FWS over the virtual link of each FQMS side. A side only
+    /// transmits when its CPIOM is available.
+    pub(super) fn send_fws_data(&self, adcn: &mut A380AvionicsDataCommunicationNetwork) {
+        if !self.self_test_finished.output() {
+            return;
+        }
+
+        let data = FqmsFuelData {
+            total_fuel_on_board: self
+                .fuel_measuring_application
+                .total_fuel_onboard()
+                .map(|fob| fob.get::<kilogram>().round() as u32),
+            center_of_gravity_mac: self
+                .fuel_measuring_application
+                .center_of_gravity()
+                .map(|cg| (cg.get::<percent>() * 10.).round() as u32),
+            status_word: self.fqms_status_word.bits() << 11,
+        };
+
+        for (id, &is_available) in self.fws_virtual_link_ids.iter().zip(&self.cpioms_available) {
+            if let Some(virtual_link) = adcn.get_virtual_link_mut(id).filter(|_| is_available) {
+                virtual_link.send_value(
+                    A380AvionicsDataCommunicationNetworkMessageData::FqmsFuelData(data).into(),
+                );
+            }
+        }
+    }
+
     fn reset(&mut self) {
         self.fuel_measuring_application.reset();
         self.fuel_pump_running_words = Default::default();
         self.fms_zero_fuel_weights = Default::default();
         self.fms_zero_fuel_weight_cgs = Default::default();
+        self.fms_jettison_target_weights = Default::default();
         self.fqms_status_word = Default::default();
     }
 
@@ -823,6 +937,8 @@ impl SimulationElement for A380FuelQuantityManagementSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.refuel_application.accept(visitor);
         self.integrated_refuel_panel.accept(visitor);
+        self.fuel_transfer_application.accept(visitor);
+        self.fuel_jettison_application.accept(visitor);
         visitor.visit(self);
     }
 
@@ -843,6 +959,17 @@ impl SimulationElement for A380FuelQuantityManagementSystem {
         {
             *zfwcg = reader.read_arinc429(id).normal_value();
         }
+
+        for (id, target_weight) in self
+            .fms_jettison_target_weight_ids
+            .iter()
+            .zip(&mut self.fms_jettison_target_weights)
+        {
+            let target_weight_arinc: Arinc429Word<f64> = reader.read_arinc429(id);
+            *target_weight = target_weight_arinc
+                .normal_value()
+                .map(|v| Mass::new::<kilogram>(v));
+        }
     }
 
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -900,5 +1027,17 @@ impl SimulationElement for A380FuelQuantityManagementSystem {
             self.fqms_status_word,
             is_powered,
         );
+        self.write_arinc429_bitflags(
+            writer,
+            &self.fuel_transfer_status_word_id,
+            self.fuel_transfer_application.status(),
+            is_powered,
+        );
+        self.write_arinc429_bitflags(
+            writer,
+            &self.fuel_jettison_status_word_id,
+            self.fuel_jettison_application.status(),
+            is_powered,
+        );
     }
 }
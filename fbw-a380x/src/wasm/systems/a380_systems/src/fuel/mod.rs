@@ -11,13 +11,13 @@ use fuel_quantity_data_concentrator::FuelQuantityDataConcentrator;
 use nalgebra::Vector3;
 use systems::{
     accept_iterable,
-    fuel::{FuelCG, FuelInfo, FuelPayload, FuelPump, FuelPumpProperties, FuelSystem},
+    fuel::{self, FuelCG, FuelInfo, FuelPayload, FuelPump, FuelPumpProperties, FuelSystem},
     integrated_modular_avionics::AvionicsDataCommunicationNetwork,
     payload::LoadsheetInfo,
     shared::{arinc429::Arinc429Word, ElectricalBusType},
     simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
-use uom::si::f64::*;
+use uom::si::{f64::*, mass::kilogram};
 
 #[cfg(test)]
 mod test;
@@ -85,6 +85,13 @@ impl A380FuelTankType {
         .iter()
         .copied()
     }
+
+    fn capacity(self) -> Mass {
+        Mass::new::<kilogram>(
+            A380FuelSystem::A380_FUEL[self as usize].total_capacity_gallons
+                * fuel::FUEL_GALLONS_TO_KG,
+        )
+    }
 }
 impl std::fmt::Display for A380FuelTankType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -135,7 +142,10 @@ pub(crate) struct A380Fuel {
     fuel_quantity_management_system: A380FuelQuantityManagementSystem,
 }
 impl A380Fuel {
-    pub(crate) fn new(context: &mut InitContext) -> Self {
+    pub(crate) fn new(
+        context: &mut InitContext,
+        acdn: &mut A380AvionicsDataCommunicationNetwork,
+    ) -> Self {
         Self {
             fuel_system: A380FuelSystem::new(context),
             fuel_quantity_data_concentrators: [
@@ -144,14 +154,14 @@ impl A380Fuel {
                 (2, ElectricalBusType::DirectCurrent(1)),
             ]
             .map(|(i, powered_by)| FuelQuantityDataConcentrator::new(context, i, powered_by)),
-            fuel_quantity_management_system: A380FuelQuantityManagementSystem::new(context),
+            fuel_quantity_management_system: A380FuelQuantityManagementSystem::new(context, acdn),
         }
     }
 
     pub(crate) fn update(
         &mut self,
         context: &UpdateContext,
-        acdn: &A380AvionicsDataCommunicationNetwork,
+        acdn: &mut A380AvionicsDataCommunicationNetwork,
         loadsheet: &LoadsheetInfo,
     ) {
         let cpioms = ["F1", "F2", "F3", "F4"].map(|id| acdn.get_cpiom(id));
@@ -165,6 +175,7 @@ impl A380Fuel {
             &self.fuel_quantity_data_concentrators,
             cpioms.map(|cpiom| cpiom.is_available()),
         );
+        self.fuel_quantity_management_system.send_fws_data(acdn);
     }
 
     pub(crate) fn feed_four_tank_has_fuel(&self) -> bool {
@@ -1,10 +1,20 @@
 use std::{collections::HashMap, time::Duration};
 
 use ntest::{assert_false, assert_true};
-use systems::{electrical::Electricity, fuel::RefuelRate, simulation::test::ReadByName};
-use uom::si::mass::kilogram;
+use systems::{
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    failures::FailureType,
+    fuel::RefuelRate,
+    shared::arinc429::SignStatus,
+    shared::PotentialOrigin,
+    simulation::test::ReadByName,
+};
+use uom::si::{electric_potential::volt, mass::kilogram};
 
-use super::*;
+use super::{
+    cpiom_f::{FuelJettisonFlags, FuelTransferFlags},
+    *,
+};
 use crate::{
     airframe::A380Airframe,
     systems::simulation::{
@@ -15,16 +25,41 @@ use crate::{
 struct FuelTestAircraft {
     acdn: A380AvionicsDataCommunicationNetwork,
     fuel: A380Fuel,
+    powered_source_dc: TestElectricitySource,
+    dc_502pp_bus: ElectricalBus,
+    dc_1_bus: ElectricalBus,
+    dc_2_bus: ElectricalBus,
+    is_cpiom_powered: bool,
+    is_dc_2_powered: bool,
 }
 
 impl FuelTestAircraft {
     fn new(context: &mut InitContext) -> Self {
+        let mut acdn = A380AvionicsDataCommunicationNetwork::new(context);
+        let fuel = A380Fuel::new(context, &mut acdn);
         Self {
-            acdn: A380AvionicsDataCommunicationNetwork::new(context),
-            fuel: A380Fuel::new(context),
+            acdn,
+            fuel,
+            powered_source_dc: TestElectricitySource::powered(context, PotentialOrigin::Battery(2)),
+            dc_502pp_bus: ElectricalBus::new(
+                context,
+                ElectricalBusType::DirectCurrentNamed("502PP"),
+            ),
+            dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+            dc_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+            is_cpiom_powered: false,
+            is_dc_2_powered: true,
         }
     }
 
+    fn set_cpiom_powered(&mut self, is_powered: bool) {
+        self.is_cpiom_powered = is_powered;
+    }
+
+    fn set_dc_2_powered(&mut self, is_powered: bool) {
+        self.is_dc_2_powered = is_powered;
+    }
+
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.fuel.fore_aft_center_of_gravity()
     }
@@ -37,12 +72,25 @@ impl FuelTestAircraft {
 impl Aircraft for FuelTestAircraft {
     fn update_before_power_distribution(
         &mut self,
-        context: &UpdateContext,
-        _electricity: &mut Electricity,
+        _: &UpdateContext,
+        electricity: &mut Electricity,
     ) {
-        self.acdn.update();
+        self.powered_source_dc
+            .power_with_potential(ElectricPotential::new::<volt>(28.));
+        electricity.supplied_by(&self.powered_source_dc);
+        if self.is_cpiom_powered {
+            electricity.flow(&self.powered_source_dc, &self.dc_502pp_bus);
+            electricity.flow(&self.powered_source_dc, &self.dc_1_bus);
+            if self.is_dc_2_powered {
+                electricity.flow(&self.powered_source_dc, &self.dc_2_bus);
+            }
+        }
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        self.acdn.update(context);
         self.fuel
-            .update(context, &self.acdn, A380Airframe::get_loadsheet());
+            .update(context, &mut self.acdn, A380Airframe::get_loadsheet());
     }
 }
 impl SimulationElement for FuelTestAircraft {
@@ -55,6 +103,12 @@ impl SimulationElement for FuelTestAircraft {
 }
 
 const MINUTES_TO_SECONDS: u64 = 60;
+const FEED_TANKS: [A380FuelTankType; 4] = [
+    A380FuelTankType::FeedOne,
+    A380FuelTankType::FeedTwo,
+    A380FuelTankType::FeedThree,
+    A380FuelTankType::FeedFour,
+];
 const FUEL_GALLONS_TO_KG: f64 = 3.039075693483925;
 
 struct FuelTestBed {
@@ -228,6 +282,115 @@ impl FuelTestBed {
         self.read_by_name("REFUEL_STARTED_BY_USR")
     }
 
+    fn in_flight_with_fqms_available(mut self) -> Self {
+        self.set_on_ground(false);
+        self.command(|a| a.set_cpiom_powered(true));
+        self.run_with_delta(Duration::from_secs(31));
+        self
+    }
+
+    fn transfer_pbs_auto(mut self) -> Self {
+        for tank in ["INRTK", "MIDTK", "OUTRTK", "TRIMTK"] {
+            self.write_by_name(&format!("OVHD_FUEL_{tank}_XFR_PB_IS_AUTO"), true);
+        }
+        self
+    }
+
+    fn transfer_pb_is_auto(mut self, tank: &str, is_auto: bool) -> Self {
+        self.write_by_name(&format!("OVHD_FUEL_{tank}_XFR_PB_IS_AUTO"), is_auto);
+        self
+    }
+
+    fn emergency_outer_tank_transfer_pb_on(mut self) -> Self {
+        self.write_by_name("OVHD_FUEL_EMER_OUTR_XFR_PB_IS_ON", true);
+        self
+    }
+
+    fn transfer_pumps_running(mut self) -> Self {
+        // Outer, mid, inner and trim tank pumps
+        for id in 9..=20 {
+            self.write_by_name(&format!("FUELSYSTEM PUMP ACTIVE:{id}"), true);
+        }
+        self
+    }
+
+    fn fms_zero_fuel_weight_and_cg(mut self, zero_fuel_weight_kg: f64, percent_mac: f64) -> Self {
+        self.write_arinc429_by_name(
+            "FM1_ZERO_FUEL_WEIGHT",
+            zero_fuel_weight_kg,
+            SignStatus::NormalOperation,
+        );
+        self.write_arinc429_by_name(
+            "FM1_ZERO_FUEL_WEIGHT_CG",
+            percent_mac,
+            SignStatus::NormalOperation,
+        );
+        self
+    }
+
+    fn tank_quantity(mut self, tank: A380FuelTankType, kg: f64) -> Self {
+        self.write_by_name(
+            &format!("FUEL_TANK_QUANTITY_{}", tank as usize + 1),
+            kg / FUEL_GALLONS_TO_KG,
+        );
+        self
+    }
+
+    fn jettison_pbs_on(mut self, arm: bool, active: bool) -> Self {
+        self.write_by_name("OVHD_FUEL_JETTISON_ARM_PB_IS_ON", arm);
+        self.write_by_name("OVHD_FUEL_JETTISON_ACTIVE_PB_IS_ON", active);
+        self
+    }
+
+    fn feed_pumps_running(mut self) -> Self {
+        for id in 1..=8 {
+            self.write_by_name(&format!("FUELSYSTEM PUMP ACTIVE:{id}"), true);
+        }
+        self
+    }
+
+    fn fms_weights(mut self, zero_fuel_weight_kg: f64, jettison_target_weight_kg: f64) -> Self {
+        self.write_arinc429_by_name(
+            "FM1_ZERO_FUEL_WEIGHT",
+            zero_fuel_weight_kg,
+            SignStatus::NormalOperation,
+        );
+        self.write_arinc429_by_name(
+            "FM1_JETTISON_TARGET_WEIGHT",
+            jettison_target_weight_kg,
+            SignStatus::NormalOperation,
+        );
+        self
+    }
+
+    fn fuel_jettison_status(&mut self) -> FuelJettisonFlags {
+        let word: Arinc429Word<u32> = self.read_arinc429_by_name("FQMS_FUEL_JETTISON_STATUS_WORD");
+        FuelJettisonFlags::from_bits_truncate(word.value() >> 11)
+    }
+
+    fn jettison_open_light_is_illuminated(&mut self) -> bool {
+        self.read_by_name("OVHD_FUEL_JETTISON_OPEN_IS_ILLUMINATED")
+    }
+
+    fn jettison_valve_open_percentage(&mut self, id: usize) -> f64 {
+        self.read_by_name(&format!("FUEL_JETTISON_VALVE_{id}_OPEN_PERCENTAGE"))
+    }
+
+    fn fuel_transfer_status(&mut self) -> FuelTransferFlags {
+        let word: Arinc429Word<u32> = self.read_arinc429_by_name("FQMS_FUEL_TRANSFER_STATUS_WORD");
+        FuelTransferFlags::from_bits_truncate(word.value() >> 11)
+    }
+
+    fn tank_quantity_kg(&self, tank: A380FuelTankType) -> f64 {
+        self.tank_mass(tank as usize).get::<kilogram>()
+    }
+
+    fn fuel_total_kg(&self) -> f64 {
+        A380FuelTankType::iterator()
+            .map(|tank| self.tank_quantity_kg(tank))
+            .sum()
+    }
+
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.query(|a: &FuelTestAircraft| a.fore_aft_center_of_gravity())
     }
@@ -565,3 +728,420 @@ fn spawn_high_fuel_load_desired_real_200000_done() {
     assert_fuel_quantity_200000(&test_bed);
     assert_false!(test_bed.refuel_status());
 }
+
+fn test_bed_in_flight() -> FuelTestBed {
+    test_bed()
+        .transfer_pbs_auto()
+        .transfer_pumps_running()
+        .in_flight_with_fqms_available()
+}
+
+#[test]
+fn feed_tanks_are_topped_up_from_inner_tanks_in_flight() {
+    let mut test_bed = test_bed_in_flight()
+        .tank_quantity(A380FuelTankType::FeedOne, 10000.)
+        .tank_quantity(A380FuelTankType::FeedTwo, 10000.)
+        .tank_quantity(A380FuelTankType::FeedThree, 10000.)
+        .tank_quantity(A380FuelTankType::FeedFour, 10000.)
+        .tank_quantity(A380FuelTankType::LeftInner, 5000.)
+        .tank_quantity(A380FuelTankType::RightInner, 5000.)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!(test_bed
+        .fuel_transfer_status()
+        .contains(FuelTransferFlags::FEED_TANK_TOP_UP));
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::LeftInner) < 1.);
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::RightInner) < 1.);
+    for tank in FEED_TANKS {
+        assert!((test_bed.tank_quantity_kg(tank) - 12500.).abs() < 1.);
+    }
+    assert!(!test_bed
+        .fuel_transfer_status()
+        .contains(FuelTransferFlags::FEED_TANK_TOP_UP));
+}
+
+#[test]
+fn feed_tanks_are_topped_up_from_mid_tanks_when_inner_tank_transfer_is_off() {
+    let test_bed = test_bed_in_flight()
+        .transfer_pb_is_auto("INRTK", false)
+        .tank_quantity(A380FuelTankType::FeedOne, 10000.)
+        .tank_quantity(A380FuelTankType::FeedTwo, 10000.)
+        .tank_quantity(A380FuelTankType::LeftInner, 5000.)
+        .tank_quantity(A380FuelTankType::LeftMid, 5000.)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::LeftInner) - 5000.).abs() < 1.);
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::LeftMid) < 5000.);
+}
+
+#[test]
+fn no_fuel_transfer_on_ground() {
+    let mut test_bed = test_bed_in_flight();
+    test_bed.set_on_ground(true);
+
+    let test_bed = test_bed
+        .tank_quantity(A380FuelTankType::FeedOne, 10000.)
+        .tank_quantity(A380FuelTankType::LeftInner, 5000.)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::FeedOne) - 10000.).abs() < 1.);
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::LeftInner) - 5000.).abs() < 1.);
+}
+
+#[test]
+fn no_fuel_transfer_without_fqms() {
+    let test_bed = test_bed()
+        .transfer_pbs_auto()
+        .transfer_pumps_running()
+        .tank_quantity(A380FuelTankType::FeedOne, 10000.)
+        .tank_quantity(A380FuelTankType::LeftInner, 5000.)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::FeedOne) - 10000.).abs() < 1.);
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::LeftInner) - 5000.).abs() < 1.);
+}
+
+#[test]
+fn transfer_pumps_off_indicate_auto_transfer_fault() {
+    let mut test_bed = test_bed()
+        .transfer_pbs_auto()
+        .in_flight_with_fqms_available()
+        .tank_quantity(A380FuelTankType::FeedOne, 10000.)
+        .tank_quantity(A380FuelTankType::LeftInner, 5000.)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::LeftInner) - 5000.).abs() < 1.);
+    assert!(test_bed
+        .fuel_transfer_status()
+        .contains(FuelTransferFlags::AUTO_TRANSFER_FAULT));
+}
+
+#[test]
+fn trim_tank_aft_transfer_when_below_target() {
+    // 101 t of fuel at 36 %MAC ZFWCG schedules 8.5 t of trim fuel
+    let mut test_bed = test_bed_in_flight()
+        .fms_zero_fuel_weight_and_cg(300000., 36.)
+        .tank_quantity(A380FuelTankType::FeedOne, 20000.)
+        .tank_quantity(A380FuelTankType::FeedTwo, 20000.)
+        .tank_quantity(A380FuelTankType::FeedThree, 20000.)
+        .tank_quantity(A380FuelTankType::FeedFour, 20000.)
+        .tank_quantity(A380FuelTankType::LeftInner, 10000.)
+        .tank_quantity(A380FuelTankType::RightInner, 10000.)
+        .tank_quantity(A380FuelTankType::Trim, 1000.)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::Trim) > 1500.);
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::LeftInner) < 10000.);
+    assert!(test_bed
+        .fuel_transfer_status()
+        .contains(FuelTransferFlags::TRIM_TANK_AFT_TRANSFER));
+}
+
+#[test]
+fn trim_tank_aft_transfer_stops_at_target() {
+    // 101 t of fuel at 41 %MAC ZFWCG schedules 2 t of trim fuel
+    let mut test_bed = test_bed_in_flight()
+        .fms_zero_fuel_weight_and_cg(300000., 41.)
+        .tank_quantity(A380FuelTankType::FeedOne, 20000.)
+        .tank_quantity(A380FuelTankType::FeedTwo, 20000.)
+        .tank_quantity(A380FuelTankType::FeedThree, 20000.)
+        .tank_quantity(A380FuelTankType::FeedFour, 20000.)
+        .tank_quantity(A380FuelTankType::LeftInner, 10000.)
+        .tank_quantity(A380FuelTankType::RightInner, 10000.)
+        .tank_quantity(A380FuelTankType::Trim, 1000.)
+        .run_multiple_frames(Duration::from_secs(5 * MINUTES_TO_SECONDS));
+
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::Trim) - 2000.).abs() < 10.);
+    assert!(!test_bed
+        .fuel_transfer_status()
+        .contains(FuelTransferFlags::TRIM_TANK_AFT_TRANSFER));
+}
+
+#[test]
+fn trim_tank_forward_transfer_when_above_target() {
+    // 65 t of fuel at 41 %MAC ZFWCG schedules no trim fuel
+    let mut test_bed = test_bed_in_flight()
+        .fms_zero_fuel_weight_and_cg(300000., 41.)
+        .tank_quantity(A380FuelTankType::FeedOne, 15000.)
+        .tank_quantity(A380FuelTankType::FeedTwo, 15000.)
+        .tank_quantity(A380FuelTankType::FeedThree, 15000.)
+        .tank_quantity(A380FuelTankType::FeedFour, 15000.)
+        .tank_quantity(A380FuelTankType::Trim, 5000.)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::Trim) < 4500.);
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::FeedOne) > 15000.);
+    assert!(test_bed
+        .fuel_transfer_status()
+        .contains(FuelTransferFlags::TRIM_TANK_FWD_TRANSFER));
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::Trim) < 1.);
+    assert!(!test_bed
+        .fuel_transfer_status()
+        .contains(FuelTransferFlags::TRIM_TANK_FWD_TRANSFER));
+}
+
+fn test_bed_with_trim_fuel_at_zero_fuel_weight_cg(percent_mac: f64) -> FuelTestBed {
+    test_bed_in_flight()
+        .fms_zero_fuel_weight_and_cg(300000., percent_mac)
+        .tank_quantity(A380FuelTankType::FeedOne, 20000.)
+        .tank_quantity(A380FuelTankType::FeedTwo, 20000.)
+        .tank_quantity(A380FuelTankType::FeedThree, 20000.)
+        .tank_quantity(A380FuelTankType::FeedFour, 20000.)
+        .tank_quantity(A380FuelTankType::LeftInner, 5000.)
+        .tank_quantity(A380FuelTankType::RightInner, 5000.)
+        .tank_quantity(A380FuelTankType::Trim, 5000.)
+        .run_multiple_frames(Duration::from_secs(10 * MINUTES_TO_SECONDS))
+}
+
+#[test]
+fn trim_tank_target_follows_zero_fuel_weight_cg() {
+    // 95 t of fuel schedules 8 t of trim fuel at 36 %MAC ZFWCG and 3 t at 40 %MAC
+    let forward_cg_test_bed = test_bed_with_trim_fuel_at_zero_fuel_weight_cg(36.);
+    let aft_cg_test_bed = test_bed_with_trim_fuel_at_zero_fuel_weight_cg(40.);
+
+    assert!((forward_cg_test_bed.tank_quantity_kg(A380FuelTankType::Trim) - 8000.).abs() < 10.);
+    assert!((aft_cg_test_bed.tank_quantity_kg(A380FuelTankType::Trim) - 3000.).abs() < 10.);
+}
+
+#[test]
+fn no_cg_control_without_fms_zero_fuel_weight() {
+    let test_bed = test_bed_in_flight()
+        .tank_quantity(A380FuelTankType::FeedOne, 15000.)
+        .tank_quantity(A380FuelTankType::FeedTwo, 15000.)
+        .tank_quantity(A380FuelTankType::FeedThree, 15000.)
+        .tank_quantity(A380FuelTankType::FeedFour, 15000.)
+        .tank_quantity(A380FuelTankType::Trim, 5000.)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::Trim) - 5000.).abs() < 1.);
+}
+
+#[test]
+fn trim_tank_transfer_off_inhibits_cg_control() {
+    let test_bed = test_bed_in_flight()
+        .transfer_pb_is_auto("TRIMTK", false)
+        .fms_zero_fuel_weight_and_cg(300000., 41.)
+        .tank_quantity(A380FuelTankType::FeedOne, 15000.)
+        .tank_quantity(A380FuelTankType::Trim, 5000.)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::Trim) - 5000.).abs() < 1.);
+}
+
+#[test]
+fn trim_tank_transfer_valve_failure_inhibits_cg_control() {
+    let mut test_bed = test_bed_in_flight()
+        .fms_zero_fuel_weight_and_cg(300000., 41.)
+        .tank_quantity(A380FuelTankType::FeedOne, 15000.)
+        .tank_quantity(A380FuelTankType::Trim, 5000.);
+    test_bed.fail(FailureType::TrimTankTransferValveStuckClosed);
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::Trim) - 5000.).abs() < 1.);
+    assert!(test_bed
+        .fuel_transfer_status()
+        .contains(FuelTransferFlags::TRIM_TANK_TRANSFER_FAULT));
+}
+
+#[test]
+fn trim_tank_fuel_is_transferred_at_end_of_flight() {
+    let mut test_bed = test_bed_in_flight()
+        .tank_quantity(A380FuelTankType::FeedOne, 5000.)
+        .tank_quantity(A380FuelTankType::FeedTwo, 5000.)
+        .tank_quantity(A380FuelTankType::FeedThree, 5000.)
+        .tank_quantity(A380FuelTankType::FeedFour, 5000.)
+        .tank_quantity(A380FuelTankType::Trim, 1000.)
+        .run_multiple_frames(Duration::from_secs(5 * MINUTES_TO_SECONDS));
+
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::Trim) < 1.);
+    for tank in FEED_TANKS {
+        assert!((test_bed.tank_quantity_kg(tank) - 5250.).abs() < 1.);
+    }
+    assert!(test_bed
+        .fuel_transfer_status()
+        .contains(FuelTransferFlags::TRIM_TANK_FUEL_TRANSFERRED));
+}
+
+#[test]
+fn outer_tank_fuel_is_transferred_when_a_feed_tank_is_low() {
+    let mut test_bed = test_bed_in_flight()
+        .tank_quantity(A380FuelTankType::FeedOne, 3500.)
+        .tank_quantity(A380FuelTankType::FeedTwo, 5000.)
+        .tank_quantity(A380FuelTankType::FeedThree, 5000.)
+        .tank_quantity(A380FuelTankType::FeedFour, 5000.)
+        .tank_quantity(A380FuelTankType::LeftOuter, 2000.)
+        .tank_quantity(A380FuelTankType::RightOuter, 2000.)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!(test_bed
+        .fuel_transfer_status()
+        .contains(FuelTransferFlags::OUTER_TANK_TRANSFER));
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(20 * MINUTES_TO_SECONDS));
+
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::LeftOuter) < 1.);
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::RightOuter) < 1.);
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::FeedOne) > 3500.);
+    assert!((test_bed.fuel_total_kg() - 22500.).abs() < 1.);
+    assert!(test_bed
+        .fuel_transfer_status()
+        .contains(FuelTransferFlags::OUTER_TANK_FUEL_TRANSFERRED));
+}
+
+#[test]
+fn emergency_outer_tank_transfer_works_without_fqms() {
+    let mut test_bed = test_bed();
+    test_bed.set_on_ground(false);
+
+    let test_bed = test_bed
+        .emergency_outer_tank_transfer_pb_on()
+        .tank_quantity(A380FuelTankType::FeedOne, 10000.)
+        .tank_quantity(A380FuelTankType::FeedFour, 10000.)
+        .tank_quantity(A380FuelTankType::LeftOuter, 2000.)
+        .tank_quantity(A380FuelTankType::RightOuter, 2000.)
+        .run_multiple_frames(Duration::from_secs(15 * MINUTES_TO_SECONDS));
+
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::LeftOuter) < 1.);
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::RightOuter) < 1.);
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::FeedOne) - 12000.).abs() < 1.);
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::FeedFour) - 12000.).abs() < 1.);
+}
+
+fn test_bed_jettisoning() -> FuelTestBed {
+    test_bed_in_flight()
+        .feed_pumps_running()
+        .tank_quantity(A380FuelTankType::FeedOne, 20000.)
+        .tank_quantity(A380FuelTankType::FeedTwo, 20000.)
+        .tank_quantity(A380FuelTankType::FeedThree, 20000.)
+        .tank_quantity(A380FuelTankType::FeedFour, 20000.)
+        .tank_quantity(A380FuelTankType::LeftMid, 10000.)
+        .tank_quantity(A380FuelTankType::RightMid, 10000.)
+        .tank_quantity(A380FuelTankType::LeftOuter, 2000.)
+        .tank_quantity(A380FuelTankType::RightOuter, 2000.)
+        .tank_quantity(A380FuelTankType::Trim, 5000.)
+}
+
+#[test]
+fn no_jettison_when_not_armed() {
+    let mut test_bed = test_bed_jettisoning()
+        .jettison_pbs_on(false, true)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!((test_bed.fuel_total_kg() - 109000.).abs() < 1.);
+    assert!(!test_bed
+        .fuel_jettison_status()
+        .contains(FuelJettisonFlags::IN_PROGRESS));
+    assert!(!test_bed.jettison_open_light_is_illuminated());
+}
+
+#[test]
+fn no_jettison_on_ground() {
+    let mut test_bed = test_bed_jettisoning();
+    test_bed.set_on_ground(true);
+
+    let test_bed = test_bed
+        .jettison_pbs_on(true, true)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!((test_bed.fuel_total_kg() - 109000.).abs() < 1.);
+}
+
+#[test]
+fn jettison_empties_mid_trim_and_feed_tanks_but_not_outer_tanks() {
+    let mut test_bed = test_bed_jettisoning()
+        .jettison_pbs_on(true, true)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    let status = test_bed.fuel_jettison_status();
+    assert!(status.contains(FuelJettisonFlags::ARMED | FuelJettisonFlags::IN_PROGRESS));
+    assert!(test_bed.jettison_open_light_is_illuminated());
+    assert!((test_bed.jettison_valve_open_percentage(1) - 100.).abs() < f64::EPSILON);
+    assert!((test_bed.jettison_valve_open_percentage(2) - 100.).abs() < f64::EPSILON);
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::LeftMid) < 10000.);
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::Trim) < 5000.);
+    // Roughly 38 kg/s from the mid, feed and trim tanks with all pumps running
+    let jettisoned = 109000. - test_bed.fuel_total_kg();
+    assert!(jettisoned > 2100. && jettisoned < 2300.);
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::LeftOuter) - 2000.).abs() < 1.);
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::RightOuter) - 2000.).abs() < 1.);
+}
+
+#[test]
+fn jettison_stops_at_minimum_feed_tank_quantity() {
+    let mut test_bed = test_bed_jettisoning()
+        .jettison_pbs_on(true, true)
+        .run_multiple_frames(Duration::from_secs(90 * MINUTES_TO_SECONDS));
+
+    for tank in FEED_TANKS {
+        assert!((test_bed.tank_quantity_kg(tank) - 4000.).abs() < 1.);
+    }
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::LeftMid) < 1.);
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::Trim) < 1.);
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::LeftOuter) - 2000.).abs() < 1.);
+
+    let status = test_bed.fuel_jettison_status();
+    assert!(status.contains(FuelJettisonFlags::FEED_TANK_MINIMUM_REACHED));
+    assert!(
+        !status.intersects(FuelJettisonFlags::IN_PROGRESS | FuelJettisonFlags::VALVE_NOT_CLOSED)
+    );
+    assert!(!test_bed.jettison_open_light_is_illuminated());
+}
+
+#[test]
+fn jettison_stops_at_fms_target_weight() {
+    let mut test_bed = test_bed_jettisoning()
+        .fms_weights(300000., 400000.)
+        .jettison_pbs_on(true, true)
+        .run_multiple_frames(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+
+    assert!((test_bed.fuel_total_kg() - 100000.).abs() < 100.);
+    let status = test_bed.fuel_jettison_status();
+    assert!(status.contains(FuelJettisonFlags::TARGET_REACHED));
+    assert!(!status.contains(FuelJettisonFlags::IN_PROGRESS));
+
+    // Cycling the ACTIVE pb does not restart the jettison below the target
+    test_bed = test_bed
+        .jettison_pbs_on(true, false)
+        .and_run()
+        .jettison_pbs_on(true, true)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!((test_bed.fuel_total_kg() - 100000.).abs() < 100.);
+}
+
+#[test]
+fn unpowered_jettison_valve_indicates_jettison_fault() {
+    let mut test_bed = test_bed_jettisoning();
+    test_bed.command(|a| a.set_dc_2_powered(false));
+
+    let mut test_bed = test_bed
+        .jettison_pbs_on(true, true)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!(test_bed.jettison_valve_open_percentage(2) < f64::EPSILON);
+    assert!(test_bed
+        .fuel_jettison_status()
+        .contains(FuelJettisonFlags::FAULT));
+    // Only the left wing and half of the trim tank fuel is jettisoned
+    assert!((test_bed.tank_quantity_kg(A380FuelTankType::RightMid) - 10000.).abs() < 1.);
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::LeftMid) < 10000.);
+}
+
+#[test]
+fn trim_tank_aft_transfer_is_inhibited_during_jettison() {
+    let mut test_bed = test_bed_jettisoning()
+        .fms_zero_fuel_weight_and_cg(300000., 36.)
+        .jettison_pbs_on(true, true)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!(!test_bed
+        .fuel_transfer_status()
+        .contains(FuelTransferFlags::TRIM_TANK_AFT_TRANSFER));
+    assert!(test_bed.tank_quantity_kg(A380FuelTankType::Trim) < 5000.);
+}
@@ -40,6 +40,7 @@ use systems::{
         },
         pumps::PumpCharacteristics,
         pushback::PushbackTug,
+        runway_friction::TyreRunwayFriction,
         trimmable_horizontal_stabilizer::{
             TrimmableHorizontalStabilizerActuator, TrimmableHorizontalStabilizerMotorController,
         },
@@ -52,10 +53,10 @@ use systems::{
     shared::{
         interpolation, random_from_range, update_iterator::MaxStepLoop, AdirsDiscreteOutputs,
         AdirsMeasurementOutputs, AirbusElectricPumpId, AirbusEngineDrivenPumpId, CargoDoorLocked,
-        ControllerSignal, DelayedFalseLogicGate, DelayedTrueLogicGate, ElectricalBusType,
-        ElectricalBuses, EngineFirePushButtons, GearWheel, HydraulicColor, LandingGearHandle,
-        LgciuInterface, LgciuWeightOnWheels, ReservoirAirPressure, SectionPressure,
-        SurfacesPositions,
+        CargoDoorOpenAmount, ControllerSignal, DelayedFalseLogicGate, DelayedTrueLogicGate,
+        ElectricalBusType, ElectricalBuses, EngineFirePushButtons, GearWheel, HydraulicColor,
+        LandingGearHandle, LgciuInterface, LgciuWeightOnWheels, ReservoirAirPressure,
+        SectionPressure, SurfacesPositions,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -887,7 +888,7 @@ impl A380ElevatorFactory {
     }
 }
 
-struct A380RudderFactory {}
+pub struct A380RudderFactory {}
 impl A380RudderFactory {
     const FLOW_CONTROL_PROPORTIONAL_GAIN: f64 = 5.;
     const FLOW_CONTROL_INTEGRAL_GAIN: f64 = 3.;
@@ -993,9 +994,9 @@ impl A380RudderFactory {
         )
     }
 
-    /// Builds an aileron assembly consisting of the aileron physical rigid body and two hydraulic actuators connected
-    /// to it
-    fn a380_rudder_assembly(
+    /// Builds a rudder assembly consisting of the rudder physical rigid body and two hydraulic actuators connected
+    /// to it, each with an electrical backup powered by the given bus
+    pub fn a380_rudder_assembly(
         context: &mut InitContext,
         init_at_center: bool,
         is_upper_body: bool,
@@ -1687,6 +1688,7 @@ pub(super) struct A380Hydraulic {
     braking_circuit_norm: BrakeCircuit,
     braking_circuit_altn: BrakeCircuit,
     braking_force: A380BrakingForce,
+    runway_friction: TyreRunwayFriction,
 
     flap_system: FlapSlatAssembly,
     slat_system: FlapSlatAssembly,
@@ -1749,6 +1751,8 @@ impl A380Hydraulic {
 
     const ELECTRIC_PUMP_MAX_CURRENT_AMPERE: f64 = 75.;
 
+    const MAIN_GEAR_TYRE_PRESSURE_PSI: f64 = 218.;
+
     const GREEN_ELEC_PUMP_CONTROL_POWER_BUS: ElectricalBusType =
         ElectricalBusType::DirectCurrent(2);
     const YELLOW_ELEC_PUMP_CONTROL_POWER_BUS: ElectricalBusType =
@@ -1996,6 +2000,10 @@ impl A380Hydraulic {
             ),
 
             braking_force: A380BrakingForce::new(context),
+            runway_friction: TyreRunwayFriction::new(
+                context,
+                Pressure::new::<psi>(Self::MAIN_GEAR_TYRE_PRESSURE_PSI),
+            ),
 
             flap_system: FlapSlatAssembly::new(
                 context,
@@ -2423,6 +2431,8 @@ impl A380Hydraulic {
         adirs: &impl AdirsMeasurementOutputs,
         lgcius: &LandingGearControlInterfaceUnitSet,
     ) {
+        self.runway_friction.update(context);
+
         self.eha_backup_inhibit_logic
             .update(context, lgciu1, lgciu2);
 
@@ -2479,6 +2489,7 @@ impl A380Hydraulic {
             self.left_spoilers.ground_spoilers_are_requested()
                 && self.right_spoilers.ground_spoilers_are_requested(),
             self.nose_steering.position_feedback(),
+            &self.runway_friction,
         );
 
         self.pushback_tug.update(context);
@@ -2526,6 +2537,8 @@ impl A380Hydraulic {
             engine1,
             engine2,
             &self.bypass_pin,
+            &self.runway_friction,
+            self.brake_steer_computer.anti_skid_is_active(),
         );
 
         self.slats_flaps_complex
@@ -3155,6 +3168,14 @@ impl CargoDoorLocked for A380Hydraulic {
         self.aft_cargo_door.is_locked()
     }
 }
+impl CargoDoorOpenAmount for A380Hydraulic {
+    fn fwd_cargo_door_open_amount(&self) -> Ratio {
+        self.forward_cargo_door.position()
+    }
+    fn aft_cargo_door_open_amount(&self) -> Ratio {
+        self.aft_cargo_door.position()
+    }
+}
 
 impl SimulationElement for A380Hydraulic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -3215,6 +3236,7 @@ impl SimulationElement for A380Hydraulic {
         self.braking_circuit_norm.accept(visitor);
         self.braking_circuit_altn.accept(visitor);
         self.braking_force.accept(visitor);
+        self.runway_friction.accept(visitor);
 
         self.nose_steering.accept(visitor);
         self.body_wheel_steering_left.accept(visitor);
@@ -4397,6 +4419,10 @@ impl A380HydraulicBrakeSteerComputerUnit {
         }
     }
 
+    fn anti_skid_is_active(&self) -> bool {
+        self.anti_skid_activated
+    }
+
     fn allow_autobrake_arming(&self, adirs: &impl AdirsMeasurementOutputs) -> bool {
         self.anti_skid_activated
             && self.normal_brakes_available
@@ -4460,6 +4486,7 @@ impl A380HydraulicBrakeSteerComputerUnit {
         adirs: &impl AdirsMeasurementOutputs,
         placeholder_ground_spoilers_out: bool,
         nose_steering_feedback: Angle,
+        runway_friction: &TyreRunwayFriction,
     ) {
         // TODO split steering part from braking part in two different computers instances
         self.update_steering_demands(
@@ -4485,6 +4512,7 @@ impl A380HydraulicBrakeSteerComputerUnit {
             lgciu1,
             lgciu2,
             placeholder_ground_spoilers_out,
+            runway_friction.max_braking_deceleration(),
         );
 
         let is_in_flight_gear_lever_up = !(lgciu1.left_and_right_gear_compressed(true)
@@ -4728,6 +4756,8 @@ impl A380BrakingForce {
         engine1: &impl Engine,
         engine2: &impl Engine,
         bypass_pin: &BypassPin,
+        runway_friction: &TyreRunwayFriction,
+        anti_skid_is_active: bool,
     ) {
         // Base formula for output force is output_force[0:1] = 50 * sqrt(current_pressure) / Max_brake_pressure
         // This formula gives a bit more punch for lower brake pressures (like 1000 psi alternate braking), as linear formula
@@ -4747,6 +4777,12 @@ impl A380BrakingForce {
         self.right_braking_force = right_force_norm + right_force_altn;
         self.right_braking_force = self.right_braking_force.clamp(0., 1.);
 
+        // Tyres can't transmit more braking force than the runway friction allows
+        self.left_braking_force =
+            runway_friction.braking_force(self.left_braking_force, anti_skid_is_active);
+        self.right_braking_force =
+            runway_friction.braking_force(self.right_braking_force, anti_skid_is_active);
+
         self.correct_with_flaps_state(context);
 
         self.update_chocks_braking(context, engine1, engine2, bypass_pin);
@@ -6464,6 +6500,10 @@ impl AileronAssembly {
         for idx in 0..3 {
             self.aerodynamic_models[idx]
                 .update_body(context, self.hydraulic_assemblies[idx].body());
+            self.hydraulic_assemblies[idx].update_fluid_viscosity([
+                current_pressure_outward[idx].fluid_viscosity(),
+                current_pressure_inward[idx].fluid_viscosity(),
+            ]);
             self.hydraulic_assemblies[idx].update(
                 context,
                 controllers[idx],
@@ -6571,6 +6611,10 @@ impl ElevatorAssembly {
             self.aerodynamic_models[idx]
                 .update_body(context, self.hydraulic_assemblies[idx].body());
 
+            self.hydraulic_assemblies[idx].update_fluid_viscosity([
+                current_pressure_outward[idx].fluid_viscosity(),
+                current_pressure_inward[idx].fluid_viscosity(),
+            ]);
             self.hydraulic_assemblies[idx].update(
                 context,
                 elevator_controllers[idx],
@@ -6672,6 +6716,10 @@ impl RudderAssembly {
             self.aerodynamic_models[idx]
                 .update_body(context, self.hydraulic_assemblies[idx].body());
 
+            self.hydraulic_assemblies[idx].update_fluid_viscosity([
+                current_pressure_upper[idx].fluid_viscosity(),
+                current_pressure_lower[idx].fluid_viscosity(),
+            ]);
             self.hydraulic_assemblies[idx].update(
                 context,
                 rudder_controllers[idx],
@@ -6754,14 +6802,16 @@ impl SpoilerElement {
         spoiler_controller: &(impl HydraulicAssemblyController
               + HydraulicLocking
               + ElectroHydrostaticPowered),
-        current_pressure: Pressure,
+        current_pressure: &impl SectionPressure,
     ) {
         self.aerodynamic_model
             .update_body(context, self.hydraulic_assembly.body());
+        self.hydraulic_assembly
+            .update_fluid_viscosity([current_pressure.fluid_viscosity()]);
         self.hydraulic_assembly.update(
             context,
             std::slice::from_ref(spoiler_controller),
-            [current_pressure],
+            [current_pressure.pressure_downstream_leak_valve()],
         );
 
         self.position = self.hydraulic_assembly.position_normalized();
@@ -6823,46 +6873,14 @@ impl SpoilerGroup {
         self.eha_backup_allowed = eha_backup_placeholder.is_allowed();
         self.hydraulic_controllers[5].set_elec_backup_allowed(self.eha_backup_allowed);
 
-        self.spoilers[0].update(
-            context,
-            &self.hydraulic_controllers[0],
-            yellow_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[1].update(
-            context,
-            &self.hydraulic_controllers[1],
-            green_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[2].update(
-            context,
-            &self.hydraulic_controllers[2],
-            yellow_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[3].update(
-            context,
-            &self.hydraulic_controllers[3],
-            green_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[4].update(
-            context,
-            &self.hydraulic_controllers[4],
-            yellow_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[5].update(
-            context,
-            &self.hydraulic_controllers[5],
-            green_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[6].update(
-            context,
-            &self.hydraulic_controllers[6],
-            yellow_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[7].update(
-            context,
-            &self.hydraulic_controllers[7],
-            green_section.pressure_downstream_leak_valve(),
-        );
+        self.spoilers[0].update(context, &self.hydraulic_controllers[0], yellow_section);
+        self.spoilers[1].update(context, &self.hydraulic_controllers[1], green_section);
+        self.spoilers[2].update(context, &self.hydraulic_controllers[2], yellow_section);
+        self.spoilers[3].update(context, &self.hydraulic_controllers[3], green_section);
+        self.spoilers[4].update(context, &self.hydraulic_controllers[4], yellow_section);
+        self.spoilers[5].update(context, &self.hydraulic_controllers[5], green_section);
+        self.spoilers[6].update(context, &self.hydraulic_controllers[6], yellow_section);
+        self.spoilers[7].update(context, &self.hydraulic_controllers[7], green_section);
 
         self.spoiler_positions = [
             self.spoilers[0].position(),
@@ -7109,7 +7127,10 @@ mod tests {
             },
             engine::{trent_engine::TrentEngine, EngineFireOverheadPanel},
             failures::FailureType,
-            hydraulic::cargo_doors::{DoorControlState, HydraulicDoorController},
+            hydraulic::{
+                cargo_doors::{DoorControlState, HydraulicDoorController},
+                runway_friction::RunwayContaminant,
+            },
             landing_gear::{GearSystemState, LandingGear, LandingGearControlInterfaceUnitSet},
             shared::{
                 arinc429::{Arinc429Word, SignStatus},
@@ -7122,6 +7143,7 @@ mod tests {
         };
 
         use uom::si::{
+            acceleration::meter_per_second_squared,
             angle::degree,
             electric_potential::volt,
             length::foot,
@@ -7378,6 +7400,13 @@ mod tests {
                 self.adirus.set_aligned(is_aligned)
             }
 
+            fn autobrake_target(&self) -> Acceleration {
+                self.hydraulics
+                    .brake_steer_computer
+                    .autobrake_controller
+                    .target()
+            }
+
             fn is_green_edp_commanded_on(&self) -> bool {
                 self.hydraulics
                     .engine_driven_pump_1a_controller
@@ -7645,6 +7674,10 @@ mod tests {
                 self.command(|a| a.set_adirs_alignement(false));
             }
 
+            fn autobrake_target(&self) -> Acceleration {
+                self.query(|a| a.autobrake_target())
+            }
+
             fn is_green_edp_commanded_on(&self) -> bool {
                 self.query(|a| a.is_green_edp_commanded_on())
             }
@@ -8399,6 +8432,11 @@ mod tests {
                 self
             }
 
+            fn set_runway_contaminant(mut self, contaminant: RunwayContaminant) -> Self {
+                self.write_by_name("RUNWAY_CONTAMINANT_SELECTED", contaminant as u8 as f64);
+                self
+            }
+
             fn set_autobrake_rto(mut self) -> Self {
                 self.write_by_name("OVHD_AUTOBRK_RTO_ARM_IS_PRESSED", true);
                 self = self.run_one_tick();
@@ -10295,6 +10333,64 @@ mod tests {
             assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(50.));
         }
 
+        #[test]
+        fn autobrake_rto_target_not_limited_on_dry_runway() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            test_bed = test_bed
+                .set_runway_contaminant(RunwayContaminant::Dry)
+                .set_autobrake_rto()
+                .run_waiting_for(Duration::from_secs(1));
+
+            test_bed = test_bed
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == A380AutobrakeMode::RTO);
+            assert!(
+                (test_bed
+                    .autobrake_target()
+                    .get::<meter_per_second_squared>()
+                    + 6.)
+                    .abs()
+                    < 0.01
+            );
+        }
+
+        #[test]
+        fn autobrake_rto_target_limited_on_icy_runway() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            test_bed = test_bed
+                .set_runway_contaminant(RunwayContaminant::Ice)
+                .set_autobrake_rto()
+                .run_waiting_for(Duration::from_secs(1));
+
+            test_bed = test_bed
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == A380AutobrakeMode::RTO);
+            assert!(
+                test_bed
+                    .autobrake_target()
+                    .get::<meter_per_second_squared>()
+                    > -2.
+            );
+        }
+
         #[test]
         fn autobrakes_activates_on_ground_and_deactivates_pressing_rto_again() {
             let mut test_bed = test_bed_on_ground_with()
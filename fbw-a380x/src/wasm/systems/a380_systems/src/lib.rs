@@ -4,6 +4,7 @@ mod air_conditioning;
 mod airframe;
 mod avionics_data_communication_network;
 mod control_display_system;
+pub mod doors;
 mod electrical;
 mod fire_and_smoke_protection;
 mod fuel;
@@ -20,6 +21,7 @@ use self::{
     air_conditioning::{A380AirConditioning, A380PressurizationOverheadPanel},
     avionics_data_communication_network::A380AvionicsDataCommunicationNetwork,
     control_display_system::A380ControlDisplaySystem,
+    doors::A380Doors,
     fuel::A380Fuel,
     pneumatic::{A380Pneumatic, A380PneumaticOverheadPanel},
     structural_flex::A380StructuralFlex,
@@ -33,11 +35,17 @@ use electrical::{
 use fire_and_smoke_protection::A380FireAndSmokeProtection;
 use hydraulic::{autobrakes::A380AutobrakePanel, A380Hydraulic, A380HydraulicOverheadPanel};
 use icing::Icing;
-use navigation::{A380AirDataInertialReferenceSystemBuilder, A380RadioAltimeters};
+use navigation::{
+    A380AirDataInertialReferenceSystemBuilder, A380MultiModeReceivers, A380RadioAltimeters,
+};
 use payload::A380Payload;
 use power_consumption::A380PowerConsumption;
 use reverser::{A380ReverserController, A380Reversers};
-use uom::si::{f64::Length, length::nautical_mile};
+use uom::si::{
+    f64::{Force, Length},
+    force::kilonewton,
+    length::nautical_mile,
+};
 
 use systems::{
     accept_iterable,
@@ -48,6 +56,7 @@ use systems::{
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
     engine::{reverser_thrust::ReverserForce, trent_engine::TrentEngine, EngineFireOverheadPanel},
     enhanced_gpwc::EnhancedGroundProximityWarningComputer,
+    indicating_recording::load_report::StructuralLoadMonitor,
     landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
     navigation::adirs::{
         AirDataInertialReferenceSystem, AirDataInertialReferenceSystemOverheadPanel,
@@ -56,6 +65,7 @@ use systems::{
     simulation::{
         Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
     },
+    surveillance::tcas::SimulatorTrafficCollisionAvoidanceSystem,
 };
 
 pub struct A380 {
@@ -71,6 +81,7 @@ pub struct A380 {
     pressurization_overhead: A380PressurizationOverheadPanel,
     electrical_overhead: A380ElectricalOverheadPanel,
     emergency_electrical_overhead: A380EmergencyElectricalOverheadPanel,
+    doors: A380Doors,
     payload: A380Payload,
     airframe: A380Airframe,
     fire_and_smoke_protection: A380FireAndSmokeProtection,
@@ -90,20 +101,26 @@ pub struct A380 {
     landing_gear: LandingGear,
     pneumatic: A380Pneumatic,
     radio_altimeters: A380RadioAltimeters,
+    mmrs: A380MultiModeReceivers,
+    tcas: SimulatorTrafficCollisionAvoidanceSystem,
     cds: A380ControlDisplaySystem,
     egpwc: EnhancedGroundProximityWarningComputer,
     icing_simulation: Icing,
     structural_flex: A380StructuralFlex,
+    structural_load_monitor: StructuralLoadMonitor,
 
     engine_reverser_control: [A380ReverserController; 2],
     reversers_assembly: A380Reversers,
     reverse_thrust: ReverserForce,
 }
 impl A380 {
+    const MAX_REVERSE_THRUST_KILONEWTON: f64 = 65.;
+
     pub fn new(context: &mut InitContext) -> A380 {
         let mut adcn = A380AvionicsDataCommunicationNetwork::new(context);
         let adcn_simvar_translation =
             A380AvionicsDataCommunicationNetworkSimvarTranslator::new(context, &mut adcn);
+        let fuel = A380Fuel::new(context, &mut adcn);
         A380 {
             adcn,
             adcn_simvar_translation,
@@ -122,10 +139,11 @@ impl A380 {
             pressurization_overhead: A380PressurizationOverheadPanel::new(context),
             electrical_overhead: A380ElectricalOverheadPanel::new(context),
             emergency_electrical_overhead: A380EmergencyElectricalOverheadPanel::new(context),
+            doors: A380Doors::new(context),
             payload: A380Payload::new(context),
             airframe: A380Airframe::new(context),
             fire_and_smoke_protection: A380FireAndSmokeProtection::new(context),
-            fuel: A380Fuel::new(context),
+            fuel,
             engine_1: TrentEngine::new(context, 1),
             engine_2: TrentEngine::new(context, 2),
             engine_3: TrentEngine::new(context, 3),
@@ -145,6 +163,11 @@ impl A380 {
             landing_gear: LandingGear::new(context, true),
             pneumatic: A380Pneumatic::new(context),
             radio_altimeters: A380RadioAltimeters::new(context),
+            mmrs: A380MultiModeReceivers::new(context),
+            tcas: SimulatorTrafficCollisionAvoidanceSystem::new(
+                context,
+                ElectricalBusType::AlternatingCurrentEssential,
+            ),
             cds: A380ControlDisplaySystem::new(context),
             egpwc: EnhancedGroundProximityWarningComputer::new(
                 context,
@@ -164,12 +187,19 @@ impl A380 {
 
             icing_simulation: Icing::new(context),
             structural_flex: A380StructuralFlex::new(context),
+            structural_load_monitor: StructuralLoadMonitor::new(
+                context,
+                A380Airframe::get_structural_limits(),
+            ),
             engine_reverser_control: [
                 A380ReverserController::new(context, 2),
                 A380ReverserController::new(context, 3),
             ],
             reversers_assembly: A380Reversers::new(context),
-            reverse_thrust: ReverserForce::new(context),
+            reverse_thrust: ReverserForce::new(
+                context,
+                Force::new::<kilonewton>(Self::MAX_REVERSE_THRUST_KILONEWTON),
+            ),
         }
     }
 }
@@ -184,7 +214,8 @@ impl Aircraft for A380 {
             &self.apu_overhead,
             self.fire_and_smoke_protection.apu_fire_on_ground(),
             &self.apu_fire_overhead,
-            self.pneumatic_overhead.apu_bleed_is_on(),
+            self.pneumatic_overhead.apu_bleed_is_on()
+                && !self.pneumatic.apu_bleed_leak_isolation_commanded(),
             // This will be replaced when integrating the whole electrical system.
             // For now we use the same logic as found in the JavaScript code; ignoring whether or not
             // the engine generators are supplying electricity.
@@ -218,7 +249,10 @@ impl Aircraft for A380 {
             .update_after_electrical(&self.electrical, electricity);
         self.emergency_electrical_overhead
             .update_after_electrical(context, &self.electrical);
-        self.payload.update(context);
+        self.doors
+            .update(context, &self.air_conditioning, &self.hydraulic);
+        self.payload
+            .update(context, A380Airframe::get_loadsheet(), &self.doors);
         self.airframe
             .update(&self.fuel, &self.payload, &self.payload);
     }
@@ -235,7 +269,7 @@ impl Aircraft for A380 {
         );
         self.apu_overhead.update_after_apu(&self.apu);
 
-        self.adcn.update();
+        self.adcn.update(context);
         self.adcn_simvar_translation.update(&self.adcn);
         self.lgcius.update(
             context,
@@ -251,6 +285,7 @@ impl Aircraft for A380 {
         );
 
         self.radio_altimeters.update(context);
+        self.mmrs.update(context);
 
         self.hydraulic.update(
             context,
@@ -278,6 +313,12 @@ impl Aircraft for A380 {
         self.adirs.update(context, &self.adirs_overhead);
         self.adirs_overhead.update(context, &self.adirs);
 
+        self.tcas.update(
+            context,
+            self.adirs.adr_bus(1),
+            self.radio_altimeters.radio_altimeter_1(),
+        );
+
         self.power_consumption.update(context);
 
         self.pneumatic.update(
@@ -328,13 +369,19 @@ impl Aircraft for A380 {
             &self.hydraulic,
             &self.fuel,
         );
+        self.structural_load_monitor.update(
+            context,
+            self.airframe.weight(),
+            &self.hydraulic,
+            self.lgcius.lgciu1(),
+        );
         self.cds.update();
 
         self.icing_simulation.update(context);
 
         self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
         self.fuel
-            .update(context, &self.adcn, A380Airframe::get_loadsheet());
+            .update(context, &mut self.adcn, A380Airframe::get_loadsheet());
 
         self.engine_reverser_control[0].update(
             &self.engine_2,
@@ -371,6 +418,7 @@ impl SimulationElement for A380 {
         self.emergency_electrical_overhead.accept(visitor);
         self.fire_and_smoke_protection.accept(visitor);
         self.fuel.accept(visitor);
+        self.doors.accept(visitor);
         self.payload.accept(visitor);
         self.airframe.accept(visitor);
         self.pneumatic_overhead.accept(visitor);
@@ -385,6 +433,8 @@ impl SimulationElement for A380 {
         accept_iterable!(self.ext_pwrs, visitor);
         self.lgcius.accept(visitor);
         self.radio_altimeters.accept(visitor);
+        self.mmrs.accept(visitor);
+        self.tcas.accept(visitor);
         self.autobrake_panel.accept(visitor);
         self.hydraulic.accept(visitor);
         self.hydraulic_overhead.accept(visitor);
@@ -394,6 +444,7 @@ impl SimulationElement for A380 {
         self.egpwc.accept(visitor);
         self.icing_simulation.accept(visitor);
         self.structural_flex.accept(visitor);
+        self.structural_load_monitor.accept(visitor);
 
         accept_iterable!(self.engine_reverser_control, visitor);
         self.reversers_assembly.accept(visitor);
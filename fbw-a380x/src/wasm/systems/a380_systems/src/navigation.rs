@@ -5,7 +5,8 @@ use systems::navigation::adirs::{
 use systems::navigation::ala52b::{
     Ala52BAircraftInstallationDelay, Ala52BRadioAltimeter, Ala52BTransceiverPair,
 };
-use systems::navigation::radio_altimeter::AntennaInstallation;
+use systems::navigation::ils::{MultiModeReceiver, TunedIlsNavaid};
+use systems::navigation::radio_altimeter::{AntennaInstallation, RadioAltimeter};
 use systems::shared::{ElectricalBusType, MachNumber};
 use systems::simulation::{
     InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
@@ -44,6 +45,50 @@ impl A380AirDataInertialReferenceSystemBuilder {
     }
 }
 
+pub struct A380MultiModeReceivers {
+    mmr_1: MultiModeReceiver,
+    mmr_1_navaid: TunedIlsNavaid,
+    mmr_2: MultiModeReceiver,
+    mmr_2_navaid: TunedIlsNavaid,
+}
+impl A380MultiModeReceivers {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            mmr_1: MultiModeReceiver::new(
+                context,
+                1,
+                ElectricalBusType::AlternatingCurrentEssential,
+            ),
+            mmr_1_navaid: TunedIlsNavaid::new(context, 1),
+            mmr_2: MultiModeReceiver::new(context, 2, ElectricalBusType::AlternatingCurrent(4)),
+            mmr_2_navaid: TunedIlsNavaid::new(context, 2),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.mmr_1.update(
+            context,
+            self.mmr_1_navaid.tuned_frequency(),
+            &self.mmr_1_navaid,
+        );
+        self.mmr_2.update(
+            context,
+            self.mmr_2_navaid.tuned_frequency(),
+            &self.mmr_2_navaid,
+        );
+    }
+}
+impl SimulationElement for A380MultiModeReceivers {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.mmr_1.accept(visitor);
+        self.mmr_1_navaid.accept(visitor);
+        self.mmr_2.accept(visitor);
+        self.mmr_2_navaid.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 pub struct A380RadioAltimeters {
     radio_altimeter_1: A380RadioAltimeter,
     radio_altimeter_2: A380RadioAltimeter,
@@ -109,6 +154,10 @@ impl A380RadioAltimeters {
         self.radio_altimeter_2.update(context);
         self.radio_altimeter_3.update(context);
     }
+
+    pub fn radio_altimeter_1(&self) -> &impl RadioAltimeter {
+        &self.radio_altimeter_1.radio_altimeter
+    }
 }
 
 impl SimulationElement for A380RadioAltimeters {
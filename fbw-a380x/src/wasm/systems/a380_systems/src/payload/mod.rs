@@ -6,12 +6,15 @@ use uom::si::{f64::Mass, mass::kilogram};
 
 use systems::{
     payload::{
-        BoardingAgent, BoardingSounds, Cargo, CargoDeck, CargoInfo, CargoPayload,
-        NumberOfPassengers, PassengerDeck, PassengerPayload, Pax, PaxInfo, PayloadManager,
+        BoardingAgent, BoardingSounds, Cargo, CargoDeck, CargoInfo, CargoLoadPlanner, CargoPayload,
+        CombinedPositionLimitInfo, LoadsheetInfo, NumberOfPassengers, PassengerDeck,
+        PassengerPayload, Pax, PaxInfo, PayloadManager, UldPositionInfo, UldType,
     },
     simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 
+use crate::{airframe::A380Airframe, doors::A380Doors};
+
 #[cfg(test)]
 mod test;
 
@@ -57,13 +60,12 @@ impl From<usize> for A380Pax {
         }
     }
 }
-#[cfg(test)]
+#[derive(Clone, Copy)]
 pub enum A380Cargo {
     Fwd,
     Aft,
     Bulk,
 }
-#[cfg(test)]
 impl From<A380Cargo> for usize {
     fn from(value: A380Cargo) -> Self {
         value as usize
@@ -82,6 +84,7 @@ impl From<usize> for A380Cargo {
 }
 pub struct A380Payload {
     payload_manager: PayloadManager<14, 5, 3>,
+    cargo_load_planner: CargoLoadPlanner,
 }
 impl A380Payload {
     // Note: These constants reflect flight_model.cfg values and will have to be updated in sync with the configuration
@@ -201,6 +204,20 @@ impl A380Payload {
             payload_id: "PAYLOAD_STATION_17_REQ",
         },
     ];
+    // LD3 positions, from front to back, as pairs of left and right positions
+    const A380_FWD_ULD_POSITIONS: [&'static str; 22] = [
+        "11L", "11R", "12L", "12R", "13L", "13R", "14L", "14R", "15L", "15R", "16L", "16R", "17L",
+        "17R", "18L", "18R", "19L", "19R", "20L", "20R", "21L", "21R",
+    ];
+    const A380_AFT_ULD_POSITIONS: [&'static str; 16] = [
+        "31L", "31R", "32L", "32R", "33L", "33R", "34L", "34R", "35L", "35R", "36L", "36R", "37L",
+        "37R", "38L", "38R",
+    ];
+    // The holds take either LD3 containers or 96 x 125 in pallets over the same floor area
+    const A380_FWD_PALLET_POSITIONS: [&'static str; 7] =
+        ["11P", "12P", "13P", "14P", "15P", "16P", "17P"];
+    const A380_AFT_PALLET_POSITIONS: [&'static str; 5] = ["31P", "32P", "33P", "34P", "35P"];
+    const ULD_ROW_PITCH: f64 = 5.2;
 
     pub fn new(context: &mut InitContext) -> Self {
         let per_pax_weight = Rc::new(Cell::new(Mass::new::<kilogram>(
@@ -233,33 +250,30 @@ impl A380Payload {
         let default_boarding_agent =
             BoardingAgent::new(None, [10, 13, 12, 11, 1, 0, 9, 8, 7, 6, 5, 4, 3, 2]);
 
+        // The boarding doors are provided by the door system, see `A380Doors::boarding_door_open_amounts`.
         // TODO: Move into a toml cfg
         let boarding_agents = [
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:0".to_owned())),
-                [10, 13, 12, 11, 1, 0, 9, 8, 7, 6, 5, 4, 3, 2],
-            ), // M1L
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:2".to_owned())),
-                [1, 0, 9, 8, 7, 6, 5, 2, 3, 4, 10, 11, 12, 13],
-            ), // M2L
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:6".to_owned())),
-                [13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
-            ), // M4L
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:8".to_owned())),
-                [13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
-            ), // M5L
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:10".to_owned())),
-                [10, 13, 12, 11, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
-            ), // U1L
+            BoardingAgent::new(None, [10, 13, 12, 11, 1, 0, 9, 8, 7, 6, 5, 4, 3, 2]), // M1L
+            BoardingAgent::new(None, [1, 0, 9, 8, 7, 6, 5, 2, 3, 4, 10, 11, 12, 13]), // M2L
+            BoardingAgent::new(None, [13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0]), // M4L
+            BoardingAgent::new(None, [13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0]), // M5L
+            BoardingAgent::new(None, [10, 13, 12, 11, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0]), // U1L
         ];
 
         let passenger_deck = PassengerDeck::new(pax, default_boarding_agent, boarding_agents);
         let cargo_deck = CargoDeck::new(cargo);
 
+        let (uld_positions, combined_position_limits) = Self::uld_positions();
+        let cargo_load_planner = CargoLoadPlanner::new(
+            context,
+            &uld_positions,
+            &combined_position_limits,
+            Self::A380_CARGO
+                .iter()
+                .map(|c| Mass::new::<kilogram>(c.max_cargo_kg))
+                .collect(),
+        );
+
         A380Payload {
             payload_manager: PayloadManager::new(
                 context,
@@ -271,11 +285,149 @@ impl A380Payload {
                 500,
                 5000,
             ),
+            cargo_load_planner,
         }
     }
 
-    pub(crate) fn update(&mut self, context: &UpdateContext) {
+    fn uld_positions() -> (
+        Vec<UldPositionInfo<'static>>,
+        Vec<CombinedPositionLimitInfo<'static>>,
+    ) {
+        let mut positions = Vec::new();
+        let mut combined_limits = Vec::new();
+        for (container_positions, pallet_positions, cargo) in [
+            (
+                &Self::A380_FWD_ULD_POSITIONS[..],
+                &Self::A380_FWD_PALLET_POSITIONS[..],
+                A380Cargo::Fwd,
+            ),
+            (
+                &Self::A380_AFT_ULD_POSITIONS[..],
+                &Self::A380_AFT_PALLET_POSITIONS[..],
+                A380Cargo::Aft,
+            ),
+        ] {
+            let (hold_positions, hold_combined_limits) =
+                Self::hold_positions(container_positions, pallet_positions, cargo);
+            positions.extend(hold_positions);
+            combined_limits.extend(hold_combined_limits);
+        }
+
+        let bulk = &Self::A380_CARGO[usize::from(A380Cargo::Bulk)];
+        positions.push(UldPositionInfo {
+            position_id: "BULK",
+            cargo_station: A380Cargo::Bulk.into(),
+            uld_type: UldType::Bulk,
+            position: bulk.position.0,
+            max_mass_kg: bulk.max_cargo_kg,
+        });
+
+        (positions, combined_limits)
+    }
+
+    /// Lays out the container rows and the pallet positions evenly over the length of a hold.
+    /// Each pallet position shares a combined position limit with the containers it overlaps.
+    fn hold_positions(
+        container_positions: &[&'static str],
+        pallet_positions: &[&'static str],
+        cargo: A380Cargo,
+    ) -> (
+        Vec<UldPositionInfo<'static>>,
+        Vec<CombinedPositionLimitInfo<'static>>,
+    ) {
+        let cs: usize = cargo.into();
+        let center = Self::A380_CARGO[cs].position.0;
+        let rows = container_positions.len() / 2;
+        let hold_length = rows as f64 * Self::ULD_ROW_PITCH;
+        let pallet_pitch = hold_length / pallet_positions.len() as f64;
+        // Positions are centered in their slot, the first slot being the most forward one
+        let slot_position = |slot: usize, slots: usize, pitch: f64| {
+            center + ((slots - 1) as f64 / 2. - slot as f64) * pitch
+        };
+
+        let containers = container_positions
+            .iter()
+            .enumerate()
+            .map(|(i, &position_id)| UldPositionInfo {
+                position_id,
+                cargo_station: cs,
+                uld_type: UldType::Ake,
+                position: slot_position(i / 2, rows, Self::ULD_ROW_PITCH),
+                max_mass_kg: UldType::Ake.max_gross_mass().get::<kilogram>(),
+            });
+        let pallets = pallet_positions
+            .iter()
+            .enumerate()
+            .map(|(i, &position_id)| UldPositionInfo {
+                position_id,
+                cargo_station: cs,
+                uld_type: UldType::Pmc,
+                position: slot_position(i, pallet_positions.len(), pallet_pitch),
+                max_mass_kg: UldType::Pmc.max_gross_mass().get::<kilogram>(),
+            });
+        let positions: Vec<_> = containers.chain(pallets).collect();
+
+        // A container row overlaps a pallet slot when they share a part of the hold length. Slot
+        // boundaries are compared as exact fractions of the hold length, as the float positions
+        // of touching slots may round into each other.
+        let slots = pallet_positions.len();
+        let combined_limits = (0..slots)
+            .map(|slot| CombinedPositionLimitInfo {
+                position_ids: container_positions
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| {
+                        let row = i / 2;
+                        row * slots < (slot + 1) * rows && slot * rows < (row + 1) * slots
+                    })
+                    .map(|(_, &position_id)| position_id)
+                    .chain([pallet_positions[slot]])
+                    .collect(),
+                max_mass_kg: UldType::Pmc.max_gross_mass().get::<kilogram>(),
+            })
+            .collect();
+
+        (positions, combined_limits)
+    }
+
+    pub(crate) fn update(
+        &mut self,
+        context: &UpdateContext,
+        loadsheet: &LoadsheetInfo,
+        doors: &A380Doors,
+    ) {
+        self.payload_manager
+            .update_boarding_doors(doors.boarding_door_open_amounts());
         self.payload_manager.update(context.delta());
+
+        if self.cargo_load_planner.is_plan_requested() {
+            self.plan_cargo(loadsheet);
+        }
+    }
+
+    /// Distributes the planned cargo over the cargo stations, aiming for the desired ZFW CG.
+    fn plan_cargo(&mut self, loadsheet: &LoadsheetInfo) {
+        let cargo = self.cargo_load_planner.total_cargo_desired();
+        let target_position = self
+            .cargo_load_planner
+            .zero_fuel_weight_cg_desired()
+            .filter(|_| cargo > Mass::default())
+            .map(|zero_fuel_weight_cg| {
+                let operating_empty_weight = loadsheet.operating_empty_weight_kg;
+                let pax = self.total_target_passenger_load().get::<kilogram>();
+                let zero_fuel_weight = operating_empty_weight + pax + cargo.get::<kilogram>();
+
+                (A380Airframe::convert_cg_to_position(zero_fuel_weight_cg) * zero_fuel_weight
+                    - loadsheet.operating_empty_position.0 * operating_empty_weight
+                    - self.total_target_passenger_moment().x)
+                    / cargo.get::<kilogram>()
+            });
+
+        self.cargo_load_planner.plan(cargo, target_position);
+        for cs in 0..Self::A380_CARGO.len() {
+            self.payload_manager
+                .set_cargo_target(cs, self.cargo_load_planner.station_planned(cs));
+        }
     }
 
     fn pax_num(&self, ps: usize) -> i8 {
@@ -377,6 +529,22 @@ impl A380Payload {
         self.payload_manager.max_cargo(cs)
     }
 
+    #[cfg(test)]
+    fn planned_cargo_center_of_gravity(&self) -> f64 {
+        let positions = self.cargo_load_planner.positions();
+        let total: Mass = positions.iter().map(|p| p.planned()).sum();
+        positions
+            .iter()
+            .map(|p| p.planned().get::<kilogram>() * p.position())
+            .sum::<f64>()
+            / total.get::<kilogram>()
+    }
+
+    #[cfg(test)]
+    fn combined_position_loads(&self) -> Vec<(Mass, Mass)> {
+        self.cargo_load_planner.combined_limits().collect()
+    }
+
     #[cfg(test)]
     fn sound_pax_boarding_playing(&self) -> bool {
         self.payload_manager.sound_pax_boarding_playing()
@@ -456,6 +624,7 @@ impl CargoPayload for A380Payload {
 impl SimulationElement for A380Payload {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.payload_manager.accept(visitor);
+        self.cargo_load_planner.accept(visitor);
 
         visitor.visit(self);
     }
@@ -5,26 +5,63 @@ use std::time::Duration;
 
 use rand::seq::IteratorRandom;
 use rand::SeedableRng;
+use systems::air_conditioning::CabinPressure;
 use systems::electrical::Electricity;
 use systems::payload::{BoardingRate, GsxState};
-use uom::si::f64::Ratio;
+use systems::shared::{CargoDoorLocked, CargoDoorOpenAmount};
+use uom::si::f64::{Pressure, Ratio};
 use uom::si::mass::pound;
+use uom::si::pressure::hectopascal;
 use uom::si::ratio::percent;
 
 use super::*;
+use crate::doors::A380Doors;
 use crate::payload::A380Payload;
 use crate::systems::simulation::{
     test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
     Aircraft, SimulationElement, SimulationElementVisitor,
 };
 
+struct TestCabinPressure;
+impl CabinPressure for TestCabinPressure {
+    fn exterior_pressure(&self) -> Pressure {
+        Pressure::new::<hectopascal>(1013.25)
+    }
+
+    fn cabin_pressure(&self) -> Pressure {
+        Pressure::new::<hectopascal>(1013.25)
+    }
+}
+
+struct TestCargoDoors;
+impl CargoDoorLocked for TestCargoDoors {
+    fn fwd_cargo_door_locked(&self) -> bool {
+        true
+    }
+
+    fn aft_cargo_door_locked(&self) -> bool {
+        true
+    }
+}
+impl CargoDoorOpenAmount for TestCargoDoors {
+    fn fwd_cargo_door_open_amount(&self) -> Ratio {
+        Ratio::default()
+    }
+
+    fn aft_cargo_door_open_amount(&self) -> Ratio {
+        Ratio::default()
+    }
+}
+
 struct BoardingTestAircraft {
+    doors: A380Doors,
     payload: A380Payload,
 }
 
 impl BoardingTestAircraft {
     fn new(context: &mut InitContext) -> Self {
         Self {
+            doors: A380Doors::new(context),
             payload: A380Payload::new(context),
         }
     }
@@ -97,6 +134,14 @@ impl BoardingTestAircraft {
     fn override_pax_payload(&mut self, ps: usize, payload: Mass) {
         self.payload.override_pax_payload(ps, payload)
     }
+
+    fn planned_cargo_center_of_gravity(&self) -> f64 {
+        self.payload.planned_cargo_center_of_gravity()
+    }
+
+    fn combined_position_loads(&self) -> Vec<(Mass, Mass)> {
+        self.payload.combined_position_loads()
+    }
 }
 impl Aircraft for BoardingTestAircraft {
     fn update_before_power_distribution(
@@ -104,11 +149,18 @@ impl Aircraft for BoardingTestAircraft {
         context: &UpdateContext,
         _electricity: &mut Electricity,
     ) {
-        self.payload.update(context);
+        self.doors
+            .update(context, &TestCabinPressure, &TestCargoDoors);
+        self.payload.update(
+            context,
+            crate::airframe::A380Airframe::get_loadsheet(),
+            &self.doors,
+        );
     }
 }
 impl SimulationElement for BoardingTestAircraft {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.doors.accept(visitor);
         self.payload.accept(visitor);
 
         visitor.visit(self);
@@ -329,6 +381,16 @@ impl BoardingTestBed {
         );
     }
 
+    fn request_cargo_plan(mut self, total: Mass, zero_fuel_weight_cg: Option<f64>) -> Self {
+        self.write_by_name("CARGO_PLAN_TOTAL_DESIRED", total.get::<kilogram>());
+        self.write_by_name(
+            "CARGO_PLAN_ZFW_CG_DESIRED",
+            zero_fuel_weight_cg.unwrap_or_default(),
+        );
+        self.write_by_name("CARGO_PLAN_REQUESTED", true);
+        self
+    }
+
     fn start_boarding(mut self) -> Self {
         self.write_by_name("BOARDING_STARTED_BY_USR", true);
         self
@@ -677,6 +739,37 @@ impl BoardingTestBed {
         self.query(|a| a.cargo_payload(cs))
     }
 
+    fn cargo_target(&mut self, cs: usize) -> Mass {
+        Mass::new::<kilogram>(
+            self.read_by_name(&format!("{}_DESIRED", A380Payload::A380_CARGO[cs].cargo_id)),
+        )
+    }
+
+    fn uld_planned(&mut self, position_id: &str) -> Mass {
+        Mass::new::<kilogram>(self.read_by_name(&format!("CARGO_PLAN_{}", position_id)))
+    }
+
+    fn cargo_unplanned(&mut self) -> Mass {
+        Mass::new::<kilogram>(self.read_by_name("CARGO_PLAN_UNPLANNED"))
+    }
+
+    fn cargo_plan_requested(&mut self) -> bool {
+        self.read_by_name("CARGO_PLAN_REQUESTED")
+    }
+
+    /// The ZFW CG in % MAC with the planned cargo and no passengers on board.
+    fn planned_zero_fuel_weight_cg(&self, total: Mass) -> f64 {
+        let loadsheet = crate::airframe::A380Airframe::get_loadsheet();
+        let cargo = total.get::<kilogram>();
+        let cargo_cg = self.query(|a| a.planned_cargo_center_of_gravity());
+        let zero_fuel_weight_cg = (loadsheet.operating_empty_weight_kg
+            * loadsheet.operating_empty_position.0
+            + cargo * cargo_cg)
+            / (loadsheet.operating_empty_weight_kg + cargo);
+
+        crate::airframe::A380Airframe::convert_cg(zero_fuel_weight_cg)
+    }
+
     #[allow(dead_code)]
     fn pax_center_of_gravity(&self) -> Vector3<f64> {
         self.query(|a| a.pax_center_of_gravity())
@@ -1721,3 +1814,139 @@ fn gsx_deboarding_half_pax() {
     test_bed.has_no_sound_pax_ambience();
     test_bed.sound_boarding_complete_reset();
 }
+
+#[test]
+fn cargo_plan_spreads_cargo_within_limits() {
+    let mut test_bed = test_bed_with()
+        .init_vars()
+        .request_cargo_plan(Mass::new::<kilogram>(30000.), None)
+        .and_run();
+
+    assert!(!test_bed.cargo_plan_requested());
+    assert_eq!(test_bed.cargo_unplanned(), Mass::default());
+
+    let mut total = Mass::default();
+    for cs in 0..A380Payload::A380_CARGO.len() {
+        let target = test_bed.cargo_target(cs);
+        assert!(target > Mass::default());
+        assert!(target <= test_bed.query(|a| a.max_cargo(cs)));
+        total += target;
+    }
+    assert!((total.get::<kilogram>() - 30000.).abs() < 1.);
+
+    for position_id in A380Payload::A380_FWD_ULD_POSITIONS
+        .iter()
+        .chain(A380Payload::A380_AFT_ULD_POSITIONS.iter())
+    {
+        assert!(test_bed.uld_planned(position_id).get::<kilogram>() <= 1588.);
+    }
+}
+
+#[test]
+fn cargo_plan_loads_pallet_positions_within_combined_limits() {
+    let mut test_bed = test_bed_with()
+        .init_vars()
+        .request_cargo_plan(Mass::new::<kilogram>(40000.), None)
+        .and_run();
+
+    assert_eq!(test_bed.cargo_unplanned(), Mass::default());
+    for position_id in A380Payload::A380_FWD_PALLET_POSITIONS
+        .iter()
+        .chain(A380Payload::A380_AFT_PALLET_POSITIONS.iter())
+    {
+        let planned = test_bed.uld_planned(position_id);
+        assert!(planned > Mass::default());
+        assert!(planned <= UldType::Pmc.max_gross_mass());
+    }
+
+    let combined_position_loads = test_bed.query(|a| a.combined_position_loads());
+    assert_eq!(
+        combined_position_loads.len(),
+        A380Payload::A380_FWD_PALLET_POSITIONS.len() + A380Payload::A380_AFT_PALLET_POSITIONS.len()
+    );
+    for (planned, limit) in combined_position_loads {
+        assert!(planned.get::<kilogram>() <= limit.get::<kilogram>() + 1e-6);
+    }
+}
+
+#[test]
+fn cargo_target_is_not_written_without_a_plan() {
+    let test_bed = test_bed_with().init_vars().and_run();
+
+    for cargo in A380Payload::A380_CARGO {
+        assert!(!test_bed.contains_variable_with_name(&format!("{}_DESIRED", cargo.cargo_id)));
+    }
+}
+
+#[test]
+fn cargo_plan_reports_cargo_over_capacity() {
+    let mut test_bed = test_bed_with()
+        .init_vars()
+        .request_cargo_plan(Mass::new::<kilogram>(60000.), None)
+        .and_run();
+
+    // 28577 kg forward, 20310 kg aft and 2513 kg bulk
+    assert!((test_bed.cargo_unplanned().get::<kilogram>() - 8600.).abs() < 1.);
+    for cs in 0..A380Payload::A380_CARGO.len() {
+        assert!(
+            (test_bed.cargo_target(cs) - test_bed.query(|a| a.max_cargo(cs)))
+                .get::<kilogram>()
+                .abs()
+                < 1.
+        );
+    }
+}
+
+#[test]
+fn cargo_plan_reaches_desired_zero_fuel_weight_cg() {
+    for desired_cg in [33., 38.] {
+        let total = Mass::new::<kilogram>(30000.);
+        let mut test_bed = test_bed_with()
+            .init_vars()
+            .request_cargo_plan(total, Some(desired_cg))
+            .and_run();
+
+        assert!((test_bed.planned_zero_fuel_weight_cg(total) - desired_cg).abs() < 0.01);
+
+        let mut planned = Mass::default();
+        for cs in 0..A380Payload::A380_CARGO.len() {
+            let target = test_bed.cargo_target(cs);
+            assert!(target <= test_bed.query(|a| a.max_cargo(cs)));
+            planned += target;
+        }
+        assert!((planned - total).get::<kilogram>().abs() < 1.);
+    }
+}
+
+#[test]
+fn cargo_plan_moves_cargo_forward_for_forward_cg() {
+    let total = Mass::new::<kilogram>(30000.);
+    let mut forward = test_bed_with()
+        .init_vars()
+        .request_cargo_plan(total, Some(30.))
+        .and_run();
+    let mut aft = test_bed_with()
+        .init_vars()
+        .request_cargo_plan(total, Some(40.))
+        .and_run();
+
+    assert!(forward.cargo_target(A380Cargo::Fwd.into()) > aft.cargo_target(A380Cargo::Fwd.into()));
+    assert!(forward.cargo_target(A380Cargo::Aft.into()) < aft.cargo_target(A380Cargo::Aft.into()));
+}
+
+#[test]
+fn cargo_plan_is_boarded() {
+    let test_bed = test_bed_with()
+        .init_vars()
+        .instant_board_rate()
+        .request_cargo_plan(Mass::new::<kilogram>(20000.), None)
+        .and_run()
+        .start_boarding()
+        .and_run()
+        .and_stabilize();
+
+    let boarded: Mass = (0..A380Payload::A380_CARGO.len())
+        .map(|cs| test_bed.cargo(cs))
+        .sum();
+    assert!((boarded.get::<kilogram>() - 20000.).abs() < 1.);
+}
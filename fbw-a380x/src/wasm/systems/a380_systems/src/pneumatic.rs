@@ -12,20 +12,24 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    engine::{start_control::EngineStartControl, trent_engine::TrentEngine},
+    failures::FailureType,
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
-        valve::*, BleedMonitoringComputerIsAliveSignal, CompressionChamber,
-        ControllablePneumaticValve, CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode,
-        DifferentialPressureTransducer, EngineCompressionChamberController, EngineModeSelector,
-        EngineState, PneumaticContainer, PneumaticPipe, PneumaticValveSignal, Precooler,
-        PressureTransducer, PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
+        leak_detection::{BleedAirDuctLeak, BleedAirLeakDetection, LeakDetectionLoop},
+        valve::*,
+        BleedMonitoringComputerIsAliveSignal, CompressionChamber, ControllablePneumaticValve,
+        CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode, DifferentialPressureTransducer,
+        EngineCompressionChamberController, EngineModeSelector, EngineState, PneumaticContainer,
+        PneumaticPipe, PneumaticValveSignal, Precooler, PressureTransducer,
+        PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
         TargetPressureTemperatureSignal, VariableVolumeContainer,
     },
     shared::{
-        pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, ElectricalBusType,
-        ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1, EngineCorrectedN2,
-        EngineFirePushButtons, EngineStartState, HydraulicColor, PackFlowValveState,
-        PneumaticBleed, PneumaticValve, ReservoirAirPressure,
+        pid::PidController, update_iterator::MaxStepLoop, BleedAirLeakZone, ControllerSignal,
+        DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, EngineBleedPushbutton,
+        EngineCorrectedN1, EngineCorrectedN2, EngineFirePushButtons, EngineStartState,
+        HydraulicColor, PackFlowValveState, PneumaticBleed, PneumaticValve, ReservoirAirPressure,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -106,6 +110,9 @@ pub struct A380Pneumatic {
 
     apu_bleed_air_valve_open_id: VariableIdentifier,
     apu_bleed_air_pressure_id: VariableIdentifier,
+    apu_bleed_leak_id: VariableIdentifier,
+    engine_bleed_leak_ids: [VariableIdentifier; 4],
+    engine_bleed_not_closed_ids: [VariableIdentifier; 4],
 
     core_processing_input_output_module_a: CoreProcessingInputOutputModuleA,
     engine_systems: [EngineBleedAirSystem; 4],
@@ -117,6 +124,9 @@ pub struct A380Pneumatic {
 
     apu_compression_chamber: CompressionChamber,
     apu_bleed_air_valve: DefaultValve,
+    apu_duct_leak: BleedAirDuctLeak,
+
+    leak_detection_loops: [LeakDetectionLoop; 9],
 
     hydraulic_reservoir_bleed_air_valves: [PurelyPneumaticValve; 2],
     hydraulic_reservoir_bleed_air_pipe: PneumaticPipe,
@@ -131,6 +141,12 @@ pub struct A380Pneumatic {
 impl A380Pneumatic {
     const PNEUMATIC_SIM_MAX_TIME_STEP: Duration = Duration::from_millis(100);
 
+    const WING_LEAK_DETECTION_TEMPERATURE_C: f64 = 124.;
+    // The bleed air reaching the pylon ducts is regulated to a lower pressure, and thus
+    // temperature, than on the A320.
+    const PYLON_LEAK_DETECTION_TEMPERATURE_C: f64 = 150.;
+    const APU_LEAK_DETECTION_TEMPERATURE_C: f64 = 124.;
+
     pub fn new(context: &mut InitContext) -> Self {
         Self {
             physics_updater: MaxStepLoop::new(Self::PNEUMATIC_SIM_MAX_TIME_STEP),
@@ -138,6 +154,12 @@ impl A380Pneumatic {
                 .get_identifier("APU_BLEED_AIR_VALVE_OPEN".to_owned()),
             apu_bleed_air_pressure_id: context
                 .get_identifier("PNEU_APU_BLEED_CONTAINER_PRESSURE".to_owned()),
+            apu_bleed_leak_id: context.get_identifier("PNEU_APU_BLEED_LEAK".to_owned()),
+            engine_bleed_leak_ids: [1, 2, 3, 4]
+                .map(|number| context.get_identifier(format!("PNEU_ENG_{}_BLEED_LEAK", number))),
+            engine_bleed_not_closed_ids: [1, 2, 3, 4].map(|number| {
+                context.get_identifier(format!("PNEU_ENG_{}_BLEED_NOT_CLOSED", number))
+            }),
             core_processing_input_output_module_a: CoreProcessingInputOutputModuleA::new(
                 ElectricalBusType::DirectCurrentEssential, // TTM 2
             ),
@@ -161,6 +183,25 @@ impl A380Pneumatic {
             ],
             apu_compression_chamber: CompressionChamber::new(Volume::new::<cubic_meter>(5.)),
             apu_bleed_air_valve: DefaultValve::new_closed(),
+            apu_duct_leak: BleedAirDuctLeak::new(BleedAirLeakZone::Apu, 0.05),
+            leak_detection_loops: [
+                Self::wing_leak_detection_loop(context, 1, ElectricalBusType::DirectCurrent(1)),
+                Self::wing_leak_detection_loop(context, 2, ElectricalBusType::DirectCurrent(1)),
+                Self::wing_leak_detection_loop(context, 3, ElectricalBusType::DirectCurrent(2)),
+                Self::wing_leak_detection_loop(context, 4, ElectricalBusType::DirectCurrent(2)),
+                Self::pylon_leak_detection_loop(context, 1, ElectricalBusType::DirectCurrent(1)),
+                Self::pylon_leak_detection_loop(context, 2, ElectricalBusType::DirectCurrent(1)),
+                Self::pylon_leak_detection_loop(context, 3, ElectricalBusType::DirectCurrent(2)),
+                Self::pylon_leak_detection_loop(context, 4, ElectricalBusType::DirectCurrent(2)),
+                LeakDetectionLoop::new(
+                    context,
+                    BleedAirLeakZone::Apu,
+                    ThermodynamicTemperature::new::<degree_celsius>(
+                        Self::APU_LEAK_DETECTION_TEMPERATURE_C,
+                    ),
+                    ElectricalBusType::DirectCurrentEssential,
+                ),
+            ],
             hydraulic_reservoir_bleed_air_valves: [
                 PurelyPneumaticValve::new(),
                 PurelyPneumaticValve::new(),
@@ -196,6 +237,36 @@ impl A380Pneumatic {
         }
     }
 
+    fn wing_leak_detection_loop(
+        context: &mut InitContext,
+        engine_number: usize,
+        powered_by: ElectricalBusType,
+    ) -> LeakDetectionLoop {
+        LeakDetectionLoop::new(
+            context,
+            BleedAirLeakZone::Wing(engine_number),
+            ThermodynamicTemperature::new::<degree_celsius>(
+                Self::WING_LEAK_DETECTION_TEMPERATURE_C,
+            ),
+            powered_by,
+        )
+    }
+
+    fn pylon_leak_detection_loop(
+        context: &mut InitContext,
+        engine_number: usize,
+        powered_by: ElectricalBusType,
+    ) -> LeakDetectionLoop {
+        LeakDetectionLoop::new(
+            context,
+            BleedAirLeakZone::Pylon(engine_number),
+            ThermodynamicTemperature::new::<degree_celsius>(
+                Self::PYLON_LEAK_DETECTION_TEMPERATURE_C,
+            ),
+            powered_by,
+        )
+    }
+
     pub(crate) fn update(
         &mut self,
         context: &UpdateContext,
@@ -236,6 +307,7 @@ impl A380Pneumatic {
             &self.apu_bleed_air_valve,
             overhead_panel,
             engine_fire_push_buttons,
+            &self.leak_detection_loops,
         );
 
         for cross_bleed_valve in self.cross_bleed_valves.iter_mut() {
@@ -245,6 +317,8 @@ impl A380Pneumatic {
                 .update_open_amount(&self.core_processing_input_output_module_a.units[0])
         }
 
+        self.fadec.update(context, &self.engine_systems);
+
         for controller in self.engine_starter_valve_controllers.iter_mut() {
             controller.update(&self.fadec);
         }
@@ -312,6 +386,14 @@ impl A380Pneumatic {
         center_cross_bleed_valve.update_move_fluid(context, engine_1_system, engine_4_system);
         right_cross_bleed_valve.update_move_fluid(context, engine_3_system, engine_4_system);
 
+        // The APU bleed duct is only pressurised while the APU bleed valve is open.
+        if self.apu_bleed_air_valve.is_open() {
+            self.apu_duct_leak
+                .update_move_fluid(context, engine_1_system);
+        } else {
+            self.apu_duct_leak.update_isolated();
+        }
+
         // PACKS
         let [pack_1, pack_2] = &mut self.packs;
         pack_1.update(
@@ -326,6 +408,38 @@ impl A380Pneumatic {
             engine_4_system,
             pack_flow_valve_signals,
         );
+
+        self.update_leak_detection(context);
+    }
+
+    fn update_leak_detection(&mut self, context: &UpdateContext) {
+        for detection_loop in self.leak_detection_loops.iter_mut() {
+            let leak = match detection_loop.zone() {
+                BleedAirLeakZone::Wing(number) => self.engine_systems[number - 1].wing_duct_leak(),
+                BleedAirLeakZone::Pylon(number) => {
+                    self.engine_systems[number - 1].pylon_duct_leak()
+                }
+                BleedAirLeakZone::Apu => &self.apu_duct_leak,
+            };
+
+            detection_loop.update(context, leak);
+        }
+    }
+
+    /// The APU bleed valve is closed through the ECB when a leak is detected in the APU bleed duct,
+    /// or in the wing of engine 1 while no engine is being started.
+    pub fn apu_bleed_leak_isolation_commanded(&self) -> bool {
+        let engine_start_in_progress = (1..=4).any(|number| {
+            matches!(
+                self.fadec.engine_state(number),
+                EngineState::Starting | EngineState::Restarting
+            )
+        });
+        let leak_monitor = &self.core_processing_input_output_module_a.leak_monitor;
+
+        leak_monitor.has_latched_leak(BleedAirLeakZone::Apu)
+            || (leak_monitor.has_latched_leak(BleedAirLeakZone::Wing(1))
+                && !engine_start_in_progress)
     }
 
     // TODO: Returning a mutable reference here is not great. I was running into an issue with the update order:
@@ -399,7 +513,9 @@ impl PackFlowValveState for A380Pneumatic {
 impl SimulationElement for A380Pneumatic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fadec.accept(visitor);
+        self.apu_duct_leak.accept(visitor);
 
+        accept_iterable!(self.leak_detection_loops, visitor);
         accept_iterable!(self.cross_bleed_valves, visitor);
         accept_iterable!(self.engine_systems, visitor);
         accept_iterable!(self.packs, visitor);
@@ -419,6 +535,22 @@ impl SimulationElement for A380Pneumatic {
             &self.apu_bleed_air_pressure_id,
             self.apu_compression_chamber.pressure(),
         );
+        writer.write(
+            &self.apu_bleed_leak_id,
+            self.leak_detection_loops
+                .leak_detected(BleedAirLeakZone::Apu),
+        );
+
+        for (unit, (leak_id, not_closed_id)) in
+            self.core_processing_input_output_module_a.units.iter().zip(
+                self.engine_bleed_leak_ids
+                    .iter()
+                    .zip(&self.engine_bleed_not_closed_ids),
+            )
+        {
+            writer.write(leak_id, unit.has_onside_leak());
+            writer.write(not_closed_id, unit.has_bleed_not_closed());
+        }
     }
 }
 impl ReservoirAirPressure for A380Pneumatic {
@@ -437,13 +569,14 @@ impl ReservoirAirPressure for A380Pneumatic {
 
 struct EngineStarterValveController {
     number: usize,
-    engine_state: EngineState,
+    is_commanded_open: bool,
 }
 impl ControllerSignal<EngineStarterValveSignal> for EngineStarterValveController {
     fn signal(&self) -> Option<EngineStarterValveSignal> {
-        match self.engine_state {
-            EngineState::Starting => Some(EngineStarterValveSignal::new_open()),
-            _ => Some(EngineStarterValveSignal::new_closed()),
+        if self.is_commanded_open {
+            Some(EngineStarterValveSignal::new_open())
+        } else {
+            Some(EngineStarterValveSignal::new_closed())
         }
     }
 }
@@ -451,12 +584,12 @@ impl EngineStarterValveController {
     fn new(number: usize) -> Self {
         Self {
             number,
-            engine_state: EngineState::Off,
+            is_commanded_open: false,
         }
     }
 
     fn update(&mut self, fadec: &FullAuthorityDigitalEngineControl) {
-        self.engine_state = fadec.engine_state(self.number);
+        self.is_commanded_open = fadec.starter_valve_is_commanded_open(self.number);
     }
 }
 
@@ -465,6 +598,7 @@ impl EngineStarterValveController {
  */
 struct CoreProcessingInputOutputModuleA {
     units: [CoreProcessingInputOutputModuleAUnit; 4],
+    leak_monitor: BleedLeakMonitor,
     powered_by: ElectricalBusType,
     is_powered: bool,
 }
@@ -477,6 +611,7 @@ impl CoreProcessingInputOutputModuleA {
                 CoreProcessingInputOutputModuleAUnit::new(3),
                 CoreProcessingInputOutputModuleAUnit::new(4),
             ],
+            leak_monitor: BleedLeakMonitor::new(),
             powered_by,
             is_powered: true,
         }
@@ -489,7 +624,11 @@ impl CoreProcessingInputOutputModuleA {
         apu_bleed_valve: &impl PneumaticValve,
         pneumatic_overhead_panel: &A380PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
+        leak_detection: &impl BleedAirLeakDetection,
     ) {
+        self.leak_monitor
+            .update(leak_detection, pneumatic_overhead_panel);
+
         for (unit, sensor) in self.units.iter_mut().zip(sensors) {
             unit.update(
                 context,
@@ -497,6 +636,7 @@ impl CoreProcessingInputOutputModuleA {
                 engine_fire_push_buttons.is_released(unit.engine_number),
                 apu_bleed_valve,
                 pneumatic_overhead_panel,
+                &self.leak_monitor,
             );
         }
     }
@@ -536,9 +676,13 @@ struct CoreProcessingInputOutputModuleAUnit {
     pressure_regulating_valve_pid: PidController,
     fan_air_valve_pid: PidController,
     cross_bleed_valve_selector: CrossBleedValveSelectorMode,
+    has_onside_leak: bool,
+    has_any_leak: bool,
+    bleed_not_closed: DelayedTrueLogicGate,
 }
 impl CoreProcessingInputOutputModuleAUnit {
     const PRESSURE_REGULATING_VALVE_TARGET_PSI: f64 = 40.; // FCOM
+    const BLEED_NOT_CLOSED_DELAY: Duration = Duration::from_secs(5);
 
     fn new(engine_number: usize) -> Self {
         Self {
@@ -565,6 +709,9 @@ impl CoreProcessingInputOutputModuleAUnit {
             ),
             fan_air_valve_pid: PidController::new(-0.005, -0.001, 0., 0., 1., 200., 1.),
             cross_bleed_valve_selector: CrossBleedValveSelectorMode::Auto,
+            has_onside_leak: false,
+            has_any_leak: false,
+            bleed_not_closed: DelayedTrueLogicGate::new(Self::BLEED_NOT_CLOSED_DELAY),
         }
     }
 
@@ -575,6 +722,7 @@ impl CoreProcessingInputOutputModuleAUnit {
         is_engine_fire_pushbutton_released: bool,
         apu_bleed_valve: &impl PneumaticValve,
         pneumatic_overhead_panel: &A380PneumaticOverheadPanel,
+        leak_monitor: &BleedLeakMonitor,
     ) {
         self.intermediate_pressure_compressor_pressure = sensors.intermediate_pressure();
         self.high_pressure_compressor_pressure = sensors.high_pressure();
@@ -614,11 +762,36 @@ impl CoreProcessingInputOutputModuleAUnit {
         self.is_apu_bleed_on = pneumatic_overhead_panel.apu_bleed_is_on();
 
         self.cross_bleed_valve_selector = pneumatic_overhead_panel.cross_bleed_mode();
+
+        self.has_onside_leak = leak_monitor.has_onside_leak(self.engine_number);
+        self.has_any_leak = leak_monitor.has_any_leak();
+
+        self.bleed_not_closed.update(
+            context,
+            self.should_command_pressure_regulating_valve_closed()
+                && !self.pressure_regulating_valve_is_closed,
+        );
     }
 
     fn should_close_pressure_regulating_valve_because_apu_bleed_is_on(&self) -> bool {
         self.is_apu_bleed_on && self.is_apu_bleed_valve_open
     }
+
+    fn should_command_pressure_regulating_valve_closed(&self) -> bool {
+        !self.is_engine_bleed_pushbutton_auto
+            || self.is_engine_fire_pushbutton_released
+            || self.should_close_pressure_regulating_valve_because_apu_bleed_is_on()
+            || self.engine_starter_valve_is_open
+            || self.has_onside_leak
+    }
+
+    fn has_onside_leak(&self) -> bool {
+        self.has_onside_leak
+    }
+
+    fn has_bleed_not_closed(&self) -> bool {
+        self.bleed_not_closed.output()
+    }
 }
 impl ControllerSignal<HighPressureValveSignal> for CoreProcessingInputOutputModuleAUnit {
     fn signal(&self) -> Option<HighPressureValveSignal> {
@@ -638,9 +811,7 @@ impl ControllerSignal<HighPressureValveSignal> for CoreProcessingInputOutputModu
 impl ControllerSignal<PressureRegulatingValveSignal> for CoreProcessingInputOutputModuleAUnit {
     fn signal(&self) -> Option<PressureRegulatingValveSignal> {
         if self.transfer_pressure < Pressure::new::<psi>(15.)
-            || (!self.is_engine_bleed_pushbutton_auto || self.is_engine_fire_pushbutton_released)
-            || self.should_close_pressure_regulating_valve_because_apu_bleed_is_on()
-            || self.engine_starter_valve_is_open
+            || self.should_command_pressure_regulating_valve_closed()
         {
             Some(PressureRegulatingValveSignal::new_closed())
         } else {
@@ -667,7 +838,9 @@ impl ControllerSignal<CrossBleedValveSignal> for CoreProcessingInputOutputModule
                 CrossBleedValveSignalType::Manual,
             )),
             CrossBleedValveSelectorMode::Auto => {
-                if self.is_apu_bleed_valve_open || self.is_any_bleed_pushbutton_off {
+                if (self.is_apu_bleed_valve_open || self.is_any_bleed_pushbutton_off)
+                    && !self.has_any_leak
+                {
                     Some(CrossBleedValveSignal::new_open(
                         CrossBleedValveSignalType::Automatic,
                     ))
@@ -681,6 +854,65 @@ impl ControllerSignal<CrossBleedValveSignal> for CoreProcessingInputOutputModule
     }
 }
 
+/// Latches the leaks reported by the detection loops. A latched leak is only reset once the zone
+/// has cooled down and the affected bleed source has been switched off.
+struct BleedLeakMonitor {
+    latched_leaks: [bool; 9],
+}
+impl BleedLeakMonitor {
+    const ZONES: [BleedAirLeakZone; 9] = [
+        BleedAirLeakZone::Wing(1),
+        BleedAirLeakZone::Wing(2),
+        BleedAirLeakZone::Wing(3),
+        BleedAirLeakZone::Wing(4),
+        BleedAirLeakZone::Pylon(1),
+        BleedAirLeakZone::Pylon(2),
+        BleedAirLeakZone::Pylon(3),
+        BleedAirLeakZone::Pylon(4),
+        BleedAirLeakZone::Apu,
+    ];
+
+    fn new() -> Self {
+        Self {
+            latched_leaks: [false; 9],
+        }
+    }
+
+    fn update(
+        &mut self,
+        leak_detection: &impl BleedAirLeakDetection,
+        overhead_panel: &A380PneumaticOverheadPanel,
+    ) {
+        for (latched_leak, zone) in self.latched_leaks.iter_mut().zip(Self::ZONES) {
+            let is_detected = leak_detection.leak_detected(zone);
+            let source_is_off = match zone {
+                BleedAirLeakZone::Wing(number) | BleedAirLeakZone::Pylon(number) => {
+                    !overhead_panel.engine_bleed_pb_is_auto(number)
+                }
+                BleedAirLeakZone::Apu => !overhead_panel.apu_bleed_is_on(),
+            };
+
+            *latched_leak = is_detected || (*latched_leak && !source_is_off);
+        }
+    }
+
+    fn has_latched_leak(&self, zone: BleedAirLeakZone) -> bool {
+        Self::ZONES
+            .iter()
+            .zip(self.latched_leaks)
+            .any(|(&latched_zone, is_latched)| latched_zone == zone && is_latched)
+    }
+
+    fn has_onside_leak(&self, engine_number: usize) -> bool {
+        self.has_latched_leak(BleedAirLeakZone::Wing(engine_number))
+            || self.has_latched_leak(BleedAirLeakZone::Pylon(engine_number))
+    }
+
+    fn has_any_leak(&self) -> bool {
+        self.latched_leaks.iter().any(|&is_latched| is_latched)
+    }
+}
+
 struct EngineBleedAirSystem {
     high_pressure_id: VariableIdentifier,
     starter_container_pressure_id: VariableIdentifier,
@@ -717,6 +949,8 @@ struct EngineBleedAirSystem {
     engine_starter_valve: DefaultValve,
     fan_air_valve: ElectroPneumaticValve,
     precooler: Precooler,
+    wing_duct_leak: BleedAirDuctLeak,
+    pylon_duct_leak: BleedAirDuctLeak,
 
     intermediate_pressure_transducer: PressureTransducer,
     transfer_pressure_transducer: PressureTransducer,
@@ -776,8 +1010,14 @@ impl EngineBleedAirSystem {
                 1.,
             )),
             intermediate_pressure_valve: PurelyPneumaticValve::new(),
-            high_pressure_valve: ElectroPneumaticValve::new(powered_by),
-            pressure_regulating_valve: ElectroPneumaticValve::new(powered_by),
+            high_pressure_valve: ElectroPneumaticValve::new(powered_by).with_stuck_failures(
+                FailureType::HighPressureValveStuckOpen(number),
+                FailureType::HighPressureValveStuckClosed(number),
+            ),
+            pressure_regulating_valve: ElectroPneumaticValve::new(powered_by).with_stuck_failures(
+                FailureType::PressureRegulatingValveStuckOpen(number),
+                FailureType::PressureRegulatingValveStuckClosed(number),
+            ),
             fan_air_valve: ElectroPneumaticValve::new(powered_by),
             transfer_pressure_pipe: PneumaticPipe::new(
                 Volume::new::<cubic_meter>(1.),
@@ -806,7 +1046,10 @@ impl EngineBleedAirSystem {
             ),
             engine_starter_exhaust: PneumaticExhaust::new(3e-2, 3e-2, Pressure::new::<psi>(0.)),
             engine_starter_valve: DefaultValve::new_closed(),
-            precooler: Precooler::new(180. * 2.),
+            precooler: Precooler::new(180. * 2.)
+                .with_clogging_failure(FailureType::PrecoolerClogged(number)),
+            wing_duct_leak: BleedAirDuctLeak::new(BleedAirLeakZone::Wing(number), 0.05),
+            pylon_duct_leak: BleedAirDuctLeak::new(BleedAirLeakZone::Pylon(number), 0.05),
             intermediate_pressure_transducer: PressureTransducer::new(powered_by),
             transfer_pressure_transducer: PressureTransducer::new(powered_by),
             regulated_pressure_transducer: PressureTransducer::new(powered_by),
@@ -881,6 +1124,11 @@ impl EngineBleedAirSystem {
         self.engine_starter_exhaust
             .update_move_fluid(context, &mut self.engine_starter_container);
 
+        self.pylon_duct_leak
+            .update_move_fluid(context, &mut self.precooler_inlet_pipe);
+        self.wing_duct_leak
+            .update_move_fluid(context, &mut self.precooler_outlet_pipe);
+
         self.intermediate_pressure_transducer
             .update(context, &self.intermediate_pressure_compression_chamber);
         self.transfer_pressure_transducer
@@ -937,10 +1185,22 @@ impl EngineBleedAirSystem {
         self.engine_starter_valve.is_open()
     }
 
+    fn engine_starter_container_pressure(&self) -> Pressure {
+        self.engine_starter_container.pressure()
+    }
+
     fn pressure_regulating_valve_is_open(&self) -> bool {
         self.pressure_regulating_valve.is_open()
     }
 
+    fn wing_duct_leak(&self) -> &BleedAirDuctLeak {
+        &self.wing_duct_leak
+    }
+
+    fn pylon_duct_leak(&self) -> &BleedAirDuctLeak {
+        &self.pylon_duct_leak
+    }
+
     fn intermediate_pressure_transducer_pressure(&self) -> Option<Pressure> {
         self.intermediate_pressure_transducer.signal()
     }
@@ -962,6 +1222,9 @@ impl SimulationElement for EngineBleedAirSystem {
         self.high_pressure_valve.accept(visitor);
         self.pressure_regulating_valve.accept(visitor);
         self.fan_air_valve.accept(visitor);
+        self.precooler.accept(visitor);
+        self.wing_duct_leak.accept(visitor);
+        self.pylon_duct_leak.accept(visitor);
 
         self.intermediate_pressure_transducer.accept(visitor);
         self.transfer_pressure_transducer.accept(visitor);
@@ -1129,7 +1392,8 @@ impl SimulationElement for A380PneumaticOverheadPanel {
     }
 }
 
-/// We use this simply as an interface to engine parameter simvars. It should probably not be part of the pneumatic system.
+/// We use this as an interface to engine parameter simvars and to sequence the engine starts.
+/// It should probably not be part of the pneumatic system.
 pub struct FullAuthorityDigitalEngineControl {
     engine_1_state_id: VariableIdentifier,
     engine_2_state_id: VariableIdentifier,
@@ -1143,6 +1407,8 @@ pub struct FullAuthorityDigitalEngineControl {
 
     engine_mode_selector1_id: VariableIdentifier,
     engine_mode_selector1_position: EngineModeSelector,
+
+    start_controls: [EngineStartControl; 4],
 }
 impl FullAuthorityDigitalEngineControl {
     fn new(context: &mut InitContext) -> Self {
@@ -1158,9 +1424,33 @@ impl FullAuthorityDigitalEngineControl {
             engine_mode_selector1_id: context
                 .get_identifier("TURB ENG IGNITION SWITCH EX1:1".to_owned()),
             engine_mode_selector1_position: EngineModeSelector::Norm,
+
+            start_controls: [1, 2, 3, 4].map(|number| {
+                EngineStartControl::new(
+                    context,
+                    number,
+                    TrentEngine::start_schedule(),
+                    ElectricalBusType::AlternatingCurrentEssential,
+                    ElectricalBusType::AlternatingCurrent(number as u8),
+                )
+            }),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, engine_systems: &[EngineBleedAirSystem; 4]) {
+        for (start_control, engine_system) in self.start_controls.iter_mut().zip(engine_systems) {
+            start_control.update(
+                context,
+                self.engine_mode_selector1_position,
+                engine_system.engine_starter_container_pressure(),
+            );
         }
     }
 
+    fn starter_valve_is_commanded_open(&self, number: usize) -> bool {
+        self.start_controls[number - 1].starter_valve_is_commanded_open()
+    }
+
     fn engine_state(&self, number: usize) -> EngineState {
         match number {
             1 => self.engine_1_state,
@@ -1176,6 +1466,12 @@ impl FullAuthorityDigitalEngineControl {
     }
 }
 impl SimulationElement for FullAuthorityDigitalEngineControl {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.start_controls, visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.engine_1_state = reader.read(&self.engine_1_state_id);
         self.engine_2_state = reader.read(&self.engine_2_state_id);
@@ -1442,19 +1738,19 @@ mod tests {
         failures::FailureType,
         payload::NumberOfPassengers,
         pneumatic::{
-            ControllablePneumaticValve, CrossBleedValveSelectorMode, EngineState,
-            PneumaticContainer, PneumaticValveSignal, TargetPressureTemperatureSignal,
+            ControllablePneumaticValve, CrossBleedValveSelectorMode, EngineModeSelector,
+            EngineState, PneumaticContainer, PneumaticValveSignal, TargetPressureTemperatureSignal,
         },
         shared::{
             arinc429::{Arinc429Word, SignStatus},
-            ApuBleedAirValveSignal, CargoDoorLocked, ControllerSignal, ElectricalBusType,
-            ElectricalBuses, EmergencyElectricalState, EngineBleedPushbutton, EngineCorrectedN1,
-            EngineFirePushButtons, EngineStartState, HydraulicColor,
+            ApuBleedAirValveSignal, BleedAirLeakZone, CargoDoorLocked, ControllerSignal,
+            ElectricalBusType, ElectricalBuses, EmergencyElectricalState, EngineBleedPushbutton,
+            EngineCorrectedN1, EngineFirePushButtons, EngineStartState, HydraulicColor,
             InternationalStandardAtmosphere, LgciuWeightOnWheels, MachNumber, PackFlowValveState,
             PneumaticBleed, PneumaticValve, PotentialOrigin,
         },
         simulation::{
-            test::{SimulationTestBed, TestBed, WriteByName},
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
             Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
         },
     };
@@ -1890,6 +2186,10 @@ mod tests {
             self
         }
 
+        fn run_multiple_frames(&mut self, duration: Duration) {
+            self.test_bed.run_multiple_frames(duration);
+        }
+
         fn mach_number(mut self, mach: MachNumber) -> Self {
             self.write_by_name("AIRSPEED MACH", mach);
 
@@ -1913,6 +2213,7 @@ mod tests {
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.55));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.2));
             self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N3:1", 70.);
 
             self
         }
@@ -1922,6 +2223,7 @@ mod tests {
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.55));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.2));
             self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N3:2", 70.);
 
             self
         }
@@ -1931,6 +2233,7 @@ mod tests {
             self.write_by_name("TURB ENG CORRECTED N2:3", Ratio::new::<ratio>(0.55));
             self.write_by_name("TURB ENG CORRECTED N1:3", Ratio::new::<ratio>(0.2));
             self.write_by_name("ENGINE_STATE:3", EngineState::On);
+            self.write_by_name("ENGINE_N3:3", 70.);
 
             self
         }
@@ -1940,15 +2243,21 @@ mod tests {
             self.write_by_name("TURB ENG CORRECTED N2:4", Ratio::new::<ratio>(0.55));
             self.write_by_name("TURB ENG CORRECTED N1:4", Ratio::new::<ratio>(0.2));
             self.write_by_name("ENGINE_STATE:4", EngineState::On);
+            self.write_by_name("ENGINE_N3:4", 70.);
 
             self
         }
 
+        fn idle_all_engines(self) -> Self {
+            self.idle_eng1().idle_eng2().idle_eng3().idle_eng4()
+        }
+
         fn toga_eng1(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.65));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.5));
             self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N3:1", 70.);
 
             self
         }
@@ -1958,6 +2267,7 @@ mod tests {
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.65));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.5));
             self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N3:2", 70.);
 
             self
         }
@@ -1967,6 +2277,7 @@ mod tests {
             self.write_by_name("TURB ENG CORRECTED N2:3", Ratio::new::<ratio>(0.65));
             self.write_by_name("TURB ENG CORRECTED N1:3", Ratio::new::<ratio>(0.5));
             self.write_by_name("ENGINE_STATE:3", EngineState::On);
+            self.write_by_name("ENGINE_N3:3", 70.);
 
             self
         }
@@ -1976,12 +2287,15 @@ mod tests {
             self.write_by_name("TURB ENG CORRECTED N2:4", Ratio::new::<ratio>(0.65));
             self.write_by_name("TURB ENG CORRECTED N1:4", Ratio::new::<ratio>(0.5));
             self.write_by_name("ENGINE_STATE:4", EngineState::On);
+            self.write_by_name("ENGINE_N3:4", 70.);
 
             self
         }
 
         fn stop_eng1(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", false);
+            self.write_by_name("FUELSYSTEM VALVE SWITCH:1", false);
+            self.write_by_name("ENGINE_N3:1", 0.);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.));
             self.write_by_name("ENGINE_STATE:1", EngineState::Off);
@@ -1991,6 +2305,8 @@ mod tests {
 
         fn stop_eng2(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", false);
+            self.write_by_name("FUELSYSTEM VALVE SWITCH:2", false);
+            self.write_by_name("ENGINE_N3:2", 0.);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.));
             self.write_by_name("ENGINE_STATE:2", EngineState::Off);
@@ -2000,6 +2316,8 @@ mod tests {
 
         fn stop_eng3(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:3", false);
+            self.write_by_name("FUELSYSTEM VALVE SWITCH:3", false);
+            self.write_by_name("ENGINE_N3:3", 0.);
             self.write_by_name("TURB ENG CORRECTED N2:3", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:3", Ratio::new::<ratio>(0.));
             self.write_by_name("ENGINE_STATE:3", EngineState::Off);
@@ -2009,6 +2327,8 @@ mod tests {
 
         fn stop_eng4(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:4", false);
+            self.write_by_name("FUELSYSTEM VALVE SWITCH:4", false);
+            self.write_by_name("ENGINE_N3:4", 0.);
             self.write_by_name("TURB ENG CORRECTED N2:4", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:4", Ratio::new::<ratio>(0.));
             self.write_by_name("ENGINE_STATE:4", EngineState::Off);
@@ -2020,6 +2340,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(n1));
             self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N3:1", 70.);
 
             self
         }
@@ -2028,6 +2349,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(n1));
             self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N3:2", 70.);
 
             self
         }
@@ -2036,6 +2358,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:3", true);
             self.write_by_name("TURB ENG CORRECTED N1:3", Ratio::new::<ratio>(n1));
             self.write_by_name("ENGINE_STATE:3", EngineState::On);
+            self.write_by_name("ENGINE_N3:3", 70.);
 
             self
         }
@@ -2044,6 +2367,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:4", true);
             self.write_by_name("TURB ENG CORRECTED N1:4", Ratio::new::<ratio>(n1));
             self.write_by_name("ENGINE_STATE:4", EngineState::On);
+            self.write_by_name("ENGINE_N3:4", 70.);
 
             self
         }
@@ -2074,6 +2398,12 @@ mod tests {
 
         fn start_eng1(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
+            self.write_by_name(
+                "TURB ENG IGNITION SWITCH EX1:1",
+                EngineModeSelector::Ignition,
+            );
+            self.write_by_name("FUELSYSTEM VALVE SWITCH:1", true);
+            self.write_by_name("ENGINE_N3:1", 0.);
             self.write_by_name("ENGINE_STATE:1", EngineState::Starting);
 
             self
@@ -2081,6 +2411,12 @@ mod tests {
 
         fn start_eng2(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
+            self.write_by_name(
+                "TURB ENG IGNITION SWITCH EX1:1",
+                EngineModeSelector::Ignition,
+            );
+            self.write_by_name("FUELSYSTEM VALVE SWITCH:2", true);
+            self.write_by_name("ENGINE_N3:2", 0.);
             self.write_by_name("ENGINE_STATE:2", EngineState::Starting);
 
             self
@@ -2088,6 +2424,12 @@ mod tests {
 
         fn start_eng3(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:3", true);
+            self.write_by_name(
+                "TURB ENG IGNITION SWITCH EX1:1",
+                EngineModeSelector::Ignition,
+            );
+            self.write_by_name("FUELSYSTEM VALVE SWITCH:3", true);
+            self.write_by_name("ENGINE_N3:3", 0.);
             self.write_by_name("ENGINE_STATE:3", EngineState::Starting);
 
             self
@@ -2095,6 +2437,12 @@ mod tests {
 
         fn start_eng4(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:4", true);
+            self.write_by_name(
+                "TURB ENG IGNITION SWITCH EX1:1",
+                EngineModeSelector::Ignition,
+            );
+            self.write_by_name("FUELSYSTEM VALVE SWITCH:4", true);
+            self.write_by_name("ENGINE_N3:4", 0.);
             self.write_by_name("ENGINE_STATE:4", EngineState::Starting);
 
             self
@@ -2256,6 +2604,12 @@ mod tests {
             self
         }
 
+        fn set_engine_bleed_push_button_auto(mut self, number: usize) -> Self {
+            self.write_by_name(&format!("OVHD_PNEU_ENG_{}_BLEED_PB_IS_AUTO", number), true);
+
+            self
+        }
+
         fn set_apu_bleed_valve_signal(mut self, signal: ApuBleedAirValveSignal) -> Self {
             self.command(|a| a.apu.set_bleed_air_valve_signal(signal));
 
@@ -2306,6 +2660,31 @@ mod tests {
             })
         }
 
+        fn cross_bleed_valves_are_closed(&self) -> bool {
+            self.query(|a| {
+                a.pneumatic
+                    .cross_bleed_valves
+                    .iter()
+                    .all(|cbv| !cbv.is_open())
+            })
+        }
+
+        fn bleed_leak(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_ENG_{}_BLEED_LEAK", number))
+        }
+
+        fn bleed_not_closed(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_ENG_{}_BLEED_NOT_CLOSED", number))
+        }
+
+        fn apu_bleed_leak(&mut self) -> bool {
+            self.read_by_name("PNEU_APU_BLEED_LEAK")
+        }
+
+        fn apu_bleed_leak_isolation_commanded(&self) -> bool {
+            self.query(|a| a.pneumatic.apu_bleed_leak_isolation_commanded())
+        }
+
         fn cross_bleed_valve_selector(&self) -> CrossBleedValveSelectorMode {
             self.query(|a| a.pneumatic_overhead_panel.cross_bleed_mode())
         }
@@ -3348,6 +3727,143 @@ mod tests {
         assert!(!test_bed.precooler_inlet_pressure(4).is_nan());
     }
 
+    #[rstest]
+    fn wing_leak_closes_onside_pressure_regulating_valve(
+        #[values(1, 2, 3, 4)] engine_number: usize,
+    ) {
+        let mut test_bed = test_bed_with().idle_all_engines().and_stabilize();
+
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(
+            engine_number,
+        )));
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        for i in 1..=4 {
+            assert_eq!(test_bed.bleed_leak(i), i == engine_number);
+            assert_eq!(test_bed.pr_valve_is_open(i), i != engine_number);
+        }
+    }
+
+    #[test]
+    fn pylon_leak_closes_onside_pressure_regulating_valve() {
+        let mut test_bed = test_bed_with()
+            .eng3_n1(0.7)
+            .eng3_n2(0.8)
+            .eng4_n1(0.7)
+            .eng4_n2(0.8)
+            .and_stabilize();
+
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Pylon(3)));
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        assert!(test_bed.bleed_leak(3));
+        assert!(!test_bed.pr_valve_is_open(3));
+        assert!(test_bed.pr_valve_is_open(4));
+    }
+
+    #[test]
+    fn leak_closes_cross_bleed_valves_in_auto_mode() {
+        let mut test_bed = test_bed_with()
+            .idle_all_engines()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+            .set_engine_bleed_push_button_off(1)
+            .and_stabilize();
+
+        assert!(test_bed.cross_bleed_valves_are_open());
+
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(3)));
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        assert!(test_bed.cross_bleed_valves_are_closed());
+    }
+
+    #[test]
+    fn apu_leak_commands_apu_bleed_valve_closed() {
+        let mut test_bed = test_bed_with().set_bleed_air_running().and_stabilize();
+
+        assert!(!test_bed.apu_bleed_leak_isolation_commanded());
+
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Apu));
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        assert!(test_bed.apu_bleed_leak());
+        assert!(test_bed.apu_bleed_leak_isolation_commanded());
+    }
+
+    #[test]
+    fn leak_isolation_is_only_reset_with_engine_bleed_push_button_off() {
+        let mut test_bed = test_bed_with().idle_all_engines().and_stabilize();
+
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(2)));
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+        test_bed.unfail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(2)));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert!(!test_bed.pr_valve_is_open(2));
+
+        test_bed = test_bed
+            .set_engine_bleed_push_button_off(2)
+            .and_run()
+            .set_engine_bleed_push_button_auto(2)
+            .and_stabilize();
+
+        assert!(test_bed.pr_valve_is_open(2));
+    }
+
+    #[test]
+    fn failed_leak_detection_loop_does_not_isolate_leak() {
+        let mut test_bed = test_bed_with().idle_all_engines().and_stabilize();
+
+        test_bed.fail(FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Wing(
+            1,
+        )));
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(1)));
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        assert!(!test_bed.bleed_leak(1));
+        assert!(test_bed.pr_valve_is_open(1));
+    }
+
+    #[test]
+    fn pressure_regulating_valve_stuck_open_triggers_bleed_not_closed() {
+        let mut test_bed = test_bed_with()
+            .idle_eng1()
+            .idle_eng2()
+            .idle_eng3()
+            .idle_eng4()
+            .and_stabilize();
+
+        test_bed.fail(FailureType::PressureRegulatingValveStuckOpen(2));
+        test_bed = test_bed.set_engine_bleed_push_button_off(2).and_run();
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(test_bed.pr_valve_is_open(2));
+        for i in 1..=4 {
+            assert_eq!(test_bed.bleed_not_closed(i), i == 2);
+        }
+    }
+
+    #[test]
+    fn clogged_precooler_cools_bleed_air_less() {
+        let precooler_outlet_temperature = |is_clogged: bool| {
+            let mut test_bed = test_bed_with()
+                .toga_eng1()
+                .toga_eng2()
+                .toga_eng3()
+                .toga_eng4()
+                .and_stabilize();
+
+            if is_clogged {
+                test_bed.fail(FailureType::PrecoolerClogged(4));
+            }
+            test_bed.run_multiple_frames(Duration::from_secs(60));
+
+            test_bed.precooler_outlet_temperature(4)
+        };
+
+        assert!(precooler_outlet_temperature(true) > precooler_outlet_temperature(false));
+    }
+
     mod overhead {
         use super::*;
 
@@ -1,346 +1,146 @@
 use systems::simulation::InitContext;
 use systems::{
-    electrical::consumption::{FlightPhasePowerConsumer, PowerConsumerFlightPhase},
+    electrical::consumption::{ElectricalLoad, ElectricalLoadCatalogue, LoadCondition},
     shared::ElectricalBusType,
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
 };
-use uom::si::{f64::*, power::watt};
+use uom::si::{f64::*, power::watt, ratio::ratio};
 
-/// This type provides an aggregated form of power consumption.
-/// We haven't yet implemented all power consumers and thus need something to
-/// consume power, as otherwise electrical load is nearly 0.
+/// The loads of the equipment which isn't simulated by a system of its own.
+/// Equipment which is simulated, such as fuel pumps, hydraulic pumps, fans and
+/// the display units, consumes power within that system and is thus not part of
+/// this catalogue.
+///
+/// Each piece of equipment is listed individually, except for:
+/// - the galleys, of which the ovens, boilers and chillers are switched together
+///   per galley feed and by the COMMERCIAL push button;
+/// - the cabin lighting and entertainment system, which consist of many small
+///   fixtures and seat units switched together per zone;
+/// - the standby equipment on the hot and service buses, such as clocks,
+///   courtesy lights and relays, which draw a few watts each.
 pub(super) struct A380PowerConsumption {
-    ac_bus_1_consumer: FlightPhasePowerConsumer,
-    ac_bus_2_consumer: FlightPhasePowerConsumer,
-    ac_ess_bus_consumer: FlightPhasePowerConsumer,
-    ac_ess_shed_bus_consumer: FlightPhasePowerConsumer,
-    ac_stat_inv_bus_consumer: FlightPhasePowerConsumer,
-    ac_gnd_flt_service_consumer: FlightPhasePowerConsumer,
-    dc_bus_1_consumer: FlightPhasePowerConsumer,
-    dc_bus_2_consumer: FlightPhasePowerConsumer,
-    dc_ess_bus_consumer: FlightPhasePowerConsumer,
-    dc_ess_shed_bus_consumer: FlightPhasePowerConsumer,
-    dc_bat_bus_consumer: FlightPhasePowerConsumer,
-    dc_hot_bus_1_consumer: FlightPhasePowerConsumer,
-    dc_hot_bus_2_consumer: FlightPhasePowerConsumer,
-    dc_gnd_flt_service_consumer: FlightPhasePowerConsumer,
+    loads: ElectricalLoadCatalogue,
 }
 impl A380PowerConsumption {
+    const GALLEY_IS_ON: LoadCondition = LoadCondition::VariableIsOff("ELEC_GALLEY_IS_SHED");
+    const PROBE_AND_WINDOW_HEAT_IS_ON: LoadCondition = LoadCondition::VariableIsOn("PITOT HEAT");
+
     pub fn new(context: &mut InitContext) -> Self {
-        // The watts in this function are all provided by komp.
-        Self {
-            ac_bus_1_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrent(1),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(26816.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(30350.1),
-                ),
-                (
-                    PowerConsumerFlightPhase::Takeoff,
-                    Power::new::<watt>(33797.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::Flight,
-                    Power::new::<watt>(39032.5),
-                ),
-                (
-                    PowerConsumerFlightPhase::Landing,
-                    Power::new::<watt>(30733.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::TaxiIn,
-                    Power::new::<watt>(30243.1),
-                ),
-            ]),
-            ac_bus_2_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrent(2),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(26960.2),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(21735.8),
-                ),
-                (
-                    PowerConsumerFlightPhase::Takeoff,
-                    Power::new::<watt>(25183.),
-                ),
-                (
-                    PowerConsumerFlightPhase::Flight,
-                    Power::new::<watt>(29777.4),
-                ),
-                (
-                    PowerConsumerFlightPhase::Landing,
-                    Power::new::<watt>(22119.),
-                ),
-                (
-                    PowerConsumerFlightPhase::TaxiIn,
-                    Power::new::<watt>(24475.8),
-                ),
-            ]),
-            ac_ess_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentEssential,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(455.7),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(715.7),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(875.7)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(875.7)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(715.7)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(715.7)),
-            ]),
-            ac_ess_shed_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentEssentialShed,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(560.5),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(823.5),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(823.5)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(823.5)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(823.5)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(823.5)),
-            ]),
-            ac_stat_inv_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentStaticInverter,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(135.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(135.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(135.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(135.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(135.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(135.)),
-            ]),
-            ac_gnd_flt_service_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentGndFltService,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(4718.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(3663.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(2628.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(2628.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(2628.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(3663.)),
-            ]),
-            dc_bus_1_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrent(1),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(252.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(308.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(364.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(280.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(364.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(336.)),
-            ]),
-            dc_bus_2_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrent(2),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(532.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(448.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(392.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(392.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(392.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(448.)),
-            ]),
-            dc_ess_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentEssential,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(168.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(140.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(168.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(140.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(168.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(140.)),
-            ]),
-            dc_ess_shed_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentEssentialShed,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(224.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(168.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(196.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(196.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(196.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(168.)),
-            ]),
-            dc_bat_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentBattery,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(0.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(28.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(28.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(28.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(28.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(28.)),
-            ]),
-            dc_hot_bus_1_consumer: FlightPhasePowerConsumer::new(
-                context,
+        // The nominal powers are approximations.
+        let load =
+            |name, bus_type, watts| ElectricalLoad::new(name, bus_type, Power::new::<watt>(watts));
+        let heater_duty_cycle = Ratio::new::<ratio>(0.7);
+        let galley_duty_cycle = Ratio::new::<ratio>(0.75);
+
+        let ac_1 = ElectricalBusType::AlternatingCurrent(1);
+        let ac_2 = ElectricalBusType::AlternatingCurrent(2);
+        let ac_3 = ElectricalBusType::AlternatingCurrent(3);
+        let ac_4 = ElectricalBusType::AlternatingCurrent(4);
+        let ac_emer = ElectricalBusType::AlternatingCurrentEssential;
+        let ac_ess = ElectricalBusType::AlternatingCurrentEssentialShed;
+        let ac_service = ElectricalBusType::AlternatingCurrentGndFltService;
+        let dc_1 = ElectricalBusType::DirectCurrent(1);
+        let dc_2 = ElectricalBusType::DirectCurrent(2);
+        let dc_ess = ElectricalBusType::DirectCurrentEssential;
+        let dc_service = ElectricalBusType::DirectCurrentGndFltService;
+
+        let loads = [
+            load("ADIRU 1", ac_emer, 110.),
+            load("ADIRU 2", ac_2, 110.),
+            load("ADIRU 3", ac_3, 110.),
+            load("PRIM 1", dc_ess, 120.),
+            load("PRIM 2", dc_1, 120.),
+            load("PRIM 3", dc_2, 120.),
+            load("SEC 1", dc_ess, 80.),
+            load("SEC 2", dc_1, 80.),
+            load("SEC 3", dc_2, 80.),
+            load("FMC A", dc_ess, 70.),
+            load("FMC B", dc_2, 70.),
+            load("FMC C", dc_1, 70.),
+            load("FCU", dc_ess, 50.),
+            load("FWS 1", dc_ess, 80.),
+            load("FWS 2", dc_2, 80.),
+            load("KCCU 1", dc_ess, 30.),
+            load("KCCU 2", dc_2, 30.),
+            load("RMP 1", dc_ess, 20.),
+            load("RMP 2", dc_2, 20.),
+            load("RMP 3", dc_1, 20.),
+            load("VHF 1", ac_emer, 90.),
+            load("VHF 2", ac_2, 90.),
+            load("VHF 3", ac_3, 90.),
+            load("AESU 1", ac_ess, 250.),
+            load("AESU 2", ac_4, 250.),
+            load("SDCU", dc_1, 60.),
+            load("CMV", dc_1, 50.),
+            load("CVR", ac_emer, 30.),
+            load("DFDR", ac_2, 45.),
+            load("OANS", ac_3, 60.),
+            load("FWD LOWER DECK GALLEY", ac_1, 30000.)
+                .duty_cycle(galley_duty_cycle)
+                .when(Self::GALLEY_IS_ON),
+            load("MAIN DECK GALLEYS", ac_2, 30000.)
+                .duty_cycle(galley_duty_cycle)
+                .when(Self::GALLEY_IS_ON),
+            load("UPPER DECK GALLEYS", ac_3, 30000.)
+                .duty_cycle(galley_duty_cycle)
+                .when(Self::GALLEY_IS_ON),
+            load("AFT GALLEYS", ac_4, 30000.)
+                .duty_cycle(galley_duty_cycle)
+                .when(Self::GALLEY_IS_ON),
+            load("MAIN DECK CABIN LIGHTING", ac_2, 3000.).when(Self::GALLEY_IS_ON),
+            load("UPPER DECK CABIN LIGHTING", ac_3, 2000.).when(Self::GALLEY_IS_ON),
+            load("MAIN DECK IFE", ac_1, 7000.).when(Self::GALLEY_IS_ON),
+            load("UPPER DECK IFE", ac_4, 5000.).when(Self::GALLEY_IS_ON),
+            load("WINDSHIELD HEAT 1", ac_1, 3500.)
+                .duty_cycle(heater_duty_cycle)
+                .when(Self::PROBE_AND_WINDOW_HEAT_IS_ON),
+            load("WINDSHIELD HEAT 2", ac_4, 3500.)
+                .duty_cycle(heater_duty_cycle)
+                .when(Self::PROBE_AND_WINDOW_HEAT_IS_ON),
+            load("SIDE WINDOW HEAT 1", ac_1, 800.)
+                .duty_cycle(heater_duty_cycle)
+                .when(Self::PROBE_AND_WINDOW_HEAT_IS_ON),
+            load("SIDE WINDOW HEAT 2", ac_4, 800.)
+                .duty_cycle(heater_duty_cycle)
+                .when(Self::PROBE_AND_WINDOW_HEAT_IS_ON),
+            load("PROBE HEAT 1", ac_1, 1200.).when(Self::PROBE_AND_WINDOW_HEAT_IS_ON),
+            load("PROBE HEAT 2", ac_4, 1200.).when(Self::PROBE_AND_WINDOW_HEAT_IS_ON),
+            load("PROBE HEAT 3", ac_ess, 400.).when(Self::PROBE_AND_WINDOW_HEAT_IS_ON),
+            load("LAVATORY WATER HEATERS", ac_service, 2500.).duty_cycle(heater_duty_cycle),
+            load("VACUUM GENERATORS", ac_service, 1600.).when(LoadCondition::OnGround),
+            load("CARGO COMPARTMENT LIGHTING", ac_service, 1200.).when(LoadCondition::OnGround),
+            load("SERVICE AREA LIGHTING", ac_service, 800.).when(LoadCondition::OnGround),
+            load(
+                "DC HOT 1 STANDBY EQUIPMENT",
                 ElectricalBusType::DirectCurrentHot(1),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(108.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(11.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(15.3)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(15.3)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(15.3)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(11.)),
-            ]),
-            dc_hot_bus_2_consumer: FlightPhasePowerConsumer::new(
-                context,
+                20.,
+            ),
+            load(
+                "DC HOT 2 STANDBY EQUIPMENT",
                 ElectricalBusType::DirectCurrentHot(2),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(24.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(24.3),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(24.3)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(24.3)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(24.3)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(24.3)),
-            ]),
-            dc_gnd_flt_service_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentGndFltService,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(168.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(84.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(84.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(84.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(112.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(84.)),
-            ]),
+                20.,
+            ),
+            load(
+                "DC HOT ESS STANDBY EQUIPMENT",
+                ElectricalBusType::DirectCurrentHot(3),
+                30.,
+            ),
+            load("DC SERVICE STANDBY EQUIPMENT", dc_service, 150.),
+            load("REFUEL PANEL", dc_service, 150.).when(LoadCondition::OnGround),
+        ];
+
+        Self {
+            loads: ElectricalLoadCatalogue::new(context, loads),
         }
     }
 
     pub fn update(&mut self, context: &UpdateContext) {
-        self.ac_bus_1_consumer.update(context);
-        self.ac_bus_2_consumer.update(context);
-        self.ac_ess_bus_consumer.update(context);
-        self.ac_ess_shed_bus_consumer.update(context);
-        self.ac_stat_inv_bus_consumer.update(context);
-        self.ac_gnd_flt_service_consumer.update(context);
-        self.dc_bus_1_consumer.update(context);
-        self.dc_bus_2_consumer.update(context);
-        self.dc_ess_bus_consumer.update(context);
-        self.dc_ess_shed_bus_consumer.update(context);
-        self.dc_bat_bus_consumer.update(context);
-        self.dc_hot_bus_1_consumer.update(context);
-        self.dc_hot_bus_2_consumer.update(context);
-        self.dc_gnd_flt_service_consumer.update(context);
+        self.loads.update(context);
     }
 }
 impl SimulationElement for A380PowerConsumption {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.ac_bus_1_consumer.accept(visitor);
-        self.ac_bus_2_consumer.accept(visitor);
-        self.ac_ess_bus_consumer.accept(visitor);
-        self.ac_ess_shed_bus_consumer.accept(visitor);
-        self.ac_stat_inv_bus_consumer.accept(visitor);
-        self.ac_gnd_flt_service_consumer.accept(visitor);
-        self.dc_bus_1_consumer.accept(visitor);
-        self.dc_bus_2_consumer.accept(visitor);
-        self.dc_ess_bus_consumer.accept(visitor);
-        self.dc_ess_shed_bus_consumer.accept(visitor);
-        self.dc_bat_bus_consumer.accept(visitor);
-        self.dc_hot_bus_1_consumer.accept(visitor);
-        self.dc_hot_bus_2_consumer.accept(visitor);
-        self.dc_gnd_flt_service_consumer.accept(visitor);
+        self.loads.accept(visitor);
 
         visitor.visit(self);
     }
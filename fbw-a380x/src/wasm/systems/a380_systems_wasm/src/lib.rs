@@ -15,7 +15,7 @@ mod rudder;
 mod spoilers;
 mod trimmable_horizontal_stabilizer;
 
-use a380_systems::A380;
+use a380_systems::{doors::A380Doors, A380};
 use ailerons::ailerons;
 use autobrakes::autobrakes;
 use body_wheel_steering::body_wheel_steering;
@@ -36,8 +36,9 @@ use systems::air_conditioning::{Channel, FdacId, OcsmId, VcmId};
 use systems::failures::FailureType;
 use systems::integrated_modular_avionics::core_processing_input_output_module::CpiomId;
 use systems::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, FireDetectionLoopID,
-    FireDetectionZone, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, BleedAirLeakZone, ElectricalBusType,
+    FireDetectionLoopID, FireDetectionZone, GearActuatorId, HydraulicColor, HydraulicLeakLocation,
+    LgciuId, ProximityDetectorId,
 };
 
 use systems_wasm::{MsfsSimulationBuilder, Variable};
@@ -269,6 +270,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             26_018,
             FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Mlg),
         ),
+        (28_000, FailureType::TrimTankTransferValveStuckClosed),
         (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
         (29_001, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
         (29_002, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
@@ -332,6 +334,97 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             29_017,
             FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp4b),
         ),
+        (
+            29_018,
+            FailureType::HydraulicExternalLeak(
+                HydraulicColor::Green,
+                HydraulicLeakLocation::SystemSectionUpstreamPriorityValve,
+            ),
+        ),
+        (
+            29_019,
+            FailureType::HydraulicExternalLeak(
+                HydraulicColor::Green,
+                HydraulicLeakLocation::SystemSectionDownstreamPriorityValve,
+            ),
+        ),
+        (
+            29_020,
+            FailureType::HydraulicExternalLeak(
+                HydraulicColor::Green,
+                HydraulicLeakLocation::AuxiliarySection,
+            ),
+        ),
+        (
+            29_021,
+            FailureType::HydraulicExternalLeak(
+                HydraulicColor::Yellow,
+                HydraulicLeakLocation::SystemSectionUpstreamPriorityValve,
+            ),
+        ),
+        (
+            29_022,
+            FailureType::HydraulicExternalLeak(
+                HydraulicColor::Yellow,
+                HydraulicLeakLocation::SystemSectionDownstreamPriorityValve,
+            ),
+        ),
+        (
+            29_023,
+            FailureType::ElecPumpWear(AirbusElectricPumpId::GreenA),
+        ),
+        (
+            29_024,
+            FailureType::ElecPumpWear(AirbusElectricPumpId::GreenB),
+        ),
+        (
+            29_025,
+            FailureType::ElecPumpWear(AirbusElectricPumpId::YellowA),
+        ),
+        (
+            29_026,
+            FailureType::ElecPumpWear(AirbusElectricPumpId::YellowB),
+        ),
+        (
+            29_027,
+            FailureType::EnginePumpWear(AirbusEngineDrivenPumpId::Edp1a),
+        ),
+        (
+            29_028,
+            FailureType::EnginePumpWear(AirbusEngineDrivenPumpId::Edp1b),
+        ),
+        (
+            29_029,
+            FailureType::EnginePumpWear(AirbusEngineDrivenPumpId::Edp2a),
+        ),
+        (
+            29_030,
+            FailureType::EnginePumpWear(AirbusEngineDrivenPumpId::Edp2b),
+        ),
+        (
+            29_031,
+            FailureType::EnginePumpWear(AirbusEngineDrivenPumpId::Edp3a),
+        ),
+        (
+            29_032,
+            FailureType::EnginePumpWear(AirbusEngineDrivenPumpId::Edp3b),
+        ),
+        (
+            29_033,
+            FailureType::EnginePumpWear(AirbusEngineDrivenPumpId::Edp4a),
+        ),
+        (
+            29_034,
+            FailureType::EnginePumpWear(AirbusEngineDrivenPumpId::Edp4b),
+        ),
+        (31_000, FailureType::DisplayUnit(1)),
+        (31_001, FailureType::DisplayUnit(2)),
+        (31_002, FailureType::DisplayUnit(3)),
+        (31_003, FailureType::DisplayUnit(4)),
+        (31_004, FailureType::DisplayUnit(5)),
+        (31_005, FailureType::DisplayUnit(6)),
+        (31_006, FailureType::DisplayUnit(7)),
+        (31_007, FailureType::DisplayUnit(8)),
         (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
         (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
         (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
@@ -408,6 +501,30 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             32_025,
             FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
         ),
+        (
+            32_030,
+            FailureType::GearActuatorInternalLeak(GearActuatorId::GearNose),
+        ),
+        (
+            32_031,
+            FailureType::GearActuatorInternalLeak(GearActuatorId::GearLeft),
+        ),
+        (
+            32_032,
+            FailureType::GearActuatorInternalLeak(GearActuatorId::GearRight),
+        ),
+        (
+            32_033,
+            FailureType::GearActuatorInternalLeak(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_034,
+            FailureType::GearActuatorInternalLeak(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_035,
+            FailureType::GearActuatorInternalLeak(GearActuatorId::GearDoorRight),
+        ),
         (34_000, FailureType::RadioAltimeter(1)),
         (34_001, FailureType::RadioAltimeter(2)),
         (34_002, FailureType::RadioAltimeter(3)),
@@ -417,6 +534,116 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (34_020, FailureType::RadioAntennaDirectCoupling(1)),
         (34_021, FailureType::RadioAntennaDirectCoupling(2)),
         (34_022, FailureType::RadioAntennaDirectCoupling(3)),
+        (34_040, FailureType::MultiModeReceiver(1)),
+        (34_041, FailureType::MultiModeReceiver(2)),
+        (34_050, FailureType::IlsLocalizerSignalLoss(1)),
+        (34_051, FailureType::IlsLocalizerSignalLoss(2)),
+        (34_052, FailureType::IlsGlideslopeSignalLoss(1)),
+        (34_053, FailureType::IlsGlideslopeSignalLoss(2)),
+        (34_054, FailureType::IlsLocalizerBeamBend(1)),
+        (34_055, FailureType::IlsLocalizerBeamBend(2)),
+        (34_056, FailureType::IlsGlideslopeBeamBend(1)),
+        (34_057, FailureType::IlsGlideslopeBeamBend(2)),
+        (34_060, FailureType::TrafficCollisionAvoidanceSystem),
+        (34_070, FailureType::InertialReferenceHeadingDrift(1)),
+        (34_071, FailureType::InertialReferenceHeadingDrift(2)),
+        (34_072, FailureType::InertialReferenceHeadingDrift(3)),
+        (
+            36_000,
+            FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(1)),
+        ),
+        (
+            36_001,
+            FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(2)),
+        ),
+        (
+            36_002,
+            FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(3)),
+        ),
+        (
+            36_003,
+            FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(4)),
+        ),
+        (
+            36_010,
+            FailureType::BleedAirDuctLeak(BleedAirLeakZone::Pylon(1)),
+        ),
+        (
+            36_011,
+            FailureType::BleedAirDuctLeak(BleedAirLeakZone::Pylon(2)),
+        ),
+        (
+            36_012,
+            FailureType::BleedAirDuctLeak(BleedAirLeakZone::Pylon(3)),
+        ),
+        (
+            36_013,
+            FailureType::BleedAirDuctLeak(BleedAirLeakZone::Pylon(4)),
+        ),
+        (36_020, FailureType::BleedAirDuctLeak(BleedAirLeakZone::Apu)),
+        (
+            36_100,
+            FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Wing(1)),
+        ),
+        (
+            36_101,
+            FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Wing(2)),
+        ),
+        (
+            36_102,
+            FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Wing(3)),
+        ),
+        (
+            36_103,
+            FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Wing(4)),
+        ),
+        (
+            36_110,
+            FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Pylon(1)),
+        ),
+        (
+            36_111,
+            FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Pylon(2)),
+        ),
+        (
+            36_112,
+            FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Pylon(3)),
+        ),
+        (
+            36_113,
+            FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Pylon(4)),
+        ),
+        (
+            36_120,
+            FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Apu),
+        ),
+        (36_200, FailureType::PressureRegulatingValveStuckOpen(1)),
+        (36_201, FailureType::PressureRegulatingValveStuckOpen(2)),
+        (36_202, FailureType::PressureRegulatingValveStuckOpen(3)),
+        (36_203, FailureType::PressureRegulatingValveStuckOpen(4)),
+        (36_210, FailureType::PressureRegulatingValveStuckClosed(1)),
+        (36_211, FailureType::PressureRegulatingValveStuckClosed(2)),
+        (36_212, FailureType::PressureRegulatingValveStuckClosed(3)),
+        (36_213, FailureType::PressureRegulatingValveStuckClosed(4)),
+        (36_220, FailureType::HighPressureValveStuckOpen(1)),
+        (36_221, FailureType::HighPressureValveStuckOpen(2)),
+        (36_222, FailureType::HighPressureValveStuckOpen(3)),
+        (36_223, FailureType::HighPressureValveStuckOpen(4)),
+        (36_230, FailureType::HighPressureValveStuckClosed(1)),
+        (36_231, FailureType::HighPressureValveStuckClosed(2)),
+        (36_232, FailureType::HighPressureValveStuckClosed(3)),
+        (36_233, FailureType::HighPressureValveStuckClosed(4)),
+        (36_300, FailureType::PrecoolerClogged(1)),
+        (36_301, FailureType::PrecoolerClogged(2)),
+        (36_302, FailureType::PrecoolerClogged(3)),
+        (36_303, FailureType::PrecoolerClogged(4)),
+        (49_000, FailureType::ApuNoLightOff),
+        (49_001, FailureType::ApuHungStart),
+        (49_002, FailureType::ApuOverspeed),
+        (49_003, FailureType::ApuEgtOvertemperature),
+        (49_004, FailureType::ApuOilPump),
+        (49_005, FailureType::ApuOilCooler),
+        (49_006, FailureType::ApuFuelPressureLoss),
     ])
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
@@ -457,6 +684,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "gallons", 10)?
     .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "gallons", 11)?
     .provides_aircraft_variable("FUELSYSTEM LINE FUEL FLOW", "gallons per hour", 141)?
+    .provides_aircraft_variable_range("FUELSYSTEM VALVE SWITCH", "Bool", 1..=4)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 0)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 1)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 2)?
@@ -473,12 +701,25 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("INCIDENCE ALPHA", "Degrees", 0)?
     .provides_aircraft_variable("INDICATED ALTITUDE", "Feet", 0)?
     .provides_aircraft_variable("INTERACTIVE POINT OPEN:0", "Percent", 0)?
+    .provides_aircraft_variable("INTERACTIVE POINT OPEN", "Percent", 1)?
     .provides_aircraft_variable("INTERACTIVE POINT OPEN", "Percent", 2)?
     .provides_aircraft_variable("INTERACTIVE POINT OPEN", "Percent", 3)?
+    .provides_aircraft_variable("INTERACTIVE POINT OPEN", "Percent", 4)?
+    .provides_aircraft_variable("INTERACTIVE POINT OPEN", "Percent", 5)?
+    .provides_aircraft_variable("INTERACTIVE POINT OPEN", "Percent", 6)?
+    .provides_aircraft_variable("INTERACTIVE POINT OPEN", "Percent", 7)?
+    .provides_aircraft_variable("INTERACTIVE POINT OPEN", "Percent", 8)?
+    .provides_aircraft_variable("INTERACTIVE POINT OPEN", "Percent", 9)?
     .provides_aircraft_variable("INTERACTIVE POINT OPEN", "Percent", 10)?
+    .provides_aircraft_variable("INTERACTIVE POINT OPEN", "Percent", 11)?
+    .provides_aircraft_variable("INTERACTIVE POINT OPEN", "Percent", 12)?
+    .provides_aircraft_variable("INTERACTIVE POINT OPEN", "Percent", 13)?
+    .provides_aircraft_variable("INTERACTIVE POINT OPEN", "Percent", 14)?
+    .provides_aircraft_variable("INTERACTIVE POINT OPEN", "Percent", 15)?
     .provides_aircraft_variable("KOHLSMAN SETTING MB", "Millibars", 1)?
     .provides_aircraft_variable("LIGHT BEACON", "Bool", 0)?
     .provides_aircraft_variable("LIGHT BEACON ON", "Bool", 0)?
+    .provides_aircraft_variable("PITOT HEAT", "Bool", 0)?
     .provides_aircraft_variable("PLANE ALT ABOVE GROUND", "Feet", 0)?
     .provides_aircraft_variable("PLANE PITCH DEGREES", "Degrees", 0)?
     .provides_aircraft_variable("PLANE BANK DEGREES", "Degrees", 0)?
@@ -486,6 +727,9 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("PLANE HEADING DEGREES TRUE", "Degrees", 0)?
     .provides_aircraft_variable("PLANE LATITUDE", "degree latitude", 0)?
     .provides_aircraft_variable("PLANE LONGITUDE", "degree longitude", 0)?
+    .provides_aircraft_variable("PLANE ALTITUDE", "Feet", 0)?
+    .provides_aircraft_variable("NAV FREQUENCY", "Hz", 3)?
+    .provides_aircraft_variable("NAV FREQUENCY", "Hz", 4)?
     .provides_aircraft_variable("PRESSURE ALTITUDE", "Feet", 0)?
     .provides_aircraft_variable("PUSHBACK STATE", "Enum", 0)?
     .provides_aircraft_variable("PUSHBACK ANGLE", "Radians", 0)?
@@ -568,6 +812,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         );
         Ok(())
     })?
+    .with_residual_pressure_door_lock(A380Doors::PASSENGER_DOORS)?
     .with_aspect(|builder| {
         for i in 1..=2 {
             builder.copy(
@@ -2,7 +2,7 @@
 // Copyright (c) 2023-2024 FlyByWire Simulations
 // SPDX-License-Identifier: GPL-3.0
 
-import { AtaChapterNumber } from '@flybywiresim/fbw-sdk';
+import { AtaChapterNumber, useSimVar } from '@flybywiresim/fbw-sdk';
 import React, { FC } from 'react';
 import { ArrowRight } from 'react-bootstrap-icons';
 import { useHistory } from 'react-router';
@@ -10,6 +10,7 @@ import { t } from '../../../Localization/translation';
 import { RemindersSection } from './RemindersSection';
 import { useFailuresOrchestrator } from '../../../failures-orchestrator-provider';
 import { findLatestSeenPathname } from '../../../Utils/routing';
+import { MAX_LOAD_REPORTS } from '../../../Utils/loadReports';
 import { useAppDispatch } from '../../../Store/store';
 import { setSearchQuery } from '../../../Store/features/failuresPage';
 
@@ -48,8 +49,48 @@ const ActiveFailureCard: FC<ActiveFailureCardProps> = ({ ata, name }) => {
   );
 };
 
+const LOAD_REPORT_TYPES = [
+  undefined,
+  'HardLanding',
+  'SevereHardLanding',
+  'OverweightLanding',
+  'FlapsOverspeed',
+  'GearOverspeed',
+  'TurbulenceOverload',
+];
+
+interface LoadReportCardProps {
+  number: number;
+}
+
+const LoadReportCard: FC<LoadReportCardProps> = ({ number }) => {
+  const [type] = useSimVar(`L:A32NX_LOAD_REPORT_${number}_TYPE`, 'number', 1000);
+  const [sinkRate] = useSimVar(`L:A32NX_LOAD_REPORT_${number}_SINK_RATE`, 'number', 1000);
+  const [loadFactor] = useSimVar(`L:A32NX_LOAD_REPORT_${number}_LOAD_FACTOR`, 'number', 1000);
+  const [grossWeight] = useSimVar(`L:A32NX_LOAD_REPORT_${number}_GW`, 'number', 1000);
+  const [indicatedAirspeed] = useSimVar(`L:A32NX_LOAD_REPORT_${number}_IAS`, 'number', 1000);
+
+  const reportType = LOAD_REPORT_TYPES[type];
+  if (!reportType) {
+    return null;
+  }
+
+  return (
+    <div className="mr-4 mt-4 flex flex-col flex-wrap rounded-md border-2 border-theme-accent bg-theme-accent p-2">
+      <h3 className="font-bold">{t('Dashboard.ImportantInformation.Maintenance.LoadReport')}</h3>
+      <span className="font-inter mt-2">{t(`Dashboard.ImportantInformation.Maintenance.${reportType}`)}</span>
+      <span className="font-inter">
+        {`${grossWeight.toFixed(0)} kg, ${loadFactor.toFixed(2)} g, ${indicatedAirspeed.toFixed(0)} kt`}
+        {sinkRate > 0 && `, ${sinkRate.toFixed(1)} ft/s`}
+      </span>
+    </div>
+  );
+};
+
 export const MaintenanceReminder = () => {
   const { allFailures, activeFailures } = useFailuresOrchestrator();
+  const [loadReportType] = useSimVar('L:A32NX_LOAD_REPORT_1_TYPE', 'number', 1000);
+  const [, setClearLoadReports] = useSimVar('L:A32NX_LOAD_REPORT_CLEAR', 'bool', 1000);
 
   return (
     <RemindersSection title={t('Dashboard.ImportantInformation.Maintenance.Title')} pageLinkPath="/failures">
@@ -67,12 +108,26 @@ export const MaintenanceReminder = () => {
             return <ActiveFailureCard ata={failure?.ata} name={failure?.name ?? '<unknown>'} />;
           })}
 
+        {Array.from({ length: MAX_LOAD_REPORTS }, (_, index) => (
+          <LoadReportCard key={index + 1} number={index + 1} />
+        ))}
+
         {!activeFailures.size && (
           <h1 className="m-auto my-4 text-center font-bold opacity-60">
             {t('Dashboard.ImportantInformation.Maintenance.NoActiveFailures')}
           </h1>
         )}
       </div>
+
+      {loadReportType > 0 && (
+        <button
+          type="button"
+          className="mt-4 rounded-md border-2 border-theme-highlight bg-theme-highlight px-4 py-2 text-theme-body transition duration-100 hover:bg-theme-body hover:text-theme-highlight"
+          onClick={() => setClearLoadReports(true)}
+        >
+          {t('Dashboard.ImportantInformation.Maintenance.ClearLoadReports')}
+        </button>
+      )}
     </RemindersSection>
   );
 };
@@ -6,6 +6,7 @@ import React, { useContext, useEffect, useState } from 'react';
 import {
   ChecklistJsonDefinition,
   FailureDefinition,
+  FailureReliability,
   UniversalConfigProvider,
   NXDataStore,
   SENTRY_CONSENT_KEY,
@@ -57,6 +58,7 @@ import { setFlightPlanProgress } from './Store/features/flightProgress';
 import { Checklists, setAutomaticItemStates } from './Checklists/Checklists';
 import { setAircraftChecklists, addTrackingChecklists } from './Store/features/checklists';
 import { FlyPadPage } from './Settings/Pages/FlyPadPage';
+import { persistLoadReports } from './Utils/loadReports';
 import { NavigraphAuthProvider } from '../react/navigraph';
 import { EventBus } from '@microsoft/msfs-sdk';
 import { TroubleshootingContextProvider } from './TroubleshootingContext';
@@ -72,11 +74,12 @@ import './toast.css';
 
 export interface EfbWrapperProps {
   failures: FailureDefinition[]; // TODO: Move failure definition into VFS
+  failureReliabilities?: FailureReliability[];
   aircraftSetup?: () => void;
   eventBus: EventBus;
 }
 
-export const EfbWrapper: React.FC<EfbWrapperProps> = ({ failures, aircraftSetup, eventBus }) => {
+export const EfbWrapper: React.FC<EfbWrapperProps> = ({ failures, failureReliabilities, aircraftSetup, eventBus }) => {
   const setSessionId = () => {
     const ALPHABET = '0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ';
     const SESSION_ID_LENGTH = 14;
@@ -116,6 +119,7 @@ export const EfbWrapper: React.FC<EfbWrapperProps> = ({ failures, aircraftSetup,
 
     syncSettingsFromPersistentStorage(globalSyncedSettings);
     migrateSettings();
+    persistLoadReports();
     setSessionId();
 
     // Needed to fetch METARs from the sim
@@ -136,7 +140,12 @@ export const EfbWrapper: React.FC<EfbWrapperProps> = ({ failures, aircraftSetup,
 
   return (
     <Provider store={store}>
-      <EfbInstrument failures={failures} aircraftChecklists={aircraftChecklists} eventBus={eventBus} />
+      <EfbInstrument
+        failures={failures}
+        failureReliabilities={failureReliabilities}
+        aircraftChecklists={aircraftChecklists}
+        eventBus={eventBus}
+      />
     </Provider>
   );
 };
@@ -524,11 +533,17 @@ export const useEventBus = () => useContext(Context);
 
 interface EfbInstrumentProps {
   failures: FailureDefinition[];
+  failureReliabilities?: FailureReliability[];
   aircraftChecklists: ChecklistJsonDefinition[];
   eventBus: EventBus;
 }
 
-export const EfbInstrument: React.FC<EfbInstrumentProps> = ({ failures, aircraftChecklists, eventBus }) => {
+export const EfbInstrument: React.FC<EfbInstrumentProps> = ({
+  failures,
+  failureReliabilities,
+  aircraftChecklists,
+  eventBus,
+}) => {
   const [, setSessionId] = usePersistentProperty('A32NX_SENTRY_SESSION_ID');
 
   useEffect(() => () => setSessionId(''), []);
@@ -537,7 +552,7 @@ export const EfbInstrument: React.FC<EfbInstrumentProps> = ({ failures, aircraft
 
   return (
     <TroubleshootingContextProvider eventBus={eventBus}>
-      <FailuresOrchestratorProvider failures={failures}>
+      <FailuresOrchestratorProvider failures={failures} reliabilities={failureReliabilities}>
         <ErrorBoundary FallbackComponent={ErrorFallback} onReset={() => setErr(false)} resetKeys={[err]}>
           <Router>
             <ModalProvider>
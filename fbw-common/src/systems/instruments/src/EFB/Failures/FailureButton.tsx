@@ -7,6 +7,8 @@ import React, { FC, MouseEventHandler } from 'react';
 export interface FailureButtonProps {
   name: string;
   isActive: boolean;
+  /** Whether the failure is armed and will be activated once its trigger condition is met. */
+  isArmed?: boolean;
   onClick: MouseEventHandler<HTMLButtonElement>;
   className: string;
   highlightedTerm?: string;
@@ -15,11 +17,17 @@ export interface FailureButtonProps {
 export const FailureButton: FC<FailureButtonProps> = ({
   name,
   isActive,
+  isArmed,
   onClick,
   className,
   highlightedTerm,
 }: FailureButtonProps) => {
-  const color = isActive ? 'border-utility-red' : 'border-utility-green';
+  let color = 'border-utility-green';
+  if (isActive) {
+    color = 'border-utility-red';
+  } else if (isArmed) {
+    color = 'border-utility-amber';
+  }
 
   return (
     <button
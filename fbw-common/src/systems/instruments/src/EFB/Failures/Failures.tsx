@@ -12,17 +12,22 @@ import { ComfortUI } from './Pages/Comfort';
 import { Navbar } from '../UtilComponents/Navbar';
 import { useAppDispatch, useAppSelector } from '../Store/store';
 import { SimpleInput } from '../UtilComponents/Form/SimpleInput/SimpleInput';
+import { SelectGroup, SelectItem } from '../UtilComponents/Form/Select';
 import { PageLink, PageRedirect } from '../Utils/routing';
 import { useFailuresOrchestrator } from '../failures-orchestrator-provider';
-import { setSearchQuery } from '../Store/features/failuresPage';
+import { FailureActivation, setActivation, setSearchQuery } from '../Store/features/failuresPage';
 import { ScrollableContainer } from '../UtilComponents/ScrollableContainer';
 
+const GENERATOR_RATE_MULTIPLIERS = [0, 1, 10, 100];
+
+const FAILURE_ACTIVATIONS: FailureActivation[] = ['Immediately', 'RandomWithin30Minutes', 'AboveHeight1500Feet'];
+
 export const Failures = () => {
-  const { allFailures } = useFailuresOrchestrator();
+  const { allFailures, generatorRateMultiplier, setGeneratorRateMultiplier } = useFailuresOrchestrator();
   const chapters = Array.from(new Set(allFailures.map((it) => it.ata))).sort((a, b) => a - b);
 
   const dispatch = useAppDispatch();
-  const { searchQuery } = useAppSelector((state) => state.failuresPage);
+  const { searchQuery, activation } = useAppSelector((state) => state.failuresPage);
 
   const filteredFailures = allFailures.filter((failure) => {
     if (searchQuery === '') {
@@ -77,6 +82,32 @@ export const Failures = () => {
           <Navbar basePath="/failures" tabs={tabs} />
         </div>
 
+        <div className="flex flex-row items-center justify-between">
+          <p>{t('Failures.RandomFailures')}</p>
+          <SelectGroup>
+            {GENERATOR_RATE_MULTIPLIERS.map((rateMultiplier) => (
+              <SelectItem
+                key={rateMultiplier}
+                selected={generatorRateMultiplier === rateMultiplier}
+                onSelect={() => setGeneratorRateMultiplier(rateMultiplier)}
+              >
+                {rateMultiplier === 0 ? t('Failures.Off') : `${rateMultiplier}x`}
+              </SelectItem>
+            ))}
+          </SelectGroup>
+        </div>
+
+        <div className="flex flex-row items-center justify-between">
+          <p>{t('Failures.Activation.Title')}</p>
+          <SelectGroup>
+            {FAILURE_ACTIVATIONS.map((it) => (
+              <SelectItem key={it} selected={activation === it} onSelect={() => dispatch(setActivation(it))}>
+                {t(`Failures.Activation.${it}`)}
+              </SelectItem>
+            ))}
+          </SelectGroup>
+        </div>
+
         <Route path="/failures/comfort">
           <ComfortUI filteredChapters={filteredChapters} allChapters={chapters} failures={filteredFailures} />
         </Route>
@@ -9,6 +9,7 @@ import { AtaChapterNumber, AtaChaptersTitle, Failure } from '@flybywiresim/fbw-s
 import { t } from '../../../Localization/translation';
 import { FailureButton } from '../../FailureButton';
 import { useFailuresOrchestrator } from '../../../failures-orchestrator-provider';
+import { FAILURE_ACTIVATION_TRIGGERS } from '../../../Store/features/failuresPage';
 import { ScrollableContainer } from '../../../UtilComponents/ScrollableContainer';
 import { useAppSelector } from '../../../Store/store';
 
@@ -18,15 +19,21 @@ interface AtaChapterPageProps {
 }
 
 export const AtaChapterPage = ({ chapter, failures }: AtaChapterPageProps) => {
-  const { activeFailures, activate, deactivate } = useFailuresOrchestrator();
-  const { searchQuery } = useAppSelector((state) => state.failuresPage);
+  const { activeFailures, activate, deactivate, armedFailures, arm, disarm } = useFailuresOrchestrator();
+  const { searchQuery, activation } = useAppSelector((state) => state.failuresPage);
   const filteredFailures = failures.filter((failure) => failure.ata === chapter);
 
   const handleFailureButtonClick = (failureIdentifier: number) => {
-    if (!activeFailures.has(failureIdentifier)) {
-      activate(failureIdentifier);
-    } else {
+    const trigger = FAILURE_ACTIVATION_TRIGGERS[activation];
+
+    if (activeFailures.has(failureIdentifier)) {
       deactivate(failureIdentifier);
+    } else if (armedFailures.has(failureIdentifier)) {
+      disarm(failureIdentifier);
+    } else if (trigger) {
+      arm(failureIdentifier, trigger);
+    } else {
+      activate(failureIdentifier);
     }
   };
 
@@ -68,6 +75,7 @@ export const AtaChapterPage = ({ chapter, failures }: AtaChapterPageProps) => {
               <FailureButton
                 name={failure.name}
                 isActive={activeFailures.has(failure.identifier)}
+                isArmed={armedFailures.has(failure.identifier)}
                 highlightedTerm={getHighlightedTerm(failure.name)}
                 onClick={() => handleFailureButtonClick(failure.identifier)}
                 className={`${index && index % 4 !== 0 && 'ml-4'} ${index >= 4 && 'mt-4'} h-36`}
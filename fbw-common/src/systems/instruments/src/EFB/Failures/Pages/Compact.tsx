@@ -8,6 +8,7 @@ import { t } from '../../Localization/translation';
 import { FailureButton } from '../FailureButton';
 import { useFailuresOrchestrator } from '../../failures-orchestrator-provider';
 import { useAppSelector } from '../../Store/store';
+import { FAILURE_ACTIVATION_TRIGGERS } from '../../Store/features/failuresPage';
 import { ScrollableContainer } from '../../UtilComponents/ScrollableContainer';
 
 interface FailureGroupProps {
@@ -16,8 +17,8 @@ interface FailureGroupProps {
 }
 
 const FailureGroup = ({ title, failures }: FailureGroupProps) => {
-  const { activeFailures, activate, deactivate } = useFailuresOrchestrator();
-  const { searchQuery } = useAppSelector((state) => state.failuresPage);
+  const { activeFailures, activate, deactivate, armedFailures, arm, disarm } = useFailuresOrchestrator();
+  const { searchQuery, activation } = useAppSelector((state) => state.failuresPage);
 
   const getHighlightedTerm = (failureName: string) => {
     const searchQueryIdx = failureName.toUpperCase().indexOf(searchQuery);
@@ -28,10 +29,16 @@ const FailureGroup = ({ title, failures }: FailureGroupProps) => {
   };
 
   const handleFailureButtonClick = (failureIdentifier: number) => {
-    if (!activeFailures.has(failureIdentifier)) {
-      activate(failureIdentifier);
-    } else {
+    const trigger = FAILURE_ACTIVATION_TRIGGERS[activation];
+
+    if (activeFailures.has(failureIdentifier)) {
       deactivate(failureIdentifier);
+    } else if (armedFailures.has(failureIdentifier)) {
+      disarm(failureIdentifier);
+    } else if (trigger) {
+      arm(failureIdentifier, trigger);
+    } else {
+      activate(failureIdentifier);
     }
   };
 
@@ -45,6 +52,7 @@ const FailureGroup = ({ title, failures }: FailureGroupProps) => {
             key={failure.identifier}
             name={failure.name}
             isActive={activeFailures.has(failure.identifier)}
+            isArmed={armedFailures.has(failure.identifier)}
             highlightedTerm={getHighlightedTerm(failure.name)}
             onClick={() => handleFailureButtonClick(failure.identifier)}
             className={`${index && index % 4 !== 0 && 'ml-4'} ${index >= 4 && 'mt-4'} h-36`}
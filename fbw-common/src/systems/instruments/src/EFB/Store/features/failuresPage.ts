@@ -2,12 +2,23 @@
 // SPDX-License-Identifier: GPL-3.0
 
 import { createSlice, PayloadAction } from '@reduxjs/toolkit';
+import { FailureTrigger } from '@flybywiresim/fbw-sdk';
+
+/** When a selected failure is activated: right away, or armed until its trigger condition is met. */
+export type FailureActivation = 'Immediately' | 'RandomWithin30Minutes' | 'AboveHeight1500Feet';
+
+export const FAILURE_ACTIVATION_TRIGGERS: Record<FailureActivation, FailureTrigger | null> = {
+  Immediately: null,
+  RandomWithin30Minutes: { randomWithinSeconds: [0, 1800] },
+  AboveHeight1500Feet: { heightAboveGroundAboveFeet: 1500 },
+};
 
 interface FailurePageState {
   searchQuery: string;
+  activation: FailureActivation;
 }
 
-const FailurePageInitialState: FailurePageState = { searchQuery: '' };
+const FailurePageInitialState: FailurePageState = { searchQuery: '', activation: 'Immediately' };
 
 export const failurePageSlice = createSlice({
   name: 'failurePage',
@@ -16,8 +27,11 @@ export const failurePageSlice = createSlice({
     setSearchQuery: (state, action: PayloadAction<string>) => {
       state.searchQuery = action.payload;
     },
+    setActivation: (state, action: PayloadAction<FailureActivation>) => {
+      state.activation = action.payload;
+    },
   },
 });
 
-export const { setSearchQuery } = failurePageSlice.actions;
+export const { setSearchQuery, setActivation } = failurePageSlice.actions;
 export default failurePageSlice.reducer;
@@ -0,0 +1,74 @@
+// Copyright (c) 2026 FlyByWire Simulations
+// SPDX-License-Identifier: GPL-3.0
+
+import { NXDataStore } from '@flybywiresim/fbw-sdk';
+
+/** Number of load reports kept by the structural load monitor of the aircraft. */
+export const MAX_LOAD_REPORTS = 10;
+
+const LOAD_REPORTS_KEY = 'LOAD_REPORTS';
+const LOAD_REPORT_FIELDS = ['TYPE', 'SINK_RATE', 'LOAD_FACTOR', 'GW', 'IAS'];
+
+/** The fields of a load report, in the order of {@link LOAD_REPORT_FIELDS}. */
+type LoadReportValues = number[];
+
+function readLoadReports(): LoadReportValues[] {
+  const reports: LoadReportValues[] = [];
+  for (let number = 1; number <= MAX_LOAD_REPORTS; number++) {
+    const report = LOAD_REPORT_FIELDS.map((field) =>
+      SimVar.GetSimVarValue(`L:A32NX_LOAD_REPORT_${number}_${field}`, 'number'),
+    );
+    if (report[0] === 0) {
+      break;
+    }
+    reports.push(report);
+  }
+
+  return reports;
+}
+
+function writeLoadReports(reports: LoadReportValues[]) {
+  reports.forEach((report, index) =>
+    LOAD_REPORT_FIELDS.forEach((field, fieldIndex) =>
+      SimVar.SetSimVarValue(`L:A32NX_LOAD_REPORT_${index + 1}_${field}`, 'number', report[fieldIndex]),
+    ),
+  );
+  SimVar.SetSimVarValue('L:A32NX_LOAD_REPORT_RESTORE', 'bool', true);
+}
+
+/**
+ * Keeps the load reports of the structural load monitor in persistent storage, so they remain available for
+ * maintenance until they are cleared. The stored reports are restored when the aircraft is loaded.
+ */
+export function persistLoadReports() {
+  let stored = NXDataStore.getLegacy(LOAD_REPORTS_KEY, '[]');
+  const storedReports: LoadReportValues[] = JSON.parse(stored);
+  let restoring = storedReports.length > 0;
+
+  if (restoring) {
+    writeLoadReports(storedReports);
+  }
+
+  setInterval(() => {
+    if (SimVar.GetSimVarValue('L:A32NX_LOAD_REPORT_RESTORE', 'bool')) {
+      return;
+    }
+
+    const reports = readLoadReports();
+
+    if (restoring) {
+      // The systems may have written their empty reports before they took over the restored ones
+      if (reports.length !== storedReports.length) {
+        writeLoadReports(storedReports);
+        return;
+      }
+      restoring = false;
+    }
+
+    const current = JSON.stringify(reports);
+    if (current !== stored) {
+      stored = current;
+      NXDataStore.setLegacy(LOAD_REPORTS_KEY, stored);
+    }
+  }, 1000);
+}
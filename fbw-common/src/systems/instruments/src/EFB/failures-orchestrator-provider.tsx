@@ -2,14 +2,29 @@
 // Copyright (c) 2023-2024 FlyByWire Simulations
 // SPDX-License-Identifier: GPL-3.0
 
-import React, { PropsWithChildren, useState } from 'react';
-import { Failure, FailuresOrchestrator, useUpdate, FailureDefinition } from '@flybywiresim/fbw-sdk';
+import React, { PropsWithChildren, useEffect, useState } from 'react';
+import {
+  Failure,
+  FailuresOrchestrator,
+  useUpdate,
+  FailureDefinition,
+  FailureReliability,
+  FailureTrigger,
+  usePersistentNumberProperty,
+} from '@flybywiresim/fbw-sdk';
 
 interface FailuresOrchestratorContext {
   allFailures: Readonly<Readonly<Failure>[]>;
   activeFailures: Set<number>;
   activate(identifier: number): Promise<void>;
   deactivate(identifier: number): Promise<void>;
+  /** Failures which are activated by the systems once their trigger condition is met. */
+  armedFailures: Set<number>;
+  arm(identifier: number, trigger: FailureTrigger): Promise<void>;
+  disarm(identifier: number): Promise<void>;
+  /** Multiplier of the failure rates of the random failure generator, 0 when the generator is disabled. */
+  generatorRateMultiplier: number;
+  setGeneratorRateMultiplier(rateMultiplier: number): void;
 }
 
 const createOrchestrator = (failures: FailureDefinition[]) => new FailuresOrchestrator(failures);
@@ -19,20 +34,39 @@ const Context = React.createContext<FailuresOrchestratorContext>({
   activeFailures: new Set<number>(),
   activate: () => Promise.resolve(),
   deactivate: () => Promise.resolve(),
+  armedFailures: new Set<number>(),
+  arm: () => Promise.resolve(),
+  disarm: () => Promise.resolve(),
+  generatorRateMultiplier: 0,
+  setGeneratorRateMultiplier: () => {},
 });
 
 export interface FailuresOrchestratorProviderProps {
   failures: FailureDefinition[];
+  /** Reliabilities of the components the random failure generator can fail. */
+  reliabilities?: FailureReliability[];
 }
 
 export const FailuresOrchestratorProvider: React.FC<PropsWithChildren<FailuresOrchestratorProviderProps>> = ({
   failures,
+  reliabilities = [],
   children,
 }) => {
   const [orchestrator] = useState(() => createOrchestrator(failures));
+  const [generatorRateMultiplier, setGeneratorRateMultiplier] = usePersistentNumberProperty(
+    'EFB_FAILURE_GENERATOR_RATE_MULTIPLIER',
+    0,
+  );
 
   const [allFailures] = useState(() => orchestrator.getAllFailures());
   const [activeFailures, setActiveFailures] = useState<Set<number>>(() => new Set<number>());
+  const [armedFailures, setArmedFailures] = useState<Set<number>>(() => new Set<number>());
+
+  useEffect(() => {
+    if (reliabilities.length > 0) {
+      orchestrator.configureGenerator({ rateMultiplier: generatorRateMultiplier, failures: reliabilities });
+    }
+  }, [generatorRateMultiplier]);
 
   useUpdate(() => {
     orchestrator.update();
@@ -41,6 +75,11 @@ export const FailuresOrchestratorProvider: React.FC<PropsWithChildren<FailuresOr
     if (!areEqual(activeFailures, af)) {
       setActiveFailures(af);
     }
+
+    const armed = new Set(orchestrator.getArmedFailures().map(({ identifier }) => identifier));
+    if (!areEqual(armedFailures, armed)) {
+      setArmedFailures(armed);
+    }
   });
 
   return (
@@ -50,6 +89,11 @@ export const FailuresOrchestratorProvider: React.FC<PropsWithChildren<FailuresOr
         activeFailures,
         activate: (identifier) => orchestrator.activate(identifier),
         deactivate: (identifier) => orchestrator.deactivate(identifier),
+        armedFailures,
+        arm: (identifier, trigger) => orchestrator.arm(identifier, trigger),
+        disarm: (identifier) => orchestrator.disarm(identifier),
+        generatorRateMultiplier,
+        setGeneratorRateMultiplier,
       }}
     >
       {children}
@@ -12,24 +12,33 @@ vitest.mock('../GenericDataListenerSync', () => ({
   }),
 }));
 
-let sendRequestForFailures = undefined;
+const commBusCallbacks = new Map<string, (data?: string) => void>();
+const sendRequestForFailures = () => commBusCallbacks.get('FBW_FAILURE_REQUEST')();
+const sendTriggeredFailures = (data: string) => commBusCallbacks.get('FBW_FAILURE_TRIGGERED')(data);
+const sendInjectedFailures = (data: string) => commBusCallbacks.get('FBW_FAILURE_INJECTED')(data);
 
 vitest.mock('../ViewListenerUtils', () => ({
   ViewListenerUtils: {
     getListener: () =>
       Promise.resolve({
-        on: (topic, requestFailuresCallback) => (sendRequestForFailures = requestFailuresCallback),
+        on: (topic, callback) => commBusCallbacks.set(topic, callback),
       }),
   },
 }));
 
 // mock enough of COMM BUS to ensure the right calls are made for WASM interop
 const failuresUpdateReceiver = vitest.fn();
+const failuresArmReceiver = vitest.fn();
+const failuresGeneratorReceiver = vitest.fn();
 (global as any).RegisterGenericDataListener = vitest.fn();
 (global as any).Coherent = {
   call: (event, data0, data1) => {
     if (event === 'COMM_BUS_WASM_CALLBACK' && data0 === 'FBW_FAILURE_UPDATE') {
       failuresUpdateReceiver(data1);
+    } else if (event === 'COMM_BUS_WASM_CALLBACK' && data0 === 'FBW_FAILURE_ARM') {
+      failuresArmReceiver(data1);
+    } else if (event === 'COMM_BUS_WASM_CALLBACK' && data0 === 'FBW_FAILURE_GENERATOR') {
+      failuresGeneratorReceiver(data1);
     }
   },
 };
@@ -102,6 +111,121 @@ describe('FailuresOrchestrator', () => {
       expect(failuresUpdateReceiver).toHaveBeenCalledTimes(1);
       expect(failuresUpdateReceiver.mock.lastCall[0]).toBe('[123]');
     });
+
+    test('sends the severity of partial failures', async () => {
+      const o = await orchestrator();
+
+      o.update();
+
+      failuresUpdateReceiver.mockReset();
+
+      o.activate(identifier, 0.25);
+
+      o.update();
+
+      expect(failuresUpdateReceiver).toHaveBeenCalledTimes(1);
+      expect(failuresUpdateReceiver.mock.lastCall[0]).toBe('[[123,0.25]]');
+      expect(o.getSeverity(identifier)).toBe(0.25);
+    });
+  });
+
+  describe('armed failures', () => {
+    test('sends armed failures when armed', async () => {
+      const o = await orchestrator();
+
+      o.update();
+
+      failuresArmReceiver.mockReset();
+
+      o.arm(identifier, { heightAboveGroundAboveFeet: 1000 }, 0.5);
+
+      o.update();
+
+      expect(o.isArmed(identifier)).toBe(true);
+      expect(failuresArmReceiver).toHaveBeenCalledTimes(1);
+      expect(failuresArmReceiver.mock.lastCall[0]).toBe(
+        '[{"id":123,"severity":0.5,"trigger":{"heightAboveGroundAboveFeet":1000}}]',
+      );
+    });
+
+    test('activates and disarms a failure when triggered', async () => {
+      const o = await orchestrator();
+
+      o.arm(identifier, { flightPhase: 'climb' });
+      o.update();
+
+      failuresUpdateReceiver.mockReset();
+      failuresArmReceiver.mockReset();
+
+      sendTriggeredFailures('[[123,1.0]]');
+
+      o.update();
+
+      expect(o.isActive(identifier)).toBe(true);
+      expect(o.isArmed(identifier)).toBe(false);
+      expect(failuresUpdateReceiver.mock.lastCall[0]).toBe('[123]');
+      expect(failuresArmReceiver.mock.lastCall[0]).toBe('[]');
+    });
+
+    test('does not arm a failure again once triggered', async () => {
+      const o = await orchestrator();
+
+      o.arm(identifier, { flightPhase: 'climb' });
+      o.update();
+
+      // the armed failures are resent before the trigger is received
+      sendRequestForFailures();
+      o.update();
+      sendTriggeredFailures('[[123,1.0]]');
+      o.update();
+
+      failuresArmReceiver.mockReset();
+
+      o.arm(identifier, { flightPhase: 'climb' });
+      o.update();
+
+      expect(o.isActive(identifier)).toBe(true);
+      expect(o.isArmed(identifier)).toBe(false);
+      expect(failuresArmReceiver).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('random failure generator', () => {
+    test('sends the generator configuration when configured and requested', async () => {
+      const o = await orchestrator();
+
+      failuresGeneratorReceiver.mockReset();
+
+      o.update();
+
+      expect(failuresGeneratorReceiver).not.toHaveBeenCalled();
+
+      o.configureGenerator({
+        rateMultiplier: 10,
+        seed: 42,
+        failures: [{ identifier, mtbfHours: 5000, exposure: { flightPhase: 'descent' } }],
+      });
+      o.update();
+
+      const configuration =
+        '{"rateMultiplier":10,"seed":42,"failures":[{"id":123,"mtbfHours":5000,"exposure":{"flightPhase":"descent"}}]}';
+      expect(failuresGeneratorReceiver).toHaveBeenCalledTimes(1);
+      expect(failuresGeneratorReceiver.mock.lastCall[0]).toBe(configuration);
+
+      sendRequestForFailures();
+      o.update();
+
+      expect(failuresGeneratorReceiver).toHaveBeenCalledTimes(2);
+      expect(failuresGeneratorReceiver.mock.lastCall[0]).toBe(configuration);
+    });
+
+    test('reports the injected failures', async () => {
+      const o = await orchestrator();
+
+      sendInjectedFailures('[[123,600.5,null]]');
+
+      expect(o.getInjectedFailures()).toEqual([{ identifier, injectedAtSeconds: 600.5, revealedAtSeconds: null }]);
+    });
   });
 
   describe('sends failures over generic data listener sync', () => {
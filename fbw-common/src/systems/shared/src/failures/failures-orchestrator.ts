@@ -14,6 +14,51 @@ export interface Failure {
 
 export type FailureDefinition = [AtaChapterNumber, number, string];
 
+/**
+ * Condition upon which an armed failure is activated by the systems.
+ */
+export type FailureTrigger =
+  | { heightAboveGroundAboveFeet: number }
+  | { heightAboveGroundBelowFeet: number }
+  | { indicatedAirspeedAboveKnots: number }
+  | { indicatedAirspeedBelowKnots: number }
+  | { flightPhase: 'ground' | 'climb' | 'cruise' | 'descent' }
+  | { timeAfterTakeoffSeconds: number }
+  | { randomWithinSeconds: [number, number] };
+
+export interface ArmedFailure {
+  identifier: number;
+  severity: number;
+  trigger: FailureTrigger;
+}
+
+/**
+ * Reliability of a component, from which the systems randomly generate its failure.
+ */
+export interface FailureReliability {
+  identifier: number;
+  /** Mean time between failures of the component, in flight hours. */
+  mtbfHours: number;
+  /** When set, the failure is latent and stays dormant until the system is exercised as per this trigger. */
+  exposure?: FailureTrigger;
+}
+
+export interface FailureGeneratorConfiguration {
+  /** Multiplier applied to all failure rates, 0 disables the generator. */
+  rateMultiplier: number;
+  /** Seed of the random generator, to reproduce the same failures. */
+  seed?: number;
+  failures: FailureReliability[];
+}
+
+export interface InjectedFailure {
+  identifier: number;
+  /** Time at which the failure occurred, in seconds since the generator was configured. */
+  injectedAtSeconds: number;
+  /** Time at which the failure became active, or null while the failure is dormant. */
+  revealedAtSeconds: number | null;
+}
+
 /**
  * Orchestrates the activation and deactivation of failures.
  *
@@ -31,8 +76,21 @@ export class FailuresOrchestrator {
 
   private activeFailures = new Set<number>();
 
+  /** Severities of the active failures which are not complete failures. */
+  private failureSeverities = new Map<number, number>();
+
+  private armedFailures = new Map<number, ArmedFailure>();
+
+  private generatorConfiguration: FailureGeneratorConfiguration | undefined;
+
+  private injectedFailures: InjectedFailure[] = [];
+
   private needSendFailures = true;
 
+  private needSendArmedFailures = true;
+
+  private needSendGeneratorConfiguration = false;
+
   constructor(failures: FailureDefinition[]) {
     failures.forEach((failure) => {
       this.failures.push({
@@ -43,14 +101,70 @@ export class FailuresOrchestrator {
     });
 
     ViewListenerUtils.getListener('JS_LISTENER_COMM_BUS').then((commBusListener) => {
-      commBusListener.on('FBW_FAILURE_REQUEST', () => (this.needSendFailures = true));
+      commBusListener.on('FBW_FAILURE_REQUEST', () => {
+        this.needSendFailures = true;
+        this.needSendArmedFailures = true;
+        this.needSendGeneratorConfiguration = this.generatorConfiguration !== undefined;
+      });
+      commBusListener.on('FBW_FAILURE_TRIGGERED', this.onFailuresTriggered.bind(this));
+      commBusListener.on('FBW_FAILURE_INJECTED', this.onFailuresInjected.bind(this));
       // better send in case we missed a request from a wasm consumer
       this.needSendFailures = true;
+      this.needSendArmedFailures = true;
     });
   }
 
   private sendFailuresToWasm(activeFailures: number[]): void {
-    Coherent.call('COMM_BUS_WASM_CALLBACK', 'FBW_FAILURE_UPDATE', JSON.stringify(activeFailures));
+    const failures = activeFailures.map((identifier) =>
+      this.failureSeverities.has(identifier) ? [identifier, this.failureSeverities.get(identifier)] : identifier,
+    );
+    Coherent.call('COMM_BUS_WASM_CALLBACK', 'FBW_FAILURE_UPDATE', JSON.stringify(failures));
+  }
+
+  private sendArmedFailuresToWasm(): void {
+    const armedFailures = Array.from(this.armedFailures.values()).map(({ identifier, severity, trigger }) => ({
+      id: identifier,
+      severity,
+      trigger,
+    }));
+    Coherent.call('COMM_BUS_WASM_CALLBACK', 'FBW_FAILURE_ARM', JSON.stringify(armedFailures));
+  }
+
+  private sendGeneratorConfigurationToWasm(): void {
+    const { rateMultiplier, seed, failures } = this.generatorConfiguration;
+    const configuration = {
+      rateMultiplier,
+      seed,
+      failures: failures.map(({ identifier, mtbfHours, exposure }) => ({ id: identifier, mtbfHours, exposure })),
+    };
+    Coherent.call('COMM_BUS_WASM_CALLBACK', 'FBW_FAILURE_GENERATOR', JSON.stringify(configuration));
+  }
+
+  /**
+   * Handles the report of the failures injected by the random failure generator,
+   * sent as a list of `[identifier, injected at, revealed at]`.
+   */
+  private onFailuresInjected(data: string): void {
+    const injectedFailures: [number, number, number | null][] = JSON.parse(data);
+    this.injectedFailures = injectedFailures.map(([identifier, injectedAtSeconds, revealedAtSeconds]) => ({
+      identifier,
+      injectedAtSeconds,
+      revealedAtSeconds,
+    }));
+  }
+
+  /**
+   * Handles armed failures whose trigger condition was met in the systems,
+   * sent as a list of `[identifier, severity]` pairs.
+   */
+  private onFailuresTriggered(data: string): void {
+    const triggeredFailures: [number, number][] = JSON.parse(data);
+    for (const [identifier, severity] of triggeredFailures) {
+      if (this.armedFailures.delete(identifier)) {
+        this.needSendArmedFailures = true;
+      }
+      this.activate(identifier, severity);
+    }
   }
 
   private sendFailuresToJs(activeFailures: number[]): void {
@@ -60,6 +174,7 @@ export class FailuresOrchestrator {
   private onDataListenerMessage(topic: string): void {
     if (topic === 'FBW_FAILURE_REQUEST') {
       this.needSendFailures = true;
+      this.needSendArmedFailures = true;
     }
   }
 
@@ -70,13 +185,34 @@ export class FailuresOrchestrator {
       this.sendFailuresToWasm(failures);
       this.sendFailuresToJs(failures);
     }
+
+    if (this.needSendArmedFailures) {
+      this.needSendArmedFailures = false;
+      this.sendArmedFailuresToWasm();
+    }
+
+    if (this.needSendGeneratorConfiguration) {
+      this.needSendGeneratorConfiguration = false;
+      this.sendGeneratorConfigurationToWasm();
+    }
   }
 
   /**
    * Activates the failure with the given identifier.
+   * @param severity Severity of the failure between 0 and 1, where 1 is a complete failure.
+   * A failure with a severity of 0 is inactive, as in the systems.
    */
-  async activate(identifier: number): Promise<void> {
+  async activate(identifier: number, severity = 1): Promise<void> {
+    if (severity <= 0) {
+      return this.deactivate(identifier);
+    }
+
     this.activeFailures.add(identifier);
+    if (severity < 1) {
+      this.failureSeverities.set(identifier, severity);
+    } else {
+      this.failureSeverities.delete(identifier);
+    }
     this.needSendFailures = true;
   }
 
@@ -85,9 +221,75 @@ export class FailuresOrchestrator {
    */
   async deactivate(identifier: number): Promise<void> {
     this.activeFailures.delete(identifier);
+    this.failureSeverities.delete(identifier);
     this.needSendFailures = true;
   }
 
+  /**
+   * Arms the failure with the given identifier, such that it is activated once the trigger condition is met.
+   * A failure which is already active isn't armed.
+   * @param severity Severity of the failure once activated, between 0 and 1.
+   * A failure with a severity of 0 is disarmed instead.
+   */
+  async arm(identifier: number, trigger: FailureTrigger, severity = 1): Promise<void> {
+    if (severity <= 0) {
+      return this.disarm(identifier);
+    }
+
+    if (this.activeFailures.has(identifier)) {
+      return;
+    }
+
+    this.armedFailures.set(identifier, { identifier, severity: Math.min(1, severity), trigger });
+    this.needSendArmedFailures = true;
+  }
+
+  /**
+   * Disarms the failure with the given identifier.
+   */
+  async disarm(identifier: number): Promise<void> {
+    this.armedFailures.delete(identifier);
+    this.needSendArmedFailures = true;
+  }
+
+  /**
+   * Determines whether or not the failure with the given identifier is armed.
+   */
+  isArmed(identifier: number): boolean {
+    return this.armedFailures.has(identifier);
+  }
+
+  /**
+   * Gets the severity of the failure with the given identifier, or 0 when the failure isn't active.
+   */
+  getSeverity(identifier: number): number {
+    if (!this.activeFailures.has(identifier)) {
+      return 0;
+    }
+    return this.failureSeverities.get(identifier) ?? 1;
+  }
+
+  /**
+   * Configures the random failure generator of the systems, replacing any previous configuration.
+   * Failures generated are activated as they become active in the systems.
+   */
+  async configureGenerator(configuration: FailureGeneratorConfiguration): Promise<void> {
+    this.generatorConfiguration = configuration;
+    this.injectedFailures = [];
+    this.needSendGeneratorConfiguration = true;
+  }
+
+  /**
+   * Gets the failures injected by the random failure generator, including the dormant ones.
+   */
+  getInjectedFailures(): Readonly<InjectedFailure>[] {
+    return this.injectedFailures;
+  }
+
+  getArmedFailures(): ArmedFailure[] {
+    return Array.from(this.armedFailures.values());
+  }
+
   /**
    * Determines whether or not the failure with the given identifier is active.
    */
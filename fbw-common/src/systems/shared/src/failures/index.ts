@@ -4,4 +4,11 @@
 
 export { FailuresConsumer } from './failures-consumer';
 export { FailuresOrchestrator, FailureDefinition } from './failures-orchestrator';
-export type { Failure } from './failures-orchestrator';
+export type {
+  Failure,
+  ArmedFailure,
+  FailureTrigger,
+  FailureReliability,
+  FailureGeneratorConfiguration,
+  InjectedFailure,
+} from './failures-orchestrator';
@@ -0,0 +1,4 @@
+//! Scenario harness for characterising hydraulic circuits outside of the simulator.
+//! It relies on the test infrastructure of the systems crate, so it is only part of this tool.
+
+pub mod simulation_harness;
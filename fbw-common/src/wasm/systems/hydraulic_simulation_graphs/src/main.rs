@@ -0,0 +1,349 @@
+use std::{env, path::PathBuf, time::Duration};
+
+use hydraulic_simulation_graphs::simulation_harness::{
+    HarnessCommand, HydraulicHarnessAircraft, HydraulicHarnessLoad, HydraulicSimulationHarness,
+};
+use systems::{
+    hydraulic::{
+        linear_actuator::{
+            ElectroHydrostaticPowered, HydraulicAssemblyController,
+            HydraulicLinearActuatorAssembly, HydraulicLocking, LinearActuatorMode,
+        },
+        pumps::PumpCharacteristics,
+        ElectricPump, EngineDrivenPump, HydraulicCircuit, PowerTransferUnit,
+    },
+    shared::{AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, SectionPressure},
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+use uom::si::{
+    angular_velocity::revolution_per_minute, electric_current::ampere, f64::*, ratio::ratio,
+    volume_rate::gallon_per_second,
+};
+
+use a320_systems::hydraulic::{A320HydraulicCircuitFactory, A320PowerTransferUnitCharacteristics};
+use a380_systems::hydraulic::{A380HydraulicCircuitFactory, A380RudderFactory};
+
+const STEP: Duration = Duration::from_millis(33);
+const EDP_SPEED_RPM: f64 = 4000.;
+
+// 427XP - AC ESS
+const A380_UPPER_RUDDER_YELLOW_EBHA_BUS: ElectricalBusType =
+    ElectricalBusType::AlternatingCurrentEssential;
+// 247XP - ACEHA
+const A380_UPPER_RUDDER_GREEN_EBHA_BUS: ElectricalBusType =
+    ElectricalBusType::AlternatingCurrentNamed("247XP");
+
+/// Runs every scenario and writes its csv and svg plots to the directory given as first
+/// argument, or to the current directory.
+fn main() {
+    let path: PathBuf = env::args().nth(1).unwrap_or_else(|| ".".to_owned()).into();
+
+    println!("Launching hyd simulation...");
+
+    a320_blue_circuit_epump(&path);
+    a320_green_yellow_ptu(&path);
+    a380_green_circuit_edps_and_epumps(&path);
+    a380_upper_rudder_ebha(&path);
+
+    println!("Plots written to {}", path.display());
+}
+
+fn pressurise<L: HydraulicHarnessLoad>(
+    circuit: usize,
+    pump: usize,
+    pressurise: bool,
+) -> HarnessCommand<L> {
+    HarnessCommand::Pressurise {
+        circuit,
+        pump,
+        pressurise,
+    }
+}
+
+fn edp_speed<L: HydraulicHarnessLoad>(circuit: usize, pump: usize, rpm: f64) -> HarnessCommand<L> {
+    HarnessCommand::EngineDrivenPumpSpeed {
+        circuit,
+        pump,
+        speed: AngularVelocity::new::<revolution_per_minute>(rpm),
+    }
+}
+
+fn flow_demand<L: HydraulicHarnessLoad>(circuit: usize, gallon_per_s: f64) -> HarnessCommand<L> {
+    HarnessCommand::FlowDemand {
+        circuit,
+        flow: VolumeRate::new::<gallon_per_second>(gallon_per_s),
+    }
+}
+
+fn rudder(command: impl Fn(&mut A380UpperRudder) + 'static) -> HarnessCommand<A380UpperRudder> {
+    HarnessCommand::Load(Box::new(command))
+}
+
+#[derive(Clone, Copy)]
+struct RudderActuatorController {
+    mode: LinearActuatorMode,
+    electrical_mode: bool,
+    requested_position: Ratio,
+}
+impl HydraulicAssemblyController for RudderActuatorController {
+    fn requested_mode(&self) -> LinearActuatorMode {
+        self.mode
+    }
+
+    fn requested_position(&self) -> Ratio {
+        self.requested_position
+    }
+
+    fn should_lock(&self) -> bool {
+        false
+    }
+
+    fn requested_lock_position(&self) -> Ratio {
+        Ratio::default()
+    }
+}
+impl HydraulicLocking for RudderActuatorController {}
+impl ElectroHydrostaticPowered for RudderActuatorController {
+    fn should_activate_electrical_mode(&self) -> bool {
+        self.electrical_mode
+    }
+}
+
+/// Upper rudder panel of the A380, moved by a yellow and a green actuator which both have an
+/// electrical backup hydraulic actuator (EBHA) to keep control when their circuit is lost.
+/// Actuators are connected to the circuits of the same index.
+struct A380UpperRudder {
+    assembly: HydraulicLinearActuatorAssembly<2>,
+    controllers: [RudderActuatorController; 2],
+}
+impl A380UpperRudder {
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            assembly: A380RudderFactory::a380_rudder_assembly(
+                context,
+                true,
+                true,
+                A380_UPPER_RUDDER_YELLOW_EBHA_BUS,
+                A380_UPPER_RUDDER_GREEN_EBHA_BUS,
+            ),
+            controllers: [RudderActuatorController {
+                mode: LinearActuatorMode::PositionControl,
+                electrical_mode: false,
+                requested_position: Ratio::new::<ratio>(0.5),
+            }; 2],
+        }
+    }
+
+    /// Requests a [0;1] rudder position, 0.5 being centered
+    fn set_requested_position(&mut self, position: f64) {
+        for controller in &mut self.controllers {
+            controller.requested_position = Ratio::new::<ratio>(position);
+        }
+    }
+
+    fn set_electrical_mode(&mut self, electrical_mode: bool) {
+        for controller in &mut self.controllers {
+            controller.electrical_mode = electrical_mode;
+        }
+    }
+}
+impl HydraulicHarnessLoad for A380UpperRudder {
+    fn update(&mut self, context: &UpdateContext, circuits: &mut [HydraulicCircuit]) {
+        self.assembly.update_fluid_viscosity([
+            circuits[0].system_section().fluid_viscosity(),
+            circuits[1].system_section().fluid_viscosity(),
+        ]);
+        self.assembly.update(
+            context,
+            &self.controllers,
+            [
+                circuits[0]
+                    .system_section()
+                    .pressure_downstream_leak_valve(),
+                circuits[1]
+                    .system_section()
+                    .pressure_downstream_leak_valve(),
+            ],
+        );
+
+        for (actuator_index, circuit) in circuits.iter_mut().enumerate() {
+            circuit.update_system_actuator_volumes(self.assembly.actuator(actuator_index));
+        }
+    }
+}
+impl SimulationElement for A380UpperRudder {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.assembly.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+fn a320_blue_circuit_epump(path: &PathBuf) {
+    HydraulicSimulationHarness::new(|context| {
+        let circuit = A320HydraulicCircuitFactory::new_blue_circuit(context);
+        let pump = ElectricPump::new(
+            context,
+            AirbusElectricPumpId::Blue,
+            ElectricalBusType::AlternatingCurrent(1),
+            ElectricCurrent::new::<ampere>(45.),
+            PumpCharacteristics::a320_electric_pump(),
+        );
+
+        HydraulicHarnessAircraft::new(context)
+            .with_circuit(circuit)
+            .with_electric_pump(context, 0, pump, ElectricalBusType::AlternatingCurrent(1))
+    })
+    .record_circuit(0, "Blue")
+    .at(Duration::ZERO, pressurise(0, 0, true))
+    .at(Duration::from_secs(10), flow_demand(0, 0.05))
+    .at(Duration::from_secs(17), pressurise(0, 0, false))
+    .run(Duration::from_secs(33), STEP)
+    .save(path, "a320_blue_circuit_epump")
+    .unwrap();
+}
+
+fn a320_green_yellow_ptu(path: &PathBuf) {
+    HydraulicSimulationHarness::new(|context| {
+        let green = A320HydraulicCircuitFactory::new_green_circuit(context);
+        let yellow = A320HydraulicCircuitFactory::new_yellow_circuit(context, Volume::default());
+        let green_edp = EngineDrivenPump::new(
+            context,
+            AirbusEngineDrivenPumpId::Green,
+            PumpCharacteristics::a320_edp(),
+        );
+        let yellow_edp = EngineDrivenPump::new(
+            context,
+            AirbusEngineDrivenPumpId::Yellow,
+            PumpCharacteristics::a320_edp(),
+        );
+        let ptu = PowerTransferUnit::new(
+            context,
+            &A320PowerTransferUnitCharacteristics::new_randomized(),
+        );
+
+        HydraulicHarnessAircraft::new(context)
+            .with_circuit(green)
+            .with_circuit(yellow)
+            .with_engine_driven_pump(0, green_edp)
+            .with_engine_driven_pump(1, yellow_edp)
+            .with_power_transfer_unit(ptu, 0, 1)
+    })
+    .record_circuit(0, "Green")
+    .record_circuit(1, "Yellow")
+    .record_power_transfer_unit()
+    .at(Duration::ZERO, pressurise(0, 0, true))
+    .at(Duration::ZERO, pressurise(1, 0, true))
+    .at(Duration::ZERO, edp_speed(0, 0, EDP_SPEED_RPM))
+    .at(Duration::ZERO, edp_speed(1, 0, EDP_SPEED_RPM))
+    .at(Duration::ZERO, HarnessCommand::PowerTransferUnit(true))
+    .at(Duration::from_secs(10), edp_speed(0, 0, 0.))
+    .at(Duration::from_secs(20), flow_demand(0, 0.2))
+    .run(Duration::from_secs(40), STEP)
+    .save(path, "a320_green_yellow_ptu")
+    .unwrap();
+}
+
+fn a380_green_circuit_edps_and_epumps(path: &PathBuf) {
+    HydraulicSimulationHarness::new(|context| {
+        let mut aircraft = HydraulicHarnessAircraft::new(context)
+            .with_circuit(A380HydraulicCircuitFactory::new_green_circuit(context));
+
+        for id in [
+            AirbusEngineDrivenPumpId::Edp1a,
+            AirbusEngineDrivenPumpId::Edp1b,
+            AirbusEngineDrivenPumpId::Edp2a,
+            AirbusEngineDrivenPumpId::Edp2b,
+        ] {
+            let pump = EngineDrivenPump::new(context, id, PumpCharacteristics::a380_edp());
+            aircraft = aircraft.with_engine_driven_pump(0, pump);
+        }
+
+        for (id, bus) in [
+            (
+                AirbusElectricPumpId::GreenA,
+                ElectricalBusType::AlternatingCurrent(1),
+            ),
+            (
+                AirbusElectricPumpId::GreenB,
+                ElectricalBusType::AlternatingCurrent(2),
+            ),
+        ] {
+            let pump = ElectricPump::new(
+                context,
+                id,
+                bus,
+                ElectricCurrent::new::<ampere>(75.),
+                PumpCharacteristics::a380_electric_pump(),
+            );
+            aircraft = aircraft.with_electric_pump(context, 0, pump, bus);
+        }
+
+        aircraft
+    })
+    .record_circuit(0, "Green")
+    .at(Duration::ZERO, pressurise(0, 4, true))
+    .at(Duration::ZERO, pressurise(0, 5, true))
+    .at(Duration::from_secs(10), flow_demand(0, 0.3))
+    .at(Duration::from_secs(15), pressurise(0, 0, true))
+    .at(Duration::from_secs(15), pressurise(0, 1, true))
+    .at(Duration::from_secs(15), edp_speed(0, 0, EDP_SPEED_RPM))
+    .at(Duration::from_secs(15), edp_speed(0, 1, EDP_SPEED_RPM))
+    .at(Duration::from_secs(25), pressurise(0, 4, false))
+    .at(Duration::from_secs(25), pressurise(0, 5, false))
+    .run(Duration::from_secs(40), STEP)
+    .save(path, "a380_green_circuit_edps_and_epumps")
+    .unwrap();
+}
+
+/// Yellow and green circuits are lost while the rudder is deflected, then the EBHAs take over and
+/// keep moving the rudder on electrical power
+fn a380_upper_rudder_ebha(path: &PathBuf) {
+    HydraulicSimulationHarness::new(|context| {
+        let rudder = A380UpperRudder::new(context);
+        let yellow_edp = EngineDrivenPump::new(
+            context,
+            AirbusEngineDrivenPumpId::Edp3a,
+            PumpCharacteristics::a380_edp(),
+        );
+        let green_edp = EngineDrivenPump::new(
+            context,
+            AirbusEngineDrivenPumpId::Edp1a,
+            PumpCharacteristics::a380_edp(),
+        );
+
+        HydraulicHarnessAircraft::new_with_load(context, rudder)
+            .with_circuit(A380HydraulicCircuitFactory::new_yellow_circuit(context))
+            .with_circuit(A380HydraulicCircuitFactory::new_green_circuit(context))
+            .with_engine_driven_pump(0, yellow_edp)
+            .with_engine_driven_pump(1, green_edp)
+            .with_powered_bus(context, A380_UPPER_RUDDER_YELLOW_EBHA_BUS)
+            .with_powered_bus(context, A380_UPPER_RUDDER_GREEN_EBHA_BUS)
+    })
+    .record_circuit(0, "Yellow")
+    .record_circuit(1, "Green")
+    .record("Rudder position [ratio]", |a| {
+        a.load().assembly.position_normalized().get::<ratio>()
+    })
+    .at(Duration::ZERO, pressurise(0, 0, true))
+    .at(Duration::ZERO, pressurise(1, 0, true))
+    .at(Duration::ZERO, edp_speed(0, 0, EDP_SPEED_RPM))
+    .at(Duration::ZERO, edp_speed(1, 0, EDP_SPEED_RPM))
+    .at(
+        Duration::from_secs(5),
+        rudder(|r| r.set_requested_position(0.8)),
+    )
+    .at(Duration::from_secs(10), edp_speed(0, 0, 0.))
+    .at(Duration::from_secs(10), edp_speed(1, 0, 0.))
+    .at(
+        Duration::from_secs(15),
+        rudder(|r| r.set_electrical_mode(true)),
+    )
+    .at(
+        Duration::from_secs(15),
+        rudder(|r| r.set_requested_position(0.2)),
+    )
+    .run(Duration::from_secs(30), STEP)
+    .save(path, "a380_upper_rudder_ebha")
+    .unwrap();
+}
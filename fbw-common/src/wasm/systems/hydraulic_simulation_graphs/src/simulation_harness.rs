@@ -0,0 +1,1001 @@
+use systems::{
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    hydraulic::{
+        linear_actuator::Actuator, ElectricPump, EngineDrivenPump, HeatingPressureSource,
+        HydraulicCircuit, HydraulicCircuitController, PowerTransferUnit,
+        PowerTransferUnitController, PressureSource, PumpController,
+    },
+    shared::{ElectricalBusType, PotentialOrigin, SectionPressure},
+    simulation::{
+        test::{SimulationTestBed, TestBed},
+        Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
+    },
+};
+
+use std::{
+    fmt::Write as FmtWrite,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    time::Duration,
+};
+
+use uom::si::{
+    angular_velocity::revolution_per_minute, electric_potential::volt, f64::*, pressure::psi,
+    ratio::ratio, thermodynamic_temperature::degree_celsius, volume::gallon,
+    volume_rate::gallon_per_second,
+};
+
+/// Anything driven by the harness on top of the circuits, for example an actuator assembly
+/// with an electro hydrostatic backup. It is updated every step before the circuits, so it can
+/// read section pressures and report the volumes it used to the circuits.
+pub trait HydraulicHarnessLoad: SimulationElement {
+    fn update(&mut self, context: &UpdateContext, circuits: &mut [HydraulicCircuit]);
+}
+
+/// Harness load for pure circuit characterisation: nothing is connected to the circuits.
+pub struct NoLoad;
+impl HydraulicHarnessLoad for NoLoad {
+    fn update(&mut self, _: &UpdateContext, _: &mut [HydraulicCircuit]) {}
+}
+impl SimulationElement for NoLoad {}
+
+/// A pump connected to one of the pump sections of a harness circuit
+pub enum HarnessPump {
+    Electric(Box<ElectricPump>),
+    EngineDriven(Box<EngineDrivenPump>, AngularVelocity),
+}
+impl HarnessPump {
+    pub fn flow(&self) -> VolumeRate {
+        match self {
+            HarnessPump::Electric(pump) => pump.flow(),
+            HarnessPump::EngineDriven(pump, _) => pump.flow(),
+        }
+    }
+
+    pub fn speed(&self) -> AngularVelocity {
+        match self {
+            HarnessPump::Electric(pump) => pump.speed(),
+            HarnessPump::EngineDriven(_, speed) => *speed,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        circuit: &HydraulicCircuit,
+        pump_index: usize,
+        controller: &HarnessPumpController,
+    ) {
+        match self {
+            HarnessPump::Electric(pump) => pump.update(
+                context,
+                circuit.pump_section(pump_index),
+                circuit.reservoir(),
+                controller,
+            ),
+            HarnessPump::EngineDriven(pump, speed) => pump.update(
+                context,
+                circuit.pump_section(pump_index),
+                circuit.reservoir(),
+                *speed,
+                controller,
+            ),
+        }
+    }
+
+    fn pressure_source_mut(&mut self) -> &mut dyn HeatingPressureSource {
+        match self {
+            HarnessPump::Electric(pump) => pump.as_mut(),
+            HarnessPump::EngineDriven(pump, _) => pump.as_mut(),
+        }
+    }
+}
+impl SimulationElement for HarnessPump {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        match self {
+            HarnessPump::Electric(pump) => pump.accept(visitor),
+            HarnessPump::EngineDriven(pump, _) => pump.accept(visitor),
+        }
+
+        visitor.visit(self);
+    }
+}
+
+struct HarnessPumpController {
+    should_pressurise: bool,
+}
+impl PumpController for HarnessPumpController {
+    fn should_pressurise(&self) -> bool {
+        self.should_pressurise
+    }
+}
+
+struct HarnessCircuitController {
+    should_route_pump_to_auxiliary: Vec<bool>,
+}
+impl HydraulicCircuitController for HarnessCircuitController {
+    fn should_open_fire_shutoff_valve(&self, _: usize) -> bool {
+        true
+    }
+
+    fn should_open_leak_measurement_valve(&self) -> bool {
+        true
+    }
+
+    fn should_route_pump_to_auxiliary(&self, pump_index: usize) -> bool {
+        self.should_route_pump_to_auxiliary
+            .get(pump_index)
+            .copied()
+            .unwrap_or(false)
+    }
+}
+
+struct HarnessPowerTransferUnitController {
+    should_enable: bool,
+}
+impl PowerTransferUnitController for HarnessPowerTransferUnitController {
+    fn should_enable(&self) -> bool {
+        self.should_enable
+    }
+}
+
+/// A consumer drawing a flow from the system section of a circuit, returning it to the reservoir.
+/// Flow is scaled with the section pressure, as a fixed orifice would do.
+struct FlowDemand {
+    flow_at_nominal_pressure: VolumeRate,
+    nominal_pressure: Pressure,
+    volume: Volume,
+}
+impl FlowDemand {
+    fn new(nominal_pressure: Pressure) -> Self {
+        Self {
+            flow_at_nominal_pressure: VolumeRate::default(),
+            nominal_pressure,
+            volume: Volume::default(),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, section: &impl SectionPressure) {
+        let pressure_ratio = (section.pressure() / self.nominal_pressure)
+            .get::<ratio>()
+            .clamp(0., 1.);
+
+        self.volume += self.flow_at_nominal_pressure * pressure_ratio * context.delta_as_time();
+    }
+}
+impl Actuator for FlowDemand {
+    fn used_volume(&self) -> Volume {
+        self.volume
+    }
+
+    fn reservoir_return(&self) -> Volume {
+        self.volume
+    }
+
+    fn reset_volumes(&mut self) {
+        self.volume = Volume::default();
+    }
+}
+
+struct HarnessPowerTransferUnit {
+    ptu: PowerTransferUnit,
+    controller: HarnessPowerTransferUnitController,
+    left_circuit: usize,
+    right_circuit: usize,
+}
+
+/// Aircraft made of any set of hydraulic circuits built from an aircraft factory, their pumps,
+/// an optional power transfer unit between two of them, and an optional load.
+///
+/// Pumps must be added in the order of the pump sections of their circuit.
+pub struct HydraulicHarnessAircraft<L: HydraulicHarnessLoad = NoLoad> {
+    circuits: Vec<HydraulicCircuit>,
+    circuit_controllers: Vec<HarnessCircuitController>,
+    pumps: Vec<Vec<HarnessPump>>,
+    pump_controllers: Vec<Vec<HarnessPumpController>>,
+    flow_demands: Vec<FlowDemand>,
+    power_transfer_unit: Option<HarnessPowerTransferUnit>,
+    load: L,
+
+    reservoir_air_pressure: Pressure,
+
+    powered_source: TestElectricitySource,
+    buses: Vec<ElectricalBus>,
+    bus_types: Vec<ElectricalBusType>,
+}
+impl HydraulicHarnessAircraft<NoLoad> {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self::new_with_load(context, NoLoad)
+    }
+}
+impl<L: HydraulicHarnessLoad> HydraulicHarnessAircraft<L> {
+    const NOMINAL_RESERVOIR_AIR_PRESSURE_PSI: f64 = 50.;
+    const NOMINAL_DEMAND_PRESSURE_PSI: f64 = 3000.;
+
+    pub fn new_with_load(context: &mut InitContext, load: L) -> Self {
+        Self {
+            circuits: vec![],
+            circuit_controllers: vec![],
+            pumps: vec![],
+            pump_controllers: vec![],
+            flow_demands: vec![],
+            power_transfer_unit: None,
+            load,
+
+            reservoir_air_pressure: Pressure::new::<psi>(Self::NOMINAL_RESERVOIR_AIR_PRESSURE_PSI),
+
+            powered_source: TestElectricitySource::powered(
+                context,
+                PotentialOrigin::EngineGenerator(1),
+            ),
+            buses: vec![],
+            bus_types: vec![],
+        }
+    }
+
+    pub fn with_circuit(mut self, circuit: HydraulicCircuit) -> Self {
+        self.circuits.push(circuit);
+        self.circuit_controllers.push(HarnessCircuitController {
+            should_route_pump_to_auxiliary: vec![],
+        });
+        self.pumps.push(vec![]);
+        self.pump_controllers.push(vec![]);
+        self.flow_demands.push(FlowDemand::new(Pressure::new::<psi>(
+            Self::NOMINAL_DEMAND_PRESSURE_PSI,
+        )));
+        self
+    }
+
+    pub fn with_electric_pump(
+        mut self,
+        context: &mut InitContext,
+        circuit_index: usize,
+        pump: ElectricPump,
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        self = self.with_powered_bus(context, powered_by);
+        self.add_pump(circuit_index, HarnessPump::Electric(Box::new(pump)))
+    }
+
+    pub fn with_engine_driven_pump(self, circuit_index: usize, pump: EngineDrivenPump) -> Self {
+        self.add_pump(
+            circuit_index,
+            HarnessPump::EngineDriven(Box::new(pump), AngularVelocity::default()),
+        )
+    }
+
+    /// Routes a pump section of the circuit to its auxiliary section instead of its system section
+    pub fn with_pump_routed_to_auxiliary(
+        mut self,
+        circuit_index: usize,
+        pump_index: usize,
+    ) -> Self {
+        let routing = &mut self.circuit_controllers[circuit_index].should_route_pump_to_auxiliary;
+        if routing.len() <= pump_index {
+            routing.resize(pump_index + 1, false);
+        }
+        routing[pump_index] = true;
+        self
+    }
+
+    pub fn with_power_transfer_unit(
+        mut self,
+        ptu: PowerTransferUnit,
+        left_circuit_index: usize,
+        right_circuit_index: usize,
+    ) -> Self {
+        self.power_transfer_unit = Some(HarnessPowerTransferUnit {
+            ptu,
+            controller: HarnessPowerTransferUnitController {
+                should_enable: false,
+            },
+            left_circuit: left_circuit_index,
+            right_circuit: right_circuit_index,
+        });
+        self
+    }
+
+    /// Powers an electrical bus for the whole run, for example for the motor of an electro
+    /// hydrostatic actuator part of the load
+    pub fn with_powered_bus(
+        mut self,
+        context: &mut InitContext,
+        bus_type: ElectricalBusType,
+    ) -> Self {
+        if !self.bus_types.contains(&bus_type) {
+            self.buses.push(ElectricalBus::new(context, bus_type));
+            self.bus_types.push(bus_type);
+        }
+        self
+    }
+
+    fn add_pump(mut self, circuit_index: usize, pump: HarnessPump) -> Self {
+        self.pumps[circuit_index].push(pump);
+        self.pump_controllers[circuit_index].push(HarnessPumpController {
+            should_pressurise: false,
+        });
+        self
+    }
+
+    pub fn circuit(&self, circuit_index: usize) -> &HydraulicCircuit {
+        &self.circuits[circuit_index]
+    }
+
+    pub fn pump(&self, circuit_index: usize, pump_index: usize) -> &HarnessPump {
+        &self.pumps[circuit_index][pump_index]
+    }
+
+    pub fn power_transfer_unit(&self) -> Option<&PowerTransferUnit> {
+        self.power_transfer_unit.as_ref().map(|p| &p.ptu)
+    }
+
+    pub fn load(&self) -> &L {
+        &self.load
+    }
+
+    pub fn load_mut(&mut self) -> &mut L {
+        &mut self.load
+    }
+
+    fn execute(&mut self, command: &HarnessCommand<L>) {
+        match command {
+            HarnessCommand::Pressurise {
+                circuit,
+                pump,
+                pressurise,
+            } => self.pump_controllers[*circuit][*pump].should_pressurise = *pressurise,
+            HarnessCommand::EngineDrivenPumpSpeed {
+                circuit,
+                pump,
+                speed,
+            } => {
+                if let HarnessPump::EngineDriven(_, pump_speed) = &mut self.pumps[*circuit][*pump] {
+                    *pump_speed = *speed;
+                }
+            }
+            HarnessCommand::PowerTransferUnit(should_enable) => {
+                if let Some(ptu) = self.power_transfer_unit.as_mut() {
+                    ptu.controller.should_enable = *should_enable;
+                }
+            }
+            HarnessCommand::FlowDemand { circuit, flow } => {
+                self.flow_demands[*circuit].flow_at_nominal_pressure = *flow
+            }
+            HarnessCommand::ReservoirAirPressure(pressure) => {
+                self.reservoir_air_pressure = *pressure
+            }
+            HarnessCommand::Load(func) => func(&mut self.load),
+        }
+    }
+}
+impl<L: HydraulicHarnessLoad> Aircraft for HydraulicHarnessAircraft<L> {
+    fn update_before_power_distribution(
+        &mut self,
+        _: &UpdateContext,
+        electricity: &mut Electricity,
+    ) {
+        self.powered_source
+            .power_with_potential(ElectricPotential::new::<volt>(115.));
+        electricity.supplied_by(&self.powered_source);
+
+        for bus in &self.buses {
+            electricity.flow(&self.powered_source, bus);
+        }
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        for (circuit_index, circuit) in self.circuits.iter().enumerate() {
+            for (pump_index, pump) in self.pumps[circuit_index].iter_mut().enumerate() {
+                pump.update(
+                    context,
+                    circuit,
+                    pump_index,
+                    &self.pump_controllers[circuit_index][pump_index],
+                );
+            }
+        }
+
+        if let Some(ptu) = self.power_transfer_unit.as_mut() {
+            ptu.ptu.update(
+                context,
+                self.circuits[ptu.left_circuit].system_section(),
+                self.circuits[ptu.right_circuit].system_section(),
+                &ptu.controller,
+            );
+        }
+
+        self.load.update(context, &mut self.circuits);
+
+        for (circuit, demand) in self.circuits.iter_mut().zip(&mut self.flow_demands) {
+            demand.update(context, circuit.system_section());
+            circuit.update_system_actuator_volumes(demand);
+        }
+
+        for (circuit_index, circuit) in self.circuits.iter_mut().enumerate() {
+            let ptu = self
+                .power_transfer_unit
+                .as_ref()
+                .filter(|p| p.left_circuit == circuit_index || p.right_circuit == circuit_index)
+                .map(|p| &p.ptu);
+
+            let mut pumps: Vec<&mut dyn HeatingPressureSource> = self.pumps[circuit_index]
+                .iter_mut()
+                .map(|pump| pump.pressure_source_mut())
+                .collect();
+
+            circuit.update(
+                context,
+                &mut pumps,
+                None::<&mut ElectricPump>,
+                None::<&mut ElectricPump>,
+                ptu,
+                &self.circuit_controllers[circuit_index],
+                self.reservoir_air_pressure,
+            );
+        }
+    }
+}
+impl<L: HydraulicHarnessLoad> SimulationElement for HydraulicHarnessAircraft<L> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        for circuit in &mut self.circuits {
+            circuit.accept(visitor);
+        }
+
+        for pump in self.pumps.iter_mut().flatten() {
+            pump.accept(visitor);
+        }
+
+        if let Some(ptu) = self.power_transfer_unit.as_mut() {
+            ptu.ptu.accept(visitor);
+        }
+
+        self.load.accept(visitor);
+
+        for bus in &mut self.buses {
+            bus.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+}
+
+/// A command applied to the harness at a scripted time of the run
+pub enum HarnessCommand<L: HydraulicHarnessLoad = NoLoad> {
+    Pressurise {
+        circuit: usize,
+        pump: usize,
+        pressurise: bool,
+    },
+    EngineDrivenPumpSpeed {
+        circuit: usize,
+        pump: usize,
+        speed: AngularVelocity,
+    },
+    PowerTransferUnit(bool),
+    /// Flow drawn from the system section when it is at nominal pressure
+    FlowDemand {
+        circuit: usize,
+        flow: VolumeRate,
+    },
+    ReservoirAirPressure(Pressure),
+    Load(Box<dyn Fn(&mut L)>),
+}
+
+type ChannelFn<L> = Box<dyn Fn(&HydraulicHarnessAircraft<L>) -> f64>;
+
+/// Runs a scripted profile on a [HydraulicHarnessAircraft] and records the requested channels
+pub struct HydraulicSimulationHarness<L: HydraulicHarnessLoad = NoLoad> {
+    test_bed: SimulationTestBed<HydraulicHarnessAircraft<L>>,
+    script: Vec<(Duration, HarnessCommand<L>)>,
+    channels: Vec<(String, ChannelFn<L>)>,
+}
+impl<L: HydraulicHarnessLoad> HydraulicSimulationHarness<L> {
+    pub fn new<U: FnOnce(&mut InitContext) -> HydraulicHarnessAircraft<L>>(
+        aircraft_ctor_fn: U,
+    ) -> Self {
+        Self {
+            test_bed: SimulationTestBed::new(aircraft_ctor_fn),
+            script: vec![],
+            channels: vec![],
+        }
+    }
+
+    /// Schedules a command at the given time from the start of the run
+    pub fn at(mut self, time: Duration, command: HarnessCommand<L>) -> Self {
+        self.script.push((time, command));
+        self.script.sort_by_key(|(time, _)| *time);
+        self
+    }
+
+    pub fn record(
+        mut self,
+        name: &str,
+        channel: impl Fn(&HydraulicHarnessAircraft<L>) -> f64 + 'static,
+    ) -> Self {
+        self.channels.push((name.to_owned(), Box::new(channel)));
+        self
+    }
+
+    /// Records the usual state of a circuit: section pressures, reservoir and accumulator
+    /// volumes and fluid temperature
+    pub fn record_circuit(mut self, circuit_index: usize, name: &str) -> Self {
+        let number_of_pumps = self.test_bed.query(|a| a.pumps[circuit_index].len());
+
+        for pump_index in 0..number_of_pumps {
+            self = self
+                .record(
+                    &format!("{} pump {} section pressure [psi]", name, pump_index + 1),
+                    move |a| {
+                        a.circuit(circuit_index)
+                            .pump_pressure(pump_index)
+                            .get::<psi>()
+                    },
+                )
+                .record(
+                    &format!("{} pump {} flow [gal/s]", name, pump_index + 1),
+                    move |a| {
+                        a.pump(circuit_index, pump_index)
+                            .flow()
+                            .get::<gallon_per_second>()
+                    },
+                )
+                .record(
+                    &format!("{} pump {} speed [rpm]", name, pump_index + 1),
+                    move |a| {
+                        a.pump(circuit_index, pump_index)
+                            .speed()
+                            .get::<revolution_per_minute>()
+                    },
+                );
+        }
+
+        self.record(
+            &format!("{} system section pressure [psi]", name),
+            move |a| {
+                a.circuit(circuit_index)
+                    .system_section_pressure()
+                    .get::<psi>()
+            },
+        )
+        .record(&format!("{} accumulator fluid [gal]", name), move |a| {
+            a.circuit(circuit_index)
+                .system_accumulator_fluid_volume()
+                .get::<gallon>()
+        })
+        .record(&format!("{} reservoir level [gal]", name), move |a| {
+            a.circuit(circuit_index).reservoir_level().get::<gallon>()
+        })
+        .record(&format!("{} fluid temperature [degC]", name), move |a| {
+            a.circuit(circuit_index)
+                .fluid_temperature()
+                .get::<degree_celsius>()
+        })
+    }
+
+    pub fn record_power_transfer_unit(self) -> Self {
+        self.record("PTU flow [gal/s]", |a| {
+            a.power_transfer_unit()
+                .map_or(0., |ptu| ptu.flow().get::<gallon_per_second>())
+        })
+    }
+
+    /// Runs the script for the given duration with a fixed step, returning the recorded history
+    pub fn run(mut self, duration: Duration, step: Duration) -> History {
+        let mut history = History::new(self.channels.iter().map(|(name, _)| name.clone()));
+        let mut time = Duration::default();
+        let mut script = std::mem::take(&mut self.script).into_iter().peekable();
+
+        history.push(time, self.sample());
+
+        while time < duration {
+            while let Some((_, command)) = script.next_if(|(at, _)| *at <= time) {
+                self.test_bed.command(|a| a.execute(&command));
+            }
+
+            self.test_bed.run_with_delta(step);
+            time += step;
+
+            history.push(time, self.sample());
+        }
+
+        history
+    }
+
+    fn sample(&self) -> Vec<f64> {
+        self.test_bed.query(|a| {
+            self.channels
+                .iter()
+                .map(|(_, channel)| channel(a))
+                .collect()
+        })
+    }
+}
+
+/// Time history of the recorded channels of a harness run
+pub struct History {
+    names: Vec<String>,
+    time: Vec<f64>,
+    data: Vec<Vec<f64>>,
+}
+impl History {
+    const SVG_PLOT_WIDTH: f64 = 900.;
+    const SVG_PLOT_HEIGHT: f64 = 160.;
+    const SVG_MARGIN_LEFT: f64 = 80.;
+    const SVG_MARGIN_TOP: f64 = 30.;
+    const SVG_MARGIN_BOTTOM: f64 = 30.;
+
+    fn new(names: impl Iterator<Item = String>) -> Self {
+        let names: Vec<String> = names.collect();
+        let data = vec![vec![]; names.len()];
+
+        Self {
+            names,
+            time: vec![],
+            data,
+        }
+    }
+
+    fn push(&mut self, time: Duration, values: Vec<f64>) {
+        self.time.push(time.as_secs_f64());
+        for (channel, value) in self.data.iter_mut().zip(values) {
+            channel.push(value);
+        }
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn time(&self) -> &[f64] {
+        &self.time
+    }
+
+    pub fn channel(&self, name: &str) -> Option<&[f64]> {
+        self.names
+            .iter()
+            .position(|n| n == name)
+            .map(|idx| self.data[idx].as_slice())
+    }
+
+    pub fn write_csv(&self, writer: &mut impl Write) -> io::Result<()> {
+        write!(writer, "{}", escape_csv("time [s]"))?;
+        for name in &self.names {
+            write!(writer, ",{}", escape_csv(name))?;
+        }
+        writeln!(writer)?;
+
+        for (idx, time) in self.time.iter().enumerate() {
+            write!(writer, "{}", time)?;
+            for channel in &self.data {
+                write!(writer, ",{}", channel[idx])?;
+            }
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders one plot per channel, stacked vertically and sharing the time axis
+    pub fn write_svg(&self, writer: &mut impl Write, title: &str) -> io::Result<()> {
+        let plot_pitch = Self::SVG_PLOT_HEIGHT + Self::SVG_MARGIN_TOP + Self::SVG_MARGIN_BOTTOM;
+        let width = Self::SVG_PLOT_WIDTH + Self::SVG_MARGIN_LEFT * 1.5;
+        let height = plot_pitch * self.names.len() as f64 + Self::SVG_MARGIN_TOP;
+        let end_time = self.time.last().copied().unwrap_or(0.).max(f64::EPSILON);
+
+        writeln!(
+            writer,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif" font-size="12">"#,
+            w = width,
+            h = height
+        )?;
+        writeln!(
+            writer,
+            r#"<rect width="100%" height="100%" fill="white"/><text x="{}" y="20" font-size="16">{}</text>"#,
+            Self::SVG_MARGIN_LEFT,
+            escape(title)
+        )?;
+
+        for (idx, (name, channel)) in self.names.iter().zip(&self.data).enumerate() {
+            let top = Self::SVG_MARGIN_TOP * 2. + plot_pitch * idx as f64;
+            let bottom = top + Self::SVG_PLOT_HEIGHT;
+            let left = Self::SVG_MARGIN_LEFT;
+            let right = left + Self::SVG_PLOT_WIDTH;
+
+            let (min, max) = channel
+                .iter()
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+                    (min.min(*v), max.max(*v))
+                });
+            let (min, max) = if !min.is_finite() {
+                (0., 1.)
+            } else if (max - min).abs() < f64::EPSILON {
+                (min - 1., max + 1.)
+            } else {
+                (min, max)
+            };
+
+            let mut points = String::new();
+            for (time, value) in self.time.iter().zip(channel) {
+                let x = left + time / end_time * Self::SVG_PLOT_WIDTH;
+                let y = bottom - (value - min) / (max - min) * Self::SVG_PLOT_HEIGHT;
+                write!(points, "{:.1},{:.1} ", x, y).unwrap();
+            }
+
+            writeln!(
+                writer,
+                r#"<text x="{left}" y="{ty}">{name}</text><rect x="{left}" y="{top}" width="{pw}" height="{ph}" fill="none" stroke="gray"/>"#,
+                left = left,
+                ty = top - 6.,
+                name = escape(name),
+                top = top,
+                pw = Self::SVG_PLOT_WIDTH,
+                ph = Self::SVG_PLOT_HEIGHT
+            )?;
+            writeln!(
+                writer,
+                r#"<text x="{x}" y="{top}" text-anchor="end" dominant-baseline="hanging">{max:.2}</text><text x="{x}" y="{bottom}" text-anchor="end">{min:.2}</text>"#,
+                x = left - 4.,
+                top = top,
+                bottom = bottom,
+                max = max,
+                min = min
+            )?;
+            writeln!(
+                writer,
+                r#"<text x="{left}" y="{y}">0 s</text><text x="{right}" y="{y}" text-anchor="end">{end:.1} s</text>"#,
+                left = left,
+                right = right,
+                y = bottom + 14.,
+                end = end_time
+            )?;
+            writeln!(
+                writer,
+                r#"<polyline fill="none" stroke="blue" stroke-width="1" points="{}"/>"#,
+                points.trim_end()
+            )?;
+        }
+
+        writeln!(writer, "</svg>")
+    }
+
+    /// Writes `<name>.csv` and `<name>.svg` in the given directory
+    pub fn save(&self, directory: impl AsRef<Path>, name: &str) -> io::Result<()> {
+        let directory = directory.as_ref();
+
+        let mut csv = BufWriter::new(File::create(directory.join(format!("{}.csv", name)))?);
+        self.write_csv(&mut csv)?;
+        csv.flush()?;
+
+        let mut svg = BufWriter::new(File::create(directory.join(format!("{}.svg", name)))?);
+        self.write_svg(&mut svg, name)?;
+        svg.flush()
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Quotes a csv field when it contains a separator, a quote or a line break, as per RFC 4180
+fn escape_csv(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use systems::{
+        hydraulic::{
+            pumps::PumpCharacteristics, PressureSwitch, PressureSwitchType, PriorityValve,
+            Reservoir,
+        },
+        shared::{AirbusElectricPumpId, AirbusEngineDrivenPumpId, HydraulicColor},
+    };
+    use uom::si::{electric_current::ampere, ratio::percent};
+
+    fn reservoir(context: &mut InitContext, color: HydraulicColor) -> Reservoir {
+        Reservoir::new(
+            context,
+            color,
+            Volume::new::<gallon>(5.),
+            Volume::new::<gallon>(4.),
+            Volume::new::<gallon>(3.),
+            vec![PressureSwitch::new(
+                Pressure::new::<psi>(23.45),
+                Pressure::new::<psi>(20.55),
+                PressureSwitchType::Relative,
+            )],
+            Volume::new::<gallon>(1.),
+        )
+    }
+
+    fn circuit(context: &mut InitContext, color: HydraulicColor) -> HydraulicCircuit {
+        let reservoir = reservoir(context, color);
+
+        HydraulicCircuit::new(
+            context,
+            color,
+            1,
+            Ratio::new::<percent>(100.),
+            Volume::new::<gallon>(10.),
+            reservoir,
+            Pressure::new::<psi>(1450.),
+            Pressure::new::<psi>(1750.),
+            Pressure::new::<psi>(1450.),
+            Pressure::new::<psi>(1750.),
+            true,
+            false,
+            false,
+            Pressure::new::<psi>(3000.),
+            PriorityValve::new(Pressure::new::<psi>(1842.), Pressure::new::<psi>(2300.)),
+            Pressure::new::<psi>(1885.),
+            Volume::new::<gallon>(0.264),
+        )
+    }
+
+    fn electric_pump_harness() -> HydraulicSimulationHarness {
+        HydraulicSimulationHarness::new(|context| {
+            let circuit = circuit(context, HydraulicColor::Blue);
+            let pump = ElectricPump::new(
+                context,
+                AirbusElectricPumpId::Blue,
+                ElectricalBusType::AlternatingCurrent(1),
+                ElectricCurrent::new::<ampere>(45.),
+                PumpCharacteristics::a320_electric_pump(),
+            );
+
+            HydraulicHarnessAircraft::new(context)
+                .with_circuit(circuit)
+                .with_electric_pump(context, 0, pump, ElectricalBusType::AlternatingCurrent(1))
+        })
+        .record_circuit(0, "Blue")
+    }
+
+    #[test]
+    fn electric_pump_pressurises_circuit_when_commanded() {
+        let history = electric_pump_harness()
+            .at(
+                Duration::from_secs(1),
+                HarnessCommand::Pressurise {
+                    circuit: 0,
+                    pump: 0,
+                    pressurise: true,
+                },
+            )
+            .run(Duration::from_secs(10), Duration::from_millis(50));
+
+        let pressure = history
+            .channel("Blue system section pressure [psi]")
+            .unwrap();
+
+        assert!(pressure[0] < 100.);
+        assert!(*pressure.last().unwrap() > 2500.);
+    }
+
+    #[test]
+    fn flow_demand_drains_accumulator_when_pump_stops() {
+        let history = electric_pump_harness()
+            .at(
+                Duration::from_secs(0),
+                HarnessCommand::Pressurise {
+                    circuit: 0,
+                    pump: 0,
+                    pressurise: true,
+                },
+            )
+            .at(
+                Duration::from_secs(10),
+                HarnessCommand::Pressurise {
+                    circuit: 0,
+                    pump: 0,
+                    pressurise: false,
+                },
+            )
+            .at(
+                Duration::from_secs(10),
+                HarnessCommand::FlowDemand {
+                    circuit: 0,
+                    flow: VolumeRate::new::<gallon_per_second>(0.1),
+                },
+            )
+            .run(Duration::from_secs(20), Duration::from_millis(50));
+
+        let pressure = history
+            .channel("Blue system section pressure [psi]")
+            .unwrap();
+
+        assert!(pressure[150] > 2500.);
+        assert!(*pressure.last().unwrap() < 500.);
+    }
+
+    #[test]
+    fn engine_driven_pump_only_pressurises_when_spinning() {
+        let history = HydraulicSimulationHarness::new(|context| {
+            let circuit = circuit(context, HydraulicColor::Green);
+            let pump = EngineDrivenPump::new(
+                context,
+                AirbusEngineDrivenPumpId::Green,
+                PumpCharacteristics::a320_edp(),
+            );
+
+            HydraulicHarnessAircraft::new(context)
+                .with_circuit(circuit)
+                .with_engine_driven_pump(0, pump)
+        })
+        .record_circuit(0, "Green")
+        .at(
+            Duration::from_secs(0),
+            HarnessCommand::Pressurise {
+                circuit: 0,
+                pump: 0,
+                pressurise: true,
+            },
+        )
+        .at(
+            Duration::from_secs(5),
+            HarnessCommand::EngineDrivenPumpSpeed {
+                circuit: 0,
+                pump: 0,
+                speed: AngularVelocity::new::<revolution_per_minute>(4000.),
+            },
+        )
+        .run(Duration::from_secs(10), Duration::from_millis(50));
+
+        let pressure = history
+            .channel("Green system section pressure [psi]")
+            .unwrap();
+
+        assert!(pressure[90] < 100.);
+        assert!(*pressure.last().unwrap() > 2500.);
+    }
+
+    #[test]
+    fn history_writes_csv_and_svg() {
+        let history =
+            electric_pump_harness().run(Duration::from_secs(1), Duration::from_millis(100));
+
+        let mut csv = vec![];
+        history.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let mut lines = csv.lines();
+
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("time [s],Blue pump 1 section pressure [psi]"));
+        assert_eq!(lines.count(), history.time().len());
+
+        let mut svg = vec![];
+        history.write_svg(&mut svg, "Blue").unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<polyline").count(), history.names().len());
+    }
+
+    #[test]
+    fn history_escapes_csv_header_fields() {
+        let history = electric_pump_harness()
+            .record("Flow, \"nominal\" [gal/s]", |a| {
+                a.pump(0, 0).flow().get::<gallon_per_second>()
+            })
+            .run(Duration::from_secs(1), Duration::from_millis(100));
+
+        let mut csv = vec![];
+        history.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+
+        assert!(csv
+            .lines()
+            .next()
+            .unwrap()
+            .ends_with(",\"Flow, \"\"nominal\"\" [gal/s]\""));
+    }
+}
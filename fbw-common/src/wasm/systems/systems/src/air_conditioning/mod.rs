@@ -486,6 +486,20 @@ impl CabinFan {
     const FAN_EFFICIENCY: f64 = 0.75; // Ratio - so output matches AMM numbers
 
     pub fn new(id: u8, design_flow_rate: VolumeRate, powered_by: ElectricalBusType) -> Self {
+        Self::new_with_failure(
+            FailureType::CabinFan(id as usize),
+            design_flow_rate,
+            powered_by,
+        )
+    }
+
+    /// Creates a fan which fails with the given failure type, for fans which aren't
+    /// part of the cabin recirculation, such as the avionics ventilation fans.
+    pub fn new_with_failure(
+        failure_type: FailureType,
+        design_flow_rate: VolumeRate,
+        powered_by: ElectricalBusType,
+    ) -> Self {
         Self {
             design_flow_rate,
             is_on: false,
@@ -493,7 +507,7 @@ impl CabinFan {
 
             is_powered: false,
             powered_by,
-            failure: Failure::new(FailureType::CabinFan(id as usize)),
+            failure: Failure::new(failure_type),
         }
     }
 
@@ -548,6 +562,10 @@ impl CabinFan {
     pub fn has_fault(&self) -> bool {
         self.failure.is_active()
     }
+
+    pub fn is_on(&self) -> bool {
+        self.is_on
+    }
 }
 
 impl OutletAir for CabinFan {
@@ -19,7 +19,7 @@ use crate::{
 
 use super::ApuConstants;
 use super::{
-    air_intake_flap::AirIntakeFlapSignal, AirIntakeFlap, ApuStartMotor,
+    air_intake_flap::AirIntakeFlapSignal, oil_system::ApuOilSystem, AirIntakeFlap, ApuStartMotor,
     AuxiliaryPowerUnitFireOverheadPanel, AuxiliaryPowerUnitOverheadPanel, FuelPressureSwitch,
     Turbine, TurbineSignal, TurbineState,
 };
@@ -39,6 +39,9 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
     apu_is_emergency_shutdown_id: VariableIdentifier,
     apu_bleed_air_pressure_id: VariableIdentifier,
     apu_fuel_line_flow_id: VariableIdentifier,
+    apu_ecb_fault_code_id: VariableIdentifier,
+    apu_oil_pressure_id: VariableIdentifier,
+    apu_oil_temperature_id: VariableIdentifier,
 
     powered_by: ElectricalBusType,
     is_powered: bool,
@@ -59,6 +62,15 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
     egt: ThermodynamicTemperature,
     egt_warning_temperature: ThermodynamicTemperature,
     n_above_95_duration: Duration,
+    oil_pressure: Pressure,
+    oil_temperature: ThermodynamicTemperature,
+    is_monitoring_start: bool,
+    starting_for: Duration,
+    lowest_egt_during_start: ThermodynamicTemperature,
+    light_off_detected: bool,
+    n_at_last_acceleration: Ratio,
+    n2_at_last_acceleration: Ratio,
+    n_not_accelerating_for: Duration,
     fire_button_is_released: bool,
     engines_on: bool,
     on_ground: bool,
@@ -74,6 +86,13 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
 impl<C: ApuConstants> ElectronicControlBox<C> {
     const START_MOTOR_POWERED_UNTIL_N: f64 = 55.;
     const JET_A_1_DENSITY: f64 = 0.804; // Kilograms per Liter
+    const OVERSPEED_N: f64 = 107.;
+    const LIGHT_OFF_EGT_RISE: f64 = 50.; // Deg C
+    const NO_LIGHT_OFF_DETECTION_TIME: Duration = Duration::from_secs(15);
+    const MIN_N_ACCELERATION: f64 = 1.;
+    const HUNG_START_DETECTION_TIME: Duration = Duration::from_secs(10);
+    const LOW_OIL_PRESSURE_PSI: f64 = 25.;
+    const HIGH_OIL_TEMPERATURE: f64 = 155.; // Deg C
 
     pub fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
         ElectronicControlBox {
@@ -94,6 +113,9 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             apu_bleed_air_pressure_id: context.get_identifier("APU_BLEED_AIR_PRESSURE".to_owned()),
             apu_fuel_line_flow_id: context
                 .get_identifier(format!("FUELSYSTEM LINE FUEL FLOW:{}", C::FUEL_LINE_ID)),
+            apu_ecb_fault_code_id: context.get_identifier("APU_ECB_FAULT_CODE".to_owned()),
+            apu_oil_pressure_id: context.get_identifier("APU_OIL_PRESSURE".to_owned()),
+            apu_oil_temperature_id: context.get_identifier("APU_OIL_TEMPERATURE".to_owned()),
 
             powered_by,
             is_powered: false,
@@ -116,6 +138,15 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
                 C::RUNNING_WARNING_EGT,
             ),
             n_above_95_duration: Duration::from_secs(0),
+            oil_pressure: Pressure::default(),
+            oil_temperature: ThermodynamicTemperature::new::<degree_celsius>(0.),
+            is_monitoring_start: false,
+            starting_for: Duration::ZERO,
+            lowest_egt_during_start: ThermodynamicTemperature::new::<degree_celsius>(0.),
+            light_off_detected: false,
+            n_at_last_acceleration: Ratio::default(),
+            n2_at_last_acceleration: Ratio::default(),
+            n_not_accelerating_for: Duration::ZERO,
             fire_button_is_released: false,
             engines_on: false,
             on_ground: false,
@@ -185,6 +216,7 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         self.update_air_intake_state(context);
         self.update_fuel_used(context);
 
+        let egt_before_update = self.egt;
         self.n2 = turbine.n2();
         self.n = turbine.n();
         self.egt = turbine.egt();
@@ -198,6 +230,9 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             self.n_above_95_duration = Duration::from_secs(0);
         }
 
+        self.monitor_start(context, egt_before_update);
+        self.monitor_overspeed_and_egt();
+
         if !self.is_on() {
             self.fault = None;
         }
@@ -230,6 +265,97 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         }
     }
 
+    pub fn update_oil_system_state(&mut self, oil_system: &ApuOilSystem) {
+        self.oil_pressure = oil_system.pressure();
+        self.oil_temperature = oil_system.temperature();
+
+        if self.turbine_state == TurbineState::Running
+            && self.oil_pressure < Pressure::new::<psi>(Self::LOW_OIL_PRESSURE_PSI)
+        {
+            self.set_fault(ApuFault::LowOilPressure);
+        }
+
+        if self.oil_temperature
+            > ThermodynamicTemperature::new::<degree_celsius>(Self::HIGH_OIL_TEMPERATURE)
+        {
+            self.set_fault(ApuFault::HighOilTemperature);
+        }
+    }
+
+    /// Aborts the start when the EGT doesn't rise after ignition (no light off)
+    /// or when N stops increasing before reaching self sustaining speed (hung start).
+    fn monitor_start(
+        &mut self,
+        context: &UpdateContext,
+        egt_before_update: ThermodynamicTemperature,
+    ) {
+        if self.turbine_state != TurbineState::Starting {
+            self.is_monitoring_start = false;
+            return;
+        }
+
+        if !self.is_monitoring_start {
+            self.is_monitoring_start = true;
+            self.starting_for = Duration::ZERO;
+            self.lowest_egt_during_start = egt_before_update;
+            self.light_off_detected = false;
+            self.n_at_last_acceleration = self.n;
+            self.n2_at_last_acceleration = self.n2;
+            self.n_not_accelerating_for = Duration::ZERO;
+        }
+
+        self.starting_for += context.delta();
+
+        self.lowest_egt_during_start = self.lowest_egt_during_start.min(self.egt);
+        if self.egt.get::<degree_celsius>() - self.lowest_egt_during_start.get::<degree_celsius>()
+            > Self::LIGHT_OFF_EGT_RISE
+        {
+            self.light_off_detected = true;
+        }
+
+        if !self.light_off_detected && self.starting_for > Self::NO_LIGHT_OFF_DETECTION_TIME {
+            self.set_fault(ApuFault::NoLightOff);
+        }
+
+        // On two spool turbines N2 accelerates first, while N remains close to 0%.
+        if (self.n - self.n_at_last_acceleration).get::<percent>() >= Self::MIN_N_ACCELERATION
+            || (self.n2 - self.n2_at_last_acceleration).get::<percent>() >= Self::MIN_N_ACCELERATION
+        {
+            self.n_at_last_acceleration = self.n;
+            self.n2_at_last_acceleration = self.n2;
+            self.n_not_accelerating_for = Duration::ZERO;
+        } else {
+            self.n_not_accelerating_for += context.delta();
+        }
+
+        if self.n.get::<percent>() < 95.
+            && self.n_not_accelerating_for > Self::HUNG_START_DETECTION_TIME
+        {
+            self.set_fault(ApuFault::HungStart);
+        }
+    }
+
+    fn monitor_overspeed_and_egt(&mut self) {
+        if self.n.get::<percent>() > Self::OVERSPEED_N {
+            self.set_fault(ApuFault::Overspeed);
+        }
+
+        if matches!(
+            self.turbine_state,
+            TurbineState::Starting | TurbineState::Running
+        ) && self.egt > self.egt_warning_temperature
+        {
+            self.set_fault(ApuFault::EgtOvertemperature);
+        }
+    }
+
+    /// Stores the first detected fault. It is kept until the ECB is switched off.
+    fn set_fault(&mut self, fault: ApuFault) {
+        if self.is_on() && self.fault.is_none() {
+            self.fault = Some(fault);
+        }
+    }
+
     fn update_fuel_used(&mut self, context: &UpdateContext) {
         self.fuel_used += self.fuel_flow
             * MassConcentration::new::<kilogram_per_liter>(Self::JET_A_1_DENSITY)
@@ -309,6 +435,10 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         self.has_fault() || self.fire_button_is_released
     }
 
+    pub fn fault(&self) -> Option<ApuFault> {
+        self.fault
+    }
+
     pub fn has_fuel_low_pressure_fault(&self) -> bool {
         if let Some(fault) = self.fault {
             fault == ApuFault::FuelLowPressure
@@ -517,6 +647,12 @@ impl<C: ApuConstants> SimulationElement for ElectronicControlBox<C> {
             self.bleed_air_pressure,
             ssm,
         );
+        writer.write_arinc429(&self.apu_oil_pressure_id, self.oil_pressure, ssm);
+        writer.write_arinc429(&self.apu_oil_temperature_id, self.oil_temperature, ssm);
+        writer.write(
+            &self.apu_ecb_fault_code_id,
+            self.fault.map_or(0, |fault| fault.code()),
+        );
 
         // Flight Warning Computer related information.
         writer.write(&self.ecam_inop_sys_apu_id, self.is_inoperable());
@@ -543,9 +679,34 @@ impl<C: ApuConstants> SimulationElement for ElectronicControlBox<C> {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
-enum ApuFault {
+/// Faults detected by the ECB. Each of them leads to a shutdown of the APU
+/// and is kept until the ECB is switched off.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApuFault {
     ApuFire,
     FuelLowPressure,
     DcPowerLoss,
+    Overspeed,
+    EgtOvertemperature,
+    LowOilPressure,
+    HighOilTemperature,
+    NoLightOff,
+    HungStart,
+}
+impl ApuFault {
+    /// The code under which the ECB stores the fault, as written to `APU_ECB_FAULT_CODE`.
+    /// A code of 0 indicates no fault.
+    pub fn code(&self) -> u8 {
+        match self {
+            ApuFault::ApuFire => 1,
+            ApuFault::FuelLowPressure => 2,
+            ApuFault::DcPowerLoss => 3,
+            ApuFault::Overspeed => 4,
+            ApuFault::EgtOvertemperature => 5,
+            ApuFault::LowOilPressure => 6,
+            ApuFault::HighOilTemperature => 7,
+            ApuFault::NoLightOff => 8,
+            ApuFault::HungStart => 9,
+        }
+    }
 }
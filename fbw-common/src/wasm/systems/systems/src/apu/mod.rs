@@ -1,9 +1,11 @@
 use self::{
     air_intake_flap::AirIntakeFlap, aps3200::ShutdownAps3200Turbine,
-    electronic_control_box::ElectronicControlBox, pw980::ShutdownPw980Turbine,
+    electronic_control_box::ElectronicControlBox, oil_system::ApuOilSystem,
+    pw980::ShutdownPw980Turbine,
 };
 use crate::{
     electrical::{ElectricalElement, ElectricitySource, ProvideFrequency, ProvidePotential},
+    failures::{Failure, FailureType},
     overhead::{FirePushButton, OnOffAvailablePushButton, OnOffFaultPushButton},
     pneumatic::{ControllablePneumaticValve, TargetPressureTemperatureSignal},
     shared::{
@@ -21,9 +23,13 @@ use uom::si::thermodynamic_temperature::degree_celsius;
 
 mod air_intake_flap;
 mod aps3200;
+mod oil_system;
+mod performance;
 mod pw980;
 use crate::simulation::{InitContext, VariableIdentifier};
 pub use aps3200::{Aps3200ApuGenerator, Aps3200Constants, Aps3200StartMotor};
+pub use electronic_control_box::ApuFault;
+pub use performance::ApuPerformance;
 pub use pw980::{Pw980ApuGenerator, Pw980Constants, Pw980StartMotor};
 
 mod electronic_control_box;
@@ -68,6 +74,7 @@ impl AuxiliaryPowerUnitFactory {
 
 pub trait ApuStartMotor: SimulationElement {
     fn is_powered(&self) -> bool;
+    fn potential(&self) -> ElectricPotential;
 }
 
 /// Komp: There is a pressure switch between the fuel valve and the APU.
@@ -77,11 +84,13 @@ pub trait ApuStartMotor: SimulationElement {
 /// probably move this type and the logic to there.
 pub struct FuelPressureSwitch {
     has_fuel_remaining: bool,
+    pressure_loss_failure: Failure,
 }
 impl FuelPressureSwitch {
     fn new() -> Self {
         FuelPressureSwitch {
             has_fuel_remaining: false,
+            pressure_loss_failure: Failure::new(FailureType::ApuFuelPressureLoss),
         }
     }
 
@@ -90,7 +99,65 @@ impl FuelPressureSwitch {
     }
 
     fn has_pressure(&self) -> bool {
-        self.has_fuel_remaining
+        self.has_fuel_remaining && !self.pressure_loss_failure.is_active()
+    }
+}
+impl SimulationElement for FuelPressureSwitch {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.pressure_loss_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// Failures of the turbine itself. These are not detected directly, but through
+/// their effect on N and EGT as monitored by the ECB.
+pub struct ApuTurbineFailures {
+    no_light_off: Failure,
+    hung_start: Failure,
+    overspeed: Failure,
+    egt_overtemperature: Failure,
+}
+impl ApuTurbineFailures {
+    fn new() -> Self {
+        Self {
+            no_light_off: Failure::new(FailureType::ApuNoLightOff),
+            hung_start: Failure::new(FailureType::ApuHungStart),
+            overspeed: Failure::new(FailureType::ApuOverspeed),
+            egt_overtemperature: Failure::new(FailureType::ApuEgtOvertemperature),
+        }
+    }
+
+    /// The igniters don't light the fuel air mixture, the starter motor
+    /// alone turns the turbine.
+    pub fn no_light_off(&self) -> bool {
+        self.no_light_off.is_active()
+    }
+
+    /// The turbine lights off, but stops accelerating before reaching
+    /// self sustaining speed while EGT keeps rising.
+    pub fn hung_start(&self) -> bool {
+        self.hung_start.is_active()
+    }
+
+    /// The fuel control unit no longer limits the speed of the running turbine.
+    pub fn overspeed(&self) -> bool {
+        self.overspeed.is_active()
+    }
+
+    /// The running turbine's EGT rises far beyond its normal value.
+    pub fn egt_overtemperature(&self) -> bool {
+        self.egt_overtemperature.is_active()
+    }
+}
+impl SimulationElement for ApuTurbineFailures {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.no_light_off.accept(visitor);
+        self.hung_start.accept(visitor);
+        self.overspeed.accept(visitor);
+        self.egt_overtemperature.accept(visitor);
+
+        visitor.visit(self);
     }
 }
 
@@ -104,11 +171,14 @@ pub struct AuxiliaryPowerUnit<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants
     apu_flap_open_percentage_id: VariableIdentifier,
 
     turbine: Option<Box<dyn Turbine>>,
+    turbine_failures: ApuTurbineFailures,
+    performance: ApuPerformance,
     generators: [T; N],
     ecb: ElectronicControlBox<C>,
     start_motor: U,
     air_intake_flap: AirIntakeFlap,
     fuel_pressure_switch: FuelPressureSwitch,
+    oil_system: ApuOilSystem,
 }
 impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
     AuxiliaryPowerUnit<T, U, C, N>
@@ -126,11 +196,14 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
                 .get_identifier("APU_FLAP_OPEN_PERCENTAGE".to_owned()),
 
             turbine: Some(turbine),
+            turbine_failures: ApuTurbineFailures::new(),
+            performance: ApuPerformance::new(),
             generators,
             ecb: ElectronicControlBox::new(context, electronic_control_box_powered_by),
             start_motor,
             air_intake_flap: AirIntakeFlap::new(air_intake_flap_powered_by),
             fuel_pressure_switch: FuelPressureSwitch::new(),
+            oil_system: ApuOilSystem::new(),
         }
     }
 
@@ -157,11 +230,16 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         self.air_intake_flap.update(context, &self.ecb);
         self.ecb.update_air_intake_flap_state(&self.air_intake_flap);
 
+        self.performance
+            .update(context, self.start_motor.potential());
+
         if let Some(turbine) = self.turbine.take() {
             let updated_turbine = turbine.update(
                 context,
                 bleed_air_valve.is_open(),
                 apu_gen_is_used,
+                &self.performance,
+                &self.turbine_failures,
                 &self.ecb,
             );
 
@@ -170,6 +248,9 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
             self.turbine = Some(updated_turbine);
         }
 
+        self.oil_system.update(context, self.ecb.n());
+        self.ecb.update_oil_system_state(&self.oil_system);
+
         let emergency_shutdown = self.is_emergency_shutdown();
         for gen in &mut self.generators {
             gen.update(self.ecb.n(), emergency_shutdown);
@@ -193,6 +274,11 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         self.ecb.has_fault()
     }
 
+    /// The fault which caused the ECB to shut down the APU, if any.
+    pub fn fault(&self) -> Option<ApuFault> {
+        self.ecb.fault()
+    }
+
     fn is_emergency_shutdown(&self) -> bool {
         self.ecb.is_emergency_shutdown()
     }
@@ -264,8 +350,11 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> Simulat
 {
     fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
         accept_iterable!(self.generators, visitor);
+        self.turbine_failures.accept(visitor);
         self.start_motor.accept(visitor);
         self.air_intake_flap.accept(visitor);
+        self.fuel_pressure_switch.accept(visitor);
+        self.oil_system.accept(visitor);
         self.ecb.accept(visitor);
 
         visitor.visit(self);
@@ -285,6 +374,8 @@ pub trait Turbine {
         context: &UpdateContext,
         apu_bleed_is_used: bool,
         apu_gen_is_used: bool,
+        performance: &ApuPerformance,
+        failures: &ApuTurbineFailures,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine>;
     fn n(&self) -> Ratio;
@@ -412,8 +503,9 @@ mod tests {
     use rstest::rstest;
     use std::time::Duration;
     use uom::si::{
+        electric_potential::volt,
         power::watt,
-        pressure::{bar, psi},
+        pressure::{bar, hectopascal, psi},
         ratio::percent,
         thermodynamic_temperature::degree_celsius,
     };
@@ -455,6 +547,8 @@ mod tests {
             _: &UpdateContext,
             _: bool,
             _: bool,
+            _: &ApuPerformance,
+            _: &ApuTurbineFailures,
             _: &dyn ControllerSignal<TurbineSignal>,
         ) -> Box<dyn Turbine> {
             self
@@ -690,6 +784,10 @@ mod tests {
             self.dc_bat_bus_electricity_source.power();
         }
 
+        fn set_dc_bat_bus_potential(&mut self, potential: ElectricPotential) {
+            self.dc_bat_bus_electricity_source.set_potential(potential);
+        }
+
         fn apu_generator_output_within_normal_parameters_after_processing_power_consumption_report(
             &self,
         ) -> bool {
@@ -898,11 +996,31 @@ mod tests {
             self
         }
 
+        fn apu_gen_used(mut self) -> Self {
+            self.command(|a| a.set_apu_gen_is_used(true));
+            self
+        }
+
         fn no_fuel_available(mut self) -> Self {
             self.command(|a| a.set_has_fuel_remaining(false));
             self
         }
 
+        fn failed(mut self, failure_type: FailureType) -> Self {
+            self.fail(failure_type);
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            let mut elapsed = Duration::ZERO;
+            while elapsed < duration {
+                self = self.run(Duration::from_millis(100));
+                elapsed += Duration::from_millis(100);
+            }
+
+            self
+        }
+
         pub fn released_apu_fire_pb(mut self) -> Self {
             self.write_by_name("FIRE_BUTTON_APU", true);
             self
@@ -988,6 +1106,11 @@ mod tests {
             self
         }
 
+        fn isa_sea_level_conditions(self) -> Self {
+            self.ambient_pressure(Pressure::new::<hectopascal>(1013.25))
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.))
+        }
+
         fn unpowered_start_motor(mut self) -> Self {
             self.command(|a| a.cut_start_motor_power());
             self
@@ -1003,6 +1126,11 @@ mod tests {
             self
         }
 
+        fn dc_bat_bus_potential(mut self, potential: ElectricPotential) -> Self {
+            self.command(|a| a.set_dc_bat_bus_potential(potential));
+            self
+        }
+
         fn command_apu_fire_on_ground(mut self, fire: bool) -> Self {
             self.command(|a| a.set_fire_on_ground(fire));
             self
@@ -1171,6 +1299,18 @@ mod tests {
             self.read_arinc429_by_name("APU_LOW_FUEL_PRESSURE_FAULT")
         }
 
+        fn fault_code(&mut self) -> u8 {
+            self.read_by_name("APU_ECB_FAULT_CODE")
+        }
+
+        fn oil_pressure(&mut self) -> Arinc429Word<Pressure> {
+            self.read_arinc429_by_name("APU_OIL_PRESSURE")
+        }
+
+        fn oil_temperature(&mut self) -> Arinc429Word<ThermodynamicTemperature> {
+            self.read_arinc429_by_name("APU_OIL_TEMPERATURE")
+        }
+
         fn is_auto_shutdown(&mut self) -> bool {
             self.read_by_name("APU_IS_AUTO_SHUTDOWN")
         }
@@ -1389,7 +1529,7 @@ mod tests {
             #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
             #[case] temp: f64,
         ) {
-            let mut test_bed = bed_with.starting_apu();
+            let mut test_bed = bed_with.isa_sea_level_conditions().starting_apu();
             let mut max_egt: f64 = 0.;
 
             loop {
@@ -1831,6 +1971,7 @@ mod tests {
             #[case] temp: f64,
         ) {
             let mut test_bed = bed_with
+                .isa_sea_level_conditions()
                 .running_apu_without_bleed_air()
                 .and()
                 .apu_gen_not_used()
@@ -1860,6 +2001,7 @@ mod tests {
             #[case] temp: f64,
         ) {
             let mut test_bed = bed_with
+                .isa_sea_level_conditions()
                 .running_apu_without_bleed_air()
                 .run(Duration::from_secs(1_000));
 
@@ -1886,6 +2028,7 @@ mod tests {
             #[case] temp: f64,
         ) {
             let mut test_bed = bed_with
+                .isa_sea_level_conditions()
                 .running_apu_with_bleed_air()
                 .and()
                 .apu_gen_not_used()
@@ -1946,6 +2089,7 @@ mod tests {
             #[case] temp: f64,
         ) {
             let mut test_bed = bed_with
+                .isa_sea_level_conditions()
                 .running_apu_with_bleed_air()
                 .run(Duration::from_secs(1_000));
 
@@ -2013,6 +2157,161 @@ mod tests {
             );
         }
 
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn apu_start_takes_longer_at_altitude<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .ambient_pressure(Pressure::new::<psi>(5.44))
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-35.))
+                .starting_apu()
+                .run(Duration::from_secs(APPROXIMATE_STARTUP_TIME));
+
+            assert!(test_bed.n().normal_value().unwrap().get::<percent>() < 100.);
+
+            test_bed = test_bed.run(Duration::from_secs(APPROXIMATE_STARTUP_TIME));
+
+            assert_about_eq!(test_bed.n().normal_value().unwrap().get::<percent>(), 100.);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn apu_start_takes_longer_with_weak_starter_supply<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .isa_sea_level_conditions()
+                .dc_bat_bus_potential(ElectricPotential::new::<volt>(20.))
+                .starting_apu()
+                .run(Duration::from_secs(APPROXIMATE_STARTUP_TIME));
+
+            assert!(test_bed.n().normal_value().unwrap().get::<percent>() < 100.);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200(), 460.)]
+        #[case::pw980(test_bed_pw980(), 555.)]
+        /// The reference temperatures are the upper bounds of the EGT at ISA sea level.
+        fn running_apu_egt_is_higher_in_hot_conditions<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+            #[case] isa_sea_level_temp: f64,
+        ) {
+            let mut test_bed = bed_with
+                .ambient_pressure(Pressure::new::<hectopascal>(1013.25))
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(45.))
+                .running_apu_with_bleed_air()
+                .run(Duration::from_secs(1_000));
+
+            let egt = test_bed
+                .egt()
+                .normal_value()
+                .unwrap()
+                .get::<degree_celsius>();
+            assert!(egt > isa_sea_level_temp + 30.);
+            assert!(!test_bed.master_has_fault());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200(), 435.)]
+        #[case::pw980(test_bed_pw980(), 530.)]
+        /// The reference temperatures are the lower bounds of the EGT at ISA sea level.
+        fn running_apu_egt_is_lower_in_cold_conditions<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+            #[case] isa_sea_level_temp: f64,
+        ) {
+            let mut test_bed = bed_with
+                .ambient_pressure(Pressure::new::<hectopascal>(1013.25))
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-30.))
+                .running_apu_with_bleed_air()
+                .run(Duration::from_secs(1_000));
+
+            let egt = test_bed
+                .egt()
+                .normal_value()
+                .unwrap()
+                .get::<degree_celsius>();
+            assert!(egt < isa_sea_level_temp - 30.);
+            assert!(!test_bed.master_has_fault());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200(), 50.)]
+        #[case::pw980(test_bed_pw980(), 54.7)]
+        fn running_apu_delivers_less_bleed_air_pressure_in_hot_and_high_conditions<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+            #[case] isa_sea_level_psi: f64,
+        ) {
+            let mut test_bed = bed_with
+                .ambient_pressure(Pressure::new::<hectopascal>(750.))
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(35.))
+                .running_apu_with_bleed_air()
+                .run(Duration::from_secs(10));
+
+            assert!(
+                test_bed.bleed_air_pressure().normal_value().unwrap()
+                    < Pressure::new::<psi>(isa_sea_level_psi - 5.)
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn generator_has_priority_over_bleed_air_when_power_is_limited<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .ambient_pressure(Pressure::new::<hectopascal>(750.))
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(35.))
+                .running_apu_with_bleed_air()
+                .and()
+                .apu_gen_not_used()
+                .run(Duration::from_secs(10));
+
+            let pressure_without_generator = test_bed.bleed_air_pressure().normal_value().unwrap();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .apu_gen_used()
+                .run(Duration::from_secs(10));
+
+            assert!(
+                test_bed.bleed_air_pressure().normal_value().unwrap() < pressure_without_generator
+            );
+        }
+
         #[rstest]
         #[case::aps3200(test_bed_aps3200())]
         #[case::pw980(test_bed_pw980())]
@@ -2614,6 +2913,244 @@ mod tests {
             assert!(test_bed.is_auto_shutdown());
         }
 
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn running_apu_shuts_down_with_fault_when_fuel_pressure_is_lost<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .and()
+                .failed(FailureType::ApuFuelPressureLoss)
+                .run_until_n_decreases(Duration::from_millis(50));
+
+            assert!(test_bed
+                .has_fuel_low_pressure_fault()
+                .normal_value()
+                .unwrap());
+            assert!(test_bed.is_auto_shutdown());
+            assert_eq!(test_bed.fault_code(), ApuFault::FuelLowPressure.code());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn start_without_light_off_is_aborted_with_fault<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .failed(FailureType::ApuNoLightOff)
+                .starting_apu()
+                .run_for(Duration::from_secs(14));
+
+            assert!(!test_bed.master_has_fault());
+            assert!(
+                test_bed
+                    .egt()
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree_celsius>()
+                    < 50.
+            );
+
+            test_bed = test_bed.run_for(Duration::from_secs(2));
+
+            assert!(test_bed.master_has_fault());
+            assert!(test_bed.is_auto_shutdown());
+            assert!(!test_bed.start_is_on());
+            assert_eq!(test_bed.fault_code(), ApuFault::NoLightOff.code());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn hung_start_is_aborted_with_fault<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.failed(FailureType::ApuHungStart).starting_apu();
+
+            while !test_bed.master_has_fault() {
+                test_bed = test_bed.run(Duration::from_millis(100));
+                assert!(test_bed.n().normal_value().unwrap().get::<percent>() < 60.);
+            }
+
+            assert!(test_bed.is_auto_shutdown());
+            assert!(!test_bed.apu_is_available());
+            assert_eq!(test_bed.fault_code(), ApuFault::HungStart.code());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn overspeeding_apu_shuts_down_with_fault<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .and()
+                .failed(FailureType::ApuOverspeed)
+                .run_for(Duration::from_secs(3));
+
+            assert!(!test_bed.master_has_fault());
+            assert!(test_bed.n().normal_value().unwrap().get::<percent>() > 100.);
+
+            test_bed = test_bed.run_for(Duration::from_secs(2));
+
+            assert!(test_bed.master_has_fault());
+            assert!(test_bed.is_auto_shutdown());
+            assert!(!test_bed.apu_is_available());
+            assert_eq!(test_bed.fault_code(), ApuFault::Overspeed.code());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn apu_with_egt_above_warning_temperature_shuts_down_with_fault<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .and()
+                .failed(FailureType::ApuEgtOvertemperature)
+                .run_for(Duration::from_secs(30));
+
+            assert!(test_bed.master_has_fault());
+            assert!(test_bed.is_auto_shutdown());
+            assert!(!test_bed.apu_is_available());
+            assert_eq!(test_bed.fault_code(), ApuFault::EgtOvertemperature.code());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn running_apu_has_oil_pressure_and_temperature_within_normal_range<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(50.))
+                .running_apu()
+                .run(Duration::from_secs(600));
+
+            assert!(test_bed.oil_pressure().normal_value().unwrap() > Pressure::new::<psi>(50.));
+            assert!(
+                test_bed
+                    .oil_temperature()
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree_celsius>()
+                    > 100.
+            );
+            assert!(!test_bed.master_has_fault());
+            assert_eq!(test_bed.fault_code(), 0);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn running_apu_shuts_down_with_fault_when_oil_pump_fails<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .and()
+                .failed(FailureType::ApuOilPump)
+                .run_for(Duration::from_secs(2));
+
+            assert!(test_bed.oil_pressure().normal_value().unwrap() < Pressure::new::<psi>(25.));
+            assert!(test_bed.master_has_fault());
+            assert!(test_bed.is_auto_shutdown());
+            assert_eq!(test_bed.fault_code(), ApuFault::LowOilPressure.code());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn running_apu_shuts_down_with_fault_when_oil_cooler_fails<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .and()
+                .failed(FailureType::ApuOilCooler);
+
+            for _ in 0..300 {
+                test_bed = test_bed.run(Duration::from_secs(1));
+            }
+
+            assert!(test_bed.master_has_fault());
+            assert!(test_bed.is_auto_shutdown());
+            assert_eq!(test_bed.fault_code(), ApuFault::HighOilTemperature.code());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn fault_code_is_reset_when_master_sw_turned_off_after_protective_shutdown<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .and()
+                .failed(FailureType::ApuOverspeed)
+                .run_for(Duration::from_secs(5));
+
+            assert_eq!(test_bed.fault_code(), ApuFault::Overspeed.code());
+
+            test_bed = test_bed.master_off();
+            while !test_bed.turbine_is_shutdown() || !test_bed.is_air_intake_flap_fully_closed() {
+                test_bed = test_bed.run(Duration::from_secs(1));
+            }
+
+            assert_eq!(test_bed.fault_code(), 0);
+            assert!(!test_bed.master_has_fault());
+        }
+
         #[rstest]
         #[case::aps3200(test_bed_aps3200())]
         #[case::pw980(test_bed_pw980())]
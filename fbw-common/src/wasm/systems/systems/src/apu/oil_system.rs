@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use uom::si::{f64::*, pressure::psi, ratio::percent, thermodynamic_temperature::degree_celsius};
+
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{calculate_towards_target_temperature, low_pass_filter::LowPassFilter},
+    simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+
+/// The lubrication system of the APU. The gearbox driven pump delivers a pressure
+/// proportional to N, and the oil is heated by the turbine bearings and cooled by the
+/// air cooled oil cooler.
+pub(super) struct ApuOilSystem {
+    pressure: LowPassFilter<Pressure>,
+    temperature: ThermodynamicTemperature,
+    is_initialised: bool,
+
+    pump_failure: Failure,
+    cooler_failure: Failure,
+}
+impl ApuOilSystem {
+    const PRESSURE_AT_100_PERCENT_N_PSI: f64 = 60.;
+    const PRESSURE_TIME_CONSTANT: Duration = Duration::from_millis(1000);
+    const TEMPERATURE_RISE_AT_100_PERCENT_N_DEGREE_CELSIUS: f64 = 90.;
+    const TEMPERATURE_RISE_WITHOUT_COOLER_DEGREE_CELSIUS: f64 = 130.;
+    const TEMPERATURE_COEFFICIENT: f64 = 1.;
+
+    pub fn new() -> Self {
+        Self {
+            pressure: LowPassFilter::new(Self::PRESSURE_TIME_CONSTANT),
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(0.),
+            is_initialised: false,
+
+            pump_failure: Failure::new(FailureType::ApuOilPump),
+            cooler_failure: Failure::new(FailureType::ApuOilCooler),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, n: Ratio) {
+        if !self.is_initialised {
+            self.temperature = context.ambient_temperature();
+            self.is_initialised = true;
+        }
+
+        let target_pressure = if self.pump_failure.is_active() {
+            Pressure::new::<psi>(0.)
+        } else {
+            Pressure::new::<psi>(Self::PRESSURE_AT_100_PERCENT_N_PSI * n.get::<percent>() / 100.)
+        };
+        self.pressure.update(context.delta(), target_pressure);
+
+        let mut temperature_rise =
+            Self::TEMPERATURE_RISE_AT_100_PERCENT_N_DEGREE_CELSIUS * n.get::<percent>() / 100.;
+        if self.cooler_failure.is_active() {
+            temperature_rise +=
+                Self::TEMPERATURE_RISE_WITHOUT_COOLER_DEGREE_CELSIUS * n.get::<percent>() / 100.;
+        }
+
+        self.temperature = calculate_towards_target_temperature(
+            self.temperature,
+            ThermodynamicTemperature::new::<degree_celsius>(
+                context.ambient_temperature().get::<degree_celsius>() + temperature_rise,
+            ),
+            Self::TEMPERATURE_COEFFICIENT,
+            context.delta(),
+        );
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.pressure.output()
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+}
+impl SimulationElement for ApuOilSystem {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.pump_failure.accept(visitor);
+        self.cooler_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
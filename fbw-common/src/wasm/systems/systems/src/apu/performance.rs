@@ -0,0 +1,120 @@
+use uom::si::{
+    electric_potential::volt, f64::*, pressure::pascal, ratio::ratio,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::simulation::UpdateContext;
+
+/// The conditions the APU operates in, expressed as factors relative to the performance
+/// it has at ISA sea level conditions with a fully charged starter supply. The turbine
+/// models are calibrated for those reference conditions.
+pub struct ApuPerformance {
+    /// Ambient pressure divided by ISA sea level pressure (delta).
+    pressure_ratio: f64,
+    /// Ambient temperature divided by ISA sea level temperature (theta).
+    temperature_ratio: f64,
+    starter_potential: ElectricPotential,
+}
+impl ApuPerformance {
+    const ISA_SEA_LEVEL_PRESSURE_PASCAL: f64 = 101325.;
+    const ISA_SEA_LEVEL_TEMPERATURE_KELVIN: f64 = 288.15;
+    /// The starter motor accelerates the turbine as designed down to this potential.
+    const NOMINAL_STARTER_POTENTIAL_VOLT: f64 = 25.;
+    const MIN_START_RATE: f64 = 0.3;
+    const MAX_START_RATE: f64 = 1.2;
+    /// How much the thinner air at altitude raises EGT for a given load.
+    const ALTITUDE_EGT_FACTOR: f64 = 0.15;
+    /// The share of the available power used by the generator(s) and the bleed air
+    /// compressor when both are used at once.
+    const GENERATOR_LOAD_SHARE: f64 = 0.35;
+    const BLEED_LOAD_SHARE: f64 = 0.65;
+
+    pub(super) fn new() -> Self {
+        Self {
+            pressure_ratio: 1.,
+            temperature_ratio: 1.,
+            starter_potential: ElectricPotential::default(),
+        }
+    }
+
+    pub(super) fn update(&mut self, context: &UpdateContext, starter_potential: ElectricPotential) {
+        self.pressure_ratio = (context.ambient_pressure().get::<pascal>()
+            / Self::ISA_SEA_LEVEL_PRESSURE_PASCAL)
+            .max(0.01);
+        self.temperature_ratio = (context.ambient_temperature().get::<kelvin>()
+            / Self::ISA_SEA_LEVEL_TEMPERATURE_KELVIN)
+            .max(0.01);
+        self.starter_potential = starter_potential;
+    }
+
+    /// The rate at which the start schedule progresses compared to ISA sea level.
+    /// Starts take longer at altitude, where the thinner air delays acceleration, and
+    /// with a weak starter supply.
+    pub fn start_rate(&self) -> f64 {
+        let aerodynamic_factor = self.corrected_mass_flow().sqrt();
+
+        // Once the starter motor is no longer powered, the turbine accelerates on its own.
+        let starter_factor = if self.starter_potential.get::<volt>() > 0. {
+            (self.starter_potential.get::<volt>() / Self::NOMINAL_STARTER_POTENTIAL_VOLT).min(1.)
+        } else {
+            1.
+        };
+
+        (aerodynamic_factor * starter_factor).clamp(Self::MIN_START_RATE, Self::MAX_START_RATE)
+    }
+
+    /// The power the turbine can deliver compared to ISA sea level.
+    pub fn available_power(&self) -> Ratio {
+        Ratio::new::<ratio>(self.corrected_mass_flow().min(1.))
+    }
+
+    /// The load demanded from the turbine as a ratio of its available power, limited to 1.
+    pub fn load(&self, apu_gen_is_used: bool, apu_bleed_is_used: bool) -> Ratio {
+        let demand = if apu_gen_is_used {
+            Self::GENERATOR_LOAD_SHARE
+        } else {
+            0.
+        } + if apu_bleed_is_used {
+            Self::BLEED_LOAD_SHARE
+        } else {
+            0.
+        };
+
+        Ratio::new::<ratio>((demand / self.available_power().get::<ratio>()).min(1.))
+    }
+
+    /// The part of the nominal bleed air pressure the turbine can still deliver after
+    /// the generator load has been served. The ECB gives priority to the generator.
+    pub fn bleed_air_pressure_factor(&self, apu_gen_is_used: bool) -> f64 {
+        let generator_share = if apu_gen_is_used {
+            Self::GENERATOR_LOAD_SHARE
+        } else {
+            0.
+        };
+
+        ((self.available_power().get::<ratio>() - generator_share) / Self::BLEED_LOAD_SHARE)
+            .clamp(0., 1.)
+    }
+
+    /// Corrects an EGT calibrated for ISA sea level to the current conditions. EGT follows
+    /// the ambient temperature and rises at altitude when the turbine is loaded, which
+    /// reduces the margin to the EGT limits in hot and high conditions.
+    pub fn corrected_egt(
+        &self,
+        isa_sea_level_egt: ThermodynamicTemperature,
+        load: Ratio,
+    ) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<kelvin>(
+            isa_sea_level_egt.get::<kelvin>()
+                * self.temperature_ratio
+                * (1.
+                    + Self::ALTITUDE_EGT_FACTOR
+                        * load.get::<ratio>()
+                        * (1. / self.pressure_ratio - 1.).max(0.)),
+        )
+    }
+
+    fn corrected_mass_flow(&self) -> f64 {
+        self.pressure_ratio / self.temperature_ratio.sqrt()
+    }
+}
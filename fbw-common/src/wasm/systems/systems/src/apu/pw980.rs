@@ -23,7 +23,10 @@ use crate::{
     simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
 };
 
-use super::{ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState};
+use super::{
+    ApuConstants, ApuGenerator, ApuPerformance, ApuStartMotor, ApuTurbineFailures, Turbine,
+    TurbineSignal, TurbineState,
+};
 
 pub struct Pw980Constants;
 
@@ -56,6 +59,8 @@ impl Turbine for ShutdownPw980Turbine {
         context: &UpdateContext,
         _: bool,
         _: bool,
+        _: &ApuPerformance,
+        _: &ApuTurbineFailures,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.egt = calculate_towards_ambient_egt(self.egt, context);
@@ -93,8 +98,15 @@ struct Starting {
     n2: Ratio,
     egt: ThermodynamicTemperature,
     ignore_calculated_egt: bool,
+    hung_for: Duration,
 }
 impl Starting {
+    // Without light off, the starter motor alone turns the turbine.
+    const NO_LIGHT_OFF_N: f64 = 25.;
+    // Below self sustaining speed, but above the speed at which the starter motor disengages.
+    const HUNG_START_N: f64 = 58.;
+    const HUNG_START_EGT_RISE_PER_SECOND: f64 = 8.;
+
     fn new(egt: ThermodynamicTemperature) -> Starting {
         Starting {
             since: Duration::from_secs(0),
@@ -102,10 +114,48 @@ impl Starting {
             n2: Ratio::default(),
             egt,
             ignore_calculated_egt: true,
+            hung_for: Duration::from_secs(0),
         }
     }
 
-    fn calculate_egt(&mut self, context: &UpdateContext) -> ThermodynamicTemperature {
+    fn calculate_n_with_failures(
+        &mut self,
+        context: &UpdateContext,
+        failures: &ApuTurbineFailures,
+    ) -> Ratio {
+        let n = self.calculate_n();
+        if failures.no_light_off() {
+            n.min(Ratio::new::<percent>(Self::NO_LIGHT_OFF_N))
+        } else if failures.hung_start() && n.get::<percent>() >= Self::HUNG_START_N {
+            self.hung_for += context.delta();
+            Ratio::new::<percent>(Self::HUNG_START_N)
+        } else {
+            n
+        }
+    }
+
+    fn calculate_egt_with_failures(
+        &mut self,
+        context: &UpdateContext,
+        performance: &ApuPerformance,
+        failures: &ApuTurbineFailures,
+    ) -> ThermodynamicTemperature {
+        if failures.no_light_off() {
+            // No fuel is burning, thus EGT only follows the ambient temperature.
+            calculate_towards_ambient_egt(self.egt, context)
+        } else {
+            self.calculate_egt(context, performance)
+                + TemperatureInterval::new::<temperature_interval::degree_celsius>(
+                    Self::HUNG_START_EGT_RISE_PER_SECOND * self.hung_for.as_secs_f64(),
+                )
+        }
+    }
+
+    fn calculate_egt(
+        &mut self,
+        context: &UpdateContext,
+        performance: &ApuPerformance,
+    ) -> ThermodynamicTemperature {
         // Refer to PW980.md for details on the values below and source data.
         const APU_N_TEMP_CONST: f64 = -67.85561068313169;
         const APU_N_TEMP_X: f64 = -19.73523738237853;
@@ -142,6 +192,9 @@ impl Starting {
                 + (APU_N_TEMP_X13 * n.powi(13)),
         );
 
+        // During the start, the turbine accelerates at its maximum fuel flow.
+        let temperature = performance.corrected_egt(temperature, Ratio::new::<percent>(100.));
+
         // The above calculated EGT can be lower than the ambient temperature,
         // or the current APU EGT (when cooling down). To prevent sudden changes
         // in temperature, we ignore the calculated EGT until it exceeds the current
@@ -286,17 +339,22 @@ impl Turbine for Starting {
         context: &UpdateContext,
         _: bool,
         _: bool,
+        performance: &ApuPerformance,
+        failures: &ApuTurbineFailures,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
-        self.since += context.delta();
-        self.n2 = self.calculate_n2();
+        self.since += context.delta().mul_f64(performance.start_rate());
+        // During a hung start, N2 stops accelerating together with N.
+        if self.hung_for == Duration::ZERO {
+            self.n2 = self.calculate_n2();
+        }
         if context.aircraft_preset_quick_mode() {
             self.n = Ratio::new::<percent>(100.);
             println!("apu/pw980.rs: Aircraft Preset Quick Mode is active, setting N to 100%");
         } else {
-            self.n = self.calculate_n();
+            self.n = self.calculate_n_with_failures(context, failures);
         };
-        self.egt = self.calculate_egt(context);
+        self.egt = self.calculate_egt_with_failures(context, performance, failures);
 
         match controller.signal() {
             Some(TurbineSignal::Stop) | None => Box::new(Stopping::new(self.egt, self.n, self.n2)),
@@ -466,18 +524,28 @@ impl ApuBleedUsageN2Delta {
 }
 
 struct Running {
+    n: Ratio,
+    bleed_air_pressure_factor: f64,
     egt: ThermodynamicTemperature,
     base_egt: ThermodynamicTemperature,
     base_egt_deviation: TemperatureInterval,
     bleed_air_usage: BleedAirUsageEgtDelta,
     apu_gen_usage: ApuGenUsageEgtDelta,
+    overtemperature_egt_delta: TemperatureInterval,
     n2: Ratio,
     bleed_air_n2_delta: ApuBleedUsageN2Delta,
 }
 impl Running {
+    const OVERSPEED_N: f64 = 112.;
+    const N_CHANGE_PER_SECOND: f64 = 2.;
+    const OVERTEMPERATURE_EGT_DELTA: f64 = 550.;
+    const OVERTEMPERATURE_EGT_CHANGE_PER_SECOND: f64 = 20.;
+
     fn new(egt: ThermodynamicTemperature) -> Running {
         let base_egt = 480. + ((random_number() % 11) as f64);
         Running {
+            n: Ratio::new::<percent>(100.),
+            bleed_air_pressure_factor: 1.,
             egt,
             base_egt: ThermodynamicTemperature::new::<degree_celsius>(base_egt),
             // This contains the deviation from the base EGT at the moment of entering the running state.
@@ -486,14 +554,51 @@ impl Running {
             ),
             bleed_air_usage: BleedAirUsageEgtDelta::new(),
             apu_gen_usage: ApuGenUsageEgtDelta::new(),
+            overtemperature_egt_delta: TemperatureInterval::new::<
+                temperature_interval::degree_celsius,
+            >(0.),
             n2: Ratio::default(),
             bleed_air_n2_delta: ApuBleedUsageN2Delta::new(),
         }
     }
 
+    fn calculate_n(&self, context: &UpdateContext, failures: &ApuTurbineFailures) -> Ratio {
+        let target = if failures.overspeed() {
+            Self::OVERSPEED_N
+        } else {
+            100.
+        };
+        let n = self.n.get::<percent>();
+        let max_change = Self::N_CHANGE_PER_SECOND * context.delta_as_secs_f64();
+
+        Ratio::new::<percent>(n + (target - n).clamp(-max_change, max_change))
+    }
+
+    fn update_overtemperature_egt_delta(
+        &mut self,
+        context: &UpdateContext,
+        failures: &ApuTurbineFailures,
+    ) {
+        let target = if failures.egt_overtemperature() {
+            Self::OVERTEMPERATURE_EGT_DELTA
+        } else {
+            0.
+        };
+        let delta = self
+            .overtemperature_egt_delta
+            .get::<temperature_interval::degree_celsius>();
+        let max_change = Self::OVERTEMPERATURE_EGT_CHANGE_PER_SECOND * context.delta_as_secs_f64();
+
+        self.overtemperature_egt_delta =
+            TemperatureInterval::new::<temperature_interval::degree_celsius>(
+                delta + (target - delta).clamp(-max_change, max_change),
+            );
+    }
+
     fn calculate_egt(
         &mut self,
         context: &UpdateContext,
+        performance: &ApuPerformance,
         apu_gen_is_used: bool,
         apu_bleed_is_used: bool,
     ) -> ThermodynamicTemperature {
@@ -512,7 +617,8 @@ impl Running {
         self.bleed_air_usage.update(context, apu_bleed_is_used);
         target += self.bleed_air_usage.egt_delta();
 
-        target
+        performance.corrected_egt(target, performance.load(apu_gen_is_used, apu_bleed_is_used))
+            + self.overtemperature_egt_delta
     }
 
     fn calculate_n2(&mut self, context: &UpdateContext, apu_bleed_is_used: bool) -> Ratio {
@@ -531,23 +637,26 @@ impl Turbine for Running {
         context: &UpdateContext,
         apu_bleed_is_used: bool,
         apu_gen_is_used: bool,
+        performance: &ApuPerformance,
+        failures: &ApuTurbineFailures,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
-        self.egt = self.calculate_egt(context, apu_gen_is_used, apu_bleed_is_used);
+        self.n = self.calculate_n(context, failures);
+        self.update_overtemperature_egt_delta(context, failures);
+        self.egt = self.calculate_egt(context, performance, apu_gen_is_used, apu_bleed_is_used);
+        self.bleed_air_pressure_factor = performance.bleed_air_pressure_factor(apu_gen_is_used);
         self.n2 = self.calculate_n2(context, apu_bleed_is_used);
 
         match controller.signal() {
             Some(TurbineSignal::StartOrContinue) => self,
-            Some(TurbineSignal::Stop) | None => Box::new(Stopping::new(
-                self.egt,
-                Ratio::new::<percent>(100.),
-                self.n2(),
-            )),
+            Some(TurbineSignal::Stop) | None => {
+                Box::new(Stopping::new(self.egt, self.n, self.n2()))
+            }
         }
     }
 
     fn n(&self) -> Ratio {
-        Ratio::new::<percent>(100.)
+        self.n
     }
 
     fn n2(&self) -> Ratio {
@@ -564,7 +673,7 @@ impl Turbine for Running {
 
     fn bleed_air_pressure(&self) -> Pressure {
         // Value from refs, we add standard pressure at sea level as state of unpressurized system
-        Pressure::new::<psi>(40.)
+        Pressure::new::<psi>(40. * self.bleed_air_pressure_factor)
             + InternationalStandardAtmosphere::pressure_at_altitude(Length::ZERO)
     }
 }
@@ -749,6 +858,8 @@ impl Turbine for Stopping {
         context: &UpdateContext,
         _: bool,
         _: bool,
+        _: &ApuPerformance,
+        _: &ApuTurbineFailures,
         _: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.since += context.delta();
@@ -968,6 +1079,7 @@ pub struct Pw980StartMotor {
     /// potential coming in via those contactors.
     powered_by: ElectricalBusType,
     is_powered: bool,
+    potential: ElectricPotential,
     powered_since: Duration,
 }
 impl Pw980StartMotor {
@@ -975,6 +1087,7 @@ impl Pw980StartMotor {
         Pw980StartMotor {
             powered_by,
             is_powered: false,
+            potential: ElectricPotential::default(),
             powered_since: Duration::ZERO,
         }
     }
@@ -983,10 +1096,15 @@ impl ApuStartMotor for Pw980StartMotor {
     fn is_powered(&self) -> bool {
         self.is_powered
     }
+
+    fn potential(&self) -> ElectricPotential {
+        self.potential
+    }
 }
 impl SimulationElement for Pw980StartMotor {
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered = buses.is_powered(self.powered_by);
+        self.potential = buses.potential_of(self.powered_by).raw();
     }
 
     fn consume_power<T: ConsumePower>(&mut self, context: &UpdateContext, consumption: &mut T) {
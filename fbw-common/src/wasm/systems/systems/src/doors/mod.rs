@@ -0,0 +1,559 @@
+use crate::{
+    air_conditioning::CabinPressure,
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use uom::si::{f64::*, pressure::hectopascal, ratio::percent};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DoorType {
+    Passenger,
+    Emergency,
+    Cargo,
+}
+
+/// A door of the fuselage with its latching and locking mechanism.
+///
+/// Passenger and emergency doors are plug type doors which are opened manually through the
+/// simulator door, whose position the door always reports. Opening a locked door while the
+/// cabin differential pressure exceeds the residual pressure threshold triggers the residual
+/// pressure warning. The door itself doesn't prevent the opening, the simulator door is closed
+/// again upon the warning by the aircraft's `residual_pressure_door_lock` aspect.
+/// Cargo doors are actuated by their own mechanism, which reports whether the door is locked.
+pub struct Door {
+    is_closed_id: VariableIdentifier,
+    is_locked_id: VariableIdentifier,
+    open_amount_id: VariableIdentifier,
+    slide_armed_id: VariableIdentifier,
+    slide_deployed_id: VariableIdentifier,
+    residual_pressure_id: VariableIdentifier,
+    open_request_id: Option<VariableIdentifier>,
+
+    door_type: DoorType,
+    open_request: Ratio,
+    open_amount: Ratio,
+    residual_pressure_warning: bool,
+
+    locking_handle: DoorLockingHandle,
+    slide: Option<EscapeSlide>,
+    closed_sensor: DoorProximitySensor,
+    locked_sensor: DoorProximitySensor,
+}
+impl Door {
+    const RESIDUAL_PRESSURE_HPA: f64 = 2.5;
+
+    /// Creates a door identified by `id`. The opening request is read from `open_request_id`,
+    /// doors without an opening request, such as the overwing exits, always remain closed.
+    pub fn new(
+        context: &mut InitContext,
+        id: &str,
+        door_type: DoorType,
+        open_request_id: Option<VariableIdentifier>,
+        slide: Option<EscapeSlide>,
+    ) -> Self {
+        Self {
+            is_closed_id: context.get_identifier(format!("DOOR_{}_IS_CLOSED", id)),
+            is_locked_id: context.get_identifier(format!("DOOR_{}_IS_LOCKED", id)),
+            open_amount_id: context.get_identifier(format!("DOOR_{}_OPEN_AMOUNT", id)),
+            slide_armed_id: context.get_identifier(format!("DOOR_{}_SLIDE_ARMED", id)),
+            slide_deployed_id: context.get_identifier(format!("DOOR_{}_SLIDE_DEPLOYED", id)),
+            residual_pressure_id: context.get_identifier(format!("DOOR_{}_RESIDUAL_PRESSURE", id)),
+            open_request_id,
+
+            door_type,
+            open_request: Ratio::default(),
+            open_amount: Ratio::default(),
+            residual_pressure_warning: false,
+
+            locking_handle: DoorLockingHandle::new(),
+            slide,
+            closed_sensor: DoorProximitySensor::new(),
+            locked_sensor: DoorProximitySensor::new(),
+        }
+    }
+
+    /// Updates a manually operated passenger or emergency door.
+    pub fn update(&mut self, context: &UpdateContext, cabin_pressure: &impl CabinPressure) {
+        let differential_pressure =
+            cabin_pressure.cabin_pressure() - cabin_pressure.exterior_pressure();
+        let residual_pressure =
+            differential_pressure > Pressure::new::<hectopascal>(Self::RESIDUAL_PRESSURE_HPA);
+        let opening_requested = self.open_request > Ratio::default();
+
+        self.residual_pressure_warning = opening_requested
+            && residual_pressure
+            && (self.residual_pressure_warning || self.is_locked());
+
+        self.open_amount = self.open_request;
+        self.locking_handle.update(
+            context,
+            opening_requested || self.open_amount > Ratio::default(),
+        );
+
+        self.update_slide_and_sensors(self.locking_handle.position());
+    }
+
+    /// Updates a door actuated by its own mechanism, such as the hydraulic cargo doors.
+    pub fn update_actuated(&mut self, open_amount: Ratio, is_locked: bool) {
+        self.open_amount = open_amount;
+        self.residual_pressure_warning = false;
+
+        self.update_slide_and_sensors(if is_locked {
+            Ratio::default()
+        } else {
+            Ratio::new::<percent>(100.)
+        });
+    }
+
+    fn update_slide_and_sensors(&mut self, locking_mechanism_position: Ratio) {
+        if let Some(slide) = &mut self.slide {
+            slide.update(self.open_amount);
+        }
+
+        self.closed_sensor.update(self.open_amount);
+        self.locked_sensor.update(locking_mechanism_position);
+    }
+
+    pub fn door_type(&self) -> DoorType {
+        self.door_type
+    }
+
+    pub fn open_amount(&self) -> Ratio {
+        self.open_amount
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed_sensor.proximity_detected()
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.is_closed() && self.locked_sensor.proximity_detected()
+    }
+
+    pub fn residual_pressure_warning(&self) -> bool {
+        self.residual_pressure_warning
+    }
+
+    pub fn slide_is_armed(&self) -> bool {
+        self.slide.as_ref().is_some_and(|slide| slide.is_armed())
+    }
+
+    pub fn slide_is_deployed(&self) -> bool {
+        self.slide.as_ref().is_some_and(|slide| slide.is_deployed())
+    }
+}
+impl SimulationElement for Door {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        if let Some(slide) = &mut self.slide {
+            slide.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        if let Some(open_request_id) = self.open_request_id {
+            self.open_request = reader.read(&open_request_id);
+        }
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_closed_id, self.is_closed());
+        writer.write(&self.is_locked_id, self.is_locked());
+        writer.write(&self.open_amount_id, self.open_amount);
+        writer.write(&self.slide_armed_id, self.slide_is_armed());
+        writer.write(&self.slide_deployed_id, self.slide_is_deployed());
+        writer.write(&self.residual_pressure_id, self.residual_pressure_warning);
+    }
+}
+
+/// Locking handle of a manually operated door. The handle is lifted to unlock the door before
+/// it moves and lowered once the door is back in its frame, which locks it again.
+struct DoorLockingHandle {
+    position: Ratio,
+}
+impl DoorLockingHandle {
+    const TRAVEL_TIME_SECONDS: f64 = 1.;
+
+    fn new() -> Self {
+        Self {
+            position: Ratio::default(),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, lifted: bool) {
+        let travel =
+            Ratio::new::<percent>(100. * context.delta_as_secs_f64() / Self::TRAVEL_TIME_SECONDS);
+
+        self.position = if lifted {
+            (self.position + travel).min(Ratio::new::<percent>(100.))
+        } else {
+            (self.position - travel).max(Ratio::default())
+        };
+    }
+
+    /// Position of the handle, from lowered (0%) to lifted (100%)
+    fn position(&self) -> Ratio {
+        self.position
+    }
+}
+
+/// The escape slide of a door. The slide deploys when its door is opened while armed.
+///
+/// Slides without an arming input, such as the overwing slides, are permanently armed.
+pub struct EscapeSlide {
+    arming_id: Option<VariableIdentifier>,
+    is_armed: bool,
+    is_deployed: bool,
+}
+impl EscapeSlide {
+    const DEPLOYMENT_DOOR_OPEN_AMOUNT_PERCENT: f64 = 20.;
+
+    pub fn new(arming_id: Option<VariableIdentifier>) -> Self {
+        Self {
+            arming_id,
+            is_armed: arming_id.is_none(),
+            is_deployed: false,
+        }
+    }
+
+    fn update(&mut self, door_open_amount: Ratio) {
+        if door_open_amount > Ratio::new::<percent>(Self::DEPLOYMENT_DOOR_OPEN_AMOUNT_PERCENT) {
+            self.is_deployed |= self.is_armed;
+        } else if door_open_amount == Ratio::default() && !self.is_armed {
+            // The slide is considered repacked once its door is closed and disarmed.
+            self.is_deployed = false;
+        }
+    }
+
+    fn is_armed(&self) -> bool {
+        self.is_armed
+    }
+
+    fn is_deployed(&self) -> bool {
+        self.is_deployed
+    }
+}
+impl SimulationElement for EscapeSlide {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        if let Some(arming_id) = self.arming_id {
+            self.is_armed = reader.read(&arming_id);
+        }
+    }
+}
+
+/// Proximity sensor of a door, detecting its target once it is within the detection range of
+/// its rest position. The closed sensor targets the door structure, the locked sensor the
+/// locking mechanism. The sensors feed the SDAC which provides the door states to the ECAM.
+struct DoorProximitySensor {
+    target_displacement: Ratio,
+}
+impl DoorProximitySensor {
+    const DETECTION_RANGE_PERCENT: f64 = 2.;
+
+    fn new() -> Self {
+        Self {
+            target_displacement: Ratio::default(),
+        }
+    }
+
+    fn update(&mut self, target_displacement: Ratio) {
+        self.target_displacement = target_displacement;
+    }
+
+    fn proximity_detected(&self) -> bool {
+        self.target_displacement < Ratio::new::<percent>(Self::DETECTION_RANGE_PERCENT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::Electricity;
+    use crate::simulation::SimulationElement;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft,
+    };
+    use std::time::Duration;
+
+    struct TestCabinPressure {
+        differential_pressure: Pressure,
+    }
+    impl CabinPressure for TestCabinPressure {
+        fn exterior_pressure(&self) -> Pressure {
+            Pressure::new::<hectopascal>(1013.25)
+        }
+
+        fn cabin_pressure(&self) -> Pressure {
+            Pressure::new::<hectopascal>(1013.25) + self.differential_pressure
+        }
+    }
+
+    struct TestAircraft {
+        cabin_pressure: TestCabinPressure,
+        passenger_door: Door,
+        emergency_exit: Door,
+        cargo_door: Door,
+        cargo_door_locked: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let open_request_id = context.get_identifier("INTERACTIVE POINT OPEN:0".to_owned());
+            let slide_arming_id = context.get_identifier("SLIDES_ARMED".to_owned());
+
+            Self {
+                cabin_pressure: TestCabinPressure {
+                    differential_pressure: Pressure::default(),
+                },
+                passenger_door: Door::new(
+                    context,
+                    "FWD_LEFT",
+                    DoorType::Passenger,
+                    Some(open_request_id),
+                    Some(EscapeSlide::new(Some(slide_arming_id))),
+                ),
+                emergency_exit: Door::new(
+                    context,
+                    "EMER_LEFT",
+                    DoorType::Emergency,
+                    None,
+                    Some(EscapeSlide::new(None)),
+                ),
+                cargo_door: Door::new(context, "FWD_CARGO", DoorType::Cargo, None, None),
+                cargo_door_locked: true,
+            }
+        }
+
+        fn set_differential_pressure(&mut self, differential_pressure: Pressure) {
+            self.cabin_pressure.differential_pressure = differential_pressure;
+        }
+
+        fn set_cargo_door_locked(&mut self, is_locked: bool) {
+            self.cargo_door_locked = is_locked;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            context: &UpdateContext,
+            _: &mut Electricity,
+        ) {
+            self.passenger_door.update(context, &self.cabin_pressure);
+            self.emergency_exit.update(context, &self.cabin_pressure);
+            self.cargo_door.update_actuated(
+                if self.cargo_door_locked {
+                    Ratio::default()
+                } else {
+                    Ratio::new::<percent>(100.)
+                },
+                self.cargo_door_locked,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.passenger_door.accept(visitor);
+            self.emergency_exit.accept(visitor);
+            self.cargo_door.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    fn request_door_open(test_bed: &mut SimulationTestBed<TestAircraft>, open_amount: f64) {
+        test_bed.write_by_name(
+            "INTERACTIVE POINT OPEN:0",
+            Ratio::new::<percent>(open_amount),
+        );
+    }
+
+    #[test]
+    fn doors_are_closed_and_locked_at_init() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.passenger_door.is_closed()));
+        assert!(test_bed.query(|a| a.passenger_door.is_locked()));
+        assert!(test_bed.query(|a| a.emergency_exit.is_locked()));
+        assert!(test_bed.query(|a| a.cargo_door.is_locked()));
+    }
+
+    #[test]
+    fn door_opens_without_differential_pressure() {
+        let mut test_bed = test_bed();
+        request_door_open(&mut test_bed, 100.);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.passenger_door.is_closed()));
+        assert!(!test_bed.query(|a| a.passenger_door.is_locked()));
+        assert_eq!(
+            test_bed.query(|a| a.passenger_door.open_amount()),
+            Ratio::new::<percent>(100.)
+        );
+        let is_closed: bool = test_bed.read_by_name("DOOR_FWD_LEFT_IS_CLOSED");
+        assert!(!is_closed);
+    }
+
+    #[test]
+    fn door_relocks_once_closed_and_handle_lowered() {
+        let mut test_bed = test_bed();
+        request_door_open(&mut test_bed, 100.);
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        request_door_open(&mut test_bed, 0.);
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert!(test_bed.query(|a| a.passenger_door.is_closed()));
+        assert!(!test_bed.query(|a| a.passenger_door.is_locked()));
+
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        assert!(test_bed.query(|a| a.passenger_door.is_closed()));
+        assert!(test_bed.query(|a| a.passenger_door.is_locked()));
+    }
+
+    #[test]
+    fn door_unlocks_before_it_moves_out_of_its_frame() {
+        let mut test_bed = test_bed();
+        request_door_open(&mut test_bed, 1.);
+        test_bed.run_with_delta(Duration::from_millis(500));
+
+        assert!(test_bed.query(|a| a.passenger_door.is_closed()));
+        assert!(!test_bed.query(|a| a.passenger_door.is_locked()));
+    }
+
+    #[test]
+    fn opening_door_with_residual_pressure_raises_warning() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_differential_pressure(Pressure::new::<hectopascal>(10.)));
+        request_door_open(&mut test_bed, 100.);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.passenger_door.residual_pressure_warning()));
+        let residual_pressure: bool = test_bed.read_by_name("DOOR_FWD_LEFT_RESIDUAL_PRESSURE");
+        assert!(residual_pressure);
+    }
+
+    #[test]
+    fn door_reports_simulator_position_with_residual_pressure() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_differential_pressure(Pressure::new::<hectopascal>(10.)));
+        request_door_open(&mut test_bed, 100.);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.passenger_door.is_closed()));
+        assert!(!test_bed.query(|a| a.passenger_door.is_locked()));
+        assert_eq!(
+            test_bed.query(|a| a.passenger_door.open_amount()),
+            Ratio::new::<percent>(100.)
+        );
+    }
+
+    #[test]
+    fn residual_pressure_warning_stops_once_door_closed_again() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_differential_pressure(Pressure::new::<hectopascal>(10.)));
+        request_door_open(&mut test_bed, 100.);
+        test_bed.run();
+
+        request_door_open(&mut test_bed, 0.);
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        assert!(!test_bed.query(|a| a.passenger_door.residual_pressure_warning()));
+        assert!(test_bed.query(|a| a.passenger_door.is_locked()));
+    }
+
+    #[test]
+    fn door_unlocks_once_cabin_is_depressurised() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_differential_pressure(Pressure::new::<hectopascal>(10.)));
+        request_door_open(&mut test_bed, 100.);
+        test_bed.run();
+
+        test_bed.command(|a| a.set_differential_pressure(Pressure::new::<hectopascal>(1.)));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.passenger_door.is_locked()));
+        assert!(!test_bed.query(|a| a.passenger_door.residual_pressure_warning()));
+    }
+
+    #[test]
+    fn open_door_is_not_affected_by_differential_pressure() {
+        let mut test_bed = test_bed();
+        request_door_open(&mut test_bed, 100.);
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        test_bed.command(|a| a.set_differential_pressure(Pressure::new::<hectopascal>(10.)));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.passenger_door.is_locked()));
+        assert!(!test_bed.query(|a| a.passenger_door.residual_pressure_warning()));
+    }
+
+    #[test]
+    fn slide_deploys_when_armed_door_opens() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("SLIDES_ARMED", true);
+        request_door_open(&mut test_bed, 100.);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.passenger_door.slide_is_deployed()));
+        let slide_deployed: bool = test_bed.read_by_name("DOOR_FWD_LEFT_SLIDE_DEPLOYED");
+        assert!(slide_deployed);
+    }
+
+    #[test]
+    fn slide_does_not_deploy_when_disarmed_door_opens() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("SLIDES_ARMED", false);
+        request_door_open(&mut test_bed, 100.);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.passenger_door.slide_is_armed()));
+        assert!(!test_bed.query(|a| a.passenger_door.slide_is_deployed()));
+    }
+
+    #[test]
+    fn deployed_slide_is_repacked_once_door_closed_and_disarmed() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("SLIDES_ARMED", true);
+        request_door_open(&mut test_bed, 100.);
+        test_bed.run();
+
+        test_bed.write_by_name("SLIDES_ARMED", false);
+        request_door_open(&mut test_bed, 0.);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.passenger_door.slide_is_deployed()));
+    }
+
+    #[test]
+    fn emergency_exit_slide_is_permanently_armed() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("SLIDES_ARMED", false);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.emergency_exit.slide_is_armed()));
+        assert!(test_bed.query(|a| a.emergency_exit.is_closed()));
+    }
+
+    #[test]
+    fn actuated_door_follows_its_mechanism() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_cargo_door_locked(false));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.cargo_door.is_locked()));
+        assert!(!test_bed.query(|a| a.cargo_door.is_closed()));
+        assert!(!test_bed.query(|a| a.cargo_door.slide_is_armed()));
+        let fwd_cargo_door_locked: bool = test_bed.read_by_name("DOOR_FWD_CARGO_IS_LOCKED");
+        assert!(!fwd_cargo_door_locked);
+    }
+}
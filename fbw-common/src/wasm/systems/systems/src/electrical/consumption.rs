@@ -19,14 +19,12 @@
 use super::ElectricalBusType;
 use crate::simulation::{InitContext, VariableIdentifier};
 use crate::{
-    shared::{random_number, ConsumePower, ElectricalBuses, FwcFlightPhase},
+    shared::{ConsumePower, ElectricalBuses},
     simulation::{
         Read, SimulationElement, SimulationElementVisitor, SimulatorReader, UpdateContext,
     },
 };
-use num_traits::FromPrimitive;
-use std::time::Duration;
-use uom::si::{f64::*, power::watt};
+use uom::si::{f64::*, power::watt, ratio::ratio};
 
 /// A generic consumer of power.
 pub struct PowerConsumer {
@@ -48,6 +46,10 @@ impl PowerConsumer {
     pub fn demand(&mut self, power: Power) {
         self.demand = power;
     }
+
+    pub fn is_powered(&self) -> bool {
+        self.is_powered
+    }
 }
 impl SimulationElement for PowerConsumer {
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
@@ -59,52 +61,95 @@ impl SimulationElement for PowerConsumer {
     }
 }
 
-/// A special type of power consumer which changes its consumption
-/// based on the phase of the flight.
-pub struct FlightPhasePowerConsumer {
-    fwc_flight_phase_id: VariableIdentifier,
+/// Condition under which an [ElectricalLoad] draws power.
+#[derive(Clone, Copy)]
+pub enum LoadCondition {
+    Always,
+    InFlight,
+    OnGround,
+    /// Draws power while the given simulation variable is true.
+    VariableIsOn(&'static str),
+    /// Draws power while the given simulation variable is false.
+    VariableIsOff(&'static str),
+}
 
+/// An equipment drawing power from an electrical bus, as listed in an [ElectricalLoadCatalogue].
+/// Equipment which cycles on and off, such as heaters, draws its nominal power scaled
+/// by its duty cycle.
+pub struct ElectricalLoad {
+    name: &'static str,
+    nominal_power: Power,
+    duty_cycle: Ratio,
+    condition: LoadCondition,
+    condition_id: Option<VariableIdentifier>,
+    condition_variable_is_on: bool,
+    is_on: bool,
     consumer: PowerConsumer,
-    base_demand: [Power; PowerConsumerFlightPhase::TaxiIn as usize + 1],
-    current_flight_phase: PowerConsumerFlightPhase,
-    update_after: Duration,
 }
-impl FlightPhasePowerConsumer {
-    pub fn new(context: &mut InitContext, bus_type: ElectricalBusType) -> Self {
+impl ElectricalLoad {
+    pub fn new(name: &'static str, bus_type: ElectricalBusType, nominal_power: Power) -> Self {
         Self {
-            fwc_flight_phase_id: context.get_identifier("FWC_FLIGHT_PHASE".to_owned()),
-
+            name,
+            nominal_power,
+            duty_cycle: Ratio::new::<ratio>(1.),
+            condition: LoadCondition::Always,
+            condition_id: None,
+            condition_variable_is_on: false,
+            is_on: false,
             consumer: PowerConsumer::from(bus_type),
-            base_demand: Default::default(),
-            current_flight_phase: PowerConsumerFlightPhase::BeforeStart,
-            update_after: Duration::from_secs(0),
         }
     }
 
-    pub fn update(&mut self, context: &UpdateContext) {
-        if self.update_after <= context.delta() {
-            self.update_after = Duration::from_secs_f64(5. + ((random_number() % 26) as f64));
-            let base_demand = self.base_demand[self.current_flight_phase as usize].get::<watt>();
-            self.consumer.demand(Power::new::<watt>(
-                base_demand * ((90. + ((random_number() % 21) as f64)) / 100.),
-            ));
+    pub fn duty_cycle(mut self, duty_cycle: Ratio) -> Self {
+        self.duty_cycle = duty_cycle;
+        self
+    }
+
+    pub fn when(mut self, condition: LoadCondition) -> Self {
+        self.condition = condition;
+        self
+    }
+
+    fn register(&mut self, context: &mut InitContext) {
+        self.condition_id = match self.condition {
+            LoadCondition::VariableIsOn(name) | LoadCondition::VariableIsOff(name) => {
+                Some(context.get_identifier(name.to_owned()))
+            }
+            _ => None,
+        };
+    }
+
+    fn update(&mut self, context: &UpdateContext) {
+        self.is_on = match self.condition {
+            LoadCondition::Always => true,
+            LoadCondition::InFlight => !context.is_on_ground(),
+            LoadCondition::OnGround => context.is_on_ground(),
+            LoadCondition::VariableIsOn(_) => self.condition_variable_is_on,
+            LoadCondition::VariableIsOff(_) => !self.condition_variable_is_on,
+        };
+
+        self.consumer.demand(if self.is_on {
+            self.nominal_power * self.duty_cycle.get::<ratio>()
         } else {
-            self.update_after -= context.delta();
-        }
+            Power::default()
+        });
     }
 
-    pub fn demand(
-        mut self,
-        demand: [(PowerConsumerFlightPhase, Power); PowerConsumerFlightPhase::TaxiIn as usize + 1],
-    ) -> Self {
-        for (phase, power) in &demand {
-            self.base_demand[*phase as usize] = *power;
-        }
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
 
-        self
+    /// The power drawn by the equipment, which is zero when it is switched off
+    /// or its bus is unpowered.
+    pub fn load(&self) -> Power {
+        if self.is_on && self.consumer.is_powered() {
+            self.nominal_power * self.duty_cycle.get::<ratio>()
+        } else {
+            Power::default()
+        }
     }
 }
-impl SimulationElement for FlightPhasePowerConsumer {
+impl SimulationElement for ElectricalLoad {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.consumer.accept(visitor);
 
@@ -112,38 +157,63 @@ impl SimulationElement for FlightPhasePowerConsumer {
     }
 
     fn read(&mut self, reader: &mut SimulatorReader) {
-        let flight_phase: Option<FwcFlightPhase> =
-            FromPrimitive::from_f64(reader.read(&self.fwc_flight_phase_id));
-        if let Some(phase) = flight_phase {
-            self.current_flight_phase = PowerConsumerFlightPhase::from(phase);
+        if let Some(id) = &self.condition_id {
+            self.condition_variable_is_on = reader.read(id);
         }
     }
 }
 
-#[derive(Copy, Clone)]
-pub enum PowerConsumerFlightPhase {
-    BeforeStart = 0,
-    AfterStart = 1,
-    Takeoff = 2,
-    Flight = 3,
-    Landing = 4,
-    TaxiIn = 5,
+/// Catalogue of the equipment loads of an aircraft which are not simulated by their own system.
+/// Each equipment draws power from its bus while its condition is met, such that bus loads reflect
+/// the equipment which is switched on, and shedding a bus removes the load of its equipment.
+pub struct ElectricalLoadCatalogue {
+    loads: Vec<ElectricalLoad>,
 }
-impl From<FwcFlightPhase> for PowerConsumerFlightPhase {
-    fn from(phase: FwcFlightPhase) -> Self {
-        match phase {
-            FwcFlightPhase::ElecPwr => PowerConsumerFlightPhase::BeforeStart,
-            FwcFlightPhase::FirstEngineStarted => PowerConsumerFlightPhase::AfterStart,
-            FwcFlightPhase::FirstEngineTakeOffPower => PowerConsumerFlightPhase::Takeoff,
-            FwcFlightPhase::AtOrAboveEightyKnots => PowerConsumerFlightPhase::Takeoff,
-            FwcFlightPhase::LiftOff => PowerConsumerFlightPhase::Takeoff,
-            FwcFlightPhase::AtOrAbove1500Feet => PowerConsumerFlightPhase::Flight,
-            FwcFlightPhase::AtOrBelow800Feet => PowerConsumerFlightPhase::Landing,
-            FwcFlightPhase::TouchDown => PowerConsumerFlightPhase::Landing,
-            FwcFlightPhase::AtOrBelowEightyKnots => PowerConsumerFlightPhase::TaxiIn,
-            FwcFlightPhase::EnginesShutdown => PowerConsumerFlightPhase::BeforeStart,
+impl ElectricalLoadCatalogue {
+    pub fn new(context: &mut InitContext, loads: impl IntoIterator<Item = ElectricalLoad>) -> Self {
+        Self {
+            loads: loads
+                .into_iter()
+                .map(|mut load| {
+                    load.register(context);
+                    load
+                })
+                .collect(),
         }
     }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.loads.iter_mut().for_each(|load| load.update(context));
+    }
+
+    /// The power drawn by the equipment with the given name.
+    pub fn load_of(&self, name: &str) -> Power {
+        self.loads
+            .iter()
+            .filter(|load| load.name == name)
+            .map(|load| load.load())
+            .sum()
+    }
+
+    /// The power drawn by all equipment on the given bus.
+    pub fn bus_load(&self, bus_type: ElectricalBusType) -> Power {
+        self.loads
+            .iter()
+            .filter(|load| load.consumer.powered_by_bus == bus_type)
+            .map(|load| load.load())
+            .sum()
+    }
+
+    pub fn loads(&self) -> &[ElectricalLoad] {
+        &self.loads
+    }
+}
+impl SimulationElement for ElectricalLoadCatalogue {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.loads, visitor);
+
+        visitor.visit(self);
+    }
 }
 
 #[cfg(test)]
@@ -152,39 +222,33 @@ mod tests {
     use crate::shared::{PotentialOrigin, PowerConsumptionReport};
 
     #[cfg(test)]
-    mod flight_phase_power_consumer_tests {
+    mod electrical_load_catalogue_tests {
         use crate::{
             electrical::{test::TestElectricitySource, ElectricalBus},
             simulation::{
-                test::{SimulationTestBed, TestBed},
+                test::{SimulationTestBed, TestBed, WriteByName},
                 Aircraft,
             },
         };
 
         use super::*;
-        use crate::simulation::test::WriteByName;
-        use crate::simulation::InitContext;
 
-        struct FlightPhasePowerConsumerTestAircraft {
+        struct ElectricalLoadCatalogueTestAircraft {
             electricity_source: TestElectricitySource,
             apu_generator_consumption: Option<Power>,
-            consumer: FlightPhasePowerConsumer,
-            bus_with_demand: ElectricalBus,
+            catalogue: ElectricalLoadCatalogue,
+            bus: ElectricalBus,
         }
-        impl FlightPhasePowerConsumerTestAircraft {
-            fn new(
-                consumer: FlightPhasePowerConsumer,
-                bus_with_demand: ElectricalBusType,
-                context: &mut InitContext,
-            ) -> Self {
+        impl ElectricalLoadCatalogueTestAircraft {
+            fn new(context: &mut InitContext, loads: Vec<ElectricalLoad>) -> Self {
                 Self {
                     electricity_source: TestElectricitySource::unpowered(
                         context,
                         PotentialOrigin::ApuGenerator(1),
                     ),
                     apu_generator_consumption: None,
-                    consumer,
-                    bus_with_demand: ElectricalBus::new(context, bus_with_demand),
+                    catalogue: ElectricalLoadCatalogue::new(context, loads),
+                    bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
                 }
             }
 
@@ -194,35 +258,28 @@ mod tests {
 
             fn consumption_equals(&self, expected: Power) -> bool {
                 match self.apu_generator_consumption {
-                    Some(consumption) => consumption == expected,
-                    None => false,
-                }
-            }
-
-            fn consumption_within_range(&self, min: Power, max: Power) -> bool {
-                match self.apu_generator_consumption {
-                    Some(consumption) => min <= consumption && consumption <= max,
+                    Some(consumption) => (consumption - expected).abs() < Power::new::<watt>(0.1),
                     None => false,
                 }
             }
         }
-        impl Aircraft for FlightPhasePowerConsumerTestAircraft {
+        impl Aircraft for ElectricalLoadCatalogueTestAircraft {
             fn update_before_power_distribution(
                 &mut self,
                 _: &UpdateContext,
                 electricity: &mut crate::electrical::Electricity,
             ) {
                 electricity.supplied_by(&self.electricity_source);
-                electricity.flow(&self.electricity_source, &self.bus_with_demand);
+                electricity.flow(&self.electricity_source, &self.bus);
             }
 
             fn update_after_power_distribution(&mut self, context: &UpdateContext) {
-                self.consumer.update(context);
+                self.catalogue.update(context);
             }
         }
-        impl SimulationElement for FlightPhasePowerConsumerTestAircraft {
+        impl SimulationElement for ElectricalLoadCatalogueTestAircraft {
             fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-                self.consumer.accept(visitor);
+                self.catalogue.accept(visitor);
 
                 visitor.visit(self);
             }
@@ -237,118 +294,133 @@ mod tests {
             }
         }
 
-        fn apply_flight_phase(
-            test_bed: &mut SimulationTestBed<FlightPhasePowerConsumerTestAircraft>,
-            phase: FwcFlightPhase,
-        ) {
-            test_bed.write_by_name("FWC_FLIGHT_PHASE", phase as i32 as f64);
+        fn test_bed(
+            loads: Vec<ElectricalLoad>,
+        ) -> SimulationTestBed<ElectricalLoadCatalogueTestAircraft> {
+            let mut test_bed = SimulationTestBed::new(|context| {
+                ElectricalLoadCatalogueTestAircraft::new(context, loads)
+            });
+            test_bed.command(|a| a.power());
+
+            test_bed
         }
 
+        const BUS: ElectricalBusType = ElectricalBusType::AlternatingCurrent(1);
+
         #[test]
-        fn when_flight_phase_doesnt_have_demand_usage_is_zero() {
-            let mut test_bed = SimulationTestBed::new(|context| {
-                FlightPhasePowerConsumerTestAircraft::new(
-                    FlightPhasePowerConsumer::new(
-                        context,
-                        ElectricalBusType::AlternatingCurrent(1),
-                    )
-                    .demand([
-                        (
-                            PowerConsumerFlightPhase::BeforeStart,
-                            Power::new::<watt>(0.),
-                        ),
-                        (PowerConsumerFlightPhase::AfterStart, Power::new::<watt>(0.)),
-                        (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(0.)),
-                        (PowerConsumerFlightPhase::Flight, Power::new::<watt>(0.)),
-                        (PowerConsumerFlightPhase::Landing, Power::new::<watt>(0.)),
-                        (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(0.)),
-                    ]),
-                    ElectricalBusType::AlternatingCurrent(1),
-                    context,
-                )
-            });
+        fn equipment_always_on_draws_its_nominal_power() {
+            let mut test_bed = test_bed(vec![ElectricalLoad::new(
+                "AVIONICS",
+                BUS,
+                Power::new::<watt>(3000.),
+            )]);
+            test_bed.run();
 
-            test_bed.command(|a| a.power());
+            assert!(test_bed.query(|a| a.consumption_equals(Power::new::<watt>(3000.))));
+            assert!(
+                test_bed.query(|a| a.catalogue.load_of("AVIONICS") == Power::new::<watt>(3000.))
+            );
+        }
+
+        #[test]
+        fn equipment_draws_its_nominal_power_scaled_by_duty_cycle() {
+            let mut test_bed = test_bed(vec![ElectricalLoad::new(
+                "WINDSHIELD HEAT",
+                BUS,
+                Power::new::<watt>(2000.),
+            )
+            .duty_cycle(Ratio::new::<ratio>(0.6))]);
+            test_bed.run();
+
+            assert!(test_bed.query(|a| a.consumption_equals(Power::new::<watt>(1200.))));
+        }
+
+        #[test]
+        fn equipment_switched_on_by_variable_draws_power_only_when_on() {
+            let mut test_bed = test_bed(vec![ElectricalLoad::new(
+                "WINDSHIELD HEAT",
+                BUS,
+                Power::new::<watt>(2000.),
+            )
+            .when(LoadCondition::VariableIsOn("PITOT HEAT"))]);
+            test_bed.write_by_name("PITOT HEAT", false);
+            test_bed.run();
 
-            apply_flight_phase(&mut test_bed, FwcFlightPhase::FirstEngineStarted);
+            assert!(test_bed.query(|a| a.consumption_equals(Power::new::<watt>(0.))));
+
+            test_bed.write_by_name("PITOT HEAT", true);
+            test_bed.run();
 
+            assert!(test_bed.query(|a| a.consumption_equals(Power::new::<watt>(2000.))));
+        }
+
+        #[test]
+        fn equipment_switched_off_by_variable_draws_no_power_when_off() {
+            let mut test_bed = test_bed(vec![ElectricalLoad::new(
+                "GALLEY",
+                BUS,
+                Power::new::<watt>(8000.),
+            )
+            .when(LoadCondition::VariableIsOff("ELEC_GALLEY_IS_SHED"))]);
+            test_bed.write_by_name("ELEC_GALLEY_IS_SHED", true);
             test_bed.run();
 
             assert!(test_bed.query(|a| a.consumption_equals(Power::new::<watt>(0.))));
+            assert!(test_bed.query(|a| a.catalogue.load_of("GALLEY") == Power::default()));
         }
 
         #[test]
-        fn when_flight_phase_does_have_demand_usage_is_close_to_demand() {
-            let input = Power::new::<watt>(20000.);
-            let mut test_bed = SimulationTestBed::new(|context| {
-                FlightPhasePowerConsumerTestAircraft::new(
-                    FlightPhasePowerConsumer::new(
-                        context,
-                        ElectricalBusType::AlternatingCurrent(1),
-                    )
-                    .demand([
-                        (
-                            PowerConsumerFlightPhase::BeforeStart,
-                            Power::new::<watt>(0.),
-                        ),
-                        (PowerConsumerFlightPhase::AfterStart, Power::new::<watt>(0.)),
-                        (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(0.)),
-                        (PowerConsumerFlightPhase::Flight, input),
-                        (PowerConsumerFlightPhase::Landing, Power::new::<watt>(0.)),
-                        (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(0.)),
-                    ]),
-                    ElectricalBusType::AlternatingCurrent(1),
-                    context,
-                )
-            });
+        fn equipment_drawing_power_in_flight_only() {
+            let mut test_bed = test_bed(vec![ElectricalLoad::new(
+                "IN FLIGHT",
+                BUS,
+                Power::new::<watt>(500.),
+            )
+            .when(LoadCondition::InFlight)]);
+            test_bed.set_on_ground(true);
+            test_bed.run();
 
-            test_bed.command(|a| a.power());
+            assert!(test_bed.query(|a| a.consumption_equals(Power::new::<watt>(0.))));
+
+            test_bed.set_on_ground(false);
+            test_bed.run();
 
-            apply_flight_phase(&mut test_bed, FwcFlightPhase::AtOrAbove1500Feet);
+            assert!(test_bed.query(|a| a.consumption_equals(Power::new::<watt>(500.))));
+        }
 
+        #[test]
+        fn loads_of_equipment_on_the_same_bus_add_up() {
+            let mut test_bed = test_bed(vec![
+                ElectricalLoad::new("AVIONICS", BUS, Power::new::<watt>(3000.)),
+                ElectricalLoad::new("CABIN", BUS, Power::new::<watt>(1500.)),
+                ElectricalLoad::new(
+                    "OTHER BUS",
+                    ElectricalBusType::AlternatingCurrent(2),
+                    Power::new::<watt>(1000.),
+                ),
+            ]);
             test_bed.run();
 
-            assert!(test_bed.query(|a| a.consumption_within_range(input * 0.9, input * 1.1)));
+            assert!(test_bed.query(|a| a.consumption_equals(Power::new::<watt>(4500.))));
+            assert!(test_bed.query(|a| a.catalogue.bus_load(BUS) == Power::new::<watt>(4500.)));
         }
 
         #[test]
-        fn when_flight_phase_does_have_demand_but_consumer_unpowered_usage_is_zero() {
+        fn equipment_on_an_unpowered_bus_draws_no_power() {
             let mut test_bed = SimulationTestBed::new(|context| {
-                FlightPhasePowerConsumerTestAircraft::new(
-                    FlightPhasePowerConsumer::new(
-                        context,
-                        ElectricalBusType::AlternatingCurrent(1),
-                    )
-                    .demand([
-                        (
-                            PowerConsumerFlightPhase::BeforeStart,
-                            Power::new::<watt>(20000.),
-                        ),
-                        (
-                            PowerConsumerFlightPhase::AfterStart,
-                            Power::new::<watt>(20000.),
-                        ),
-                        (
-                            PowerConsumerFlightPhase::Takeoff,
-                            Power::new::<watt>(20000.),
-                        ),
-                        (PowerConsumerFlightPhase::Flight, Power::new::<watt>(20000.)),
-                        (
-                            PowerConsumerFlightPhase::Landing,
-                            Power::new::<watt>(20000.),
-                        ),
-                        (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(20000.)),
-                    ]),
-                    ElectricalBusType::AlternatingCurrent(1),
+                ElectricalLoadCatalogueTestAircraft::new(
                     context,
+                    vec![ElectricalLoad::new(
+                        "AVIONICS",
+                        BUS,
+                        Power::new::<watt>(3000.),
+                    )],
                 )
             });
-
-            apply_flight_phase(&mut test_bed, FwcFlightPhase::FirstEngineStarted);
-
             test_bed.run();
 
             assert!(test_bed.query(|a| a.consumption_equals(Power::new::<watt>(0.))));
+            assert!(test_bed.query(|a| a.catalogue.load_of("AVIONICS") == Power::default()));
         }
     }
 }
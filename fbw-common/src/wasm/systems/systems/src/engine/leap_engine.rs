@@ -1,11 +1,16 @@
-use uom::si::{angular_velocity::revolution_per_minute, f64::*, pressure::psi, ratio::percent};
+use std::time::Duration;
+
+use uom::si::{
+    angular_velocity::revolution_per_minute, f64::*, pressure::psi, ratio::percent,
+    temperature_interval, thermodynamic_temperature::degree_celsius,
+};
 
 use crate::{
     shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
     simulation::{Read, SimulationElement, SimulatorReader, UpdateContext},
 };
 
-use super::Engine;
+use super::{start_control::EngineStartSchedule, Engine};
 use crate::simulation::{InitContext, VariableIdentifier};
 
 pub struct LeapEngine {
@@ -56,6 +61,21 @@ impl LeapEngine {
         }
     }
 
+    /// The start is sequenced on N2, which is the spool driven by the starter.
+    pub fn start_schedule() -> EngineStartSchedule {
+        EngineStartSchedule::new(
+            "ENGINE_N2",
+            Ratio::new::<percent>(16.0),
+            Ratio::new::<percent>(22.0),
+            Ratio::new::<percent>(50.0),
+            TemperatureInterval::new::<temperature_interval::degree_celsius>(50.),
+            Duration::from_secs(15),
+            ThermodynamicTemperature::new::<degree_celsius>(750.0),
+            Duration::from_secs(10),
+            Duration::from_secs(120),
+        )
+    }
+
     pub fn update(&mut self, _: &UpdateContext) {}
 
     fn update_parameters(&mut self) {
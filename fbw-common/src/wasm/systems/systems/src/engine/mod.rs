@@ -10,6 +10,7 @@ use crate::{
 pub mod leap_engine;
 pub mod reverser;
 pub mod reverser_thrust;
+pub mod start_control;
 pub mod trent_engine;
 
 pub trait Engine: EngineCorrectedN2 + EngineUncorrectedN2 + EngineCorrectedN1 {
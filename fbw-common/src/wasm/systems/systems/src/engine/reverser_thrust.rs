@@ -19,16 +19,19 @@ use crate::simulation::{InitContext, VariableIdentifier};
 
 #[derive(Copy, Clone)]
 struct ReverserThrust {
+    max_reverse_thrust: Force,
     current_thrust: Force,
 }
 impl ReverserThrust {
     const MIN_REVERSER_POS: f64 = 0.2;
 
-    fn new() -> Self {
+    fn new(max_reverse_thrust: Force) -> Self {
         Self {
+            max_reverse_thrust,
             current_thrust: Force::default(),
         }
     }
+
     fn update(
         &mut self,
         engine: &impl Engine,
@@ -40,12 +43,15 @@ impl ReverserThrust {
                 let current_thrust_force =
                     Force::new::<newton>(engine.net_thrust().get::<kilogram>() * 9.80665);
 
-                // Total net thrust with reverser will need to cancel out forward thrust generated by engine + added desired reverse thrust
-                (current_thrust_force
-                    + current_thrust_force
+                // Doors in the flow cancel out forward thrust generated by engine, then add reverse thrust
+                // depending on how much of the flow is actually deflected forward
+                current_thrust_force * reverser_position.reverser_position()
+                    + self.max_reverse_thrust
                         * Self::reverse_thrust_ratio_from_n1(engine.corrected_n1())
-                        * Self::reverse_thrust_ratio_longitudinal_velocity(longitudinal_velocity))
-                    * reverser_position.reverser_position()
+                        * Self::reverse_thrust_ratio_from_door_position(
+                            reverser_position.reverser_position(),
+                        )
+                        * Self::reverse_thrust_ratio_longitudinal_velocity(longitudinal_velocity)
             } else {
                 Force::default()
             };
@@ -56,9 +62,9 @@ impl ReverserThrust {
     }
 
     fn reverse_thrust_ratio_from_n1(engine_n1: Ratio) -> Ratio {
-        let n1_breakpoints = [0., 15., 20., 50., 55., 100.];
+        let n1_breakpoints = [0., 20., 30., 50., 70., 100.];
 
-        let reverse_thrust_ratio = [0., 0., 0.04, 0.31, 0.31, 0.31];
+        let reverse_thrust_ratio = [0., 0.05, 0.15, 0.45, 1., 1.];
 
         Ratio::new::<ratio>(interpolation(
             &n1_breakpoints,
@@ -67,13 +73,33 @@ impl ReverserThrust {
         ))
     }
 
+    fn reverse_thrust_ratio_from_door_position(reverser_position: Ratio) -> Ratio {
+        let position_breakpoints = [0., 0.2, 0.5, 0.8, 1.];
+
+        let reverse_thrust_ratio = [0., 0., 0.3, 0.8, 1.];
+
+        Ratio::new::<ratio>(interpolation(
+            &position_breakpoints,
+            &reverse_thrust_ratio,
+            reverser_position.get::<ratio>(),
+        ))
+    }
+
     fn reverse_thrust_ratio_longitudinal_velocity(longitudinal_velocity: Velocity) -> Ratio {
         let longitudinal_velocity_knots = longitudinal_velocity.get::<knot>();
 
         if longitudinal_velocity_knots >= 0. {
-            Ratio::new::<ratio>(1.0)
+            // At low speed reversed flow is re-ingested by the engine, losing reverse efficiency
+            let speed_breakpoints = [0., 40., 70., 500.];
+            let reingestion_ratio = [0.6, 0.75, 1., 1.];
+
+            Ratio::new::<ratio>(interpolation(
+                &speed_breakpoints,
+                &reingestion_ratio,
+                longitudinal_velocity_knots,
+            ))
         } else {
-            Ratio::new::<ratio>((0.1151 * longitudinal_velocity_knots).exp())
+            Ratio::new::<ratio>(0.6 * (0.1151 * longitudinal_velocity_knots).exp())
         }
     }
 }
@@ -93,14 +119,14 @@ pub struct ReverserForce {
 impl ReverserForce {
     const DISTANCE_FROM_CG_TO_ENGINE_METER: f64 = 5.;
 
-    pub fn new(context: &mut InitContext) -> Self {
+    pub fn new(context: &mut InitContext, max_reverse_thrust: Force) -> Self {
         Self {
             reverser_delta_speed_id: context.get_identifier("REVERSER_DELTA_SPEED".to_owned()),
             reverser_angular_accel_id: context
                 .get_identifier("REVERSER_ANGULAR_ACCELERATION".to_owned()),
             reverser_delta_accel_id: context.get_identifier("REVERSER_DELTA_ACCEL".to_owned()),
 
-            reversers: [ReverserThrust::new(); 2],
+            reversers: [ReverserThrust::new(max_reverse_thrust); 2],
             plane_delta_speed_due_to_reverse_thrust: Velocity::default(),
             plane_delta_acceleration_due_to_reverse_thrust: Acceleration::default(),
             dissimetry_acceleration: AngularAcceleration::default(),
@@ -173,3 +199,117 @@ impl SimulationElement for ReverserForce {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2};
+    use uom::si::angular_velocity::revolution_per_minute;
+
+    struct TestEngine {
+        corrected_n1: Ratio,
+        net_thrust: Mass,
+    }
+    impl TestEngine {
+        fn new(corrected_n1: Ratio, net_thrust: Mass) -> Self {
+            Self {
+                corrected_n1,
+                net_thrust,
+            }
+        }
+    }
+    impl EngineCorrectedN1 for TestEngine {
+        fn corrected_n1(&self) -> Ratio {
+            self.corrected_n1
+        }
+    }
+    impl EngineCorrectedN2 for TestEngine {
+        fn corrected_n2(&self) -> Ratio {
+            Ratio::new::<percent>(80.)
+        }
+    }
+    impl EngineUncorrectedN2 for TestEngine {
+        fn uncorrected_n2(&self) -> Ratio {
+            Ratio::new::<percent>(80.)
+        }
+    }
+    impl Engine for TestEngine {
+        fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
+            AngularVelocity::new::<revolution_per_minute>(0.)
+        }
+
+        fn oil_pressure_is_low(&self) -> bool {
+            false
+        }
+
+        fn is_above_minimum_idle(&self) -> bool {
+            true
+        }
+
+        fn net_thrust(&self) -> Mass {
+            self.net_thrust
+        }
+
+        fn gearbox_speed(&self) -> AngularVelocity {
+            AngularVelocity::new::<revolution_per_minute>(0.)
+        }
+    }
+
+    struct TestReverser {
+        position: Ratio,
+    }
+    impl ReverserPosition for TestReverser {
+        fn reverser_position(&self) -> Ratio {
+            self.position
+        }
+    }
+
+    fn reverse_thrust(n1_percent: f64, position: f64, speed_knots: f64) -> Force {
+        let mut reverser = ReverserThrust::new(Force::new::<newton>(30000.));
+
+        reverser.update(
+            &TestEngine::new(
+                Ratio::new::<percent>(n1_percent),
+                Mass::new::<kilogram>(1000.),
+            ),
+            &TestReverser {
+                position: Ratio::new::<ratio>(position),
+            },
+            Velocity::new::<knot>(speed_knots),
+        );
+
+        reverser.current_thrust()
+    }
+
+    #[test]
+    fn no_reverse_thrust_when_stowed() {
+        assert!(reverse_thrust(70., 0., 100.) == Force::default());
+    }
+
+    #[test]
+    fn full_reverse_thrust_when_deployed_at_max_reverse_n1() {
+        let forward_thrust = 1000. * 9.80665;
+
+        assert!(
+            (reverse_thrust(70., 1., 100.).get::<newton>() - (forward_thrust + 30000.)).abs() < 1.
+        );
+    }
+
+    #[test]
+    fn reverse_thrust_increases_with_n1() {
+        assert!(reverse_thrust(70., 1., 100.) > reverse_thrust(50., 1., 100.));
+        assert!(reverse_thrust(50., 1., 100.) > reverse_thrust(25., 1., 100.));
+    }
+
+    #[test]
+    fn reverse_thrust_increases_with_door_opening() {
+        assert!(reverse_thrust(70., 1., 100.) > reverse_thrust(70., 0.6, 100.));
+        assert!(reverse_thrust(70., 0.6, 100.) > reverse_thrust(70., 0.3, 100.));
+    }
+
+    #[test]
+    fn reverse_thrust_reduced_by_reingestion_at_low_speed() {
+        assert!(reverse_thrust(70., 1., 100.) > reverse_thrust(70., 1., 50.));
+        assert!(reverse_thrust(70., 1., 50.) > reverse_thrust(70., 1., 10.));
+    }
+}
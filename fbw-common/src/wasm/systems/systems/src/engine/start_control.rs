@@ -0,0 +1,964 @@
+use std::time::Duration;
+
+use uom::si::{
+    f64::*, power::watt, pressure::psi, ratio::percent, thermodynamic_temperature::degree_celsius,
+};
+
+use crate::{
+    electrical::consumption::PowerConsumer,
+    pneumatic::EngineModeSelector,
+    shared::ElectricalBusType,
+    simulation::{
+        InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write, Writer,
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EngineStartPhase {
+    Idle = 0,
+    DryCranking = 1,
+    Cranking = 2,
+    Ignition = 3,
+    Accelerating = 4,
+    Running = 5,
+    Aborted = 6,
+}
+
+read_write_enum!(EngineStartPhase);
+
+impl From<f64> for EngineStartPhase {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => EngineStartPhase::Idle,
+            1 => EngineStartPhase::DryCranking,
+            2 => EngineStartPhase::Cranking,
+            3 => EngineStartPhase::Ignition,
+            4 => EngineStartPhase::Accelerating,
+            5 => EngineStartPhase::Running,
+            6 => EngineStartPhase::Aborted,
+            _ => panic!("EngineStartPhase value does not correspond to any enum member"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EngineStartFault {
+    None = 0,
+    HotStart = 1,
+    HungStart = 2,
+    NoLightOff = 3,
+    StarterTimeExceeded = 4,
+}
+
+read_write_enum!(EngineStartFault);
+
+impl From<f64> for EngineStartFault {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => EngineStartFault::None,
+            1 => EngineStartFault::HotStart,
+            2 => EngineStartFault::HungStart,
+            3 => EngineStartFault::NoLightOff,
+            4 => EngineStartFault::StarterTimeExceeded,
+            _ => panic!("EngineStartFault value does not correspond to any enum member"),
+        }
+    }
+}
+
+/// The engine type specific speeds, temperatures and times of the start sequence.
+/// Speeds are of the high pressure spool, which is the spool driven by the starter.
+#[derive(Clone, Copy)]
+pub struct EngineStartSchedule {
+    hp_spool_speed_variable: &'static str,
+    ignition_speed: Ratio,
+    fuel_on_speed: Ratio,
+    starter_cut_out_speed: Ratio,
+    light_off_egt_rise: TemperatureInterval,
+    max_light_off_time: Duration,
+    start_egt_limit: ThermodynamicTemperature,
+    min_acceleration: Ratio,
+    hung_start_time: Duration,
+    max_starter_time: Duration,
+    dry_crank_time: Duration,
+}
+impl EngineStartSchedule {
+    pub fn new(
+        hp_spool_speed_variable: &'static str,
+        ignition_speed: Ratio,
+        fuel_on_speed: Ratio,
+        starter_cut_out_speed: Ratio,
+        light_off_egt_rise: TemperatureInterval,
+        max_light_off_time: Duration,
+        start_egt_limit: ThermodynamicTemperature,
+        hung_start_time: Duration,
+        max_starter_time: Duration,
+    ) -> Self {
+        Self {
+            hp_spool_speed_variable,
+            ignition_speed,
+            fuel_on_speed,
+            starter_cut_out_speed,
+            light_off_egt_rise,
+            max_light_off_time,
+            start_egt_limit,
+            min_acceleration: Ratio::new::<percent>(1.),
+            hung_start_time,
+            max_starter_time,
+            dry_crank_time: Duration::from_secs(30),
+        }
+    }
+}
+
+/// The start sequencing of a single engine as performed by its FADEC.
+///
+/// An automatic start is initiated by setting the master switch on with the mode selector in IGN/START.
+/// On ground, the FADEC alternates between igniter A and B for each start and aborts the start on a
+/// hot start, hung start, no light off or when the starter time limit is exceeded. The engine is then
+/// dry cranked to clear the fuel. In flight or during a manual start, faults are only annunciated.
+///
+/// A manual start is initiated by the MAN START pushbutton with the mode selector in IGN/START.
+/// Ignition and fuel are supplied as soon as the master switch is set on. A dry crank is performed
+/// by the MAN START pushbutton with the mode selector in CRANK.
+pub struct EngineStartControl {
+    schedule: EngineStartSchedule,
+
+    master_switch_id: VariableIdentifier,
+    manual_start_id: VariableIdentifier,
+    hp_spool_speed_id: VariableIdentifier,
+    egt_id: VariableIdentifier,
+    phase_id: VariableIdentifier,
+    fault_id: VariableIdentifier,
+    igniter_a_active_id: VariableIdentifier,
+    igniter_b_active_id: VariableIdentifier,
+    starter_valve_commanded_id: VariableIdentifier,
+    fuel_on_id: VariableIdentifier,
+
+    master_switch_is_on: bool,
+    manual_start_is_on: bool,
+    hp_spool_speed: Ratio,
+    egt: ThermodynamicTemperature,
+
+    phase: EngineStartPhase,
+    fault: EngineStartFault,
+    is_manual_start: bool,
+    phase_duration: Duration,
+    starter_duration: Duration,
+    light_off_reference_egt: ThermodynamicTemperature,
+    hung_start_reference_speed: Ratio,
+    hung_start_duration: Duration,
+
+    igniter_b_selected: bool,
+    previous_mode_selector: EngineModeSelector,
+    continuous_ignition_selected: bool,
+    igniter_a_commanded: bool,
+    igniter_b_commanded: bool,
+    igniter_a: PowerConsumer,
+    igniter_b: PowerConsumer,
+}
+impl EngineStartControl {
+    const MIN_STARTER_AIR_PRESSURE_PSIG: f64 = 10.;
+    const IGNITER_POWER_WATT: f64 = 60.;
+
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        schedule: EngineStartSchedule,
+        igniter_a_powered_by: ElectricalBusType,
+        igniter_b_powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            schedule,
+
+            master_switch_id: context.get_identifier(format!("FUELSYSTEM VALVE SWITCH:{}", number)),
+            manual_start_id: context.get_identifier(format!("ENGMANSTART{}_TOGGLE", number)),
+            hp_spool_speed_id: context
+                .get_identifier(format!("{}:{}", schedule.hp_spool_speed_variable, number)),
+            egt_id: context.get_identifier(format!("ENGINE_EGT:{}", number)),
+            phase_id: context.get_identifier(format!("FADEC_START_PHASE_ENG{}", number)),
+            fault_id: context.get_identifier(format!("FADEC_START_FAULT_ENG{}", number)),
+            igniter_a_active_id: context
+                .get_identifier(format!("FADEC_IGNITER_A_ACTIVE_ENG{}", number)),
+            igniter_b_active_id: context
+                .get_identifier(format!("FADEC_IGNITER_B_ACTIVE_ENG{}", number)),
+            starter_valve_commanded_id: context
+                .get_identifier(format!("FADEC_STARTER_VALVE_COMMANDED_ENG{}", number)),
+            fuel_on_id: context.get_identifier(format!("FADEC_FUEL_ON_COMMANDED_ENG{}", number)),
+
+            master_switch_is_on: false,
+            manual_start_is_on: false,
+            hp_spool_speed: Ratio::default(),
+            egt: ThermodynamicTemperature::new::<degree_celsius>(0.),
+
+            phase: EngineStartPhase::Idle,
+            fault: EngineStartFault::None,
+            is_manual_start: false,
+            phase_duration: Duration::default(),
+            starter_duration: Duration::default(),
+            light_off_reference_egt: ThermodynamicTemperature::new::<degree_celsius>(0.),
+            hung_start_reference_speed: Ratio::default(),
+            hung_start_duration: Duration::default(),
+
+            igniter_b_selected: false,
+            previous_mode_selector: EngineModeSelector::Norm,
+            continuous_ignition_selected: false,
+            igniter_a_commanded: false,
+            igniter_b_commanded: false,
+            igniter_a: PowerConsumer::from(igniter_a_powered_by),
+            igniter_b: PowerConsumer::from(igniter_b_powered_by),
+        }
+    }
+
+    /// Updates the start sequence. The starter air pressure is the absolute pressure
+    /// downstream of the starter valve, which must be sufficient for the starter to motor the engine.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        mode_selector: EngineModeSelector,
+        starter_air_pressure: Pressure,
+    ) {
+        let starter_is_motoring = self.starter_valve_is_commanded_open()
+            && (starter_air_pressure - context.ambient_pressure()).get::<psi>()
+                >= Self::MIN_STARTER_AIR_PRESSURE_PSIG;
+        if starter_is_motoring {
+            self.starter_duration += context.delta();
+        } else {
+            self.starter_duration = Duration::default();
+        }
+        self.phase_duration += context.delta();
+
+        let next_phase = self.next_phase(context, mode_selector);
+        if next_phase != self.phase {
+            self.transition_to(next_phase);
+        }
+
+        self.update_ignition(context, mode_selector);
+    }
+
+    fn next_phase(
+        &mut self,
+        context: &UpdateContext,
+        mode_selector: EngineModeSelector,
+    ) -> EngineStartPhase {
+        match self.phase {
+            EngineStartPhase::Idle => {
+                if self.master_switch_is_on && self.is_above_starter_cut_out_speed() {
+                    EngineStartPhase::Running
+                } else if mode_selector == EngineModeSelector::Crank
+                    && self.manual_start_is_on
+                    && !self.master_switch_is_on
+                {
+                    EngineStartPhase::DryCranking
+                } else if mode_selector == EngineModeSelector::Ignition
+                    && (self.master_switch_is_on || self.manual_start_is_on)
+                {
+                    self.is_manual_start = self.manual_start_is_on;
+                    EngineStartPhase::Cranking
+                } else {
+                    EngineStartPhase::Idle
+                }
+            }
+            EngineStartPhase::DryCranking => {
+                if self.fault != EngineStartFault::None {
+                    if self.phase_duration >= self.schedule.dry_crank_time
+                        || self.is_above_starter_cut_out_speed()
+                    {
+                        EngineStartPhase::Aborted
+                    } else {
+                        EngineStartPhase::DryCranking
+                    }
+                } else if mode_selector != EngineModeSelector::Crank || !self.manual_start_is_on {
+                    EngineStartPhase::Idle
+                } else if self.starter_time_is_exceeded() {
+                    self.fault = EngineStartFault::StarterTimeExceeded;
+                    EngineStartPhase::Aborted
+                } else {
+                    EngineStartPhase::DryCranking
+                }
+            }
+            EngineStartPhase::Cranking => {
+                if !self.start_is_selected() {
+                    EngineStartPhase::Idle
+                } else if self.starter_time_is_exceeded() {
+                    self.abort(context, EngineStartFault::StarterTimeExceeded)
+                } else if (self.is_manual_start && self.master_switch_is_on)
+                    || (!self.is_manual_start && self.hp_spool_speed >= self.schedule.fuel_on_speed)
+                {
+                    EngineStartPhase::Ignition
+                } else {
+                    EngineStartPhase::Cranking
+                }
+            }
+            EngineStartPhase::Ignition => {
+                if !self.master_switch_is_on {
+                    EngineStartPhase::Idle
+                } else if self.is_above_starter_cut_out_speed() {
+                    EngineStartPhase::Running
+                } else if self.egt > self.schedule.start_egt_limit {
+                    self.abort(context, EngineStartFault::HotStart)
+                } else if self.egt
+                    >= self.light_off_reference_egt + self.schedule.light_off_egt_rise
+                {
+                    EngineStartPhase::Accelerating
+                } else if self.phase_duration >= self.schedule.max_light_off_time {
+                    self.abort(context, EngineStartFault::NoLightOff)
+                } else if self.starter_time_is_exceeded() {
+                    self.abort(context, EngineStartFault::StarterTimeExceeded)
+                } else {
+                    EngineStartPhase::Ignition
+                }
+            }
+            EngineStartPhase::Accelerating => {
+                self.update_hung_start_detection(context);
+
+                if !self.master_switch_is_on {
+                    EngineStartPhase::Idle
+                } else if self.is_above_starter_cut_out_speed() {
+                    EngineStartPhase::Running
+                } else if self.egt > self.schedule.start_egt_limit {
+                    self.abort(context, EngineStartFault::HotStart)
+                } else if self.hung_start_duration >= self.schedule.hung_start_time {
+                    self.abort(context, EngineStartFault::HungStart)
+                } else if self.starter_time_is_exceeded() {
+                    self.abort(context, EngineStartFault::StarterTimeExceeded)
+                } else {
+                    EngineStartPhase::Accelerating
+                }
+            }
+            EngineStartPhase::Running => {
+                if self.master_switch_is_on {
+                    EngineStartPhase::Running
+                } else {
+                    EngineStartPhase::Idle
+                }
+            }
+            EngineStartPhase::Aborted => {
+                if self.master_switch_is_on || self.manual_start_is_on {
+                    EngineStartPhase::Aborted
+                } else {
+                    EngineStartPhase::Idle
+                }
+            }
+        }
+    }
+
+    /// Records the fault and returns the phase following it. The start is only aborted
+    /// by the FADEC during an automatic start on ground.
+    fn abort(&mut self, context: &UpdateContext, fault: EngineStartFault) -> EngineStartPhase {
+        self.fault = fault;
+
+        if self.is_manual_start || !context.is_on_ground() {
+            self.phase
+        } else if fault == EngineStartFault::StarterTimeExceeded {
+            EngineStartPhase::Aborted
+        } else {
+            EngineStartPhase::DryCranking
+        }
+    }
+
+    fn transition_to(&mut self, phase: EngineStartPhase) {
+        let was_starting = self.is_starting();
+
+        self.phase = phase;
+        self.phase_duration = Duration::default();
+
+        match phase {
+            EngineStartPhase::Idle => {
+                if !self.master_switch_is_on && !self.manual_start_is_on {
+                    self.fault = EngineStartFault::None;
+                }
+            }
+            EngineStartPhase::Cranking => self.fault = EngineStartFault::None,
+            EngineStartPhase::Ignition => self.light_off_reference_egt = self.egt,
+            EngineStartPhase::Accelerating => {
+                self.hung_start_reference_speed = self.hp_spool_speed;
+                self.hung_start_duration = Duration::default();
+            }
+            _ => {}
+        }
+
+        if was_starting && !self.is_starting() && !self.is_manual_start {
+            self.igniter_b_selected = !self.igniter_b_selected;
+        }
+    }
+
+    fn update_hung_start_detection(&mut self, context: &UpdateContext) {
+        if self.hp_spool_speed - self.hung_start_reference_speed >= self.schedule.min_acceleration {
+            self.hung_start_reference_speed = self.hp_spool_speed;
+            self.hung_start_duration = Duration::default();
+        } else {
+            self.hung_start_duration += context.delta();
+        }
+    }
+
+    fn update_ignition(&mut self, context: &UpdateContext, mode_selector: EngineModeSelector) {
+        // Continuous ignition is selected by setting the mode selector to IGN/START while the
+        // engine runs, thus it isn't selected when the selector remains there after a start.
+        self.continuous_ignition_selected = mode_selector == EngineModeSelector::Ignition
+            && (self.continuous_ignition_selected
+                || (self.phase == EngineStartPhase::Running
+                    && self.previous_mode_selector != EngineModeSelector::Ignition));
+        self.previous_mode_selector = mode_selector;
+
+        let (igniter_a, igniter_b) = match self.phase {
+            EngineStartPhase::Cranking
+                if self.is_manual_start || self.hp_spool_speed < self.schedule.ignition_speed =>
+            {
+                (false, false)
+            }
+            EngineStartPhase::Cranking
+            | EngineStartPhase::Ignition
+            | EngineStartPhase::Accelerating => {
+                if self.is_manual_start
+                    || !context.is_on_ground()
+                    || self.fault != EngineStartFault::None
+                {
+                    (true, true)
+                } else {
+                    (!self.igniter_b_selected, self.igniter_b_selected)
+                }
+            }
+            EngineStartPhase::Running if self.continuous_ignition_selected => (true, true),
+            _ => (false, false),
+        };
+
+        self.igniter_a_commanded = igniter_a;
+        self.igniter_b_commanded = igniter_b;
+
+        let power = |commanded| {
+            Power::new::<watt>(if commanded {
+                Self::IGNITER_POWER_WATT
+            } else {
+                0.
+            })
+        };
+        self.igniter_a.demand(power(igniter_a));
+        self.igniter_b.demand(power(igniter_b));
+    }
+
+    fn start_is_selected(&self) -> bool {
+        self.master_switch_is_on || (self.is_manual_start && self.manual_start_is_on)
+    }
+
+    fn starter_time_is_exceeded(&self) -> bool {
+        self.starter_duration >= self.schedule.max_starter_time
+    }
+
+    fn is_above_starter_cut_out_speed(&self) -> bool {
+        self.hp_spool_speed >= self.schedule.starter_cut_out_speed
+    }
+
+    pub fn phase(&self) -> EngineStartPhase {
+        self.phase
+    }
+
+    pub fn fault(&self) -> EngineStartFault {
+        self.fault
+    }
+
+    pub fn is_starting(&self) -> bool {
+        matches!(
+            self.phase,
+            EngineStartPhase::Cranking
+                | EngineStartPhase::Ignition
+                | EngineStartPhase::Accelerating
+        )
+    }
+
+    pub fn starter_valve_is_commanded_open(&self) -> bool {
+        self.is_starting() || self.phase == EngineStartPhase::DryCranking
+    }
+
+    pub fn fuel_is_commanded_on(&self) -> bool {
+        matches!(
+            self.phase,
+            EngineStartPhase::Ignition | EngineStartPhase::Accelerating | EngineStartPhase::Running
+        )
+    }
+
+    pub fn igniter_a_is_active(&self) -> bool {
+        self.igniter_a_commanded && self.igniter_a.is_powered()
+    }
+
+    pub fn igniter_b_is_active(&self) -> bool {
+        self.igniter_b_commanded && self.igniter_b.is_powered()
+    }
+}
+impl SimulationElement for EngineStartControl {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.igniter_a.accept(visitor);
+        self.igniter_b.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.master_switch_is_on = reader.read(&self.master_switch_id);
+        self.manual_start_is_on = reader.read(&self.manual_start_id);
+        self.hp_spool_speed = Ratio::new::<percent>(reader.read(&self.hp_spool_speed_id));
+        self.egt = reader.read(&self.egt_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.phase_id, self.phase);
+        writer.write(&self.fault_id, self.fault);
+        writer.write(&self.igniter_a_active_id, self.igniter_a_is_active());
+        writer.write(&self.igniter_b_active_id, self.igniter_b_is_active());
+        writer.write(
+            &self.starter_valve_commanded_id,
+            self.starter_valve_is_commanded_open(),
+        );
+        writer.write(&self.fuel_on_id, self.fuel_is_commanded_on());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        engine::leap_engine::LeapEngine,
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        ac_ess_shed_bus: ElectricalBus,
+        ac_1_bus: ElectricalBus,
+        start_control: EngineStartControl,
+        mode_selector: EngineModeSelector,
+        starter_air_pressure: Pressure,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                ac_ess_shed_bus: ElectricalBus::new(
+                    context,
+                    ElectricalBusType::AlternatingCurrentEssentialShed,
+                ),
+                ac_1_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                start_control: EngineStartControl::new(
+                    context,
+                    1,
+                    LeapEngine::start_schedule(),
+                    ElectricalBusType::AlternatingCurrentEssentialShed,
+                    ElectricalBusType::AlternatingCurrent(1),
+                ),
+                mode_selector: EngineModeSelector::Norm,
+                starter_air_pressure: Pressure::new::<psi>(14.7 + 35.),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.electricity_source);
+            electricity.flow(&self.electricity_source, &self.ac_ess_shed_bus);
+            electricity.flow(&self.electricity_source, &self.ac_1_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.start_control
+                .update(context, self.mode_selector, self.starter_air_pressure);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.ac_ess_shed_bus.accept(visitor);
+            self.ac_1_bus.accept(visitor);
+            self.start_control.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct StartControlTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl StartControlTestBed {
+        fn new() -> Self {
+            let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+            test_bed.set_on_ground(true);
+
+            let mut start_control_test_bed = Self { test_bed };
+            start_control_test_bed.n2(0.);
+            start_control_test_bed.egt(15.);
+
+            start_control_test_bed
+        }
+
+        fn mode_selector(&mut self, mode_selector: EngineModeSelector) {
+            self.test_bed.command(|a| a.mode_selector = mode_selector);
+        }
+
+        fn master_switch(&mut self, is_on: bool) {
+            self.test_bed
+                .write_by_name("FUELSYSTEM VALVE SWITCH:1", is_on);
+        }
+
+        fn manual_start(&mut self, is_on: bool) {
+            self.test_bed.write_by_name("ENGMANSTART1_TOGGLE", is_on);
+        }
+
+        fn n2(&mut self, n2_percent: f64) {
+            self.test_bed.write_by_name("ENGINE_N2:1", n2_percent);
+        }
+
+        fn egt(&mut self, egt_celsius: f64) {
+            self.test_bed.write_by_name("ENGINE_EGT:1", egt_celsius);
+        }
+
+        fn no_starter_air(&mut self) {
+            self.test_bed
+                .command(|a| a.starter_air_pressure = Pressure::new::<psi>(14.7));
+        }
+
+        fn run_for(&mut self, duration: Duration) {
+            self.test_bed.run_multiple_frames(duration);
+        }
+
+        fn run(&mut self) {
+            self.test_bed.run_with_delta(Duration::from_millis(100));
+        }
+
+        fn phase(&self) -> EngineStartPhase {
+            self.test_bed.query(|a| a.start_control.phase())
+        }
+
+        fn fault(&self) -> EngineStartFault {
+            self.test_bed.query(|a| a.start_control.fault())
+        }
+
+        fn starter_valve_is_commanded_open(&self) -> bool {
+            self.test_bed
+                .query(|a| a.start_control.starter_valve_is_commanded_open())
+        }
+
+        fn fuel_is_commanded_on(&self) -> bool {
+            self.test_bed
+                .query(|a| a.start_control.fuel_is_commanded_on())
+        }
+
+        fn fadec_fuel_on_commanded(&mut self) -> bool {
+            self.test_bed.read_by_name("FADEC_FUEL_ON_COMMANDED_ENG1")
+        }
+
+        fn fadec_start_fault(&mut self) -> EngineStartFault {
+            self.test_bed.read_by_name("FADEC_START_FAULT_ENG1")
+        }
+
+        fn fadec_start_phase(&mut self) -> EngineStartPhase {
+            self.test_bed.read_by_name("FADEC_START_PHASE_ENG1")
+        }
+
+        fn fadec_starter_valve_commanded(&mut self) -> bool {
+            self.test_bed
+                .read_by_name("FADEC_STARTER_VALVE_COMMANDED_ENG1")
+        }
+
+        fn igniters_active(&self) -> (bool, bool) {
+            self.test_bed.query(|a| {
+                (
+                    a.start_control.igniter_a_is_active(),
+                    a.start_control.igniter_b_is_active(),
+                )
+            })
+        }
+
+        fn auto_start_up_to_light_off(&mut self) {
+            self.mode_selector(EngineModeSelector::Ignition);
+            self.master_switch(true);
+            self.run();
+            self.n2(25.);
+            self.run();
+            self.egt(150.);
+            self.run();
+        }
+
+        fn complete_start(&mut self) {
+            self.n2(55.);
+            self.run();
+        }
+    }
+
+    #[test]
+    fn starter_valve_opens_on_auto_start() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.mode_selector(EngineModeSelector::Ignition);
+        test_bed.run();
+
+        assert!(!test_bed.starter_valve_is_commanded_open());
+
+        test_bed.master_switch(true);
+        test_bed.run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Cranking);
+        assert!(test_bed.starter_valve_is_commanded_open());
+        assert!(!test_bed.fuel_is_commanded_on());
+        assert_eq!(test_bed.igniters_active(), (false, false));
+    }
+
+    #[test]
+    fn auto_start_ignites_and_supplies_fuel_at_scheduled_n2() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.mode_selector(EngineModeSelector::Ignition);
+        test_bed.master_switch(true);
+        test_bed.run();
+
+        test_bed.n2(18.);
+        test_bed.run();
+
+        assert_eq!(test_bed.igniters_active(), (true, false));
+        assert!(!test_bed.fuel_is_commanded_on());
+
+        test_bed.n2(23.);
+        test_bed.run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Ignition);
+        assert!(test_bed.fuel_is_commanded_on());
+    }
+
+    #[test]
+    fn starter_cuts_out_when_start_completes() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.auto_start_up_to_light_off();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Accelerating);
+
+        test_bed.complete_start();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Running);
+        assert!(!test_bed.starter_valve_is_commanded_open());
+        assert!(test_bed.fuel_is_commanded_on());
+        assert_eq!(test_bed.igniters_active(), (false, false));
+    }
+
+    #[test]
+    fn igniters_alternate_between_auto_starts() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.auto_start_up_to_light_off();
+
+        assert_eq!(test_bed.igniters_active(), (true, false));
+
+        test_bed.complete_start();
+        test_bed.master_switch(false);
+        test_bed.n2(0.);
+        test_bed.egt(15.);
+        test_bed.run();
+
+        test_bed.auto_start_up_to_light_off();
+
+        assert_eq!(test_bed.igniters_active(), (false, true));
+    }
+
+    #[test]
+    fn continuous_ignition_is_selected_with_engine_running() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.auto_start_up_to_light_off();
+        test_bed.complete_start();
+        test_bed.mode_selector(EngineModeSelector::Norm);
+        test_bed.run();
+
+        assert_eq!(test_bed.igniters_active(), (false, false));
+
+        test_bed.mode_selector(EngineModeSelector::Ignition);
+        test_bed.run();
+
+        assert_eq!(test_bed.igniters_active(), (true, true));
+    }
+
+    #[test]
+    fn no_light_off_aborts_auto_start_and_dry_cranks() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.mode_selector(EngineModeSelector::Ignition);
+        test_bed.master_switch(true);
+        test_bed.n2(25.);
+        test_bed.run();
+        test_bed.run();
+
+        assert!(test_bed.fuel_is_commanded_on());
+
+        test_bed.run_for(Duration::from_secs(16));
+
+        assert_eq!(test_bed.fault(), EngineStartFault::NoLightOff);
+        assert_eq!(test_bed.phase(), EngineStartPhase::DryCranking);
+        assert!(test_bed.starter_valve_is_commanded_open());
+        assert!(!test_bed.fuel_is_commanded_on());
+        assert_eq!(test_bed.igniters_active(), (false, false));
+
+        test_bed.run_for(Duration::from_secs(31));
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Aborted);
+        assert!(!test_bed.starter_valve_is_commanded_open());
+    }
+
+    #[test]
+    fn hot_start_aborts_auto_start() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.auto_start_up_to_light_off();
+        test_bed.egt(800.);
+        test_bed.run();
+
+        assert_eq!(test_bed.fault(), EngineStartFault::HotStart);
+        assert!(!test_bed.fuel_is_commanded_on());
+    }
+
+    #[test]
+    fn hot_start_abort_cuts_fuel_commanded_to_fadec() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.auto_start_up_to_light_off();
+
+        assert!(test_bed.fadec_fuel_on_commanded());
+        assert_eq!(test_bed.fadec_start_fault(), EngineStartFault::None);
+
+        test_bed.egt(800.);
+        test_bed.run();
+
+        assert!(!test_bed.fadec_fuel_on_commanded());
+        assert_eq!(test_bed.fadec_start_fault(), EngineStartFault::HotStart);
+    }
+
+    #[test]
+    fn fadec_keeps_starter_commanded_while_dry_cranking_after_abort() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.auto_start_up_to_light_off();
+        test_bed.egt(800.);
+        test_bed.run();
+
+        assert_eq!(test_bed.fadec_start_phase(), EngineStartPhase::DryCranking);
+        assert!(test_bed.fadec_starter_valve_commanded());
+        assert!(!test_bed.fadec_fuel_on_commanded());
+
+        test_bed.run_for(Duration::from_secs(31));
+
+        assert_eq!(test_bed.fadec_start_phase(), EngineStartPhase::Aborted);
+        assert!(!test_bed.fadec_starter_valve_commanded());
+    }
+
+    #[test]
+    fn hung_start_aborts_auto_start() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.auto_start_up_to_light_off();
+        test_bed.n2(35.);
+        test_bed.run_for(Duration::from_secs(15));
+
+        assert_eq!(test_bed.fault(), EngineStartFault::HungStart);
+        assert!(!test_bed.fuel_is_commanded_on());
+    }
+
+    #[test]
+    fn starter_time_limit_aborts_auto_start() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.mode_selector(EngineModeSelector::Ignition);
+        test_bed.master_switch(true);
+        test_bed.run_for(Duration::from_secs(125));
+
+        assert_eq!(test_bed.fault(), EngineStartFault::StarterTimeExceeded);
+        assert_eq!(test_bed.phase(), EngineStartPhase::Aborted);
+        assert!(!test_bed.starter_valve_is_commanded_open());
+    }
+
+    #[test]
+    fn starter_time_does_not_elapse_without_starter_air() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.no_starter_air();
+        test_bed.mode_selector(EngineModeSelector::Ignition);
+        test_bed.master_switch(true);
+        test_bed.run_for(Duration::from_secs(125));
+
+        assert_eq!(test_bed.fault(), EngineStartFault::None);
+        assert_eq!(test_bed.phase(), EngineStartPhase::Cranking);
+    }
+
+    #[test]
+    fn fault_is_cleared_by_setting_master_switch_off() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.auto_start_up_to_light_off();
+        test_bed.egt(800.);
+        test_bed.run();
+        test_bed.run_for(Duration::from_secs(31));
+        test_bed.master_switch(false);
+        test_bed.run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Idle);
+        assert_eq!(test_bed.fault(), EngineStartFault::None);
+    }
+
+    #[test]
+    fn auto_start_is_not_aborted_in_flight() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.test_bed.set_on_ground(false);
+        test_bed.auto_start_up_to_light_off();
+        test_bed.egt(800.);
+        test_bed.run();
+
+        assert_eq!(test_bed.fault(), EngineStartFault::HotStart);
+        assert!(test_bed.fuel_is_commanded_on());
+        assert_eq!(test_bed.igniters_active(), (true, true));
+    }
+
+    #[test]
+    fn manual_start_supplies_ignition_and_fuel_when_master_switch_is_set_on() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.mode_selector(EngineModeSelector::Ignition);
+        test_bed.manual_start(true);
+        test_bed.run();
+
+        assert!(test_bed.starter_valve_is_commanded_open());
+        assert!(!test_bed.fuel_is_commanded_on());
+
+        test_bed.n2(20.);
+        test_bed.master_switch(true);
+        test_bed.run();
+
+        assert!(test_bed.fuel_is_commanded_on());
+        assert_eq!(test_bed.igniters_active(), (true, true));
+
+        test_bed.egt(150.);
+        test_bed.run();
+        test_bed.complete_start();
+
+        assert!(!test_bed.starter_valve_is_commanded_open());
+    }
+
+    #[test]
+    fn dry_crank_motors_engine_without_ignition_and_fuel() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed.mode_selector(EngineModeSelector::Crank);
+        test_bed.manual_start(true);
+        test_bed.run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::DryCranking);
+        assert!(test_bed.starter_valve_is_commanded_open());
+        assert!(!test_bed.fuel_is_commanded_on());
+        assert_eq!(test_bed.igniters_active(), (false, false));
+
+        test_bed.manual_start(false);
+        test_bed.run();
+
+        assert!(!test_bed.starter_valve_is_commanded_open());
+    }
+
+    #[test]
+    fn igniters_are_inactive_when_unpowered() {
+        let mut test_bed = StartControlTestBed::new();
+        test_bed
+            .test_bed
+            .command(|a| a.electricity_source.unpower());
+        test_bed.auto_start_up_to_light_off();
+
+        assert_eq!(test_bed.igniters_active(), (false, false));
+    }
+}
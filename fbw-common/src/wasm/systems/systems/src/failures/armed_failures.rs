@@ -0,0 +1,484 @@
+use std::time::Duration;
+
+use uom::si::{f64::*, velocity::foot_per_minute};
+
+use crate::{shared::random_from_range, simulation::UpdateContext};
+
+use super::FailureType;
+
+/// Flight phase upon which a failure can be armed.
+/// Derived from ground state and vertical speed, so level flight at any altitude is cruise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FailureFlightPhase {
+    Ground,
+    Climb,
+    Cruise,
+    Descent,
+}
+impl FailureFlightPhase {
+    const CLIMB_DESCENT_VERTICAL_SPEED_FPM: f64 = 500.;
+
+    fn from_context(context: &UpdateContext) -> Self {
+        let vertical_speed = context
+            .local_velocity()
+            .vert_velocity()
+            .get::<foot_per_minute>();
+
+        if context.is_on_ground() {
+            Self::Ground
+        } else if vertical_speed > Self::CLIMB_DESCENT_VERTICAL_SPEED_FPM {
+            Self::Climb
+        } else if vertical_speed < -Self::CLIMB_DESCENT_VERTICAL_SPEED_FPM {
+            Self::Descent
+        } else {
+            Self::Cruise
+        }
+    }
+}
+
+/// Condition upon which an armed failure activates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FailureTrigger {
+    HeightAboveGroundAbove(Length),
+    HeightAboveGroundBelow(Length),
+    IndicatedAirspeedAbove(Velocity),
+    IndicatedAirspeedBelow(Velocity),
+    FlightPhase(FailureFlightPhase),
+    /// Time elapsed since the aircraft left the ground
+    TimeAfterTakeoff(Duration),
+    /// Random time after arming, drawn once between the two durations
+    RandomWithin(Duration, Duration),
+}
+
+struct ArmedFailure {
+    failure_type: FailureType,
+    severity: Ratio,
+    trigger: FailureTrigger,
+
+    armed_duration: Duration,
+    random_delay: Duration,
+}
+impl ArmedFailure {
+    fn new(failure_type: FailureType, severity: Ratio, trigger: FailureTrigger) -> Self {
+        Self {
+            failure_type,
+            severity,
+            trigger,
+
+            armed_duration: Duration::default(),
+            random_delay: match trigger {
+                FailureTrigger::RandomWithin(earliest, latest) if latest > earliest => {
+                    Duration::from_secs_f64(random_from_range(
+                        earliest.as_secs_f64(),
+                        latest.as_secs_f64(),
+                    ))
+                }
+                FailureTrigger::RandomWithin(earliest, _) => earliest,
+                _ => Duration::default(),
+            },
+        }
+    }
+
+    fn is_same_as(&self, severity: Ratio, trigger: FailureTrigger) -> bool {
+        self.severity == severity && self.trigger == trigger
+    }
+
+    fn is_triggered(
+        &self,
+        context: &UpdateContext,
+        flight_phase: FailureFlightPhase,
+        time_since_takeoff: Option<Duration>,
+    ) -> bool {
+        match self.trigger {
+            FailureTrigger::HeightAboveGroundAbove(height) => {
+                context.plane_height_over_ground() > height
+            }
+            FailureTrigger::HeightAboveGroundBelow(height) => {
+                context.plane_height_over_ground() < height
+            }
+            FailureTrigger::IndicatedAirspeedAbove(speed) => context.indicated_airspeed() > speed,
+            FailureTrigger::IndicatedAirspeedBelow(speed) => context.indicated_airspeed() < speed,
+            FailureTrigger::FlightPhase(phase) => flight_phase == phase,
+            FailureTrigger::TimeAfterTakeoff(delay) => {
+                time_since_takeoff.is_some_and(|time| time >= delay)
+            }
+            FailureTrigger::RandomWithin(_, _) => self.armed_duration >= self.random_delay,
+        }
+    }
+}
+
+/// Failures pre-loaded with a trigger condition, evaluated each update.
+/// A failure is disarmed once triggered, and reported with its severity so it can be activated.
+#[derive(Default)]
+pub struct ArmedFailures {
+    armed_failures: Vec<ArmedFailure>,
+
+    was_on_ground: Option<bool>,
+    time_since_takeoff: Option<Duration>,
+}
+impl ArmedFailures {
+    /// Arms a failure. Arming again an already armed failure with the same severity and trigger
+    /// keeps it running, so that random and delayed triggers are not restarted.
+    pub fn arm(&mut self, failure_type: FailureType, severity: Ratio, trigger: FailureTrigger) {
+        if self
+            .armed_failures
+            .iter()
+            .any(|armed| armed.failure_type == failure_type && armed.is_same_as(severity, trigger))
+        {
+            return;
+        }
+
+        self.disarm(failure_type);
+        self.armed_failures
+            .push(ArmedFailure::new(failure_type, severity, trigger));
+    }
+
+    pub fn disarm(&mut self, failure_type: FailureType) {
+        self.armed_failures
+            .retain(|armed| armed.failure_type != failure_type);
+    }
+
+    /// Keeps armed only the given failures, arming the ones not yet armed.
+    /// Failures which are already active are ignored, as the armed failures may have been sent
+    /// before the trigger of these failures was received.
+    pub fn set_armed(
+        &mut self,
+        failures: impl IntoIterator<Item = (FailureType, Ratio, FailureTrigger)>,
+        is_active: impl Fn(FailureType) -> bool,
+    ) {
+        let failures: Vec<_> = failures
+            .into_iter()
+            .filter(|(failure_type, _, _)| !is_active(*failure_type))
+            .collect();
+
+        self.armed_failures.retain(|armed| {
+            failures.iter().any(|(failure_type, severity, trigger)| {
+                armed.failure_type == *failure_type && armed.is_same_as(*severity, *trigger)
+            })
+        });
+
+        for (failure_type, severity, trigger) in failures {
+            self.arm(failure_type, severity, trigger);
+        }
+    }
+
+    pub fn is_armed(&self, failure_type: FailureType) -> bool {
+        self.armed_failures
+            .iter()
+            .any(|armed| armed.failure_type == failure_type)
+    }
+
+    /// Returns the failures triggered during this update along with their severity.
+    pub fn update(&mut self, context: &UpdateContext) -> Vec<(FailureType, Ratio)> {
+        self.update_time_since_takeoff(context);
+
+        let flight_phase = FailureFlightPhase::from_context(context);
+        let mut triggered_failures = Vec::new();
+
+        self.armed_failures.retain_mut(|armed| {
+            armed.armed_duration += context.delta();
+
+            if armed.is_triggered(context, flight_phase, self.time_since_takeoff) {
+                triggered_failures.push((armed.failure_type, armed.severity));
+                false
+            } else {
+                true
+            }
+        });
+
+        triggered_failures
+    }
+
+    fn update_time_since_takeoff(&mut self, context: &UpdateContext) {
+        if context.is_on_ground() {
+            self.time_since_takeoff = None;
+        } else if let Some(time) = self.time_since_takeoff.as_mut() {
+            *time += context.delta();
+        } else if self.was_on_ground == Some(true) {
+            self.time_since_takeoff = Some(Duration::default());
+        }
+
+        self.was_on_ground = Some(context.is_on_ground());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed, WriteByName},
+        Aircraft, InitContext, SimulationElement,
+    };
+    use uom::si::{
+        length::foot,
+        ratio::ratio,
+        velocity::{foot_per_second, knot},
+    };
+
+    struct TestAircraft {
+        armed_failures: ArmedFailures,
+        triggered_failures: Vec<(FailureType, Ratio)>,
+    }
+    impl TestAircraft {
+        fn new(_: &mut InitContext) -> Self {
+            Self {
+                armed_failures: ArmedFailures::default(),
+                triggered_failures: Vec::new(),
+            }
+        }
+
+        fn arm(&mut self, failure_type: FailureType, trigger: FailureTrigger) {
+            self.armed_failures
+                .arm(failure_type, Ratio::new::<ratio>(1.), trigger);
+        }
+
+        fn is_triggered(&self, failure_type: FailureType) -> bool {
+            self.triggered_failures
+                .iter()
+                .any(|(triggered, _)| *triggered == failure_type)
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.triggered_failures
+                .extend(self.armed_failures.update(context));
+        }
+    }
+    impl SimulationElement for TestAircraft {}
+
+    const FAILURE: FailureType = FailureType::TransformerRectifier(1);
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_on_ground(true);
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(0.));
+        test_bed.write_by_name("PLANE ALT ABOVE GROUND", 0.);
+        test_bed.write_by_name("VELOCITY BODY Y", 0.);
+
+        test_bed
+    }
+
+    fn take_off(test_bed: &mut SimulationTestBed<TestAircraft>) {
+        test_bed.set_on_ground(false);
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(160.));
+        test_bed.write_by_name(
+            "VELOCITY BODY Y",
+            Velocity::new::<foot_per_minute>(2000.).get::<foot_per_second>(),
+        );
+    }
+
+    #[test]
+    fn armed_failure_is_not_triggered_before_its_condition() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.arm(
+                FAILURE,
+                FailureTrigger::IndicatedAirspeedAbove(Velocity::new::<knot>(100.)),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| !a.is_triggered(FAILURE)));
+        assert!(test_bed.query(|a| a.armed_failures.is_armed(FAILURE)));
+    }
+
+    #[test]
+    fn airspeed_triggered_failure_fires_once_above_airspeed_and_is_disarmed() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.arm(
+                FAILURE,
+                FailureTrigger::IndicatedAirspeedAbove(Velocity::new::<knot>(100.)),
+            )
+        });
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(120.));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.triggered_failures.len() == 1));
+        assert!(test_bed.query(|a| a.is_triggered(FAILURE)));
+        assert!(test_bed.query(|a| !a.armed_failures.is_armed(FAILURE)));
+    }
+
+    #[test]
+    fn triggered_failure_keeps_its_severity() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.armed_failures.arm(
+                FAILURE,
+                Ratio::new::<ratio>(0.4),
+                FailureTrigger::FlightPhase(FailureFlightPhase::Ground),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed
+            .query(|a| { a.triggered_failures == vec![(FAILURE, Ratio::new::<ratio>(0.4))] }));
+    }
+
+    #[test]
+    fn height_triggered_failure_fires_above_height() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.arm(
+                FAILURE,
+                FailureTrigger::HeightAboveGroundAbove(Length::new::<foot>(1000.)),
+            )
+        });
+        take_off(&mut test_bed);
+        test_bed.write_by_name("PLANE ALT ABOVE GROUND", 800.);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| !a.is_triggered(FAILURE)));
+
+        test_bed.write_by_name("PLANE ALT ABOVE GROUND", 1200.);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.is_triggered(FAILURE)));
+    }
+
+    #[test]
+    fn flight_phase_triggered_failure_fires_in_descent_only() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.arm(
+                FAILURE,
+                FailureTrigger::FlightPhase(FailureFlightPhase::Descent),
+            )
+        });
+        take_off(&mut test_bed);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| !a.is_triggered(FAILURE)));
+
+        test_bed.write_by_name("VELOCITY BODY Y", 0.);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| !a.is_triggered(FAILURE)));
+
+        test_bed.write_by_name(
+            "VELOCITY BODY Y",
+            Velocity::new::<foot_per_minute>(-1500.).get::<foot_per_second>(),
+        );
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.is_triggered(FAILURE)));
+    }
+
+    #[test]
+    fn time_after_takeoff_failure_fires_after_delay_from_liftoff() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.arm(
+                FAILURE,
+                FailureTrigger::TimeAfterTakeoff(Duration::from_secs(30)),
+            )
+        });
+        test_bed.run_iterations_with_delta(60, Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| !a.is_triggered(FAILURE)));
+
+        take_off(&mut test_bed);
+        test_bed.run_iterations_with_delta(25, Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| !a.is_triggered(FAILURE)));
+
+        test_bed.run_iterations_with_delta(10, Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.is_triggered(FAILURE)));
+    }
+
+    #[test]
+    fn time_after_takeoff_failure_does_not_fire_when_spawned_in_flight() {
+        let mut test_bed = test_bed();
+        take_off(&mut test_bed);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        test_bed.command(|a| {
+            a.arm(
+                FAILURE,
+                FailureTrigger::TimeAfterTakeoff(Duration::from_secs(1)),
+            )
+        });
+        test_bed.run_iterations_with_delta(10, Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| !a.is_triggered(FAILURE)));
+    }
+
+    #[test]
+    fn random_failure_fires_within_its_window() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.arm(
+                FAILURE,
+                FailureTrigger::RandomWithin(Duration::from_secs(60), Duration::from_secs(120)),
+            )
+        });
+        test_bed.run_iterations_with_delta(59, Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| !a.is_triggered(FAILURE)));
+
+        test_bed.run_iterations_with_delta(62, Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.is_triggered(FAILURE)));
+    }
+
+    #[test]
+    fn rearming_identical_failure_does_not_restart_its_trigger() {
+        let mut test_bed = test_bed();
+        let trigger =
+            FailureTrigger::RandomWithin(Duration::from_secs(10), Duration::from_secs(10));
+        test_bed.command(|a| a.arm(FAILURE, trigger));
+        test_bed.run_iterations_with_delta(6, Duration::from_secs(1));
+
+        test_bed.command(|a| {
+            a.armed_failures
+                .set_armed([(FAILURE, Ratio::new::<ratio>(1.), trigger)], |_| false)
+        });
+        test_bed.run_iterations_with_delta(5, Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.is_triggered(FAILURE)));
+    }
+
+    #[test]
+    fn failure_missing_from_armed_set_is_disarmed() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.arm(
+                FAILURE,
+                FailureTrigger::IndicatedAirspeedAbove(Velocity::new::<knot>(100.)),
+            )
+        });
+        test_bed.command(|a| a.armed_failures.set_armed([], |_| false));
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(120.));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| !a.is_triggered(FAILURE)));
+        assert!(test_bed.query(|a| !a.armed_failures.is_armed(FAILURE)));
+    }
+
+    #[test]
+    fn triggered_failure_is_not_armed_again_when_still_in_the_armed_set() {
+        let mut test_bed = test_bed();
+        let trigger = FailureTrigger::FlightPhase(FailureFlightPhase::Ground);
+        test_bed.command(|a| a.arm(FAILURE, trigger));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        test_bed.command(|a| {
+            let triggered_failures = a.triggered_failures.clone();
+            a.armed_failures.set_armed(
+                [(FAILURE, Ratio::new::<ratio>(1.), trigger)],
+                |failure_type| {
+                    triggered_failures
+                        .iter()
+                        .any(|(triggered, _)| *triggered == failure_type)
+                },
+            )
+        });
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.triggered_failures.len() == 1));
+        assert!(test_bed.query(|a| !a.armed_failures.is_armed(FAILURE)));
+    }
+}
@@ -4,11 +4,18 @@ use crate::air_conditioning::{
 use crate::air_conditioning::{FdacId, OcsmId};
 use crate::integrated_modular_avionics::core_processing_input_output_module::CpiomId;
 use crate::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, FireDetectionLoopID,
-    FireDetectionZone, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, BleedAirLeakZone, ElectricalBusType,
+    FireDetectionLoopID, FireDetectionZone, FlightControlSurface, GearActuatorId, HydraulicColor,
+    HydraulicLeakLocation, LgciuId, ProximityDetectorId,
 };
 use crate::simulation::SimulationElement;
-use rustc_hash::FxHashSet;
+use rustc_hash::FxHashMap;
+use uom::si::{f64::*, ratio::ratio};
+
+mod armed_failures;
+mod random_failures;
+pub use armed_failures::{ArmedFailures, FailureFlightPhase, FailureTrigger};
+pub use random_failures::{FailureReliability, InjectedFailure, RandomFailureGenerator};
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FailureType {
@@ -24,6 +31,9 @@ pub enum FailureType {
     OutflowValveFault,
     SafetyValveFault,
     RapidDecompression,
+    AvionicsBlowerFan,
+    AvionicsExtractFan,
+    AvionicsSmoke,
     Fdac(FdacId, Channel),
     Tadd(Channel),
     Vcm(VcmId, Channel),
@@ -47,17 +57,32 @@ pub enum FailureType {
     // ATA26
     SetOnFire(FireDetectionZone),
     FireDetectionLoop(FireDetectionLoopID, FireDetectionZone),
+    // ATA27
+    FlightControlServoJam(FlightControlSurface, HydraulicColor),
+    FlightControlServoRunaway(FlightControlSurface, HydraulicColor),
+    FlightControlServoFeedbackLoss(FlightControlSurface, HydraulicColor),
+    FlightControlServoOscillation(FlightControlSurface, HydraulicColor),
+    FlightControlServoInternalLeak(FlightControlSurface, HydraulicColor),
+    // ATA28
+    TrimTankTransferValveStuckClosed,
     // ATA29
     ReservoirLeak(HydraulicColor),
     ReservoirAirLeak(HydraulicColor),
     ReservoirReturnLeak(HydraulicColor),
     EnginePumpOverheat(AirbusEngineDrivenPumpId),
     ElecPumpOverheat(AirbusElectricPumpId),
+    HydraulicExternalLeak(HydraulicColor, HydraulicLeakLocation),
+    EnginePumpWear(AirbusEngineDrivenPumpId),
+    ElecPumpWear(AirbusElectricPumpId),
+    PowerTransferUnitShaft,
+    // ATA31
+    DisplayUnit(usize),
     // ATA32
     LgciuPowerSupply(LgciuId),
     LgciuInternalError(LgciuId),
     GearProxSensorDamage(ProximityDetectorId),
     GearActuatorJammed(GearActuatorId),
+    GearActuatorInternalLeak(GearActuatorId),
     BrakeHydraulicLeak(HydraulicColor),
     BrakeAccumulatorGasLeak,
     // ATA34
@@ -65,17 +90,47 @@ pub enum FailureType {
     RadioAntennaInterrupted(usize),
     RadioAntennaDirectCoupling(usize),
     EnhancedGroundProximityWarningSystemComputer,
+    MultiModeReceiver(usize),
+    IlsLocalizerSignalLoss(usize),
+    IlsGlideslopeSignalLoss(usize),
+    IlsLocalizerBeamBend(usize),
+    IlsGlideslopeBeamBend(usize),
+    TrafficCollisionAvoidanceSystem,
+    InertialReferenceHeadingDrift(usize),
+    // ATA36
+    BleedAirDuctLeak(BleedAirLeakZone),
+    BleedLeakDetectionLoop(BleedAirLeakZone),
+    PressureRegulatingValveStuckOpen(usize),
+    PressureRegulatingValveStuckClosed(usize),
+    HighPressureValveStuckOpen(usize),
+    HighPressureValveStuckClosed(usize),
+    OverpressureValveStuckOpen(usize),
+    OverpressureValveStuckClosed(usize),
+    PrecoolerClogged(usize),
+    // ATA49
+    ApuNoLightOff,
+    ApuHungStart,
+    ApuOverspeed,
+    ApuEgtOvertemperature,
+    ApuOilPump,
+    ApuOilCooler,
+    ApuFuelPressureLoss,
+    // ATA78
+    ReverserInadvertentDeployment(usize),
+    ReverserJammed(usize),
 }
 
 pub struct Failure {
     failure_type: FailureType,
     is_active: bool,
+    severity: Ratio,
 }
 impl Failure {
     pub fn new(failure_type: FailureType) -> Self {
         Self {
             failure_type,
             is_active: false,
+            severity: Ratio::default(),
         }
     }
 
@@ -83,13 +138,24 @@ impl Failure {
         self.is_active
     }
 
+    /// Severity of the failure, from 0 when inactive to 1 for the complete failure.
+    /// Failures activated without a severity are complete failures.
+    pub fn severity(&self) -> Ratio {
+        self.severity
+    }
+
     pub fn failure_type(&self) -> FailureType {
         self.failure_type
     }
 }
 impl SimulationElement for Failure {
-    fn receive_failure(&mut self, active_failures: &FxHashSet<FailureType>) {
-        self.is_active = active_failures.contains(&self.failure_type);
+    fn receive_failure(&mut self, active_failures: &FxHashMap<FailureType, Ratio>) {
+        self.severity = active_failures
+            .get(&self.failure_type)
+            .map_or(Ratio::default(), |severity| {
+                severity.max(Ratio::default()).min(Ratio::new::<ratio>(1.))
+            });
+        self.is_active = self.severity > Ratio::default();
     }
 }
 
@@ -115,6 +181,40 @@ mod tests {
         assert!(test_bed.query_element(|el| el.is_active()));
     }
 
+    #[test]
+    fn failure_indicated_without_severity_is_complete() {
+        let mut test_bed =
+            SimulationTestBed::from(Failure::new(FailureType::TransformerRectifier(1)));
+        test_bed.fail(FailureType::TransformerRectifier(1));
+        test_bed.run();
+
+        assert!(test_bed.query_element(|el| el.severity() == Ratio::new::<ratio>(1.)));
+    }
+
+    #[test]
+    fn failure_indicated_with_severity_reports_it() {
+        let mut test_bed =
+            SimulationTestBed::from(Failure::new(FailureType::TransformerRectifier(1)));
+        test_bed.fail_with_severity(
+            FailureType::TransformerRectifier(1),
+            Ratio::new::<ratio>(0.3),
+        );
+        test_bed.run();
+
+        assert!(test_bed.query_element(|el| el.is_active()));
+        assert!(test_bed.query_element(|el| el.severity() == Ratio::new::<ratio>(0.3)));
+    }
+
+    #[test]
+    fn failure_indicated_with_zero_severity_is_not_active() {
+        let mut test_bed =
+            SimulationTestBed::from(Failure::new(FailureType::TransformerRectifier(1)));
+        test_bed.fail_with_severity(FailureType::TransformerRectifier(1), Ratio::default());
+        test_bed.run();
+
+        assert!(test_bed.query_element(|el| !el.is_active()));
+    }
+
     #[test]
     fn does_not_become_failed_when_non_matching_failure_indicated() {
         let mut test_bed =
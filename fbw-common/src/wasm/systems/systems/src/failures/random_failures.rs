@@ -0,0 +1,371 @@
+use std::time::Duration;
+
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use uom::si::{f64::*, ratio::ratio, time::hour};
+
+use crate::simulation::UpdateContext;
+
+use super::{ArmedFailures, FailureTrigger, FailureType};
+
+/// Reliability figure of a component from which its failures are randomly generated.
+#[derive(Clone, Copy, PartialEq)]
+pub struct FailureReliability {
+    failure_type: FailureType,
+    mean_time_between_failures: Time,
+    exposure: Option<FailureTrigger>,
+}
+impl FailureReliability {
+    pub fn new(failure_type: FailureType, mean_time_between_failures: Time) -> Self {
+        Self {
+            failure_type,
+            mean_time_between_failures,
+            exposure: None,
+        }
+    }
+
+    /// A latent failure stays dormant once it occurred, and only becomes active
+    /// when the system is exercised as given by the exposure trigger.
+    pub fn latent(
+        failure_type: FailureType,
+        mean_time_between_failures: Time,
+        exposure: FailureTrigger,
+    ) -> Self {
+        Self {
+            failure_type,
+            mean_time_between_failures,
+            exposure: Some(exposure),
+        }
+    }
+
+    /// Probability of the failure occurring within the given time,
+    /// considering failures occur at a constant rate.
+    fn probability_within(&self, time: Time, rate_multiplier: f64) -> f64 {
+        let mean_time_between_failures = self.mean_time_between_failures.get::<hour>();
+        if mean_time_between_failures <= 0. {
+            return 0.;
+        }
+
+        1. - (-time.get::<hour>() * rate_multiplier / mean_time_between_failures).exp()
+    }
+}
+
+/// Record of a failure injected by the [RandomFailureGenerator].
+#[derive(Clone, Copy, PartialEq)]
+pub struct InjectedFailure {
+    failure_type: FailureType,
+    injected_at: Duration,
+    revealed_at: Option<Duration>,
+}
+impl InjectedFailure {
+    pub fn failure_type(&self) -> FailureType {
+        self.failure_type
+    }
+
+    /// Time since the generator started at which the failure occurred.
+    pub fn injected_at(&self) -> Duration {
+        self.injected_at
+    }
+
+    /// Time since the generator started at which the failure became active,
+    /// or `None` while the failure is dormant.
+    pub fn revealed_at(&self) -> Option<Duration> {
+        self.revealed_at
+    }
+
+    pub fn is_dormant(&self) -> bool {
+        self.revealed_at.is_none()
+    }
+}
+
+/// Randomly injects failures in flight based on the mean time between failures of each component.
+/// The rate multiplier scales all failure rates, such that failures happen within a single flight.
+pub struct RandomFailureGenerator {
+    rng: SmallRng,
+    rate_multiplier: f64,
+    reliabilities: Vec<FailureReliability>,
+
+    dormant_failures: ArmedFailures,
+    injected_failures: Vec<InjectedFailure>,
+    elapsed: Duration,
+}
+impl RandomFailureGenerator {
+    /// Creates a generator. Giving a seed makes the injected failures reproducible.
+    pub fn new(
+        reliabilities: Vec<FailureReliability>,
+        rate_multiplier: f64,
+        seed: Option<u64>,
+    ) -> Self {
+        Self {
+            rng: match seed {
+                Some(seed) => SmallRng::seed_from_u64(seed),
+                None => SmallRng::from_os_rng(),
+            },
+            rate_multiplier: rate_multiplier.max(0.),
+            reliabilities,
+
+            dormant_failures: ArmedFailures::default(),
+            injected_failures: Vec::new(),
+            elapsed: Duration::default(),
+        }
+    }
+
+    /// Returns the failures becoming active during this update.
+    pub fn update(&mut self, context: &UpdateContext) -> Vec<(FailureType, Ratio)> {
+        self.elapsed += context.delta();
+
+        let mut activated_failures = if context.is_on_ground() {
+            Vec::new()
+        } else {
+            self.inject_failures(context.delta_as_time())
+        };
+
+        for (failure_type, severity) in self.dormant_failures.update(context) {
+            if let Some(injected) = self
+                .injected_failures
+                .iter_mut()
+                .find(|injected| injected.failure_type == failure_type)
+            {
+                injected.revealed_at = Some(self.elapsed);
+            }
+            activated_failures.push((failure_type, severity));
+        }
+
+        activated_failures
+    }
+
+    /// Returns the injected failures which are not latent, and thus immediately active.
+    fn inject_failures(&mut self, delta: Time) -> Vec<(FailureType, Ratio)> {
+        let mut active_failures = Vec::new();
+
+        for reliability in &self.reliabilities {
+            if self
+                .injected_failures
+                .iter()
+                .any(|injected| injected.failure_type == reliability.failure_type)
+            {
+                continue;
+            }
+
+            if self.rng.random::<f64>()
+                < reliability.probability_within(delta, self.rate_multiplier)
+            {
+                let revealed_at = if let Some(exposure) = reliability.exposure {
+                    self.dormant_failures.arm(
+                        reliability.failure_type,
+                        Ratio::new::<ratio>(1.),
+                        exposure,
+                    );
+                    None
+                } else {
+                    active_failures.push((reliability.failure_type, Ratio::new::<ratio>(1.)));
+                    Some(self.elapsed)
+                };
+
+                self.injected_failures.push(InjectedFailure {
+                    failure_type: reliability.failure_type,
+                    injected_at: self.elapsed,
+                    revealed_at,
+                });
+            }
+        }
+
+        active_failures
+    }
+
+    pub fn injected_failures(&self) -> &[InjectedFailure] {
+        &self.injected_failures
+    }
+
+    pub fn dormant_failures(&self) -> impl Iterator<Item = &InjectedFailure> {
+        self.injected_failures
+            .iter()
+            .filter(|injected| injected.is_dormant())
+    }
+}
+impl Default for RandomFailureGenerator {
+    fn default() -> Self {
+        Self::new(Vec::new(), 1., None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::failures::FailureFlightPhase;
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed, WriteByName},
+        Aircraft, InitContext, SimulationElement,
+    };
+    use uom::si::velocity::{foot_per_minute, foot_per_second};
+
+    struct TestAircraft {
+        generator: RandomFailureGenerator,
+        activated_failures: Vec<FailureType>,
+    }
+    impl TestAircraft {
+        fn new(_: &mut InitContext) -> Self {
+            Self {
+                generator: RandomFailureGenerator::default(),
+                activated_failures: Vec::new(),
+            }
+        }
+
+        fn is_activated(&self, failure_type: FailureType) -> bool {
+            self.activated_failures.contains(&failure_type)
+        }
+
+        fn injected_failure_types(&self) -> Vec<FailureType> {
+            self.generator
+                .injected_failures()
+                .iter()
+                .map(|injected| injected.failure_type())
+                .collect()
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.activated_failures.extend(
+                self.generator
+                    .update(context)
+                    .into_iter()
+                    .map(|(failure_type, _)| failure_type),
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {}
+
+    const FAILURE: FailureType = FailureType::TransformerRectifier(1);
+    const OTHER_FAILURE: FailureType = FailureType::TransformerRectifier(2);
+
+    fn test_bed(
+        reliabilities: Vec<FailureReliability>,
+        seed: u64,
+    ) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed
+            .command(|a| a.generator = RandomFailureGenerator::new(reliabilities, 1., Some(seed)));
+        test_bed.set_on_ground(true);
+        test_bed.write_by_name("VELOCITY BODY Y", 0.);
+
+        test_bed
+    }
+
+    fn in_flight(test_bed: &mut SimulationTestBed<TestAircraft>) {
+        test_bed.set_on_ground(false);
+        test_bed.write_by_name(
+            "VELOCITY BODY Y",
+            Velocity::new::<foot_per_minute>(2000.).get::<foot_per_second>(),
+        );
+    }
+
+    /// Failing on average every 5 seconds
+    fn unreliable(failure_type: FailureType) -> FailureReliability {
+        FailureReliability::new(failure_type, Time::new::<hour>(5. / 3600.))
+    }
+
+    #[test]
+    fn no_failures_are_injected_on_ground() {
+        let mut test_bed = test_bed(vec![unreliable(FAILURE)], 1);
+        test_bed.run_iterations_with_delta(120, Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.generator.injected_failures().is_empty()));
+        assert!(test_bed.query(|a| !a.is_activated(FAILURE)));
+    }
+
+    #[test]
+    fn unreliable_component_fails_in_flight() {
+        let mut test_bed = test_bed(vec![unreliable(FAILURE)], 1);
+        in_flight(&mut test_bed);
+        test_bed.run_iterations_with_delta(120, Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.is_activated(FAILURE)));
+        assert!(test_bed.query(|a| a.injected_failure_types() == vec![FAILURE]));
+    }
+
+    #[test]
+    fn reliable_component_does_not_fail_in_flight() {
+        let mut test_bed = test_bed(
+            vec![FailureReliability::new(
+                FAILURE,
+                Time::new::<hour>(1_000_000.),
+            )],
+            1,
+        );
+        in_flight(&mut test_bed);
+        test_bed.run_iterations_with_delta(120, Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| !a.is_activated(FAILURE)));
+    }
+
+    #[test]
+    fn rate_multiplier_of_zero_disables_injection() {
+        let mut test_bed = test_bed(Vec::new(), 1);
+        test_bed.command(|a| {
+            a.generator = RandomFailureGenerator::new(vec![unreliable(FAILURE)], 0., Some(1))
+        });
+        in_flight(&mut test_bed);
+        test_bed.run_iterations_with_delta(120, Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.generator.injected_failures().is_empty()));
+    }
+
+    #[test]
+    fn failure_is_injected_only_once() {
+        let mut test_bed = test_bed(vec![unreliable(FAILURE)], 1);
+        in_flight(&mut test_bed);
+        test_bed.run_iterations_with_delta(600, Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.activated_failures == vec![FAILURE]));
+    }
+
+    #[test]
+    fn same_seed_injects_the_same_failures_at_the_same_time() {
+        let injections = |seed| {
+            let mut test_bed = test_bed(vec![unreliable(FAILURE), unreliable(OTHER_FAILURE)], seed);
+            in_flight(&mut test_bed);
+            test_bed.run_iterations_with_delta(30, Duration::from_secs(1));
+
+            test_bed.query(|a| {
+                a.generator
+                    .injected_failures()
+                    .iter()
+                    .map(|injected| injected.injected_at())
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        assert!(!injections(42).is_empty());
+        assert_eq!(injections(42), injections(42));
+    }
+
+    #[test]
+    fn latent_failure_stays_dormant_until_exercised() {
+        let mut test_bed = test_bed(
+            vec![FailureReliability::latent(
+                FAILURE,
+                Time::new::<hour>(5. / 3600.),
+                FailureTrigger::FlightPhase(FailureFlightPhase::Descent),
+            )],
+            1,
+        );
+        in_flight(&mut test_bed);
+        test_bed.run_iterations_with_delta(120, Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.generator.dormant_failures().count() == 1));
+        assert!(test_bed.query(|a| !a.is_activated(FAILURE)));
+
+        test_bed.write_by_name(
+            "VELOCITY BODY Y",
+            Velocity::new::<foot_per_minute>(-1500.).get::<foot_per_second>(),
+        );
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.is_activated(FAILURE)));
+        assert!(test_bed.query(|a| a.generator.dormant_failures().count() == 0));
+        assert!(test_bed.query(|a| {
+            let injected = a.generator.injected_failures()[0];
+            injected.revealed_at() > Some(injected.injected_at())
+        }));
+    }
+}
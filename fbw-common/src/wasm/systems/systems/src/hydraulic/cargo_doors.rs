@@ -232,7 +232,7 @@ impl CargoDoor {
         }
     }
 
-    fn position(&self) -> Ratio {
+    pub fn position(&self) -> Ratio {
         self.position
     }
 
@@ -254,6 +254,8 @@ impl CargoDoor {
     ) {
         self.aerodynamic_model
             .update_body(context, self.hydraulic_assembly.body());
+        self.hydraulic_assembly
+            .update_fluid_viscosity([current_pressure.fluid_viscosity()]);
         self.hydraulic_assembly.update(
             context,
             std::slice::from_ref(cargo_door_controller),
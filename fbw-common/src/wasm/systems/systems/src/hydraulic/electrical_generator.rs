@@ -360,10 +360,12 @@ impl EmergencyGeneratorPower for TestGenerator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::hydraulic::Fluid;
     use crate::shared::update_iterator::MaxStepLoop;
     use crate::simulation::test::{SimulationTestBed, TestBed};
     use crate::simulation::{Aircraft, SimulationElement, SimulationElementVisitor};
     use std::time::Duration;
+    use uom::si::kinematic_viscosity::centistokes;
 
     struct TestEmergencyState {
         is_emergency: bool,
@@ -431,6 +433,10 @@ mod tests {
         fn is_pressure_switch_pressurised(&self) -> bool {
             self.pressure.get::<psi>() > 1700.
         }
+
+        fn fluid_viscosity(&self) -> KinematicViscosity {
+            KinematicViscosity::new::<centistokes>(Fluid::NOMINAL_VISCOSITY_CENTISTOKES)
+        }
     }
 
     struct TestLgciuSensors {
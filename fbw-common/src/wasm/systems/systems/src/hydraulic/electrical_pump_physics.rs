@@ -304,8 +304,10 @@ mod tests {
     use crate::shared::{update_iterator::MaxStepLoop, PotentialOrigin};
     use crate::simulation::{Aircraft, SimulationElement, SimulationElementVisitor, UpdateContext};
 
+    use crate::hydraulic::Fluid;
     use crate::simulation::test::{SimulationTestBed, TestBed};
     use std::time::Duration;
+    use uom::si::kinematic_viscosity::centistokes;
     use uom::si::{pressure::psi, volume::gallon};
 
     #[derive(Default)]
@@ -333,6 +335,10 @@ mod tests {
         fn is_pressure_switch_pressurised(&self) -> bool {
             self.pressure.get::<psi>() > 1700.
         }
+
+        fn fluid_viscosity(&self) -> KinematicViscosity {
+            KinematicViscosity::new::<centistokes>(Fluid::NOMINAL_VISCOSITY_CENTISTOKES)
+        }
     }
 
     struct TestAircraft {
@@ -592,7 +592,9 @@ impl PositionPickoffUnit for FlapSlatAssembly {
 mod tests {
     use super::*;
 
+    use crate::hydraulic::Fluid;
     use std::time::Duration;
+    use uom::si::kinematic_viscosity::centistokes;
     use uom::si::volume::cubic_inch;
     use uom::si::volume_rate::gallon_per_minute;
     use uom::si::{angle::degree, pressure::psi};
@@ -633,6 +635,10 @@ mod tests {
         fn is_pressure_switch_pressurised(&self) -> bool {
             self.pressure.get::<psi>() > 1700.
         }
+
+        fn fluid_viscosity(&self) -> KinematicViscosity {
+            KinematicViscosity::new::<centistokes>(Fluid::NOMINAL_VISCOSITY_CENTISTOKES)
+        }
     }
 
     #[derive(Default)]
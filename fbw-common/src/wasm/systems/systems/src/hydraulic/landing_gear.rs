@@ -187,24 +187,28 @@ impl HydraulicGearSystem {
         );
 
         let current_pressure = self.hydraulic_supply.gear_system_manifold_pressure();
+        let fluid_viscosity = main_hydraulic_circuit.fluid_viscosity();
 
         self.nose_door_assembly.update(
             context,
             lgciu_controller,
             valves_controller,
             current_pressure,
+            fluid_viscosity,
         );
         self.left_door_assembly.update(
             context,
             lgciu_controller,
             valves_controller,
             current_pressure,
+            fluid_viscosity,
         );
         self.right_door_assembly.update(
             context,
             lgciu_controller,
             valves_controller,
             current_pressure,
+            fluid_viscosity,
         );
 
         self.nose_gear_assembly.update(
@@ -212,18 +216,21 @@ impl HydraulicGearSystem {
             lgciu_controller,
             valves_controller,
             current_pressure,
+            fluid_viscosity,
         );
         self.left_gear_assembly.update(
             context,
             lgciu_controller,
             valves_controller,
             current_pressure,
+            fluid_viscosity,
         );
         self.right_gear_assembly.update(
             context,
             lgciu_controller,
             valves_controller,
             current_pressure,
+            fluid_viscosity,
         );
     }
 
@@ -513,6 +520,7 @@ impl GearSystemComponentAssembly {
         gear_system_controller: &impl LgciuGearControl,
         valves_controller: &impl GearSystemController,
         current_pressure: Pressure,
+        fluid_viscosity: KinematicViscosity,
     ) {
         self.update_proximity_detectors();
 
@@ -521,6 +529,9 @@ impl GearSystemComponentAssembly {
         self.aerodynamic_model
             .update_body(context, self.hydraulic_assembly.body());
 
+        self.hydraulic_assembly
+            .update_fluid_viscosity([fluid_viscosity]);
+
         self.hydraulic_assembly.update(
             context,
             std::slice::from_ref(&self.hydraulic_controller),
@@ -629,6 +640,7 @@ struct GearSystemComponentHydraulicController {
     actual_position: Ratio,
 
     jammed_actuator_failure: Failure,
+    internal_leak_failure: Failure,
     jamming_position: Ratio,
     jamming_is_effective: bool,
 
@@ -644,6 +656,7 @@ impl GearSystemComponentHydraulicController {
             lock_position: Ratio::new::<ratio>(0.),
             actual_position: Ratio::new::<ratio>(0.5),
             jammed_actuator_failure: Failure::new(FailureType::GearActuatorJammed(id)),
+            internal_leak_failure: Failure::new(FailureType::GearActuatorInternalLeak(id)),
             jamming_position: Ratio::new::<ratio>(random_from_range(0., 1.)),
             jamming_is_effective: false,
             soft_downlock_is_active: false,
@@ -754,12 +767,17 @@ impl HydraulicAssemblyController for GearSystemComponentHydraulicController {
             self.lock_position
         }
     }
+
+    fn has_internal_leakage(&self) -> bool {
+        self.internal_leak_failure.is_active()
+    }
 }
 impl HydraulicLocking for GearSystemComponentHydraulicController {}
 impl ElectroHydrostaticPowered for GearSystemComponentHydraulicController {}
 impl SimulationElement for GearSystemComponentHydraulicController {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.jammed_actuator_failure.accept(visitor);
+        self.internal_leak_failure.accept(visitor);
 
         visitor.visit(self);
     }
@@ -844,13 +862,14 @@ mod tests {
 
     use std::time::Duration;
     use uom::si::{
-        angle::degree, electric_potential::volt, length::meter, mass::kilogram,
-        volume_rate::gallon_per_second,
+        angle::degree, electric_potential::volt, kinematic_viscosity::centistokes, length::meter,
+        mass::kilogram, volume_rate::gallon_per_second,
     };
 
     use crate::hydraulic::linear_actuator::{
         BoundedLinearLength, LinearActuatedRigidBodyOnHingeAxis, LinearActuator,
     };
+    use crate::hydraulic::Fluid;
     use crate::shared::{update_iterator::MaxStepLoop, ElectricalBusType, PotentialOrigin};
 
     use crate::simulation::test::{SimulationTestBed, TestBed};
@@ -1117,6 +1136,7 @@ mod tests {
                 &self.component_controller,
                 &TestGearValvesController::with_safety_and_shutoff_opened(),
                 self.pressure,
+                KinematicViscosity::new::<centistokes>(Fluid::NOMINAL_VISCOSITY_CENTISTOKES),
             );
 
             self.gear_assembly.update(
@@ -1124,6 +1144,7 @@ mod tests {
                 &self.component_controller,
                 &TestGearValvesController::with_safety_and_shutoff_opened(),
                 self.pressure,
+                KinematicViscosity::new::<centistokes>(Fluid::NOMINAL_VISCOSITY_CENTISTOKES),
             );
 
             println!(
@@ -7,6 +7,7 @@ use uom::si::{
     area::square_meter,
     f64::*,
     force::newton,
+    kinematic_viscosity::centistokes,
     length::meter,
     mass::kilogram,
     power::watt,
@@ -19,10 +20,11 @@ use uom::si::{
 };
 
 use crate::{
+    failures::{Failure, FailureType},
     shared::{
         interpolation, low_pass_filter::LowPassFilter, pid::PidController,
         random_from_normal_distribution, random_from_range, ConsumePower, ElectricalBusType,
-        ElectricalBuses,
+        ElectricalBuses, FlightControlSurface, HydraulicColor,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -415,6 +417,8 @@ struct CoreHydraulicForce {
     has_flow_restriction: bool,
     max_working_pressure: Pressure,
 
+    viscosity_flow_factor: f64,
+
     locks_position_in_closed_mode: bool,
     is_soft_locked: bool,
     soft_lock_velocity: (AngularVelocity, AngularVelocity),
@@ -434,6 +438,10 @@ impl CoreHydraulicForce {
     //   ie: 500 value for a max pressure of 3000psi will start reducing flow when below 2500 psi
     const FLOW_REDUCTION_THRESHOLD_BELOW_MAX_PRESS_PSI: f64 = 500.;
 
+    // Above this viscosity, servo valve and lines restrictions start reducing the achievable flow
+    const MAX_VISCOSITY_FOR_FULL_FLOW_CENTISTOKES: f64 = 50.;
+    const MIN_VISCOSITY_FLOW_FACTOR: f64 = 0.25;
+
     fn new(
         context: &mut InitContext,
         init_position: Ratio,
@@ -515,6 +523,8 @@ impl CoreHydraulicForce {
             has_flow_restriction,
             max_working_pressure,
 
+            viscosity_flow_factor: 1.,
+
             locks_position_in_closed_mode,
             is_soft_locked: locks_position_in_closed_mode,
             soft_lock_velocity: soft_lock_velocity.unwrap_or_default(),
@@ -823,6 +833,13 @@ impl CoreHydraulicForce {
         }
     }
 
+    fn update_fluid_viscosity(&mut self, fluid_viscosity: KinematicViscosity) {
+        self.viscosity_flow_factor = (Self::MAX_VISCOSITY_FOR_FULL_FLOW_CENTISTOKES
+            / fluid_viscosity.get::<centistokes>().max(1.))
+        .sqrt()
+        .clamp(Self::MIN_VISCOSITY_FLOW_FACTOR, 1.);
+    }
+
     fn force_position_control(
         &mut self,
         context: &UpdateContext,
@@ -849,7 +866,8 @@ impl CoreHydraulicForce {
             1.
         };
 
-        let pressure_corrected_openloop_target = open_loop_flow_target * pressure_correction_factor;
+        let pressure_corrected_openloop_target =
+            open_loop_flow_target * pressure_correction_factor * self.viscosity_flow_factor;
         self.pid_controller
             .change_setpoint(pressure_corrected_openloop_target.get::<gallon_per_second>());
 
@@ -968,8 +986,17 @@ pub struct LinearActuator {
     core_hydraulics: CoreHydraulicForce,
 
     electro_hydrostatic_backup: Option<ElectroHydrostaticBackup>,
+
+    max_working_pressure: Pressure,
+    seal_wear: Ratio,
+    internal_leakage_flow: VolumeRate,
 }
 impl LinearActuator {
+    // Worn piston seals let fluid bypass from the pressure side to the return side
+    const SEAL_WEAR_RATE_PER_SECOND: f64 = 1. / 120.;
+    const MAX_INTERNAL_LEAKAGE_GAL_PER_MIN: f64 = 1.;
+    const MAX_WEAR_PRESSURE_LOSS_RATIO: f64 = 0.5;
+
     pub fn new(
         context: &mut InitContext,
         bounded_linear_length: &impl BoundedLinearLength,
@@ -1086,6 +1113,10 @@ impl LinearActuator {
                 max_working_pressure,
             ),
             electro_hydrostatic_backup,
+
+            max_working_pressure,
+            seal_wear: Ratio::default(),
+            internal_leakage_flow: VolumeRate::default(),
         }
     }
 
@@ -1122,10 +1153,18 @@ impl LinearActuator {
             }
         } else if can_move_using_aircraft_hydraulic_pressure {
             current_input_pressure
+                * (1. - Self::MAX_WEAR_PRESSURE_LOSS_RATIO * self.seal_wear.get::<ratio>())
         } else {
             Pressure::default()
         };
 
+        self.update_internal_leakage(
+            context,
+            controller,
+            current_input_pressure,
+            can_move_using_aircraft_hydraulic_pressure,
+        );
+
         self.core_hydraulics.update_force(
             context,
             self.requested_position,
@@ -1138,6 +1177,39 @@ impl LinearActuator {
         connected_body.apply_control_arm_force(self.core_hydraulics.force());
     }
 
+    fn update_fluid_viscosity(&mut self, fluid_viscosity: KinematicViscosity) {
+        self.core_hydraulics.update_fluid_viscosity(fluid_viscosity);
+    }
+
+    fn update_internal_leakage(
+        &mut self,
+        context: &UpdateContext,
+        controller: &impl HydraulicAssemblyController,
+        current_input_pressure: Pressure,
+        can_move_using_aircraft_hydraulic_pressure: bool,
+    ) {
+        self.seal_wear = if controller.has_internal_leakage() {
+            (self.seal_wear
+                + Ratio::new::<ratio>(
+                    Self::SEAL_WEAR_RATE_PER_SECOND * context.delta_as_secs_f64(),
+                ))
+            .min(Ratio::new::<ratio>(1.))
+        } else {
+            Ratio::default()
+        };
+
+        self.internal_leakage_flow = if can_move_using_aircraft_hydraulic_pressure {
+            VolumeRate::new::<gallon_per_minute>(
+                Self::MAX_INTERNAL_LEAKAGE_GAL_PER_MIN * self.number_of_actuators as f64,
+            ) * self.seal_wear.get::<ratio>()
+                * (current_input_pressure / self.max_working_pressure)
+                    .get::<ratio>()
+                    .clamp(0., 1.)
+        } else {
+            VolumeRate::default()
+        };
+    }
+
     fn update_after_rigid_body(
         &mut self,
         context: &UpdateContext,
@@ -1190,8 +1262,10 @@ impl LinearActuator {
             //   meaning actuator takes fluid in the return circuit to be able to move.
             // This is a shortcut as it shouldn't directly take from reservoir but from return circuit
             if self.core_hydraulics.mode() == LinearActuatorMode::PositionControl {
-                self.total_volume_to_actuator += volume_to_actuator;
-                self.total_volume_to_reservoir += volume_to_reservoir;
+                let internal_leakage = self.internal_leakage_flow * context.delta_as_time();
+
+                self.total_volume_to_actuator += volume_to_actuator + internal_leakage;
+                self.total_volume_to_reservoir += volume_to_reservoir + internal_leakage;
             } else {
                 self.total_volume_to_reservoir += volume_to_reservoir - volume_to_actuator;
             }
@@ -1219,6 +1293,10 @@ impl LinearActuator {
         self.signed_flow
     }
 
+    pub fn internal_leakage_flow(&self) -> VolumeRate {
+        self.internal_leakage_flow
+    }
+
     fn pressure(&self) -> Pressure {
         let area = if self.speed > Velocity::new::<meter_per_second>(0.) {
             self.bore_side_area
@@ -1292,6 +1370,192 @@ impl Debug for LinearActuator {
     }
 }
 
+/// Failures of a flight control servo actuator, applied on top of the demand of its controller.
+/// -Jam: servo seizes mechanically and holds the surface where it is whatever adjacent actuators do
+/// -Runaway: servo valve drives the actuator to one of its stops
+/// -Feedback loss: without LVDT the servo loop is open so the actuator reverts to damping
+/// -Oscillation: an oscillatory signal is added to the position demand
+///
+/// Failure is detected by monitoring position error against demand, so that flight control
+/// computers can passivate the failed servo and hand over control to the adjacent one.
+pub struct FlightControlServoFailures {
+    jam_failure: Failure,
+    runaway_failure: Failure,
+    feedback_loss_failure: Failure,
+    oscillation_failure: Failure,
+    internal_leak_failure: Failure,
+
+    jam_position: Option<Ratio>,
+    runaway_position: Ratio,
+    oscillation_duration: Duration,
+
+    position_error_duration: Duration,
+    is_failure_detected: bool,
+}
+impl FlightControlServoFailures {
+    const OSCILLATION_AMPLITUDE: f64 = 0.05;
+    const OSCILLATION_FREQUENCY_HZ: f64 = 2.;
+    const OSCILLATION_DETECTION_TIME: Duration = Duration::from_millis(1500);
+
+    const POSITION_ERROR_THRESHOLD: f64 = 0.1;
+    const POSITION_ERROR_CONFIRMATION_TIME: Duration = Duration::from_millis(500);
+
+    pub fn new(surface: FlightControlSurface, circuit: HydraulicColor) -> Self {
+        Self {
+            jam_failure: Failure::new(FailureType::FlightControlServoJam(surface, circuit)),
+            runaway_failure: Failure::new(FailureType::FlightControlServoRunaway(surface, circuit)),
+            feedback_loss_failure: Failure::new(FailureType::FlightControlServoFeedbackLoss(
+                surface, circuit,
+            )),
+            oscillation_failure: Failure::new(FailureType::FlightControlServoOscillation(
+                surface, circuit,
+            )),
+            internal_leak_failure: Failure::new(FailureType::FlightControlServoInternalLeak(
+                surface, circuit,
+            )),
+
+            jam_position: None,
+            runaway_position: Self::random_runaway_position(),
+            oscillation_duration: Duration::default(),
+
+            position_error_duration: Duration::default(),
+            is_failure_detected: false,
+        }
+    }
+
+    /// Positions are normalized surface positions, commanded ones being the demand before failures
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        commanded_mode: LinearActuatorMode,
+        commanded_position: Ratio,
+        surface_position: Ratio,
+    ) {
+        if self.jam_failure.is_active() {
+            self.jam_position.get_or_insert(surface_position);
+        } else {
+            self.jam_position = None;
+        }
+
+        if !self.runaway_failure.is_active() {
+            // Taking a new random stop when failure is switched off so next runaway can go either way
+            self.runaway_position = Self::random_runaway_position();
+        }
+
+        if self.oscillation_failure.is_active() {
+            self.oscillation_duration += context.delta();
+        } else {
+            self.oscillation_duration = Duration::default();
+        }
+
+        self.update_monitoring(
+            context,
+            commanded_mode,
+            commanded_position,
+            surface_position,
+        );
+    }
+
+    fn update_monitoring(
+        &mut self,
+        context: &UpdateContext,
+        commanded_mode: LinearActuatorMode,
+        commanded_position: Ratio,
+        surface_position: Ratio,
+    ) {
+        if !self.is_any_failure_active() {
+            self.position_error_duration = Duration::default();
+            self.is_failure_detected = false;
+            return;
+        }
+
+        if commanded_mode == LinearActuatorMode::PositionControl
+            && (commanded_position - surface_position).abs().get::<ratio>()
+                > Self::POSITION_ERROR_THRESHOLD
+        {
+            self.position_error_duration += context.delta();
+        } else {
+            self.position_error_duration = Duration::default();
+        }
+
+        // Detection is latched until failures are cleared, as computer has passivated the servo
+        self.is_failure_detected = self.is_failure_detected
+            || self.feedback_loss_failure.is_active()
+            || self.position_error_duration > Self::POSITION_ERROR_CONFIRMATION_TIME
+            || self.oscillation_duration > Self::OSCILLATION_DETECTION_TIME;
+    }
+
+    fn is_any_failure_active(&self) -> bool {
+        self.jam_failure.is_active()
+            || self.runaway_failure.is_active()
+            || self.feedback_loss_failure.is_active()
+            || self.oscillation_failure.is_active()
+            || self.internal_leak_failure.is_active()
+    }
+
+    fn random_runaway_position() -> Ratio {
+        if random_from_range(0., 1.) < 0.5 {
+            Ratio::new::<ratio>(0.)
+        } else {
+            Ratio::new::<ratio>(1.)
+        }
+    }
+
+    pub fn mode(&self, commanded_mode: LinearActuatorMode) -> LinearActuatorMode {
+        if self.feedback_loss_failure.is_active()
+            && commanded_mode == LinearActuatorMode::PositionControl
+        {
+            LinearActuatorMode::ActiveDamping
+        } else {
+            commanded_mode
+        }
+    }
+
+    pub fn position(&self, commanded_position: Ratio) -> Ratio {
+        if self.runaway_failure.is_active() {
+            self.runaway_position
+        } else if self.oscillation_failure.is_active() {
+            let oscillation = Self::OSCILLATION_AMPLITUDE
+                * (2.
+                    * std::f64::consts::PI
+                    * Self::OSCILLATION_FREQUENCY_HZ
+                    * self.oscillation_duration.as_secs_f64())
+                .sin();
+
+            Ratio::new::<ratio>((commanded_position.get::<ratio>() + oscillation).clamp(0., 1.))
+        } else {
+            commanded_position
+        }
+    }
+
+    pub fn is_jammed(&self) -> bool {
+        self.jam_position.is_some()
+    }
+
+    pub fn jam_position(&self) -> Ratio {
+        self.jam_position.unwrap_or_default()
+    }
+
+    pub fn is_failure_detected(&self) -> bool {
+        self.is_failure_detected
+    }
+
+    pub fn has_internal_leakage(&self) -> bool {
+        self.internal_leak_failure.is_active()
+    }
+}
+impl SimulationElement for FlightControlServoFailures {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.jam_failure.accept(visitor);
+        self.runaway_failure.accept(visitor);
+        self.feedback_loss_failure.accept(visitor);
+        self.oscillation_failure.accept(visitor);
+        self.internal_leak_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 pub trait HydraulicAssemblyController {
     fn requested_mode(&self) -> LinearActuatorMode;
     fn requested_position(&self) -> Ratio;
@@ -1300,6 +1564,9 @@ pub trait HydraulicAssemblyController {
     fn should_run_electro_hydrostatic_backup(&self) -> bool {
         false
     }
+    fn has_internal_leakage(&self) -> bool {
+        false
+    }
 }
 
 pub trait HydraulicLocking {
@@ -1474,6 +1741,13 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
         self.rigid_body.apply_global_angle_offset(trim_angle);
     }
 
+    /// Cold and thick fluid slows down actuators, each actuator sees the fluid of its own circuit
+    pub fn update_fluid_viscosity(&mut self, fluid_viscosity: [KinematicViscosity; N]) {
+        for (index, actuator) in self.linear_actuators.iter_mut().enumerate() {
+            actuator.update_fluid_viscosity(fluid_viscosity[index]);
+        }
+    }
+
     #[cfg(test)]
     pub fn actuator_flow(&self, index: usize) -> VolumeRate {
         self.linear_actuators[index].signed_flow().abs()
@@ -2011,6 +2285,8 @@ mod tests {
 
         should_activate_elec_backup: bool,
         should_activate_elec_backup_refill: bool,
+
+        has_internal_leakage: bool,
     }
     impl TestHydraulicAssemblyController {
         fn new() -> Self {
@@ -2025,6 +2301,8 @@ mod tests {
 
                 should_activate_elec_backup: false,
                 should_activate_elec_backup_refill: false,
+
+                has_internal_leakage: false,
             }
         }
 
@@ -2057,6 +2335,10 @@ mod tests {
         fn set_elec_backup_refill(&mut self, is_on: bool) {
             self.should_activate_elec_backup_refill = is_on;
         }
+
+        fn set_internal_leakage(&mut self, has_internal_leakage: bool) {
+            self.has_internal_leakage = has_internal_leakage;
+        }
     }
     impl HydraulicAssemblyController for TestHydraulicAssemblyController {
         fn requested_mode(&self) -> LinearActuatorMode {
@@ -2074,6 +2356,10 @@ mod tests {
         fn requested_lock_position(&self) -> Ratio {
             self.lock_position
         }
+
+        fn has_internal_leakage(&self) -> bool {
+            self.has_internal_leakage
+        }
     }
     impl HydraulicLocking for TestHydraulicAssemblyController {
         fn should_soft_lock(&self) -> bool {
@@ -2202,10 +2488,23 @@ mod tests {
             }
         }
 
+        fn command_internal_leakage(&mut self, has_internal_leakage: bool, actuator_id: usize) {
+            assert!(actuator_id < N);
+            self.controllers[actuator_id].set_internal_leakage(has_internal_leakage);
+        }
+
         fn actuator_used_volume(&self, actuator_id: usize) -> Volume {
             self.hydraulic_assembly.linear_actuators[actuator_id].used_volume()
         }
 
+        fn actuator_reservoir_return(&self, actuator_id: usize) -> Volume {
+            self.hydraulic_assembly.linear_actuators[actuator_id].reservoir_return()
+        }
+
+        fn actuator_internal_leakage_flow(&self, actuator_id: usize) -> VolumeRate {
+            self.hydraulic_assembly.linear_actuators[actuator_id].internal_leakage_flow()
+        }
+
         fn command_empty_eha_accumulator(&mut self, actuator_id: usize) {
             assert!(actuator_id < N);
             if let Some(eha) = self.hydraulic_assembly.linear_actuators[actuator_id]
@@ -3957,6 +4256,106 @@ mod tests {
         assert!(test_bed.query(|a| a.actuator_used_volume(0).get::<gallon>()) <= 0.0001);
     }
 
+    fn aileron_holding_position_test_bed(
+        has_internal_leakage: bool,
+    ) -> SimulationTestBed<TestAircraft<2>> {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = aileron_assembly(context, false);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| {
+            a.set_pressures([Pressure::new::<psi>(3000.), Pressure::new::<psi>(3000.)])
+        });
+        test_bed.command(|a| a.command_active_damping_mode(0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(0.5), 1));
+        test_bed.command(|a| a.command_internal_leakage(has_internal_leakage, 1));
+
+        test_bed
+    }
+
+    #[test]
+    fn linear_actuator_without_internal_leakage_failure_has_no_leakage_flow() {
+        let mut test_bed = aileron_holding_position_test_bed(false);
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert!(test_bed.query(|a| a.actuator_internal_leakage_flow(1)) == VolumeRate::default());
+    }
+
+    #[test]
+    fn linear_actuator_internal_leakage_grows_with_seal_wear() {
+        let mut test_bed = aileron_holding_position_test_bed(true);
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        let early_leakage_flow = test_bed.query(|a| a.actuator_internal_leakage_flow(1));
+        assert!(early_leakage_flow > VolumeRate::default());
+
+        test_bed.run_with_delta(Duration::from_secs(60));
+        assert!(test_bed.query(|a| a.actuator_internal_leakage_flow(1)) > early_leakage_flow);
+
+        // Seals are fully worn after two minutes
+        test_bed.run_with_delta(Duration::from_secs(60));
+        assert_about_eq!(
+            test_bed
+                .query(|a| a.actuator_internal_leakage_flow(1))
+                .get::<gallon_per_minute>(),
+            1.,
+            0.05
+        );
+    }
+
+    #[test]
+    fn linear_actuator_internal_leakage_consumes_fluid_returned_to_reservoir() {
+        let mut normal_test_bed = aileron_holding_position_test_bed(false);
+        let mut leaking_test_bed = aileron_holding_position_test_bed(true);
+        normal_test_bed.run_with_delta(Duration::from_secs(60));
+        leaking_test_bed.run_with_delta(Duration::from_secs(60));
+
+        let normal_used_volume = normal_test_bed.query(|a| a.actuator_used_volume(1));
+        let leaking_used_volume = leaking_test_bed.query(|a| a.actuator_used_volume(1));
+        assert!(leaking_used_volume - normal_used_volume > Volume::new::<gallon>(0.1));
+
+        // The bypassed fluid goes straight to the return side, so nothing is lost overboard
+        let normal_returned_volume = normal_test_bed.query(|a| a.actuator_reservoir_return(1));
+        let leaking_returned_volume = leaking_test_bed.query(|a| a.actuator_reservoir_return(1));
+        assert_about_eq!(
+            (leaking_used_volume - leaking_returned_volume).get::<gallon>(),
+            (normal_used_volume - normal_returned_volume).get::<gallon>(),
+            0.01
+        );
+    }
+
+    #[test]
+    fn linear_actuator_internal_leakage_needs_hydraulic_pressure() {
+        let mut test_bed = aileron_holding_position_test_bed(true);
+        test_bed.command(|a| a.set_pressures([Pressure::default(), Pressure::default()]));
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert!(test_bed.query(|a| a.actuator_internal_leakage_flow(1)) == VolumeRate::default());
+    }
+
+    #[test]
+    fn linear_actuator_internal_leakage_stops_when_failure_is_cleared() {
+        let mut test_bed = aileron_holding_position_test_bed(true);
+        test_bed.run_with_delta(Duration::from_secs(30));
+        assert!(test_bed.query(|a| a.actuator_internal_leakage_flow(1)) > VolumeRate::default());
+
+        test_bed.command(|a| a.command_internal_leakage(false, 1));
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert!(test_bed.query(|a| a.actuator_internal_leakage_flow(1)) == VolumeRate::default());
+    }
+
+    #[test]
+    fn linear_actuator_with_worn_seals_holds_position() {
+        let mut test_bed = aileron_holding_position_test_bed(true);
+        test_bed.run_with_delta(Duration::from_secs(150));
+
+        assert!(test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.45));
+        assert!(test_bed.query(|a| a.body_position()) < Ratio::new::<ratio>(0.55));
+    }
+
     #[test]
     fn linear_actuator_can_move_heavy_door_up() {
         let mut test_bed = SimulationTestBed::new(|context| {
@@ -4718,4 +5117,209 @@ mod tests {
 
         HydraulicLinearActuatorAssembly::new([standard_actuator, eha_actuator], rigid_body)
     }
+
+    struct TestServoAircraft {
+        servo_failures: FlightControlServoFailures,
+
+        commanded_mode: LinearActuatorMode,
+        commanded_position: Ratio,
+        surface_position: Ratio,
+    }
+    impl TestServoAircraft {
+        fn new(_: &mut InitContext) -> Self {
+            Self {
+                servo_failures: FlightControlServoFailures::new(
+                    FlightControlSurface::LeftAileron,
+                    HydraulicColor::Blue,
+                ),
+
+                commanded_mode: LinearActuatorMode::PositionControl,
+                commanded_position: Ratio::new::<ratio>(0.5),
+                surface_position: Ratio::new::<ratio>(0.5),
+            }
+        }
+
+        fn command(&mut self, mode: LinearActuatorMode, position: Ratio) {
+            self.commanded_mode = mode;
+            self.commanded_position = position;
+        }
+
+        fn set_surface_position(&mut self, position: Ratio) {
+            self.surface_position = position;
+        }
+    }
+    impl Aircraft for TestServoAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.servo_failures.update(
+                context,
+                self.commanded_mode,
+                self.commanded_position,
+                self.surface_position,
+            );
+        }
+    }
+    impl SimulationElement for TestServoAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.servo_failures.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn servo_failure(
+        failure: fn(FlightControlSurface, HydraulicColor) -> FailureType,
+    ) -> FailureType {
+        failure(FlightControlSurface::LeftAileron, HydraulicColor::Blue)
+    }
+
+    #[test]
+    fn servo_without_failure_follows_demand() {
+        let mut test_bed = SimulationTestBed::new(TestServoAircraft::new);
+        test_bed.command(|a| {
+            a.command(
+                LinearActuatorMode::PositionControl,
+                Ratio::new::<ratio>(0.8),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        assert!(test_bed.query(|a| !a.servo_failures.is_jammed()));
+        assert!(test_bed.query(|a| !a.servo_failures.is_failure_detected()));
+        assert!(test_bed.query(|a| {
+            a.servo_failures.mode(LinearActuatorMode::PositionControl)
+                == LinearActuatorMode::PositionControl
+        }));
+        assert!(test_bed.query(|a| {
+            a.servo_failures.position(Ratio::new::<ratio>(0.8)) == Ratio::new::<ratio>(0.8)
+        }));
+    }
+
+    #[test]
+    fn jammed_servo_locks_at_surface_position_and_is_detected_on_demand_change() {
+        let mut test_bed = SimulationTestBed::new(TestServoAircraft::new);
+        test_bed.command(|a| a.set_surface_position(Ratio::new::<ratio>(0.3)));
+        test_bed.fail(servo_failure(FailureType::FlightControlServoJam));
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        assert!(test_bed.query(|a| a.servo_failures.is_jammed()));
+        assert!(test_bed.query(|a| a.servo_failures.jam_position() == Ratio::new::<ratio>(0.3)));
+        assert!(test_bed.query(|a| !a.servo_failures.is_failure_detected()));
+
+        test_bed.command(|a| {
+            a.command(
+                LinearActuatorMode::PositionControl,
+                Ratio::new::<ratio>(0.3),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_secs(2));
+        assert!(test_bed.query(|a| !a.servo_failures.is_failure_detected()));
+
+        test_bed.command(|a| {
+            a.command(
+                LinearActuatorMode::PositionControl,
+                Ratio::new::<ratio>(0.8),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_millis(300));
+        assert!(test_bed.query(|a| !a.servo_failures.is_failure_detected()));
+
+        test_bed.run_with_delta(Duration::from_millis(300));
+        assert!(test_bed.query(|a| a.servo_failures.is_failure_detected()));
+        assert!(test_bed.query(|a| a.servo_failures.jam_position() == Ratio::new::<ratio>(0.3)));
+    }
+
+    #[test]
+    fn jam_is_released_when_failure_is_cleared() {
+        let mut test_bed = SimulationTestBed::new(TestServoAircraft::new);
+        test_bed.fail(servo_failure(FailureType::FlightControlServoJam));
+        test_bed.command(|a| {
+            a.command(
+                LinearActuatorMode::PositionControl,
+                Ratio::new::<ratio>(0.9),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert!(test_bed.query(|a| a.servo_failures.is_failure_detected()));
+
+        test_bed.unfail(servo_failure(FailureType::FlightControlServoJam));
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        assert!(test_bed.query(|a| !a.servo_failures.is_jammed()));
+        assert!(test_bed.query(|a| !a.servo_failures.is_failure_detected()));
+    }
+
+    #[test]
+    fn runaway_servo_drives_to_a_stop() {
+        let mut test_bed = SimulationTestBed::new(TestServoAircraft::new);
+        test_bed.fail(servo_failure(FailureType::FlightControlServoRunaway));
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        let runaway_position: Ratio =
+            test_bed.query(|a| a.servo_failures.position(Ratio::new::<ratio>(0.5)));
+        assert!(
+            runaway_position == Ratio::new::<ratio>(0.)
+                || runaway_position == Ratio::new::<ratio>(1.)
+        );
+
+        test_bed.command(|a| a.set_surface_position(runaway_position));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.servo_failures.is_failure_detected()));
+    }
+
+    #[test]
+    fn servo_with_feedback_loss_reverts_to_damping_and_is_detected() {
+        let mut test_bed = SimulationTestBed::new(TestServoAircraft::new);
+        test_bed.fail(servo_failure(FailureType::FlightControlServoFeedbackLoss));
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        assert!(test_bed.query(|a| a.servo_failures.is_failure_detected()));
+        assert!(test_bed.query(|a| {
+            a.servo_failures.mode(LinearActuatorMode::PositionControl)
+                == LinearActuatorMode::ActiveDamping
+        }));
+        assert!(test_bed.query(|a| {
+            a.servo_failures
+                .mode(LinearActuatorMode::ClosedCircuitDamping)
+                == LinearActuatorMode::ClosedCircuitDamping
+        }));
+    }
+
+    #[test]
+    fn servo_with_internal_leak_has_internal_leakage() {
+        let mut test_bed = SimulationTestBed::new(TestServoAircraft::new);
+        test_bed.run_with_delta(Duration::from_millis(50));
+        assert!(test_bed.query(|a| !a.servo_failures.has_internal_leakage()));
+
+        test_bed.fail(servo_failure(FailureType::FlightControlServoInternalLeak));
+        test_bed.run_with_delta(Duration::from_millis(50));
+        assert!(test_bed.query(|a| a.servo_failures.has_internal_leakage()));
+
+        test_bed.unfail(servo_failure(FailureType::FlightControlServoInternalLeak));
+        test_bed.run_with_delta(Duration::from_millis(50));
+        assert!(test_bed.query(|a| !a.servo_failures.has_internal_leakage()));
+    }
+
+    #[test]
+    fn oscillating_servo_adds_oscillation_to_demand_and_is_detected() {
+        let mut test_bed = SimulationTestBed::new(TestServoAircraft::new);
+        test_bed.fail(servo_failure(FailureType::FlightControlServoOscillation));
+
+        let mut min_position = Ratio::new::<ratio>(1.);
+        let mut max_position = Ratio::new::<ratio>(0.);
+        for _ in 0..20 {
+            test_bed.run_with_delta(Duration::from_millis(50));
+            let position: Ratio =
+                test_bed.query(|a| a.servo_failures.position(Ratio::new::<ratio>(0.5)));
+            min_position = min_position.min(position);
+            max_position = max_position.max(position);
+        }
+
+        assert!(min_position.get::<ratio>() < 0.47);
+        assert!(max_position.get::<ratio>() > 0.53);
+        assert!(test_bed.query(|a| !a.servo_failures.is_failure_detected()));
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert!(test_bed.query(|a| a.servo_failures.is_failure_detected()));
+    }
 }
@@ -11,7 +11,8 @@ use crate::physics::{GravityEffect, WobblePhysics};
 use crate::shared::{
     interpolation, low_pass_filter::LowPassFilter, random_from_normal_distribution,
     random_from_range, AirbusElectricPumpId, AirbusEngineDrivenPumpId, DelayedTrueLogicGate,
-    ElectricalBusType, ElectricalBuses, HydraulicColor, RamAirTurbineController, SectionPressure,
+    ElectricalBusType, ElectricalBuses, HydraulicColor, HydraulicLeakLocation,
+    RamAirTurbineController, SectionPressure,
 };
 use crate::simulation::{
     InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -24,9 +25,12 @@ use std::time::Duration;
 use uom::si::{
     angular_velocity::{radian_per_second, revolution_per_minute},
     f64::*,
+    kinematic_viscosity::centistokes,
     length::meter,
+    power::watt,
     pressure::{pascal, psi},
     ratio::ratio,
+    thermodynamic_temperature::degree_celsius,
     torque::{newton_meter, pound_force_inch},
     volume::{cubic_inch, cubic_meter, gallon},
     volume_rate::{gallon_per_minute, gallon_per_second},
@@ -49,6 +53,7 @@ pub mod pumps;
 pub mod pushback;
 pub mod reverser;
 pub mod rudder_control;
+pub mod runway_friction;
 pub mod trimmable_horizontal_stabilizer;
 
 /// Indicates the pressure sensors info of an hydraulic circuit at different locations
@@ -86,29 +91,48 @@ pub trait PressureSource {
 
 pub struct Fluid {
     current_bulk: Pressure,
-    heat_state: HeatingProperties,
+
+    temperature: ThermodynamicTemperature,
+    is_temperature_initialised: bool,
+
+    // Fuel tanks have a huge thermal inertia and only slowly soak to ambient temperature
+    fuel_temperature: LowPassFilter<f64>, // Degree Celsius
 }
 impl Fluid {
-    const HEATING_TIME_CONSTANT_MEAN_S: f64 = 40.;
-    const HEATING_TIME_CONSTANT_STD_S: f64 = 10.;
+    const FLUID_DENSITY_KG_PER_GALLON: f64 = 3.8;
+    const FLUID_SPECIFIC_HEAT_J_PER_KG_KELVIN: f64 = 1750.;
 
-    const COOLING_TIME_CONSTANT: Duration = Duration::from_secs(60 * 3);
-    const DAMAGE_TIME_CONSTANT: Duration = Duration::from_secs(60 * 3);
+    // Pumps shaft power is entirely turned into heat once the fluid has been throttled back to the reservoir
+    const PUMP_EFFICIENCY: f64 = 0.85;
+    const PUMP_OVERHEAT_HEAT_POWER_WATT: f64 = 250000.;
+
+    const PTU_ROTATING_HEAT_POWER_WATT: f64 = 3000.;
+    const PTU_OVERHEAT_HEAT_POWER_WATT: f64 = 250000.;
+
+    // Heat exchangers are located in the fuel tanks and are crossed by the pumps case drain flow
+    const FUEL_HEAT_EXCHANGER_CONDUCTANCE_WATT_PER_KELVIN: f64 = 300.;
+    const AMBIENT_CONDUCTANCE_WATT_PER_KELVIN: f64 = 30.;
+    const FUEL_TEMPERATURE_TIME_CONSTANT: Duration = Duration::from_secs(60 * 60);
+
+    const OVERHEATING_TEMPERATURE_DEGREE_CELSIUS: f64 = 95.;
+    const DAMAGING_TEMPERATURE_DEGREE_CELSIUS: f64 = 135.;
+
+    pub const NOMINAL_VISCOSITY_CENTISTOKES: f64 = 10.5;
+
+    // Kinematic viscosity of a phosphate ester fluid against temperature
+    const VISCOSITY_TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS: [f64; 10] =
+        [-55., -40., -20., 0., 20., 40., 60., 80., 100., 120.];
+    const VISCOSITY_MAP_CENTISTOKES: [f64; 10] =
+        [1500., 500., 120., 40., 18., 10.5, 7., 5., 3.9, 3.];
 
     pub fn new(bulk: Pressure) -> Self {
         Self {
             current_bulk: bulk,
-            heat_state: HeatingProperties::new(
-                Duration::from_secs_f64(
-                    random_from_normal_distribution(
-                        Self::HEATING_TIME_CONSTANT_MEAN_S,
-                        Self::HEATING_TIME_CONSTANT_STD_S,
-                    )
-                    .max(10.),
-                ),
-                Self::COOLING_TIME_CONSTANT,
-                Self::DAMAGE_TIME_CONSTANT,
-            ),
+
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            is_temperature_initialised: false,
+
+            fuel_temperature: LowPassFilter::new(Self::FUEL_TEMPERATURE_TIME_CONSTANT),
         }
     }
 
@@ -116,17 +140,71 @@ impl Fluid {
         self.current_bulk
     }
 
-    fn update(&mut self, context: &UpdateContext, is_heating: bool) {
-        self.heat_state.update(context, is_heating);
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    pub fn kinematic_viscosity(&self) -> KinematicViscosity {
+        Self::kinematic_viscosity_at(self.temperature)
+    }
+
+    pub fn kinematic_viscosity_at(temperature: ThermodynamicTemperature) -> KinematicViscosity {
+        KinematicViscosity::new::<centistokes>(interpolation(
+            &Self::VISCOSITY_TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS,
+            &Self::VISCOSITY_MAP_CENTISTOKES,
+            temperature.get::<degree_celsius>(),
+        ))
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        heat_power: Power,
+        fluid_volume: Volume,
+        is_heat_exchanger_flowing: bool,
+    ) {
+        let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
+
+        // Fluid is soaked at ambient temperature when the simulation starts
+        if !self.is_temperature_initialised {
+            self.temperature = context.ambient_temperature();
+            self.fuel_temperature.reset(ambient_temperature);
+            self.is_temperature_initialised = true;
+        }
+
+        self.fuel_temperature
+            .update(context.delta(), ambient_temperature);
+
+        let fluid_temperature = self.temperature.get::<degree_celsius>();
+
+        let heat_exchanger_power = if is_heat_exchanger_flowing {
+            Self::FUEL_HEAT_EXCHANGER_CONDUCTANCE_WATT_PER_KELVIN
+                * (fluid_temperature - self.fuel_temperature.output())
+        } else {
+            0.
+        };
+        let ambient_power =
+            Self::AMBIENT_CONDUCTANCE_WATT_PER_KELVIN * (fluid_temperature - ambient_temperature);
+
+        let heat_capacity = fluid_volume.get::<gallon>().max(1.)
+            * Self::FLUID_DENSITY_KG_PER_GALLON
+            * Self::FLUID_SPECIFIC_HEAT_J_PER_KG_KELVIN;
+
+        self.temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            fluid_temperature
+                + (heat_power.get::<watt>() - heat_exchanger_power - ambient_power)
+                    * context.delta_as_secs_f64()
+                    / heat_capacity,
+        );
     }
 }
 impl HeatingElement for Fluid {
     fn is_overheating(&self) -> bool {
-        self.heat_state.is_overheating()
+        self.temperature.get::<degree_celsius>() > Self::OVERHEATING_TEMPERATURE_DEGREE_CELSIUS
     }
 
     fn is_damaged(&self) -> bool {
-        self.heat_state.is_damaged()
+        self.temperature.get::<degree_celsius>() > Self::DAMAGING_TEMPERATURE_DEGREE_CELSIUS
     }
 }
 
@@ -350,6 +428,8 @@ pub struct PowerTransferUnit {
     efficiency: Ratio,
 
     heat_state: HeatingProperties,
+
+    shaft_failure: Failure,
 }
 impl PowerTransferUnit {
     const MIN_SPEED_SIMULATION_RPM: f64 = 50.;
@@ -452,6 +532,8 @@ impl PowerTransferUnit {
                 Self::COOLING_TIME_CONSTANT,
                 Self::DAMAGE_TIME_CONSTANT,
             ),
+
+            shaft_failure: Failure::new(FailureType::PowerTransferUnitShaft),
         }
     }
 
@@ -598,7 +680,9 @@ impl PowerTransferUnit {
 
         let total_torque = friction_torque + left_side_torque + right_side_torque;
 
+        // A sheared shaft can't transmit any torque between both sides
         if !self.heat_state.is_damaged()
+            && !self.shaft_failure.is_active()
             && (self.is_rotating()
                 || total_torque.abs().get::<newton_meter>() > Self::BREAKOUT_TORQUE_NM)
         {
@@ -720,6 +804,11 @@ impl PowerTransferUnit {
     }
 }
 impl SimulationElement for PowerTransferUnit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.shaft_failure.accept(visitor);
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.valve_opened_id, self.is_enabled());
         writer.write(
@@ -987,6 +1076,12 @@ impl HydraulicCircuit {
     const SYSTEM_SECTION_STATIC_LEAK_GAL_P_S: f64 = 0.03;
     const AUX_SECTION_STATIC_LEAK_GAL_P_S: f64 = 0.001;
 
+    const PUMP_SECTION_EXTERNAL_LEAK_GAL_P_S: f64 = 0.05;
+    const SYSTEM_SECTION_EXTERNAL_LEAK_GAL_P_S: f64 = 0.1;
+    const AUX_SECTION_EXTERNAL_LEAK_GAL_P_S: f64 = 0.02;
+
+    const MIN_FLOW_FOR_HEAT_EXCHANGE_GAL_P_S: f64 = 0.01;
+
     const FLUID_BULK_MODULUS_PASCAL: f64 = 1450000000.0;
 
     // TODO firevalves are actually powered by a sub-bus (401PP DC ESS)
@@ -1034,25 +1129,35 @@ impl HydraulicCircuit {
                 Self::DEFAULT_FIRE_VALVE_POWERING_BUS,
             ));
 
-            pump_sections.push(Section::new(
-                context,
-                id,
-                "PUMP",
-                pump_id,
-                VolumeRate::new::<gallon_per_second>(Self::PUMP_SECTION_STATIC_LEAK_GAL_P_S),
-                Volume::new::<gallon>(
-                    Self::PUMP_SECTION_MAX_VOLUME_GAL * priming_volume.get::<ratio>(),
+            pump_sections.push(
+                Section::new(
+                    context,
+                    id,
+                    "PUMP",
+                    pump_id,
+                    VolumeRate::new::<gallon_per_second>(Self::PUMP_SECTION_STATIC_LEAK_GAL_P_S),
+                    Volume::new::<gallon>(
+                        Self::PUMP_SECTION_MAX_VOLUME_GAL * priming_volume.get::<ratio>(),
+                    ),
+                    Volume::new::<gallon>(Self::PUMP_SECTION_MAX_VOLUME_GAL),
+                    None,
+                    pump_pressure_switch_lo_hyst,
+                    pump_pressure_switch_hi_hyst,
+                    fire_valve,
+                    false,
+                    false,
+                    None,
+                    None,
+                )
+                .with_external_leak(
+                    FailureType::HydraulicExternalLeak(
+                        id,
+                        HydraulicLeakLocation::PumpSection(pump_id),
+                    ),
+                    VolumeRate::new::<gallon_per_second>(Self::PUMP_SECTION_EXTERNAL_LEAK_GAL_P_S),
+                    false,
                 ),
-                Volume::new::<gallon>(Self::PUMP_SECTION_MAX_VOLUME_GAL),
-                None,
-                pump_pressure_switch_lo_hyst,
-                pump_pressure_switch_hi_hyst,
-                fire_valve,
-                false,
-                false,
-                None,
-                None,
-            ));
+            );
 
             pump_to_system_check_valves.push(CheckValve::new());
 
@@ -1088,27 +1193,55 @@ impl HydraulicCircuit {
                     Self::DEFAULT_LEAK_MEASUREMENT_VALVE_POWERING_BUS,
                 )),
                 Some(priority_valve),
+            )
+            .with_external_leak(
+                FailureType::HydraulicExternalLeak(
+                    id,
+                    HydraulicLeakLocation::SystemSectionUpstreamPriorityValve,
+                ),
+                VolumeRate::new::<gallon_per_second>(Self::SYSTEM_SECTION_EXTERNAL_LEAK_GAL_P_S),
+                false,
+            )
+            .with_external_leak(
+                FailureType::HydraulicExternalLeak(
+                    id,
+                    HydraulicLeakLocation::SystemSectionDownstreamPriorityValve,
+                ),
+                VolumeRate::new::<gallon_per_second>(Self::SYSTEM_SECTION_EXTERNAL_LEAK_GAL_P_S),
+                true,
             ),
             auxiliary_section: if has_auxiliary_section {
-                Some(Section::new(
-                    context,
-                    id,
-                    "AUXILIARY",
-                    1,
-                    VolumeRate::new::<gallon_per_second>(Self::AUX_SECTION_STATIC_LEAK_GAL_P_S),
-                    system_section_volume
-                        * Self::AUXILIARY_TO_SYSTEM_SECTION_SIZE_RATIO
-                        * priming_volume,
-                    system_section_volume * Self::AUXILIARY_TO_SYSTEM_SECTION_SIZE_RATIO,
-                    None,
-                    system_pressure_switch_lo_hyst,
-                    system_pressure_switch_hi_hyst,
-                    None,
-                    false,
-                    false,
-                    None,
-                    None,
-                ))
+                Some(
+                    Section::new(
+                        context,
+                        id,
+                        "AUXILIARY",
+                        1,
+                        VolumeRate::new::<gallon_per_second>(Self::AUX_SECTION_STATIC_LEAK_GAL_P_S),
+                        system_section_volume
+                            * Self::AUXILIARY_TO_SYSTEM_SECTION_SIZE_RATIO
+                            * priming_volume,
+                        system_section_volume * Self::AUXILIARY_TO_SYSTEM_SECTION_SIZE_RATIO,
+                        None,
+                        system_pressure_switch_lo_hyst,
+                        system_pressure_switch_hi_hyst,
+                        None,
+                        false,
+                        false,
+                        None,
+                        None,
+                    )
+                    .with_external_leak(
+                        FailureType::HydraulicExternalLeak(
+                            id,
+                            HydraulicLeakLocation::AuxiliarySection,
+                        ),
+                        VolumeRate::new::<gallon_per_second>(
+                            Self::AUX_SECTION_EXTERNAL_LEAK_GAL_P_S,
+                        ),
+                        false,
+                    ),
+                )
             } else {
                 None
             },
@@ -1146,32 +1279,16 @@ impl HydraulicCircuit {
         controller: &impl HydraulicCircuitController,
         reservoir_pressure: Pressure,
     ) {
-        let mut any_pump_is_overheating = false;
-        for pump in main_section_pumps.iter() {
-            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
-                any_pump_is_overheating = true;
-            }
-        }
-
-        if let Some(pump) = system_section_pump.as_ref() {
-            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
-                any_pump_is_overheating = true;
-            }
-        }
-
-        if let Some(pump) = auxiliary_section_pump.as_ref() {
-            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
-                any_pump_is_overheating = true;
-            }
-        }
-
-        let ptu_overheats_fluid = ptu.is_some_and(|p| p.is_overheating() && p.is_rotating());
-
-        self.fluid
-            .update(context, ptu_overheats_fluid || any_pump_is_overheating);
+        self.update_fluid_temperature(
+            context,
+            main_section_pumps,
+            &system_section_pump,
+            &auxiliary_section_pump,
+            ptu,
+        );
 
         self.reservoir
-            .update(context, reservoir_pressure, &self.fluid);
+            .update(context, reservoir_pressure, self.fluid.temperature());
 
         self.update_shutoff_valves(controller);
         self.update_leak_measurement_valves(context, controller);
@@ -1212,6 +1329,89 @@ impl HydraulicCircuit {
         self.update_final_delta_vol_and_pressure(context);
     }
 
+    fn update_fluid_temperature(
+        &mut self,
+        context: &UpdateContext,
+        main_section_pumps: &[&mut dyn HeatingPressureSource],
+        system_section_pump: &Option<&mut impl HeatingPressureSource>,
+        auxiliary_section_pump: &Option<&mut impl HeatingPressureSource>,
+        ptu: Option<&PowerTransferUnit>,
+    ) {
+        let mut heat_power = Power::default();
+        let mut total_pump_flow = VolumeRate::default();
+
+        for (pump_index, pump) in main_section_pumps.iter().enumerate() {
+            heat_power += Self::pump_heat_power(&**pump, self.pump_sections[pump_index].pressure());
+            total_pump_flow += pump.flow();
+        }
+
+        if let Some(pump) = system_section_pump.as_ref() {
+            heat_power += Self::pump_heat_power(&**pump, self.system_section.pressure());
+            total_pump_flow += pump.flow();
+        }
+
+        if let (Some(pump), Some(auxiliary_section)) = (
+            auxiliary_section_pump.as_ref(),
+            self.auxiliary_section.as_ref(),
+        ) {
+            heat_power += Self::pump_heat_power(&**pump, auxiliary_section.pressure());
+            total_pump_flow += pump.flow();
+        }
+
+        if let Some(ptu) = ptu {
+            if ptu.is_rotating() {
+                heat_power += Power::new::<watt>(if ptu.is_overheating() {
+                    Fluid::PTU_OVERHEAT_HEAT_POWER_WATT
+                } else {
+                    Fluid::PTU_ROTATING_HEAT_POWER_WATT
+                });
+            }
+        }
+
+        let fluid_volume = self
+            .pump_sections
+            .iter()
+            .map(|s| s.current_volume)
+            .sum::<Volume>()
+            + self.system_section.current_volume
+            + self
+                .auxiliary_section
+                .as_ref()
+                .map_or(Volume::default(), |s| s.current_volume)
+            + self.reservoir.fluid_level_real();
+
+        self.fluid.update(
+            context,
+            heat_power,
+            fluid_volume,
+            total_pump_flow.get::<gallon_per_second>() > Self::MIN_FLOW_FOR_HEAT_EXCHANGE_GAL_P_S,
+        );
+
+        let fluid_viscosity = self.fluid.kinematic_viscosity();
+        for section in &mut self.pump_sections {
+            section.set_fluid_viscosity(fluid_viscosity);
+        }
+        self.system_section.set_fluid_viscosity(fluid_viscosity);
+        if let Some(auxiliary_section) = self.auxiliary_section.as_mut() {
+            auxiliary_section.set_fluid_viscosity(fluid_viscosity);
+        }
+    }
+
+    fn pump_heat_power(pump: &dyn HeatingPressureSource, section_pressure: Pressure) -> Power {
+        if pump.flow().get::<gallon_per_second>() > Self::MIN_FLOW_FOR_HEAT_EXCHANGE_GAL_P_S {
+            let losses =
+                pump.flow() * section_pressure.max(Pressure::default()) / Fluid::PUMP_EFFICIENCY;
+
+            if pump.is_overheating() {
+                losses + Power::new::<watt>(Fluid::PUMP_OVERHEAT_HEAT_POWER_WATT)
+            } else {
+                losses
+            }
+        } else {
+            Power::default()
+        }
+    }
+
     fn update_delta_vol_from_valves(&mut self) {
         for (pump_index, section) in self.pump_sections.iter_mut().enumerate() {
             section.update_downstream_delta_vol(&self.pump_sections_check_valves[pump_index]);
@@ -1446,6 +1646,10 @@ impl HydraulicCircuit {
         &self.reservoir
     }
 
+    pub fn fluid_temperature(&self) -> ThermodynamicTemperature {
+        self.fluid.temperature()
+    }
+
     pub fn system_section_pressure(&self) -> Pressure {
         self.system_section.pressure()
     }
@@ -1532,6 +1736,10 @@ pub struct Section {
 
     total_actuator_consumed_volume: Volume,
     total_actuator_returned_volume: Volume,
+
+    external_leaks: Vec<ExternalLeak>,
+
+    fluid_viscosity: KinematicViscosity,
 }
 impl Section {
     pub fn new(
@@ -1587,9 +1795,35 @@ impl Section {
 
             total_actuator_consumed_volume: Volume::new::<gallon>(0.),
             total_actuator_returned_volume: Volume::new::<gallon>(0.),
+
+            external_leaks: Vec::new(),
+
+            fluid_viscosity: KinematicViscosity::new::<centistokes>(
+                Fluid::NOMINAL_VISCOSITY_CENTISTOKES,
+            ),
         }
     }
 
+    fn set_fluid_viscosity(&mut self, fluid_viscosity: KinematicViscosity) {
+        self.fluid_viscosity = fluid_viscosity;
+    }
+
+    /// Adds an external leak to the section that can be triggered by a failure.
+    /// Leaks downstream of the priority valve only see the pressure the valve lets through.
+    pub fn with_external_leak(
+        mut self,
+        failure_type: FailureType,
+        leak_at_max_press: VolumeRate,
+        is_downstream_priority_valve: bool,
+    ) -> Self {
+        self.external_leaks.push(ExternalLeak::new(
+            failure_type,
+            leak_at_max_press,
+            is_downstream_priority_valve,
+        ));
+        self
+    }
+
     /// Gives the exact volume of fluid needed to get to any target_press pressure
     fn volume_to_reach_target(&self, target_press: Pressure, fluid: &Fluid) -> Volume {
         (target_press - self.current_pressure) * (self.max_high_press_volume) / fluid.bulk_mod()
@@ -1645,6 +1879,25 @@ impl Section {
             / target_pressure
     }
 
+    fn external_leak(&self, context: &UpdateContext, target_pressure: Pressure) -> Volume {
+        let pressure_downstream_priority_valve = self.pressure_downstream_priority_valve();
+
+        self.external_leaks
+            .iter()
+            .map(|leak| {
+                leak.volume(
+                    context,
+                    if leak.is_downstream_priority_valve {
+                        pressure_downstream_priority_valve
+                    } else {
+                        self.current_pressure
+                    },
+                    target_pressure,
+                )
+            })
+            .sum()
+    }
+
     /// Updates hydraulic flow from consumers like accumulator / ptu / any actuator
     pub fn update_flow(
         &mut self,
@@ -1658,6 +1911,9 @@ impl Section {
 
         reservoir.add_return_volume(static_leak);
 
+        // External leaks are lost overboard and never make it back to the reservoir
+        delta_volume_flow_pass -= self.external_leak(context, target_pressure);
+
         if let Some(accumulator) = &mut self.accumulator {
             accumulator.update(
                 context,
@@ -1820,6 +2076,10 @@ impl SimulationElement for Section {
             leak_meas_valve.accept(visitor);
         }
 
+        for leak in &mut self.external_leaks {
+            leak.failure.accept(visitor);
+        }
+
         visitor.visit(self);
     }
 
@@ -1856,6 +2116,47 @@ impl SectionPressure for Section {
     fn is_pressure_switch_pressurised(&self) -> bool {
         self.pressure_switch_state() == PressureSwitchState::Pressurised
     }
+
+    fn fluid_viscosity(&self) -> KinematicViscosity {
+        self.fluid_viscosity
+    }
+}
+
+/// A leak to the outside of the circuit, scaling with the pressure of the leaking line
+struct ExternalLeak {
+    failure: Failure,
+    leak_at_max_press: VolumeRate,
+    is_downstream_priority_valve: bool,
+}
+impl ExternalLeak {
+    fn new(
+        failure_type: FailureType,
+        leak_at_max_press: VolumeRate,
+        is_downstream_priority_valve: bool,
+    ) -> Self {
+        Self {
+            failure: Failure::new(failure_type),
+            leak_at_max_press,
+            is_downstream_priority_valve,
+        }
+    }
+
+    fn volume(
+        &self,
+        context: &UpdateContext,
+        line_pressure: Pressure,
+        target_pressure: Pressure,
+    ) -> Volume {
+        if self.failure.is_active() {
+            self.leak_at_max_press
+                * self.failure.severity()
+                * context.delta_as_time()
+                * ((line_pressure - Pressure::new::<psi>(14.7)) / target_pressure)
+                    .max(Ratio::default())
+        } else {
+            Volume::default()
+        }
+    }
 }
 
 pub struct FireValve {
@@ -2221,6 +2522,7 @@ pub struct Reservoir {
     low_level_id: VariableIdentifier,
     low_air_press_id: VariableIdentifier,
     overheating_id: VariableIdentifier,
+    temperature_id: VariableIdentifier,
 
     max_capacity: Volume,
     max_gaugeable: Volume,
@@ -2237,7 +2539,11 @@ pub struct Reservoir {
 
     fluid_physics: FluidPhysics,
 
-    heat_state: HeatingProperties,
+    temperature: LowPassFilter<f64>, // Degree Celsius
+    is_temperature_initialised: bool,
+    is_overheating: bool,
+    damaging_time: DelayedTrueLogicGate,
+    is_damaged_by_heat: bool,
 
     total_return_flow: VolumeRate,
     total_return_volume: Volume,
@@ -2250,9 +2556,13 @@ impl Reservoir {
     // Part of the fluid lost instead of returning to reservoir
     const RETURN_FAILURE_LEAK_RATIO: f64 = 0.1;
 
-    const HEATING_TIME_CONSTANT_MEAN_S: f64 = 30.;
-    const HEATING_TIME_CONSTANT_STD_S: f64 = 5.;
-    const COOLING_TIME_CONSTANT: Duration = Duration::from_secs(60 * 3);
+    // Returning fluid mixes with the reservoir content, which cools down to ambient when there's no return
+    const RETURN_MIXING_TIME_CONSTANT: Duration = Duration::from_secs(10);
+    const AMBIENT_COOLING_TIME_CONSTANT: Duration = Duration::from_secs(60 * 30);
+
+    const OVERHEAT_TEMPERATURE_DEGREE_CELSIUS: f64 = 95.;
+    const OVERHEAT_RESET_TEMPERATURE_DEGREE_CELSIUS: f64 = 85.;
+    const MAX_TEMPERATURE_DEGREE_CELSIUS: f64 = 140.;
     const DAMAGE_TIME_CONSTANT: Duration = Duration::from_secs(60 * 5);
 
     pub fn new(
@@ -2271,6 +2581,8 @@ impl Reservoir {
             low_air_press_id: context
                 .get_identifier(format!("HYD_{}_RESERVOIR_AIR_PRESSURE_IS_LOW", hyd_loop_id)),
             overheating_id: context.get_identifier(format!("HYD_{}_RESERVOIR_OVHT", hyd_loop_id)),
+            temperature_id: context
+                .get_identifier(format!("HYD_{}_RESERVOIR_TEMPERATURE", hyd_loop_id)),
 
             max_capacity,
             max_gaugeable,
@@ -2283,17 +2595,12 @@ impl Reservoir {
             level_switch: LevelSwitch::new(low_level_threshold),
             fluid_physics: FluidPhysics::new(),
 
-            heat_state: HeatingProperties::new(
-                Duration::from_secs_f64(
-                    random_from_normal_distribution(
-                        Self::HEATING_TIME_CONSTANT_MEAN_S,
-                        Self::HEATING_TIME_CONSTANT_STD_S,
-                    )
-                    .max(10.),
-                ),
-                Self::COOLING_TIME_CONSTANT,
-                Self::DAMAGE_TIME_CONSTANT,
-            ),
+            temperature: LowPassFilter::new(Self::RETURN_MIXING_TIME_CONSTANT),
+            is_temperature_initialised: false,
+            is_overheating: false,
+            damaging_time: DelayedTrueLogicGate::new(Self::DAMAGE_TIME_CONSTANT),
+            is_damaged_by_heat: false,
+
             total_return_flow: VolumeRate::default(),
             total_return_volume: Volume::default(),
         }
@@ -2303,12 +2610,12 @@ impl Reservoir {
         &mut self,
         context: &UpdateContext,
         air_pressure: Pressure,
-        fluid: &impl HeatingElement,
+        fluid_temperature: ThermodynamicTemperature,
     ) {
         self.air_pressure = air_pressure;
 
         self.update_return_flow(context);
-        self.update_heat(context, fluid);
+        self.update_heat(context, fluid_temperature);
 
         self.fluid_physics.update(context);
 
@@ -2327,16 +2634,70 @@ impl Reservoir {
         self.total_return_volume = Volume::default();
     }
 
-    fn update_heat(&mut self, context: &UpdateContext, fluid: &impl HeatingElement) {
+    fn update_heat(
+        &mut self,
+        context: &UpdateContext,
+        fluid_temperature: ThermodynamicTemperature,
+    ) {
+        // Reservoir is soaked at ambient temperature when the simulation starts
+        if !self.is_temperature_initialised {
+            self.temperature
+                .reset(context.ambient_temperature().get::<degree_celsius>());
+            self.is_temperature_initialised = true;
+        }
+
         let has_fluid_return = self.total_return_flow.get::<gallon_per_second>() > 0.01;
-        self.heat_state
-            .update(context, has_fluid_return && fluid.is_overheating())
+        if has_fluid_return {
+            self.temperature
+                .set_time_constant(Self::RETURN_MIXING_TIME_CONSTANT);
+            self.temperature
+                .update(context.delta(), fluid_temperature.get::<degree_celsius>());
+        } else {
+            self.temperature
+                .set_time_constant(Self::AMBIENT_COOLING_TIME_CONSTANT);
+            self.temperature.update(
+                context.delta(),
+                context.ambient_temperature().get::<degree_celsius>(),
+            );
+        }
+
+        let temperature = self.temperature.output();
+        self.is_overheating = if self.is_overheating {
+            temperature > Self::OVERHEAT_RESET_TEMPERATURE_DEGREE_CELSIUS
+        } else {
+            temperature > Self::OVERHEAT_TEMPERATURE_DEGREE_CELSIUS
+        };
+
+        self.damaging_time.update(context, self.is_overheating);
+        self.is_damaged_by_heat = self.is_damaged_by_heat || self.damaging_time.output();
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(self.temperature.output())
+    }
+
+    /// Viscosity of the fluid drawn by the pumps
+    pub fn fluid_viscosity(&self) -> KinematicViscosity {
+        Fluid::kinematic_viscosity_at(self.temperature())
+    }
+
+    /// When overheating, provides a ratio of the heating severity
+    /// Above overheat temperature it will rise from 0 to 1, while always 0 under the threshold
+    fn overheat_ratio(&self) -> Ratio {
+        Ratio::new::<ratio>(
+            ((self.temperature.output() - Self::OVERHEAT_TEMPERATURE_DEGREE_CELSIUS)
+                / (Self::MAX_TEMPERATURE_DEGREE_CELSIUS
+                    - Self::OVERHEAT_TEMPERATURE_DEGREE_CELSIUS))
+                .clamp(0., 1.),
+        )
     }
 
     fn update_leak_failure(&mut self, context: &UpdateContext) {
         if self.leak_failure.is_active() {
+            // Leak rate scales with failure severity
             self.current_level -=
                 VolumeRate::new::<gallon_per_second>(Self::LEAK_FAILURE_FLOW_GAL_PER_S)
+                    * self.leak_failure.severity()
                     * context.delta_as_time();
 
             self.current_level = self.current_level.max(Volume::new::<gallon>(0.));
@@ -2431,6 +2792,7 @@ impl SimulationElement for Reservoir {
         writer.write(&self.low_level_id, self.is_low_level());
         writer.write(&self.low_air_press_id, self.is_low_air_pressure());
         writer.write(&self.overheating_id, self.is_overheating());
+        writer.write(&self.temperature_id, self.temperature());
     }
 }
 impl PressurizeableReservoir for Reservoir {
@@ -2440,11 +2802,11 @@ impl PressurizeableReservoir for Reservoir {
 }
 impl HeatingElement for Reservoir {
     fn is_damaged(&self) -> bool {
-        self.heat_state.is_damaged()
+        self.is_damaged_by_heat
     }
 
     fn is_overheating(&self) -> bool {
-        self.heat_state.is_overheating()
+        self.is_overheating
     }
 }
 
@@ -2472,6 +2834,8 @@ pub struct Pump {
     speed: AngularVelocity,
 
     cavitation_efficiency: Ratio,
+
+    wear: Ratio,
 }
 impl Pump {
     const SECONDS_PER_MINUTES: f64 = 60.;
@@ -2479,6 +2843,14 @@ impl Pump {
 
     const MAX_DISPLACEMENT_FILTER_TIME_CONSTANT: Duration = Duration::from_millis(150);
 
+    // A worn pump loses displacement progressively while it is turning
+    const WEAR_RATE_PER_SECOND: f64 = 1. / 300.;
+    const MAX_WEAR_DISPLACEMENT_LOSS_RATIO: f64 = 0.8;
+
+    const VISCOSITY_EFFICIENCY_BREAKPOINTS_CENTISTOKES: [f64; 6] =
+        [2.5, 5., 100., 300., 800., 1500.];
+    const VISCOSITY_EFFICIENCY_MAP: [f64; 6] = [0.7, 1., 1., 0.9, 0.7, 0.6];
+
     fn new(pump_characteristics: PumpCharacteristics) -> Self {
         Self {
             delta_vol_max: Volume::new::<gallon>(0.),
@@ -2492,9 +2864,42 @@ impl Pump {
             speed: AngularVelocity::new::<revolution_per_minute>(0.),
 
             cavitation_efficiency: Ratio::new::<ratio>(1.),
+
+            wear: Ratio::default(),
         }
     }
 
+    /// Wear progresses while pump is turning up to the failure severity, so a partial wear
+    /// failure leaves the pump with a partial output
+    fn update_wear(&mut self, context: &UpdateContext, wear_severity: Ratio) {
+        self.wear = if wear_severity <= Ratio::default() {
+            Ratio::default()
+        } else if self.speed
+            > self
+                .pump_characteristics
+                .min_speed_for_non_zero_efficiency()
+        {
+            (self.wear
+                + Ratio::new::<ratio>(Self::WEAR_RATE_PER_SECOND * context.delta_as_secs_f64()))
+            .min(wear_severity)
+        } else {
+            self.wear.min(wear_severity)
+        };
+    }
+
+    fn wear(&self) -> Ratio {
+        self.wear
+    }
+
+    /// Thick cold fluid starves the pump inlet while thin hot fluid leaks internally
+    fn viscosity_efficiency(viscosity: KinematicViscosity) -> Ratio {
+        Ratio::new::<ratio>(interpolation(
+            &Self::VISCOSITY_EFFICIENCY_BREAKPOINTS_CENTISTOKES,
+            &Self::VISCOSITY_EFFICIENCY_MAP,
+            viscosity.get::<centistokes>(),
+        ))
+    }
+
     fn update<T: PumpController>(
         &mut self,
         context: &UpdateContext,
@@ -2528,10 +2933,9 @@ impl Pump {
 
     fn update_cavitation(&mut self, reservoir: &Reservoir) {
         self.cavitation_efficiency = if !reservoir.is_empty() {
-            self.pump_characteristics.cavitation_efficiency(
-                reservoir.air_pressure(),
-                reservoir.heat_state.overheat_ratio(),
-            )
+            self.pump_characteristics
+                .cavitation_efficiency(reservoir.air_pressure(), reservoir.overheat_ratio())
+                * Self::viscosity_efficiency(reservoir.fluid_viscosity())
         } else {
             Ratio::new::<ratio>(0.)
         };
@@ -2545,6 +2949,7 @@ impl Pump {
         if controller.should_pressurise() {
             self.pump_characteristics
                 .current_displacement(section.pressure())
+                * (1. - Self::MAX_WEAR_DISPLACEMENT_LOSS_RATIO * self.wear.get::<ratio>())
         } else {
             Volume::new::<cubic_inch>(0.)
         }
@@ -2641,6 +3046,7 @@ pub struct ElectricPump {
     overheat_id: VariableIdentifier,
     pump: Pump,
     pump_physics: ElectricalPumpPhysics,
+    wear_failure: Failure,
 }
 impl ElectricPump {
     pub fn new(
@@ -2662,6 +3068,7 @@ impl ElectricPump {
                 max_current,
                 regulated_speed,
             ),
+            wear_failure: Failure::new(FailureType::ElecPumpWear(id)),
         }
     }
 
@@ -2676,6 +3083,7 @@ impl ElectricPump {
         self.pump_physics
             .update(context, section, self.pump.displacement());
 
+        self.pump.update_wear(context, self.wear_failure.severity());
         self.pump.update(
             context,
             section,
@@ -2696,6 +3104,10 @@ impl ElectricPump {
     pub fn speed(&self) -> AngularVelocity {
         self.pump.speed
     }
+
+    pub fn wear(&self) -> Ratio {
+        self.pump.wear()
+    }
 }
 impl PressureSource for ElectricPump {
     fn delta_vol_max(&self) -> Volume {
@@ -2728,6 +3140,7 @@ impl PressureSource for ElectricPump {
 impl SimulationElement for ElectricPump {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.pump_physics.accept(visitor);
+        self.wear_failure.accept(visitor);
 
         visitor.visit(self);
     }
@@ -2759,6 +3172,7 @@ pub struct EngineDrivenPump {
     pump: Pump,
 
     overheat_failure: Failure,
+    wear_failure: Failure,
     heat_state: HeatingProperties,
 }
 impl EngineDrivenPump {
@@ -2783,6 +3197,7 @@ impl EngineDrivenPump {
             speed: AngularVelocity::new::<revolution_per_minute>(0.),
             pump: Pump::new(pump_characteristics),
             overheat_failure: Failure::new(FailureType::EnginePumpOverheat(id)),
+            wear_failure: Failure::new(FailureType::EnginePumpWear(id)),
             heat_state: HeatingProperties::new(
                 Duration::from_secs_f64(
                     random_from_normal_distribution(
@@ -2822,11 +3237,16 @@ impl EngineDrivenPump {
         };
         self.speed = self.speed.max(AngularVelocity::default());
 
+        self.pump.update_wear(context, self.wear_failure.severity());
         self.pump
             .update(context, section, reservoir, self.speed, controller);
 
         self.is_active = controller.should_pressurise();
     }
+
+    pub fn wear(&self) -> Ratio {
+        self.pump.wear()
+    }
 }
 impl PressureSource for EngineDrivenPump {
     fn delta_vol_max(&self) -> Volume {
@@ -2859,6 +3279,7 @@ impl PressureSource for EngineDrivenPump {
 impl SimulationElement for EngineDrivenPump {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.overheat_failure.accept(visitor);
+        self.wear_failure.accept(visitor);
         visitor.visit(self);
     }
 
@@ -3233,7 +3654,7 @@ impl SimulationElement for HydraulicValve {
 #[cfg(test)]
 mod tests {
     use crate::simulation::test::{
-        ElementCtorFn, ReadByName, SimulationTestBed, TestBed, WriteByName,
+        ElementCtorFn, ReadByName, SimulationTestBed, TestAircraft, TestBed, WriteByName,
     };
     use crate::simulation::InitContext;
     use ntest::assert_about_eq;
@@ -3242,25 +3663,16 @@ mod tests {
 
     use super::*;
 
-    struct TestFluid {
-        is_hot: bool,
+    fn nominal_fluid_temperature() -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(40.)
     }
-    impl TestFluid {
-        fn overheat() -> Self {
-            Self { is_hot: true }
-        }
 
-        fn nominal() -> Self {
-            Self { is_hot: false }
-        }
-    }
-    impl HeatingElement for TestFluid {
-        fn is_overheating(&self) -> bool {
-            self.is_hot
-        }
+    fn overheated_fluid_temperature() -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(120.)
     }
 
     impl SimulationElement for PriorityValve {}
+    impl SimulationElement for Fluid {}
 
     #[test]
     fn section_writes_its_state() {
@@ -3412,6 +3824,98 @@ mod tests {
         assert!(test_bed.query_element(|e| e.downstream_pressure() >= Pressure::new::<psi>(0.)));
     }
 
+    #[test]
+    fn section_without_external_leak_failure_does_not_leak() {
+        let mut test_bed = leaking_section_test_bed(true);
+        test_bed.command_element(|s| s.pressure = Pressure::new::<psi>(3000.));
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(test_bed.query_element(|s| s.leaked_volume) == Volume::default());
+    }
+
+    #[test]
+    fn section_external_leak_scales_with_line_pressure() {
+        let mut test_bed = leaking_section_test_bed(false);
+        test_bed.command_element(|s| s.pressure = Pressure::new::<psi>(3000.));
+        test_bed.fail(FailureType::HydraulicExternalLeak(
+            HydraulicColor::Green,
+            HydraulicLeakLocation::SystemSectionUpstreamPriorityValve,
+        ));
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        // 0.1 gal/s at the 3000 psi target pressure, proportional to the pressure above ambient
+        assert_about_eq!(
+            test_bed.query_element(|s| s.leak_flow.get::<gallon_per_second>()),
+            0.1 * (3000. - 14.7) / 3000.
+        );
+
+        test_bed.command_element(|s| s.pressure = Pressure::new::<psi>(1500.));
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert_about_eq!(
+            test_bed.query_element(|s| s.leak_flow.get::<gallon_per_second>()),
+            0.1 * (1500. - 14.7) / 3000.
+        );
+
+        test_bed.command_element(|s| s.pressure = Pressure::new::<psi>(14.7));
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert_about_eq!(
+            test_bed.query_element(|s| s.leak_flow.get::<gallon_per_second>()),
+            0.
+        );
+    }
+
+    #[test]
+    fn section_external_leak_upstream_priority_valve_leaks_with_closed_priority_valve() {
+        let mut test_bed = leaking_section_test_bed(false);
+        test_bed.command_element(|s| s.pressure = Pressure::new::<psi>(1400.));
+        test_bed.fail(FailureType::HydraulicExternalLeak(
+            HydraulicColor::Green,
+            HydraulicLeakLocation::SystemSectionUpstreamPriorityValve,
+        ));
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(test_bed.query_element(|s| s.leaked_volume) > Volume::new::<gallon>(0.4));
+    }
+
+    #[test]
+    fn section_external_leak_downstream_priority_valve_stops_with_closed_priority_valve() {
+        let mut test_bed = leaking_section_test_bed(true);
+        test_bed.command_element(|s| s.pressure = Pressure::new::<psi>(1400.));
+        test_bed.fail(FailureType::HydraulicExternalLeak(
+            HydraulicColor::Green,
+            HydraulicLeakLocation::SystemSectionDownstreamPriorityValve,
+        ));
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(test_bed.query_element(|s| s.leaked_volume) == Volume::default());
+    }
+
+    #[test]
+    fn section_external_leak_downstream_priority_valve_leaks_with_opened_priority_valve() {
+        let mut test_bed = leaking_section_test_bed(true);
+        test_bed.command_element(|s| s.pressure = Pressure::new::<psi>(3000.));
+        test_bed.fail(FailureType::HydraulicExternalLeak(
+            HydraulicColor::Green,
+            HydraulicLeakLocation::SystemSectionDownstreamPriorityValve,
+        ));
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        let fully_opened_leaked_volume = test_bed.query_element(|s| s.leaked_volume);
+        assert!(fully_opened_leaked_volume > Volume::new::<gallon>(0.9));
+
+        test_bed.command_element(|s| {
+            s.pressure = Pressure::new::<psi>(1800.);
+            s.leaked_volume = Volume::default();
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        let partially_opened_leaked_volume = test_bed.query_element(|s| s.leaked_volume);
+        assert!(partially_opened_leaked_volume > Volume::default());
+        assert!(partially_opened_leaked_volume < fully_opened_leaked_volume * 0.5);
+    }
+
     #[test]
     fn reservoir_reports_only_gaugeable_volume() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
@@ -3444,7 +3948,11 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_fluid_temperature(),
+            )
         });
 
         test_bed.fail(FailureType::ReservoirLeak(HydraulicColor::Green));
@@ -3454,6 +3962,37 @@ mod tests {
         assert!(volume_after_leak_gallon < 4.5);
     }
 
+    #[test]
+    fn reservoir_partially_leaking_loses_fluid_slower() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            reservoir(
+                context,
+                HydraulicColor::Green,
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(5.),
+            )
+        }));
+
+        test_bed.set_update_after_power_distribution(|reservoir, context| {
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_fluid_temperature(),
+            )
+        });
+
+        test_bed.fail_with_severity(
+            FailureType::ReservoirLeak(HydraulicColor::Green),
+            Ratio::new::<ratio>(0.25),
+        );
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        let volume_after_leak_gallon: f64 = test_bed.read_by_name("HYD_GREEN_RESERVOIR_LEVEL");
+        assert!(volume_after_leak_gallon > 4.6);
+        assert!(volume_after_leak_gallon < 4.9);
+    }
+
     #[test]
     fn reservoir_leaking_cant_go_lower_then_0() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
@@ -3467,7 +4006,11 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_fluid_temperature(),
+            )
         });
 
         test_bed.fail(FailureType::ReservoirLeak(HydraulicColor::Green));
@@ -3490,7 +4033,11 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_fluid_temperature(),
+            )
         });
 
         let is_low: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_LEVEL_IS_LOW");
@@ -3516,7 +4063,11 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_fluid_temperature(),
+            )
         });
 
         test_bed.run_multiple_frames(Duration::from_secs(2));
@@ -3543,7 +4094,11 @@ mod tests {
             )
         }))
         .with_update_after_power_distribution(|el, context| {
-            el.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+            el.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_fluid_temperature(),
+            )
         });
 
         test_bed.write_by_name("PLANE BANK DEGREES", 180.);
@@ -3599,16 +4154,18 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::overheat());
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                overheated_fluid_temperature(),
+            );
 
             reservoir.try_take_volume(Volume::new::<gallon>(0.10));
 
             reservoir.add_return_volume(Volume::new::<gallon>(0.10));
         });
 
-        test_bed.run_multiple_frames(Duration::from_secs_f64(
-            Reservoir::HEATING_TIME_CONSTANT_MEAN_S + 4. * Reservoir::HEATING_TIME_CONSTANT_STD_S,
-        ));
+        test_bed.run_multiple_frames(Reservoir::RETURN_MIXING_TIME_CONSTANT * 5);
 
         let is_overheating: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_OVHT");
         assert!(is_overheating);
@@ -3627,17 +4184,145 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::overheat());
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                overheated_fluid_temperature(),
+            );
         });
 
-        test_bed.run_multiple_frames(Duration::from_secs_f64(
-            Reservoir::HEATING_TIME_CONSTANT_MEAN_S + 4. * Reservoir::HEATING_TIME_CONSTANT_STD_S,
-        ));
+        test_bed.run_multiple_frames(Reservoir::RETURN_MIXING_TIME_CONSTANT * 5);
 
         let is_overheating: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_OVHT");
         assert!(!is_overheating);
     }
 
+    #[test]
+    fn reservoir_temperature_follows_returning_fluid_temperature() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            reservoir(
+                context,
+                HydraulicColor::Green,
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(2.),
+                Volume::new::<gallon>(5.),
+            )
+        }));
+
+        test_bed.set_update_after_power_distribution(|reservoir, context| {
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_fluid_temperature(),
+            );
+
+            reservoir.try_take_volume(Volume::new::<gallon>(0.10));
+
+            reservoir.add_return_volume(Volume::new::<gallon>(0.10));
+        });
+
+        test_bed.run_multiple_frames(Reservoir::RETURN_MIXING_TIME_CONSTANT * 5);
+
+        let temperature: f64 = test_bed.read_by_name("HYD_GREEN_RESERVOIR_TEMPERATURE");
+        assert!(temperature > 39. && temperature <= 40.);
+    }
+
+    #[test]
+    fn fluid_is_soaked_at_ambient_temperature() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|_| fluid()))
+            .with_update_after_power_distribution(|fluid, context| {
+                fluid.update(
+                    context,
+                    Power::new::<watt>(0.),
+                    Volume::new::<gallon>(10.),
+                    true,
+                )
+            });
+
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-20.));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert_about_eq!(
+            test_bed.query_element(|f| f.temperature().get::<degree_celsius>()),
+            -20.,
+            0.1
+        );
+    }
+
+    #[test]
+    fn fluid_heats_up_with_pump_work() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|_| fluid()))
+            .with_update_after_power_distribution(|fluid, context| {
+                fluid.update(
+                    context,
+                    Power::new::<watt>(20000.),
+                    Volume::new::<gallon>(10.),
+                    true,
+                )
+            });
+
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        test_bed.run_multiple_frames(Duration::from_secs(600));
+
+        assert!(test_bed.query_element(|f| f.temperature().get::<degree_celsius>()) > 40.);
+        assert!(!test_bed.query_element(|f| f.is_overheating()));
+    }
+
+    #[test]
+    fn fluid_overheats_without_fuel_heat_exchanger_flow() {
+        let mut cooled_test_bed = SimulationTestBed::from(ElementCtorFn(|_| fluid()))
+            .with_update_after_power_distribution(|fluid, context| {
+                fluid.update(
+                    context,
+                    Power::new::<watt>(20000.),
+                    Volume::new::<gallon>(10.),
+                    true,
+                )
+            });
+        let mut uncooled_test_bed = SimulationTestBed::from(ElementCtorFn(|_| fluid()))
+            .with_update_after_power_distribution(|fluid, context| {
+                fluid.update(
+                    context,
+                    Power::new::<watt>(20000.),
+                    Volume::new::<gallon>(10.),
+                    false,
+                )
+            });
+
+        cooled_test_bed
+            .set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        uncooled_test_bed
+            .set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        cooled_test_bed.run_multiple_frames(Duration::from_secs(1200));
+        uncooled_test_bed.run_multiple_frames(Duration::from_secs(1200));
+
+        assert!(!cooled_test_bed.query_element(|f| f.is_overheating()));
+        assert!(uncooled_test_bed.query_element(|f| f.is_overheating()));
+    }
+
+    #[test]
+    fn cold_fluid_is_more_viscous() {
+        assert_about_eq!(
+            Fluid::kinematic_viscosity_at(nominal_fluid_temperature()).get::<centistokes>(),
+            Fluid::NOMINAL_VISCOSITY_CENTISTOKES
+        );
+
+        assert!(
+            Fluid::kinematic_viscosity_at(ThermodynamicTemperature::new::<degree_celsius>(-40.))
+                .get::<centistokes>()
+                > 20. * Fluid::NOMINAL_VISCOSITY_CENTISTOKES
+        );
+
+        assert!(
+            Fluid::kinematic_viscosity_at(overheated_fluid_temperature()).get::<centistokes>()
+                < Fluid::NOMINAL_VISCOSITY_CENTISTOKES
+        );
+    }
+
+    fn fluid() -> Fluid {
+        Fluid::new(Pressure::new::<pascal>(1450000000.))
+    }
+
     fn section(
         context: &mut InitContext,
         loop_id: HydraulicColor,
@@ -3676,6 +4361,64 @@ mod tests {
         )
     }
 
+    struct TestLeakingSection {
+        section: Section,
+        pressure: Pressure,
+        leaked_volume: Volume,
+        leak_flow: VolumeRate,
+    }
+    impl TestLeakingSection {
+        fn new(context: &mut InitContext, is_downstream_priority_valve: bool) -> Self {
+            let location = if is_downstream_priority_valve {
+                HydraulicLeakLocation::SystemSectionDownstreamPriorityValve
+            } else {
+                HydraulicLeakLocation::SystemSectionUpstreamPriorityValve
+            };
+
+            Self {
+                section: section(context, HydraulicColor::Green, "SYSTEM", 1).with_external_leak(
+                    FailureType::HydraulicExternalLeak(HydraulicColor::Green, location),
+                    VolumeRate::new::<gallon_per_second>(0.1),
+                    is_downstream_priority_valve,
+                ),
+                pressure: Pressure::default(),
+                leaked_volume: Volume::default(),
+                leak_flow: VolumeRate::default(),
+            }
+        }
+
+        fn update(&mut self, context: &UpdateContext) {
+            self.section.current_pressure = self.pressure;
+            if let Some(priority_valve) = &mut self.section.priority_valve {
+                priority_valve.update(context, self.pressure);
+            }
+
+            let leaked_volume = self
+                .section
+                .external_leak(context, Pressure::new::<psi>(3000.));
+            self.leaked_volume += leaked_volume;
+            self.leak_flow = leaked_volume / context.delta_as_time();
+        }
+    }
+    impl SimulationElement for TestLeakingSection {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.section.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn leaking_section_test_bed(
+        is_downstream_priority_valve: bool,
+    ) -> SimulationTestBed<TestAircraft<TestLeakingSection>> {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(move |context| {
+            TestLeakingSection::new(context, is_downstream_priority_valve)
+        }));
+        test_bed.set_update_after_power_distribution(|section, context| section.update(context));
+
+        test_bed
+    }
+
     fn reservoir(
         context: &mut InitContext,
         hyd_loop_id: HydraulicColor,
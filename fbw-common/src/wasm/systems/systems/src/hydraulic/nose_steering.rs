@@ -304,9 +304,11 @@ mod tests {
 
     use super::*;
 
+    use crate::hydraulic::Fluid;
     use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
     use crate::simulation::{Aircraft, SimulationElement, SimulationElementVisitor};
     use std::time::Duration;
+    use uom::si::kinematic_viscosity::centistokes;
     use uom::si::{angle::degree, pressure::psi};
 
     struct TestPushBack {
@@ -384,6 +386,10 @@ mod tests {
         fn is_pressure_switch_pressurised(&self) -> bool {
             self.pressure.get::<psi>() > 1700.
         }
+
+        fn fluid_viscosity(&self) -> KinematicViscosity {
+            KinematicViscosity::new::<centistokes>(Fluid::NOMINAL_VISCOSITY_CENTISTOKES)
+        }
     }
 
     struct TestAircraft {
@@ -2,6 +2,7 @@ use std::time::Duration;
 use uom::si::{f64::*, ratio::ratio, volume::gallon};
 
 use crate::{
+    failures::{Failure, FailureType},
     shared::{
         low_pass_filter::LowPassFilter, random_from_normal_distribution, ElectricalBusType,
         ElectricalBuses, ReverserPosition,
@@ -284,13 +285,38 @@ impl SimulationElement for ReverserHydraulicManifold {
     }
 }
 
+/// Command seen by the reverser when its control chain fails to deploy position:
+/// tertiary lock released and valves commanded open whatever the controller asks.
+struct InadvertentDeploymentCommand;
+impl ReverserInterface for InadvertentDeploymentCommand {
+    fn should_unlock(&self) -> bool {
+        true
+    }
+
+    fn should_power_valves(&self) -> bool {
+        true
+    }
+
+    fn should_isolate_hydraulics(&self) -> bool {
+        false
+    }
+
+    fn should_deploy_reverser(&self) -> bool {
+        true
+    }
+}
+
 pub struct ReverserAssembly {
     electrical_lock: ElectricalLock,
     hydraulic_manifold: ReverserHydraulicManifold,
     actuator: ReverserActuator,
+
+    inadvertent_deployment_failure: Failure,
+    jammed_failure: Failure,
 }
 impl ReverserAssembly {
     pub fn new(
+        engine_number: usize,
         nominal_hydraulic_pressure: Pressure,
         switch_high_threshold_pressure: Pressure,
         switch_low_threshold_pressure: Pressure,
@@ -307,6 +333,11 @@ impl ReverserAssembly {
                 switch_low_threshold_pressure,
             ),
             actuator: ReverserActuator::new(nominal_hydraulic_pressure),
+
+            inadvertent_deployment_failure: Failure::new(
+                FailureType::ReverserInadvertentDeployment(engine_number),
+            ),
+            jammed_failure: Failure::new(FailureType::ReverserJammed(engine_number)),
         }
     }
 
@@ -315,6 +346,19 @@ impl ReverserAssembly {
         context: &UpdateContext,
         controller: &impl ReverserInterface,
         pressure: Pressure,
+    ) {
+        if self.inadvertent_deployment_failure.is_active() {
+            self.update_with_command(context, &InadvertentDeploymentCommand, pressure);
+        } else {
+            self.update_with_command(context, controller, pressure);
+        }
+    }
+
+    fn update_with_command(
+        &mut self,
+        context: &UpdateContext,
+        controller: &impl ReverserInterface,
+        pressure: Pressure,
     ) {
         self.electrical_lock
             .update(controller, self.reverser_position());
@@ -325,7 +369,7 @@ impl ReverserAssembly {
         self.actuator.update(
             context,
             self.hydraulic_manifold.actuator_pressure(),
-            self.electrical_lock.is_locked(),
+            self.electrical_lock.is_locked() || self.jammed_failure.is_active(),
         );
     }
 
@@ -369,6 +413,8 @@ impl SimulationElement for ReverserAssembly {
     fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
         self.electrical_lock.accept(visitor);
         self.hydraulic_manifold.accept(visitor);
+        self.inadvertent_deployment_failure.accept(visitor);
+        self.jammed_failure.accept(visitor);
 
         visitor.visit(self);
     }
@@ -459,6 +505,7 @@ mod tests {
                 controller: TestReverserController::default(),
 
                 reverser: ReverserAssembly::new(
+                    1,
                     Pressure::new::<psi>(3000.),
                     Pressure::new::<psi>(2100.),
                     Pressure::new::<psi>(1750.),
@@ -708,4 +755,62 @@ mod tests {
         assert!(test_bed.query(|a| a.reverser_position().get::<ratio>()) <= 0.01);
         assert!(test_bed.query(|a| a.reverser_is_locked()));
     }
+
+    #[test]
+    fn reverser_deploys_without_command_on_inadvertent_deployment_failure() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_hyd_pressure(Pressure::new::<psi>(3000.)));
+        test_bed.command(|a| a.set_ac_elec_power(true));
+        test_bed.command(|a| a.set_dc_elec_power(true));
+
+        test_bed.run_with_delta(Duration::from_millis(1000));
+        assert!(test_bed.query(|a| a.reverser_position().get::<ratio>()) == 0.);
+
+        test_bed.fail(FailureType::ReverserInadvertentDeployment(1));
+        test_bed.run_with_delta(Duration::from_millis(2500));
+
+        assert!(test_bed.query(|a| a.reverser_position().get::<ratio>()) >= 0.97);
+    }
+
+    #[test]
+    fn jammed_reverser_does_not_deploy_when_commanded() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.fail(FailureType::ReverserJammed(1));
+
+        test_bed.command(|a| a.set_hyd_pressure(Pressure::new::<psi>(3000.)));
+        test_bed.command(|a| a.set_ac_elec_power(true));
+        test_bed.command(|a| a.set_dc_elec_power(true));
+        test_bed.command(|a| a.set_isolation_valve(false));
+        test_bed.command(|a| a.set_deploy_reverser(true));
+        test_bed.command(|a| a.set_lock_reverser(false));
+
+        test_bed.run_with_delta(Duration::from_millis(2500));
+
+        assert!(test_bed.query(|a| a.reverser_manifold_pressure().get::<psi>()) >= 2800.);
+        assert!(test_bed.query(|a| a.reverser_position().get::<ratio>()) == 0.);
+    }
+
+    #[test]
+    fn jammed_reverser_stays_deployed_when_stow_commanded() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_hyd_pressure(Pressure::new::<psi>(3000.)));
+        test_bed.command(|a| a.set_ac_elec_power(true));
+        test_bed.command(|a| a.set_dc_elec_power(true));
+        test_bed.command(|a| a.set_isolation_valve(false));
+        test_bed.command(|a| a.set_deploy_reverser(true));
+        test_bed.command(|a| a.set_lock_reverser(false));
+
+        test_bed.run_with_delta(Duration::from_millis(2500));
+        assert!(test_bed.query(|a| a.reverser_position().get::<ratio>()) >= 0.97);
+
+        test_bed.fail(FailureType::ReverserJammed(1));
+        test_bed.command(|a| a.set_lock_reverser(true));
+        test_bed.command(|a| a.set_deploy_reverser(false));
+
+        test_bed.run_with_delta(Duration::from_millis(3000));
+        assert!(test_bed.query(|a| a.reverser_position().get::<ratio>()) >= 0.97);
+    }
 }
@@ -0,0 +1,466 @@
+use crate::{
+    shared::interpolation,
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, SurfaceTypeMsfs,
+        UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+use uom::si::{
+    acceleration::meter_per_second_squared, f64::*, length::millimeter, pressure::psi,
+    thermodynamic_temperature::degree_celsius, velocity::knot,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum RunwayContaminant {
+    Dry = 1,
+    Wet = 2,
+    StandingWater = 3,
+    Slush = 4,
+    Snow = 5,
+    Ice = 6,
+}
+impl RunwayContaminant {
+    fn from_selection(value: f64) -> Option<Self> {
+        match value as u8 {
+            1 => Some(RunwayContaminant::Dry),
+            2 => Some(RunwayContaminant::Wet),
+            3 => Some(RunwayContaminant::StandingWater),
+            4 => Some(RunwayContaminant::Slush),
+            5 => Some(RunwayContaminant::Snow),
+            6 => Some(RunwayContaminant::Ice),
+            _ => None,
+        }
+    }
+
+    /// Contaminant derived from the surface and the weather of the simulator
+    fn from_simulation(context: &UpdateContext) -> Self {
+        let is_freezing = context.ambient_temperature().get::<degree_celsius>() <= 0.;
+        let precipitation_rate = context.precipitation_rate().get::<millimeter>();
+
+        match context.surface_type() {
+            SurfaceTypeMsfs::Ice => RunwayContaminant::Ice,
+            SurfaceTypeMsfs::Snow => RunwayContaminant::Snow,
+            _ if precipitation_rate > TyreRunwayFriction::HEAVY_PRECIPITATION_MM && is_freezing => {
+                RunwayContaminant::Slush
+            }
+            _ if precipitation_rate > TyreRunwayFriction::HEAVY_PRECIPITATION_MM => {
+                RunwayContaminant::StandingWater
+            }
+            _ if precipitation_rate > TyreRunwayFriction::LIGHT_PRECIPITATION_MM && is_freezing => {
+                RunwayContaminant::Snow
+            }
+            _ if precipitation_rate > TyreRunwayFriction::LIGHT_PRECIPITATION_MM => {
+                RunwayContaminant::Wet
+            }
+            _ => RunwayContaminant::Dry,
+        }
+    }
+
+    /// Runway condition code (RWYCC) of the global reporting format.
+    /// Snow compacts and ice becomes wet depending on the outside air temperature.
+    pub fn runway_condition_code(&self, outside_air_temperature: ThermodynamicTemperature) -> u8 {
+        let oat = outside_air_temperature.get::<degree_celsius>();
+        match self {
+            RunwayContaminant::Dry => 6,
+            RunwayContaminant::Wet => 5,
+            RunwayContaminant::Snow if oat <= -15. => 4,
+            RunwayContaminant::Snow => 3,
+            RunwayContaminant::StandingWater | RunwayContaminant::Slush => 2,
+            RunwayContaminant::Ice if oat > -3. => 0,
+            RunwayContaminant::Ice => 1,
+        }
+    }
+
+    fn is_fluid(&self) -> bool {
+        matches!(
+            self,
+            RunwayContaminant::StandingWater | RunwayContaminant::Slush
+        )
+    }
+}
+
+/// Limits the friction the tyres can achieve on the runway depending on its condition.
+/// The braking force is expressed relative to the one achievable on a dry runway.
+pub struct TyreRunwayFriction {
+    contaminant_selected_id: VariableIdentifier,
+    contaminant_id: VariableIdentifier,
+    runway_condition_code_id: VariableIdentifier,
+    aquaplaning_id: VariableIdentifier,
+
+    contaminant_selected: Option<RunwayContaminant>,
+    contaminant: RunwayContaminant,
+    runway_condition_code: u8,
+
+    aquaplaning_speed: Velocity,
+    is_aquaplaning: bool,
+
+    friction_coefficient: f64,
+}
+impl TyreRunwayFriction {
+    const LIGHT_PRECIPITATION_MM: f64 = 0.1;
+    const HEAVY_PRECIPITATION_MM: f64 = 7.6;
+
+    // Braking friction coefficient per RWYCC, from 0 to 6
+    const RUNWAY_CONDITION_CODES: [f64; 7] = [0., 1., 2., 3., 4., 5., 6.];
+    const FRICTION_COEFFICIENT: [f64; 7] = [0.04, 0.08, 0.15, 0.2, 0.25, 0.35, 0.5];
+
+    // Friction of contaminated runways decreases with speed, as water can't escape the tyre footprint
+    const SPEED_BREAKPOINTS_KNOT: [f64; 2] = [0., 150.];
+    const CONTAMINATED_FRICTION_SPEED_FACTOR: [f64; 2] = [1., 0.7];
+
+    // Dynamic hydroplaning speed from NASA (Horne): V = 9 * sqrt(tyre pressure in psi)
+    const AQUAPLANING_SPEED_FACTOR: f64 = 9.;
+    const AQUAPLANING_FRICTION_COEFFICIENT: f64 = 0.05;
+
+    // Friction of a locked wheel sliding on the runway, relative to the maximum friction
+    const LOCKED_WHEEL_FRICTION_RATIO: f64 = 0.7;
+
+    const STANDARD_GRAVITY_MS2: f64 = 9.80665;
+
+    /// Creates the friction model for tyres inflated at the given pressure
+    pub fn new(context: &mut InitContext, tyre_pressure: Pressure) -> Self {
+        Self {
+            contaminant_selected_id: context
+                .get_identifier("RUNWAY_CONTAMINANT_SELECTED".to_owned()),
+            contaminant_id: context.get_identifier("RUNWAY_CONTAMINANT".to_owned()),
+            runway_condition_code_id: context.get_identifier("RUNWAY_CONDITION_CODE".to_owned()),
+            aquaplaning_id: context.get_identifier("TYRES_AQUAPLANING".to_owned()),
+
+            contaminant_selected: None,
+            contaminant: RunwayContaminant::Dry,
+            runway_condition_code: 6,
+
+            aquaplaning_speed: Velocity::new::<knot>(
+                Self::AQUAPLANING_SPEED_FACTOR * tyre_pressure.get::<psi>().sqrt(),
+            ),
+            is_aquaplaning: false,
+
+            friction_coefficient: Self::dry_friction_coefficient(),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.contaminant = self
+            .contaminant_selected
+            .unwrap_or_else(|| RunwayContaminant::from_simulation(context));
+        self.runway_condition_code = self
+            .contaminant
+            .runway_condition_code(context.ambient_temperature());
+
+        let ground_speed = context.ground_speed().abs();
+        self.is_aquaplaning = context.is_on_ground()
+            && self.contaminant.is_fluid()
+            && ground_speed > self.aquaplaning_speed;
+
+        self.friction_coefficient = if self.is_aquaplaning {
+            Self::AQUAPLANING_FRICTION_COEFFICIENT
+        } else {
+            let friction_coefficient = interpolation(
+                &Self::RUNWAY_CONDITION_CODES,
+                &Self::FRICTION_COEFFICIENT,
+                self.runway_condition_code as f64,
+            );
+
+            if self.contaminant == RunwayContaminant::Dry {
+                friction_coefficient
+            } else {
+                friction_coefficient
+                    * interpolation(
+                        &Self::SPEED_BREAKPOINTS_KNOT,
+                        &Self::CONTAMINATED_FRICTION_SPEED_FACTOR,
+                        ground_speed.get::<knot>(),
+                    )
+            }
+        };
+    }
+
+    fn dry_friction_coefficient() -> f64 {
+        Self::FRICTION_COEFFICIENT[Self::FRICTION_COEFFICIENT.len() - 1]
+    }
+
+    /// Braking force achievable by the tyres, relative to the one achievable on a dry runway
+    pub fn available_braking_force(&self) -> f64 {
+        self.friction_coefficient / Self::dry_friction_coefficient()
+    }
+
+    /// Actual braking force relative to the one achievable on a dry runway, given the demanded one.
+    /// Anti-skid releases the brakes to keep the wheels at maximum friction,
+    /// whereas without it the wheels lock and slide when the demand exceeds the available friction.
+    pub fn braking_force(&self, demanded_braking_force: f64, anti_skid_is_active: bool) -> f64 {
+        let available_braking_force = self.available_braking_force();
+
+        if demanded_braking_force <= available_braking_force {
+            demanded_braking_force
+        } else if anti_skid_is_active {
+            available_braking_force
+        } else {
+            available_braking_force * Self::LOCKED_WHEEL_FRICTION_RATIO
+        }
+    }
+
+    /// Maximum deceleration the wheel brakes can achieve on a contaminated runway.
+    /// A dry runway doesn't limit the deceleration targets the brakes are designed for.
+    pub fn max_braking_deceleration(&self) -> Option<Acceleration> {
+        (self.contaminant != RunwayContaminant::Dry).then(|| {
+            Acceleration::new::<meter_per_second_squared>(
+                -self.friction_coefficient * Self::STANDARD_GRAVITY_MS2,
+            )
+        })
+    }
+
+    pub fn contaminant(&self) -> RunwayContaminant {
+        self.contaminant
+    }
+
+    pub fn runway_condition_code(&self) -> u8 {
+        self.runway_condition_code
+    }
+
+    pub fn is_aquaplaning(&self) -> bool {
+        self.is_aquaplaning
+    }
+}
+impl SimulationElement for TyreRunwayFriction {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let selected: f64 = reader.read(&self.contaminant_selected_id);
+        self.contaminant_selected = RunwayContaminant::from_selection(selected);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.contaminant_id, self.contaminant as u8 as f64);
+        writer.write(
+            &self.runway_condition_code_id,
+            self.runway_condition_code as f64,
+        );
+        writer.write(&self.aquaplaning_id, self.is_aquaplaning);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::simulation::test::{
+        ElementCtorFn, SimulationTestBed, TestAircraft, TestBed, WriteByName,
+    };
+    use std::time::Duration;
+
+    fn test_bed() -> SimulationTestBed<TestAircraft<TyreRunwayFriction>> {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            TyreRunwayFriction::new(context, Pressure::new::<psi>(200.))
+        }))
+        .with_update_after_power_distribution(|e, context| e.update(context));
+        test_bed.set_on_ground(true);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+
+        test_bed
+    }
+
+    fn rolling_on(
+        test_bed: &mut SimulationTestBed<TestAircraft<TyreRunwayFriction>>,
+        contaminant: RunwayContaminant,
+        ground_speed: Velocity,
+    ) {
+        test_bed.write_by_name("RUNWAY_CONTAMINANT_SELECTED", contaminant as u8 as f64);
+        test_bed.write_by_name("GPS GROUND SPEED", ground_speed.get::<knot>());
+        test_bed.run_with_delta(Duration::from_millis(50));
+    }
+
+    #[test]
+    fn dry_runway_allows_full_braking() {
+        let mut test_bed = test_bed();
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::Dry,
+            Velocity::new::<knot>(130.),
+        );
+
+        assert!(test_bed.query_element(|e| e.runway_condition_code()) == 6);
+        assert!(test_bed.query_element(|e| e.braking_force(1., true)) == 1.);
+    }
+
+    #[test]
+    fn contaminated_runway_limits_braking_force() {
+        let mut test_bed = test_bed();
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::Wet,
+            Velocity::new::<knot>(130.),
+        );
+        let wet_braking_force = test_bed.query_element(|e| e.braking_force(1., true));
+
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::Snow,
+            Velocity::new::<knot>(130.),
+        );
+        let snow_braking_force = test_bed.query_element(|e| e.braking_force(1., true));
+
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::Ice,
+            Velocity::new::<knot>(130.),
+        );
+        let ice_braking_force = test_bed.query_element(|e| e.braking_force(1., true));
+
+        assert!(wet_braking_force < 1.);
+        assert!(snow_braking_force < wet_braking_force);
+        assert!(ice_braking_force < snow_braking_force);
+    }
+
+    #[test]
+    fn low_braking_demand_is_not_limited_on_contaminated_runway() {
+        let mut test_bed = test_bed();
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::Wet,
+            Velocity::new::<knot>(130.),
+        );
+
+        assert!(test_bed.query_element(|e| e.braking_force(0.2, true)) == 0.2);
+    }
+
+    #[test]
+    fn wet_runway_friction_increases_when_slowing_down() {
+        let mut test_bed = test_bed();
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::Wet,
+            Velocity::new::<knot>(130.),
+        );
+        let high_speed_braking_force = test_bed.query_element(|e| e.available_braking_force());
+
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::Wet,
+            Velocity::new::<knot>(30.),
+        );
+        let low_speed_braking_force = test_bed.query_element(|e| e.available_braking_force());
+
+        assert!(low_speed_braking_force > high_speed_braking_force);
+    }
+
+    #[test]
+    fn locked_wheels_brake_less_than_anti_skid() {
+        let mut test_bed = test_bed();
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::Wet,
+            Velocity::new::<knot>(130.),
+        );
+
+        assert!(
+            test_bed.query_element(|e| e.braking_force(1., false))
+                < test_bed.query_element(|e| e.braking_force(1., true))
+        );
+    }
+
+    #[test]
+    fn aquaplaning_above_speed_threshold_on_standing_water() {
+        let mut test_bed = test_bed();
+
+        // 9 * sqrt(200 psi) = 127 kt
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::StandingWater,
+            Velocity::new::<knot>(135.),
+        );
+        assert!(test_bed.query_element(|e| e.is_aquaplaning()));
+        let aquaplaning_braking_force = test_bed.query_element(|e| e.available_braking_force());
+
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::StandingWater,
+            Velocity::new::<knot>(115.),
+        );
+        assert!(!test_bed.query_element(|e| e.is_aquaplaning()));
+        assert!(
+            test_bed.query_element(|e| e.available_braking_force()) > aquaplaning_braking_force
+        );
+    }
+
+    #[test]
+    fn no_aquaplaning_on_wet_runway() {
+        let mut test_bed = test_bed();
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::Wet,
+            Velocity::new::<knot>(150.),
+        );
+
+        assert!(!test_bed.query_element(|e| e.is_aquaplaning()));
+    }
+
+    #[test]
+    fn ice_becomes_wet_ice_near_freezing() {
+        let mut test_bed = test_bed();
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-1.));
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::Ice,
+            Velocity::new::<knot>(50.),
+        );
+        assert!(test_bed.query_element(|e| e.runway_condition_code()) == 0);
+
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-10.));
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::Ice,
+            Velocity::new::<knot>(50.),
+        );
+        assert!(test_bed.query_element(|e| e.runway_condition_code()) == 1);
+    }
+
+    #[test]
+    fn contaminant_is_derived_from_weather_when_none_selected() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("AMBIENT PRECIP RATE", 2.);
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        assert!(test_bed.query_element(|e| e.contaminant()) == RunwayContaminant::Wet);
+
+        test_bed.write_by_name("AMBIENT PRECIP RATE", 0.);
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        assert!(test_bed.query_element(|e| e.contaminant()) == RunwayContaminant::Dry);
+    }
+
+    #[test]
+    fn max_braking_deceleration_is_not_limited_on_dry_runway() {
+        let mut test_bed = test_bed();
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::Dry,
+            Velocity::new::<knot>(100.),
+        );
+
+        assert!(test_bed
+            .query_element(|e| e.max_braking_deceleration())
+            .is_none());
+    }
+
+    #[test]
+    fn max_braking_deceleration_is_lower_on_ice() {
+        let mut test_bed = test_bed();
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::Wet,
+            Velocity::new::<knot>(100.),
+        );
+        let wet_deceleration = test_bed
+            .query_element(|e| e.max_braking_deceleration())
+            .unwrap();
+
+        rolling_on(
+            &mut test_bed,
+            RunwayContaminant::Ice,
+            Velocity::new::<knot>(100.),
+        );
+        let ice_deceleration = test_bed
+            .query_element(|e| e.max_braking_deceleration())
+            .unwrap();
+
+        assert!(ice_deceleration > wet_deceleration);
+    }
+}
@@ -42,7 +42,7 @@ impl CursorControlDevice {
         }
     }
 
-    pub fn update(&self, can_buses: &mut [CanBus<5>; 2]) {
+    pub fn update<const N: usize>(&self, can_buses: &mut [CanBus<N>; 2]) {
         // mark messages as received
         can_buses[0].received_message(self.function_id);
         can_buses[1].received_message(self.function_id);
@@ -0,0 +1,297 @@
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{
+        can_bus::CanBus, power_supply_relay::PowerSupplyRelay, ConsumePower, ElectricalBusType,
+    },
+    simulation::{
+        InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorWriter,
+        UpdateContext, VariableIdentifier, Write, Writer,
+    },
+};
+use uom::si::{f64::*, power::watt};
+
+/// The format which is shown by a display unit.
+/// The format is selected by the display system and depends on the reconfiguration state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisplayUnitFormat {
+    None = 0,
+    PrimaryFlightDisplay = 1,
+    NavigationDisplay = 2,
+    MultiFunctionDisplay = 3,
+    EngineWarningDisplay = 4,
+    SystemDisplay = 5,
+}
+read_write_enum!(DisplayUnitFormat);
+impl From<f64> for DisplayUnitFormat {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            1 => DisplayUnitFormat::PrimaryFlightDisplay,
+            2 => DisplayUnitFormat::NavigationDisplay,
+            3 => DisplayUnitFormat::MultiFunctionDisplay,
+            4 => DisplayUnitFormat::EngineWarningDisplay,
+            5 => DisplayUnitFormat::SystemDisplay,
+            _ => DisplayUnitFormat::None,
+        }
+    }
+}
+
+/*
+ * A display unit (DU) of the control and display system.
+ * The DU is supplied by a primary and a fallback source and is attached to the CAN buses of its side.
+ * The DU takes the frames off the bus and the hosted application reads the latched bus word.
+ * The system decides which format is shown on the DU, depending on the availability of all units.
+ */
+pub struct DisplayUnit {
+    available_id: VariableIdentifier,
+    format_id: VariableIdentifier,
+    power_supply: PowerSupplyRelay,
+    failure: Failure,
+    function_id: u8,
+    format: DisplayUnitFormat,
+}
+
+impl DisplayUnit {
+    const POWER_CONSUMPTION_WATT: f64 = 90.;
+
+    pub fn new(
+        context: &mut InitContext,
+        name: &str,
+        number: usize,
+        function_id: u8,
+        primary_power_supply: ElectricalBusType,
+        fallback_power_supply: ElectricalBusType,
+    ) -> Self {
+        Self {
+            available_id: context.get_identifier(format!("CDS_DU_{}_AVAIL", name)),
+            format_id: context.get_identifier(format!("CDS_DU_{}_FORMAT", name)),
+            power_supply: PowerSupplyRelay::new(primary_power_supply, fallback_power_supply),
+            failure: Failure::new(FailureType::DisplayUnit(number)),
+            function_id,
+            format: DisplayUnitFormat::None,
+        }
+    }
+
+    pub fn update(&mut self) {
+        self.power_supply.update();
+
+        if !self.is_operative() {
+            self.format = DisplayUnitFormat::None;
+        }
+    }
+
+    pub fn consume_messages<const N: usize>(&self, can_bus: &mut CanBus<N>) {
+        // mark messages as received
+        can_bus.received_message(self.function_id);
+    }
+
+    pub fn is_operative(&self) -> bool {
+        self.power_supply.output_is_powered() && !self.failure.is_active()
+    }
+
+    pub fn function_id(&self) -> u8 {
+        self.function_id
+    }
+
+    pub fn format(&self) -> DisplayUnitFormat {
+        self.format
+    }
+
+    pub fn show(&mut self, format: DisplayUnitFormat) {
+        self.format = if self.is_operative() {
+            format
+        } else {
+            DisplayUnitFormat::None
+        };
+    }
+}
+
+impl SimulationElement for DisplayUnit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.power_supply.accept(visitor);
+        self.failure.accept(visitor);
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.available_id, self.is_operative());
+        writer.write(&self.format_id, self.format);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if let Some(bus) = self.power_supply.output_source() {
+            if !self.failure.is_active() {
+                consumption.consume_from_bus(bus, Power::new::<watt>(Self::POWER_CONSUMPTION_WATT));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::{PotentialOrigin, PowerConsumptionReport},
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed},
+            Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
+        },
+    };
+    use uom::si::electric_potential::volt;
+
+    struct DisplayUnitTestAircraft {
+        display_unit: DisplayUnit,
+        powered_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        dc_2_bus: ElectricalBus,
+        dc_1_powered: bool,
+        dc_2_powered: bool,
+        power_consumption: Power,
+    }
+    impl DisplayUnitTestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                display_unit: DisplayUnit::new(
+                    context,
+                    "TEST",
+                    1,
+                    44,
+                    ElectricalBusType::DirectCurrent(1),
+                    ElectricalBusType::DirectCurrent(2),
+                ),
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                dc_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+                dc_1_powered: true,
+                dc_2_powered: true,
+                power_consumption: Power::default(),
+            }
+        }
+
+        fn set_dc_1_powered(&mut self, powered: bool) {
+            self.dc_1_powered = powered;
+        }
+
+        fn set_dc_2_powered(&mut self, powered: bool) {
+            self.dc_2_powered = powered;
+        }
+
+        fn show(&mut self, format: DisplayUnitFormat) {
+            self.display_unit.show(format);
+        }
+    }
+    impl Aircraft for DisplayUnitTestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.powered_source
+                .power_with_potential(ElectricPotential::new::<volt>(28.));
+            electricity.supplied_by(&self.powered_source);
+
+            if self.dc_1_powered {
+                electricity.flow(&self.powered_source, &self.dc_1_bus);
+            }
+            if self.dc_2_powered {
+                electricity.flow(&self.powered_source, &self.dc_2_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
+            self.display_unit.update();
+        }
+    }
+    impl SimulationElement for DisplayUnitTestAircraft {
+        fn process_power_consumption_report<T: PowerConsumptionReport>(
+            &mut self,
+            _: &UpdateContext,
+            report: &T,
+        ) {
+            self.power_consumption = report.total_consumption_of(PotentialOrigin::Battery(1));
+        }
+
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.display_unit.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    #[test]
+    fn display_unit_is_available_when_powered() {
+        let mut test_bed = SimulationTestBed::new(DisplayUnitTestAircraft::new);
+        test_bed.run();
+
+        let available: bool = test_bed.read_by_name("CDS_DU_TEST_AVAIL");
+        assert!(available);
+    }
+
+    #[test]
+    fn display_unit_uses_fallback_supply() {
+        let mut test_bed = SimulationTestBed::new(DisplayUnitTestAircraft::new);
+        test_bed.command(|a| a.set_dc_1_powered(false));
+        test_bed.run();
+        test_bed.run();
+
+        let available: bool = test_bed.read_by_name("CDS_DU_TEST_AVAIL");
+        assert!(available);
+    }
+
+    #[test]
+    fn display_unit_is_unavailable_without_power() {
+        let mut test_bed = SimulationTestBed::new(DisplayUnitTestAircraft::new);
+        test_bed.command(|a| {
+            a.set_dc_1_powered(false);
+            a.set_dc_2_powered(false);
+        });
+        test_bed.run();
+        test_bed.run();
+
+        let available: bool = test_bed.read_by_name("CDS_DU_TEST_AVAIL");
+        assert!(!available);
+    }
+
+    #[test]
+    fn failed_display_unit_shows_no_format() {
+        let mut test_bed = SimulationTestBed::new(DisplayUnitTestAircraft::new);
+        test_bed.run();
+        test_bed.command(|a| a.show(DisplayUnitFormat::PrimaryFlightDisplay));
+        test_bed.run();
+
+        let format: DisplayUnitFormat = test_bed.read_by_name("CDS_DU_TEST_FORMAT");
+        assert_eq!(format, DisplayUnitFormat::PrimaryFlightDisplay);
+
+        test_bed.fail(FailureType::DisplayUnit(1));
+        test_bed.run();
+
+        let available: bool = test_bed.read_by_name("CDS_DU_TEST_AVAIL");
+        assert!(!available);
+        let format: DisplayUnitFormat = test_bed.read_by_name("CDS_DU_TEST_FORMAT");
+        assert_eq!(format, DisplayUnitFormat::None);
+    }
+
+    #[test]
+    fn display_unit_draws_power_when_powered() {
+        let mut test_bed = SimulationTestBed::new(DisplayUnitTestAircraft::new);
+        test_bed.run();
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.power_consumption) > Power::new::<watt>(0.));
+    }
+
+    #[test]
+    fn failed_display_unit_draws_no_power() {
+        let mut test_bed = SimulationTestBed::new(DisplayUnitTestAircraft::new);
+        test_bed.fail(FailureType::DisplayUnit(1));
+        test_bed.run();
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.power_consumption),
+            Power::new::<watt>(0.)
+        );
+    }
+}
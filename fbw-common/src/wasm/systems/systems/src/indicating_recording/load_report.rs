@@ -0,0 +1,881 @@
+use std::{collections::VecDeque, time::Duration};
+
+use uom::si::{
+    f64::*,
+    mass::kilogram,
+    velocity::{foot_per_second, knot},
+};
+
+use crate::{
+    airframe::WeightData,
+    shared::{LgciuGearExtension, SurfacesPositions},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+/// Structural limits of an aircraft, used to detect exceedances requiring a maintenance inspection.
+pub struct StructuralLimits {
+    pub maximum_landing_weight_kg: f64,
+    /// Sink rate (ft/s) and load factor (g) above which a landing is hard.
+    pub hard_landing: (f64, f64),
+    /// Sink rate (ft/s) and load factor (g) above which a landing is severe hard.
+    pub severe_hard_landing: (f64, f64),
+    /// Sink rate (ft/s) and load factor (g) above which a landing above the maximum landing weight is hard.
+    pub overweight_hard_landing: (f64, f64),
+    /// Pairs of minimum flaps position and VFE (kt), ordered by flaps position.
+    pub flaps_extended_speeds_kt: &'static [(f64, f64)],
+    pub gear_extended_speed_kt: f64,
+    /// Minimum and maximum load factor (g) with flaps retracted.
+    pub clean_load_factor: (f64, f64),
+    /// Minimum and maximum load factor (g) with flaps extended.
+    pub flaps_extended_load_factor: (f64, f64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoadReportType {
+    HardLanding = 1,
+    SevereHardLanding = 2,
+    OverweightLanding = 3,
+    FlapsOverspeed = 4,
+    GearOverspeed = 5,
+    TurbulenceOverload = 6,
+}
+impl LoadReportType {
+    fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(LoadReportType::HardLanding),
+            2 => Some(LoadReportType::SevereHardLanding),
+            3 => Some(LoadReportType::OverweightLanding),
+            4 => Some(LoadReportType::FlapsOverspeed),
+            5 => Some(LoadReportType::GearOverspeed),
+            6 => Some(LoadReportType::TurbulenceOverload),
+            _ => None,
+        }
+    }
+}
+
+/// A load report (load <15>) recording a structural exceedance for maintenance.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LoadReport {
+    /// Number of the report since power up, restored reports are numbered when they are restored.
+    number: usize,
+    report_type: LoadReportType,
+    sink_rate: Velocity,
+    load_factor: f64,
+    gross_weight: Mass,
+    indicated_airspeed: Velocity,
+}
+impl LoadReport {
+    pub fn report_type(&self) -> LoadReportType {
+        self.report_type
+    }
+
+    /// Sink rate at touchdown, zero for in flight reports.
+    pub fn sink_rate(&self) -> Velocity {
+        self.sink_rate
+    }
+
+    /// Peak load factor during the event.
+    pub fn load_factor(&self) -> f64 {
+        self.load_factor
+    }
+
+    pub fn gross_weight(&self) -> Mass {
+        self.gross_weight
+    }
+
+    /// Peak indicated airspeed during the event.
+    pub fn indicated_airspeed(&self) -> Velocity {
+        self.indicated_airspeed
+    }
+
+    fn update_peaks(&mut self, load_factor: f64, indicated_airspeed: Velocity) {
+        if (load_factor - 1.).abs() > (self.load_factor - 1.).abs() {
+            self.load_factor = load_factor;
+        }
+        self.indicated_airspeed = self.indicated_airspeed.max(indicated_airspeed);
+    }
+}
+
+struct LoadReportIdentifiers {
+    type_id: VariableIdentifier,
+    sink_rate_id: VariableIdentifier,
+    load_factor_id: VariableIdentifier,
+    gross_weight_id: VariableIdentifier,
+    indicated_airspeed_id: VariableIdentifier,
+}
+impl LoadReportIdentifiers {
+    fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            type_id: context.get_identifier(format!("LOAD_REPORT_{}_TYPE", number)),
+            sink_rate_id: context.get_identifier(format!("LOAD_REPORT_{}_SINK_RATE", number)),
+            load_factor_id: context.get_identifier(format!("LOAD_REPORT_{}_LOAD_FACTOR", number)),
+            gross_weight_id: context.get_identifier(format!("LOAD_REPORT_{}_GW", number)),
+            indicated_airspeed_id: context.get_identifier(format!("LOAD_REPORT_{}_IAS", number)),
+        }
+    }
+
+    fn read(&self, reader: &mut SimulatorReader) -> Option<LoadReport> {
+        let report_type = LoadReportType::from_code(reader.read(&self.type_id))?;
+        let sink_rate: f64 = reader.read(&self.sink_rate_id);
+        let gross_weight: f64 = reader.read(&self.gross_weight_id);
+        let indicated_airspeed: f64 = reader.read(&self.indicated_airspeed_id);
+
+        Some(LoadReport {
+            number: 0,
+            report_type,
+            sink_rate: Velocity::new::<foot_per_second>(sink_rate),
+            load_factor: reader.read(&self.load_factor_id),
+            gross_weight: Mass::new::<kilogram>(gross_weight),
+            indicated_airspeed: Velocity::new::<knot>(indicated_airspeed),
+        })
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter, report: Option<&LoadReport>) {
+        writer.write(
+            &self.type_id,
+            report.map_or(0, |report| report.report_type as u8),
+        );
+        writer.write(
+            &self.sink_rate_id,
+            report.map_or(0., |report| report.sink_rate.get::<foot_per_second>()),
+        );
+        writer.write(
+            &self.load_factor_id,
+            report.map_or(0., |report| report.load_factor),
+        );
+        writer.write(
+            &self.gross_weight_id,
+            report.map_or(0., |report| report.gross_weight.get::<kilogram>()),
+        );
+        writer.write(
+            &self.indicated_airspeed_id,
+            report.map_or(0., |report| report.indicated_airspeed.get::<knot>()),
+        );
+    }
+}
+
+/// Monitors the structural loads and keeps the load reports of exceedances until they are
+/// cleared by maintenance. The most recent report is number 1. The reports are persisted by the
+/// EFB, which writes them back into the report variables and sets `LOAD_REPORT_RESTORE` when the
+/// aircraft is loaded.
+pub struct StructuralLoadMonitor {
+    report_count_id: VariableIdentifier,
+    clear_reports_id: VariableIdentifier,
+    restore_reports_id: VariableIdentifier,
+    report_ids: Vec<LoadReportIdentifiers>,
+
+    limits: &'static StructuralLimits,
+
+    reports: VecDeque<LoadReport>,
+    report_count: usize,
+    clear_reports: bool,
+
+    was_airborne: bool,
+    sink_rate: Velocity,
+    touchdown: Option<(Duration, LoadReport)>,
+    flaps_overspeed: Option<usize>,
+    gear_overspeed: Option<usize>,
+    turbulence_overload: Option<usize>,
+}
+impl StructuralLoadMonitor {
+    const MAX_REPORTS: usize = 10;
+    const TOUCHDOWN_RECORDING_TIME: Duration = Duration::from_secs(2);
+    const FLAPS_RETRACTED_POSITION: f64 = 0.02;
+
+    pub fn new(context: &mut InitContext, limits: &'static StructuralLimits) -> Self {
+        Self {
+            report_count_id: context.get_identifier("LOAD_REPORT_COUNT".to_owned()),
+            clear_reports_id: context.get_identifier("LOAD_REPORT_CLEAR".to_owned()),
+            restore_reports_id: context.get_identifier("LOAD_REPORT_RESTORE".to_owned()),
+            report_ids: (1..=Self::MAX_REPORTS)
+                .map(|number| LoadReportIdentifiers::new(context, number))
+                .collect(),
+
+            limits,
+
+            reports: VecDeque::with_capacity(Self::MAX_REPORTS),
+            report_count: 0,
+            clear_reports: false,
+
+            was_airborne: false,
+            sink_rate: Velocity::default(),
+            touchdown: None,
+            flaps_overspeed: None,
+            gear_overspeed: None,
+            turbulence_overload: None,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        weight: &WeightData,
+        surfaces_positions: &impl SurfacesPositions,
+        gear: &impl LgciuGearExtension,
+    ) {
+        if self.clear_reports {
+            self.reports.clear();
+            self.clear_reports = false;
+        }
+
+        let load_factor = Self::load_factor(context);
+        let gross_weight = weight.gross_weight();
+        let indicated_airspeed = context.indicated_airspeed();
+        let flaps_position = surfaces_positions
+            .left_flaps_position()
+            .max(surfaces_positions.right_flaps_position());
+
+        if context.is_on_ground() {
+            // A bounce is recorded as part of the first touchdown
+            if self.was_airborne && self.touchdown.is_none() {
+                self.touchdown = Some((
+                    Duration::ZERO,
+                    LoadReport {
+                        number: 0,
+                        report_type: LoadReportType::HardLanding,
+                        sink_rate: self.sink_rate,
+                        load_factor,
+                        gross_weight,
+                        indicated_airspeed,
+                    },
+                ));
+            }
+            self.update_touchdown(context, load_factor);
+            self.was_airborne = false;
+        } else {
+            self.was_airborne = true;
+            self.sink_rate = -context.local_velocity().vert_velocity();
+
+            let (min_load_factor, max_load_factor) =
+                if flaps_position > Self::FLAPS_RETRACTED_POSITION {
+                    self.limits.flaps_extended_load_factor
+                } else {
+                    self.limits.clean_load_factor
+                };
+            self.turbulence_overload = self.update_exceedance(
+                self.turbulence_overload,
+                load_factor < min_load_factor || load_factor > max_load_factor,
+                LoadReportType::TurbulenceOverload,
+                load_factor,
+                gross_weight,
+                indicated_airspeed,
+            );
+        }
+
+        let flaps_extended_speed = self
+            .limits
+            .flaps_extended_speeds_kt
+            .iter()
+            .rev()
+            .find(|(position, _)| flaps_position >= *position)
+            .map(|(_, speed)| Velocity::new::<knot>(*speed));
+        self.flaps_overspeed = self.update_exceedance(
+            self.flaps_overspeed,
+            flaps_extended_speed.is_some_and(|speed| indicated_airspeed > speed),
+            LoadReportType::FlapsOverspeed,
+            load_factor,
+            gross_weight,
+            indicated_airspeed,
+        );
+        self.gear_overspeed = self.update_exceedance(
+            self.gear_overspeed,
+            !gear.all_up_and_locked()
+                && indicated_airspeed > Velocity::new::<knot>(self.limits.gear_extended_speed_kt),
+            LoadReportType::GearOverspeed,
+            load_factor,
+            gross_weight,
+            indicated_airspeed,
+        );
+    }
+
+    /// The load factor is only recorded after the touchdown, as the peak load of the impact
+    /// is reached during the main gear compression.
+    fn update_touchdown(&mut self, context: &UpdateContext, load_factor: f64) {
+        let Some((time, mut report)) = self.touchdown.take() else {
+            return;
+        };
+
+        report.load_factor = report.load_factor.max(load_factor);
+        let time = time + context.delta();
+        if time < Self::TOUCHDOWN_RECORDING_TIME {
+            self.touchdown = Some((time, report));
+            return;
+        }
+
+        let sink_rate = report.sink_rate.get::<foot_per_second>();
+        let exceeds = |(limit_sink_rate, limit_load_factor): (f64, f64)| {
+            sink_rate >= limit_sink_rate || report.load_factor >= limit_load_factor
+        };
+        let overweight =
+            report.gross_weight > Mass::new::<kilogram>(self.limits.maximum_landing_weight_kg);
+
+        let report_type = if exceeds(self.limits.severe_hard_landing) {
+            Some(LoadReportType::SevereHardLanding)
+        } else if exceeds(self.limits.hard_landing)
+            || (overweight && exceeds(self.limits.overweight_hard_landing))
+        {
+            Some(LoadReportType::HardLanding)
+        } else if overweight {
+            Some(LoadReportType::OverweightLanding)
+        } else {
+            None
+        };
+
+        if let Some(report_type) = report_type {
+            report.report_type = report_type;
+            self.add_report(report);
+        }
+    }
+
+    /// Opens a report when an exceedance starts and records the peak values into it until the
+    /// exceedance ends. Returns the number of the open report, which is still valid when the
+    /// report has been cleared or pushed out by newer reports in the meantime.
+    fn update_exceedance(
+        &mut self,
+        open_report: Option<usize>,
+        is_exceeded: bool,
+        report_type: LoadReportType,
+        load_factor: f64,
+        gross_weight: Mass,
+        indicated_airspeed: Velocity,
+    ) -> Option<usize> {
+        if !is_exceeded {
+            return None;
+        }
+
+        match open_report {
+            Some(number) => {
+                if let Some(report) = self
+                    .reports
+                    .iter_mut()
+                    .find(|report| report.number == number)
+                {
+                    report.update_peaks(load_factor, indicated_airspeed);
+                }
+                Some(number)
+            }
+            None => {
+                self.add_report(LoadReport {
+                    number: 0,
+                    report_type,
+                    sink_rate: Velocity::default(),
+                    load_factor,
+                    gross_weight,
+                    indicated_airspeed,
+                });
+                Some(self.report_count)
+            }
+        }
+    }
+
+    fn add_report(&mut self, report: LoadReport) {
+        self.report_count += 1;
+        self.reports.truncate(Self::MAX_REPORTS - 1);
+        self.reports.push_front(LoadReport {
+            number: self.report_count,
+            ..report
+        });
+    }
+
+    fn load_factor(context: &UpdateContext) -> f64 {
+        -context.acceleration_plane_reference_unfiltered_ms2_vector()[1] / 9.8
+    }
+
+    pub fn reports(&self) -> impl Iterator<Item = &LoadReport> {
+        self.reports.iter()
+    }
+
+    /// The number of reports generated or restored since power up, including cleared ones.
+    pub fn report_count(&self) -> usize {
+        self.report_count
+    }
+
+    /// Replaces the reports with the restored ones, which are numbered after the existing reports
+    /// so that the numbers stay unique. An exceedance open at this time is reported again as it
+    /// continues, as its report may not be part of the restored ones.
+    fn restore_reports(&mut self, restored: Vec<LoadReport>) {
+        let first_number = self.report_count + restored.len();
+        self.report_count = first_number;
+        self.reports = restored
+            .into_iter()
+            .enumerate()
+            .map(|(i, report)| LoadReport {
+                number: first_number - i,
+                ..report
+            })
+            .collect();
+
+        self.flaps_overspeed = None;
+        self.gear_overspeed = None;
+        self.turbulence_overload = None;
+    }
+}
+impl SimulationElement for StructuralLoadMonitor {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.clear_reports = reader.read(&self.clear_reports_id);
+
+        let restore_reports: bool = reader.read(&self.restore_reports_id);
+        if restore_reports {
+            let restored = self
+                .report_ids
+                .iter()
+                .map_while(|ids| ids.read(reader))
+                .collect();
+            self.restore_reports(restored);
+        }
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.report_count_id, self.report_count as f64);
+        writer.write(&self.clear_reports_id, self.clear_reports);
+        writer.write(&self.restore_reports_id, false);
+        for (number, ids) in self.report_ids.iter().enumerate() {
+            ids.write(writer, self.reports.get(number));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::Electricity,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft, SimulationElementVisitor,
+        },
+    };
+    use uom::si::acceleration::meter_per_second_squared;
+
+    static LIMITS: StructuralLimits = StructuralLimits {
+        maximum_landing_weight_kg: 64500.,
+        hard_landing: (10., 2.6),
+        severe_hard_landing: (14., 2.86),
+        overweight_hard_landing: (6., 1.7),
+        flaps_extended_speeds_kt: &[(0.02, 215.), (0.4, 200.), (0.7, 185.), (0.95, 177.)],
+        gear_extended_speed_kt: 280.,
+        clean_load_factor: (-1., 2.5),
+        flaps_extended_load_factor: (0., 2.),
+    };
+
+    struct TestSurfaces {
+        flaps: f64,
+    }
+    impl SurfacesPositions for TestSurfaces {
+        fn left_spoilers_positions(&self) -> &[f64] {
+            &[]
+        }
+
+        fn right_spoilers_positions(&self) -> &[f64] {
+            &[]
+        }
+
+        fn left_ailerons_positions(&self) -> &[f64] {
+            &[]
+        }
+
+        fn right_ailerons_positions(&self) -> &[f64] {
+            &[]
+        }
+
+        fn left_flaps_position(&self) -> f64 {
+            self.flaps
+        }
+
+        fn right_flaps_position(&self) -> f64 {
+            self.flaps
+        }
+    }
+
+    struct TestGear {
+        down: bool,
+    }
+    impl LgciuGearExtension for TestGear {
+        fn all_down_and_locked(&self) -> bool {
+            self.down
+        }
+
+        fn all_up_and_locked(&self) -> bool {
+            !self.down
+        }
+
+        fn main_down_and_locked(&self) -> bool {
+            self.down
+        }
+
+        fn main_up_and_locked(&self) -> bool {
+            !self.down
+        }
+
+        fn nose_down_and_locked(&self) -> bool {
+            self.down
+        }
+
+        fn nose_up_and_locked(&self) -> bool {
+            !self.down
+        }
+
+        fn left_down_and_locked(&self) -> bool {
+            self.down
+        }
+    }
+
+    struct TestAircraft {
+        monitor: StructuralLoadMonitor,
+        weight: WeightData,
+        surfaces: TestSurfaces,
+        gear: TestGear,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let mut weight = WeightData::new(context);
+            weight.set_gross_weight(Mass::new::<kilogram>(60000.));
+
+            Self {
+                monitor: StructuralLoadMonitor::new(context, &LIMITS),
+                weight,
+                surfaces: TestSurfaces { flaps: 0. },
+                gear: TestGear { down: false },
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            context: &UpdateContext,
+            _: &mut Electricity,
+        ) {
+            self.monitor
+                .update(context, &self.weight, &self.surfaces, &self.gear);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.monitor.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    struct LoadReportTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl LoadReportTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_on_ground(true);
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(0.));
+            test_bed.set_load_factor(1.);
+            test_bed.run_with_delta(Duration::from_millis(100));
+
+            test_bed
+        }
+
+        fn in_flight(mut self) -> Self {
+            self.set_on_ground(false);
+            self.set_indicated_airspeed(Velocity::new::<knot>(250.));
+            self.run_with_delta(Duration::from_millis(100));
+            self
+        }
+
+        fn set_load_factor(&mut self, load_factor: f64) {
+            self.set_norm_acc(Acceleration::new::<meter_per_second_squared>(
+                (load_factor - 1.) * 9.8,
+            ));
+        }
+
+        fn land(mut self, sink_rate_fps: f64, load_factor: f64) -> Self {
+            self.set_gear_down(true);
+            self.set_indicated_airspeed(Velocity::new::<knot>(140.));
+            self.write_by_name("VELOCITY BODY Y", -sink_rate_fps);
+            self.run_with_delta(Duration::from_millis(100));
+
+            self.set_on_ground(true);
+            self.set_load_factor(load_factor);
+            self.run_with_delta(Duration::from_millis(100));
+            self.set_load_factor(1.);
+            for _ in 0..30 {
+                self.run_with_delta(Duration::from_millis(100));
+            }
+            self
+        }
+
+        fn set_gross_weight(&mut self, gross_weight: Mass) {
+            self.command(|a| a.weight.set_gross_weight(gross_weight));
+        }
+
+        fn set_flaps(&mut self, flaps: f64) {
+            self.command(|a| a.surfaces.flaps = flaps);
+        }
+
+        fn set_gear_down(&mut self, down: bool) {
+            self.command(|a| a.gear.down = down);
+        }
+
+        fn reports(&self) -> Vec<LoadReport> {
+            self.query(|a| a.monitor.reports().copied().collect())
+        }
+
+        fn report_types(&self) -> Vec<LoadReportType> {
+            self.reports().iter().map(|r| r.report_type()).collect()
+        }
+    }
+    impl TestBed for LoadReportTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> LoadReportTestBed {
+        LoadReportTestBed::new()
+    }
+
+    #[test]
+    fn no_report_when_starting_on_ground() {
+        let test_bed = test_bed();
+
+        assert!(test_bed.reports().is_empty());
+    }
+
+    #[test]
+    fn no_report_after_normal_landing() {
+        let test_bed = test_bed().in_flight().land(4., 1.4);
+
+        assert!(test_bed.reports().is_empty());
+    }
+
+    #[test]
+    fn hard_landing_from_sink_rate() {
+        let mut test_bed = test_bed().in_flight().land(11., 1.8);
+
+        assert_eq!(test_bed.report_types(), vec![LoadReportType::HardLanding]);
+        let report = test_bed.reports()[0];
+        assert!((report.sink_rate().get::<foot_per_second>() - 11.).abs() < 0.01);
+        assert!((report.load_factor() - 1.8).abs() < 0.01);
+
+        let report_type: u8 = test_bed.read_by_name("LOAD_REPORT_1_TYPE");
+        assert_eq!(report_type, LoadReportType::HardLanding as u8);
+        let sink_rate: f64 = test_bed.read_by_name("LOAD_REPORT_1_SINK_RATE");
+        assert!((sink_rate - 11.).abs() < 0.01);
+    }
+
+    #[test]
+    fn severe_hard_landing_from_load_factor() {
+        let test_bed = test_bed().in_flight().land(8., 3.);
+
+        assert_eq!(
+            test_bed.report_types(),
+            vec![LoadReportType::SevereHardLanding]
+        );
+    }
+
+    #[test]
+    fn overweight_landing_is_reported() {
+        let mut test_bed = test_bed();
+        test_bed.set_gross_weight(Mass::new::<kilogram>(70000.));
+        test_bed = test_bed.in_flight().land(4., 1.4);
+
+        assert_eq!(
+            test_bed.report_types(),
+            vec![LoadReportType::OverweightLanding]
+        );
+        assert_eq!(
+            test_bed.reports()[0].gross_weight(),
+            Mass::new::<kilogram>(70000.)
+        );
+    }
+
+    #[test]
+    fn overweight_landing_has_reduced_hard_landing_limits() {
+        let mut test_bed = test_bed();
+        test_bed.set_gross_weight(Mass::new::<kilogram>(70000.));
+        test_bed = test_bed.in_flight().land(7., 1.4);
+
+        assert_eq!(test_bed.report_types(), vec![LoadReportType::HardLanding]);
+    }
+
+    #[test]
+    fn flaps_overspeed_is_reported_once_with_peak_speed() {
+        let mut test_bed = test_bed().in_flight();
+        test_bed.set_flaps(0.5);
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(205.));
+        test_bed.run_with_delta(Duration::from_millis(100));
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(212.));
+        test_bed.run_with_delta(Duration::from_millis(100));
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(190.));
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert_eq!(
+            test_bed.report_types(),
+            vec![LoadReportType::FlapsOverspeed]
+        );
+        assert!((test_bed.reports()[0].indicated_airspeed().get::<knot>() - 212.).abs() < 0.01);
+    }
+
+    #[test]
+    fn gear_overspeed_is_reported() {
+        let mut test_bed = test_bed().in_flight();
+        test_bed.set_gear_down(true);
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(290.));
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert_eq!(test_bed.report_types(), vec![LoadReportType::GearOverspeed]);
+    }
+
+    #[test]
+    fn simultaneous_exceedances_are_reported_once_each() {
+        let mut test_bed = test_bed().in_flight();
+        test_bed.set_gear_down(true);
+        test_bed.set_flaps(0.5);
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(290.));
+        for _ in 0..10 {
+            test_bed.run_with_delta(Duration::from_millis(100));
+        }
+
+        assert_eq!(test_bed.reports().len(), 2);
+    }
+
+    #[test]
+    fn turbulence_overload_depends_on_flaps() {
+        let mut test_bed = test_bed().in_flight();
+        test_bed.set_load_factor(2.2);
+        test_bed.run_with_delta(Duration::from_millis(100));
+        assert!(test_bed.reports().is_empty());
+
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(180.));
+        test_bed.set_flaps(0.5);
+        test_bed.run_with_delta(Duration::from_millis(100));
+        assert_eq!(
+            test_bed.report_types(),
+            vec![LoadReportType::TurbulenceOverload]
+        );
+    }
+
+    #[test]
+    fn reports_are_kept_until_cleared() {
+        let mut test_bed = test_bed()
+            .in_flight()
+            .land(11., 1.8)
+            .in_flight()
+            .land(15., 1.8);
+
+        assert_eq!(
+            test_bed.report_types(),
+            vec![
+                LoadReportType::SevereHardLanding,
+                LoadReportType::HardLanding
+            ]
+        );
+        let count: f64 = test_bed.read_by_name("LOAD_REPORT_COUNT");
+        assert_eq!(count, 2.);
+
+        test_bed.write_by_name("LOAD_REPORT_CLEAR", true);
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert!(test_bed.reports().is_empty());
+        let report_type: u8 = test_bed.read_by_name("LOAD_REPORT_1_TYPE");
+        assert_eq!(report_type, 0);
+        let count: f64 = test_bed.read_by_name("LOAD_REPORT_COUNT");
+        assert_eq!(count, 2.);
+    }
+
+    #[test]
+    fn open_exceedance_is_not_reported_again_after_clearing() {
+        let mut test_bed = test_bed().in_flight();
+        test_bed.set_flaps(0.5);
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(205.));
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        test_bed.write_by_name("LOAD_REPORT_CLEAR", true);
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        test_bed.set_gear_down(true);
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(290.));
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert_eq!(test_bed.report_types(), vec![LoadReportType::GearOverspeed]);
+    }
+
+    #[test]
+    fn reports_are_restored_from_the_report_variables() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("LOAD_REPORT_1_TYPE", LoadReportType::GearOverspeed as u8);
+        test_bed.write_by_name("LOAD_REPORT_1_IAS", 295.);
+        test_bed.write_by_name("LOAD_REPORT_2_TYPE", LoadReportType::HardLanding as u8);
+        test_bed.write_by_name("LOAD_REPORT_2_SINK_RATE", 11.);
+        test_bed.write_by_name("LOAD_REPORT_RESTORE", true);
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert_eq!(
+            test_bed.report_types(),
+            vec![LoadReportType::GearOverspeed, LoadReportType::HardLanding]
+        );
+        assert!((test_bed.reports()[0].indicated_airspeed().get::<knot>() - 295.).abs() < 0.01);
+        assert!((test_bed.reports()[1].sink_rate().get::<foot_per_second>() - 11.).abs() < 0.01);
+        let restore: bool = test_bed.read_by_name("LOAD_REPORT_RESTORE");
+        assert!(!restore);
+
+        test_bed = test_bed.in_flight().land(11., 1.8);
+
+        assert_eq!(
+            test_bed.report_types(),
+            vec![
+                LoadReportType::HardLanding,
+                LoadReportType::GearOverspeed,
+                LoadReportType::HardLanding
+            ]
+        );
+    }
+
+    #[test]
+    fn exceedance_open_during_a_restore_keeps_recording_its_peaks() {
+        let mut test_bed = test_bed().in_flight();
+        test_bed.set_flaps(0.5);
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(205.));
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        test_bed.write_by_name("LOAD_REPORT_1_TYPE", LoadReportType::GearOverspeed as u8);
+        test_bed.write_by_name("LOAD_REPORT_1_IAS", 295.);
+        test_bed.write_by_name("LOAD_REPORT_2_TYPE", 0);
+        test_bed.write_by_name("LOAD_REPORT_RESTORE", true);
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(212.));
+        test_bed.run_with_delta(Duration::from_millis(100));
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(208.));
+        test_bed.run_with_delta(Duration::from_millis(100));
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(190.));
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        let reports = test_bed.reports();
+        assert_eq!(
+            test_bed.report_types(),
+            vec![
+                LoadReportType::FlapsOverspeed,
+                LoadReportType::GearOverspeed
+            ]
+        );
+        assert!((reports[0].indicated_airspeed().get::<knot>() - 212.).abs() < 0.01);
+        assert_ne!(reports[0].number, reports[1].number);
+    }
+
+    #[test]
+    fn only_the_latest_reports_are_kept() {
+        let mut test_bed = test_bed();
+        for _ in 0..StructuralLoadMonitor::MAX_REPORTS + 2 {
+            test_bed = test_bed.in_flight().land(11., 1.8);
+        }
+
+        assert_eq!(test_bed.reports().len(), StructuralLoadMonitor::MAX_REPORTS);
+    }
+}
@@ -1 +1,3 @@
 pub mod controls;
+pub mod display_unit;
+pub mod load_report;
@@ -1,12 +1,15 @@
 use self::{
     core_processing_input_output_module::CoreProcessingInputOutputModule,
     input_output_module::InputOutputModule,
+    virtual_link::{VirtualLink, VirtualLinkConfiguration},
 };
 use std::ops::Deref;
 
 pub mod avionics_full_duplex_switch;
 pub mod core_processing_input_output_module;
 pub mod input_output_module;
+pub mod topology;
+pub mod virtual_link;
 
 /// Represents an identifier for messages in the Avionics Data Communication Network.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
@@ -68,6 +71,29 @@ pub trait AvionicsDataCommunicationNetwork<'a, MessageData: Clone + Eq + Partial
 
     /// Returns a reference to the IOM with the specified name.
     fn get_iom(&self, name: &str) -> &InputOutputModule<MessageData>;
+
+    /// Defines a virtual link for the message with the given name and returns its identifier.
+    /// The paths contain the ids of the AFDX switches the virtual link traverses on network A and
+    /// B respectively, ordered from the source to the destination.
+    fn add_virtual_link(
+        &mut self,
+        name: String,
+        configuration: VirtualLinkConfiguration,
+        network_a_path: &[u8],
+        network_b_path: &[u8],
+    ) -> AvionicsDataCommunicationNetworkMessageIdentifier;
+
+    /// Returns a reference to the virtual link with the specified identifier.
+    fn get_virtual_link(
+        &self,
+        id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+    ) -> Option<&VirtualLink<MessageData>>;
+
+    /// Returns a mutable reference to the virtual link with the specified identifier.
+    fn get_virtual_link_mut(
+        &mut self,
+        id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+    ) -> Option<&mut VirtualLink<MessageData>>;
 }
 
 /// This type represents a message on the ACDN
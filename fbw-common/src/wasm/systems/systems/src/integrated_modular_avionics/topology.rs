@@ -0,0 +1,697 @@
+use super::virtual_link::{AfdxNetwork, VirtualLinkConfiguration};
+use crate::shared::ElectricalBusType;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+use std::{error::Error, fmt::Display, time::Duration};
+
+/// The declarative description of an avionics data communication network, as loaded from a TOML
+/// file. The file consists of the following tables:
+///
+/// - `[[switch]]` with an `id`, the `network` (`"A"` or `"B"`), a `power_supply`, an optional
+///   `secondary_power_supply` and the ids of the switches it is `linked_to`.
+/// - `[[cpiom]]` and `[[iom]]` with a `name`, a `power_supply` and the `switches` they are
+///   attached to.
+/// - `[[virtual_link]]` with a `name`, the `bag_ms`, the `max_frame_size` in bytes and the
+///   `network_a_path` and `network_b_path` through the switches.
+///
+/// Power supplies are given by their bus name, e.g. `"DC_1"`, `"DC_ESS"` or `"DC_NAMED_108PH"`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TopologyDefinition {
+    #[serde(rename = "switch")]
+    switches: Vec<SwitchDefinition>,
+    #[serde(default, rename = "cpiom")]
+    cpioms: Vec<ModuleDefinition>,
+    #[serde(default, rename = "iom")]
+    ioms: Vec<ModuleDefinition>,
+    #[serde(default, rename = "virtual_link")]
+    virtual_links: Vec<VirtualLinkDefinition>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SwitchDefinition {
+    id: u8,
+    network: NetworkDefinition,
+    power_supply: String,
+    secondary_power_supply: Option<String>,
+    #[serde(default)]
+    linked_to: Vec<u8>,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+enum NetworkDefinition {
+    A,
+    B,
+}
+impl From<NetworkDefinition> for AfdxNetwork {
+    fn from(value: NetworkDefinition) -> Self {
+        match value {
+            NetworkDefinition::A => AfdxNetwork::A,
+            NetworkDefinition::B => AfdxNetwork::B,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ModuleDefinition {
+    name: String,
+    power_supply: String,
+    switches: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct VirtualLinkDefinition {
+    name: String,
+    bag_ms: u64,
+    max_frame_size: usize,
+    #[serde(default)]
+    network_a_path: Vec<u8>,
+    #[serde(default)]
+    network_b_path: Vec<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TopologyError {
+    Parse(String),
+    UnknownPowerSupply(String),
+    DuplicateSwitch(u8),
+    DanglingLink {
+        from: u8,
+        to: u8,
+    },
+    LinkAcrossNetworks {
+        from: u8,
+        to: u8,
+    },
+    DuplicateModule(String),
+    DanglingModuleAttachment {
+        module: String,
+        switch: u8,
+    },
+    DanglingVirtualLinkPath {
+        virtual_link: String,
+        switch: u8,
+    },
+    DisconnectedVirtualLinkPath {
+        virtual_link: String,
+        from: u8,
+        to: u8,
+    },
+    InvalidVirtualLink(String),
+}
+impl Display for TopologyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TopologyError::Parse(message) => write!(f, "Failed to parse topology: {}", message),
+            TopologyError::UnknownPowerSupply(name) => {
+                write!(f, "Unknown power supply '{}'", name)
+            }
+            TopologyError::DuplicateSwitch(id) => write!(f, "Switch {} is defined twice", id),
+            TopologyError::DanglingLink { from, to } => {
+                write!(f, "Switch {} is linked to undefined switch {}", from, to)
+            }
+            TopologyError::LinkAcrossNetworks { from, to } => write!(
+                f,
+                "Switch {} is linked to switch {} of another network",
+                from, to
+            ),
+            TopologyError::DuplicateModule(name) => {
+                write!(f, "Module {} is defined twice", name)
+            }
+            TopologyError::DanglingModuleAttachment { module, switch } => write!(
+                f,
+                "Module {} is attached to undefined switch {}",
+                module, switch
+            ),
+            TopologyError::DanglingVirtualLinkPath {
+                virtual_link,
+                switch,
+            } => write!(
+                f,
+                "Virtual link {} is routed through undefined switch {}",
+                virtual_link, switch
+            ),
+            TopologyError::DisconnectedVirtualLinkPath {
+                virtual_link,
+                from,
+                to,
+            } => write!(
+                f,
+                "Virtual link {} is routed from switch {} to switch {} which aren't linked",
+                virtual_link, from, to
+            ),
+            TopologyError::InvalidVirtualLink(name) => {
+                write!(f, "Virtual link {} has an invalid BAG or frame size", name)
+            }
+        }
+    }
+}
+impl Error for TopologyError {}
+
+pub struct SwitchTopology {
+    id: u8,
+    network: AfdxNetwork,
+    power_supply: ElectricalBusType,
+    secondary_power_supply: Option<ElectricalBusType>,
+    linked_to: Vec<u8>,
+}
+impl SwitchTopology {
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    pub fn network(&self) -> AfdxNetwork {
+        self.network
+    }
+
+    pub fn power_supply(&self) -> ElectricalBusType {
+        self.power_supply
+    }
+
+    pub fn secondary_power_supply(&self) -> Option<ElectricalBusType> {
+        self.secondary_power_supply
+    }
+
+    /// The ids of the switches this switch is directly linked to. Links are bidirectional,
+    /// thus a link declared on either of the two switches is listed for both.
+    pub fn linked_to(&self) -> &[u8] {
+        &self.linked_to
+    }
+}
+
+pub struct ModuleTopology {
+    name: String,
+    power_supply: ElectricalBusType,
+    switches: Vec<u8>,
+}
+impl ModuleTopology {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn power_supply(&self) -> ElectricalBusType {
+        self.power_supply
+    }
+
+    pub fn switches(&self) -> &[u8] {
+        &self.switches
+    }
+}
+
+pub struct VirtualLinkTopology {
+    name: String,
+    configuration: VirtualLinkConfiguration,
+    network_a_path: Vec<u8>,
+    network_b_path: Vec<u8>,
+}
+impl VirtualLinkTopology {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn configuration(&self) -> VirtualLinkConfiguration {
+        self.configuration
+    }
+
+    pub fn network_a_path(&self) -> &[u8] {
+        &self.network_a_path
+    }
+
+    pub fn network_b_path(&self) -> &[u8] {
+        &self.network_b_path
+    }
+}
+
+/// A validated avionics data communication network topology.
+pub struct AvionicsDataCommunicationNetworkTopology {
+    switches: Vec<SwitchTopology>,
+    cpioms: Vec<ModuleTopology>,
+    ioms: Vec<ModuleTopology>,
+    virtual_links: Vec<VirtualLinkTopology>,
+}
+impl AvionicsDataCommunicationNetworkTopology {
+    pub fn from_toml(source: &str) -> Result<Self, TopologyError> {
+        let definition: TopologyDefinition =
+            toml::from_str(source).map_err(|error| TopologyError::Parse(error.to_string()))?;
+
+        let mut networks: FxHashMap<u8, AfdxNetwork> = FxHashMap::default();
+        for switch in &definition.switches {
+            if networks.insert(switch.id, switch.network.into()).is_some() {
+                return Err(TopologyError::DuplicateSwitch(switch.id));
+            }
+        }
+
+        let mut links: FxHashMap<u8, Vec<u8>> = FxHashMap::default();
+        for switch in &definition.switches {
+            for &to in &switch.linked_to {
+                match networks.get(&to) {
+                    None => {
+                        return Err(TopologyError::DanglingLink {
+                            from: switch.id,
+                            to,
+                        })
+                    }
+                    Some(&network) if network != switch.network.into() => {
+                        return Err(TopologyError::LinkAcrossNetworks {
+                            from: switch.id,
+                            to,
+                        })
+                    }
+                    _ => {}
+                }
+
+                for (a, b) in [(switch.id, to), (to, switch.id)] {
+                    let linked_to = links.entry(a).or_default();
+                    if !linked_to.contains(&b) {
+                        linked_to.push(b);
+                    }
+                }
+            }
+        }
+
+        let switches = definition
+            .switches
+            .iter()
+            .map(|switch| {
+                Ok(SwitchTopology {
+                    id: switch.id,
+                    network: switch.network.into(),
+                    power_supply: parse_power_supply(&switch.power_supply)?,
+                    secondary_power_supply: switch
+                        .secondary_power_supply
+                        .as_deref()
+                        .map(parse_power_supply)
+                        .transpose()?,
+                    linked_to: links.remove(&switch.id).unwrap_or_default(),
+                })
+            })
+            .collect::<Result<Vec<_>, TopologyError>>()?;
+
+        // Module names only need to be unique among modules of the same kind.
+        let modules = |definitions: &[ModuleDefinition]| {
+            let mut module_names = FxHashSet::default();
+            definitions
+                .iter()
+                .map(|module| {
+                    if !module_names.insert(module.name.clone()) {
+                        return Err(TopologyError::DuplicateModule(module.name.clone()));
+                    }
+                    if let Some(&switch) =
+                        module.switches.iter().find(|id| !networks.contains_key(id))
+                    {
+                        return Err(TopologyError::DanglingModuleAttachment {
+                            module: module.name.clone(),
+                            switch,
+                        });
+                    }
+
+                    Ok(ModuleTopology {
+                        name: module.name.clone(),
+                        power_supply: parse_power_supply(&module.power_supply)?,
+                        switches: module.switches.clone(),
+                    })
+                })
+                .collect::<Result<Vec<_>, TopologyError>>()
+        };
+        let cpioms = modules(&definition.cpioms)?;
+        let ioms = modules(&definition.ioms)?;
+
+        let topology = Self {
+            switches,
+            cpioms,
+            ioms,
+            virtual_links: vec![],
+        };
+
+        let virtual_links = definition
+            .virtual_links
+            .into_iter()
+            .map(|virtual_link| topology.validate_virtual_link(virtual_link))
+            .collect::<Result<Vec<_>, TopologyError>>()?;
+
+        Ok(Self {
+            virtual_links,
+            ..topology
+        })
+    }
+
+    fn validate_virtual_link(
+        &self,
+        definition: VirtualLinkDefinition,
+    ) -> Result<VirtualLinkTopology, TopologyError> {
+        for (network, path) in [
+            (AfdxNetwork::A, &definition.network_a_path),
+            (AfdxNetwork::B, &definition.network_b_path),
+        ] {
+            if let Some(&switch) = path.iter().find(|&&id| {
+                self.switch(id)
+                    .is_none_or(|switch| switch.network() != network)
+            }) {
+                return Err(TopologyError::DanglingVirtualLinkPath {
+                    virtual_link: definition.name,
+                    switch,
+                });
+            }
+
+            if let Some(hop) = path.windows(2).find(|hop| {
+                self.switch(hop[0])
+                    .is_some_and(|switch| !switch.linked_to().contains(&hop[1]))
+            }) {
+                return Err(TopologyError::DisconnectedVirtualLinkPath {
+                    virtual_link: definition.name,
+                    from: hop[0],
+                    to: hop[1],
+                });
+            }
+        }
+
+        let bag_is_valid = definition.bag_ms.is_power_of_two()
+            && definition.bag_ms <= VirtualLinkConfiguration::MAX_BANDWIDTH_ALLOCATION_GAP_MS;
+        let frame_size_is_valid = (VirtualLinkConfiguration::MIN_FRAME_SIZE_BYTES
+            ..=VirtualLinkConfiguration::MAX_FRAME_SIZE_BYTES)
+            .contains(&definition.max_frame_size);
+        if !bag_is_valid || !frame_size_is_valid {
+            return Err(TopologyError::InvalidVirtualLink(definition.name));
+        }
+
+        Ok(VirtualLinkTopology {
+            configuration: VirtualLinkConfiguration::new(
+                Duration::from_millis(definition.bag_ms),
+                definition.max_frame_size,
+            ),
+            name: definition.name,
+            network_a_path: definition.network_a_path,
+            network_b_path: definition.network_b_path,
+        })
+    }
+
+    pub fn switches(&self) -> &[SwitchTopology] {
+        &self.switches
+    }
+
+    pub fn switch(&self, id: u8) -> Option<&SwitchTopology> {
+        self.switches.iter().find(|switch| switch.id == id)
+    }
+
+    pub fn cpioms(&self) -> &[ModuleTopology] {
+        &self.cpioms
+    }
+
+    pub fn ioms(&self) -> &[ModuleTopology] {
+        &self.ioms
+    }
+
+    pub fn virtual_links(&self) -> &[VirtualLinkTopology] {
+        &self.virtual_links
+    }
+}
+
+/// Parses a bus name as written by the [`Display`] implementation of [`ElectricalBusType`].
+/// Named buses are written with a `DC_NAMED_` or `AC_NAMED_` prefix.
+fn parse_power_supply(name: &str) -> Result<ElectricalBusType, TopologyError> {
+    let numbered = |prefix: &str| {
+        name.strip_prefix(prefix)
+            .and_then(|number| number.parse::<u8>().ok())
+    };
+    // Named buses require a static name. Topologies are only loaded once during initialisation,
+    // so leaking the few names used is acceptable.
+    let named = |prefix: &str| {
+        name.strip_prefix(prefix)
+            .filter(|name| !name.is_empty())
+            .map(|name| &*Box::leak(name.to_owned().into_boxed_str()))
+    };
+
+    let bus = match name {
+        "AC_ESS" => Some(ElectricalBusType::AlternatingCurrentEssential),
+        "AC_ESS_SHED" => Some(ElectricalBusType::AlternatingCurrentEssentialShed),
+        "AC_STAT_INV" => Some(ElectricalBusType::AlternatingCurrentStaticInverter),
+        "AC_GND_FLT_SVC" => Some(ElectricalBusType::AlternatingCurrentGndFltService),
+        "DC_ESS" => Some(ElectricalBusType::DirectCurrentEssential),
+        "DC_ESS_SHED" => Some(ElectricalBusType::DirectCurrentEssentialShed),
+        "DC_BAT" => Some(ElectricalBusType::DirectCurrentBattery),
+        "DC_GND_FLT_SVC" => Some(ElectricalBusType::DirectCurrentGndFltService),
+        _ => numbered("AC_")
+            .map(ElectricalBusType::AlternatingCurrent)
+            .or_else(|| numbered("DC_HOT_").map(ElectricalBusType::DirectCurrentHot))
+            .or_else(|| numbered("DC_").map(ElectricalBusType::DirectCurrent))
+            .or_else(|| named("AC_NAMED_").map(ElectricalBusType::AlternatingCurrentNamed))
+            .or_else(|| named("DC_NAMED_").map(ElectricalBusType::DirectCurrentNamed)),
+    };
+
+    bus.ok_or_else(|| TopologyError::UnknownPowerSupply(name.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOPOLOGY: &str = r#"
+        [[switch]]
+        id = 1
+        network = "A"
+        power_supply = "DC_ESS"
+        linked_to = [2]
+
+        [[switch]]
+        id = 2
+        network = "A"
+        power_supply = "DC_1"
+        secondary_power_supply = "DC_NAMED_108PH"
+        linked_to = [3]
+
+        [[switch]]
+        id = 3
+        network = "A"
+        power_supply = "DC_2"
+
+        [[switch]]
+        id = 11
+        network = "B"
+        power_supply = "DC_2"
+
+        [[cpiom]]
+        name = "A1"
+        power_supply = "DC_1"
+        switches = [1, 11]
+
+        [[iom]]
+        name = "A1"
+        power_supply = "DC_ESS"
+        switches = [3]
+
+        [[virtual_link]]
+        name = "TEST"
+        bag_ms = 32
+        max_frame_size = 200
+        network_a_path = [1, 2, 3]
+        network_b_path = [11]
+    "#;
+
+    fn topology_with(
+        extra: &str,
+    ) -> Result<AvionicsDataCommunicationNetworkTopology, TopologyError> {
+        AvionicsDataCommunicationNetworkTopology::from_toml(&format!("{}\n{}", TOPOLOGY, extra))
+    }
+
+    #[test]
+    fn parses_switches() {
+        let topology = topology_with("").unwrap();
+
+        assert_eq!(topology.switches().len(), 4);
+
+        let switch = topology.switch(2).unwrap();
+        assert_eq!(switch.network(), AfdxNetwork::A);
+        assert_eq!(switch.power_supply(), ElectricalBusType::DirectCurrent(1));
+        assert_eq!(
+            switch.secondary_power_supply(),
+            Some(ElectricalBusType::DirectCurrentNamed("108PH"))
+        );
+    }
+
+    #[test]
+    fn links_are_bidirectional() {
+        let topology = topology_with("").unwrap();
+
+        assert_eq!(topology.switch(1).unwrap().linked_to(), &[2]);
+        assert_eq!(topology.switch(2).unwrap().linked_to(), &[1, 3]);
+        assert_eq!(topology.switch(3).unwrap().linked_to(), &[2]);
+        assert!(topology.switch(11).unwrap().linked_to().is_empty());
+    }
+
+    #[test]
+    fn parses_modules_and_virtual_links() {
+        let topology = topology_with("").unwrap();
+
+        assert_eq!(topology.cpioms()[0].name(), "A1");
+        assert_eq!(topology.cpioms()[0].switches(), &[1, 11]);
+        assert_eq!(
+            topology.ioms()[0].power_supply(),
+            ElectricalBusType::DirectCurrentEssential
+        );
+
+        let virtual_link = &topology.virtual_links()[0];
+        assert_eq!(virtual_link.name(), "TEST");
+        assert_eq!(
+            virtual_link.configuration().bandwidth_allocation_gap(),
+            Duration::from_millis(32)
+        );
+        assert_eq!(virtual_link.network_a_path(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn rejects_dangling_links() {
+        let result = topology_with(
+            r#"
+            [[switch]]
+            id = 12
+            network = "B"
+            power_supply = "DC_2"
+            linked_to = [13]
+            "#,
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(TopologyError::DanglingLink { from: 12, to: 13 })
+        );
+    }
+
+    #[test]
+    fn rejects_links_across_networks() {
+        let result = topology_with(
+            r#"
+            [[switch]]
+            id = 12
+            network = "B"
+            power_supply = "DC_2"
+            linked_to = [1]
+            "#,
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(TopologyError::LinkAcrossNetworks { from: 12, to: 1 })
+        );
+    }
+
+    #[test]
+    fn rejects_duplicate_switches() {
+        let result = topology_with(
+            r#"
+            [[switch]]
+            id = 1
+            network = "A"
+            power_supply = "DC_2"
+            "#,
+        );
+
+        assert_eq!(result.err(), Some(TopologyError::DuplicateSwitch(1)));
+    }
+
+    #[test]
+    fn rejects_modules_attached_to_undefined_switches() {
+        let result = topology_with(
+            r#"
+            [[cpiom]]
+            name = "B1"
+            power_supply = "DC_1"
+            switches = [7]
+            "#,
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(TopologyError::DanglingModuleAttachment {
+                module: "B1".to_owned(),
+                switch: 7
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_virtual_links_through_unlinked_switches() {
+        let result = topology_with(
+            r#"
+            [[virtual_link]]
+            name = "SHORTCUT"
+            bag_ms = 32
+            max_frame_size = 200
+            network_a_path = [1, 3]
+            "#,
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(TopologyError::DisconnectedVirtualLinkPath {
+                virtual_link: "SHORTCUT".to_owned(),
+                from: 1,
+                to: 3
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_virtual_links_through_switches_of_another_network() {
+        let result = topology_with(
+            r#"
+            [[virtual_link]]
+            name = "WRONG_NETWORK"
+            bag_ms = 32
+            max_frame_size = 200
+            network_b_path = [1]
+            "#,
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(TopologyError::DanglingVirtualLinkPath {
+                virtual_link: "WRONG_NETWORK".to_owned(),
+                switch: 1
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_virtual_links_with_invalid_bag() {
+        let result = topology_with(
+            r#"
+            [[virtual_link]]
+            name = "SLOW"
+            bag_ms = 100
+            max_frame_size = 200
+            "#,
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(TopologyError::InvalidVirtualLink("SLOW".to_owned()))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_power_supplies() {
+        let result = topology_with(
+            r#"
+            [[switch]]
+            id = 12
+            network = "B"
+            power_supply = "DC_UNKNOWN"
+            "#,
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(TopologyError::UnknownPowerSupply("DC_UNKNOWN".to_owned()))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        let result = AvionicsDataCommunicationNetworkTopology::from_toml("[[switch]]\nid = 1");
+
+        assert!(matches!(result, Err(TopologyError::Parse(_))));
+    }
+}
@@ -0,0 +1,499 @@
+use super::{
+    avionics_full_duplex_switch::AvionicsFullDuplexSwitch, AvionicsDataCommunicationNetworkMessage,
+    AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus,
+};
+use crate::simulation::UpdateContext;
+use std::{cell::RefCell, collections::VecDeque, rc::Rc, time::Duration};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AfdxNetwork {
+    A = 0,
+    B = 1,
+}
+
+/// The static definition of a virtual link as loaded into the end systems and switches.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VirtualLinkConfiguration {
+    bandwidth_allocation_gap: Duration,
+    max_frame_size: usize,
+    skew_max: Duration,
+    freshness_limit: Duration,
+}
+impl VirtualLinkConfiguration {
+    pub(super) const MAX_BANDWIDTH_ALLOCATION_GAP_MS: u64 = 128;
+    pub(super) const MIN_FRAME_SIZE_BYTES: usize = 64;
+    pub(super) const MAX_FRAME_SIZE_BYTES: usize = 1518;
+    const DEFAULT_SKEW_MAX: Duration = Duration::from_millis(2);
+    const DEFAULT_FRESHNESS_LIMIT: Duration = Duration::from_millis(500);
+
+    /// Creates a new configuration. The bandwidth allocation gap (BAG) is the minimum time
+    /// between two consecutive frames on the virtual link and must be a power of two between
+    /// 1 ms and 128 ms. The maximum frame size is given in bytes.
+    pub fn new(bandwidth_allocation_gap: Duration, max_frame_size: usize) -> Self {
+        let bag_ms = bandwidth_allocation_gap.as_millis();
+        assert!(
+            bandwidth_allocation_gap == Duration::from_millis(bag_ms as u64)
+                && bag_ms.is_power_of_two()
+                && bag_ms <= Self::MAX_BANDWIDTH_ALLOCATION_GAP_MS as u128,
+            "The BAG of a virtual link must be a power of two between 1 ms and 128 ms."
+        );
+        assert!(
+            (Self::MIN_FRAME_SIZE_BYTES..=Self::MAX_FRAME_SIZE_BYTES).contains(&max_frame_size),
+            "The maximum frame size of a virtual link must be between 64 and 1518 bytes."
+        );
+
+        Self {
+            bandwidth_allocation_gap,
+            max_frame_size,
+            skew_max: Self::DEFAULT_SKEW_MAX,
+            freshness_limit: Self::DEFAULT_FRESHNESS_LIMIT,
+        }
+    }
+
+    /// The time window within which a frame received on the second network is considered
+    /// a redundant copy of the frame already received on the first network.
+    pub fn with_skew_max(mut self, skew_max: Duration) -> Self {
+        self.skew_max = skew_max;
+        self
+    }
+
+    /// The maximum age of received data before it is no longer considered valid.
+    pub fn with_freshness_limit(mut self, freshness_limit: Duration) -> Self {
+        self.freshness_limit = freshness_limit;
+        self
+    }
+
+    pub fn bandwidth_allocation_gap(&self) -> Duration {
+        self.bandwidth_allocation_gap
+    }
+
+    pub fn max_frame_size(&self) -> usize {
+        self.max_frame_size
+    }
+}
+
+struct Frame<MessageData: Clone + Eq + PartialEq> {
+    sequence_number: u8,
+    message: AvionicsDataCommunicationNetworkMessage<MessageData>,
+    /// The time since the source application sent the message.
+    age: Duration,
+    time_in_flight: Duration,
+}
+
+/// Verifies that the frames received on a single network have consecutive sequence numbers,
+/// as per ARINC 664 part 7. As frames may be lost, one missing frame is tolerated.
+#[derive(Default)]
+struct IntegrityChecker {
+    last_sequence_number: Option<u8>,
+}
+impl IntegrityChecker {
+    fn check(&mut self, sequence_number: u8) -> bool {
+        let is_valid = match self.last_sequence_number {
+            None => true,
+            Some(last) => {
+                // A sequence number of zero indicates a reset of the transmitting end system.
+                sequence_number == 0
+                    || sequence_number == next_sequence_number(last)
+                    || sequence_number == next_sequence_number(next_sequence_number(last))
+            }
+        };
+        self.last_sequence_number = Some(sequence_number);
+
+        is_valid
+    }
+}
+
+/// Accepts the first valid copy of each frame received on either network and discards the
+/// redundant copy received on the other network.
+struct RedundancyManager {
+    skew_max: Duration,
+    last_accepted: Option<(u8, Duration)>,
+}
+impl RedundancyManager {
+    fn new(skew_max: Duration) -> Self {
+        Self {
+            skew_max,
+            last_accepted: None,
+        }
+    }
+
+    fn update(&mut self, delta: Duration) {
+        if let Some((_, elapsed)) = &mut self.last_accepted {
+            *elapsed += delta;
+        }
+    }
+
+    fn accept(&mut self, sequence_number: u8) -> bool {
+        match self.last_accepted {
+            Some((last, elapsed)) if last == sequence_number && elapsed <= self.skew_max => false,
+            _ => {
+                self.last_accepted = Some((sequence_number, Duration::ZERO));
+                true
+            }
+        }
+    }
+}
+
+/// The sequence of AFDX switches a virtual link traverses on one of the networks.
+struct VirtualLinkPath<MessageData: Clone + Eq + PartialEq> {
+    switches: Vec<Rc<RefCell<AvionicsFullDuplexSwitch<MessageData>>>>,
+    hop_latency: Duration,
+    frames_in_flight: VecDeque<Frame<MessageData>>,
+    integrity_checker: IntegrityChecker,
+    frames_lost: usize,
+    frames_rejected: usize,
+}
+impl<MessageData: Clone + Eq + PartialEq> VirtualLinkPath<MessageData> {
+    /// ARINC 664 part 7 upper bound of the time a frame spends inside a switch.
+    const SWITCH_TECHNOLOGICAL_LATENCY: Duration = Duration::from_micros(100);
+    const LINK_RATE_BITS_PER_SECOND: f64 = 100_000_000.;
+    /// Preamble, start frame delimiter and inter frame gap.
+    const FRAME_OVERHEAD_BYTES: usize = 20;
+
+    fn new(
+        switches: Vec<Rc<RefCell<AvionicsFullDuplexSwitch<MessageData>>>>,
+        max_frame_size: usize,
+    ) -> Self {
+        let transmission_time = Duration::from_secs_f64(
+            ((max_frame_size + Self::FRAME_OVERHEAD_BYTES) * 8) as f64
+                / Self::LINK_RATE_BITS_PER_SECOND,
+        );
+
+        Self {
+            switches,
+            hop_latency: transmission_time + Self::SWITCH_TECHNOLOGICAL_LATENCY,
+            frames_in_flight: VecDeque::new(),
+            integrity_checker: IntegrityChecker::default(),
+            frames_lost: 0,
+            frames_rejected: 0,
+        }
+    }
+
+    fn is_configured(&self) -> bool {
+        !self.switches.is_empty()
+    }
+
+    /// The worst case end to end latency of a frame. Each switch stores the complete frame before
+    /// forwarding it, thus the transmission time adds up for every link.
+    fn latency(&self) -> Duration {
+        let transmission_time = self.hop_latency - Self::SWITCH_TECHNOLOGICAL_LATENCY;
+        self.hop_latency * self.switches.len() as u32 + transmission_time
+    }
+
+    /// Returns whether all switches the frame has not yet left are available.
+    fn remaining_path_is_available(&self, time_in_flight: Duration) -> bool {
+        let passed_switches =
+            (time_in_flight.as_secs_f64() / self.hop_latency.as_secs_f64()).floor() as usize;
+
+        self.switches
+            .iter()
+            .skip(passed_switches)
+            .all(|switch| switch.borrow().is_available())
+    }
+
+    /// Transmits a frame which was emitted the given time ago. Returns the frame when it already
+    /// reached the destination and passed the integrity check.
+    fn transmit(
+        &mut self,
+        frame: Frame<MessageData>,
+        time_since_emission: Duration,
+    ) -> Option<Frame<MessageData>> {
+        if self.is_configured() {
+            self.advance(frame, time_since_emission)
+        } else {
+            None
+        }
+    }
+
+    /// Advances the frames in flight and returns the ones which reached the destination and
+    /// passed the integrity check.
+    fn update(&mut self, delta: Duration) -> Vec<Frame<MessageData>> {
+        std::mem::take(&mut self.frames_in_flight)
+            .into_iter()
+            .filter_map(|frame| self.advance(frame, delta))
+            .collect()
+    }
+
+    fn advance(
+        &mut self,
+        mut frame: Frame<MessageData>,
+        delta: Duration,
+    ) -> Option<Frame<MessageData>> {
+        // A frame is lost when any of the switches it still has to pass through becomes
+        // unavailable while it is being transferred.
+        if !self.remaining_path_is_available(frame.time_in_flight) {
+            self.frames_lost += 1;
+            return None;
+        }
+
+        frame.time_in_flight += delta;
+        frame.age += delta;
+
+        if frame.time_in_flight < self.latency() {
+            self.frames_in_flight.push_back(frame);
+            None
+        } else if self.integrity_checker.check(frame.sequence_number) {
+            Some(frame)
+        } else {
+            self.frames_rejected += 1;
+            None
+        }
+    }
+}
+
+/// A virtual link is a unidirectional logical connection from one source end system to one or
+/// more destination end systems. Frames are transmitted at most once per bandwidth allocation
+/// gap (BAG) over up to two redundant networks, and the receiving end system keeps the first valid
+/// copy of each frame.
+///
+/// The source behaves like a sampling port: only the most recent message is transmitted when
+/// the BAG elapses. The received data ages until a newer frame is received and reports no data
+/// once it exceeds the freshness limit.
+pub struct VirtualLink<MessageData: Clone + Eq + PartialEq> {
+    configuration: VirtualLinkConfiguration,
+    paths: [VirtualLinkPath<MessageData>; 2],
+    redundancy_manager: RedundancyManager,
+
+    pending: Option<(
+        AvionicsDataCommunicationNetworkMessage<MessageData>,
+        Duration,
+    )>,
+    time_until_next_frame: Duration,
+    next_sequence_number: u8,
+
+    received: Option<(
+        AvionicsDataCommunicationNetworkMessage<MessageData>,
+        Duration,
+    )>,
+}
+impl<MessageData: Clone + Eq + PartialEq> VirtualLink<MessageData> {
+    /// Creates a new virtual link. The paths contain the switches the virtual link traverses
+    /// on network A and B in order from the source to the destination. An empty path indicates
+    /// that the virtual link isn't routed over that network.
+    pub fn new(
+        configuration: VirtualLinkConfiguration,
+        network_a_path: Vec<Rc<RefCell<AvionicsFullDuplexSwitch<MessageData>>>>,
+        network_b_path: Vec<Rc<RefCell<AvionicsFullDuplexSwitch<MessageData>>>>,
+    ) -> Self {
+        Self {
+            configuration,
+            paths: [
+                VirtualLinkPath::new(network_a_path, configuration.max_frame_size),
+                VirtualLinkPath::new(network_b_path, configuration.max_frame_size),
+            ],
+            redundancy_manager: RedundancyManager::new(configuration.skew_max),
+            pending: None,
+            time_until_next_frame: Duration::ZERO,
+            next_sequence_number: 0,
+            received: None,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        let delta = context.delta();
+
+        self.redundancy_manager.update(delta);
+        if let Some((_, age)) = &mut self.received {
+            *age += delta;
+        }
+        if let Some((_, age)) = &mut self.pending {
+            *age += delta;
+        }
+
+        let mut delivered: Vec<_> = self.paths[AfdxNetwork::A as usize].update(delta);
+        delivered.extend(self.paths[AfdxNetwork::B as usize].update(delta));
+
+        if self.time_until_next_frame <= delta {
+            if let Some((message, age)) = self.pending.take() {
+                let time_in_flight = delta - self.time_until_next_frame;
+                self.emit(message, age, time_in_flight, &mut delivered);
+                self.time_until_next_frame = self
+                    .configuration
+                    .bandwidth_allocation_gap
+                    .saturating_sub(time_in_flight);
+            } else {
+                self.time_until_next_frame = Duration::ZERO;
+            }
+        } else {
+            self.time_until_next_frame -= delta;
+        }
+
+        for frame in delivered {
+            if self.redundancy_manager.accept(frame.sequence_number) {
+                self.received = Some((frame.message, frame.age));
+            }
+        }
+    }
+
+    fn emit(
+        &mut self,
+        message: AvionicsDataCommunicationNetworkMessage<MessageData>,
+        age: Duration,
+        time_in_flight: Duration,
+        delivered: &mut Vec<Frame<MessageData>>,
+    ) {
+        let sequence_number = self.next_sequence_number;
+        self.next_sequence_number = next_sequence_number(sequence_number);
+
+        // The frame was emitted part way through this update.
+        for path in self.paths.iter_mut() {
+            delivered.extend(path.transmit(
+                Frame {
+                    sequence_number,
+                    message: message.clone(),
+                    age: age.saturating_sub(time_in_flight),
+                    time_in_flight: Duration::ZERO,
+                },
+                time_in_flight,
+            ));
+        }
+    }
+
+    /// Queues a message for transmission. A message which has not yet been transmitted is
+    /// replaced.
+    pub fn send_value(&mut self, value: AvionicsDataCommunicationNetworkMessage<MessageData>) {
+        self.pending = Some((value, Duration::ZERO));
+    }
+
+    /// Returns the last received message. When the message is older than the freshness limit
+    /// its status is downgraded to no data.
+    pub fn recv_value(&self) -> Option<AvionicsDataCommunicationNetworkMessage<MessageData>> {
+        self.received.as_ref().map(|(message, age)| {
+            if *age > self.configuration.freshness_limit {
+                AvionicsDataCommunicationNetworkMessage::new(
+                    AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::NoData,
+                    message.data().clone(),
+                )
+            } else {
+                message.clone()
+            }
+        })
+    }
+
+    /// The time since the last received message was sent by the source.
+    pub fn data_age(&self) -> Option<Duration> {
+        self.received.as_ref().map(|(_, age)| *age)
+    }
+
+    pub fn is_fresh(&self) -> bool {
+        self.data_age()
+            .is_some_and(|age| age <= self.configuration.freshness_limit)
+    }
+
+    /// The worst case end to end latency on the given network, or `None` when the virtual link
+    /// isn't routed over it.
+    pub fn latency(&self, network: AfdxNetwork) -> Option<Duration> {
+        let path = &self.paths[network as usize];
+        path.is_configured().then(|| path.latency())
+    }
+
+    /// The number of frames lost due to unavailable switches on the given network.
+    pub fn frames_lost(&self, network: AfdxNetwork) -> usize {
+        self.paths[network as usize].frames_lost
+    }
+
+    /// The number of frames discarded by the integrity checker on the given network.
+    pub fn frames_rejected(&self, network: AfdxNetwork) -> usize {
+        self.paths[network as usize].frames_rejected
+    }
+
+    pub fn configuration(&self) -> &VirtualLinkConfiguration {
+        &self.configuration
+    }
+}
+
+/// Sequence numbers start at zero after a reset and then wrap around from 255 to 1.
+fn next_sequence_number(sequence_number: u8) -> u8 {
+    if sequence_number == u8::MAX {
+        1
+    } else {
+        sequence_number + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequence_numbers_skip_zero_when_wrapping() {
+        assert_eq!(next_sequence_number(0), 1);
+        assert_eq!(next_sequence_number(254), 255);
+        assert_eq!(next_sequence_number(255), 1);
+    }
+
+    #[test]
+    fn integrity_checker_accepts_consecutive_frames() {
+        let mut checker = IntegrityChecker::default();
+
+        assert!(checker.check(0));
+        assert!(checker.check(1));
+        assert!(checker.check(2));
+    }
+
+    #[test]
+    fn integrity_checker_tolerates_a_single_lost_frame() {
+        let mut checker = IntegrityChecker::default();
+
+        assert!(checker.check(1));
+        assert!(checker.check(3));
+        assert!(checker.check(5));
+    }
+
+    #[test]
+    fn integrity_checker_tolerates_a_single_lost_frame_when_wrapping() {
+        let mut checker = IntegrityChecker::default();
+
+        assert!(checker.check(254));
+        assert!(checker.check(1));
+    }
+
+    #[test]
+    fn integrity_checker_rejects_out_of_sequence_frames() {
+        let mut checker = IntegrityChecker::default();
+
+        assert!(checker.check(1));
+        assert!(!checker.check(5));
+        assert!(!checker.check(5));
+        assert!(checker.check(6));
+    }
+
+    #[test]
+    fn integrity_checker_accepts_end_system_reset() {
+        let mut checker = IntegrityChecker::default();
+
+        assert!(checker.check(100));
+        assert!(checker.check(0));
+        assert!(checker.check(1));
+    }
+
+    #[test]
+    fn redundancy_manager_discards_redundant_copy() {
+        let mut manager = RedundancyManager::new(Duration::from_millis(2));
+
+        assert!(manager.accept(1));
+        assert!(!manager.accept(1));
+        assert!(manager.accept(2));
+    }
+
+    #[test]
+    fn redundancy_manager_accepts_same_sequence_number_after_skew_max() {
+        let mut manager = RedundancyManager::new(Duration::from_millis(2));
+
+        assert!(manager.accept(1));
+        manager.update(Duration::from_millis(3));
+        assert!(manager.accept(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn bag_must_be_a_power_of_two() {
+        VirtualLinkConfiguration::new(Duration::from_millis(3), 100);
+    }
+
+    #[test]
+    #[should_panic]
+    fn frame_size_must_be_within_ethernet_limits() {
+        VirtualLinkConfiguration::new(Duration::from_millis(4), 2000);
+    }
+}
@@ -3,6 +3,7 @@ use crate::payload::BoardingRate;
 use crate::shared::InternationalStandardAtmosphere;
 use crate::simulation::{InitContext, VariableIdentifier};
 use crate::{
+    failures::{Failure, FailureType},
     overhead::{IndicationLight, OnOffFaultPushButton},
     shared::{
         arinc429::{Arinc429Word, SignStatus},
@@ -1648,6 +1649,9 @@ struct InertialReference {
     excess_motion_inhibit_time: Option<Duration>,
     quick_realign_remaining_available_time: Duration,
     alignment_failed: bool,
+    heading_drift_failure: Failure,
+    /// The error of the heading outputs, accumulated while the heading drift failure is active.
+    heading_drift: Angle,
 
     pitch: AdirsArinc429Data<Angle>,
     roll: AdirsArinc429Data<Angle>,
@@ -1726,6 +1730,8 @@ impl InertialReference {
     const ALIGNMENT_VELOCITY_TIME_CONSTANT: Duration = Duration::from_millis(500);
     const MAX_ALIGNMENT_VELOCITY_FPS: f64 = 0.011;
     const MAX_LATITUDE_FOR_ALIGNMENT: f64 = 82.;
+    // The drift rate of the heading at the full severity of the heading drift failure.
+    const MAX_HEADING_DRIFT_RATE_DEGREES_PER_MINUTE: f64 = 2.;
 
     fn new(context: &mut InitContext, number: usize) -> Self {
         Self {
@@ -1744,6 +1750,8 @@ impl InertialReference {
             excess_motion_inhibit_time: None,
             quick_realign_remaining_available_time: Duration::default(),
             alignment_failed: false,
+            heading_drift_failure: Failure::new(FailureType::InertialReferenceHeadingDrift(number)),
+            heading_drift: Angle::default(),
 
             pitch: AdirsArinc429Data::new_ir(context, number, Self::PITCH),
             roll: AdirsArinc429Data::new_ir(context, number, Self::ROLL),
@@ -1816,6 +1824,7 @@ impl InertialReference {
 
         self.update_latitude(simulator_data);
         self.update_attitude_values(context, simulator_data);
+        self.update_heading_drift(context);
         self.update_heading_values(overhead, simulator_data);
         self.update_non_attitude_values(context, true_airspeed_source, overhead, simulator_data);
         self.update_maint_word(overhead);
@@ -2004,6 +2013,19 @@ impl InertialReference {
             .set_value(context.vert_accel() / g + pitch.cos() * roll.cos(), ssm);
     }
 
+    fn update_heading_drift(&mut self, context: &UpdateContext) {
+        self.heading_drift = if self.heading_drift_failure.is_active() {
+            let drift_rate = Self::MAX_HEADING_DRIFT_RATE_DEGREES_PER_MINUTE
+                * self.heading_drift_failure.severity().get::<ratio>();
+
+            (self.heading_drift
+                + Angle::new::<degree>(drift_rate * context.delta_as_secs_f64() / 60.))
+            .normalised_180()
+        } else {
+            Angle::default()
+        };
+    }
+
     fn update_heading_values(
         &mut self,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
@@ -2025,8 +2047,10 @@ impl InertialReference {
         } else {
             SignStatus::NoComputedData
         };
-        self.true_heading
-            .set_value(simulator_data.true_heading, true_heading_ssm);
+        self.true_heading.set_value(
+            (simulator_data.true_heading + self.heading_drift).normalised(),
+            true_heading_ssm,
+        );
 
         // TODO in ATT mode NCD until heading initialised on MCDU
         let magnetic_heading_ssm = if self.is_on
@@ -2039,11 +2063,12 @@ impl InertialReference {
             SignStatus::NoComputedData
         };
         self.heading.set_value(
-            if self.has_magnetic_data() {
+            (if self.has_magnetic_data() {
                 simulator_data.heading
             } else {
                 simulator_data.true_heading
-            },
+            } + self.heading_drift)
+                .normalised(),
             magnetic_heading_ssm,
         );
     }
@@ -2528,6 +2553,12 @@ impl InertialReferenceBus for InertialReference {
     }
 }
 impl SimulationElement for InertialReference {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.heading_drift_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.pitch.write_to(writer);
         self.roll.write_to(writer);
@@ -2591,7 +2622,6 @@ fn subtract_delta_from_duration(context: &UpdateContext, duration: Duration) ->
 
 trait NormaliseAngleExt {
     fn normalised(self) -> Angle;
-    #[allow(dead_code)]
     fn normalised_180(self) -> Angle;
 }
 
@@ -4799,6 +4829,57 @@ mod tests {
             );
         }
 
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn heading_drifts_at_a_rate_scaled_by_the_failure_severity(#[case] adiru_number: usize) {
+            let angle = Angle::new::<degree>(160.);
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .heading_of(angle)
+                .true_heading_of(angle);
+            test_bed.fail_with_severity(
+                FailureType::InertialReferenceHeadingDrift(adiru_number),
+                Ratio::new::<ratio>(0.5),
+            );
+            test_bed.run_with_delta(Duration::from_secs(60));
+
+            assert_about_eq!(
+                test_bed
+                    .heading(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                161.
+            );
+            assert_about_eq!(
+                test_bed
+                    .true_heading(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                161.
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn heading_drift_is_removed_when_the_failure_is_cleared(#[case] adiru_number: usize) {
+            let angle = Angle::new::<degree>(160.);
+            let mut test_bed = all_adirus_aligned_test_bed_with().heading_of(angle);
+            test_bed.fail(FailureType::InertialReferenceHeadingDrift(adiru_number));
+            test_bed.run_with_delta(Duration::from_secs(60));
+            test_bed.unfail(FailureType::InertialReferenceHeadingDrift(adiru_number));
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.heading(adiru_number).normal_value().unwrap(),
+                angle
+            );
+        }
+
         #[rstest]
         #[case(1)]
         #[case(2)]
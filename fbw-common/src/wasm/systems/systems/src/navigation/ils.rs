@@ -1,14 +1,25 @@
 use crate::{
-    shared::arinc429::{Arinc429Word, SignStatus},
-    simulation::{Read, SimulationElement, VariableIdentifier},
+    failures::{Failure, FailureType},
+    shared::{
+        arinc429::{Arinc429Word, SignStatus},
+        ConsumePower, ElectricalBusType, ElectricalBuses,
+    },
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
 };
 use uom::si::{
-    angle::degree,
-    f64::{Angle, Frequency, Ratio},
+    angle::{degree, radian},
+    f64::{Angle, Frequency, Length, Power, Ratio},
+    frequency::hertz,
+    length::{foot, meter, nautical_mile},
+    power::watt,
+    ratio::ratio,
 };
 
 pub trait InstrumentLandingSystemBus {
-    /// Label 017
+    /// Label 017, the magnetic course of the localizer.
     fn runway_heading(&self) -> Arinc429Word<Angle>;
     /// Label 033
     fn ils_frequency(&self) -> Arinc429Word<Frequency>;
@@ -26,134 +37,1431 @@ pub trait InstrumentLandingSystemBus {
     fn ground_station_ident_2(&self) -> Arinc429Word<u32>;
 }
 
-/// This represents an interface to an MMR, which is implemented outside of the rust systems.
-/// Currently, this implementation is done by MSFS.
-pub struct MultiModeReceiverShim {
+/// Normalises an angle into the range [-180; 180).
+fn normalize_180(angle: Angle) -> Angle {
+    let normalized_360 = normalize_360(angle);
+
+    if normalized_360 >= Angle::new::<degree>(180.0) {
+        normalized_360 - Angle::new::<degree>(360.0)
+    } else {
+        normalized_360
+    }
+}
+
+/// Normalises an angle into the range [0; 360).
+fn normalize_360(angle: Angle) -> Angle {
+    let angle = angle % Angle::new::<degree>(360.0);
+
+    (angle + Angle::new::<degree>(360.0)) % Angle::new::<degree>(360.0)
+}
+
+/// A position on the surface of the earth, including the elevation above mean sea level.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GeographicPosition {
+    latitude: Angle,
+    longitude: Angle,
+    elevation: Length,
+}
+impl GeographicPosition {
+    const EARTH_RADIUS_METERS: f64 = 6_371_000.;
+
+    pub fn new(latitude: Angle, longitude: Angle, elevation: Length) -> Self {
+        Self {
+            latitude,
+            longitude,
+            elevation,
+        }
+    }
+
+    pub fn latitude(&self) -> Angle {
+        self.latitude
+    }
+
+    pub fn longitude(&self) -> Angle {
+        self.longitude
+    }
+
+    pub fn elevation(&self) -> Length {
+        self.elevation
+    }
+
+    /// Returns the (north, east) offset of this position relative to the given origin.
+    /// Uses an equirectangular projection, which is accurate enough within the service volume
+    /// of a radio navaid.
+    fn offset_from(&self, origin: &GeographicPosition) -> (Length, Length) {
+        let mean_latitude = (self.latitude + origin.latitude).get::<radian>() / 2.;
+        let delta_longitude = normalize_180(self.longitude - origin.longitude);
+
+        (
+            Length::new::<meter>(
+                (self.latitude - origin.latitude).get::<radian>() * Self::EARTH_RADIUS_METERS,
+            ),
+            Length::new::<meter>(
+                delta_longitude.get::<radian>() * mean_latitude.cos() * Self::EARTH_RADIUS_METERS,
+            ),
+        )
+    }
+
+    /// Returns the position reached when moving the given distance along the given true bearing.
+    fn moved_by(&self, bearing: Angle, distance: Length) -> Self {
+        let distance_radians = distance.get::<meter>() / Self::EARTH_RADIUS_METERS;
+        let north = distance_radians * bearing.get::<radian>().cos();
+        let east =
+            distance_radians * bearing.get::<radian>().sin() / self.latitude.get::<radian>().cos();
+
+        Self {
+            latitude: self.latitude + Angle::new::<radian>(north),
+            longitude: self.longitude + Angle::new::<radian>(east),
+            elevation: self.elevation,
+        }
+    }
+
+    /// Returns the true bearing from the given origin to this position and the horizontal distance
+    /// between them.
+    fn bearing_and_distance_from(&self, origin: &GeographicPosition) -> (Angle, Length) {
+        let (north, east) = self.offset_from(origin);
+
+        (
+            normalize_360(Angle::new::<radian>(
+                east.get::<meter>().atan2(north.get::<meter>()),
+            )),
+            Length::new::<meter>(north.get::<meter>().hypot(east.get::<meter>())),
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IlsGlideslope {
+    angle: Angle,
+    threshold_crossing_height: Length,
+}
+impl IlsGlideslope {
+    pub fn new(angle: Angle, threshold_crossing_height: Length) -> Self {
+        Self {
+            angle,
+            threshold_crossing_height,
+        }
+    }
+
+    pub fn angle(&self) -> Angle {
+        self.angle
+    }
+
+    pub fn threshold_crossing_height(&self) -> Length {
+        self.threshold_crossing_height
+    }
+}
+
+/// The definition of an ILS ground installation as it would be found in a navigation database.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IlsNavaid {
+    ident: String,
     frequency: Frequency,
-    nav_valid: bool,
-    loc_valid: bool,
-    localizer_course: Angle,
-    localizer_deviation: Angle,
-    glideslope_valid: bool,
-    glideslope_deviation: Angle,
+    /// The magnetic course of the localizer, as published in the navigation database.
+    magnetic_course: Angle,
+    /// The true course of the localizer, which defines the geometry of the signals in space.
+    course: Angle,
+    /// The full angular width of the localizer course sector, i.e. the angle between the two
+    /// points at which full scale deflection (0.155 DDM) is reached.
+    course_width: Angle,
+    localizer_position: GeographicPosition,
+    threshold_position: GeographicPosition,
+    glideslope: Option<IlsGlideslope>,
+    dme_position: Option<GeographicPosition>,
+}
+impl IlsNavaid {
+    const DEFAULT_COURSE_WIDTH_DEGREES: f64 = 5.;
+
+    pub fn new(
+        ident: &str,
+        frequency: Frequency,
+        magnetic_course: Angle,
+        course: Angle,
+        localizer_position: GeographicPosition,
+        threshold_position: GeographicPosition,
+    ) -> Self {
+        Self {
+            ident: ident.to_owned(),
+            frequency,
+            magnetic_course,
+            course,
+            course_width: Angle::new::<degree>(Self::DEFAULT_COURSE_WIDTH_DEGREES),
+            localizer_position,
+            threshold_position,
+            glideslope: None,
+            dme_position: None,
+        }
+    }
+
+    pub fn with_course_width(mut self, course_width: Angle) -> Self {
+        self.course_width = course_width;
+        self
+    }
+
+    pub fn with_glideslope(mut self, glideslope: IlsGlideslope) -> Self {
+        self.glideslope = Some(glideslope);
+        self
+    }
+
+    pub fn with_dme(mut self, dme_position: GeographicPosition) -> Self {
+        self.dme_position = Some(dme_position);
+        self
+    }
+
+    pub fn ident(&self) -> &str {
+        &self.ident
+    }
+
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    pub fn magnetic_course(&self) -> Angle {
+        self.magnetic_course
+    }
+
+    pub fn course(&self) -> Angle {
+        self.course
+    }
+
+    pub fn glideslope(&self) -> Option<IlsGlideslope> {
+        self.glideslope
+    }
+
+    /// The point on the runway from which the glide path originates. It is located such that an
+    /// aircraft on the glide path crosses the threshold at the threshold crossing height.
+    fn glide_path_origin(&self, glideslope: &IlsGlideslope) -> GeographicPosition {
+        self.threshold_position.moved_by(
+            self.course,
+            glideslope.threshold_crossing_height / glideslope.angle.get::<radian>().tan(),
+        )
+    }
+}
 
+/// Provides the definitions of the navaids a receiver can be tuned to.
+pub trait NavaidDataProvider {
+    fn ils_navaid(&self, frequency: Frequency) -> Option<&IlsNavaid>;
+}
+
+/// A simple navaid data provider backed by a list of navaid definitions.
+#[derive(Default)]
+pub struct NavaidDatabase {
+    ils_navaids: Vec<IlsNavaid>,
+}
+impl NavaidDatabase {
+    pub fn new(ils_navaids: Vec<IlsNavaid>) -> Self {
+        Self { ils_navaids }
+    }
+
+    pub fn add_ils(&mut self, navaid: IlsNavaid) {
+        self.ils_navaids.push(navaid);
+    }
+}
+impl NavaidDataProvider for NavaidDatabase {
+    fn ils_navaid(&self, frequency: Frequency) -> Option<&IlsNavaid> {
+        self.ils_navaids
+            .iter()
+            .find(|navaid| is_same_channel(navaid.frequency, frequency))
+    }
+}
+
+/// Frequencies are spaced by at least 50 kHz, thus anything within half of that
+/// is considered the same channel.
+fn is_same_channel(frequency: Frequency, other: Frequency) -> bool {
+    const CHANNEL_TOLERANCE_HZ: f64 = 25_000.;
+
+    (frequency - other).abs().get::<hertz>() < CHANNEL_TOLERANCE_HZ
+}
+
+/// Provides the definition of the ILS a multi mode receiver is tuned to. The FMS publishes the
+/// navigation database entry of the ILS it tuned, together with the frequency set on the receiver.
+pub struct TunedIlsNavaid {
+    tuned_frequency_id: VariableIdentifier,
+    ident_id: VariableIdentifier,
     frequency_id: VariableIdentifier,
-    nav_valid_id: VariableIdentifier,
-    loc_valid_id: VariableIdentifier,
-    localizer_course_id: VariableIdentifier,
+    magnetic_course_id: VariableIdentifier,
+    course_id: VariableIdentifier,
+    localizer_latitude_id: VariableIdentifier,
+    localizer_longitude_id: VariableIdentifier,
+    glideslope_angle_id: VariableIdentifier,
+    glideslope_latitude_id: VariableIdentifier,
+    glideslope_longitude_id: VariableIdentifier,
+    glideslope_elevation_id: VariableIdentifier,
+    has_dme_id: VariableIdentifier,
+    dme_latitude_id: VariableIdentifier,
+    dme_longitude_id: VariableIdentifier,
+    dme_elevation_id: VariableIdentifier,
+
+    tuned_frequency: Frequency,
+    navaid: Option<IlsNavaid>,
+}
+impl TunedIlsNavaid {
+    /// The database does not contain the threshold crossing height, thus the standard height is used.
+    const THRESHOLD_CROSSING_HEIGHT_FEET: f64 = 50.;
+
+    pub fn new(context: &mut InitContext, number: usize) -> Self {
+        let mut ils_identifier =
+            |name: &str| context.get_identifier(format!("MMR_{}_ILS_{}", number, name));
+
+        Self {
+            ident_id: ils_identifier("IDENT"),
+            frequency_id: ils_identifier("FREQUENCY"),
+            magnetic_course_id: ils_identifier("MAGNETIC_COURSE"),
+            course_id: ils_identifier("TRUE_COURSE"),
+            localizer_latitude_id: ils_identifier("LOC_LATITUDE"),
+            localizer_longitude_id: ils_identifier("LOC_LONGITUDE"),
+            glideslope_angle_id: ils_identifier("GS_ANGLE"),
+            glideslope_latitude_id: ils_identifier("GS_LATITUDE"),
+            glideslope_longitude_id: ils_identifier("GS_LONGITUDE"),
+            glideslope_elevation_id: ils_identifier("GS_ELEVATION"),
+            has_dme_id: ils_identifier("HAS_DME"),
+            dme_latitude_id: ils_identifier("DME_LATITUDE"),
+            dme_longitude_id: ils_identifier("DME_LONGITUDE"),
+            dme_elevation_id: ils_identifier("DME_ELEVATION"),
+            // The MMRs are tuned through the third and fourth NAV radio of the simulator.
+            tuned_frequency_id: context.get_identifier(format!("NAV FREQUENCY:{}", number + 2)),
+
+            tuned_frequency: Frequency::default(),
+            navaid: None,
+        }
+    }
+
+    pub fn tuned_frequency(&self) -> Frequency {
+        self.tuned_frequency
+    }
+
+    /// Decodes an ident of up to four ISO 5 characters packed into seven bits each.
+    fn decoded_ident(packed_ident: u32) -> String {
+        (0..4)
+            .map(|i| ((packed_ident >> (7 * i)) & 0x7f) as u8)
+            .take_while(|&c| c != 0)
+            .map(char::from)
+            .collect()
+    }
+}
+impl NavaidDataProvider for TunedIlsNavaid {
+    fn ils_navaid(&self, frequency: Frequency) -> Option<&IlsNavaid> {
+        self.navaid
+            .as_ref()
+            .filter(|navaid| is_same_channel(navaid.frequency, frequency))
+    }
+}
+impl SimulationElement for TunedIlsNavaid {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.tuned_frequency = reader.read(&self.tuned_frequency_id);
+
+        let frequency: Frequency = reader.read(&self.frequency_id);
+        if frequency <= Frequency::default() {
+            self.navaid = None;
+            return;
+        }
+
+        let magnetic_course: Angle = reader.read(&self.magnetic_course_id);
+        let course: Angle = reader.read(&self.course_id);
+        let glideslope_angle: Angle = reader.read(&self.glideslope_angle_id);
+        let glideslope_position = GeographicPosition::new(
+            reader.read(&self.glideslope_latitude_id),
+            reader.read(&self.glideslope_longitude_id),
+            reader.read(&self.glideslope_elevation_id),
+        );
+        let localizer_position = GeographicPosition::new(
+            reader.read(&self.localizer_latitude_id),
+            reader.read(&self.localizer_longitude_id),
+            glideslope_position.elevation(),
+        );
+
+        let glideslope = (glideslope_angle > Angle::default()).then(|| {
+            IlsGlideslope::new(
+                glideslope_angle,
+                Length::new::<foot>(Self::THRESHOLD_CROSSING_HEIGHT_FEET),
+            )
+        });
+        // The glide path originates abeam the glideslope antenna, from which the threshold position
+        // follows. Without a glideslope the threshold is only used to place beam bends.
+        let threshold_position = glideslope.map_or(localizer_position, |glideslope| {
+            glideslope_position.moved_by(
+                course + Angle::new::<degree>(180.),
+                glideslope.threshold_crossing_height() / glideslope.angle().get::<radian>().tan(),
+            )
+        });
+
+        let packed_ident: f64 = reader.read(&self.ident_id);
+        let mut navaid = IlsNavaid::new(
+            &Self::decoded_ident(packed_ident as u32),
+            frequency,
+            magnetic_course,
+            course,
+            localizer_position,
+            threshold_position,
+        );
+        if let Some(glideslope) = glideslope {
+            navaid = navaid.with_glideslope(glideslope);
+        }
+        if reader.read(&self.has_dme_id) {
+            navaid = navaid.with_dme(GeographicPosition::new(
+                reader.read(&self.dme_latitude_id),
+                reader.read(&self.dme_longitude_id),
+                reader.read(&self.dme_elevation_id),
+            ));
+        }
+
+        self.navaid = Some(navaid);
+    }
+}
+
+/// The signals received from an ILS ground installation at a given aircraft position.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct IlsSignals {
+    /// Positive when the aircraft is left of the course, i.e. the aircraft needs to fly right.
+    localizer_ddm: Option<Ratio>,
+    /// Positive when the aircraft is above the glide path, i.e. the aircraft needs to fly down.
+    glideslope_ddm: Option<Ratio>,
+    dme_distance: Option<Length>,
+}
+
+/// Computes the ILS signals in space from the navaid geometry.
+struct IlsSignalModel;
+impl IlsSignalModel {
+    const LOCALIZER_FULL_SCALE_DDM: f64 = 0.155;
+    const LOCALIZER_MAX_DDM: f64 = 0.4;
+    const GLIDESLOPE_HALF_SCALE_DDM: f64 = 0.0875;
+    /// The glideslope half sector is 0.12 times the glide path angle as per ICAO Annex 10.
+    const GLIDESLOPE_HALF_SECTOR_RATIO: f64 = 0.12;
+
+    /// Coverage as per ICAO Annex 10: 25 NM within 10° of the course line and 17 NM
+    /// within 35° of the course line.
+    const LOCALIZER_NARROW_COVERAGE_ANGLE_DEGREES: f64 = 10.;
+    const LOCALIZER_NARROW_COVERAGE_RANGE_NM: f64 = 25.;
+    const LOCALIZER_WIDE_COVERAGE_ANGLE_DEGREES: f64 = 35.;
+    const LOCALIZER_WIDE_COVERAGE_RANGE_NM: f64 = 17.;
+
+    /// Glideslope coverage is 10 NM within 8° of the course line. Vertically the signal is
+    /// receivable well beyond the guaranteed 1.75 times the glide path angle, which is where the
+    /// false glide paths are encountered.
+    const GLIDESLOPE_COVERAGE_ANGLE_DEGREES: f64 = 8.;
+    const GLIDESLOPE_COVERAGE_RANGE_NM: f64 = 10.;
+    const GLIDESLOPE_MAX_ELEVATION_RATIO: f64 = 3.5;
+
+    const DME_RANGE_NM: f64 = 50.;
+
+    fn signals(navaid: &IlsNavaid, aircraft: &GeographicPosition) -> IlsSignals {
+        let (bearing_from_localizer, distance_to_localizer) =
+            aircraft.bearing_and_distance_from(&navaid.localizer_position);
+
+        // The angle between the aircraft and the approach course line as seen from the localizer
+        // antenna. The approach course line extends from the antenna in the opposite direction of
+        // the course.
+        let course_angle =
+            normalize_180(bearing_from_localizer - navaid.course - Angle::new::<degree>(180.));
+        let is_on_back_beam = course_angle.abs() > Angle::new::<degree>(90.);
+        let off_course_angle = if course_angle < Angle::new::<degree>(-90.) {
+            Angle::new::<degree>(-180.) - course_angle
+        } else if course_angle > Angle::new::<degree>(90.) {
+            Angle::new::<degree>(180.) - course_angle
+        } else {
+            course_angle
+        };
+
+        let localizer_ddm = if Self::localizer_in_coverage(off_course_angle, distance_to_localizer)
+        {
+            Some(Ratio::new::<ratio>(
+                (Self::LOCALIZER_FULL_SCALE_DDM * off_course_angle.get::<degree>()
+                    / (navaid.course_width.get::<degree>() / 2.))
+                    .clamp(-Self::LOCALIZER_MAX_DDM, Self::LOCALIZER_MAX_DDM),
+            ))
+        } else {
+            None
+        };
+
+        let glideslope_ddm = if is_on_back_beam {
+            None
+        } else {
+            navaid
+                .glideslope
+                .and_then(|glideslope| Self::glideslope_ddm(navaid, &glideslope, aircraft))
+        };
+
+        let dme_distance = navaid.dme_position.and_then(|dme_position| {
+            let (_, horizontal_distance) = aircraft.bearing_and_distance_from(&dme_position);
+            let slant_range = Length::new::<meter>(
+                horizontal_distance
+                    .get::<meter>()
+                    .hypot((aircraft.elevation - dme_position.elevation).get::<meter>()),
+            );
+
+            if slant_range.get::<nautical_mile>() <= Self::DME_RANGE_NM {
+                Some(slant_range)
+            } else {
+                None
+            }
+        });
+
+        IlsSignals {
+            localizer_ddm,
+            glideslope_ddm,
+            dme_distance,
+        }
+    }
+
+    fn localizer_in_coverage(off_course_angle: Angle, distance: Length) -> bool {
+        let angle = off_course_angle.abs().get::<degree>();
+        let distance = distance.get::<nautical_mile>();
+
+        (angle <= Self::LOCALIZER_NARROW_COVERAGE_ANGLE_DEGREES
+            && distance <= Self::LOCALIZER_NARROW_COVERAGE_RANGE_NM)
+            || (angle <= Self::LOCALIZER_WIDE_COVERAGE_ANGLE_DEGREES
+                && distance <= Self::LOCALIZER_WIDE_COVERAGE_RANGE_NM)
+    }
+
+    /// The glideslope DDM is modelled as a sinusoidal function of the elevation angle above the
+    /// glide path. This results in the typical false glide paths at twice (reversed sensing) and
+    /// three times (normal sensing) the glide path angle, as produced by a null reference
+    /// antenna array. Below the glide path the DDM saturates at a fly up indication.
+    fn glideslope_ddm(
+        navaid: &IlsNavaid,
+        glideslope: &IlsGlideslope,
+        aircraft: &GeographicPosition,
+    ) -> Option<Ratio> {
+        let origin = navaid.glide_path_origin(glideslope);
+        let (bearing_from_origin, distance_to_origin) = aircraft.bearing_and_distance_from(&origin);
+
+        let off_course_angle =
+            normalize_180(bearing_from_origin - navaid.course - Angle::new::<degree>(180.));
+        if off_course_angle.abs().get::<degree>() > Self::GLIDESLOPE_COVERAGE_ANGLE_DEGREES
+            || distance_to_origin.get::<nautical_mile>() > Self::GLIDESLOPE_COVERAGE_RANGE_NM
+        {
+            return None;
+        }
+
+        let height = aircraft.elevation - navaid.threshold_position.elevation;
+        let elevation_angle = Angle::new::<radian>(
+            height
+                .get::<meter>()
+                .atan2(distance_to_origin.get::<meter>().max(1.)),
+        );
+
+        let path_angle = glideslope.angle.get::<degree>();
+        let elevation_ratio = elevation_angle.get::<degree>() / path_angle;
+        if elevation_ratio > Self::GLIDESLOPE_MAX_ELEVATION_RATIO {
+            return None;
+        }
+
+        let half_sector = Self::GLIDESLOPE_HALF_SECTOR_RATIO * path_angle;
+        let linear_ddm = Self::GLIDESLOPE_HALF_SCALE_DDM
+            * (elevation_angle.get::<degree>() - path_angle)
+            / half_sector;
+        // The peak of the sinusoid is chosen such that its slope at the glide path matches the
+        // linear sensitivity of the glideslope.
+        let peak_ddm = Self::GLIDESLOPE_HALF_SCALE_DDM
+            / (Self::GLIDESLOPE_HALF_SECTOR_RATIO * std::f64::consts::PI);
+
+        Some(Ratio::new::<ratio>(if elevation_ratio <= 1. {
+            linear_ddm.max(-peak_ddm)
+        } else {
+            peak_ddm * (std::f64::consts::PI * (elevation_ratio - 1.)).sin()
+        }))
+    }
+
+    /// The distance from the threshold measured along the course, used to place beam bends.
+    fn along_course_distance(navaid: &IlsNavaid, aircraft: &GeographicPosition) -> Length {
+        let (bearing, distance) = aircraft.bearing_and_distance_from(&navaid.threshold_position);
+
+        -distance * normalize_180(bearing - navaid.course).get::<radian>().cos()
+    }
+}
+
+/// A native model of a multi mode receiver ILS and DME function. The signals are computed from the
+/// navaid definition of the tuned frequency, as provided by a [`NavaidDataProvider`], and the
+/// aircraft antenna position.
+/// VOR is not a function of the multi mode receiver. VOR bearings are still provided by the
+/// simulator NAV radios, which act as the VOR receivers of the aircraft.
+pub struct MultiModeReceiver {
+    number: usize,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    failure: Failure,
+    localizer_signal_loss: Failure,
+    glideslope_signal_loss: Failure,
+    localizer_beam_bend: Failure,
+    glideslope_beam_bend: Failure,
+
+    latitude_id: VariableIdentifier,
+    longitude_id: VariableIdentifier,
+    altitude_id: VariableIdentifier,
+    aircraft_position: GeographicPosition,
+
+    runway_heading_id: VariableIdentifier,
     localizer_deviation_id: VariableIdentifier,
-    glideslope_valid_id: VariableIdentifier,
     glideslope_deviation_id: VariableIdentifier,
+    dme_distance_id: VariableIdentifier,
+
+    tuned_frequency: Frequency,
+    tuned_magnetic_course: Option<Angle>,
+    tuned_ident: Option<String>,
+    signals: IlsSignals,
 }
-impl MultiModeReceiverShim {
-    pub fn new(context: &mut crate::simulation::InitContext) -> Self {
+impl MultiModeReceiver {
+    const LATITUDE_KEY: &'static str = "PLANE LATITUDE";
+    const LONGITUDE_KEY: &'static str = "PLANE LONGITUDE";
+    const ALTITUDE_KEY: &'static str = "PLANE ALTITUDE";
+
+    /// Beam bends are modelled as a spatially periodic DDM offset along the approach course.
+    const LOCALIZER_BEAM_BEND_AMPLITUDE_DDM: f64 = 0.04;
+    const LOCALIZER_BEAM_BEND_WAVELENGTH_NM: f64 = 1.;
+    const GLIDESLOPE_BEAM_BEND_AMPLITUDE_DDM: f64 = 0.06;
+    const GLIDESLOPE_BEAM_BEND_WAVELENGTH_NM: f64 = 0.5;
+
+    pub fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
         Self {
-            frequency: Frequency::default(),
-            nav_valid: false,
-            loc_valid: false,
-            localizer_course: Angle::default(),
-            localizer_deviation: Angle::default(),
-            glideslope_valid: false,
-            glideslope_deviation: Angle::default(),
-
-            frequency_id: context.get_identifier("NAV FREQUENCY:3".to_owned()),
-            nav_valid_id: context.get_identifier("NAV HAS NAV:3".to_owned()),
-            loc_valid_id: context.get_identifier("NAV HAS LOC:3".to_owned()),
-            localizer_course_id: context.get_identifier("FM_LS_COURSE".to_owned()),
-            localizer_deviation_id: context.get_identifier("NAV RADIAL ERROR:3".to_owned()),
-            glideslope_valid_id: context.get_identifier("NAV HAS GLIDE SLOPE:3".to_owned()),
-            glideslope_deviation_id: context.get_identifier("NAV GLIDE SLOPE ERROR:3".to_owned()),
-        }
-    }
-}
-impl MultiModeReceiverShim {
-    /// Corrects an MSFS localiser radial error to give the correct deviations on the back beam.
-    fn get_corrected_localizer_deviation(&self) -> Angle {
-        let normalized_error = Self::normalize_180(self.localizer_deviation);
-
-        if normalized_error < Angle::new::<degree>(-90.0) {
-            Angle::new::<degree>(-180.0) - normalized_error
-        } else if normalized_error > Angle::new::<degree>(90.0) {
-            Angle::new::<degree>(180.0) - normalized_error
+            number,
+
+            powered_by,
+            is_powered: false,
+
+            failure: Failure::new(FailureType::MultiModeReceiver(number)),
+            localizer_signal_loss: Failure::new(FailureType::IlsLocalizerSignalLoss(number)),
+            glideslope_signal_loss: Failure::new(FailureType::IlsGlideslopeSignalLoss(number)),
+            localizer_beam_bend: Failure::new(FailureType::IlsLocalizerBeamBend(number)),
+            glideslope_beam_bend: Failure::new(FailureType::IlsGlideslopeBeamBend(number)),
+
+            latitude_id: context.get_identifier(Self::LATITUDE_KEY.to_owned()),
+            longitude_id: context.get_identifier(Self::LONGITUDE_KEY.to_owned()),
+            altitude_id: context.get_identifier(Self::ALTITUDE_KEY.to_owned()),
+            aircraft_position: GeographicPosition::default(),
+
+            runway_heading_id: context.get_identifier(format!("MMR_{}_RUNWAY_HEADING", number)),
+            localizer_deviation_id: context.get_identifier(format!("MMR_{}_LOC_DEVIATION", number)),
+            glideslope_deviation_id: context.get_identifier(format!("MMR_{}_GS_DEVIATION", number)),
+            dme_distance_id: context.get_identifier(format!("MMR_{}_DME_DISTANCE", number)),
+
+            tuned_frequency: Frequency::default(),
+            tuned_magnetic_course: None,
+            tuned_ident: None,
+            signals: IlsSignals::default(),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        _: &UpdateContext,
+        tuned_frequency: Frequency,
+        navaids: &impl NavaidDataProvider,
+    ) {
+        self.tuned_frequency = tuned_frequency;
+
+        let navaid = if self.is_operative() {
+            navaids.ils_navaid(tuned_frequency)
         } else {
-            normalized_error
+            None
+        };
+
+        self.tuned_magnetic_course = navaid.map(|navaid| navaid.magnetic_course);
+        self.tuned_ident = navaid.map(|navaid| navaid.ident.clone());
+        self.signals = navaid.map_or(IlsSignals::default(), |navaid| {
+            self.degraded_signals(
+                navaid,
+                IlsSignalModel::signals(navaid, &self.aircraft_position),
+            )
+        });
+    }
+
+    fn degraded_signals(&self, navaid: &IlsNavaid, signals: IlsSignals) -> IlsSignals {
+        let along_course_distance =
+            IlsSignalModel::along_course_distance(navaid, &self.aircraft_position)
+                .get::<nautical_mile>();
+        let beam_bend = |amplitude: f64, wavelength: f64| {
+            Ratio::new::<ratio>(
+                amplitude * (2. * std::f64::consts::PI * along_course_distance / wavelength).sin(),
+            )
+        };
+
+        IlsSignals {
+            localizer_ddm: if self.localizer_signal_loss.is_active() {
+                None
+            } else if self.localizer_beam_bend.is_active() {
+                signals.localizer_ddm.map(|ddm| {
+                    ddm + beam_bend(
+                        Self::LOCALIZER_BEAM_BEND_AMPLITUDE_DDM,
+                        Self::LOCALIZER_BEAM_BEND_WAVELENGTH_NM,
+                    )
+                })
+            } else {
+                signals.localizer_ddm
+            },
+            glideslope_ddm: if self.glideslope_signal_loss.is_active() {
+                None
+            } else if self.glideslope_beam_bend.is_active() {
+                signals.glideslope_ddm.map(|ddm| {
+                    ddm + beam_bend(
+                        Self::GLIDESLOPE_BEAM_BEND_AMPLITUDE_DDM,
+                        Self::GLIDESLOPE_BEAM_BEND_WAVELENGTH_NM,
+                    )
+                })
+            } else {
+                signals.glideslope_ddm
+            },
+            dme_distance: signals.dme_distance,
         }
     }
 
-    /// Normalises an angle into the range [-180; 180).
-    fn normalize_180(angle: Angle) -> Angle {
-        let normalized_360 = Self::normalize_360(angle);
+    fn is_operative(&self) -> bool {
+        self.is_powered && !self.failure.is_active()
+    }
 
-        if normalized_360 >= Angle::new::<degree>(180.0) {
-            normalized_360 - Angle::new::<degree>(360.0)
+    fn ssm_for(&self, has_signal: bool) -> SignStatus {
+        if !self.is_operative() {
+            SignStatus::FailureWarning
+        } else if has_signal {
+            SignStatus::NormalOperation
         } else {
-            normalized_360
+            SignStatus::NoComputedData
+        }
+    }
+
+    /// Encodes up to three characters of the ground station ident starting at the given index
+    /// as ISO 5 characters.
+    fn encoded_ident(&self, first_character: usize, characters: usize) -> Arinc429Word<u32> {
+        match &self.tuned_ident {
+            Some(ident) => Arinc429Word::new(
+                ident
+                    .bytes()
+                    .skip(first_character)
+                    .take(characters)
+                    .enumerate()
+                    .fold(0, |word, (i, c)| word | (((c & 0x7f) as u32) << (7 * i))),
+                self.ssm_for(true),
+            ),
+            None => Arinc429Word::new(0, self.ssm_for(false)),
         }
     }
 
-    /// Normalises an angle into the range [0; 360).
-    fn normalize_360(angle: Angle) -> Angle {
-        let angle = angle % Angle::new::<degree>(360.0);
+    /// The slant range to the DME associated with the tuned ILS.
+    pub fn dme_distance(&self) -> Arinc429Word<Length> {
+        Arinc429Word::new(
+            self.signals.dme_distance.unwrap_or_default(),
+            self.ssm_for(self.signals.dme_distance.is_some()),
+        )
+    }
 
-        (angle + Angle::new::<degree>(360.0)) % Angle::new::<degree>(360.0)
+    pub fn has_failed(&self) -> bool {
+        self.failure.is_active()
+    }
+
+    pub fn number(&self) -> usize {
+        self.number
     }
 }
-impl InstrumentLandingSystemBus for MultiModeReceiverShim {
+impl InstrumentLandingSystemBus for MultiModeReceiver {
     fn runway_heading(&self) -> Arinc429Word<Angle> {
         Arinc429Word::new(
-            self.localizer_course,
-            if self.loc_valid {
-                SignStatus::NormalOperation
-            } else {
-                SignStatus::NoComputedData
-            },
+            self.tuned_magnetic_course.unwrap_or_default(),
+            self.ssm_for(self.tuned_magnetic_course.is_some()),
         )
     }
+
     fn ils_frequency(&self) -> Arinc429Word<Frequency> {
-        Arinc429Word::new(
-            self.frequency,
-            if self.nav_valid {
-                SignStatus::NormalOperation
-            } else {
-                SignStatus::NoComputedData
-            },
-        )
+        Arinc429Word::new(self.tuned_frequency, self.ssm_for(true))
     }
+
     fn localizer_deviation(&self) -> Arinc429Word<Ratio> {
         Arinc429Word::new(
-            self.get_corrected_localizer_deviation() / Angle::new::<degree>(0.8) * 0.0775,
-            if self.loc_valid {
-                SignStatus::NormalOperation
-            } else {
-                SignStatus::NoComputedData
-            },
+            self.signals.localizer_ddm.unwrap_or_default(),
+            self.ssm_for(self.signals.localizer_ddm.is_some()),
         )
     }
+
     fn glideslope_deviation(&self) -> Arinc429Word<Ratio> {
         Arinc429Word::new(
-            self.glideslope_deviation / Angle::new::<degree>(0.4) * 0.0875,
-            if self.glideslope_valid {
-                SignStatus::NormalOperation
-            } else {
-                SignStatus::NoComputedData
-            },
+            self.signals.glideslope_ddm.unwrap_or_default(),
+            self.ssm_for(self.signals.glideslope_ddm.is_some()),
         )
     }
+
     fn ground_station_ident_1(&self) -> Arinc429Word<u32> {
-        Arinc429Word::new(0, SignStatus::NoComputedData) // Placeholder implementation
+        self.encoded_ident(0, 3)
     }
+
     fn ground_station_ident_2(&self) -> Arinc429Word<u32> {
-        Arinc429Word::new(0, SignStatus::NoComputedData) // Placeholder implementation
-    }
-}
-impl SimulationElement for MultiModeReceiverShim {
-    fn read(&mut self, reader: &mut crate::simulation::SimulatorReader) {
-        self.frequency = reader.read(&self.frequency_id);
-        self.nav_valid = reader.read(&self.nav_valid_id);
-        self.loc_valid = reader.read(&self.loc_valid_id);
-        self.localizer_course = reader.read(&self.localizer_course_id);
-        self.localizer_deviation = reader.read(&self.localizer_deviation_id);
-        self.glideslope_valid = reader.read(&self.glideslope_valid_id);
-        self.glideslope_deviation = reader.read(&self.glideslope_deviation_id);
+        self.encoded_ident(3, 1)
+    }
+}
+impl SimulationElement for MultiModeReceiver {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+        self.localizer_signal_loss.accept(visitor);
+        self.glideslope_signal_loss.accept(visitor);
+        self.localizer_beam_bend.accept(visitor);
+        self.glideslope_beam_bend.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.aircraft_position = GeographicPosition::new(
+            reader.read(&self.latitude_id),
+            reader.read(&self.longitude_id),
+            reader.read(&self.altitude_id),
+        );
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        let runway_heading = self.runway_heading();
+        writer.write_arinc429(
+            &self.runway_heading_id,
+            runway_heading.value(),
+            runway_heading.ssm(),
+        );
+
+        let localizer_deviation = self.localizer_deviation();
+        writer.write_arinc429(
+            &self.localizer_deviation_id,
+            localizer_deviation.value().get::<ratio>(),
+            localizer_deviation.ssm(),
+        );
+
+        let glideslope_deviation = self.glideslope_deviation();
+        writer.write_arinc429(
+            &self.glideslope_deviation_id,
+            glideslope_deviation.value().get::<ratio>(),
+            glideslope_deviation.ssm(),
+        );
+
+        let dme_distance = self.dme_distance();
+        writer.write_arinc429(
+            &self.dme_distance_id,
+            dme_distance.value().get::<nautical_mile>(),
+            dme_distance.ssm(),
+        );
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if !self.has_failed() {
+            consumption.consume_from_bus(self.powered_by, Power::new::<watt>(40.))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::test::TestElectricitySource;
+    use crate::electrical::{ElectricalBus, Electricity};
+    use crate::shared::PotentialOrigin;
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, InitContext, SimulationElementVisitor};
+    use ntest::assert_about_eq;
+    use uom::si::length::foot;
+    use uom::si::{electric_potential::volt, f64::ElectricPotential, frequency::megahertz};
+
+    const ILS_FREQUENCY_MHZ: f64 = 110.3;
+
+    fn threshold() -> GeographicPosition {
+        GeographicPosition::new(
+            Angle::new::<degree>(47.),
+            Angle::new::<degree>(8.),
+            Length::new::<foot>(1400.),
+        )
+    }
+
+    fn runway_course() -> Angle {
+        Angle::new::<degree>(360.)
+    }
+
+    /// The localizer course is published magnetic, with 2° of easterly variation.
+    fn runway_magnetic_course() -> Angle {
+        Angle::new::<degree>(358.)
+    }
+
+    fn test_navaid() -> IlsNavaid {
+        // A 3000 m runway with the localizer antenna 300 m beyond the far end.
+        IlsNavaid::new(
+            "IZRH",
+            Frequency::new::<megahertz>(ILS_FREQUENCY_MHZ),
+            runway_magnetic_course(),
+            runway_course(),
+            threshold().moved_by(runway_course(), Length::new::<meter>(3300.)),
+            threshold(),
+        )
+        .with_glideslope(IlsGlideslope::new(
+            Angle::new::<degree>(3.),
+            Length::new::<foot>(50.),
+        ))
+        .with_dme(threshold())
+    }
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        ac_1_bus: ElectricalBus,
+        mmr: MultiModeReceiver,
+        navaids: NavaidDatabase,
+        tuned_frequency: Frequency,
+        is_ac_1_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                ac_1_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                mmr: MultiModeReceiver::new(context, 1, ElectricalBusType::AlternatingCurrent(1)),
+                navaids: NavaidDatabase::new(vec![test_navaid()]),
+                tuned_frequency: Frequency::new::<megahertz>(ILS_FREQUENCY_MHZ),
+                is_ac_1_powered: true,
+            }
+        }
+
+        fn set_ac_1_power(&mut self, is_powered: bool) {
+            self.is_ac_1_powered = is_powered;
+        }
+
+        fn tune(&mut self, frequency: Frequency) {
+            self.tuned_frequency = frequency;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(115.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_ac_1_powered {
+                electricity.flow(&self.electricity_source, &self.ac_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.mmr
+                .update(context, self.tuned_frequency, &self.navaids);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.mmr.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct MultiModeReceiverTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl MultiModeReceiverTestBed {
+        fn new() -> Self {
+            Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            }
+        }
+
+        /// Positions the aircraft on the approach side of the threshold at the given distance,
+        /// lateral offset (positive to the right of the course) and height above the threshold.
+        fn at(mut self, distance: Length, lateral_offset: Length, height: Length) -> Self {
+            let position = threshold()
+                .moved_by(runway_course() + Angle::new::<degree>(180.), distance)
+                .moved_by(runway_course() + Angle::new::<degree>(90.), lateral_offset);
+
+            self.write_by_name(MultiModeReceiver::LATITUDE_KEY, position.latitude());
+            self.write_by_name(MultiModeReceiver::LONGITUDE_KEY, position.longitude());
+            self.write_by_name(
+                MultiModeReceiver::ALTITUDE_KEY,
+                threshold().elevation() + height,
+            );
+            self
+        }
+
+        /// Positions the aircraft on the approach course at the given distance from the threshold
+        /// and the given elevation angle above the glide path origin.
+        fn at_elevation_angle(self, distance: Length, elevation_angle: Angle) -> Self {
+            let distance_from_origin = distance
+                + Length::new::<foot>(50.) / Angle::new::<degree>(3.).get::<radian>().tan();
+
+            self.at(
+                distance,
+                Length::default(),
+                distance_from_origin * elevation_angle.get::<radian>().tan(),
+            )
+        }
+
+        fn on_glide_path(self, distance: Length) -> Self {
+            self.at_elevation_angle(distance, Angle::new::<degree>(3.))
+        }
+
+        fn no_power(mut self) -> Self {
+            self.command(|a| a.set_ac_1_power(false));
+            self
+        }
+
+        fn tuned_to(mut self, frequency: Frequency) -> Self {
+            self.command(|a| a.tune(frequency));
+            self
+        }
+
+        fn failed(mut self, failure_type: FailureType) -> Self {
+            self.fail(failure_type);
+            self
+        }
+
+        fn and(self) -> Self {
+            self
+        }
+
+        fn run_once(mut self) -> Self {
+            self.run();
+            self
+        }
+
+        fn localizer_deviation(&self) -> Arinc429Word<Ratio> {
+            self.query(|a| a.mmr.localizer_deviation())
+        }
+
+        fn glideslope_deviation(&self) -> Arinc429Word<Ratio> {
+            self.query(|a| a.mmr.glideslope_deviation())
+        }
+
+        fn dme_distance(&self) -> Arinc429Word<Length> {
+            self.query(|a| a.mmr.dme_distance())
+        }
+
+        fn runway_heading(&self) -> Arinc429Word<Angle> {
+            self.query(|a| a.mmr.runway_heading())
+        }
+
+        fn ground_station_ident_1(&self) -> Arinc429Word<u32> {
+            self.query(|a| a.mmr.ground_station_ident_1())
+        }
+
+        fn published_glideslope_deviation(&mut self) -> Arinc429Word<f64> {
+            self.read_arinc429_by_name("MMR_1_GS_DEVIATION")
+        }
+    }
+    impl TestBed for MultiModeReceiverTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> MultiModeReceiverTestBed {
+        MultiModeReceiverTestBed::new()
+    }
+
+    fn nm(distance: f64) -> Length {
+        Length::new::<nautical_mile>(distance)
+    }
+
+    #[test]
+    fn centred_on_glide_path_has_no_deviation() {
+        let test_bed = test_bed().on_glide_path(nm(5.)).run_once();
+
+        assert!(test_bed.localizer_deviation().is_normal_operation());
+        assert!(test_bed.glideslope_deviation().is_normal_operation());
+        assert_about_eq!(
+            test_bed.localizer_deviation().value().get::<ratio>(),
+            0.,
+            0.001
+        );
+        assert_about_eq!(
+            test_bed.glideslope_deviation().value().get::<ratio>(),
+            0.,
+            0.001
+        );
+    }
+
+    #[test]
+    fn runway_heading_is_magnetic_localizer_course() {
+        let test_bed = test_bed().on_glide_path(nm(5.)).run_once();
+
+        assert!(test_bed.runway_heading().is_normal_operation());
+        assert_about_eq!(test_bed.runway_heading().value().get::<degree>(), 358.);
+    }
+
+    #[test]
+    fn left_of_course_gives_positive_localizer_deviation() {
+        let test_bed = test_bed()
+            .at(
+                nm(5.),
+                Length::new::<meter>(-200.),
+                Length::new::<foot>(1600.),
+            )
+            .run_once();
+
+        assert!(test_bed.localizer_deviation().value().get::<ratio>() > 0.);
+    }
+
+    #[test]
+    fn right_of_course_gives_negative_localizer_deviation() {
+        let test_bed = test_bed()
+            .at(
+                nm(5.),
+                Length::new::<meter>(200.),
+                Length::new::<foot>(1600.),
+            )
+            .run_once();
+
+        assert!(test_bed.localizer_deviation().value().get::<ratio>() < 0.);
+    }
+
+    #[test]
+    fn localizer_reaches_full_scale_at_half_course_width() {
+        // 2.5° off course as seen from the localizer antenna.
+        let distance_from_localizer = nm(5.) + Length::new::<meter>(3300.);
+        let offset = distance_from_localizer * Angle::new::<degree>(2.5).get::<radian>().tan();
+
+        let test_bed = test_bed()
+            .at(nm(5.), -offset, Length::new::<foot>(1600.))
+            .run_once();
+
+        assert_about_eq!(
+            test_bed.localizer_deviation().value().get::<ratio>(),
+            0.155,
+            0.002
+        );
+    }
+
+    #[test]
+    fn localizer_deviation_saturates_far_off_course() {
+        let test_bed = test_bed()
+            .at(nm(5.), nm(-2.), Length::new::<foot>(1600.))
+            .run_once();
+
+        assert_about_eq!(test_bed.localizer_deviation().value().get::<ratio>(), 0.4);
+    }
+
+    #[test]
+    fn above_glide_path_gives_positive_glideslope_deviation() {
+        let test_bed = test_bed()
+            .at_elevation_angle(nm(5.), Angle::new::<degree>(3.36))
+            .run_once();
+
+        // 0.12 times the path angle above the path is half scale.
+        assert_about_eq!(
+            test_bed.glideslope_deviation().value().get::<ratio>(),
+            0.0875,
+            0.01
+        );
+    }
+
+    #[test]
+    fn publishes_glideslope_deviation_in_ddm() {
+        let mut test_bed = test_bed()
+            .at_elevation_angle(nm(5.), Angle::new::<degree>(3.36))
+            .run_once();
+
+        let published_deviation = test_bed.published_glideslope_deviation();
+        assert!(published_deviation.is_normal_operation());
+        assert_about_eq!(published_deviation.value(), 0.0875, 0.01);
+    }
+
+    #[test]
+    fn below_glide_path_gives_saturated_fly_up_deviation() {
+        let test_bed = test_bed()
+            .at_elevation_angle(nm(5.), Angle::new::<degree>(1.))
+            .run_once();
+
+        assert!(test_bed.glideslope_deviation().value().get::<ratio>() < -0.2);
+    }
+
+    #[test]
+    fn false_glide_path_at_twice_the_path_angle_has_reversed_sensing() {
+        let slightly_below = test_bed()
+            .at_elevation_angle(nm(5.), Angle::new::<degree>(5.8))
+            .run_once();
+        let slightly_above = test_bed()
+            .at_elevation_angle(nm(5.), Angle::new::<degree>(6.2))
+            .run_once();
+
+        assert!(slightly_below.glideslope_deviation().value().get::<ratio>() > 0.);
+        assert!(slightly_above.glideslope_deviation().value().get::<ratio>() < 0.);
+    }
+
+    #[test]
+    fn false_glide_path_at_three_times_the_path_angle_has_normal_sensing() {
+        let slightly_below = test_bed()
+            .at_elevation_angle(nm(5.), Angle::new::<degree>(8.8))
+            .run_once();
+        let slightly_above = test_bed()
+            .at_elevation_angle(nm(5.), Angle::new::<degree>(9.2))
+            .run_once();
+
+        assert!(slightly_below.glideslope_deviation().value().get::<ratio>() < 0.);
+        assert!(slightly_above.glideslope_deviation().value().get::<ratio>() > 0.);
+    }
+
+    #[test]
+    fn glideslope_is_not_received_beyond_its_range() {
+        let test_bed = test_bed().on_glide_path(nm(15.)).run_once();
+
+        assert!(test_bed.localizer_deviation().is_normal_operation());
+        assert!(test_bed.glideslope_deviation().is_no_computed_data());
+    }
+
+    #[test]
+    fn localizer_is_not_received_beyond_its_range() {
+        let test_bed = test_bed().on_glide_path(nm(30.)).run_once();
+
+        assert!(test_bed.localizer_deviation().is_no_computed_data());
+        assert!(test_bed.glideslope_deviation().is_no_computed_data());
+    }
+
+    #[test]
+    fn back_beam_provides_localizer_without_glideslope() {
+        // Beyond the localizer antenna, on the extended runway centreline.
+        let test_bed = test_bed()
+            .at(
+                nm(-6.),
+                Length::new::<meter>(0.),
+                Length::new::<foot>(2000.),
+            )
+            .run_once();
+
+        assert!(test_bed.localizer_deviation().is_normal_operation());
+        assert_about_eq!(
+            test_bed.localizer_deviation().value().get::<ratio>(),
+            0.,
+            0.001
+        );
+        assert!(test_bed.glideslope_deviation().is_no_computed_data());
+    }
+
+    #[test]
+    fn dme_provides_slant_range() {
+        let test_bed = test_bed()
+            .at(nm(5.), Length::default(), Length::default())
+            .run_once();
+
+        assert!(test_bed.dme_distance().is_normal_operation());
+        assert_about_eq!(
+            test_bed.dme_distance().value().get::<nautical_mile>(),
+            5.,
+            0.01
+        );
+    }
+
+    #[test]
+    fn encodes_ground_station_ident() {
+        let test_bed = test_bed().on_glide_path(nm(5.)).run_once();
+
+        let word = test_bed.ground_station_ident_1();
+        assert!(word.is_normal_operation());
+        assert_eq!(word.value() & 0x7f, b'I' as u32);
+        assert_eq!((word.value() >> 7) & 0x7f, b'Z' as u32);
+        assert_eq!((word.value() >> 14) & 0x7f, b'R' as u32);
+    }
+
+    #[test]
+    fn tuned_to_frequency_without_navaid_has_no_computed_data() {
+        let test_bed = test_bed()
+            .tuned_to(Frequency::new::<megahertz>(109.9))
+            .and()
+            .on_glide_path(nm(5.))
+            .run_once();
+
+        assert!(test_bed.localizer_deviation().is_no_computed_data());
+        assert!(test_bed.glideslope_deviation().is_no_computed_data());
+        assert!(test_bed.runway_heading().is_no_computed_data());
+    }
+
+    #[test]
+    fn unpowered_receiver_has_failure_warning() {
+        let test_bed = test_bed().no_power().and().on_glide_path(nm(5.)).run_once();
+
+        assert!(test_bed.localizer_deviation().is_failure_warning());
+        assert!(test_bed.glideslope_deviation().is_failure_warning());
+    }
+
+    #[test]
+    fn unpowered_receiver_publishes_failure_warning() {
+        let mut test_bed = test_bed().no_power().and().on_glide_path(nm(5.)).run_once();
+
+        assert!(test_bed
+            .published_glideslope_deviation()
+            .is_failure_warning());
+    }
+
+    #[test]
+    fn failed_receiver_has_failure_warning() {
+        let test_bed = test_bed()
+            .failed(FailureType::MultiModeReceiver(1))
+            .and()
+            .on_glide_path(nm(5.))
+            .run_once();
+
+        assert!(test_bed.localizer_deviation().is_failure_warning());
+        assert!(test_bed.glideslope_deviation().is_failure_warning());
+    }
+
+    #[test]
+    fn localizer_signal_loss_only_removes_localizer() {
+        let test_bed = test_bed()
+            .failed(FailureType::IlsLocalizerSignalLoss(1))
+            .and()
+            .on_glide_path(nm(5.))
+            .run_once();
+
+        assert!(test_bed.localizer_deviation().is_no_computed_data());
+        assert!(test_bed.glideslope_deviation().is_normal_operation());
+    }
+
+    #[test]
+    fn localizer_signal_loss_of_other_receiver_does_not_remove_localizer() {
+        let test_bed = test_bed()
+            .failed(FailureType::IlsLocalizerSignalLoss(2))
+            .and()
+            .on_glide_path(nm(5.))
+            .run_once();
+
+        assert!(test_bed.localizer_deviation().is_normal_operation());
+    }
+
+    #[test]
+    fn glideslope_signal_loss_only_removes_glideslope() {
+        let test_bed = test_bed()
+            .failed(FailureType::IlsGlideslopeSignalLoss(1))
+            .and()
+            .on_glide_path(nm(5.))
+            .run_once();
+
+        assert!(test_bed.localizer_deviation().is_normal_operation());
+        assert!(test_bed.glideslope_deviation().is_no_computed_data());
+    }
+
+    #[test]
+    fn localizer_beam_bend_deviates_indication_on_course() {
+        let test_bed = test_bed()
+            .failed(FailureType::IlsLocalizerBeamBend(1))
+            .and()
+            .on_glide_path(nm(5.25))
+            .run_once();
+
+        assert_about_eq!(
+            test_bed.localizer_deviation().value().get::<ratio>(),
+            MultiModeReceiver::LOCALIZER_BEAM_BEND_AMPLITUDE_DDM,
+            0.002
+        );
+    }
+
+    #[test]
+    fn glideslope_beam_bend_deviates_indication_on_glide_path() {
+        let test_bed = test_bed()
+            .failed(FailureType::IlsGlideslopeBeamBend(1))
+            .and()
+            .on_glide_path(nm(3.125))
+            .run_once();
+
+        assert_about_eq!(
+            test_bed.glideslope_deviation().value().get::<ratio>(),
+            MultiModeReceiver::GLIDESLOPE_BEAM_BEND_AMPLITUDE_DDM,
+            0.002
+        );
+    }
+
+    struct TunedNavaidTestAircraft {
+        navaid: TunedIlsNavaid,
+    }
+    impl TunedNavaidTestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                navaid: TunedIlsNavaid::new(context, 1),
+            }
+        }
+    }
+    impl Aircraft for TunedNavaidTestAircraft {}
+    impl SimulationElement for TunedNavaidTestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.navaid.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn tuned_navaid_test_bed() -> SimulationTestBed<TunedNavaidTestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TunedNavaidTestAircraft::new);
+        let glideslope_antenna = threshold().moved_by(
+            runway_course(),
+            Length::new::<foot>(50.) / Angle::new::<degree>(3.).get::<radian>().tan(),
+        );
+        let localizer = threshold().moved_by(runway_course(), Length::new::<meter>(3300.));
+
+        test_bed.write_by_name(
+            "NAV FREQUENCY:3",
+            Frequency::new::<megahertz>(ILS_FREQUENCY_MHZ),
+        );
+        test_bed.write_by_name(
+            "MMR_1_ILS_IDENT",
+            (b'I' as u32 | (b'Z' as u32) << 7 | (b'R' as u32) << 14 | (b'H' as u32) << 21) as f64,
+        );
+        test_bed.write_by_name(
+            "MMR_1_ILS_FREQUENCY",
+            Frequency::new::<megahertz>(ILS_FREQUENCY_MHZ),
+        );
+        test_bed.write_by_name("MMR_1_ILS_MAGNETIC_COURSE", runway_magnetic_course());
+        test_bed.write_by_name("MMR_1_ILS_TRUE_COURSE", runway_course());
+        test_bed.write_by_name("MMR_1_ILS_LOC_LATITUDE", localizer.latitude());
+        test_bed.write_by_name("MMR_1_ILS_LOC_LONGITUDE", localizer.longitude());
+        test_bed.write_by_name("MMR_1_ILS_GS_ANGLE", Angle::new::<degree>(3.));
+        test_bed.write_by_name("MMR_1_ILS_GS_LATITUDE", glideslope_antenna.latitude());
+        test_bed.write_by_name("MMR_1_ILS_GS_LONGITUDE", glideslope_antenna.longitude());
+        test_bed.write_by_name("MMR_1_ILS_GS_ELEVATION", threshold().elevation());
+        test_bed.run();
+
+        test_bed
+    }
+
+    #[test]
+    fn tuned_navaid_provides_the_ils_published_by_the_fms() {
+        let test_bed = tuned_navaid_test_bed();
+
+        test_bed.query(|a| {
+            assert_about_eq!(
+                a.navaid.tuned_frequency().get::<megahertz>(),
+                ILS_FREQUENCY_MHZ
+            );
+
+            let navaid = a.navaid.ils_navaid(a.navaid.tuned_frequency()).unwrap();
+            assert_eq!(navaid.ident(), "IZRH");
+            assert_eq!(navaid.magnetic_course(), runway_magnetic_course());
+            assert_eq!(navaid.course(), runway_course());
+            assert_about_eq!(navaid.glideslope().unwrap().angle().get::<degree>(), 3.);
+        });
+    }
+
+    #[test]
+    fn tuned_navaid_places_threshold_ahead_of_glideslope_antenna() {
+        let test_bed = tuned_navaid_test_bed();
+
+        test_bed.query(|a| {
+            let navaid = a.navaid.ils_navaid(a.navaid.tuned_frequency()).unwrap();
+            let (_, distance) = navaid
+                .threshold_position
+                .bearing_and_distance_from(&threshold());
+
+            assert!(distance.get::<meter>() < 1.);
+        });
+    }
+
+    #[test]
+    fn tuned_navaid_is_not_provided_for_another_frequency() {
+        let test_bed = tuned_navaid_test_bed();
+
+        assert!(test_bed.query(|a| a
+            .navaid
+            .ils_navaid(Frequency::new::<megahertz>(109.5))
+            .is_none()));
     }
 }
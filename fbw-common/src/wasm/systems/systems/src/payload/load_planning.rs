@@ -0,0 +1,482 @@
+use uom::si::{f64::Mass, mass::kilogram};
+
+use crate::{
+    shared::interpolation,
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, VariableIdentifier, Write,
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UldType {
+    /// LD3 container
+    Ake,
+    /// 96 x 125 in pallet
+    Pmc,
+    /// 88 x 125 in pallet
+    Pag,
+    /// Loose load in the bulk compartment
+    Bulk,
+}
+impl UldType {
+    pub fn max_gross_mass(&self) -> Mass {
+        Mass::new::<kilogram>(match self {
+            UldType::Ake => 1588.,
+            UldType::Pmc => 6804.,
+            UldType::Pag => 6033.,
+            UldType::Bulk => f64::MAX,
+        })
+    }
+}
+
+pub struct UldPositionInfo<'a> {
+    pub position_id: &'a str,
+    pub cargo_station: usize,
+    pub uld_type: UldType,
+    pub position: f64,
+    pub max_mass_kg: f64,
+}
+
+pub struct UldPosition {
+    planned_id: VariableIdentifier,
+    cargo_station: usize,
+    uld_type: UldType,
+    position: f64,
+    max_mass: Mass,
+    planned: Mass,
+}
+impl UldPosition {
+    pub fn new(context: &mut InitContext, info: &UldPositionInfo) -> Self {
+        UldPosition {
+            planned_id: context.get_identifier(format!("CARGO_PLAN_{}", info.position_id)),
+            cargo_station: info.cargo_station,
+            uld_type: info.uld_type,
+            position: info.position,
+            max_mass: Mass::new::<kilogram>(info.max_mass_kg),
+            planned: Mass::default(),
+        }
+    }
+
+    /// The position limit, or the limit of the ULD if it is lower.
+    pub fn capacity(&self) -> Mass {
+        self.max_mass.min(self.uld_type.max_gross_mass())
+    }
+
+    pub fn position(&self) -> f64 {
+        self.position
+    }
+
+    pub fn planned(&self) -> Mass {
+        self.planned
+    }
+}
+impl SimulationElement for UldPosition {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.planned_id, self.planned.get::<kilogram>());
+    }
+}
+
+/// Limits the combined mass of positions which share the same floor area, such as a pallet
+/// position and the container positions it overlaps.
+pub struct CombinedPositionLimitInfo<'a> {
+    pub position_ids: Vec<&'a str>,
+    pub max_mass_kg: f64,
+}
+
+struct CombinedPositionLimit {
+    positions: Vec<usize>,
+    max_mass: Mass,
+}
+
+/// Distributes a cargo mass over the ULD positions of the cargo compartments.
+///
+/// The cargo is spread evenly over the available capacity. When a target position is given,
+/// cargo is then moved between the outermost positions until the cargo center of gravity
+/// matches the target, without exceeding any position, combined position or combined
+/// compartment limit.
+pub struct CargoLoadPlanner {
+    plan_requested_id: VariableIdentifier,
+    total_cargo_desired_id: VariableIdentifier,
+    zero_fuel_weight_cg_desired_id: VariableIdentifier,
+    unplanned_id: VariableIdentifier,
+
+    plan_requested: bool,
+    total_cargo_desired: Mass,
+    zero_fuel_weight_cg_desired: f64,
+
+    positions: Vec<UldPosition>,
+    combined_limits: Vec<CombinedPositionLimit>,
+    station_limits: Vec<Mass>,
+    unplanned: Mass,
+}
+impl CargoLoadPlanner {
+    const MAX_ITERATIONS: usize = 100;
+    const CG_TOLERANCE: f64 = 0.001;
+
+    /// `station_limits` are the combined load limits of the cargo stations the positions belong to.
+    pub fn new(
+        context: &mut InitContext,
+        positions: &[UldPositionInfo],
+        combined_limits: &[CombinedPositionLimitInfo],
+        station_limits: Vec<Mass>,
+    ) -> Self {
+        let combined_limits = combined_limits
+            .iter()
+            .map(|limit| CombinedPositionLimit {
+                positions: limit
+                    .position_ids
+                    .iter()
+                    .map(|id| {
+                        positions
+                            .iter()
+                            .position(|info| info.position_id == *id)
+                            .unwrap_or_else(|| panic!("Unknown ULD position {}", id))
+                    })
+                    .collect(),
+                max_mass: Mass::new::<kilogram>(limit.max_mass_kg),
+            })
+            .collect();
+
+        CargoLoadPlanner {
+            plan_requested_id: context.get_identifier("CARGO_PLAN_REQUESTED".to_owned()),
+            total_cargo_desired_id: context.get_identifier("CARGO_PLAN_TOTAL_DESIRED".to_owned()),
+            zero_fuel_weight_cg_desired_id: context
+                .get_identifier("CARGO_PLAN_ZFW_CG_DESIRED".to_owned()),
+            unplanned_id: context.get_identifier("CARGO_PLAN_UNPLANNED".to_owned()),
+
+            plan_requested: false,
+            total_cargo_desired: Mass::default(),
+            zero_fuel_weight_cg_desired: 0.,
+
+            positions: positions
+                .iter()
+                .map(|info| UldPosition::new(context, info))
+                .collect(),
+            combined_limits,
+            station_limits,
+            unplanned: Mass::default(),
+        }
+    }
+
+    pub fn is_plan_requested(&self) -> bool {
+        self.plan_requested
+    }
+
+    pub fn total_cargo_desired(&self) -> Mass {
+        self.total_cargo_desired
+    }
+
+    /// The desired zero fuel weight CG in % MAC, if any.
+    pub fn zero_fuel_weight_cg_desired(&self) -> Option<f64> {
+        (self.zero_fuel_weight_cg_desired > 0.).then_some(self.zero_fuel_weight_cg_desired)
+    }
+
+    pub fn positions(&self) -> &[UldPosition] {
+        &self.positions
+    }
+
+    pub fn unplanned(&self) -> Mass {
+        self.unplanned
+    }
+
+    pub fn station_planned(&self, cs: usize) -> Mass {
+        self.positions
+            .iter()
+            .filter(|p| p.cargo_station == cs)
+            .map(|p| p.planned)
+            .sum()
+    }
+
+    /// The planned mass and the limit of each combined position limit.
+    pub fn combined_limits(&self) -> impl Iterator<Item = (Mass, Mass)> + '_ {
+        self.combined_limits
+            .iter()
+            .map(|limit| (self.combined_planned(limit), limit.max_mass))
+    }
+
+    fn combined_planned(&self, limit: &CombinedPositionLimit) -> Mass {
+        limit
+            .positions
+            .iter()
+            .map(|&idx| self.positions[idx].planned)
+            .sum()
+    }
+
+    /// The capacity of a position for the even spread, scaled down so that spreading cargo over
+    /// all positions of a combined position limit doesn't exceed it.
+    fn spread_capacity(&self, idx: usize) -> Mass {
+        let capacity = self.positions[idx].capacity();
+        self.combined_limits
+            .iter()
+            .filter(|limit| limit.positions.contains(&idx))
+            .fold(capacity, |spread_capacity, limit| {
+                let limit_capacity: Mass = limit
+                    .positions
+                    .iter()
+                    .map(|&other| self.positions[other].capacity())
+                    .sum();
+                spread_capacity.min(capacity * (limit.max_mass / limit_capacity).value.min(1.))
+            })
+    }
+
+    fn station_capacity(&self, cs: usize) -> Mass {
+        let positions_capacity: Mass = (0..self.positions.len())
+            .filter(|&idx| self.positions[idx].cargo_station == cs)
+            .map(|idx| self.spread_capacity(idx))
+            .sum();
+        positions_capacity.min(self.station_limits[cs])
+    }
+
+    fn room(&self, idx: usize, donor: usize) -> Mass {
+        let position = &self.positions[idx];
+        let mut room = position.capacity() - position.planned;
+        if self.positions[donor].cargo_station != position.cargo_station {
+            room = room.min(
+                self.station_limits[position.cargo_station]
+                    - self.station_planned(position.cargo_station),
+            );
+        }
+
+        self.combined_limits
+            .iter()
+            .filter(|limit| limit.positions.contains(&idx) && !limit.positions.contains(&donor))
+            .fold(room, |room, limit| {
+                room.min(limit.max_mass - self.combined_planned(limit))
+            })
+    }
+
+    fn planned_center_of_gravity(&self, total: Mass) -> f64 {
+        self.positions
+            .iter()
+            .map(|p| p.planned.get::<kilogram>() * p.position)
+            .sum::<f64>()
+            / total.get::<kilogram>()
+    }
+
+    /// Plans `target` cargo, optionally aiming for a cargo center of gravity at `target_position`.
+    pub fn plan(&mut self, target: Mass, target_position: Option<f64>) {
+        self.plan_requested = false;
+
+        let station_capacities: Vec<Mass> = (0..self.station_limits.len())
+            .map(|cs| self.station_capacity(cs))
+            .collect();
+        let total_capacity: Mass = station_capacities.iter().copied().sum();
+
+        let planned_total = target.max(Mass::default()).min(total_capacity);
+        self.unplanned = target - planned_total;
+
+        let ratio = if total_capacity > Mass::default() {
+            (planned_total / total_capacity).value
+        } else {
+            0.
+        };
+        let spread_capacities: Vec<Mass> = (0..self.positions.len())
+            .map(|idx| self.spread_capacity(idx))
+            .collect();
+        for (cs, station_capacity) in station_capacities.iter().enumerate() {
+            let positions_capacity: Mass = self
+                .positions
+                .iter()
+                .zip(&spread_capacities)
+                .filter(|(p, _)| p.cargo_station == cs)
+                .map(|(_, &capacity)| capacity)
+                .sum();
+            let share = *station_capacity * ratio;
+            for (position, &capacity) in self
+                .positions
+                .iter_mut()
+                .zip(&spread_capacities)
+                .filter(|(p, _)| p.cargo_station == cs)
+            {
+                position.planned = share * (capacity / positions_capacity).value;
+            }
+        }
+
+        if let Some(target_position) = target_position {
+            if planned_total > Mass::default() {
+                self.balance(planned_total, target_position);
+            }
+        }
+    }
+
+    fn balance(&mut self, total: Mass, target_position: f64) {
+        for _ in 0..Self::MAX_ITERATIONS {
+            let error = target_position - self.planned_center_of_gravity(total);
+            if error.abs() < Self::CG_TOLERANCE {
+                return;
+            }
+
+            // Positive positions are forward: move cargo forward when the CG is aft of the target
+            let towards_forward = error > 0.;
+            let mut order: Vec<usize> = (0..self.positions.len()).collect();
+            order.sort_by(|a, b| {
+                self.positions[*a]
+                    .position
+                    .total_cmp(&self.positions[*b].position)
+            });
+            if !towards_forward {
+                order.reverse();
+            }
+
+            let Some(donor) = order
+                .iter()
+                .copied()
+                .find(|i| self.positions[*i].planned > Mass::default())
+            else {
+                return;
+            };
+            let Some(receiver) = order
+                .iter()
+                .rev()
+                .copied()
+                .find(|i| self.room(*i, donor) > Mass::default())
+            else {
+                return;
+            };
+
+            let arm = (self.positions[receiver].position - self.positions[donor].position).abs();
+            if arm <= 0.
+                || (self.positions[receiver].position > self.positions[donor].position)
+                    != towards_forward
+            {
+                return;
+            }
+
+            let amount = (total * error.abs() / arm)
+                .min(self.positions[donor].planned)
+                .min(self.room(receiver, donor));
+            self.positions[donor].planned -= amount;
+            self.positions[receiver].planned += amount;
+        }
+    }
+}
+impl SimulationElement for CargoLoadPlanner {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.positions, visitor);
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.plan_requested = reader.read(&self.plan_requested_id);
+        self.total_cargo_desired = Mass::new::<kilogram>(reader.read(&self.total_cargo_desired_id));
+        self.zero_fuel_weight_cg_desired = reader.read(&self.zero_fuel_weight_cg_desired_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.plan_requested_id, self.plan_requested);
+        writer.write(&self.unplanned_id, self.unplanned.get::<kilogram>());
+    }
+}
+
+/// Weight and balance figures of a load sheet.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LoadsheetFigures {
+    pub zero_fuel_weight: Mass,
+    pub zero_fuel_weight_cg_percent_mac: f64,
+    pub take_off_weight: Mass,
+    pub take_off_cg_percent_mac: f64,
+}
+
+/// Issues a load sheet with the planned weight and balance figures and the take-off trim setting
+/// each time one is requested.
+pub struct Loadsheet {
+    requested_id: VariableIdentifier,
+    edition_id: VariableIdentifier,
+    zero_fuel_weight_id: VariableIdentifier,
+    zero_fuel_weight_cg_id: VariableIdentifier,
+    take_off_weight_id: VariableIdentifier,
+    take_off_cg_id: VariableIdentifier,
+    take_off_trim_id: VariableIdentifier,
+
+    take_off_trim_cg_percent_mac: &'static [f64],
+    take_off_trim_degrees: &'static [f64],
+
+    requested: bool,
+    edition: u32,
+    figures: LoadsheetFigures,
+    take_off_trim: f64,
+}
+impl Loadsheet {
+    /// The take-off trim is interpolated from the take-off CG, with nose up trim positive.
+    pub fn new(
+        context: &mut InitContext,
+        take_off_trim_cg_percent_mac: &'static [f64],
+        take_off_trim_degrees: &'static [f64],
+    ) -> Self {
+        Loadsheet {
+            requested_id: context.get_identifier("LOADSHEET_REQUESTED".to_owned()),
+            edition_id: context.get_identifier("LOADSHEET_EDITION".to_owned()),
+            zero_fuel_weight_id: context.get_identifier("LOADSHEET_ZFW".to_owned()),
+            zero_fuel_weight_cg_id: context.get_identifier("LOADSHEET_MACZFW".to_owned()),
+            take_off_weight_id: context.get_identifier("LOADSHEET_TOW".to_owned()),
+            take_off_cg_id: context.get_identifier("LOADSHEET_MACTOW".to_owned()),
+            take_off_trim_id: context.get_identifier("LOADSHEET_TAKEOFF_TRIM".to_owned()),
+
+            take_off_trim_cg_percent_mac,
+            take_off_trim_degrees,
+
+            requested: false,
+            edition: 0,
+            figures: LoadsheetFigures::default(),
+            take_off_trim: 0.,
+        }
+    }
+
+    pub fn update(&mut self, figures: LoadsheetFigures) {
+        if !self.requested {
+            return;
+        }
+
+        self.requested = false;
+        self.edition += 1;
+        self.figures = figures;
+        self.take_off_trim = interpolation(
+            self.take_off_trim_cg_percent_mac,
+            self.take_off_trim_degrees,
+            figures.take_off_cg_percent_mac,
+        );
+    }
+
+    pub fn edition(&self) -> u32 {
+        self.edition
+    }
+
+    pub fn figures(&self) -> LoadsheetFigures {
+        self.figures
+    }
+
+    pub fn take_off_trim(&self) -> f64 {
+        self.take_off_trim
+    }
+}
+impl SimulationElement for Loadsheet {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.requested = reader.read(&self.requested_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.requested_id, self.requested);
+        writer.write(&self.edition_id, self.edition);
+        writer.write(
+            &self.zero_fuel_weight_id,
+            self.figures.zero_fuel_weight.get::<kilogram>().round(),
+        );
+        writer.write(
+            &self.zero_fuel_weight_cg_id,
+            (self.figures.zero_fuel_weight_cg_percent_mac * 10.).round() / 10.,
+        );
+        writer.write(
+            &self.take_off_weight_id,
+            self.figures.take_off_weight.get::<kilogram>().round(),
+        );
+        writer.write(
+            &self.take_off_cg_id,
+            (self.figures.take_off_cg_percent_mac * 10.).round() / 10.,
+        );
+        writer.write(
+            &self.take_off_trim_id,
+            (self.take_off_trim * 10.).round() / 10.,
+        );
+    }
+}
@@ -1,6 +1,12 @@
 use std::{cell::Cell, rc::Rc, time::Duration};
 use uom::si::{f64::Ratio, ratio::percent};
 
+mod load_planning;
+pub use load_planning::{
+    CargoLoadPlanner, CombinedPositionLimitInfo, Loadsheet, LoadsheetFigures, UldPosition,
+    UldPositionInfo, UldType,
+};
+
 use crate::{
     shared::random_from_range,
     simulation::{
@@ -102,13 +108,20 @@ impl<const P: usize> BoardingAgent<P> {
     pub fn is_door_open(&self) -> bool {
         self.door_open_ratio >= Ratio::new::<percent>(100.)
     }
+
+    /// Sets the open amount of the boarding door as provided by the aircraft's door system.
+    /// Only used by agents which don't read their door from the simulator.
+    pub fn set_door_open_ratio(&mut self, door_open_ratio: Ratio) {
+        if self.door_id.is_none() {
+            self.door_open_ratio = door_open_ratio;
+        }
+    }
 }
 impl<const P: usize> SimulationElement for BoardingAgent<P> {
     fn read(&mut self, reader: &mut SimulatorReader) {
-        self.door_open_ratio = self
-            .door_id
-            .map(|door_id| reader.read(&door_id))
-            .unwrap_or_default();
+        if let Some(door_id) = self.door_id {
+            self.door_open_ratio = reader.read(&door_id);
+        }
     }
 }
 
@@ -193,6 +206,14 @@ impl<const N: usize, const G: usize> PassengerDeck<N, G> {
         }
     }
 
+    fn update_boarding_doors(&mut self, door_open_ratios: [Ratio; G]) {
+        for (boarding_agent, door_open_ratio) in
+            self.boarding_agents.iter_mut().zip(door_open_ratios)
+        {
+            boarding_agent.set_door_open_ratio(door_open_ratio);
+        }
+    }
+
     fn update_one_tick(&mut self) {
         let doors_open = self.boarding_agents.iter().any(|ba| ba.is_door_open());
         if doors_open {
@@ -319,6 +340,10 @@ impl<const N: usize> CargoDeck<N> {
         }
     }
 
+    fn set_cargo_target(&mut self, cs: usize, target: Mass) {
+        self.cargo[cs].set_cargo_target(target);
+    }
+
     fn move_one_cargo(&mut self) {
         for cs in &mut self.cargo {
             if cs.cargo_is_target() {
@@ -511,6 +536,7 @@ pub struct Cargo {
     cargo: Mass,
     cargo_loaded: Mass,
     cargo_target: Mass,
+    cargo_target_changed: bool,
     developer_state: Rc<Cell<i8>>,
     payload: Mass,
 
@@ -535,6 +561,7 @@ impl Cargo {
             cargo: Mass::default(),
             cargo_loaded: Mass::default(),
             cargo_target: Mass::default(),
+            cargo_target_changed: false,
             developer_state,
             payload: Mass::default(),
             position,
@@ -622,6 +649,16 @@ impl Cargo {
     pub fn reset_cargo_target(&mut self) {
         self.cargo_target = Mass::default();
     }
+
+    /// Sets the cargo target, which is only written to the simulation when it changed so that it
+    /// doesn't overwrite the target set by the EFB.
+    pub fn set_cargo_target(&mut self, target: Mass) {
+        let target = target.max(Mass::default()).min(self.max_capacity);
+        if target != self.cargo_target {
+            self.cargo_target = target;
+            self.cargo_target_changed = true;
+        }
+    }
 }
 impl SimulationElement for Cargo {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -630,6 +667,7 @@ impl SimulationElement for Cargo {
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.cargo = Mass::new::<kilogram>(reader.read(&self.cargo_id));
         self.cargo_target = Mass::new::<kilogram>(reader.read(&self.cargo_target_id));
+        self.cargo_target_changed = false;
         self.payload = reader.read(&self.payload_id);
         if !self.is_developer_state_active() && !self.payload_is_sync() {
             self.load_payload()
@@ -637,6 +675,9 @@ impl SimulationElement for Cargo {
     }
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.cargo_id, self.cargo.get::<kilogram>());
+        if self.cargo_target_changed {
+            writer.write(&self.cargo_target_id, self.cargo_target.get::<kilogram>());
+        }
         writer.write(&self.payload_id, self.payload);
     }
 }
@@ -844,6 +885,10 @@ impl<const P: usize, const G: usize, const C: usize> PayloadManager<P, G, C> {
         self.cargo_deck.max_cargo(cs)
     }
 
+    pub fn set_cargo_target(&mut self, cs: usize, target: Mass) {
+        self.cargo_deck.set_cargo_target(cs, target)
+    }
+
     pub fn sound_pax_boarding_playing(&self) -> bool {
         self.boarding_sounds.pax_boarding()
     }
@@ -955,6 +1000,10 @@ impl<const P: usize, const G: usize, const C: usize> PayloadManager<P, G, C> {
     }
 
     // ======================================
+    pub fn update_boarding_doors(&mut self, door_open_ratios: [Ratio; G]) {
+        self.passenger_deck.update_boarding_doors(door_open_ratios);
+    }
+
     pub fn update(&mut self, delta_time: Duration) {
         self.update_pax_ambience();
 
@@ -0,0 +1,356 @@
+use uom::si::{
+    f64::*, mass_rate::kilogram_per_second, pressure::psi,
+    thermodynamic_temperature::degree_celsius,
+};
+
+use crate::{
+    failures::{Failure, FailureType},
+    pneumatic::{valve::PneumaticExhaust, PneumaticContainer},
+    shared::{
+        calculate_towards_target_temperature, BleedAirLeakZone, ElectricalBusType, ElectricalBuses,
+    },
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+/// A rupture in a bleed air duct. While the failure is active, hot air escapes from the
+/// duct into the surrounding zone of the aircraft for as long as the duct is pressurised.
+pub struct BleedAirDuctLeak {
+    exhaust: PneumaticExhaust,
+    fluid_flow: MassRate,
+    air_temperature: ThermodynamicTemperature,
+
+    failure: Failure,
+}
+impl BleedAirDuctLeak {
+    /// The `leak_speed` has the same meaning as the exhaust speed of a `PneumaticExhaust`.
+    pub fn new(zone: BleedAirLeakZone, leak_speed: f64) -> Self {
+        Self {
+            exhaust: PneumaticExhaust::new(leak_speed, leak_speed, Pressure::new::<psi>(0.)),
+            fluid_flow: MassRate::default(),
+            air_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+
+            failure: Failure::new(FailureType::BleedAirDuctLeak(zone)),
+        }
+    }
+
+    pub fn update_move_fluid(
+        &mut self,
+        context: &UpdateContext,
+        duct: &mut impl PneumaticContainer,
+    ) {
+        self.air_temperature = duct.temperature();
+
+        if self.failure.is_active() {
+            self.exhaust.update_move_fluid(context, duct);
+            self.fluid_flow = self.exhaust.fluid_flow();
+        } else {
+            self.fluid_flow = MassRate::default();
+        }
+    }
+
+    /// Updates the leak for a duct section which is cut off from all of its bleed air sources,
+    /// in which case no more air escapes through it.
+    pub fn update_isolated(&mut self) {
+        self.fluid_flow = MassRate::default();
+    }
+
+    /// The mass of air leaving the duct per second.
+    pub fn fluid_flow(&self) -> MassRate {
+        self.fluid_flow
+    }
+
+    /// The temperature of the air escaping through the leak.
+    pub fn air_temperature(&self) -> ThermodynamicTemperature {
+        self.air_temperature
+    }
+}
+impl SimulationElement for BleedAirDuctLeak {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// A sensing loop of eutectic salt elements running along the bleed air ducts of a zone.
+/// The zone heats up when hot air escapes from a leaking duct, and the loop detects the
+/// leak once its elements reach their detection temperature.
+pub struct LeakDetectionLoop {
+    leak_detected_id: VariableIdentifier,
+    loop_fault_id: VariableIdentifier,
+
+    zone: BleedAirLeakZone,
+    detection_temperature: ThermodynamicTemperature,
+    zone_temperature: ThermodynamicTemperature,
+    is_initialised: bool,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    failure: Failure,
+}
+impl LeakDetectionLoop {
+    /// A leak of this flow heats the zone up to the temperature of the escaping air.
+    const FULL_ZONE_HEATING_LEAK_FLOW_KG_PER_S: f64 = 0.05;
+    const ZONE_TEMPERATURE_COEFFICIENT: f64 = 50.;
+
+    pub fn new(
+        context: &mut InitContext,
+        zone: BleedAirLeakZone,
+        detection_temperature: ThermodynamicTemperature,
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            leak_detected_id: context.get_identifier(format!("PNEU_{}_LEAK_DETECTED", zone)),
+            loop_fault_id: context
+                .get_identifier(format!("PNEU_{}_LEAK_DETECTION_LOOP_FAULT", zone)),
+
+            zone,
+            detection_temperature,
+            zone_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            is_initialised: false,
+
+            powered_by,
+            is_powered: false,
+
+            failure: Failure::new(FailureType::BleedLeakDetectionLoop(zone)),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, leak: &BleedAirDuctLeak) {
+        if !self.is_initialised {
+            self.zone_temperature = context.ambient_temperature();
+            self.is_initialised = true;
+        }
+
+        let leak_share = (leak.fluid_flow().get::<kilogram_per_second>()
+            / Self::FULL_ZONE_HEATING_LEAK_FLOW_KG_PER_S)
+            .clamp(0., 1.);
+        let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
+        let target_temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            ambient_temperature
+                + (leak.air_temperature().get::<degree_celsius>() - ambient_temperature)
+                    * leak_share,
+        );
+
+        self.zone_temperature = calculate_towards_target_temperature(
+            self.zone_temperature,
+            target_temperature,
+            Self::ZONE_TEMPERATURE_COEFFICIENT,
+            context.delta(),
+        );
+    }
+
+    pub fn zone(&self) -> BleedAirLeakZone {
+        self.zone
+    }
+
+    pub fn leak_detected(&self) -> bool {
+        self.is_powered
+            && !self.failure.is_active()
+            && self.zone_temperature > self.detection_temperature
+    }
+
+    pub fn has_fault(&self) -> bool {
+        self.failure.is_active()
+    }
+}
+impl SimulationElement for LeakDetectionLoop {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.leak_detected_id, self.leak_detected());
+        writer.write(&self.loop_fault_id, self.has_fault());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+pub trait BleedAirLeakDetection {
+    fn leak_detected(&self, zone: BleedAirLeakZone) -> bool;
+}
+impl<const N: usize> BleedAirLeakDetection for [LeakDetectionLoop; N] {
+    fn leak_detected(&self, zone: BleedAirLeakZone) -> bool {
+        self.iter()
+            .any(|detection_loop| detection_loop.zone() == zone && detection_loop.leak_detected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        pneumatic::{CompressionChamber, TargetPressureTemperatureSignal},
+        shared::{ControllerSignal, PotentialOrigin},
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed},
+            Aircraft,
+        },
+    };
+
+    use std::time::Duration;
+    use uom::si::volume::cubic_meter;
+
+    struct HotDuctSupply;
+    impl ControllerSignal<TargetPressureTemperatureSignal> for HotDuctSupply {
+        fn signal(&self) -> Option<TargetPressureTemperatureSignal> {
+            Some(TargetPressureTemperatureSignal::new(
+                Pressure::new::<psi>(60.),
+                ThermodynamicTemperature::new::<degree_celsius>(200.),
+            ))
+        }
+    }
+
+    struct TestAircraft {
+        duct: CompressionChamber,
+        leak: BleedAirDuctLeak,
+        detection_loops: [LeakDetectionLoop; 1],
+
+        powered_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        is_dc_1_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                duct: CompressionChamber::new(Volume::new::<cubic_meter>(1.)),
+                leak: BleedAirDuctLeak::new(BleedAirLeakZone::Wing(1), 0.05),
+                detection_loops: [LeakDetectionLoop::new(
+                    context,
+                    BleedAirLeakZone::Wing(1),
+                    ThermodynamicTemperature::new::<degree_celsius>(124.),
+                    ElectricalBusType::DirectCurrent(1),
+                )],
+
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                is_dc_1_powered: true,
+            }
+        }
+
+        fn leak_flow(&self) -> MassRate {
+            self.leak.fluid_flow()
+        }
+
+        fn set_dc_1_power(&mut self, is_powered: bool) {
+            self.is_dc_1_powered = is_powered;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_source);
+
+            if self.is_dc_1_powered {
+                electricity.flow(&self.powered_source, &self.dc_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.duct.update(&HotDuctSupply);
+            self.leak.update_move_fluid(context, &mut self.duct);
+            self.detection_loops[0].update(context, &self.leak);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.leak.accept(visitor);
+            self.detection_loops[0].accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    fn leak_detected(test_bed: &mut SimulationTestBed<TestAircraft>) -> bool {
+        test_bed.read_by_name("PNEU_WING_1_LEAK_DETECTED")
+    }
+
+    #[test]
+    fn intact_duct_does_not_leak() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_secs(60));
+
+        assert_eq!(test_bed.query(|a| a.leak_flow()), MassRate::default());
+        assert!(!leak_detected(&mut test_bed));
+    }
+
+    #[test]
+    fn leaking_duct_loses_air() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(1)));
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.leak_flow()) > MassRate::default());
+    }
+
+    #[test]
+    fn leak_is_detected_once_the_zone_has_heated_up() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(1)));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(!leak_detected(&mut test_bed));
+
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert!(leak_detected(&mut test_bed));
+        assert!(test_bed.query(|a| a.detection_loops.leak_detected(BleedAirLeakZone::Wing(1))));
+        assert!(!test_bed.query(|a| a.detection_loops.leak_detected(BleedAirLeakZone::Wing(2))));
+    }
+
+    #[test]
+    fn leak_is_no_longer_detected_once_the_zone_has_cooled_down() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(1)));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        test_bed.unfail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(1)));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert!(!leak_detected(&mut test_bed));
+    }
+
+    #[test]
+    fn failed_loop_does_not_detect_leak_and_reports_fault() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(1)));
+        test_bed.fail(FailureType::BleedLeakDetectionLoop(BleedAirLeakZone::Wing(
+            1,
+        )));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert!(!leak_detected(&mut test_bed));
+        let has_fault: bool = test_bed.read_by_name("PNEU_WING_1_LEAK_DETECTION_LOOP_FAULT");
+        assert!(has_fault);
+    }
+
+    #[test]
+    fn unpowered_loop_does_not_detect_leak() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_dc_1_power(false));
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirLeakZone::Wing(1)));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert!(!leak_detected(&mut test_bed));
+    }
+}
@@ -21,6 +21,7 @@ use uom::si::{
     volume::cubic_meter,
 };
 
+pub mod leak_detection;
 pub mod valve;
 
 pub trait PneumaticValveSignal {
@@ -531,9 +532,12 @@ pub struct Precooler {
     heat_transfer_coefficient: f64,
     internal_connector: PneumaticContainerConnector,
     exhaust: PneumaticExhaust,
+    clogging_failure: Option<Failure>,
 }
 impl Precooler {
     const HEAT_CAPACITY_CONSTANT_PRESSURE: f64 = 1.005e3;
+    /// Part of the heat exchange left when the cooling air passages are clogged.
+    const CLOGGED_HEAT_TRANSFER_FACTOR: f64 = 0.2;
 
     /// The `heat_transfer_coefficient` contains both the heat transfer coefficient and the area of exchange.
     /// Typical values of the heat transfer coefficient for air to air coolers are 60-180 W/(m^2*K).
@@ -542,6 +546,23 @@ impl Precooler {
             heat_transfer_coefficient,
             internal_connector: PneumaticContainerConnector::new(),
             exhaust: PneumaticExhaust::new(3., 3., Pressure::new::<psi>(0.)),
+            clogging_failure: None,
+        }
+    }
+
+    /// Allows the precooler to be failed with clogged cooling air passages,
+    /// which leaves the bleed air leaving the precooler much hotter.
+    pub fn with_clogging_failure(mut self, failure_type: FailureType) -> Self {
+        self.clogging_failure = Some(Failure::new(failure_type));
+        self
+    }
+
+    fn effective_heat_transfer_coefficient(&self) -> f64 {
+        match &self.clogging_failure {
+            Some(failure) if failure.is_active() => {
+                self.heat_transfer_coefficient * Self::CLOGGED_HEAT_TRANSFER_FACTOR
+            }
+            _ => self.heat_transfer_coefficient,
         }
     }
 
@@ -558,7 +579,7 @@ impl Precooler {
         );
 
         let mass_energy_change = temperature_gradient / Self::HEAT_CAPACITY_CONSTANT_PRESSURE
-            * (self.heat_transfer_coefficient * context.delta_as_secs_f64());
+            * (self.effective_heat_transfer_coefficient() * context.delta_as_secs_f64());
 
         supply.update_temperature(-mass_energy_change / supply.mass().get::<kilogram>());
         container_one
@@ -569,6 +590,15 @@ impl Precooler {
             .update_move_fluid(context, container_one, container_two);
     }
 }
+impl SimulationElement for Precooler {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        if let Some(failure) = &mut self.clogging_failure {
+            failure.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+}
 
 pub struct VariableVolumeContainer {
     pipe: PneumaticPipe,
@@ -1,6 +1,7 @@
 use std::f64::consts::PI;
 
 use crate::{
+    failures::{Failure, FailureType},
     pneumatic::{Solenoid, SolenoidSignal},
     shared::{interpolation, ControllerSignal, ElectricalBusType, ElectricalBuses, PneumaticValve},
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
@@ -109,6 +110,41 @@ impl<const N: usize> PneumaticValveCharacteristics<N> {
     }
 }
 
+/// Failures which jam a valve fully open or fully closed, whatever it is commanded to
+/// and whatever the pressures acting on it are.
+struct StuckValveFailures {
+    stuck_open: Failure,
+    stuck_closed: Failure,
+}
+impl StuckValveFailures {
+    fn new(stuck_open: FailureType, stuck_closed: FailureType) -> Self {
+        Self {
+            stuck_open: Failure::new(stuck_open),
+            stuck_closed: Failure::new(stuck_closed),
+        }
+    }
+
+    fn stuck_open_amount(failures: &Option<Self>) -> Option<Ratio> {
+        failures.as_ref().and_then(|failures| {
+            if failures.stuck_closed.is_active() {
+                Some(Ratio::new::<ratio>(0.))
+            } else if failures.stuck_open.is_active() {
+                Some(Ratio::new::<ratio>(1.))
+            } else {
+                None
+            }
+        })
+    }
+}
+impl SimulationElement for StuckValveFailures {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.stuck_open.accept(visitor);
+        self.stuck_closed.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 /// A valve with a solenoid. If the solenoid is energized, the valve is allowed to open
 /// If the solenoid is de-energized, the valve is closed.
 pub struct SolenoidValve<const N: usize> {
@@ -116,6 +152,7 @@ pub struct SolenoidValve<const N: usize> {
     characteristics: PneumaticValveCharacteristics<N>,
     solenoid: Solenoid,
     open_amount: Ratio,
+    stuck_failures: Option<StuckValveFailures>,
 }
 impl<const N: usize> SolenoidValve<N> {
     pub fn new(
@@ -127,9 +164,20 @@ impl<const N: usize> SolenoidValve<N> {
             characteristics,
             solenoid: Solenoid::new(powered_by),
             open_amount: Ratio::default(),
+            stuck_failures: None,
         }
     }
 
+    /// Allows the valve to be failed in its fully open or fully closed position.
+    pub fn with_stuck_failures(
+        mut self,
+        stuck_open: FailureType,
+        stuck_closed: FailureType,
+    ) -> Self {
+        self.stuck_failures = Some(StuckValveFailures::new(stuck_open, stuck_closed));
+        self
+    }
+
     pub fn update_solenoid(&mut self, controller: &impl ControllerSignal<SolenoidSignal>) {
         self.solenoid.update(controller);
     }
@@ -158,6 +206,11 @@ impl<const N: usize> SolenoidValve<N> {
             target_open_amount.max(current_open_amount - open_amount_change)
         });
 
+        if let Some(stuck_open_amount) = StuckValveFailures::stuck_open_amount(&self.stuck_failures)
+        {
+            self.open_amount = stuck_open_amount;
+        }
+
         self.connector
             .with_transfer_speed_factor(self.open_amount)
             .update_move_fluid(context, upstream, downstream);
@@ -179,6 +232,9 @@ impl<const N: usize> PneumaticValve for SolenoidValve<N> {
 impl<const N: usize> SimulationElement for SolenoidValve<N> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.solenoid.accept(visitor);
+        if let Some(stuck_failures) = &mut self.stuck_failures {
+            stuck_failures.accept(visitor);
+        }
 
         visitor.visit(self);
     }
@@ -189,6 +245,7 @@ pub struct ElectroPneumaticValve {
     connector: PneumaticContainerConnector,
     is_powered: bool,
     powered_by: ElectricalBusType,
+    stuck_failures: Option<StuckValveFailures>,
 }
 impl ElectroPneumaticValve {
     const SPRING_CHARACTERISTIC: f64 = 1.;
@@ -199,9 +256,20 @@ impl ElectroPneumaticValve {
             connector: PneumaticContainerConnector::new(),
             is_powered: false,
             powered_by,
+            stuck_failures: None,
         }
     }
 
+    /// Allows the valve to be failed in its fully open or fully closed position.
+    pub fn with_stuck_failures(
+        mut self,
+        stuck_open: FailureType,
+        stuck_closed: FailureType,
+    ) -> Self {
+        self.stuck_failures = Some(StuckValveFailures::new(stuck_open, stuck_closed));
+        self
+    }
+
     pub fn update_move_fluid(
         &mut self,
         context: &UpdateContext,
@@ -213,6 +281,7 @@ impl ElectroPneumaticValve {
                 container_one.pressure() - container_two.pressure(),
             )
         }
+        self.update_stuck_open_amount();
 
         self.connector
             .with_transfer_speed_factor(self.open_amount)
@@ -232,6 +301,13 @@ impl ElectroPneumaticValve {
         self.connector.fluid_flow()
     }
 
+    fn update_stuck_open_amount(&mut self) {
+        if let Some(stuck_open_amount) = StuckValveFailures::stuck_open_amount(&self.stuck_failures)
+        {
+            self.open_amount = stuck_open_amount;
+        }
+    }
+
     pub fn is_powered(&self) -> bool {
         self.is_powered
     }
@@ -255,10 +331,15 @@ impl ControllablePneumaticValve for ElectroPneumaticValve {
                 self.open_amount = signal.target_open_amount();
             }
         }
+        self.update_stuck_open_amount();
     }
 }
 impl SimulationElement for ElectroPneumaticValve {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        if let Some(stuck_failures) = &mut self.stuck_failures {
+            stuck_failures.accept(visitor);
+        }
+
         visitor.visit(self);
     }
 
@@ -273,6 +354,7 @@ pub struct OverpressureValve<const N: usize> {
     protection_threshold: Pressure,
     open_amount: Ratio,
     is_closing: bool,
+    stuck_failures: Option<StuckValveFailures>,
 }
 impl<const N: usize> OverpressureValve<N> {
     pub fn new(
@@ -285,9 +367,21 @@ impl<const N: usize> OverpressureValve<N> {
             protection_threshold,
             open_amount: Ratio::new::<ratio>(1.0),
             is_closing: false,
+            stuck_failures: None,
         }
     }
 
+    /// Allows the valve to be failed in its fully open or fully closed position.
+    /// A valve stuck open no longer protects the downstream ducts against overpressure.
+    pub fn with_stuck_failures(
+        mut self,
+        stuck_open: FailureType,
+        stuck_closed: FailureType,
+    ) -> Self {
+        self.stuck_failures = Some(StuckValveFailures::new(stuck_open, stuck_closed));
+        self
+    }
+
     pub fn update_move_fluid(
         &mut self,
         context: &UpdateContext,
@@ -322,6 +416,11 @@ impl<const N: usize> OverpressureValve<N> {
             self.is_closing = false;
         }
 
+        if let Some(stuck_open_amount) = StuckValveFailures::stuck_open_amount(&self.stuck_failures)
+        {
+            self.open_amount = stuck_open_amount;
+        }
+
         self.connector
             .with_transfer_speed_factor(self.open_amount)
             .update_move_fluid(context, upstream, downstream);
@@ -332,6 +431,15 @@ impl<const N: usize> FullyOpen for OverpressureValve<N> {
         self.open_amount >= Ratio::new::<ratio>(1.0)
     }
 }
+impl<const N: usize> SimulationElement for OverpressureValve<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        if let Some(stuck_failures) = &mut self.stuck_failures {
+            stuck_failures.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+}
 
 pub trait FullyOpen {
     fn is_fully_open(&self) -> bool;
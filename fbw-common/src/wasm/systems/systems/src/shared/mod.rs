@@ -7,7 +7,6 @@ use crate::{
 
 use arinc429::Arinc429Word;
 use nalgebra::Vector3;
-use num_derive::FromPrimitive;
 use std::{cell::Ref, fmt::Display, time::Duration};
 use uom::si::{
     angle::{degree, radian},
@@ -103,6 +102,11 @@ pub trait CargoDoorLocked {
     fn aft_cargo_door_locked(&self) -> bool;
 }
 
+pub trait CargoDoorOpenAmount {
+    fn fwd_cargo_door_open_amount(&self) -> Ratio;
+    fn aft_cargo_door_open_amount(&self) -> Ratio;
+}
+
 pub trait LgciuWeightOnWheels {
     fn right_gear_compressed(&self, treat_ext_pwr_as_ground: bool) -> bool;
     fn right_gear_extended(&self, treat_ext_pwr_as_ground: bool) -> bool;
@@ -280,6 +284,7 @@ pub trait SectionPressure {
     fn pressure_downstream_leak_valve(&self) -> Pressure;
     fn pressure_downstream_priority_valve(&self) -> Pressure;
     fn is_pressure_switch_pressurised(&self) -> bool;
+    fn fluid_viscosity(&self) -> KinematicViscosity;
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -298,6 +303,29 @@ impl Display for HydraulicColor {
     }
 }
 
+/// Location of an external leak in a hydraulic circuit.
+/// Pump sections are numbered from 1 as their section names.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HydraulicLeakLocation {
+    PumpSection(usize),
+    SystemSectionUpstreamPriorityValve,
+    SystemSectionDownstreamPriorityValve,
+    AuxiliarySection,
+}
+
+/// Flight control surface driven by hydraulic servo actuators.
+/// Spoilers are numbered from 1, inboard to outboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FlightControlSurface {
+    LeftAileron,
+    RightAileron,
+    LeftElevator,
+    RightElevator,
+    Rudder,
+    LeftSpoiler(usize),
+    RightSpoiler(usize),
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AirbusEngineDrivenPumpId {
     Edp1a,
@@ -517,20 +545,6 @@ pub enum ContactorSignal {
     Close,
 }
 
-#[derive(FromPrimitive)]
-pub(crate) enum FwcFlightPhase {
-    ElecPwr = 1,
-    FirstEngineStarted = 2,
-    FirstEngineTakeOffPower = 3,
-    AtOrAboveEightyKnots = 4,
-    LiftOff = 5,
-    AtOrAbove1500Feet = 6,
-    AtOrBelow800Feet = 7,
-    TouchDown = 8,
-    AtOrBelowEightyKnots = 9,
-    EnginesShutdown = 10,
-}
-
 /// The delay logic gate delays the true result of a given expression by the given amount of time.
 /// False results are output immediately.
 pub struct DelayedTrueLogicGate {
@@ -1131,6 +1145,25 @@ pub enum FireDetectionLoopID {
     B,
 }
 
+/// A zone of the aircraft monitored by a bleed air leak detection loop.
+/// Wing and pylon zones are numbered by the engine which supplies their ducts.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum BleedAirLeakZone {
+    Wing(usize),
+    Pylon(usize),
+    Apu,
+}
+
+impl Display for BleedAirLeakZone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BleedAirLeakZone::Wing(number) => write!(f, "WING_{}", number),
+            BleedAirLeakZone::Pylon(number) => write!(f, "PYLON_{}", number),
+            BleedAirLeakZone::Apu => write!(f, "APU"),
+        }
+    }
+}
+
 pub trait Clamp {
     /// Restrict a value to a certain interval unless it is NaN.
     ///
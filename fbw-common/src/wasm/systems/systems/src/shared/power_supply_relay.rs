@@ -51,6 +51,16 @@ impl PowerSupplyRelay {
         (self.switch_connected_to_primary && self.primary_powered)
             || (!self.switch_connected_to_primary && self.fallback_feedback_powered)
     }
+
+    pub fn output_source(&self) -> Option<ElectricalBusType> {
+        if !self.output_is_powered() {
+            None
+        } else if self.switch_connected_to_primary {
+            Some(self.primary)
+        } else {
+            Some(self.fallback)
+        }
+    }
 }
 
 impl SimulationElement for PowerSupplyRelay {
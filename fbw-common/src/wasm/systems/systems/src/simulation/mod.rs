@@ -10,7 +10,7 @@ use crate::{
     shared::arinc825::{from_arinc825, to_arinc825, Arinc825Word},
     shared::{to_bool, ConsumePower, ElectricalBuses, MachNumber, PowerConsumptionReport},
 };
-use rustc_hash::FxHashSet;
+use rustc_hash::FxHashMap;
 use uom::si::mass_rate::kilogram_per_second;
 use uom::si::{
     acceleration::foot_per_second_squared, angle::degree, angular_velocity::revolution_per_minute,
@@ -323,7 +323,8 @@ pub trait SimulationElement {
     }
 
     /// Receives a failure in order to activate or deactivate it.
-    fn receive_failure(&mut self, _active_failures: &FxHashSet<FailureType>) {}
+    /// Active failures are given with their severity, 1 being the complete failure.
+    fn receive_failure(&mut self, _active_failures: &FxHashMap<FailureType, Ratio>) {}
 }
 
 /// Trait for visitors that visit the aircraft's system simulation to call
@@ -456,11 +457,17 @@ impl<T: Aircraft> Simulation<T> {
         self.aircraft.accept(&mut visitor);
     }
 
-    pub fn update_active_failures(&mut self, active_failures: FxHashSet<FailureType>) {
+    pub fn update_active_failures(&mut self, active_failures: FxHashMap<FailureType, Ratio>) {
         self.aircraft
             .accept(&mut FailureSimulationElementVisitor::new(active_failures));
     }
 
+    /// Context of the last executed tick, used outside of the aircraft such as for
+    /// evaluating armed failure triggers.
+    pub fn update_context(&self) -> &UpdateContext {
+        &self.update_context
+    }
+
     fn electricity(&self) -> &Electricity {
         &self.electricity
     }
@@ -482,10 +489,10 @@ impl<T: Aircraft> Simulation<T> {
 }
 
 struct FailureSimulationElementVisitor {
-    active_failures: FxHashSet<FailureType>,
+    active_failures: FxHashMap<FailureType, Ratio>,
 }
 impl FailureSimulationElementVisitor {
-    fn new(active_failures: FxHashSet<FailureType>) -> Self {
+    fn new(active_failures: FxHashMap<FailureType, Ratio>) -> Self {
         Self { active_failures }
     }
 }
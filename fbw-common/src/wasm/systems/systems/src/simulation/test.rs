@@ -1,5 +1,5 @@
 use rand::Rng;
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::FxHashMap;
 use std::{cell::Ref, time::Duration};
 use uom::si::{
     acceleration::foot_per_second_squared,
@@ -58,6 +58,11 @@ pub trait TestBed {
         self.test_bed_mut().fail(failure_type);
     }
 
+    fn fail_with_severity(&mut self, failure_type: FailureType, severity: Ratio) {
+        self.test_bed_mut()
+            .fail_with_severity(failure_type, severity);
+    }
+
     fn unfail(&mut self, failure_type: FailureType) {
         self.test_bed_mut().unfail(failure_type);
     }
@@ -240,7 +245,7 @@ pub struct SimulationTestBed<T: Aircraft> {
     reader_writer: TestReaderWriter,
     simulation: Simulation<T>,
     variable_registry: TestVariableRegistry,
-    failures: FxHashSet<FailureType>,
+    failures: FxHashMap<FailureType, Ratio>,
 }
 impl<T: Aircraft> SimulationTestBed<T> {
     pub fn new<U: FnOnce(&mut InitContext) -> T>(aircraft_ctor_fn: U) -> Self {
@@ -256,7 +261,7 @@ impl<T: Aircraft> SimulationTestBed<T> {
             reader_writer: TestReaderWriter::new(),
             simulation: Simulation::new(start_state, aircraft_ctor_fn, &mut variable_registry),
             variable_registry,
-            failures: FxHashSet::default(),
+            failures: FxHashMap::default(),
         };
 
         test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
@@ -327,7 +332,11 @@ impl<T: Aircraft> SimulationTestBed<T> {
     }
 
     fn fail(&mut self, failure_type: FailureType) {
-        self.failures.insert(failure_type);
+        self.fail_with_severity(failure_type, Ratio::new::<ratio>(1.));
+    }
+
+    fn fail_with_severity(&mut self, failure_type: FailureType, severity: Ratio) {
+        self.failures.insert(failure_type, severity);
         self.simulation
             .update_active_failures(self.failures.clone());
     }
@@ -1,2 +1,3 @@
 pub mod egpws;
 pub mod taws;
+pub mod tcas;
@@ -0,0 +1,2615 @@
+use crate::{
+    failures::{Failure, FailureType},
+    navigation::{adirs::AirDataReferenceBus, radio_altimeter::RadioAltimeter},
+    shared::{ConsumePower, ElectricalBusType, ElectricalBuses},
+    simulation::{
+        InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write, Writer,
+    },
+};
+use rustc_hash::FxHashMap;
+use std::time::Duration;
+use uom::si::{
+    angle::degree,
+    f64::{Angle, Length, Power, Time, Velocity},
+    length::{foot, nautical_mile},
+    power::watt,
+    time::second,
+    velocity::foot_per_minute,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TcasMode {
+    Standby = 0,
+    TrafficAdvisoryOnly = 1,
+    TrafficAdvisoryResolutionAdvisory = 2,
+}
+read_write_enum!(TcasMode);
+impl From<f64> for TcasMode {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => TcasMode::Standby,
+            1 => TcasMode::TrafficAdvisoryOnly,
+            2 => TcasMode::TrafficAdvisoryResolutionAdvisory,
+            _ => panic!("TCAS mode selector position not recognized."),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TcasTrafficDisplayMode {
+    Threat = 0,
+    All = 1,
+    Above = 2,
+    Below = 3,
+}
+read_write_enum!(TcasTrafficDisplayMode);
+impl From<f64> for TcasTrafficDisplayMode {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => TcasTrafficDisplayMode::Threat,
+            1 => TcasTrafficDisplayMode::All,
+            2 => TcasTrafficDisplayMode::Above,
+            3 => TcasTrafficDisplayMode::Below,
+            _ => panic!("TCAS traffic selector position not recognized."),
+        }
+    }
+}
+impl TcasTrafficDisplayMode {
+    /// The relative altitude band, in feet, within which traffic is displayed.
+    fn relative_altitude_band(&self) -> (f64, f64) {
+        match self {
+            TcasTrafficDisplayMode::Threat | TcasTrafficDisplayMode::All => (-2700., 2700.),
+            TcasTrafficDisplayMode::Above => (-2700., 9900.),
+            TcasTrafficDisplayMode::Below => (-9900., 2700.),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TcasState {
+    None = 0,
+    TrafficAdvisory = 1,
+    ResolutionAdvisory = 2,
+}
+read_write_enum!(TcasState);
+impl From<f64> for TcasState {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => TcasState::None,
+            1 => TcasState::TrafficAdvisory,
+            2 => TcasState::ResolutionAdvisory,
+            _ => panic!("TCAS state not recognized."),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IntrusionLevel {
+    Other = 0,
+    Proximate = 1,
+    TrafficAdvisory = 2,
+    ResolutionAdvisory = 3,
+}
+read_write_enum!(IntrusionLevel);
+impl From<f64> for IntrusionLevel {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => IntrusionLevel::Other,
+            1 => IntrusionLevel::Proximate,
+            2 => IntrusionLevel::TrafficAdvisory,
+            3 => IntrusionLevel::ResolutionAdvisory,
+            _ => panic!("TCAS intrusion level not recognized."),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RaSense {
+    Up,
+    Down,
+}
+impl RaSense {
+    fn opposite(&self) -> Self {
+        match self {
+            RaSense::Up => RaSense::Down,
+            RaSense::Down => RaSense::Up,
+        }
+    }
+}
+
+/// The kind of corrective RA, as shown on the PFD and used for the aural alerts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RaType {
+    None = 0,
+    Crossing = 1,
+    Reversal = 2,
+    Increase = 3,
+    Maintain = 4,
+}
+read_write_enum!(RaType);
+impl From<f64> for RaType {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => RaType::None,
+            1 => RaType::Crossing,
+            2 => RaType::Reversal,
+            3 => RaType::Increase,
+            4 => RaType::Maintain,
+            _ => panic!("TCAS RA type not recognized."),
+        }
+    }
+}
+
+/// The advisory given in one vertical direction. For the up direction these read climb and
+/// don't descend, for the down direction descend and don't climb.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UpDownAdvisoryStatus {
+    NoAdvisory = 0,
+    ClimbDescend = 1,
+    DontClimbDontDescend = 2,
+    DontClimbDontDescendGreater500 = 3,
+    DontClimbDontDescendGreater1000 = 4,
+    DontClimbDontDescendGreater2000 = 5,
+}
+read_write_enum!(UpDownAdvisoryStatus);
+impl From<f64> for UpDownAdvisoryStatus {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => UpDownAdvisoryStatus::NoAdvisory,
+            1 => UpDownAdvisoryStatus::ClimbDescend,
+            2 => UpDownAdvisoryStatus::DontClimbDontDescend,
+            3 => UpDownAdvisoryStatus::DontClimbDontDescendGreater500,
+            4 => UpDownAdvisoryStatus::DontClimbDontDescendGreater1000,
+            5 => UpDownAdvisoryStatus::DontClimbDontDescendGreater2000,
+            _ => panic!("TCAS up/down advisory status not recognized."),
+        }
+    }
+}
+
+/// The aural alert announcing the active RA.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RaCallout {
+    None = 0,
+    Climb = 1,
+    ClimbCrossing = 2,
+    IncreaseClimb = 3,
+    ClimbNow = 4,
+    Descend = 5,
+    DescendCrossing = 6,
+    IncreaseDescent = 7,
+    DescendNow = 8,
+    MonitorVerticalSpeed = 9,
+    MaintainVerticalSpeed = 10,
+    MaintainVerticalSpeedCrossing = 11,
+}
+read_write_enum!(RaCallout);
+impl From<f64> for RaCallout {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => RaCallout::None,
+            1 => RaCallout::Climb,
+            2 => RaCallout::ClimbCrossing,
+            3 => RaCallout::IncreaseClimb,
+            4 => RaCallout::ClimbNow,
+            5 => RaCallout::Descend,
+            6 => RaCallout::DescendCrossing,
+            7 => RaCallout::IncreaseDescent,
+            8 => RaCallout::DescendNow,
+            9 => RaCallout::MonitorVerticalSpeed,
+            10 => RaCallout::MaintainVerticalSpeed,
+            11 => RaCallout::MaintainVerticalSpeedCrossing,
+            _ => panic!("TCAS RA callout not recognized."),
+        }
+    }
+}
+
+/// A surveillance track of another aircraft, as obtained through interrogation of its
+/// transponder. Range and bearing are measured by the directional antenna, while the altitude is
+/// the one reported by the intruder.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IntruderTrack {
+    id: u32,
+    range: Length,
+    /// The bearing relative to the own aircraft's heading.
+    bearing: Angle,
+    /// The reported pressure altitude. `None` for non altitude reporting intruders.
+    altitude: Option<Length>,
+    /// The RA sense selected by the intruder's TCAS, as received over the coordination link.
+    /// `None` for intruders without TCAS.
+    coordination_sense: Option<RaSense>,
+}
+impl IntruderTrack {
+    pub fn new(id: u32, range: Length, bearing: Angle, altitude: Option<Length>) -> Self {
+        Self {
+            id,
+            range,
+            bearing,
+            altitude,
+            coordination_sense: None,
+        }
+    }
+
+    pub fn with_coordination_sense(mut self, sense: RaSense) -> Self {
+        self.coordination_sense = Some(sense);
+        self
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+/// Provides the intruder tracks surrounding the aircraft. In the simulator these come from
+/// the simulator's traffic, in tests they can be scripted.
+pub trait TrafficProvider {
+    fn intruder_tracks(&self) -> &[IntruderTrack];
+}
+
+/// The traffic surrounding the aircraft in the simulator. The simulator's traffic is only
+/// accessible from the instruments, which publish the closest aircraft as intruder tracks.
+/// The simulator's traffic isn't TCAS equipped, hence its tracks never carry a coordination
+/// sense and the RAs against it are not coordinated.
+pub struct SimulatorTraffic {
+    intruder_ids: Vec<IntruderTrackIdentifiers>,
+    tracks: Vec<IntruderTrack>,
+    /// The track published in each intruder slot.
+    slot_track_ids: Vec<Option<u32>>,
+}
+impl SimulatorTraffic {
+    /// AMM 34-43-00: Up to 40 intruders are tracked.
+    const MAX_TRACKS: usize = 40;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            intruder_ids: (1..=Self::MAX_TRACKS)
+                .map(|number| IntruderTrackIdentifiers::new(context, number))
+                .collect(),
+            tracks: Vec::with_capacity(Self::MAX_TRACKS),
+            slot_track_ids: vec![None; Self::MAX_TRACKS],
+        }
+    }
+
+    /// Writes the intrusion level determined for each intruder back to its slot, so the
+    /// instruments can show the traffic they track with the right symbol.
+    fn write_intrusion_levels(
+        &self,
+        writer: &mut SimulatorWriter,
+        tcas: &TrafficCollisionAvoidanceSystem,
+    ) {
+        for (ids, track_id) in self.intruder_ids.iter().zip(&self.slot_track_ids) {
+            writer.write(
+                &ids.intrusion_level_id,
+                track_id
+                    .and_then(|id| tcas.intrusion_level(id))
+                    .unwrap_or(IntrusionLevel::Other),
+            );
+        }
+    }
+}
+impl TrafficProvider for SimulatorTraffic {
+    fn intruder_tracks(&self) -> &[IntruderTrack] {
+        &self.tracks
+    }
+}
+impl SimulationElement for SimulatorTraffic {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.tracks.clear();
+        for (ids, track_id) in self.intruder_ids.iter().zip(&mut self.slot_track_ids) {
+            let track = ids.read(reader);
+            *track_id = track.map(|track| track.id());
+            if let Some(track) = track {
+                self.tracks.push(track);
+            }
+        }
+    }
+}
+
+/// The identifiers of a single intruder track published by the instruments.
+struct IntruderTrackIdentifiers {
+    valid_id: VariableIdentifier,
+    id_id: VariableIdentifier,
+    range_id: VariableIdentifier,
+    bearing_id: VariableIdentifier,
+    altitude_id: VariableIdentifier,
+    altitude_valid_id: VariableIdentifier,
+    intrusion_level_id: VariableIdentifier,
+}
+impl IntruderTrackIdentifiers {
+    fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            valid_id: context.get_identifier(format!("TCAS_INTRUDER_{}_VALID", number)),
+            id_id: context.get_identifier(format!("TCAS_INTRUDER_{}_ID", number)),
+            range_id: context.get_identifier(format!("TCAS_INTRUDER_{}_RANGE", number)),
+            bearing_id: context.get_identifier(format!("TCAS_INTRUDER_{}_BEARING", number)),
+            altitude_id: context.get_identifier(format!("TCAS_INTRUDER_{}_ALTITUDE", number)),
+            altitude_valid_id: context
+                .get_identifier(format!("TCAS_INTRUDER_{}_ALTITUDE_VALID", number)),
+            intrusion_level_id: context
+                .get_identifier(format!("TCAS_INTRUDER_{}_INTRUSION_LEVEL", number)),
+        }
+    }
+
+    fn read(&self, reader: &mut SimulatorReader) -> Option<IntruderTrack> {
+        let valid: bool = reader.read(&self.valid_id);
+        if !valid {
+            return None;
+        }
+
+        let id: f64 = reader.read(&self.id_id);
+        let range: f64 = reader.read(&self.range_id);
+        let altitude: f64 = reader.read(&self.altitude_id);
+        let altitude_valid: bool = reader.read(&self.altitude_valid_id);
+
+        Some(IntruderTrack::new(
+            id as u32,
+            Length::new::<nautical_mile>(range),
+            reader.read(&self.bearing_id),
+            altitude_valid.then(|| Length::new::<foot>(altitude)),
+        ))
+    }
+}
+
+/// The thresholds used for a given sensitivity level, as per TCAS II version 7.1.
+struct SensitivityLevelThresholds {
+    ta_tau: Duration,
+    ra_tau: Option<Duration>,
+    ta_dmod: Length,
+    ra_dmod: Option<Length>,
+    ta_zthr: Length,
+    ra_zthr: Option<Length>,
+    /// The minimum vertical separation an RA aims for at closest point of approach.
+    alim: Option<Length>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RaStrength {
+    /// Only prevents the crew from flying vertical speeds beyond the given limit, in feet per
+    /// minute, against the RA sense.
+    Preventive(f64),
+    Corrective,
+    Increase,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ResolutionAdvisory {
+    sense: RaSense,
+    strength: RaStrength,
+    /// The own aircraft crosses through the intruder's altitude to comply with the RA.
+    is_crossing: bool,
+    /// The own aircraft already flies beyond the target vertical speed in the RA sense.
+    maintains_vertical_speed: bool,
+    is_reversal: bool,
+}
+impl ResolutionAdvisory {
+    fn is_corrective(&self) -> bool {
+        !matches!(self.strength, RaStrength::Preventive(_))
+    }
+
+    fn ra_type(&self) -> RaType {
+        match self.strength {
+            RaStrength::Preventive(_) => RaType::None,
+            RaStrength::Increase => RaType::Increase,
+            RaStrength::Corrective if self.is_reversal => RaType::Reversal,
+            RaStrength::Corrective if self.maintains_vertical_speed => RaType::Maintain,
+            RaStrength::Corrective if self.is_crossing => RaType::Crossing,
+            RaStrength::Corrective => RaType::None,
+        }
+    }
+
+    fn callout(&self) -> RaCallout {
+        match (self.ra_type(), self.sense) {
+            _ if !self.is_corrective() => RaCallout::MonitorVerticalSpeed,
+            (RaType::Increase, RaSense::Up) => RaCallout::IncreaseClimb,
+            (RaType::Increase, RaSense::Down) => RaCallout::IncreaseDescent,
+            (RaType::Reversal, RaSense::Up) => RaCallout::ClimbNow,
+            (RaType::Reversal, RaSense::Down) => RaCallout::DescendNow,
+            (RaType::Maintain, _) if self.is_crossing => RaCallout::MaintainVerticalSpeedCrossing,
+            (RaType::Maintain, _) => RaCallout::MaintainVerticalSpeed,
+            (RaType::Crossing, RaSense::Up) => RaCallout::ClimbCrossing,
+            (RaType::Crossing, RaSense::Down) => RaCallout::DescendCrossing,
+            (RaType::None, RaSense::Up) => RaCallout::Climb,
+            (RaType::None, RaSense::Down) => RaCallout::Descend,
+        }
+    }
+
+    /// The vertical speed the RA asks for in the RA sense, in feet per minute.
+    fn target_vertical_speed(&self) -> f64 {
+        match self.strength {
+            RaStrength::Preventive(_) => 0.,
+            RaStrength::Corrective => TrafficCollisionAvoidanceSystem::RA_TARGET_VERTICAL_SPEED_FPM,
+            RaStrength::Increase => {
+                TrafficCollisionAvoidanceSystem::RA_INCREASE_TARGET_VERTICAL_SPEED_FPM
+            }
+        }
+    }
+
+    fn rate_to_maintain(&self) -> f64 {
+        match self.sense {
+            RaSense::Up => self.target_vertical_speed(),
+            RaSense::Down => -self.target_vertical_speed(),
+        }
+    }
+
+    /// Vertical speed range which must be avoided, in feet per minute.
+    fn red(&self) -> (f64, f64) {
+        let limit = match self.strength {
+            RaStrength::Preventive(limit) => -limit,
+            _ => self.target_vertical_speed(),
+        };
+
+        match self.sense {
+            RaSense::Up => (
+                TrafficCollisionAvoidanceSystem::MIN_VERTICAL_SPEED_FPM,
+                limit,
+            ),
+            RaSense::Down => (
+                -limit,
+                TrafficCollisionAvoidanceSystem::MAX_VERTICAL_SPEED_FPM,
+            ),
+        }
+    }
+
+    /// Vertical speed range which must be flown, in feet per minute. Only corrective RAs
+    /// have one.
+    fn green(&self) -> Option<(f64, f64)> {
+        let max = if self.strength == RaStrength::Increase || self.maintains_vertical_speed {
+            TrafficCollisionAvoidanceSystem::RA_INCREASE_MAX_VERTICAL_SPEED_FPM
+        } else {
+            TrafficCollisionAvoidanceSystem::RA_MAX_VERTICAL_SPEED_FPM
+        };
+
+        self.is_corrective()
+            .then(|| (self.target_vertical_speed(), max))
+            .map(|(min, max)| match self.sense {
+                RaSense::Up => (min, max),
+                RaSense::Down => (-max, -min),
+            })
+    }
+
+    fn advisory_status(&self) -> UpDownAdvisoryStatus {
+        match self.strength {
+            RaStrength::Preventive(limit) if limit >= 2000. => {
+                UpDownAdvisoryStatus::DontClimbDontDescendGreater2000
+            }
+            RaStrength::Preventive(limit) if limit >= 1000. => {
+                UpDownAdvisoryStatus::DontClimbDontDescendGreater1000
+            }
+            RaStrength::Preventive(limit) if limit >= 500. => {
+                UpDownAdvisoryStatus::DontClimbDontDescendGreater500
+            }
+            RaStrength::Preventive(_) => UpDownAdvisoryStatus::DontClimbDontDescend,
+            _ => UpDownAdvisoryStatus::ClimbDescend,
+        }
+    }
+
+    fn up_advisory_status(&self) -> UpDownAdvisoryStatus {
+        match self.sense {
+            RaSense::Up => self.advisory_status(),
+            RaSense::Down => UpDownAdvisoryStatus::NoAdvisory,
+        }
+    }
+
+    fn down_advisory_status(&self) -> UpDownAdvisoryStatus {
+        match self.sense {
+            RaSense::Up => UpDownAdvisoryStatus::NoAdvisory,
+            RaSense::Down => self.advisory_status(),
+        }
+    }
+}
+
+struct Intruder {
+    track: IntruderTrack,
+    /// Time elapsed since the track last changed. Replies are received less often than the
+    /// system is updated, so the rates are only estimated when a new reply comes in.
+    time_since_reply: Duration,
+    /// Positive when the intruder is closing in.
+    range_rate: Option<Velocity>,
+    vertical_speed: Option<Velocity>,
+    intrusion_level: IntrusionLevel,
+}
+impl Intruder {
+    fn new(track: IntruderTrack) -> Self {
+        Self {
+            track,
+            time_since_reply: Duration::ZERO,
+            range_rate: None,
+            vertical_speed: None,
+            intrusion_level: IntrusionLevel::Other,
+        }
+    }
+
+    fn update_track(&mut self, context: &UpdateContext, track: IntruderTrack) {
+        self.time_since_reply += context.delta();
+        if track.range == self.track.range && track.altitude == self.track.altitude {
+            self.track = track;
+            return;
+        }
+
+        let elapsed = Time::new::<second>(self.time_since_reply.as_secs_f64());
+        if elapsed.value > 0. {
+            self.range_rate = Some((self.track.range - track.range) / elapsed);
+            self.vertical_speed = match (self.track.altitude, track.altitude) {
+                (Some(previous), Some(current)) => Some((current - previous) / elapsed),
+                _ => None,
+            };
+        }
+
+        self.track = track;
+        self.time_since_reply = Duration::ZERO;
+    }
+
+    fn relative_altitude(&self, own_altitude: Length) -> Option<Length> {
+        self.track.altitude.map(|altitude| altitude - own_altitude)
+    }
+
+    /// The time until the intruder reaches the boundary of the DMOD protected volume.
+    fn range_tau(&self, dmod: Length) -> Option<Duration> {
+        let range = self.track.range.get::<foot>();
+        let dmod = dmod.get::<foot>();
+
+        if range <= dmod {
+            Some(Duration::ZERO)
+        } else {
+            self.range_rate
+                .map(|rate| rate.get::<foot_per_minute>() / 60.)
+                .filter(|&rate| rate > 0.)
+                .map(|rate| Duration::from_secs_f64((range * range - dmod * dmod) / (range * rate)))
+        }
+    }
+
+    fn vertical_tau(&self, own_altitude: Length, own_vertical_speed: Velocity) -> Option<Duration> {
+        let relative_altitude = self.relative_altitude(own_altitude)?.get::<foot>();
+        let relative_vertical_speed =
+            (self.vertical_speed.unwrap_or_default() - own_vertical_speed).get::<foot_per_minute>()
+                / 60.;
+        // Positive when the vertical separation is decreasing.
+        let closure_rate = -relative_altitude.signum() * relative_vertical_speed;
+
+        if closure_rate > 0. {
+            Some(Duration::from_secs_f64(
+                relative_altitude.abs() / closure_rate,
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn is_threat(
+        &self,
+        own_altitude: Length,
+        own_vertical_speed: Velocity,
+        tau: Duration,
+        dmod: Length,
+        zthr: Length,
+    ) -> bool {
+        let range_test = self.range_tau(dmod).is_some_and(|t| t < tau);
+
+        let altitude_test = match self.relative_altitude(own_altitude) {
+            Some(relative_altitude) => {
+                relative_altitude.abs() < zthr
+                    || self
+                        .vertical_tau(own_altitude, own_vertical_speed)
+                        .is_some_and(|t| t < tau)
+            }
+            // Non altitude reporting intruders can only ever cause TAs.
+            None => true,
+        };
+
+        range_test && altitude_test
+    }
+
+    fn time_to_closest_approach(&self, fallback: Duration) -> Duration {
+        match self
+            .range_rate
+            .map(|rate| rate.get::<foot_per_minute>() / 60.)
+        {
+            Some(rate) if rate > 0. => Duration::from_secs_f64(
+                (self.track.range.get::<foot>() / rate)
+                    .min(TrafficCollisionAvoidanceSystem::MAX_CPA_LOOKAHEAD.as_secs_f64()),
+            ),
+            _ => fallback,
+        }
+    }
+
+    fn is_diverging(&self) -> bool {
+        self.range_rate
+            .is_some_and(|rate| rate <= Velocity::default())
+    }
+}
+
+/// A TCAS II computer. It tracks intruders provided by a [`TrafficProvider`], classifies them
+/// into proximate, TA and RA intrusions according to the sensitivity level applicable for
+/// the current altitude, and generates resolution advisories, which are coordinated with TCAS
+/// equipped intruders.
+pub struct TrafficCollisionAvoidanceSystem {
+    failure: Failure,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    mode_id: VariableIdentifier,
+    mode: TcasMode,
+    traffic_display_mode_id: VariableIdentifier,
+    traffic_display_mode: TcasTrafficDisplayMode,
+
+    tcas_state_id: VariableIdentifier,
+    tcas_fault_id: VariableIdentifier,
+    ta_only_id: VariableIdentifier,
+    sensitivity_id: VariableIdentifier,
+    ra_corrective_id: VariableIdentifier,
+    vertical_speed_red_min_id: VariableIdentifier,
+    vertical_speed_red_max_id: VariableIdentifier,
+    vertical_speed_green_min_id: VariableIdentifier,
+    vertical_speed_green_max_id: VariableIdentifier,
+    ra_type_id: VariableIdentifier,
+    rate_to_maintain_id: VariableIdentifier,
+    up_advisory_status_id: VariableIdentifier,
+    down_advisory_status_id: VariableIdentifier,
+    ra_callout_id: VariableIdentifier,
+    traffic_ids: Vec<TrafficSymbolIdentifiers>,
+
+    is_operative: bool,
+    has_fault: bool,
+    sensitivity_level: u8,
+    own_altitude: Length,
+    own_vertical_speed: Velocity,
+    intruders: FxHashMap<u32, Intruder>,
+    resolution_advisory: Option<ResolutionAdvisory>,
+    resolution_advisory_duration: Duration,
+}
+impl TrafficCollisionAvoidanceSystem {
+    /// The operating mode selected on the ATC/TCAS panel, as interlocked with the transponder.
+    const MODE_KEY: &'static str = "TCAS_MODE";
+    const TRAFFIC_DISPLAY_MODE_KEY: &'static str = "SWITCH_TCAS_Traffic_Position";
+    const STATE_KEY: &'static str = "TCAS_STATE";
+    const FAULT_KEY: &'static str = "TCAS_FAULT";
+    const TA_ONLY_KEY: &'static str = "TCAS_TA_ONLY";
+    const SENSITIVITY_KEY: &'static str = "TCAS_SENSITIVITY";
+    const RA_CORRECTIVE_KEY: &'static str = "TCAS_RA_CORRECTIVE";
+    const VSPEED_RED_KEY: &'static str = "TCAS_VSPEED_RED";
+    const VSPEED_GREEN_KEY: &'static str = "TCAS_VSPEED_GREEN";
+    const RA_TYPE_KEY: &'static str = "TCAS_RA_TYPE";
+    const RA_RATE_TO_MAINTAIN_KEY: &'static str = "TCAS_RA_RATE_TO_MAINTAIN";
+    const RA_UP_ADVISORY_STATUS_KEY: &'static str = "TCAS_RA_UP_ADVISORY_STATUS";
+    const RA_DOWN_ADVISORY_STATUS_KEY: &'static str = "TCAS_RA_DOWN_ADVISORY_STATUS";
+    const RA_CALLOUT_KEY: &'static str = "TCAS_RA_CALLOUT";
+
+    /// AMM 34-43-00: Up to 8 intruders are displayed.
+    const DISPLAY_MAX: usize = 8;
+
+    const PROXIMATE_RANGE_NM: f64 = 6.;
+    const PROXIMATE_ALTITUDE_FT: f64 = 1200.;
+
+    const RA_INHIBIT_ALTITUDE_AGL_FT: f64 = 1000.;
+    const DESCEND_RA_INHIBIT_ALTITUDE_AGL_FT: f64 = 1200.;
+    const CLIMB_RA_INHIBIT_ALTITUDE_FT: f64 = 39000.;
+    const INCREASE_DESCENT_RA_INHIBIT_ALTITUDE_AGL_FT: f64 = 1450.;
+
+    const MIN_VERTICAL_SPEED_FPM: f64 = -6000.;
+    const MAX_VERTICAL_SPEED_FPM: f64 = 6000.;
+    const RA_TARGET_VERTICAL_SPEED_FPM: f64 = 1500.;
+    const RA_MAX_VERTICAL_SPEED_FPM: f64 = 2000.;
+    const RA_INCREASE_TARGET_VERTICAL_SPEED_FPM: f64 = 2500.;
+    const RA_INCREASE_MAX_VERTICAL_SPEED_FPM: f64 = 4400.;
+    /// The vertical speed limits of preventive RAs, from least to most restrictive.
+    const PREVENTIVE_LIMITS_FPM: [f64; 4] = [2000., 1000., 500., 0.];
+    /// The intruder must be at least this far above or below for an RA to be crossing.
+    const CROSSING_MARGIN_FT: f64 = 100.;
+    const VERTICAL_TREND_THRESHOLD_FPM: f64 = 500.;
+
+    /// Assumed pilot response delay to a new RA.
+    const PILOT_RESPONSE_DELAY: Duration = Duration::from_secs(5);
+    /// Assumed pilot response delay to a reversed RA.
+    const FOLLOW_UP_RESPONSE_DELAY: Duration = Duration::from_millis(2500);
+    const MIN_DURATION_BEFORE_REVERSAL: Duration = Duration::from_secs(10);
+    const MIN_TIME_TO_CPA_FOR_REVERSAL: Duration = Duration::from_secs(4);
+    const MIN_RA_DURATION: Duration = Duration::from_secs(5);
+    const MAX_CPA_LOOKAHEAD: Duration = Duration::from_secs(60);
+
+    pub fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
+        Self {
+            failure: Failure::new(FailureType::TrafficCollisionAvoidanceSystem),
+            powered_by,
+            is_powered: false,
+
+            mode_id: context.get_identifier(Self::MODE_KEY.to_owned()),
+            mode: TcasMode::Standby,
+            traffic_display_mode_id: context
+                .get_identifier(Self::TRAFFIC_DISPLAY_MODE_KEY.to_owned()),
+            traffic_display_mode: TcasTrafficDisplayMode::Threat,
+
+            tcas_state_id: context.get_identifier(Self::STATE_KEY.to_owned()),
+            tcas_fault_id: context.get_identifier(Self::FAULT_KEY.to_owned()),
+            ta_only_id: context.get_identifier(Self::TA_ONLY_KEY.to_owned()),
+            sensitivity_id: context.get_identifier(Self::SENSITIVITY_KEY.to_owned()),
+            ra_corrective_id: context.get_identifier(Self::RA_CORRECTIVE_KEY.to_owned()),
+            vertical_speed_red_min_id: context
+                .get_identifier(format!("{}:1", Self::VSPEED_RED_KEY)),
+            vertical_speed_red_max_id: context
+                .get_identifier(format!("{}:2", Self::VSPEED_RED_KEY)),
+            vertical_speed_green_min_id: context
+                .get_identifier(format!("{}:1", Self::VSPEED_GREEN_KEY)),
+            vertical_speed_green_max_id: context
+                .get_identifier(format!("{}:2", Self::VSPEED_GREEN_KEY)),
+            ra_type_id: context.get_identifier(Self::RA_TYPE_KEY.to_owned()),
+            rate_to_maintain_id: context.get_identifier(Self::RA_RATE_TO_MAINTAIN_KEY.to_owned()),
+            up_advisory_status_id: context
+                .get_identifier(Self::RA_UP_ADVISORY_STATUS_KEY.to_owned()),
+            down_advisory_status_id: context
+                .get_identifier(Self::RA_DOWN_ADVISORY_STATUS_KEY.to_owned()),
+            ra_callout_id: context.get_identifier(Self::RA_CALLOUT_KEY.to_owned()),
+            traffic_ids: (1..=Self::DISPLAY_MAX)
+                .map(|number| TrafficSymbolIdentifiers::new(context, number))
+                .collect(),
+
+            is_operative: false,
+            has_fault: false,
+            sensitivity_level: 1,
+            own_altitude: Length::default(),
+            own_vertical_speed: Velocity::default(),
+            intruders: FxHashMap::default(),
+            resolution_advisory: None,
+            resolution_advisory_duration: Duration::ZERO,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        adr: &impl AirDataReferenceBus,
+        radio_altimeter: &impl RadioAltimeter,
+        traffic: &impl TrafficProvider,
+    ) {
+        let altitude = adr.standard_altitude();
+        let vertical_speed = adr.vertical_speed();
+
+        self.has_fault =
+            self.failure.is_active() || (self.is_powered && (!altitude.is_normal_operation()));
+        self.is_operative = self.is_powered && !self.has_fault;
+
+        if !self.is_operative || self.mode == TcasMode::Standby {
+            self.sensitivity_level = 1;
+            self.intruders.clear();
+            self.resolution_advisory = None;
+            return;
+        }
+
+        self.own_altitude = altitude.value();
+        self.own_vertical_speed = vertical_speed.value_or_default();
+        let radio_altitude = radio_altimeter.radio_altitude().normal_value();
+
+        self.sensitivity_level = self.sensitivity_level(radio_altitude);
+
+        self.update_tracks(context, traffic);
+        self.classify_intruders();
+        self.update_resolution_advisory(context, radio_altitude);
+    }
+
+    fn sensitivity_level(&self, radio_altitude: Option<Length>) -> u8 {
+        let radio_altitude = radio_altitude.map(|ra| ra.get::<foot>());
+        let altitude = self.own_altitude.get::<foot>();
+
+        if self.mode == TcasMode::TrafficAdvisoryOnly
+            || radio_altitude.is_some_and(|ra| ra < Self::RA_INHIBIT_ALTITUDE_AGL_FT)
+        {
+            2
+        } else if radio_altitude.is_some_and(|ra| ra < 2350.) {
+            3
+        } else if altitude < 5000. {
+            4
+        } else if altitude < 10000. {
+            5
+        } else if altitude < 20000. {
+            6
+        } else if altitude < 42000. {
+            7
+        } else {
+            8
+        }
+    }
+
+    fn thresholds(&self) -> SensitivityLevelThresholds {
+        let (ta_tau, ra_tau, ta_dmod, ra_dmod, ta_zthr, ra_zthr, alim) =
+            match self.sensitivity_level {
+                2 => (20, None, 0.3, None, 850., None, None),
+                3 => (25, Some(15), 0.33, Some(0.2), 850., Some(300.), Some(300.)),
+                4 => (30, Some(20), 0.48, Some(0.35), 850., Some(300.), Some(300.)),
+                5 => (40, Some(25), 0.75, Some(0.55), 850., Some(350.), Some(350.)),
+                6 => (45, Some(30), 1., Some(0.8), 850., Some(400.), Some(400.)),
+                7 => (48, Some(35), 1.3, Some(1.1), 850., Some(600.), Some(600.)),
+                _ => (48, Some(35), 1.3, Some(1.1), 1200., Some(700.), Some(700.)),
+            };
+
+        SensitivityLevelThresholds {
+            ta_tau: Duration::from_secs(ta_tau),
+            ra_tau: ra_tau.map(Duration::from_secs),
+            ta_dmod: Length::new::<nautical_mile>(ta_dmod),
+            ra_dmod: ra_dmod.map(Length::new::<nautical_mile>),
+            ta_zthr: Length::new::<foot>(ta_zthr),
+            ra_zthr: ra_zthr.map(Length::new::<foot>),
+            alim: alim.map(Length::new::<foot>),
+        }
+    }
+
+    fn update_tracks(&mut self, context: &UpdateContext, traffic: &impl TrafficProvider) {
+        let tracks = traffic.intruder_tracks();
+        self.intruders
+            .retain(|id, _| tracks.iter().any(|track| track.id == *id));
+
+        for track in tracks {
+            self.intruders
+                .entry(track.id)
+                .and_modify(|intruder| intruder.update_track(context, *track))
+                .or_insert_with(|| Intruder::new(*track));
+        }
+    }
+
+    fn classify_intruders(&mut self) {
+        let thresholds = self.thresholds();
+        let own_altitude = self.own_altitude;
+        let own_vertical_speed = self.own_vertical_speed;
+        let ra_allowed = self.mode == TcasMode::TrafficAdvisoryResolutionAdvisory;
+
+        for intruder in self.intruders.values_mut() {
+            let is_ra = ra_allowed
+                && intruder.track.altitude.is_some()
+                && match (thresholds.ra_tau, thresholds.ra_dmod, thresholds.ra_zthr) {
+                    (Some(tau), Some(dmod), Some(zthr)) => {
+                        intruder.is_threat(own_altitude, own_vertical_speed, tau, dmod, zthr)
+                    }
+                    _ => false,
+                };
+            // Once an RA has been issued against an intruder, it stays a threat until it is
+            // clear of conflict, i.e. the range starts diverging.
+            let remains_ra = ra_allowed
+                && intruder.intrusion_level == IntrusionLevel::ResolutionAdvisory
+                && !intruder.is_diverging();
+
+            intruder.intrusion_level = if is_ra || remains_ra {
+                IntrusionLevel::ResolutionAdvisory
+            } else if intruder.is_threat(
+                own_altitude,
+                own_vertical_speed,
+                thresholds.ta_tau,
+                thresholds.ta_dmod,
+                thresholds.ta_zthr,
+            ) {
+                IntrusionLevel::TrafficAdvisory
+            } else if intruder.track.range.get::<nautical_mile>() < Self::PROXIMATE_RANGE_NM
+                && intruder
+                    .relative_altitude(own_altitude)
+                    .is_some_and(|alt| alt.abs().get::<foot>() < Self::PROXIMATE_ALTITUDE_FT)
+            {
+                IntrusionLevel::Proximate
+            } else {
+                IntrusionLevel::Other
+            };
+        }
+    }
+
+    fn update_resolution_advisory(
+        &mut self,
+        context: &UpdateContext,
+        radio_altitude: Option<Length>,
+    ) {
+        let thresholds = self.thresholds();
+        let (Some(alim), Some(ra_tau)) = (thresholds.alim, thresholds.ra_tau) else {
+            self.resolution_advisory = None;
+            return;
+        };
+
+        let most_threatening = self
+            .intruders
+            .values()
+            .filter(|intruder| intruder.intrusion_level == IntrusionLevel::ResolutionAdvisory)
+            .min_by(|a, b| {
+                a.time_to_closest_approach(ra_tau)
+                    .cmp(&b.time_to_closest_approach(ra_tau))
+            });
+
+        let Some(threat) = most_threatening else {
+            if self.resolution_advisory_duration < Self::MIN_RA_DURATION
+                && self.resolution_advisory.is_some()
+            {
+                self.resolution_advisory_duration += context.delta();
+            } else {
+                self.resolution_advisory = None;
+            }
+            return;
+        };
+
+        let time_to_cpa = threat.time_to_closest_approach(ra_tau);
+        let advisory = match self.resolution_advisory {
+            Some(advisory) => self.revised_resolution_advisory(
+                advisory,
+                threat,
+                time_to_cpa,
+                alim,
+                radio_altitude,
+            ),
+            None => {
+                self.resolution_advisory_duration = Duration::ZERO;
+                self.new_resolution_advisory(threat, time_to_cpa, alim, radio_altitude)
+            }
+        };
+        self.resolution_advisory_duration += context.delta();
+        self.resolution_advisory = Some(advisory);
+    }
+
+    /// Issues a new RA. When the current vertical speed already provides ALIM separation, the
+    /// least restrictive preventive RA which keeps it is issued. Otherwise the RA is corrective.
+    fn new_resolution_advisory(
+        &self,
+        threat: &Intruder,
+        time_to_cpa: Duration,
+        alim: Length,
+        radio_altitude: Option<Length>,
+    ) -> ResolutionAdvisory {
+        let sense = self.select_sense(threat, time_to_cpa, alim, radio_altitude);
+        let strength = if self.projected_separation(threat, time_to_cpa, sense) >= alim {
+            RaStrength::Preventive(self.preventive_limit(threat, time_to_cpa, sense, alim))
+        } else {
+            RaStrength::Corrective
+        };
+
+        ResolutionAdvisory {
+            sense,
+            strength,
+            is_crossing: self.is_crossing(threat, sense),
+            maintains_vertical_speed: self.is_beyond_target_vertical_speed(sense),
+            is_reversal: false,
+        }
+    }
+
+    /// Revises the active RA. While the threat remains, an RA is only ever strengthened or
+    /// reversed, so that the crew is never given contradicting advisories.
+    fn revised_resolution_advisory(
+        &self,
+        advisory: ResolutionAdvisory,
+        threat: &Intruder,
+        time_to_cpa: Duration,
+        alim: Length,
+        radio_altitude: Option<Length>,
+    ) -> ResolutionAdvisory {
+        if let Some(reversal) = self.reversal(advisory, threat, time_to_cpa, alim, radio_altitude) {
+            return reversal;
+        }
+
+        if self.projected_separation(threat, time_to_cpa, advisory.sense) >= alim {
+            return advisory;
+        }
+
+        match advisory.strength {
+            RaStrength::Preventive(_) => ResolutionAdvisory {
+                strength: RaStrength::Corrective,
+                is_crossing: self.is_crossing(threat, advisory.sense),
+                maintains_vertical_speed: self.is_beyond_target_vertical_speed(advisory.sense),
+                ..advisory
+            },
+            // The crew already complies with the RA, but it no longer provides ALIM separation.
+            RaStrength::Corrective
+                if self.resolution_advisory_duration >= Self::PILOT_RESPONSE_DELAY
+                    && self.is_beyond_target_vertical_speed(advisory.sense)
+                    && self.can_increase(advisory.sense, radio_altitude) =>
+            {
+                ResolutionAdvisory {
+                    strength: RaStrength::Increase,
+                    ..advisory
+                }
+            }
+            _ => advisory,
+        }
+    }
+
+    /// Reverses the active RA when the intruder has selected the same sense, or when the RA
+    /// can no longer be strengthened and the opposite sense provides more separation. An RA
+    /// is only reversed once.
+    fn reversal(
+        &self,
+        advisory: ResolutionAdvisory,
+        threat: &Intruder,
+        time_to_cpa: Duration,
+        alim: Length,
+        radio_altitude: Option<Length>,
+    ) -> Option<ResolutionAdvisory> {
+        let reversed_sense = advisory.sense.opposite();
+        if advisory.is_reversal || self.is_sense_inhibited(reversed_sense, radio_altitude) {
+            return None;
+        }
+
+        let coordination_conflict = threat.track.coordination_sense == Some(advisory.sense);
+        let projected_separation = self.projected_separation(threat, time_to_cpa, advisory.sense);
+        let reversal_provides_more_separation = || {
+            let target = Velocity::new::<foot_per_minute>(Self::RA_TARGET_VERTICAL_SPEED_FPM);
+            self.separation_at_cpa(
+                threat,
+                time_to_cpa,
+                reversed_sense,
+                match reversed_sense {
+                    RaSense::Up => target,
+                    RaSense::Down => -target,
+                },
+                Self::FOLLOW_UP_RESPONSE_DELAY,
+            ) > projected_separation
+        };
+        let geometric_reversal = self.resolution_advisory_duration
+            >= Self::MIN_DURATION_BEFORE_REVERSAL
+            && time_to_cpa >= Self::MIN_TIME_TO_CPA_FOR_REVERSAL
+            && (advisory.strength == RaStrength::Increase
+                || !self.can_increase(advisory.sense, radio_altitude))
+            && projected_separation < alim
+            && reversal_provides_more_separation();
+
+        (coordination_conflict || geometric_reversal).then(|| ResolutionAdvisory {
+            sense: reversed_sense,
+            strength: RaStrength::Corrective,
+            is_crossing: self.is_crossing(threat, reversed_sense),
+            maintains_vertical_speed: false,
+            is_reversal: true,
+        })
+    }
+
+    /// The least restrictive vertical speed limit against the RA sense, in feet per minute,
+    /// which still provides ALIM separation.
+    fn preventive_limit(
+        &self,
+        threat: &Intruder,
+        time_to_cpa: Duration,
+        sense: RaSense,
+        alim: Length,
+    ) -> f64 {
+        Self::PREVENTIVE_LIMITS_FPM
+            .iter()
+            .copied()
+            .find(|&limit| {
+                let vertical_speed = Velocity::new::<foot_per_minute>(match sense {
+                    RaSense::Up => -limit,
+                    RaSense::Down => limit,
+                });
+
+                self.separation_at_cpa(
+                    threat,
+                    time_to_cpa,
+                    sense,
+                    vertical_speed,
+                    Self::PILOT_RESPONSE_DELAY,
+                ) >= alim
+            })
+            .unwrap_or_default()
+    }
+
+    fn is_crossing(&self, threat: &Intruder, sense: RaSense) -> bool {
+        threat
+            .relative_altitude(self.own_altitude)
+            .is_some_and(|relative_altitude| match sense {
+                RaSense::Up => relative_altitude.get::<foot>() > Self::CROSSING_MARGIN_FT,
+                RaSense::Down => relative_altitude.get::<foot>() < -Self::CROSSING_MARGIN_FT,
+            })
+    }
+
+    fn is_beyond_target_vertical_speed(&self, sense: RaSense) -> bool {
+        let vertical_speed = self.own_vertical_speed.get::<foot_per_minute>();
+        match sense {
+            RaSense::Up => vertical_speed >= Self::RA_TARGET_VERTICAL_SPEED_FPM,
+            RaSense::Down => vertical_speed <= -Self::RA_TARGET_VERTICAL_SPEED_FPM,
+        }
+    }
+
+    fn is_sense_inhibited(&self, sense: RaSense, radio_altitude: Option<Length>) -> bool {
+        match sense {
+            RaSense::Up => self.own_altitude.get::<foot>() > Self::CLIMB_RA_INHIBIT_ALTITUDE_FT,
+            RaSense::Down => radio_altitude
+                .is_some_and(|ra| ra.get::<foot>() < Self::DESCEND_RA_INHIBIT_ALTITUDE_AGL_FT),
+        }
+    }
+
+    fn can_increase(&self, sense: RaSense, radio_altitude: Option<Length>) -> bool {
+        sense == RaSense::Up
+            || !radio_altitude.is_some_and(|ra| {
+                ra.get::<foot>() < Self::INCREASE_DESCENT_RA_INHIBIT_ALTITUDE_AGL_FT
+            })
+    }
+
+    /// Selects the sense of a new RA. When the intruder is TCAS equipped and has already selected
+    /// a sense, the complementary sense is chosen. Otherwise the non crossing sense is preferred
+    /// as long as it provides ALIM separation, falling back to the sense providing the largest
+    /// separation at closest point of approach.
+    fn select_sense(
+        &self,
+        threat: &Intruder,
+        time_to_cpa: Duration,
+        alim: Length,
+        radio_altitude: Option<Length>,
+    ) -> RaSense {
+        if self.is_sense_inhibited(RaSense::Up, radio_altitude) {
+            return RaSense::Down;
+        } else if self.is_sense_inhibited(RaSense::Down, radio_altitude) {
+            return RaSense::Up;
+        }
+
+        if let Some(intruder_sense) = threat.track.coordination_sense {
+            return intruder_sense.opposite();
+        }
+
+        let target = Velocity::new::<foot_per_minute>(Self::RA_TARGET_VERTICAL_SPEED_FPM);
+        let up_separation = self.separation_at_cpa(
+            threat,
+            time_to_cpa,
+            RaSense::Up,
+            target,
+            Self::PILOT_RESPONSE_DELAY,
+        );
+        let down_separation = self.separation_at_cpa(
+            threat,
+            time_to_cpa,
+            RaSense::Down,
+            -target,
+            Self::PILOT_RESPONSE_DELAY,
+        );
+
+        let non_crossing_sense = match threat.relative_altitude(self.own_altitude) {
+            Some(relative_altitude) if relative_altitude > Length::default() => RaSense::Down,
+            _ => RaSense::Up,
+        };
+        let non_crossing_separation = match non_crossing_sense {
+            RaSense::Up => up_separation,
+            RaSense::Down => down_separation,
+        };
+
+        if non_crossing_separation >= alim {
+            non_crossing_sense
+        } else if up_separation >= down_separation {
+            RaSense::Up
+        } else {
+            RaSense::Down
+        }
+    }
+
+    /// The vertical separation at closest point of approach in the given sense when the own
+    /// aircraft keeps its current vertical speed.
+    fn projected_separation(
+        &self,
+        threat: &Intruder,
+        time_to_cpa: Duration,
+        sense: RaSense,
+    ) -> Length {
+        self.separation_at_cpa(
+            threat,
+            time_to_cpa,
+            sense,
+            self.own_vertical_speed,
+            Duration::ZERO,
+        )
+    }
+
+    /// The vertical separation at closest point of approach in the given sense, assuming the own
+    /// aircraft continues with its current vertical speed during the response delay and then
+    /// flies the given vertical speed.
+    fn separation_at_cpa(
+        &self,
+        threat: &Intruder,
+        time_to_cpa: Duration,
+        sense: RaSense,
+        vertical_speed: Velocity,
+        response_delay: Duration,
+    ) -> Length {
+        let time = time_to_cpa.as_secs_f64();
+        let delay = response_delay.as_secs_f64().min(time);
+
+        let own_altitude_at_cpa = self.own_altitude.get::<foot>()
+            + self.own_vertical_speed.get::<foot_per_minute>() / 60. * delay
+            + vertical_speed.get::<foot_per_minute>() / 60. * (time - delay);
+        let intruder_altitude_at_cpa = threat.track.altitude.unwrap_or_default().get::<foot>()
+            + threat
+                .vertical_speed
+                .unwrap_or_default()
+                .get::<foot_per_minute>()
+                / 60.
+                * time;
+
+        Length::new::<foot>(match sense {
+            RaSense::Up => own_altitude_at_cpa - intruder_altitude_at_cpa,
+            RaSense::Down => intruder_altitude_at_cpa - own_altitude_at_cpa,
+        })
+    }
+
+    pub fn state(&self) -> TcasState {
+        if self.resolution_advisory.is_some() {
+            TcasState::ResolutionAdvisory
+        } else if self
+            .intruders
+            .values()
+            .any(|intruder| intruder.intrusion_level >= IntrusionLevel::TrafficAdvisory)
+        {
+            TcasState::TrafficAdvisory
+        } else {
+            TcasState::None
+        }
+    }
+
+    /// The sense of the active RA, which is transmitted to other TCAS equipped aircraft for
+    /// coordination.
+    pub fn resolution_advisory_sense(&self) -> Option<RaSense> {
+        self.resolution_advisory.map(|ra| ra.sense)
+    }
+
+    pub fn is_corrective_resolution_advisory(&self) -> bool {
+        self.resolution_advisory
+            .is_some_and(|ra| ra.is_corrective())
+    }
+
+    pub fn resolution_advisory_type(&self) -> RaType {
+        self.resolution_advisory
+            .map_or(RaType::None, |ra| ra.ra_type())
+    }
+
+    /// The aural alert announcing the active RA.
+    pub fn resolution_advisory_callout(&self) -> RaCallout {
+        self.resolution_advisory
+            .map_or(RaCallout::None, |ra| ra.callout())
+    }
+
+    /// The vertical speed range to avoid during an RA.
+    pub fn red_vertical_speed_band(&self) -> Option<(Velocity, Velocity)> {
+        self.resolution_advisory.map(|ra| {
+            let red = ra.red();
+            (
+                Velocity::new::<foot_per_minute>(red.0),
+                Velocity::new::<foot_per_minute>(red.1),
+            )
+        })
+    }
+
+    /// The vertical speed range to fly during a corrective RA.
+    pub fn green_vertical_speed_band(&self) -> Option<(Velocity, Velocity)> {
+        self.resolution_advisory
+            .and_then(|ra| ra.green())
+            .map(|green| {
+                (
+                    Velocity::new::<foot_per_minute>(green.0),
+                    Velocity::new::<foot_per_minute>(green.1),
+                )
+            })
+    }
+
+    pub fn intrusion_level(&self, id: u32) -> Option<IntrusionLevel> {
+        self.intruders
+            .get(&id)
+            .map(|intruder| intruder.intrusion_level)
+    }
+
+    pub fn sensitivity(&self) -> u8 {
+        self.sensitivity_level
+    }
+
+    pub fn has_fault(&self) -> bool {
+        self.has_fault
+    }
+
+    /// The intruders to display, ordered by decreasing threat and increasing range.
+    fn displayed_intruders(&self) -> Vec<&Intruder> {
+        let (lower, upper) = self.traffic_display_mode.relative_altitude_band();
+        let any_threat = self.state() != TcasState::None;
+
+        let mut displayed: Vec<&Intruder> = self
+            .intruders
+            .values()
+            .filter(|intruder| {
+                intruder.intrusion_level >= IntrusionLevel::TrafficAdvisory
+                    || ((self.traffic_display_mode != TcasTrafficDisplayMode::Threat || any_threat)
+                        && intruder
+                            .relative_altitude(self.own_altitude)
+                            .is_none_or(|alt| (lower..=upper).contains(&alt.get::<foot>())))
+            })
+            .collect();
+        displayed.sort_by(|a, b| {
+            b.intrusion_level
+                .cmp(&a.intrusion_level)
+                .then(a.track.range.value.total_cmp(&b.track.range.value))
+        });
+        displayed.truncate(Self::DISPLAY_MAX);
+
+        displayed
+    }
+}
+impl SimulationElement for TrafficCollisionAvoidanceSystem {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.mode = reader.read(&self.mode_id);
+        self.traffic_display_mode = reader.read(&self.traffic_display_mode_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.tcas_state_id, self.state());
+        writer.write(&self.tcas_fault_id, self.has_fault);
+        writer.write(
+            &self.ta_only_id,
+            self.is_operative && self.mode != TcasMode::Standby && self.sensitivity_level == 2,
+        );
+        writer.write(&self.sensitivity_id, self.sensitivity_level);
+        writer.write(
+            &self.ra_corrective_id,
+            self.is_corrective_resolution_advisory(),
+        );
+
+        let (red_min, red_max) = self.resolution_advisory.map_or((0., 0.), |ra| ra.red());
+        let (green_min, green_max) = self
+            .resolution_advisory
+            .and_then(|ra| ra.green())
+            .unwrap_or((0., 0.));
+        writer.write(&self.vertical_speed_red_min_id, red_min);
+        writer.write(&self.vertical_speed_red_max_id, red_max);
+        writer.write(&self.vertical_speed_green_min_id, green_min);
+        writer.write(&self.vertical_speed_green_max_id, green_max);
+        writer.write(&self.ra_type_id, self.resolution_advisory_type());
+        writer.write(
+            &self.rate_to_maintain_id,
+            self.resolution_advisory
+                .map_or(0., |ra| ra.rate_to_maintain()),
+        );
+        writer.write(
+            &self.up_advisory_status_id,
+            self.resolution_advisory
+                .map_or(UpDownAdvisoryStatus::NoAdvisory, |ra| {
+                    ra.up_advisory_status()
+                }),
+        );
+        writer.write(
+            &self.down_advisory_status_id,
+            self.resolution_advisory
+                .map_or(UpDownAdvisoryStatus::NoAdvisory, |ra| {
+                    ra.down_advisory_status()
+                }),
+        );
+        writer.write(&self.ra_callout_id, self.resolution_advisory_callout());
+
+        let displayed = self.displayed_intruders();
+        for (index, ids) in self.traffic_ids.iter().enumerate() {
+            match displayed.get(index) {
+                Some(intruder) => ids.write(
+                    writer,
+                    intruder,
+                    self.own_altitude,
+                    Self::VERTICAL_TREND_THRESHOLD_FPM,
+                ),
+                None => ids.write_empty(writer),
+            }
+        }
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if !self.failure.is_active() {
+            consumption.consume_from_bus(self.powered_by, Power::new::<watt>(85.))
+        }
+    }
+}
+
+/// A TCAS computer tracking the traffic surrounding the aircraft in the simulator.
+pub struct SimulatorTrafficCollisionAvoidanceSystem {
+    tcas: TrafficCollisionAvoidanceSystem,
+    traffic: SimulatorTraffic,
+}
+impl SimulatorTrafficCollisionAvoidanceSystem {
+    pub fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
+        Self {
+            tcas: TrafficCollisionAvoidanceSystem::new(context, powered_by),
+            traffic: SimulatorTraffic::new(context),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        adr: &impl AirDataReferenceBus,
+        radio_altimeter: &impl RadioAltimeter,
+    ) {
+        self.tcas
+            .update(context, adr, radio_altimeter, &self.traffic);
+    }
+}
+impl SimulationElement for SimulatorTrafficCollisionAvoidanceSystem {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.tcas.accept(visitor);
+        self.traffic.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        self.traffic.write_intrusion_levels(writer, &self.tcas);
+    }
+}
+
+/// The identifiers of a single traffic symbol slot on the navigation display.
+struct TrafficSymbolIdentifiers {
+    valid_id: VariableIdentifier,
+    range_id: VariableIdentifier,
+    bearing_id: VariableIdentifier,
+    relative_altitude_id: VariableIdentifier,
+    vertical_trend_id: VariableIdentifier,
+    intrusion_level_id: VariableIdentifier,
+}
+impl TrafficSymbolIdentifiers {
+    fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            valid_id: context.get_identifier(format!("TCAS_TRAFFIC_{}_VALID", number)),
+            range_id: context.get_identifier(format!("TCAS_TRAFFIC_{}_RANGE", number)),
+            bearing_id: context.get_identifier(format!("TCAS_TRAFFIC_{}_BEARING", number)),
+            relative_altitude_id: context
+                .get_identifier(format!("TCAS_TRAFFIC_{}_RELATIVE_ALTITUDE", number)),
+            vertical_trend_id: context
+                .get_identifier(format!("TCAS_TRAFFIC_{}_VERTICAL_TREND", number)),
+            intrusion_level_id: context
+                .get_identifier(format!("TCAS_TRAFFIC_{}_INTRUSION_LEVEL", number)),
+        }
+    }
+
+    fn write(
+        &self,
+        writer: &mut SimulatorWriter,
+        intruder: &Intruder,
+        own_altitude: Length,
+        vertical_trend_threshold_fpm: f64,
+    ) {
+        let vertical_speed = intruder
+            .vertical_speed
+            .unwrap_or_default()
+            .get::<foot_per_minute>();
+
+        writer.write(&self.valid_id, true);
+        writer.write(&self.range_id, intruder.track.range.get::<nautical_mile>());
+        writer.write(&self.bearing_id, intruder.track.bearing.get::<degree>());
+        writer.write(
+            &self.relative_altitude_id,
+            intruder
+                .relative_altitude(own_altitude)
+                .unwrap_or_default()
+                .get::<foot>(),
+        );
+        writer.write(
+            &self.vertical_trend_id,
+            if vertical_speed > vertical_trend_threshold_fpm {
+                1.
+            } else if vertical_speed < -vertical_trend_threshold_fpm {
+                -1.
+            } else {
+                0.
+            },
+        );
+        writer.write(&self.intrusion_level_id, intruder.intrusion_level);
+    }
+
+    fn write_empty(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.valid_id, false);
+        writer.write(&self.range_id, 0.);
+        writer.write(&self.bearing_id, 0.);
+        writer.write(&self.relative_altitude_id, 0.);
+        writer.write(&self.vertical_trend_id, 0.);
+        writer.write(&self.intrusion_level_id, IntrusionLevel::Other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::test::TestElectricitySource;
+    use crate::electrical::{ElectricalBus, Electricity};
+    use crate::shared::arinc429::{Arinc429Word, SignStatus};
+    use crate::shared::{MachNumber, PotentialOrigin};
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, StartState};
+    use ntest::assert_about_eq;
+    use uom::si::electric_potential::volt;
+    use uom::si::f64::{ElectricPotential, Pressure, ThermodynamicTemperature};
+    use uom::si::velocity::knot;
+
+    struct TestAdr {
+        altitude: Length,
+        vertical_speed: Velocity,
+        failed: bool,
+    }
+    impl TestAdr {
+        fn word<T: Copy>(&self, value: T) -> Arinc429Word<T> {
+            Arinc429Word::new(
+                value,
+                if self.failed {
+                    SignStatus::FailureWarning
+                } else {
+                    SignStatus::NormalOperation
+                },
+            )
+        }
+    }
+    impl AirDataReferenceBus for TestAdr {
+        fn standard_altitude(&self) -> Arinc429Word<Length> {
+            self.word(self.altitude)
+        }
+        fn baro_corrected_altitude_1(&self) -> Arinc429Word<Length> {
+            self.word(self.altitude)
+        }
+        fn mach(&self) -> Arinc429Word<MachNumber> {
+            self.word(MachNumber::default())
+        }
+        fn computed_airspeed(&self) -> Arinc429Word<Velocity> {
+            self.word(Velocity::default())
+        }
+        fn max_allowable_airspeed(&self) -> Arinc429Word<Velocity> {
+            self.word(Velocity::default())
+        }
+        fn true_airspeed(&self) -> Arinc429Word<Velocity> {
+            self.word(Velocity::default())
+        }
+        fn total_air_temperature(&self) -> Arinc429Word<ThermodynamicTemperature> {
+            self.word(ThermodynamicTemperature::default())
+        }
+        fn vertical_speed(&self) -> Arinc429Word<Velocity> {
+            self.word(self.vertical_speed)
+        }
+        fn static_air_temperature(&self) -> Arinc429Word<ThermodynamicTemperature> {
+            self.word(ThermodynamicTemperature::default())
+        }
+        fn baro_corrected_altitude_2(&self) -> Arinc429Word<Length> {
+            self.word(self.altitude)
+        }
+        fn baro_correction_1(&self) -> Arinc429Word<Pressure> {
+            self.word(Pressure::default())
+        }
+        fn baro_correction_2(&self) -> Arinc429Word<Pressure> {
+            self.word(Pressure::default())
+        }
+        fn corrected_angle_of_attack(&self) -> Arinc429Word<Angle> {
+            self.word(Angle::default())
+        }
+    }
+
+    struct TestRadioAltimeter {
+        radio_altitude: Length,
+    }
+    impl RadioAltimeter for TestRadioAltimeter {
+        fn radio_altitude(&self) -> Arinc429Word<Length> {
+            if self.radio_altitude > Length::new::<foot>(2500.) {
+                Arinc429Word::new(Length::new::<foot>(2500.), SignStatus::NoComputedData)
+            } else {
+                Arinc429Word::new(self.radio_altitude, SignStatus::NormalOperation)
+            }
+        }
+    }
+
+    /// An intruder flying straight towards the own aircraft.
+    struct ScriptedIntruder {
+        id: u32,
+        range: Length,
+        closure_rate: Velocity,
+        altitude: Option<Length>,
+        vertical_speed: Velocity,
+        coordination_sense: Option<RaSense>,
+    }
+
+    struct TestTraffic {
+        intruders: Vec<ScriptedIntruder>,
+        tracks: Vec<IntruderTrack>,
+        /// When set, the tracks are only refreshed at this interval.
+        reply_interval: Option<Duration>,
+        time_since_reply: Duration,
+    }
+    impl TestTraffic {
+        fn new() -> Self {
+            Self {
+                intruders: vec![],
+                tracks: vec![],
+                reply_interval: None,
+                time_since_reply: Duration::ZERO,
+            }
+        }
+
+        fn update(&mut self, context: &UpdateContext) {
+            let delta = context.delta_as_time();
+            for intruder in self.intruders.iter_mut() {
+                intruder.range -= intruder.closure_rate * delta;
+                intruder.altitude = intruder
+                    .altitude
+                    .map(|altitude| altitude + intruder.vertical_speed * delta);
+            }
+
+            self.time_since_reply += context.delta();
+            if self
+                .reply_interval
+                .is_some_and(|interval| self.time_since_reply < interval)
+            {
+                return;
+            }
+            self.time_since_reply = Duration::ZERO;
+
+            self.tracks = self
+                .intruders
+                .iter()
+                .map(|intruder| {
+                    let track = IntruderTrack::new(
+                        intruder.id,
+                        intruder.range.abs(),
+                        Angle::default(),
+                        intruder.altitude,
+                    );
+                    match intruder.coordination_sense {
+                        Some(sense) => track.with_coordination_sense(sense),
+                        None => track,
+                    }
+                })
+                .collect();
+        }
+    }
+    impl TrafficProvider for TestTraffic {
+        fn intruder_tracks(&self) -> &[IntruderTrack] {
+            &self.tracks
+        }
+    }
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        is_dc_1_powered: bool,
+        adr: TestAdr,
+        radio_altimeter: TestRadioAltimeter,
+        traffic: TestTraffic,
+        tcas: TrafficCollisionAvoidanceSystem,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                is_dc_1_powered: true,
+                adr: TestAdr {
+                    altitude: Length::new::<foot>(30000.),
+                    vertical_speed: Velocity::default(),
+                    failed: false,
+                },
+                radio_altimeter: TestRadioAltimeter {
+                    radio_altitude: Length::new::<foot>(30000.),
+                },
+                traffic: TestTraffic::new(),
+                tcas: TrafficCollisionAvoidanceSystem::new(
+                    context,
+                    ElectricalBusType::DirectCurrent(1),
+                ),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(28.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_dc_1_powered {
+                electricity.flow(&self.electricity_source, &self.dc_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.traffic.update(context);
+            self.tcas
+                .update(context, &self.adr, &self.radio_altimeter, &self.traffic);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.tcas.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct TcasTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl TcasTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new_with_start_state(
+                    StartState::Cruise,
+                    TestAircraft::new,
+                ),
+            };
+            test_bed.write_by_name(
+                TrafficCollisionAvoidanceSystem::MODE_KEY,
+                TcasMode::TrafficAdvisoryResolutionAdvisory,
+            );
+            test_bed.write_by_name(
+                TrafficCollisionAvoidanceSystem::TRAFFIC_DISPLAY_MODE_KEY,
+                TcasTrafficDisplayMode::Threat,
+            );
+
+            test_bed
+        }
+
+        fn and(self) -> Self {
+            self
+        }
+
+        fn mode(mut self, mode: TcasMode) -> Self {
+            self.write_by_name(TrafficCollisionAvoidanceSystem::MODE_KEY, mode);
+            self
+        }
+
+        fn traffic_display_mode(mut self, mode: TcasTrafficDisplayMode) -> Self {
+            self.write_by_name(
+                TrafficCollisionAvoidanceSystem::TRAFFIC_DISPLAY_MODE_KEY,
+                mode,
+            );
+            self
+        }
+
+        fn no_power(mut self) -> Self {
+            self.command(|a| a.is_dc_1_powered = false);
+            self
+        }
+
+        fn failed_adr(mut self) -> Self {
+            self.command(|a| a.adr.failed = true);
+            self
+        }
+
+        fn altitude(mut self, altitude: Length) -> Self {
+            self.command(|a| a.adr.altitude = altitude);
+            self
+        }
+
+        fn vertical_speed(mut self, vertical_speed: Velocity) -> Self {
+            self.command(|a| a.adr.vertical_speed = vertical_speed);
+            self
+        }
+
+        fn radio_altitude(mut self, radio_altitude: Length) -> Self {
+            self.command(|a| a.radio_altimeter.radio_altitude = radio_altitude);
+            self
+        }
+
+        fn intruder(
+            mut self,
+            id: u32,
+            range: Length,
+            closure_rate: Velocity,
+            relative_altitude: Option<Length>,
+            vertical_speed: Velocity,
+        ) -> Self {
+            self.command(|a| {
+                let own_altitude = a.adr.altitude;
+                a.traffic.intruders.push(ScriptedIntruder {
+                    id,
+                    range,
+                    closure_rate,
+                    altitude: relative_altitude.map(|altitude| own_altitude + altitude),
+                    vertical_speed,
+                    coordination_sense: None,
+                })
+            });
+            self
+        }
+
+        fn head_on_intruder(self, id: u32, range_nm: f64, relative_altitude_ft: f64) -> Self {
+            self.intruder(
+                id,
+                Length::new::<nautical_mile>(range_nm),
+                Velocity::new::<knot>(500.),
+                Some(Length::new::<foot>(relative_altitude_ft)),
+                Velocity::default(),
+            )
+        }
+
+        fn with_coordination_sense(mut self, id: u32, sense: RaSense) -> Self {
+            self.command(|a| {
+                a.traffic
+                    .intruders
+                    .iter_mut()
+                    .filter(|intruder| intruder.id == id)
+                    .for_each(|intruder| intruder.coordination_sense = Some(sense))
+            });
+            self
+        }
+
+        fn reply_interval(mut self, interval: Duration) -> Self {
+            self.command(|a| a.traffic.reply_interval = Some(interval));
+            self
+        }
+
+        fn remove_intruder(mut self, id: u32) -> Self {
+            self.command(|a| a.traffic.intruders.retain(|intruder| intruder.id != id));
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            let step = Duration::from_millis(500);
+            let mut elapsed = Duration::ZERO;
+            while elapsed < duration {
+                self.run_with_delta(step);
+                elapsed += step;
+            }
+            self
+        }
+
+        fn state(&mut self) -> TcasState {
+            self.read_by_name(TrafficCollisionAvoidanceSystem::STATE_KEY)
+        }
+
+        fn sensitivity(&mut self) -> u8 {
+            let sensitivity: f64 =
+                self.read_by_name(TrafficCollisionAvoidanceSystem::SENSITIVITY_KEY);
+            sensitivity as u8
+        }
+
+        fn intrusion_level(&self, id: u32) -> Option<IntrusionLevel> {
+            self.query(|a| a.tcas.intrusion_level(id))
+        }
+
+        fn sense(&self) -> Option<RaSense> {
+            self.query(|a| a.tcas.resolution_advisory_sense())
+        }
+
+        fn is_corrective(&mut self) -> bool {
+            self.read_by_name(TrafficCollisionAvoidanceSystem::RA_CORRECTIVE_KEY)
+        }
+
+        fn red_band(&mut self) -> (f64, f64) {
+            (
+                self.read_by_name("TCAS_VSPEED_RED:1"),
+                self.read_by_name("TCAS_VSPEED_RED:2"),
+            )
+        }
+
+        fn green_band(&mut self) -> (f64, f64) {
+            (
+                self.read_by_name("TCAS_VSPEED_GREEN:1"),
+                self.read_by_name("TCAS_VSPEED_GREEN:2"),
+            )
+        }
+
+        fn ra_type(&mut self) -> RaType {
+            self.read_by_name(TrafficCollisionAvoidanceSystem::RA_TYPE_KEY)
+        }
+
+        fn rate_to_maintain(&mut self) -> f64 {
+            self.read_by_name(TrafficCollisionAvoidanceSystem::RA_RATE_TO_MAINTAIN_KEY)
+        }
+
+        fn up_advisory_status(&mut self) -> UpDownAdvisoryStatus {
+            self.read_by_name(TrafficCollisionAvoidanceSystem::RA_UP_ADVISORY_STATUS_KEY)
+        }
+
+        fn down_advisory_status(&mut self) -> UpDownAdvisoryStatus {
+            self.read_by_name(TrafficCollisionAvoidanceSystem::RA_DOWN_ADVISORY_STATUS_KEY)
+        }
+
+        fn callout(&mut self) -> RaCallout {
+            self.read_by_name(TrafficCollisionAvoidanceSystem::RA_CALLOUT_KEY)
+        }
+
+        fn has_fault(&mut self) -> bool {
+            self.read_by_name(TrafficCollisionAvoidanceSystem::FAULT_KEY)
+        }
+
+        fn traffic_slot_is_valid(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("TCAS_TRAFFIC_{}_VALID", number))
+        }
+
+        fn traffic_slot_range(&mut self, number: usize) -> f64 {
+            self.read_by_name(&format!("TCAS_TRAFFIC_{}_RANGE", number))
+        }
+
+        fn traffic_slot_relative_altitude(&mut self, number: usize) -> f64 {
+            self.read_by_name(&format!("TCAS_TRAFFIC_{}_RELATIVE_ALTITUDE", number))
+        }
+
+        fn traffic_slot_intrusion_level(&mut self, number: usize) -> IntrusionLevel {
+            self.read_by_name(&format!("TCAS_TRAFFIC_{}_INTRUSION_LEVEL", number))
+        }
+    }
+    impl TestBed for TcasTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> TcasTestBed {
+        TcasTestBed::new()
+    }
+
+    #[test]
+    fn standby_is_sensitivity_level_1() {
+        let mut test_bed = test_bed()
+            .mode(TcasMode::Standby)
+            .and()
+            .run_for(Duration::from_secs(1));
+
+        assert_eq!(test_bed.sensitivity(), 1);
+    }
+
+    #[test]
+    fn ta_only_mode_is_sensitivity_level_2() {
+        let mut test_bed = test_bed()
+            .mode(TcasMode::TrafficAdvisoryOnly)
+            .and()
+            .run_for(Duration::from_secs(1));
+
+        assert_eq!(test_bed.sensitivity(), 2);
+        let is_ta_only: bool = test_bed.read_by_name(TrafficCollisionAvoidanceSystem::TA_ONLY_KEY);
+        assert!(is_ta_only);
+    }
+
+    #[test]
+    fn sensitivity_level_depends_on_altitude() {
+        let mut test_bed = test_bed()
+            .altitude(Length::new::<foot>(800.))
+            .radio_altitude(Length::new::<foot>(800.))
+            .run_for(Duration::from_secs(1));
+        assert_eq!(test_bed.sensitivity(), 2);
+
+        test_bed = test_bed
+            .altitude(Length::new::<foot>(2000.))
+            .radio_altitude(Length::new::<foot>(2000.))
+            .run_for(Duration::from_secs(1));
+        assert_eq!(test_bed.sensitivity(), 3);
+
+        test_bed = test_bed
+            .altitude(Length::new::<foot>(8000.))
+            .radio_altitude(Length::new::<foot>(8000.))
+            .run_for(Duration::from_secs(1));
+        assert_eq!(test_bed.sensitivity(), 5);
+
+        test_bed = test_bed
+            .altitude(Length::new::<foot>(30000.))
+            .radio_altitude(Length::new::<foot>(30000.))
+            .run_for(Duration::from_secs(1));
+        assert_eq!(test_bed.sensitivity(), 7);
+    }
+
+    #[test]
+    fn distant_traffic_is_not_a_threat() {
+        let mut test_bed = test_bed()
+            .head_on_intruder(1, 20., 0.)
+            .run_for(Duration::from_secs(2));
+
+        assert_eq!(test_bed.state(), TcasState::None);
+        assert_eq!(test_bed.intrusion_level(1), Some(IntrusionLevel::Other));
+    }
+
+    #[test]
+    fn close_traffic_is_proximate() {
+        let mut test_bed = test_bed()
+            .intruder(
+                1,
+                Length::new::<nautical_mile>(4.),
+                Velocity::default(),
+                Some(Length::new::<foot>(1000.)),
+                Velocity::default(),
+            )
+            .run_for(Duration::from_secs(2));
+
+        assert_eq!(test_bed.state(), TcasState::None);
+        assert_eq!(test_bed.intrusion_level(1), Some(IntrusionLevel::Proximate));
+    }
+
+    #[test]
+    fn closing_traffic_triggers_ta_before_ra() {
+        let mut test_bed = test_bed()
+            .head_on_intruder(1, 8., 0.)
+            .run_for(Duration::from_secs(14));
+
+        assert_eq!(test_bed.state(), TcasState::TrafficAdvisory);
+        assert_eq!(
+            test_bed.intrusion_level(1),
+            Some(IntrusionLevel::TrafficAdvisory)
+        );
+
+        test_bed = test_bed.run_for(Duration::from_secs(14));
+
+        assert_eq!(test_bed.state(), TcasState::ResolutionAdvisory);
+        assert_eq!(
+            test_bed.intrusion_level(1),
+            Some(IntrusionLevel::ResolutionAdvisory)
+        );
+    }
+
+    #[test]
+    fn ta_only_mode_does_not_issue_ras() {
+        let mut test_bed = test_bed()
+            .mode(TcasMode::TrafficAdvisoryOnly)
+            .and()
+            .head_on_intruder(1, 8., 0.)
+            .run_for(Duration::from_secs(45));
+
+        assert_eq!(test_bed.state(), TcasState::TrafficAdvisory);
+        assert_eq!(test_bed.sense(), None);
+    }
+
+    #[test]
+    fn non_altitude_reporting_traffic_only_triggers_tas() {
+        let mut test_bed = test_bed()
+            .intruder(
+                1,
+                Length::new::<nautical_mile>(8.),
+                Velocity::new::<knot>(500.),
+                None,
+                Velocity::default(),
+            )
+            .run_for(Duration::from_secs(45));
+
+        assert_eq!(test_bed.state(), TcasState::TrafficAdvisory);
+    }
+
+    #[test]
+    fn ra_against_traffic_above_is_descend() {
+        let test_bed = test_bed()
+            .head_on_intruder(1, 5., 200.)
+            .run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.sense(), Some(RaSense::Down));
+    }
+
+    #[test]
+    fn ra_against_traffic_below_is_climb() {
+        let test_bed = test_bed()
+            .head_on_intruder(1, 5., -200.)
+            .run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.sense(), Some(RaSense::Up));
+    }
+
+    #[test]
+    fn ra_is_complementary_to_intruder_sense() {
+        let test_bed = test_bed()
+            .head_on_intruder(1, 5., 200.)
+            .with_coordination_sense(1, RaSense::Down)
+            .run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.sense(), Some(RaSense::Up));
+    }
+
+    #[test]
+    fn climb_ras_are_inhibited_at_high_altitude() {
+        let test_bed = test_bed()
+            .altitude(Length::new::<foot>(40000.))
+            .and()
+            .head_on_intruder(1, 5., -200.)
+            .run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.sense(), Some(RaSense::Down));
+    }
+
+    #[test]
+    fn descend_ras_are_inhibited_close_to_the_ground() {
+        let test_bed = test_bed()
+            .altitude(Length::new::<foot>(1600.))
+            .radio_altitude(Length::new::<foot>(1100.))
+            .and()
+            .head_on_intruder(1, 3., 100.)
+            .run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.sense(), Some(RaSense::Up));
+    }
+
+    #[test]
+    fn ra_with_insufficient_separation_is_corrective() {
+        let mut test_bed = test_bed()
+            .head_on_intruder(1, 5., 200.)
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.is_corrective());
+
+        let (red_min, red_max) = test_bed.red_band();
+        assert_about_eq!(red_min, -1500.);
+        assert_about_eq!(red_max, 6000.);
+
+        let (green_min, green_max) = test_bed.green_band();
+        assert_about_eq!(green_min, -2000.);
+        assert_about_eq!(green_max, -1500.);
+    }
+
+    #[test]
+    fn ra_with_sufficient_separation_is_preventive() {
+        let mut test_bed = test_bed()
+            .vertical_speed(Velocity::new::<foot_per_minute>(2500.))
+            .and()
+            .head_on_intruder(1, 5., -500.)
+            .run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.sense(), Some(RaSense::Up));
+        assert!(!test_bed.is_corrective());
+
+        let (red_min, red_max) = test_bed.red_band();
+        assert_about_eq!(red_min, -6000.);
+        assert_about_eq!(red_max, 0.);
+
+        let (green_min, green_max) = test_bed.green_band();
+        assert_about_eq!(green_min, 0.);
+        assert_about_eq!(green_max, 0.);
+    }
+
+    #[test]
+    fn corrective_ra_announces_descend() {
+        let mut test_bed = test_bed()
+            .head_on_intruder(1, 5., 200.)
+            .run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.callout(), RaCallout::Descend);
+        assert_eq!(test_bed.ra_type(), RaType::None);
+        assert_about_eq!(test_bed.rate_to_maintain(), -1500.);
+        assert_eq!(
+            test_bed.up_advisory_status(),
+            UpDownAdvisoryStatus::NoAdvisory
+        );
+        assert_eq!(
+            test_bed.down_advisory_status(),
+            UpDownAdvisoryStatus::ClimbDescend
+        );
+    }
+
+    #[test]
+    fn preventive_ra_announces_monitor_vertical_speed() {
+        let mut test_bed = test_bed()
+            .vertical_speed(Velocity::new::<foot_per_minute>(2500.))
+            .and()
+            .head_on_intruder(1, 5., -500.)
+            .run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.callout(), RaCallout::MonitorVerticalSpeed);
+        assert_eq!(test_bed.ra_type(), RaType::None);
+        assert_about_eq!(test_bed.rate_to_maintain(), 0.);
+        assert_eq!(
+            test_bed.up_advisory_status(),
+            UpDownAdvisoryStatus::DontClimbDontDescend
+        );
+        assert_eq!(
+            test_bed.down_advisory_status(),
+            UpDownAdvisoryStatus::NoAdvisory
+        );
+    }
+
+    #[test]
+    fn preventive_ra_limits_vertical_speed_as_little_as_possible() {
+        let mut test_bed = test_bed()
+            .intruder(
+                1,
+                Length::new::<nautical_mile>(5.),
+                Velocity::new::<knot>(500.),
+                Some(Length::new::<foot>(-500.)),
+                Velocity::new::<foot_per_minute>(-1500.),
+            )
+            .run_for(Duration::from_secs(5));
+
+        assert_eq!(test_bed.state(), TcasState::ResolutionAdvisory);
+        assert!(!test_bed.is_corrective());
+        assert_eq!(
+            test_bed.up_advisory_status(),
+            UpDownAdvisoryStatus::DontClimbDontDescendGreater1000
+        );
+
+        let (red_min, red_max) = test_bed.red_band();
+        assert_about_eq!(red_min, -6000.);
+        assert_about_eq!(red_max, -1000.);
+    }
+
+    #[test]
+    fn ra_through_the_intruder_altitude_is_crossing() {
+        let mut test_bed = test_bed()
+            .head_on_intruder(1, 5., 200.)
+            .with_coordination_sense(1, RaSense::Down)
+            .run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.sense(), Some(RaSense::Up));
+        assert_eq!(test_bed.ra_type(), RaType::Crossing);
+        assert_eq!(test_bed.callout(), RaCallout::ClimbCrossing);
+    }
+
+    #[test]
+    fn ra_when_already_beyond_the_target_vertical_speed_is_maintain() {
+        let mut test_bed = test_bed()
+            .vertical_speed(Velocity::new::<foot_per_minute>(-1800.))
+            .and()
+            .intruder(
+                1,
+                Length::new::<nautical_mile>(5.),
+                Velocity::new::<knot>(500.),
+                Some(Length::new::<foot>(300.)),
+                Velocity::new::<foot_per_minute>(-2500.),
+            )
+            .with_coordination_sense(1, RaSense::Up)
+            .run_for(Duration::from_secs(3));
+
+        assert_eq!(test_bed.sense(), Some(RaSense::Down));
+        assert_eq!(test_bed.ra_type(), RaType::Maintain);
+        assert_eq!(test_bed.callout(), RaCallout::MaintainVerticalSpeed);
+
+        let (green_min, green_max) = test_bed.green_band();
+        assert_about_eq!(green_min, -4400.);
+        assert_about_eq!(green_max, -1500.);
+    }
+
+    #[test]
+    fn corrective_ra_remains_when_the_crew_complies() {
+        let mut test_bed = test_bed()
+            .head_on_intruder(1, 5., 200.)
+            .run_for(Duration::from_secs(5));
+        assert_eq!(test_bed.callout(), RaCallout::Descend);
+
+        test_bed = test_bed
+            .vertical_speed(Velocity::new::<foot_per_minute>(-1500.))
+            .run_for(Duration::from_secs(5));
+
+        assert!(test_bed.is_corrective());
+        assert_eq!(test_bed.callout(), RaCallout::Descend);
+    }
+
+    #[test]
+    fn corrective_ra_is_increased_when_complying_is_not_sufficient() {
+        let mut test_bed = test_bed()
+            .intruder(
+                1,
+                Length::new::<nautical_mile>(5.),
+                Velocity::new::<knot>(500.),
+                Some(Length::new::<foot>(-600.)),
+                Velocity::new::<foot_per_minute>(2500.),
+            )
+            .with_coordination_sense(1, RaSense::Down)
+            .run_for(Duration::from_secs(3));
+        assert_eq!(test_bed.callout(), RaCallout::Climb);
+
+        test_bed = test_bed
+            .vertical_speed(Velocity::new::<foot_per_minute>(1800.))
+            .run_for(Duration::from_secs(6));
+
+        assert_eq!(test_bed.ra_type(), RaType::Increase);
+        assert_eq!(test_bed.callout(), RaCallout::IncreaseClimb);
+        assert_about_eq!(test_bed.rate_to_maintain(), 2500.);
+
+        let (red_min, red_max) = test_bed.red_band();
+        assert_about_eq!(red_min, -6000.);
+        assert_about_eq!(red_max, 2500.);
+
+        let (green_min, green_max) = test_bed.green_band();
+        assert_about_eq!(green_min, 2500.);
+        assert_about_eq!(green_max, 4400.);
+    }
+
+    #[test]
+    fn ra_is_reversed_when_the_intruder_selects_the_same_sense() {
+        let mut test_bed = test_bed()
+            .head_on_intruder(1, 5., 200.)
+            .run_for(Duration::from_secs(5));
+        assert_eq!(test_bed.sense(), Some(RaSense::Down));
+
+        test_bed = test_bed
+            .with_coordination_sense(1, RaSense::Down)
+            .run_for(Duration::from_secs(1));
+
+        assert_eq!(test_bed.sense(), Some(RaSense::Up));
+        assert_eq!(test_bed.ra_type(), RaType::Reversal);
+        assert_eq!(test_bed.callout(), RaCallout::ClimbNow);
+    }
+
+    #[test]
+    fn ra_is_only_reversed_once() {
+        let mut test_bed = test_bed()
+            .head_on_intruder(1, 5., 200.)
+            .run_for(Duration::from_secs(5))
+            .with_coordination_sense(1, RaSense::Down)
+            .run_for(Duration::from_secs(1));
+        assert_eq!(test_bed.sense(), Some(RaSense::Up));
+
+        test_bed = test_bed
+            .with_coordination_sense(1, RaSense::Up)
+            .run_for(Duration::from_secs(1));
+
+        assert_eq!(test_bed.sense(), Some(RaSense::Up));
+        assert_eq!(test_bed.callout(), RaCallout::ClimbNow);
+    }
+
+    #[test]
+    fn ra_outputs_are_cleared_without_an_ra() {
+        let mut test_bed = test_bed()
+            .head_on_intruder(1, 5., 200.)
+            .run_for(Duration::from_secs(10))
+            .run_for(Duration::from_secs(60));
+
+        assert_eq!(test_bed.callout(), RaCallout::None);
+        assert_eq!(test_bed.ra_type(), RaType::None);
+        assert_about_eq!(test_bed.rate_to_maintain(), 0.);
+        assert_eq!(
+            test_bed.up_advisory_status(),
+            UpDownAdvisoryStatus::NoAdvisory
+        );
+        assert_eq!(
+            test_bed.down_advisory_status(),
+            UpDownAdvisoryStatus::NoAdvisory
+        );
+    }
+
+    #[test]
+    fn ra_clears_once_traffic_diverges() {
+        let mut test_bed = test_bed()
+            .head_on_intruder(1, 5., 200.)
+            .run_for(Duration::from_secs(10));
+        assert_eq!(test_bed.state(), TcasState::ResolutionAdvisory);
+
+        test_bed = test_bed.run_for(Duration::from_secs(60));
+
+        assert_eq!(test_bed.state(), TcasState::None);
+        assert_eq!(test_bed.sense(), None);
+    }
+
+    #[test]
+    fn intruders_which_are_no_longer_reported_are_dropped() {
+        let test_bed = test_bed()
+            .head_on_intruder(1, 10., 0.)
+            .run_for(Duration::from_secs(1));
+        assert!(test_bed.intrusion_level(1).is_some());
+
+        let test_bed = test_bed.remove_intruder(1).run_for(Duration::from_secs(1));
+
+        assert_eq!(test_bed.intrusion_level(1), None);
+    }
+
+    #[test]
+    fn replies_received_less_often_than_updates_still_trigger_advisories() {
+        let mut test_bed = test_bed()
+            .reply_interval(Duration::from_secs(1))
+            .and()
+            .head_on_intruder(1, 8., 0.)
+            .run_for(Duration::from_secs(14));
+
+        assert_eq!(test_bed.state(), TcasState::TrafficAdvisory);
+
+        test_bed = test_bed.run_for(Duration::from_secs(14));
+
+        assert_eq!(test_bed.state(), TcasState::ResolutionAdvisory);
+    }
+
+    #[test]
+    fn unpowered_tcas_does_not_issue_advisories() {
+        let mut test_bed = test_bed()
+            .no_power()
+            .and()
+            .head_on_intruder(1, 5., 0.)
+            .run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.state(), TcasState::None);
+        assert_eq!(test_bed.sensitivity(), 1);
+    }
+
+    #[test]
+    fn failed_tcas_is_faulty() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::TrafficCollisionAvoidanceSystem);
+        let mut test_bed = test_bed
+            .head_on_intruder(1, 5., 0.)
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.has_fault());
+        assert_eq!(test_bed.state(), TcasState::None);
+    }
+
+    #[test]
+    fn loss_of_altitude_data_is_a_fault() {
+        let mut test_bed = test_bed().failed_adr().run_for(Duration::from_secs(1));
+
+        assert!(test_bed.has_fault());
+    }
+
+    #[test]
+    fn proximate_traffic_is_only_displayed_in_threat_mode_when_there_is_a_threat() {
+        let mut test_bed = test_bed()
+            .intruder(
+                1,
+                Length::new::<nautical_mile>(4.),
+                Velocity::default(),
+                Some(Length::new::<foot>(1000.)),
+                Velocity::default(),
+            )
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.traffic_slot_is_valid(1));
+
+        test_bed = test_bed
+            .traffic_display_mode(TcasTrafficDisplayMode::All)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.traffic_slot_is_valid(1));
+        assert_about_eq!(test_bed.traffic_slot_range(1), 4.);
+        assert_about_eq!(test_bed.traffic_slot_relative_altitude(1), 1000.);
+        assert_eq!(
+            test_bed.traffic_slot_intrusion_level(1),
+            IntrusionLevel::Proximate
+        );
+    }
+
+    #[test]
+    fn traffic_is_displayed_by_decreasing_threat() {
+        let mut test_bed = test_bed()
+            .traffic_display_mode(TcasTrafficDisplayMode::All)
+            .and()
+            .intruder(
+                1,
+                Length::new::<nautical_mile>(2.),
+                Velocity::default(),
+                Some(Length::new::<foot>(1000.)),
+                Velocity::default(),
+            )
+            .head_on_intruder(2, 8., 0.)
+            .run_for(Duration::from_secs(14));
+
+        assert_eq!(
+            test_bed.traffic_slot_intrusion_level(1),
+            IntrusionLevel::TrafficAdvisory
+        );
+        assert_eq!(
+            test_bed.traffic_slot_intrusion_level(2),
+            IntrusionLevel::Proximate
+        );
+        assert!(!test_bed.traffic_slot_is_valid(3));
+    }
+
+    #[test]
+    fn traffic_outside_the_selected_altitude_band_is_not_displayed() {
+        let mut test_bed = test_bed()
+            .traffic_display_mode(TcasTrafficDisplayMode::Below)
+            .and()
+            .intruder(
+                1,
+                Length::new::<nautical_mile>(4.),
+                Velocity::default(),
+                Some(Length::new::<foot>(5000.)),
+                Velocity::default(),
+            )
+            .run_for(Duration::from_secs(1));
+        assert!(!test_bed.traffic_slot_is_valid(1));
+
+        test_bed = test_bed
+            .traffic_display_mode(TcasTrafficDisplayMode::Above)
+            .run_for(Duration::from_secs(1));
+        assert!(test_bed.traffic_slot_is_valid(1));
+    }
+
+    struct SimulatorTcasTestAircraft {
+        electricity_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        adr: TestAdr,
+        radio_altimeter: TestRadioAltimeter,
+        tcas: SimulatorTrafficCollisionAvoidanceSystem,
+    }
+    impl SimulatorTcasTestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                adr: TestAdr {
+                    altitude: Length::new::<foot>(30000.),
+                    vertical_speed: Velocity::default(),
+                    failed: false,
+                },
+                radio_altimeter: TestRadioAltimeter {
+                    radio_altitude: Length::new::<foot>(30000.),
+                },
+                tcas: SimulatorTrafficCollisionAvoidanceSystem::new(
+                    context,
+                    ElectricalBusType::DirectCurrent(1),
+                ),
+            }
+        }
+    }
+    impl Aircraft for SimulatorTcasTestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(28.));
+            electricity.supplied_by(&self.electricity_source);
+            electricity.flow(&self.electricity_source, &self.dc_1_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.tcas.update(context, &self.adr, &self.radio_altimeter);
+        }
+    }
+    impl SimulationElement for SimulatorTcasTestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.tcas.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn write_intruder_slot(
+        test_bed: &mut SimulationTestBed<SimulatorTcasTestAircraft>,
+        number: usize,
+        id: u32,
+        range_nm: f64,
+        altitude_ft: f64,
+    ) {
+        test_bed.write_by_name(&format!("TCAS_INTRUDER_{}_VALID", number), true);
+        test_bed.write_by_name(&format!("TCAS_INTRUDER_{}_ID", number), id);
+        test_bed.write_by_name(&format!("TCAS_INTRUDER_{}_RANGE", number), range_nm);
+        test_bed.write_by_name(&format!("TCAS_INTRUDER_{}_BEARING", number), 0.);
+        test_bed.write_by_name(&format!("TCAS_INTRUDER_{}_ALTITUDE", number), altitude_ft);
+        test_bed.write_by_name(&format!("TCAS_INTRUDER_{}_ALTITUDE_VALID", number), true);
+    }
+
+    #[test]
+    fn simulator_traffic_reads_valid_intruder_tracks() {
+        let mut test_bed = SimulationTestBed::new(SimulatorTcasTestAircraft::new);
+        write_intruder_slot(&mut test_bed, 1, 42, 3.5, 12000.);
+        test_bed.write_by_name("TCAS_INTRUDER_1_BEARING", 90.);
+        test_bed.write_by_name("TCAS_INTRUDER_2_VALID", false);
+        test_bed.write_by_name("TCAS_INTRUDER_2_ID", 43.);
+        test_bed.run();
+
+        let tracks = test_bed.query(|a| a.tcas.traffic.intruder_tracks().to_vec());
+        assert_eq!(tracks.len(), 1);
+        assert_eq!(tracks[0].id(), 42);
+        assert_about_eq!(tracks[0].range.get::<nautical_mile>(), 3.5);
+        assert_about_eq!(tracks[0].bearing.get::<degree>(), 90.);
+        assert_about_eq!(tracks[0].altitude.unwrap().get::<foot>(), 12000.);
+        assert_eq!(tracks[0].coordination_sense, None);
+    }
+
+    #[test]
+    fn simulator_traffic_reads_non_altitude_reporting_intruder_tracks() {
+        let mut test_bed = SimulationTestBed::new(SimulatorTcasTestAircraft::new);
+        write_intruder_slot(&mut test_bed, 1, 42, 3.5, 12000.);
+        test_bed.write_by_name("TCAS_INTRUDER_1_ALTITUDE_VALID", false);
+        test_bed.run();
+
+        let tracks = test_bed.query(|a| a.tcas.traffic.intruder_tracks().to_vec());
+        assert_eq!(tracks.len(), 1);
+        assert_eq!(tracks[0].altitude, None);
+    }
+
+    #[test]
+    fn simulator_tcas_writes_the_intrusion_level_back_to_each_intruder_slot() {
+        let mut test_bed = SimulationTestBed::new_with_start_state(
+            StartState::Cruise,
+            SimulatorTcasTestAircraft::new,
+        );
+        test_bed.write_by_name(
+            TrafficCollisionAvoidanceSystem::MODE_KEY,
+            TcasMode::TrafficAdvisoryResolutionAdvisory,
+        );
+        write_intruder_slot(&mut test_bed, 1, 7, 20., 30000.);
+        write_intruder_slot(&mut test_bed, 3, 9, 0.5, 30000.);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let slot_1: IntrusionLevel = test_bed.read_by_name("TCAS_INTRUDER_1_INTRUSION_LEVEL");
+        let slot_2: IntrusionLevel = test_bed.read_by_name("TCAS_INTRUDER_2_INTRUSION_LEVEL");
+        let slot_3: IntrusionLevel = test_bed.read_by_name("TCAS_INTRUDER_3_INTRUSION_LEVEL");
+        assert_eq!(slot_1, IntrusionLevel::Other);
+        assert_eq!(slot_2, IntrusionLevel::Other);
+        assert_eq!(slot_3, IntrusionLevel::ResolutionAdvisory);
+    }
+}
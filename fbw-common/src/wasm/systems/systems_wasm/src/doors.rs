@@ -0,0 +1,40 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::msfs::legacy::execute_calculator_code;
+#[cfg(target_arch = "wasm32")]
+use msfs::legacy::execute_calculator_code;
+use systems::shared::to_bool;
+
+use crate::{ExecuteOn, MsfsAspectBuilder, Variable};
+use std::error::Error;
+
+/// Closes the simulator door again when it is opened while residual cabin pressure keeps the
+/// door locked. The doors are identified by their door id and the interactive point opening them.
+pub(super) fn residual_pressure_door_lock(
+    doors: impl IntoIterator<Item = (&'static str, usize)>,
+) -> impl FnOnce(&mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
+    move |builder: &mut MsfsAspectBuilder| {
+        for (door_id, interactive_point) in doors {
+            builder.on_change(
+                ExecuteOn::PostTick,
+                vec![Variable::named(&format!(
+                    "DOOR_{}_RESIDUAL_PRESSURE",
+                    door_id
+                ))],
+                Box::new(move |prev_values, new_values| {
+                    let had_residual_pressure = to_bool(prev_values[0]);
+                    let has_residual_pressure = to_bool(new_values[0]);
+
+                    if !had_residual_pressure && has_residual_pressure {
+                        // Aircraft exits are numbered from 1, interactive points from 0.
+                        execute_calculator_code::<()>(&format!(
+                            "{} (>K:TOGGLE_AIRCRAFT_EXIT)",
+                            interactive_point + 1
+                        ));
+                    }
+                }),
+            );
+        }
+
+        Ok(())
+    }
+}
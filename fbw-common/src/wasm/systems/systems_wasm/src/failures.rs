@@ -1,55 +1,330 @@
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::FxHashMap;
 use serde::de::{Deserializer, SeqAccess, Visitor};
-use systems::failures::FailureType;
+use serde::Deserialize;
+use std::time::{Duration, TryFromFloatSecsError};
+use systems::failures::{
+    ArmedFailures, FailureFlightPhase, FailureReliability, FailureTrigger, FailureType,
+    RandomFailureGenerator,
+};
+use systems::simulation::UpdateContext;
+use uom::si::{f64::*, length::foot, ratio::ratio, time::hour, velocity::knot};
 
 #[derive(Default)]
 pub(super) struct Failures {
     identifier_to_failure_type: FxHashMap<u64, FailureType>,
-    active_failures: Option<FxHashSet<FailureType>>,
+    active_failures: FxHashMap<FailureType, Ratio>,
+    triggered_failures: FxHashMap<FailureType, Ratio>,
+    armed_failures: ArmedFailures,
+    random_failures: RandomFailureGenerator,
+    generator_configuration: Option<FailureGeneratorMessage>,
+    reported_injections: (usize, usize),
+    has_update: bool,
+    has_injection_update: bool,
 }
 impl Failures {
     pub(super) fn add_failures(&mut self, failures: impl IntoIterator<Item = (u64, FailureType)>) {
         self.identifier_to_failure_type.extend(failures);
     }
 
+    /// Handles the list of active failures sent by the failures orchestrator.
+    /// Each entry is either a failure id, or a `[id, severity]` pair for a partial failure.
     pub(super) fn handle_failure_update(&mut self, data: &str) {
         let visitor = FailureIdVisitor(&self.identifier_to_failure_type);
-        self.active_failures =
-            match serde_json::Deserializer::from_str(data).deserialize_seq(visitor) {
-                Ok(active_failures) => Some(active_failures),
-                Err(e) => {
-                    eprintln!("SYSTEMS: Failed to parse failure update message: '{e}'");
-                    None
+        match serde_json::Deserializer::from_str(data).deserialize_seq(visitor) {
+            Ok(active_failures) => {
+                // Triggered failures are kept until the orchestrator acknowledges them,
+                // as an update sent before it received the trigger would otherwise clear them.
+                self.triggered_failures
+                    .retain(|failure_type, _| !active_failures.contains_key(failure_type));
+                self.active_failures = active_failures;
+                self.has_update = true;
+            }
+            Err(e) => {
+                eprintln!("SYSTEMS: Failed to parse failure update message: '{e}'");
+            }
+        }
+    }
+
+    /// Handles the list of armed failures sent by the failures orchestrator.
+    pub(super) fn handle_failure_arm(&mut self, data: &str) {
+        match serde_json::from_str::<Vec<ArmedFailureMessage>>(data) {
+            Ok(armed_failures) => {
+                let identifier_to_failure_type = &self.identifier_to_failure_type;
+                let active_failures = &self.active_failures;
+                let triggered_failures = &self.triggered_failures;
+                // Failures with an invalid trigger, such as a non-finite delay, aren't armed.
+                self.armed_failures.set_armed(
+                    armed_failures.into_iter().filter_map(|armed| {
+                        let failure_type = identifier_to_failure_type.get(&armed.id)?;
+                        let trigger = FailureTrigger::try_from(armed.trigger).ok()?;
+                        Some((*failure_type, Ratio::new::<ratio>(armed.severity), trigger))
+                    }),
+                    |failure_type| {
+                        active_failures.contains_key(&failure_type)
+                            || triggered_failures.contains_key(&failure_type)
+                    },
+                );
+            }
+            Err(e) => {
+                eprintln!("SYSTEMS: Failed to parse failure arm message: '{e}'");
+            }
+        }
+    }
+
+    /// Handles the configuration of the random failure generator sent by the failures orchestrator.
+    /// Sending no failures or a rate multiplier of zero disables the generator.
+    /// The orchestrator resends its configuration on every failure request, an unchanged
+    /// configuration is ignored so the generator keeps its injected and dormant failures.
+    pub(super) fn handle_failure_generator(&mut self, data: &str) {
+        match serde_json::from_str::<FailureGeneratorMessage>(data) {
+            Ok(generator) => {
+                if self.generator_configuration.as_ref() == Some(&generator) {
+                    return;
                 }
-            };
+
+                let reliabilities = generator
+                    .failures
+                    .iter()
+                    .filter_map(|reliability| {
+                        let failure_type = *self.identifier_to_failure_type.get(&reliability.id)?;
+                        let mtbf = Time::new::<hour>(reliability.mtbf_hours);
+                        match &reliability.exposure {
+                            Some(exposure) => {
+                                FailureTrigger::try_from(exposure.clone())
+                                    .ok()
+                                    .map(|exposure| {
+                                        FailureReliability::latent(failure_type, mtbf, exposure)
+                                    })
+                            }
+                            None => Some(FailureReliability::new(failure_type, mtbf)),
+                        }
+                    })
+                    .collect();
+
+                self.random_failures = RandomFailureGenerator::new(
+                    reliabilities,
+                    generator.rate_multiplier,
+                    generator.seed,
+                );
+                self.generator_configuration = Some(generator);
+                self.reported_injections = (0, 0);
+                self.has_injection_update = true;
+            }
+            Err(e) => {
+                eprintln!("SYSTEMS: Failed to parse failure generator message: '{e}'");
+            }
+        }
     }
 
-    pub(super) fn get_updated_active_failures(&mut self) -> Option<FxHashSet<FailureType>> {
-        self.active_failures.take()
+    /// Evaluates the armed failures and the random failure generator, and returns the
+    /// identifiers and severities of the failures triggered during this update.
+    pub(super) fn update(&mut self, context: &UpdateContext) -> Vec<(u64, f64)> {
+        let mut triggered_failures = self.armed_failures.update(context);
+        triggered_failures.extend(self.random_failures.update(context));
+
+        let injections = (
+            self.random_failures.injected_failures().len(),
+            self.random_failures.dormant_failures().count(),
+        );
+        if injections != self.reported_injections {
+            self.reported_injections = injections;
+            self.has_injection_update = true;
+        }
+
+        if triggered_failures.is_empty() {
+            return Vec::new();
+        }
+
+        self.triggered_failures
+            .extend(triggered_failures.iter().copied());
+        self.has_update = true;
+
+        triggered_failures
+            .into_iter()
+            .filter_map(|(failure_type, severity)| {
+                self.identifier(failure_type)
+                    .map(|id| (id, severity.get::<ratio>()))
+            })
+            .collect()
+    }
+
+    /// Returns the failures injected by the random failure generator when they changed,
+    /// as a list of `[id, injected at, revealed at]` where times are in seconds since
+    /// the generator was configured and the revealed time is `null` for dormant failures.
+    pub(super) fn get_updated_injected_failures(&mut self) -> Option<Vec<(u64, f64, Option<f64>)>> {
+        if !self.has_injection_update {
+            return None;
+        }
+        self.has_injection_update = false;
+
+        Some(
+            self.random_failures
+                .injected_failures()
+                .iter()
+                .filter_map(|injected| {
+                    self.identifier(injected.failure_type()).map(|id| {
+                        (
+                            id,
+                            injected.injected_at().as_secs_f64(),
+                            injected.revealed_at().map(|time| time.as_secs_f64()),
+                        )
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    fn identifier(&self, failure_type: FailureType) -> Option<u64> {
+        self.identifier_to_failure_type
+            .iter()
+            .find(|(_, ft)| **ft == failure_type)
+            .map(|(id, _)| *id)
     }
+
+    pub(super) fn get_updated_active_failures(&mut self) -> Option<FxHashMap<FailureType, Ratio>> {
+        if !self.has_update {
+            return None;
+        }
+        self.has_update = false;
+
+        let mut active_failures = self.active_failures.clone();
+        active_failures.extend(self.triggered_failures.iter());
+        Some(active_failures)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FailureEntry {
+    Id(u64),
+    WithSeverity(u64, f64),
 }
 
 struct FailureIdVisitor<'a>(&'a FxHashMap<u64, FailureType>);
 impl<'de> Visitor<'de> for FailureIdVisitor<'_> {
-    type Value = FxHashSet<FailureType>;
+    type Value = FxHashMap<FailureType, Ratio>;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(formatter, "a sequence of failure ids")
+        write!(
+            formatter,
+            "a sequence of failure ids or [failure id, severity] pairs"
+        )
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
-        let mut active_failures = FxHashSet::with_capacity_and_hasher(
+        let mut active_failures = FxHashMap::with_capacity_and_hasher(
             seq.size_hint().unwrap_or_default(),
             Default::default(),
         );
-        while let Some(failure_id) = seq.next_element()? {
+        while let Some(entry) = seq.next_element()? {
+            let (failure_id, severity) = match entry {
+                FailureEntry::Id(failure_id) => (failure_id, Ratio::new::<ratio>(1.)),
+                FailureEntry::WithSeverity(failure_id, severity) => {
+                    (failure_id, Ratio::new::<ratio>(severity))
+                }
+            };
             if let Some(failure) = self.0.get(&failure_id).copied() {
-                active_failures.insert(failure);
+                active_failures.insert(failure, severity);
             }
         }
         Ok(active_failures)
     }
 }
+
+fn default_severity() -> f64 {
+    1.
+}
+
+#[derive(Deserialize)]
+struct ArmedFailureMessage {
+    id: u64,
+    #[serde(default = "default_severity")]
+    severity: f64,
+    trigger: FailureTriggerMessage,
+}
+
+#[derive(Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct FailureGeneratorMessage {
+    rate_multiplier: f64,
+    seed: Option<u64>,
+    failures: Vec<FailureReliabilityMessage>,
+}
+
+#[derive(Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct FailureReliabilityMessage {
+    id: u64,
+    mtbf_hours: f64,
+    exposure: Option<FailureTriggerMessage>,
+}
+
+#[derive(Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+enum FailureTriggerMessage {
+    HeightAboveGroundAboveFeet(f64),
+    HeightAboveGroundBelowFeet(f64),
+    IndicatedAirspeedAboveKnots(f64),
+    IndicatedAirspeedBelowKnots(f64),
+    FlightPhase(FailureFlightPhaseMessage),
+    TimeAfterTakeoffSeconds(f64),
+    RandomWithinSeconds(f64, f64),
+}
+impl FailureTriggerMessage {
+    /// Negative durations are treated as zero, non-finite ones are invalid.
+    fn duration(seconds: f64) -> Result<Duration, TryFromFloatSecsError> {
+        Duration::try_from_secs_f64(if seconds < 0. { 0. } else { seconds })
+    }
+}
+impl TryFrom<FailureTriggerMessage> for FailureTrigger {
+    type Error = TryFromFloatSecsError;
+
+    fn try_from(message: FailureTriggerMessage) -> Result<Self, Self::Error> {
+        Ok(match message {
+            FailureTriggerMessage::HeightAboveGroundAboveFeet(height) => {
+                FailureTrigger::HeightAboveGroundAbove(Length::new::<foot>(height))
+            }
+            FailureTriggerMessage::HeightAboveGroundBelowFeet(height) => {
+                FailureTrigger::HeightAboveGroundBelow(Length::new::<foot>(height))
+            }
+            FailureTriggerMessage::IndicatedAirspeedAboveKnots(speed) => {
+                FailureTrigger::IndicatedAirspeedAbove(Velocity::new::<knot>(speed))
+            }
+            FailureTriggerMessage::IndicatedAirspeedBelowKnots(speed) => {
+                FailureTrigger::IndicatedAirspeedBelow(Velocity::new::<knot>(speed))
+            }
+            FailureTriggerMessage::FlightPhase(phase) => FailureTrigger::FlightPhase(phase.into()),
+            FailureTriggerMessage::TimeAfterTakeoffSeconds(seconds) => {
+                FailureTrigger::TimeAfterTakeoff(FailureTriggerMessage::duration(seconds)?)
+            }
+            FailureTriggerMessage::RandomWithinSeconds(earliest, latest) => {
+                FailureTrigger::RandomWithin(
+                    FailureTriggerMessage::duration(earliest)?,
+                    FailureTriggerMessage::duration(latest)?,
+                )
+            }
+        })
+    }
+}
+
+#[derive(Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+enum FailureFlightPhaseMessage {
+    Ground,
+    Climb,
+    Cruise,
+    Descent,
+}
+impl From<FailureFlightPhaseMessage> for FailureFlightPhase {
+    fn from(message: FailureFlightPhaseMessage) -> Self {
+        match message {
+            FailureFlightPhaseMessage::Ground => FailureFlightPhase::Ground,
+            FailureFlightPhaseMessage::Climb => FailureFlightPhase::Climb,
+            FailureFlightPhaseMessage::Cruise => FailureFlightPhase::Cruise,
+            FailureFlightPhaseMessage::Descent => FailureFlightPhase::Descent,
+        }
+    }
+}
@@ -1,6 +1,7 @@
 #[macro_use]
 pub mod aspects;
 mod anti_ice;
+mod doors;
 mod electrical;
 mod failures;
 mod fuel;
@@ -19,6 +20,7 @@ use ::msfs::commbus::{CommBus, CommBusBroadcastFlags};
 
 use crate::anti_ice::{engine_anti_ice, wing_anti_ice};
 use crate::aspects::{Aspect, ExecuteOn, MsfsAspectBuilder};
+use crate::doors::residual_pressure_door_lock;
 use crate::electrical::{auxiliary_power_unit, electrical_buses};
 use ::msfs::{
     sim_connect::{data_definition, Period, SimConnect, SimConnectRecv, SIMCONNECT_OBJECT_ID_USER},
@@ -126,6 +128,13 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
         self.with_aspect(wing_anti_ice())
     }
 
+    pub fn with_residual_pressure_door_lock(
+        self,
+        doors: impl IntoIterator<Item = (&'static str, usize)>,
+    ) -> Result<Self, Box<dyn Error>> {
+        self.with_aspect(residual_pressure_door_lock(doors))
+    }
+
     pub fn with_fuel_pumps(
         self,
         pump_indexes: impl IntoIterator<Item = u32>,
@@ -206,6 +215,18 @@ impl MsfsHandler {
                 failures.borrow_mut().handle_failure_update(data);
             });
         }
+        {
+            let failures = failures.clone();
+            commbus.register("FBW_FAILURE_ARM", move |data| {
+                failures.borrow_mut().handle_failure_arm(data);
+            });
+        }
+        {
+            let failures = failures.clone();
+            commbus.register("FBW_FAILURE_GENERATOR", move |data| {
+                failures.borrow_mut().handle_failure_generator(data);
+            });
+        }
         CommBus::call("FBW_FAILURE_REQUEST", "", CommBusBroadcastFlags::JS);
         Ok(Self {
             variables: Some(variables),
@@ -227,6 +248,7 @@ impl MsfsHandler {
                 if !self.time.is_pausing() {
                     let delta_time = self.time.take();
                     self.pre_tick(sim_connect, delta_time)?;
+                    self.update_triggered_failures(simulation);
                     self.read_failures_into_simulation(simulation);
 
                     simulation.tick(delta_time, self.time.simulation_time(), self);
@@ -291,6 +313,28 @@ impl MsfsHandler {
         Ok(())
     }
 
+    fn update_triggered_failures<T: Aircraft>(&mut self, simulation: &Simulation<T>) {
+        let mut failures = self.failures.borrow_mut();
+
+        let triggered_failures = failures.update(simulation.update_context());
+        if !triggered_failures.is_empty() {
+            Self::broadcast_failures("FBW_FAILURE_TRIGGERED", &triggered_failures);
+        }
+
+        if let Some(injected_failures) = failures.get_updated_injected_failures() {
+            Self::broadcast_failures("FBW_FAILURE_INJECTED", &injected_failures);
+        }
+    }
+
+    fn broadcast_failures(event_name: &str, failures: &impl serde::Serialize) {
+        match serde_json::to_string(failures) {
+            Ok(data) => {
+                CommBus::call(event_name, &data, CommBusBroadcastFlags::JS);
+            }
+            Err(e) => eprintln!("SYSTEMS: Failed to serialize {event_name} message: '{e}'"),
+        }
+    }
+
     fn read_failures_into_simulation<T: Aircraft>(&mut self, simulation: &mut Simulation<T>) {
         if let Some(active_failures) = self.failures.borrow_mut().get_updated_active_failures() {
             simulation.update_active_failures(active_failures);